@@ -0,0 +1,168 @@
+use crate::config::ReportFormat;
+use crate::diff::Snapshot;
+use crate::i18n::t_with;
+use crate::models::Repository;
+use crate::report::report_files;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// Distinct commits a repository needs across snapshots before it has a baseline
+const MIN_COMMITS: usize = 5;
+
+/// How many typical gaps a silence must last to count as an anomaly
+const SILENCE_FACTOR: u32 = 5;
+
+/// Shortest silence flagged, so a daily repository isn't flagged over a weekend
+const MIN_SILENCE: Duration = Duration::from_secs(7 * 86400);
+
+/// A repository gone quiet compared with how often it usually commits
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommitAnomaly {
+    /// Median gap between commits seen across snapshots
+    pub typical_gap: Duration,
+    /// Time since the latest commit
+    pub silent_for: Duration,
+}
+
+impl CommitAnomaly {
+    /// Human-readable explanation, e.g. "usually commits every 1d, silent for 21d"
+    pub fn description(&self) -> String {
+        t_with(
+            "activity.anomaly",
+            &[
+                ("typical", &format_gap(self.typical_gap)),
+                ("silent", &format_gap(self.silent_for)),
+            ],
+        )
+    }
+}
+
+/// Typical gap between commits per repository (full name), learned from snapshots
+///
+/// Each snapshot records the repository's latest commit; the distinct values
+/// across snapshots are the commits the snapshots saw, and the median gap
+/// between them is the repository's own baseline. Repositories seen committing
+/// fewer than `MIN_COMMITS` times have no baseline.
+pub fn commit_baselines(snapshots: &[Snapshot]) -> HashMap<String, Duration> {
+    let mut commits: HashMap<&str, Vec<SystemTime>> = HashMap::new();
+    for snapshot in snapshots {
+        for row in &snapshot.repositories {
+            if let Some(at) = row.latest_commit_at {
+                commits
+                    .entry(row.repository.as_str())
+                    .or_default()
+                    .push(at.into());
+            }
+        }
+    }
+
+    commits
+        .into_iter()
+        .filter_map(|(repository, mut times)| {
+            times.sort();
+            times.dedup();
+            if times.len() < MIN_COMMITS {
+                return None;
+            }
+            let mut gaps: Vec<Duration> = times
+                .windows(2)
+                .map(|pair| pair[1].duration_since(pair[0]).unwrap_or_default())
+                .collect();
+            gaps.sort();
+            Some((repository.to_string(), gaps[gaps.len() / 2]))
+        })
+        .collect()
+}
+
+/// Learn commit baselines from the JSON reports in a directory
+///
+/// Unreadable reports are skipped; a missing directory means no baselines yet.
+pub fn load_baselines(directory: &Path) -> HashMap<String, Duration> {
+    let snapshots: Vec<Snapshot> = report_files(directory, ReportFormat::Json.extension())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|path| Snapshot::load(path).ok())
+        .collect();
+    commit_baselines(&snapshots)
+}
+
+/// Whether a repository has been silent far longer than its baseline allows
+pub fn commit_anomaly(
+    repo: &Repository,
+    baselines: &HashMap<String, Duration>,
+    now: SystemTime,
+) -> Option<CommitAnomaly> {
+    let typical_gap = *baselines.get(&repo.full_name())?;
+    let silent_for = now.duration_since(repo.latest_commit_at?).ok()?;
+    (silent_for >= MIN_SILENCE && silent_for >= typical_gap * SILENCE_FACTOR).then_some(
+        CommitAnomaly {
+            typical_gap,
+            silent_for,
+        },
+    )
+}
+
+/// Format a gap in whole days, or hours below a day
+fn format_gap(gap: Duration) -> String {
+    let hours = gap.as_secs() / 3600;
+    if hours < 24 {
+        t_with("activity.hours", &[("count", &hours.max(1))])
+    } else {
+        t_with("activity.days", &[("count", &(hours / 24))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::{SnapshotRow, SnapshotTotals};
+    use chrono::{TimeZone, Utc};
+
+    const DAY: Duration = Duration::from_secs(86400);
+
+    fn snapshot(commit_day: u32) -> Snapshot {
+        Snapshot {
+            generated_at: String::new(),
+            scope: String::new(),
+            totals: SnapshotTotals {
+                repositories: 1,
+                average_health: None,
+                needs_attention: 0,
+                open_pull_requests: 0,
+                ci_broken: 0,
+            },
+            repositories: vec![SnapshotRow {
+                repository: "acme/api".to_string(),
+                health_score: 80,
+                open_pull_requests: 0,
+                needs_attention: false,
+                latest_commit_at: Some(Utc.with_ymd_and_hms(2026, 3, commit_day, 9, 0, 0).unwrap()),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_daily_repository_silent_for_weeks_is_flagged() {
+        // Hourly snapshots see the same commit repeatedly; only distinct ones count
+        let snapshots: Vec<Snapshot> = [1, 1, 2, 3, 3, 4, 5, 6].into_iter().map(snapshot).collect();
+        let baselines = commit_baselines(&snapshots);
+        assert_eq!(baselines["acme/api"], DAY);
+        assert!(commit_baselines(&snapshots[..4]).is_empty());
+
+        let mut repo = Repository::new("api".to_string(), "acme".to_string());
+        let last_commit: SystemTime = Utc.with_ymd_and_hms(2026, 3, 6, 9, 0, 0).unwrap().into();
+        repo.latest_commit_at = Some(last_commit);
+
+        let anomaly = commit_anomaly(&repo, &baselines, last_commit + 21 * DAY).unwrap();
+        assert_eq!(
+            anomaly.description(),
+            "usually commits every 1d, silent for 21d"
+        );
+        assert!(commit_anomaly(&repo, &baselines, last_commit + 3 * DAY).is_none());
+
+        // A naturally slow repository isn't flagged for the same silence
+        let slow = HashMap::from([("acme/api".to_string(), 30 * DAY)]);
+        assert!(commit_anomaly(&repo, &slow, last_commit + 21 * DAY).is_none());
+    }
+}
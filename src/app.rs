@@ -1,8 +1,42 @@
-use crate::github::GitHubClient;
-use crate::models::Repository;
+use crate::activity::CommitAnomaly;
+use crate::audit::{AuditEvent, OrgAuditLog};
+use crate::bus::{AppEvent, EventBus};
+use crate::canary::{DatasetChange, HeldDataset};
+use crate::cleanup::{CleanupCandidate, CleanupReason};
+use crate::codeowners::Codeowners;
+use crate::config::{AppConfig, Collector, SmartViewConfig, TimeStyle};
+use crate::config_check::ConfigProblem;
+use crate::credentials;
+use crate::dependencies::DependencyGraph;
+use crate::dialog::{
+    Dialog, DialogAction, DialogField, DialogOutcome, IssueTriage, Menu, MenuAction, MenuItem,
+    MenuOutcome,
+};
+use crate::failures::FailureCluster;
+use crate::github::{EnhancementProfile, GitHubClient, RateLimitStatus, TokenCheck};
+use crate::hooks::HookRunner;
+use crate::i18n::{t, t_with};
+use crate::metrics::{SessionSummary, StartupMilestone, StartupTimings};
+use crate::models::{
+    redacted_name, ChangedFile, CheckRun, ComplianceReport, ContributionCalendar,
+    CustomCheckResult, Issue, IssueAction, IssueActivity, IssueSearchFilter, MergeRequirements,
+    OrgMembershipHealth, Repository, RepositoryStatus, ReviewEvent, SearchedIssue, WorkflowStatus,
+};
+use crate::notifications::{AlertSubscriber, Notifier, ThresholdMonitor, ThresholdSubscriber};
+use crate::platform_status::PlatformStatus;
+use crate::policy::{self, PolicyFacts, PolicyReport};
+use crate::prefetch::OrgHistory;
+use crate::releases::ReleaseReadiness;
+use crate::report::{self, ArchivedReport};
+use crate::table::RowCache;
+use crate::undo::{UndoAction, UndoStack};
 use ratatui::crossterm::event::KeyCode;
-use std::collections::HashMap;
-use std::time::Instant;
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::SystemTime;
 use tokio::sync::mpsc;
 
 /// Messages sent from background tasks to the main UI thread
@@ -30,10 +64,117 @@ pub enum BackgroundMessage {
     },
     /// All repositories have been enhanced with full details
     EnhancementCompleted { repositories: Vec<Repository> },
-    /// Organizations list fetching started
-    OrganizationsFetchStarted,
+    /// Enhancement hit a rate limit at repository `current` and waits for the reset
+    EnhancementPaused {
+        current: usize,
+        total: usize,
+        resume_at: SystemTime,
+    },
+    /// Enhancement continued after a rate-limit pause
+    EnhancementResumed,
     /// Organizations list fetched
     OrganizationsFetched { organizations: Vec<String> },
+    /// Check runs for a pull request head were fetched
+    CheckRunsFetched {
+        pr_number: u32,
+        check_runs: Vec<CheckRun>,
+    },
+    /// A pull request's mergeability and branch protection were fetched
+    MergeRequirementsFetched {
+        pr_number: u32,
+        requirements: MergeRequirements,
+    },
+    /// Files changed by a pull request were fetched
+    PullRequestFilesFetched {
+        pr_number: u32,
+        files: Vec<ChangedFile>,
+    },
+    /// A check run was successfully re-requested
+    CheckRunRerequested { check_run_id: u64 },
+    /// A review was submitted on a pull request
+    PullRequestReviewed { pr_number: u32, event: ReviewEvent },
+    /// Re-requesting a check run failed
+    CheckRunRerequestFailed { check_run_id: u64, error: String },
+    /// Organization membership health was fetched
+    OrgMembershipFetched { health: OrgMembershipHealth },
+    /// Health-relevant organization audit log events were fetched
+    OrgAuditLogFetched { audit_log: OrgAuditLog },
+    /// Open critical issues were fetched for a repository
+    CriticalIssuesFetched {
+        repository: String,
+        issues: Vec<Issue>,
+    },
+    /// Issue open/close activity was fetched for a burn-down chart
+    IssueActivityFetched {
+        repository: String,
+        weeks: usize,
+        issues: Vec<IssueActivity>,
+    },
+    /// Open issues were fetched for the issues view
+    OpenIssuesFetched {
+        repository: String,
+        issues: Vec<Issue>,
+    },
+    /// Open issues were searched across the loaded repositories
+    IssuesSearched {
+        filter: IssueSearchFilter,
+        issues: Vec<SearchedIssue>,
+        /// Issues GitHub found, which can exceed those returned
+        total: usize,
+    },
+    /// An issue was assigned, labeled or commented on
+    IssueTriaged {
+        repository: String,
+        issue: Issue,
+        action: IssueAction,
+    },
+    /// A repository was created from the template and fetched
+    RepositoryCreated { repository: Repository },
+    /// Template compliance scan finished
+    ComplianceScanned { report: ComplianceReport },
+    /// Policy facts were read, keyed by repository full name
+    PoliciesScanned { facts: HashMap<String, PolicyFacts> },
+    /// Manifests were scanned for dependencies between repositories
+    DependenciesScanned { graph: DependencyGraph },
+    /// CODEOWNERS files were read, keyed by repository full name
+    OwnershipScanned {
+        ownership: HashMap<String, Codeowners>,
+    },
+    /// Repositories were checked for signs of being abandoned or duplicated
+    CleanupScanned { candidates: Vec<CleanupCandidate> },
+    /// A repository's custom health checks finished
+    CustomChecksCompleted {
+        repository: String,
+        results: Vec<CustomCheckResult>,
+    },
+    /// A repository was archived from the cleanup view
+    RepositoryArchived { repository: String },
+    /// Failed workflow jobs were clustered by error signature
+    FailuresClustered { clusters: Vec<FailureCluster> },
+    /// The user's contribution calendar was fetched
+    ContributionsFetched { calendar: ContributionCalendar },
+    /// The rate limit was checked before a refresh (None if the check failed)
+    RateLimitChecked { status: Option<RateLimitStatus> },
+    /// The token was checked before the first fetch (None if GitHub couldn't be asked)
+    TokenChecked { check: Option<TokenCheck> },
+    /// GitHub rejected the token
+    TokenRejected { error: String },
+    /// GitHub platform status was fetched from githubstatus.com
+    PlatformStatusFetched { status: PlatformStatus },
+    /// An organization was loaded in the background before being viewed
+    OrganizationPrefetched {
+        org: String,
+        result: Result<Vec<Repository>, String>,
+        /// API requests the load took
+        requests: u64,
+    },
+    /// An organization's enhanced repositories were fetched for comparison
+    ComparisonFetched {
+        org: String,
+        repositories: Vec<Repository>,
+    },
+    /// A user-triggered action or detail fetch failed
+    ActionError { error: String },
 }
 
 /// Application state and configuration
@@ -67,7 +208,7 @@ pub struct App {
     /// List of organizations the user belongs to
     pub user_organizations: Vec<String>,
 
-    /// Current organization index for cycling (0 = Personal, 1+ = organizations)
+    /// Current view mode index for cycling (0 = Personal, 1+ = organizations, then All)
     pub current_org_index: usize,
 
     /// Cached personal repositories
@@ -76,6 +217,19 @@ pub struct App {
     /// Cached organization repositories (org_name -> repositories)
     pub organization_repositories: std::collections::HashMap<String, Vec<Repository>>,
 
+    /// Cached repositories of the All view mode
+    pub all_repositories: Option<Vec<Repository>>,
+
+    /// Which organizations were viewed and what loading them cost
+    pub org_history: OrgHistory,
+
+    /// Organizations left to prefetch, most useful first (None until the
+    /// organization list is known)
+    prefetch_queue: Option<Vec<String>>,
+
+    /// Organization being prefetched and its task
+    prefetching: Option<(String, tokio::task::JoinHandle<()>)>,
+
     /// Loading state for async operations
     pub is_loading: bool,
 
@@ -88,9 +242,15 @@ pub struct App {
     /// Loading progress information
     pub loading_progress: Option<(usize, usize)>, // (current, total)
 
+    /// Set to stop paging through an organization after the current page
+    pub pagination_stop: Option<Arc<AtomicBool>>,
+
     /// Enhancement progress information
     pub enhancement_progress: Option<(usize, usize)>, // (current, total)
 
+    /// Set while enhancement waits for a rate limit to reset
+    pub rate_limit_pause: Option<RateLimitPause>,
+
     /// Error message if something goes wrong
     pub error_message: Option<String>,
 
@@ -103,21 +263,405 @@ pub struct App {
     /// Receiver for background task messages
     pub background_receiver: Option<mpsc::UnboundedReceiver<BackgroundMessage>>,
 
-    /// Repository view mode - personal or organizations
-    pub repository_view_mode: RepositoryViewMode,
+    /// Repository fetch in progress, cancelled if it stops making progress
+    background_task: Option<tokio::task::JoinHandle<()>>,
+
+    /// When the repository fetch last sent a message
+    last_background_progress: Option<std::time::Instant>,
+
+    /// Repositories listed by the current refresh but not enhanced yet
+    pending_enhancement: HashSet<String>,
+
+    /// Retries of stalled refreshes since the last complete one
+    stall_retries: u32,
+
+    /// When a cancelled refresh is retried
+    stall_retry_at: Option<std::time::Instant>,
+
+    /// Sender for detail fetches and user actions
+    ///
+    /// Unlike the fetch channel this one lives for the whole session, so a
+    /// refresh replacing the fetch channel doesn't drop in-flight actions.
+    pub action_sender: mpsc::UnboundedSender<BackgroundMessage>,
+
+    /// Receiver for detail fetches and user actions
+    pub action_receiver: mpsc::UnboundedReceiver<BackgroundMessage>,
+
+    /// Currently selected pull request in the repository details view
+    pub selected_pull_request: usize,
+
+    /// Check runs for the pull request shown in the PR details view
+    pub check_runs: Vec<CheckRun>,
+
+    /// Currently selected check run in the PR details view
+    pub selected_check_run: usize,
+
+    /// Whether check runs are currently being fetched
+    pub is_fetching_check_runs: bool,
+
+    /// Mergeability and branch protection of the pull request in the PR details view
+    pub merge_requirements: Option<MergeRequirements>,
+
+    /// Whether merge requirements are currently being fetched
+    pub is_fetching_merge_requirements: bool,
+
+    /// Files changed by the pull request shown in the files view, in path order
+    pub pull_request_files: Vec<ChangedFile>,
+
+    /// Whether pull request files are currently being fetched
+    pub is_fetching_pull_request_files: bool,
+
+    /// Scroll offset of the pull request files view
+    pub pull_request_files_scroll: usize,
+
+    /// Whether the files view lists the most churned files first
+    pub sort_files_by_churn: bool,
+
+    /// Status each re-requested check had before it was marked pending
+    pub pending_check_reruns: HashMap<u64, WorkflowStatus>,
+
+    /// Transient status message shown in the footer (e.g. action results)
+    pub status_message: Option<String>,
+
+    /// Configuration loaded from the config file
+    pub config: AppConfig,
+
+    /// Compiled commit message convention (None if the configured regex is invalid)
+    pub commit_convention: Option<Regex>,
+
+    /// Internal event bus that notifiers and other plugins subscribe to
+    pub event_bus: EventBus,
+
+    /// Number of refreshes that finished enhancing every repository
+    pub completed_refreshes: u64,
+
+    /// Errors shown this session, for the summary printed on exit
+    session_errors: Vec<String>,
+
+    /// API requests made before the current refresh started
+    refresh_request_baseline: Option<usize>,
+
+    /// API requests made by the last completed refresh
+    pub last_refresh_requests: Option<usize>,
+
+    /// Detail fetched by the next refresh (reset to full once it starts)
+    refresh_profile: EnhancementProfile,
+
+    /// Repository list shown before the refresh in progress, kept on screen
+    /// until the refreshed list passes the canary check
+    canary_baseline: Option<Vec<Repository>>,
+
+    /// Refreshed repository list that changed too much, awaiting confirmation
+    pub held_dataset: Option<HeldDataset>,
+
+    /// Whether focus mode (hide repositories not needing attention) is on
+    pub focus_mode: bool,
+
+    /// Order of the repositories in the dashboard table
+    pub sort: RepositorySort,
+
+    /// Whether the table is sorted descending by `sort`
+    pub sort_descending: bool,
+
+    /// Key ordering repositories that tie on `sort`, and its direction
+    pub secondary_sort: Option<(RepositorySort, bool)>,
+
+    /// Index of the smart view filtering the dashboard in `config.views`
+    pub smart_view: Option<usize>,
+
+    /// Owning team the dashboard is filtered to
+    pub team_filter: Option<String>,
+
+    /// Repository topic the dashboard is filtered to
+    pub topic_filter: Option<String>,
+
+    /// CODEOWNERS of the loaded repositories by full name (None until scanned)
+    pub ownership: Option<HashMap<String, Codeowners>>,
+
+    /// Latest custom health check results by repository full name, kept
+    /// until the checks run again
+    pub custom_checks: HashMap<String, Vec<CustomCheckResult>>,
+
+    /// Reversible actions taken from the TUI, for `u`
+    pub undo_stack: UndoStack,
+
+    /// Issue whose label edit is being reverted, so its result isn't
+    /// recorded as a new action
+    undoing_issue: Option<(String, u64)>,
+
+    /// Whether CODEOWNERS files are being read
+    pub is_scanning_ownership: bool,
+
+    /// Indices into `repositories` shown in the dashboard table, in order
+    pub visible_repositories: Vec<usize>,
+
+    /// First row shown in the CI analytics panel
+    pub ci_scroll: usize,
+
+    /// Failed workflow jobs of the loaded repositories grouped by error signature
+    pub failure_clusters: Option<Vec<FailureCluster>>,
+
+    /// Whether failing job logs are being downloaded and clustered
+    pub is_clustering_failures: bool,
+
+    /// Issue activity for the burn-down chart, keyed by repository full name
+    pub issue_activity: Option<(String, Vec<IssueActivity>)>,
+
+    /// Whether issue activity is being fetched
+    pub is_fetching_issue_activity: bool,
+
+    /// Open issues for the issues view, keyed by repository full name
+    pub open_issues: Option<(String, Vec<Issue>)>,
+
+    /// Currently selected issue in the issues view
+    pub selected_issue: usize,
+
+    /// Whether open issues are being fetched
+    pub is_fetching_open_issues: bool,
+
+    /// Filters of the issue search across loaded repositories
+    pub issue_search_filter: IssueSearchFilter,
+
+    /// Issues found by the last search, with the total GitHub found
+    pub searched_issues: Option<(Vec<SearchedIssue>, usize)>,
+
+    /// Currently selected issue in the issue search view
+    pub selected_searched_issue: usize,
+
+    /// Whether issues are being searched
+    pub is_searching_issues: bool,
+
+    /// Number of weeks shown in the burn-down chart
+    pub burndown_weeks: usize,
+
+    /// Number of hours up to now shown in the workflow timeline
+    pub timeline_hours: u64,
+
+    /// Scroll offset of the workflow timeline
+    pub timeline_scroll: u16,
+
+    /// Modal dialog collecting input for an action (captures all keys while open)
+    pub dialog: Option<Dialog>,
+
+    /// Modal menu of links for the selected repository (captures all keys while open)
+    pub menu: Option<Menu>,
+
+    /// Whether the API request metrics overlay is shown
+    pub show_metrics: bool,
+
+    /// How long the first load took to reach each milestone (metrics overlay)
+    pub startup: StartupTimings,
+
+    /// Whether the popover explaining the selected row's indicators is shown
+    pub show_inspect: bool,
+
+    /// Repository name prefix typed since `'`, while quick jump is active
+    pub quick_jump: Option<String>,
+
+    /// Typical gap between commits per repository, learned from stored reports
+    pub commit_baselines: HashMap<String, std::time::Duration>,
+
+    /// Whether guest mode is on: private repository names and descriptions
+    /// are redacted and actions that change anything are disabled
+    pub guest_mode: bool,
+
+    /// Whether the dashboard shows the built-in demo repositories (`--demo`)
+    /// instead of fetching from GitHub
+    pub demo: bool,
+
+    /// Startup check of the token and its scopes
+    pub token_status: TokenStatus,
+
+    /// Problems found in the config file on launch
+    pub config_problems: Vec<ConfigProblem>,
+
+    /// Latest template compliance report
+    pub compliance_report: Option<ComplianceReport>,
+
+    /// Whether a template compliance scan is running
+    pub is_scanning_compliance: bool,
+
+    /// Scroll offset of the template compliance view
+    pub compliance_scroll: u16,
+
+    /// Latest topic policy report
+    pub policy_report: Option<PolicyReport>,
+
+    /// Whether repositories are being checked against their topic policies
+    pub is_scanning_policies: bool,
+
+    /// Scroll offset of the policy view
+    pub policy_scroll: u16,
+
+    /// Scroll offset of the release readiness board
+    pub release_scroll: u16,
+
+    /// Internal dependencies between the loaded repositories
+    pub dependency_graph: Option<DependencyGraph>,
+
+    /// Whether repository manifests are being scanned for dependencies
+    pub is_scanning_dependencies: bool,
+
+    /// Scroll offset of the dependency view
+    pub dependencies_scroll: u16,
+
+    /// Repositories that look safe to archive (None until scanned)
+    pub cleanup_candidates: Option<Vec<CleanupCandidate>>,
+
+    /// Whether repositories are being checked for cleanup candidates
+    pub is_scanning_cleanup: bool,
+
+    /// Scroll offset of the cleanup view
+    pub cleanup_scroll: u16,
+
+    /// Archive candidates left to confirm in the bulk-archive workflow
+    pub archive_queue: Vec<String>,
+
+    /// Candidates in the current bulk-archive workflow, for "2/5" progress
+    pub archive_total: usize,
+
+    /// The user's contribution calendar (None until fetched)
+    pub contributions: Option<ContributionCalendar>,
+
+    /// Whether the contribution calendar is being fetched
+    pub is_fetching_contributions: bool,
+
+    /// Reports and digests in the reports directory, newest first
+    pub archived_reports: Vec<ArchivedReport>,
+
+    /// Index of the selected archived report
+    pub selected_archived_report: usize,
+
+    /// Links opened this session, most recent first
+    pub opened_links: Vec<String>,
+
+    /// Index of the selected link in the links pane
+    pub selected_link: usize,
+
+    /// Latest GitHub platform status (None until first fetched)
+    pub platform_status: Option<PlatformStatus>,
+
+    /// When the platform status was last requested
+    pub platform_status_checked_at: Option<std::time::Instant>,
+
+    /// Whether the terminal has focus (true unless it reports losing it)
+    pub terminal_focused: bool,
+
+    /// Left and right panes of the comparison view
+    pub comparison_panes: Vec<ComparisonPane>,
+
+    /// Index of the comparison pane receiving navigation keys
+    pub comparison_focus: usize,
+
+    /// Enhanced organization repositories fetched for comparison, by org name
+    comparison_cache: HashMap<String, Vec<Repository>>,
+
+    /// Membership health of the organization shown in the membership panel
+    pub org_membership: Option<OrgMembershipHealth>,
+
+    /// Whether organization membership health is being fetched
+    pub is_fetching_org_membership: bool,
+
+    /// Scroll position of the membership panel
+    pub membership_scroll: u16,
+
+    /// Audit log feed of the organization shown in the admin view
+    pub org_audit_log: Option<OrgAuditLog>,
+
+    /// Whether the organization audit log is being fetched
+    pub is_fetching_audit_log: bool,
+
+    /// Index of the selected audit log event
+    pub selected_audit_event: usize,
+
+    /// Pre-formatted table rows, kept in step with `repositories`
+    pub row_cache: RowCache,
 }
 
+/// How often the GitHub status page is polled
+const PLATFORM_STATUS_INTERVAL: std::time::Duration = std::time::Duration::from_secs(120);
+
+/// Most links kept in the links pane
+const MAX_OPENED_LINKS: usize = 50;
+
+/// Burn-down chart windows (in weeks) cycled with `w`
+pub const BURNDOWN_WINDOWS: [usize; 4] = [4, 12, 26, 52];
+
+/// Workflow timeline windows (in hours) zoomed through with `+`/`-`
+pub const TIMELINE_WINDOWS: [u64; 6] = [1, 6, 24, 72, 168, 720];
+
+/// Snooze durations offered in the snooze dialog (message key, days)
+const SNOOZE_DURATIONS: [(&str, i64); 4] = [
+    ("dialog.one_week", 7),
+    ("dialog.one_day", 1),
+    ("dialog.two_weeks", 14),
+    ("dialog.one_month", 30),
+];
+
 /// Different views/screens in the application
 #[derive(Debug, Default, Clone, PartialEq)]
 pub enum AppView {
     #[default]
     Dashboard,
+    /// Details of the selected repository with its open pull requests
+    RepoDetails,
+    /// Details of the selected pull request with its check runs
+    PullRequestDetails,
+    /// Diffstat of the files changed by the selected pull request
+    PullRequestFiles,
+    /// Membership hygiene panel for the current organization
+    OrgMembership,
+    /// Admin view: health-relevant events from the organization's audit log
+    AuditLog,
+    /// CI analytics across the loaded repositories
+    CiAnalytics,
+    /// Open pull requests grouped by release milestone or label, across repositories
+    ReleaseBoard,
+    /// Weekly opened vs closed issues for the selected repository
+    IssueBurndown,
+    /// Open issues of the selected repository, for triage
+    Issues,
+    /// Open issues across the loaded repositories, searched with filters
+    IssueSearch,
+    /// Recent workflow runs of the selected repository on a time axis
+    WorkflowTimeline,
+    /// Template compliance of the loaded repositories
+    TemplateCompliance,
+    /// Topic policy violations of the loaded repositories
+    Policies,
+    /// Two organizations or groups side by side
+    Comparison,
+    /// Internal dependencies, ranked by the blast radius of unhealthy repositories
+    Dependencies,
+    /// Repositories that look abandoned or duplicated, as archive candidates
+    Cleanup,
+    /// The authenticated user's contribution calendar and streaks
+    Contributions,
+    /// Reports and digests stored in the reports directory
+    Reports,
+    /// Links opened this session, as clickable hyperlinks
+    Links,
+    /// Why the token can't be used, or what it can't see
+    TokenDiagnostics,
+    /// Problems found in the config file on launch
+    ConfigProblems,
     // Future views:
     // Settings,
-    // RepoDetails,
     // Help,
 }
 
+/// Where the startup token check stands
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum TokenStatus {
+    #[default]
+    Unchecked,
+    Checking,
+    /// Checked, or GitHub couldn't be asked (`None`); fetching goes ahead
+    Checked(Option<TokenCheck>),
+    /// GitHub rejected the token; nothing is fetched
+    Rejected(String),
+}
+
 /// Different repository view modes
 #[derive(Debug, Clone, PartialEq)]
 pub enum RepositoryViewMode {
@@ -125,68 +669,580 @@ pub enum RepositoryViewMode {
     Personal,
     /// Show repositories from a specific organization
     Organization(String), // Organization name
+    /// Show personal and organization repositories together
+    All,
 }
 
 impl RepositoryViewMode {
     pub fn display_name(&self) -> String {
         match self {
-            RepositoryViewMode::Personal => "Personal".to_string(),
-            RepositoryViewMode::Organization(org) => format!("Org: {}", org),
+            RepositoryViewMode::Personal => t("header.personal").to_string(),
+            RepositoryViewMode::Organization(org) => t_with("header.organization", &[("org", org)]),
+            RepositoryViewMode::All => t("header.all").to_string(),
+        }
+    }
+}
+
+/// What a comparison pane shows
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComparisonSource {
+    /// All repositories of an organization
+    Organization(String),
+    /// The loaded repositories belonging to a configured group
+    Group(String),
+}
+
+impl ComparisonSource {
+    pub fn display_name(&self) -> String {
+        match self {
+            ComparisonSource::Organization(org) => t_with("header.organization", &[("org", org)]),
+            ComparisonSource::Group(group) => t_with("header.group", &[("group", group)]),
+        }
+    }
+}
+
+/// One side of the comparison view, with its own list selection
+#[derive(Debug, Clone)]
+pub struct ComparisonPane {
+    pub source: ComparisonSource,
+    pub repositories: Vec<Repository>,
+    pub selected: usize,
+    pub is_loading: bool,
+}
+
+/// Where a rate-limited enhancement pass stopped and when it resumes
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimitPause {
+    /// Repository (1-based) that will be enhanced again after the pause
+    pub current: usize,
+    pub total: usize,
+    pub resume_at: SystemTime,
+}
+
+impl RateLimitPause {
+    /// Time left until the pass resumes, as e.g. "4m 05s"
+    pub fn countdown(&self, now: SystemTime) -> String {
+        let secs = self
+            .resume_at
+            .duration_since(now)
+            .map(|remaining| remaining.as_secs())
+            .unwrap_or(0);
+        if secs >= 60 {
+            format!("{}m {:02}s", secs / 60, secs % 60)
+        } else {
+            format!("{}s", secs)
+        }
+    }
+}
+
+/// Order of the repositories in the dashboard table
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RepositorySort {
+    /// Order returned by GitHub (most recently updated first)
+    #[default]
+    #[serde(rename = "updated")]
+    Default,
+    /// Largest repositories first
+    Size,
+    /// Repository name
+    Name,
+    /// Open pull request count
+    #[serde(rename = "prs")]
+    PullRequests,
+    /// Latest commit (or issue activity)
+    Activity,
+    /// Activity status, Dormant first
+    Status,
+    /// Latest release date, repositories without one first
+    Release,
+}
+
+impl RepositorySort {
+    /// Every sort key, in sort menu order
+    pub const ALL: [RepositorySort; 7] = [
+        RepositorySort::Default,
+        RepositorySort::Name,
+        RepositorySort::PullRequests,
+        RepositorySort::Activity,
+        RepositorySort::Status,
+        RepositorySort::Release,
+        RepositorySort::Size,
+    ];
+
+    /// The sort order `s` switches to from this one
+    pub fn next(self) -> Self {
+        match self {
+            RepositorySort::Default => RepositorySort::Size,
+            _ => RepositorySort::Default,
+        }
+    }
+
+    /// Short label for the footer
+    pub fn label(&self) -> &'static str {
+        match self {
+            RepositorySort::Default => t("sort.updated"),
+            RepositorySort::Size => t("sort.size"),
+            RepositorySort::Name => t("sort.name"),
+            RepositorySort::PullRequests => t("sort.pull_requests"),
+            RepositorySort::Activity => t("sort.activity"),
+            RepositorySort::Status => t("sort.status"),
+            RepositorySort::Release => t("sort.release"),
+        }
+    }
+
+    /// Name used for the order in the config file
+    pub fn key(&self) -> &'static str {
+        match self {
+            RepositorySort::Default => "updated",
+            RepositorySort::Size => "size",
+            RepositorySort::Name => "name",
+            RepositorySort::PullRequests => "prs",
+            RepositorySort::Activity => "activity",
+            RepositorySort::Status => "status",
+            RepositorySort::Release => "release",
+        }
+    }
+
+    /// Whether the key starts out descending: names go A to Z, everything
+    /// else newest, largest or least active first
+    pub fn descending_by_default(&self) -> bool {
+        *self != RepositorySort::Name
+    }
+
+    /// Compare two repositories in ascending order of this key
+    ///
+    /// `a` and `b` are positions in GitHub's order, most recently updated
+    /// first, which "updated" ascending reverses.
+    pub fn compare(
+        &self,
+        (a, repo_a): (usize, &Repository),
+        (b, repo_b): (usize, &Repository),
+    ) -> std::cmp::Ordering {
+        match self {
+            RepositorySort::Default => b.cmp(&a),
+            RepositorySort::Size => repo_a.size_kb.cmp(&repo_b.size_kb),
+            RepositorySort::Name => repo_a.name.to_lowercase().cmp(&repo_b.name.to_lowercase()),
+            RepositorySort::PullRequests => repo_a
+                .open_pull_request_count()
+                .cmp(&repo_b.open_pull_request_count()),
+            RepositorySort::Activity => {
+                let latest = |repo: &Repository| match &repo.issue_health {
+                    Some(health) => health.latest_activity,
+                    None => repo.latest_commit_at,
+                };
+                latest(repo_a).cmp(&latest(repo_b))
+            }
+            RepositorySort::Status => {
+                let rank = |repo: &Repository| match repo.status {
+                    RepositoryStatus::Unknown => 0,
+                    RepositoryStatus::Hot => 1,
+                    RepositoryStatus::Active => 2,
+                    RepositoryStatus::Moderate => 3,
+                    RepositoryStatus::Quiet => 4,
+                    RepositoryStatus::Stale => 5,
+                    RepositoryStatus::Dormant => 6,
+                    RepositoryStatus::Empty => 7,
+                };
+                rank(repo_a).cmp(&rank(repo_b))
+            }
+            RepositorySort::Release => {
+                let published = |repo: &Repository| {
+                    repo.latest_release
+                        .as_ref()
+                        .map(|release| release.published_at)
+                };
+                published(repo_a).cmp(&published(repo_b))
+            }
         }
     }
 }
 
+/// Header arrow for a sort direction
+pub fn sort_arrow(descending: bool) -> &'static str {
+    if descending {
+        "▼"
+    } else {
+        "▲"
+    }
+}
+
 impl App {
-    /// Create a new application instance with default settings
+    /// Create a new application instance using the config file
+    ///
+    /// Problems found in the file are shown before the dashboard.
     pub fn new() -> Self {
+        let (config, problems) = AppConfig::load_checked();
+        let mut app = match config {
+            Some(config) => {
+                let locale = crate::i18n::init(&config.i18n);
+                let baselines = config
+                    .reports
+                    .directory()
+                    .map(|directory| crate::activity::load_baselines(&directory))
+                    .unwrap_or_default();
+                let org_history = match config.prefetch.history_path() {
+                    Some(path) if config.prefetch.organizations > 0 => OrgHistory::load(&path),
+                    _ => OrgHistory::default(),
+                };
+                let mut app = Self::with_config(config);
+                app.commit_baselines = baselines;
+                app.org_history = org_history;
+                if let Err(e) = locale {
+                    app.status_message = Some(t_with("messages.locale_error", &[("error", &e)]));
+                }
+                app
+            }
+            None => {
+                let mut app = Self::with_config(AppConfig::default());
+                let errors: Vec<String> = problems.iter().map(ToString::to_string).collect();
+                app.status_message = Some(t_with(
+                    "messages.config_error",
+                    &[("errors", &errors.join("; "))],
+                ));
+                app
+            }
+        };
+        if !problems.is_empty() {
+            app.config_problems = problems;
+            app.current_view = AppView::ConfigProblems;
+        }
+        app
+    }
+
+    /// Create an application showing the demo repositories (`--demo`)
+    ///
+    /// No token is needed: the GitHub client is dropped, and refreshing
+    /// reloads the demo data.
+    pub fn demo() -> Self {
+        let mut app = Self::new();
+        app.demo = true;
+        app.github_client = None;
+        app.error_message = None;
+        app.refresh();
+        app
+    }
+
+    /// Create a new application instance with the given configuration
+    pub fn with_config(config: AppConfig) -> Self {
         // Try to initialize GitHub client
-        let (github_client, error_message) = match GitHubClient::new() {
-            Ok(client) => (Some(client), None),
-            Err(e) => (None, Some(format!("GitHub setup error: {}", e))),
+        let (github_client, error_message) =
+            match GitHubClient::new(&config.network, credentials::token) {
+                Ok(client) => (
+                    Some(
+                        client
+                            .with_page_sizes(config.api.page_sizes())
+                            .with_retry_policy(config.api.retry_policy()),
+                    ),
+                    None,
+                ),
+                Err(e) => (
+                    None,
+                    Some(t_with("messages.github_setup_error", &[("error", &e)])),
+                ),
+            };
+        let (action_sender, action_receiver) = mpsc::unbounded_channel();
+        let theme = config.theme.clone();
+        let time = config.time.clone();
+
+        // Routing problems are reported up front rather than on the first alert
+        let mut event_bus = EventBus::default();
+        let (notifier, status_message) = match Notifier::from_config(&config) {
+            Ok(notifier) => (notifier, None),
+            Err(errors) => (
+                None,
+                Some(t_with(
+                    "messages.notifications_disabled",
+                    &[("errors", &errors.join("; "))],
+                )),
+            ),
+        };
+        let (threshold_monitor, status_message) = match ThresholdMonitor::from_config(&config) {
+            Ok(monitor) => (monitor, status_message),
+            Err(errors) => (
+                None,
+                Some(t_with(
+                    "messages.webhooks_disabled",
+                    &[("errors", &errors.join("; "))],
+                )),
+            ),
         };
+        if let Some(notifier) = notifier {
+            event_bus.subscribe(Box::new(AlertSubscriber::new(
+                notifier,
+                action_sender.clone(),
+            )));
+        }
+        if let Some(monitor) = threshold_monitor {
+            event_bus.subscribe(Box::new(ThresholdSubscriber::new(
+                monitor,
+                action_sender.clone(),
+            )));
+        }
+        if let Some(runner) = HookRunner::from_config(&config, action_sender.clone()) {
+            event_bus.subscribe(Box::new(runner));
+        }
+
+        // Launch straight into the configured mode, smart view and sort
+        let startup = &config.startup;
+        let smart_view = startup
+            .view
+            .as_ref()
+            .and_then(|name| config.views.iter().position(|view| &view.name == name));
+        let sort = startup
+            .sort
+            .or(smart_view.map(|index| config.views[index].sort))
+            .unwrap_or_default();
+        let repo_view_mode = startup.view_mode();
 
         Self {
             should_quit: false,
             current_view: AppView::Dashboard,
-            title: "❤️ Repo Health Dashboard ❤️".to_string(),
+            title: t("header.title").to_string(),
             last_refresh: None,
             github_client,
             repositories: Vec::new(),
-            repo_view_mode: RepositoryViewMode::Personal,
+            repo_view_mode,
             user_organizations: Vec::new(),
             current_org_index: 0,
             personal_repositories: None,
             organization_repositories: HashMap::new(),
+            all_repositories: None,
+            org_history: OrgHistory::default(),
+            prefetch_queue: None,
+            prefetching: None,
             is_loading: false,
             is_enhancing: false,
             is_fetching_organizations: false,
             loading_progress: None,
+            pagination_stop: None,
             enhancement_progress: None,
+            rate_limit_pause: None,
             error_message,
             selected_repository: 0,
             scroll_offset: 0,
             background_receiver: None,
-            repository_view_mode: RepositoryViewMode::Personal,
+            background_task: None,
+            last_background_progress: None,
+            pending_enhancement: HashSet::new(),
+            stall_retries: 0,
+            stall_retry_at: None,
+            action_sender,
+            action_receiver,
+            selected_pull_request: 0,
+            check_runs: Vec::new(),
+            selected_check_run: 0,
+            is_fetching_check_runs: false,
+            merge_requirements: None,
+            is_fetching_merge_requirements: false,
+            pull_request_files: Vec::new(),
+            is_fetching_pull_request_files: false,
+            pull_request_files_scroll: 0,
+            sort_files_by_churn: false,
+            pending_check_reruns: HashMap::new(),
+            status_message,
+            commit_convention: config.commits.convention_regex(),
+            config,
+            event_bus,
+            completed_refreshes: 0,
+            session_errors: Vec::new(),
+            refresh_request_baseline: None,
+            refresh_profile: EnhancementProfile::Full,
+            canary_baseline: None,
+            held_dataset: None,
+            last_refresh_requests: None,
+            focus_mode: false,
+            sort,
+            sort_descending: sort.descending_by_default(),
+            secondary_sort: None,
+            smart_view,
+            visible_repositories: Vec::new(),
+            row_cache: RowCache::new(theme, time),
+            org_membership: None,
+            is_fetching_org_membership: false,
+            membership_scroll: 0,
+            org_audit_log: None,
+            is_fetching_audit_log: false,
+            selected_audit_event: 0,
+            ci_scroll: 0,
+            failure_clusters: None,
+            is_clustering_failures: false,
+            issue_activity: None,
+            is_fetching_issue_activity: false,
+            open_issues: None,
+            selected_issue: 0,
+            is_fetching_open_issues: false,
+            issue_search_filter: IssueSearchFilter::default(),
+            searched_issues: None,
+            selected_searched_issue: 0,
+            is_searching_issues: false,
+            burndown_weeks: BURNDOWN_WINDOWS[1],
+            timeline_hours: TIMELINE_WINDOWS[2],
+            timeline_scroll: 0,
+            dialog: None,
+            menu: None,
+            show_metrics: false,
+            startup: StartupTimings::default(),
+            show_inspect: false,
+            quick_jump: None,
+            commit_baselines: HashMap::new(),
+            guest_mode: false,
+            demo: false,
+            token_status: TokenStatus::Unchecked,
+            config_problems: Vec::new(),
+            compliance_report: None,
+            is_scanning_compliance: false,
+            compliance_scroll: 0,
+            policy_report: None,
+            is_scanning_policies: false,
+            policy_scroll: 0,
+            release_scroll: 0,
+            dependency_graph: None,
+            team_filter: None,
+            topic_filter: None,
+            ownership: None,
+            custom_checks: HashMap::new(),
+            undo_stack: UndoStack::default(),
+            undoing_issue: None,
+            is_scanning_ownership: false,
+            is_scanning_dependencies: false,
+            dependencies_scroll: 0,
+            cleanup_candidates: None,
+            is_scanning_cleanup: false,
+            cleanup_scroll: 0,
+            archive_queue: Vec::new(),
+            archive_total: 0,
+            contributions: None,
+            is_fetching_contributions: false,
+            archived_reports: Vec::new(),
+            selected_archived_report: 0,
+            opened_links: Vec::new(),
+            selected_link: 0,
+            platform_status: None,
+            platform_status_checked_at: None,
+            terminal_focused: true,
+            comparison_panes: Vec::new(),
+            comparison_focus: 0,
+            comparison_cache: HashMap::new(),
         }
     }
 
     /// Initialize the GitHub client
     pub fn initialize_github_client(&mut self) {
-        match GitHubClient::new() {
+        match GitHubClient::new(&self.config.network, credentials::token) {
             Ok(client) => {
-                self.github_client = Some(client);
+                client.hold(self.is_background_paused());
+                self.github_client = Some(
+                    client
+                        .with_page_sizes(self.config.api.page_sizes())
+                        .with_retry_policy(self.config.api.retry_policy()),
+                );
                 self.error_message = None;
             }
             Err(e) => {
-                self.error_message = Some(format!("Failed to initialize GitHub client: {}", e));
+                self.error_message =
+                    Some(t_with("messages.github_client_failed", &[("error", &e)]));
             }
         }
     }
 
+    /// Handle an event read from the terminal
+    ///
+    /// Keys, including the refresh keys, go through `handle_key_event` so the
+    /// open view, dialog or quick jump sees them first.
+    pub fn handle_terminal_event(&mut self, event: crate::events::AppEvent) {
+        match event {
+            crate::events::AppEvent::Key(key_event) => {
+                self.handle_key_event(key_event.code);
+            }
+            crate::events::AppEvent::Resize(_width, _height) => {
+                // Terminal was resized - the next draw will handle the new size
+                // No explicit action needed as ratatui handles this automatically
+            }
+            crate::events::AppEvent::Mouse(_mouse_event) => {
+                // Future: Handle mouse events for enhanced interaction
+            }
+            crate::events::AppEvent::Focus(focused) => self.set_focused(focused),
+        }
+    }
+
     /// Handle keyboard input and update application state
     ///
     /// Returns true if the event was handled, false otherwise
     pub fn handle_key_event(&mut self, key_code: KeyCode) -> bool {
+        // An open dialog captures all input
+        if self.dialog.is_some() {
+            self.handle_dialog_key(key_code);
+            return true;
+        }
+        if self.menu.is_some() {
+            self.handle_menu_key(key_code);
+            return true;
+        }
+        if self.quick_jump.is_some() {
+            return self.handle_quick_jump_key(key_code);
+        }
+
+        // d toggles the request metrics overlay from any view; Esc closes it
+        match key_code {
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                self.show_metrics = !self.show_metrics;
+                return true;
+            }
+            KeyCode::Esc if self.show_metrics => {
+                self.show_metrics = false;
+                return true;
+            }
+            // u undoes the last reversible action from any view, U lists them
+            KeyCode::Char('u') => {
+                self.undo_last();
+                return true;
+            }
+            KeyCode::Char('U') => {
+                self.open_undo_menu();
+                return true;
+            }
+            KeyCode::Esc if self.show_inspect && self.current_view == AppView::Dashboard => {
+                self.show_inspect = false;
+                return true;
+            }
+            _ => {}
+        }
+
+        let handled = match self.current_view {
+            AppView::Dashboard => self.handle_dashboard_key(key_code),
+            AppView::RepoDetails => self.handle_repo_details_key(key_code),
+            AppView::PullRequestDetails => self.handle_pull_request_details_key(key_code),
+            AppView::PullRequestFiles => self.handle_pull_request_files_key(key_code),
+            AppView::OrgMembership => self.handle_org_membership_key(key_code),
+            AppView::AuditLog => self.handle_audit_log_key(key_code),
+            AppView::CiAnalytics => self.handle_ci_analytics_key(key_code),
+            AppView::ReleaseBoard => self.handle_release_board_key(key_code),
+            AppView::IssueBurndown => self.handle_issue_burndown_key(key_code),
+            AppView::Issues => self.handle_issues_key(key_code),
+            AppView::IssueSearch => self.handle_issue_search_key(key_code),
+            AppView::WorkflowTimeline => self.handle_workflow_timeline_key(key_code),
+            AppView::TemplateCompliance => self.handle_template_compliance_key(key_code),
+            AppView::Policies => self.handle_policies_key(key_code),
+            AppView::Comparison => self.handle_comparison_key(key_code),
+            AppView::Dependencies => self.handle_dependencies_key(key_code),
+            AppView::Cleanup => self.handle_cleanup_key(key_code),
+            AppView::Contributions => self.handle_contributions_key(key_code),
+            AppView::Reports => self.handle_reports_key(key_code),
+            AppView::Links => self.handle_links_key(key_code),
+            AppView::TokenDiagnostics => self.handle_token_diagnostics_key(key_code),
+            AppView::ConfigProblems => self.handle_config_problems_key(key_code),
+        };
+        // Views without a refresh of their own refresh the repositories
+        if !handled && matches!(key_code, KeyCode::Char('r' | 'R') | KeyCode::F(5)) {
+            self.request_refresh();
+            return true;
+        }
+        handled
+    }
+
+    /// Handle keyboard input on the main dashboard
+    fn handle_dashboard_key(&mut self, key_code: KeyCode) -> bool {
         match key_code {
             // Quit the application
             KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => {
@@ -194,9 +1250,9 @@ impl App {
                 true
             }
 
-            // Refresh data (future implementation)
+            // Refresh data, checking the API budget first
             KeyCode::Char('r') | KeyCode::Char('R') | KeyCode::F(5) => {
-                self.refresh();
+                self.request_refresh();
                 true
             }
 
@@ -208,31 +1264,29 @@ impl App {
 
             // Navigation - Up arrow
             KeyCode::Up => {
-                if !self.repositories.is_empty() {
-                    if self.selected_repository > 0 {
-                        self.selected_repository -= 1;
-                        // Auto-scroll to keep selection visible
-                        self.ensure_selected_visible(10); // Assume at least 10 items visible
-                    }
+                if self.visible_repository_count() > 0 && self.selected_repository > 0 {
+                    self.selected_repository -= 1;
+                    // Auto-scroll to keep selection visible
+                    self.ensure_selected_visible(10); // Assume at least 10 items visible
                 }
                 true
             }
 
             // Navigation - Down arrow
             KeyCode::Down => {
-                if !self.repositories.is_empty() {
-                    if self.selected_repository < self.repositories.len() - 1 {
-                        self.selected_repository += 1;
-                        // Auto-scroll to keep selection visible
-                        self.ensure_selected_visible(10); // Assume at least 10 items visible
-                    }
+                if self.visible_repository_count() > 0
+                    && self.selected_repository < self.visible_repository_count() - 1
+                {
+                    self.selected_repository += 1;
+                    // Auto-scroll to keep selection visible
+                    self.ensure_selected_visible(10); // Assume at least 10 items visible
                 }
                 true
             }
 
             // Page Up - scroll up by page
             KeyCode::PageUp => {
-                if !self.repositories.is_empty() {
+                if self.visible_repository_count() > 0 {
                     // Move selection up by 10 items or to the top
                     if self.selected_repository >= 10 {
                         self.selected_repository -= 10;
@@ -247,12 +1301,13 @@ impl App {
 
             // Page Down - scroll down by page
             KeyCode::PageDown => {
-                if !self.repositories.is_empty() {
+                let count = self.visible_repository_count();
+                if count > 0 {
                     // Move selection down by 10 items or to the bottom
-                    if self.selected_repository + 10 < self.repositories.len() {
+                    if self.selected_repository + 10 < count {
                         self.selected_repository += 10;
                     } else {
-                        self.selected_repository = self.repositories.len() - 1;
+                        self.selected_repository = count - 1;
                     }
                     // Update scroll position
                     self.ensure_selected_visible(10);
@@ -262,7 +1317,7 @@ impl App {
 
             // Home - jump to top
             KeyCode::Home => {
-                if !self.repositories.is_empty() {
+                if self.visible_repository_count() > 0 {
                     self.selected_repository = 0;
                     self.scroll_offset = 0;
                 }
@@ -271,458 +1326,5539 @@ impl App {
 
             // End - jump to bottom
             KeyCode::End => {
-                if !self.repositories.is_empty() {
-                    self.selected_repository = self.repositories.len() - 1;
+                if self.visible_repository_count() > 0 {
+                    self.selected_repository = self.visible_repository_count() - 1;
                     // Let ensure_selected_visible handle the scroll
                     self.ensure_selected_visible(10);
                 }
                 true
             }
 
-            // Future key handlers:
-            // KeyCode::Tab => self.next_view(),
-            // KeyCode::BackTab => self.previous_view(),
-            _ => false, // Event not handled
-        }
-    }
+            // Enter - open the selected repository
+            KeyCode::Enter => {
+                if self.selected_repository < self.visible_repository_count() {
+                    self.open_repo_details();
+                }
+                true
+            }
 
-    /// Refresh application data
-    ///
-    /// Updates the last refresh timestamp and initializes GitHub client if needed.
-    /// Marks the app as loading to trigger data fetching.
-    pub fn refresh(&mut self) {
-        self.last_refresh = Some(std::time::Instant::now());
+            // c - open the CI analytics panel
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                self.open_ci_analytics();
+                true
+            }
 
-        // If no GitHub client is initialized, try to initialize it
-        if self.github_client.is_none() {
-            self.initialize_github_client();
-        }
+            // b - open the release readiness board
+            KeyCode::Char('b') | KeyCode::Char('B') => {
+                self.current_view = AppView::ReleaseBoard;
+                self.release_scroll = 0;
+                true
+            }
 
-        // Clear cache for current mode to force refresh
-        match &self.repo_view_mode {
-            RepositoryViewMode::Personal => self.personal_repositories = None,
-            RepositoryViewMode::Organization(org_name) => {
-                self.organization_repositories.remove(org_name);
+            // n - create a repository in the current organization from the template
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                self.open_create_repository_dialog();
+                true
             }
-        }
 
-        // Fetch repositories for current mode
-        self.fetch_repositories_for_current_mode();
-    }
+            // t - scan the loaded repositories for template compliance
+            KeyCode::Char('t') | KeyCode::Char('T') => {
+                self.open_template_compliance();
+                true
+            }
+
+            // y - review a refreshed repository list held back by the canary check
+            KeyCode::Char('y') | KeyCode::Char('Y') if self.held_dataset.is_some() => {
+                self.open_held_dataset_menu();
+                true
+            }
+
+            // a - audit the loaded repositories against their topic policies
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                self.open_policies();
+                true
+            }
+
+            // g - dependency graph between the loaded repositories
+            KeyCode::Char('g') | KeyCode::Char('G') => {
+                self.open_dependencies();
+                true
+            }
+
+            // w - pick or save a smart view
+            KeyCode::Char('w') | KeyCode::Char('W') => {
+                self.open_views_menu();
+                true
+            }
+
+            // i - explain the selected row's indicators
+            KeyCode::Char('i') | KeyCode::Char('I') => {
+                self.show_inspect = !self.show_inspect && self.get_selected_repository().is_some();
+                true
+            }
+
+            // z - snooze the selected repository, or wake it up
+            KeyCode::Char('z') | KeyCode::Char('Z') => {
+                self.toggle_snooze();
+                true
+            }
+
+            // * - the user's contribution calendar
+            KeyCode::Char('*') => {
+                self.open_contributions();
+                true
+            }
+
+            // l - open issues across the loaded repositories
+            KeyCode::Char('l') | KeyCode::Char('L') => {
+                self.open_issue_search();
+                true
+            }
+
+            // j - browse the archived reports and digests
+            KeyCode::Char('j') | KeyCode::Char('J') => {
+                self.open_reports();
+                true
+            }
+
+            // k - links opened this session, for SSH sessions without a browser
+            KeyCode::Char('k') | KeyCode::Char('K') => {
+                self.current_view = AppView::Links;
+                self.selected_link = 0;
+                true
+            }
+
+            // p - guest mode for screenshots and screen-shares
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                self.guest_mode = !self.guest_mode;
+                true
+            }
+
+            // ' - jump to a repository by typing the start of its name
+            KeyCode::Char('\'') => {
+                self.quick_jump = Some(String::new());
+                true
+            }
+
+            // x - stop paging through a large organization
+            KeyCode::Char('x') | KeyCode::Char('X') => {
+                self.stop_pagination();
+                true
+            }
+
+            // h - repositories that look abandoned or duplicated
+            KeyCode::Char('h') | KeyCode::Char('H') => {
+                self.open_cleanup();
+                true
+            }
+
+            // s - switch between GitHub's order and largest first
+            KeyCode::Char('s') => {
+                self.cycle_sort();
+                true
+            }
+
+            // S - sort menu: primary and secondary key, each either direction
+            KeyCode::Char('S') => {
+                self.open_sort_menu(0);
+                true
+            }
+
+            // f - toggle focus mode (only repositories needing attention)
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                self.toggle_focus_mode();
+                true
+            }
+
+            // m - open the membership panel for the current organization
+            KeyCode::Char('m') => {
+                self.open_org_membership();
+                true
+            }
+
+            // . - open the links menu for the selected repository
+            KeyCode::Char('.') => {
+                self.open_repository_menu();
+                true
+            }
+
+            // e - switch data collectors on or off
+            KeyCode::Char('e') | KeyCode::Char('E') => {
+                self.open_features_menu(0);
+                true
+            }
+
+            // o - filter the dashboard by owning team from CODEOWNERS
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                self.open_team_menu();
+                true
+            }
+
+            // # - filter the dashboard by repository topic
+            KeyCode::Char('#') => {
+                self.open_topic_menu();
+                true
+            }
+
+            // v - compare two organizations or groups side by side
+            KeyCode::Char('v') | KeyCode::Char('V') => {
+                self.open_comparison();
+                true
+            }
+
+            // @ - switch between relative and absolute timestamps
+            KeyCode::Char('@') => {
+                self.toggle_time_style();
+                true
+            }
+
+            // M - include repositories the user is a collaborator or member of
+            KeyCode::Char('M') => {
+                self.toggle_affiliated();
+                true
+            }
+
+            // Future key handlers:
+            // KeyCode::Tab => self.next_view(),
+            // KeyCode::BackTab => self.previous_view(),
+            _ => false, // Event not handled
+        }
+    }
+
+    /// Handle keyboard input in the repository details view
+    fn handle_repo_details_key(&mut self, key_code: KeyCode) -> bool {
+        let pr_count = self
+            .get_selected_repository()
+            .map_or(0, |repo| repo.open_pull_requests.len());
+
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::Dashboard;
+                true
+            }
+            KeyCode::Up => {
+                self.selected_pull_request = self.selected_pull_request.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                if self.selected_pull_request + 1 < pr_count {
+                    self.selected_pull_request += 1;
+                }
+                true
+            }
+            KeyCode::Enter => {
+                if self.selected_pull_request < pr_count {
+                    self.open_pull_request_details();
+                }
+                true
+            }
+            KeyCode::Char('b') | KeyCode::Char('B') => {
+                self.open_issue_burndown();
+                true
+            }
+            KeyCode::Char('i') | KeyCode::Char('I') => {
+                self.open_issues();
+                true
+            }
+            KeyCode::Char('w') | KeyCode::Char('W') => {
+                self.open_workflow_timeline();
+                true
+            }
+            KeyCode::Char('.') => {
+                self.open_repository_menu();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle keyboard input in the pull request details view
+    fn handle_pull_request_details_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::RepoDetails;
+                true
+            }
+            KeyCode::Up => {
+                self.selected_check_run = self.selected_check_run.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                if self.selected_check_run + 1 < self.check_runs.len() {
+                    self.selected_check_run += 1;
+                }
+                true
+            }
+            KeyCode::Char('e') => {
+                self.rerun_selected_check();
+                true
+            }
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                self.open_pull_request_files();
+                true
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                self.open_review_dialog();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle keyboard input in the pull request files view
+    fn handle_pull_request_files_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::PullRequestDetails;
+                true
+            }
+            KeyCode::Up => {
+                self.pull_request_files_scroll = self.pull_request_files_scroll.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                if self.pull_request_files_scroll + 1 < self.pull_request_files.len() {
+                    self.pull_request_files_scroll += 1;
+                }
+                true
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                self.sort_files_by_churn = !self.sort_files_by_churn;
+                self.pull_request_files_scroll = 0;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle keyboard input in the organization membership panel
+    fn handle_org_membership_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::Dashboard;
+                true
+            }
+            KeyCode::Up => {
+                self.membership_scroll = self.membership_scroll.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                self.membership_scroll = self.membership_scroll.saturating_add(1);
+                true
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                self.open_audit_log();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle keyboard input in the audit log admin view
+    fn handle_audit_log_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::OrgMembership;
+                true
+            }
+            KeyCode::Up => {
+                self.selected_audit_event = self.selected_audit_event.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                let events = self
+                    .org_audit_log
+                    .as_ref()
+                    .and_then(|log| log.events.as_ref())
+                    .map_or(0, Vec::len);
+                if self.selected_audit_event + 1 < events {
+                    self.selected_audit_event += 1;
+                }
+                true
+            }
+            KeyCode::Enter | KeyCode::Char('o') | KeyCode::Char('O') => {
+                let repository = self
+                    .get_selected_audit_event()
+                    .and_then(|event| event.repository.clone());
+                if let Some(repository) = repository {
+                    self.open_link(&format!("https://github.com/{}", repository));
+                }
+                true
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.org_audit_log = None;
+                self.open_audit_log();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Get the audit log event under the selection
+    pub fn get_selected_audit_event(&self) -> Option<&AuditEvent> {
+        self.org_audit_log
+            .as_ref()
+            .and_then(|log| log.events.as_ref())
+            .and_then(|events| events.get(self.selected_audit_event))
+    }
+
+    /// Handle keyboard input in the comparison view
+    ///
+    /// Tab switches panes; ←/→ change the focused pane's organization or group.
+    fn handle_comparison_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace => {
+                self.current_view = AppView::Dashboard;
+                true
+            }
+            KeyCode::Tab => {
+                self.comparison_focus = (self.comparison_focus + 1) % self.comparison_panes.len();
+                true
+            }
+            KeyCode::Left => {
+                self.cycle_comparison_source(false);
+                true
+            }
+            KeyCode::Right => {
+                self.cycle_comparison_source(true);
+                true
+            }
+            KeyCode::Up => {
+                if let Some(pane) = self.comparison_panes.get_mut(self.comparison_focus) {
+                    pane.selected = pane.selected.saturating_sub(1);
+                }
+                true
+            }
+            KeyCode::Down => {
+                if let Some(pane) = self.comparison_panes.get_mut(self.comparison_focus) {
+                    if pane.selected + 1 < pane.repositories.len() {
+                        pane.selected += 1;
+                    }
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle keyboard input in the CI analytics panel
+    fn handle_ci_analytics_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::Dashboard;
+                true
+            }
+            KeyCode::Up => {
+                self.ci_scroll = self.ci_scroll.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                if self.ci_scroll + 1 < self.repositories.len() {
+                    self.ci_scroll += 1;
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Open the CI analytics panel and cluster workflow failures once per refresh
+    fn open_ci_analytics(&mut self) {
+        self.current_view = AppView::CiAnalytics;
+        self.ci_scroll = 0;
+
+        if self.failure_clusters.is_some() || self.is_clustering_failures {
+            return;
+        }
+        let Some(client) = self.github_client.clone() else {
+            return;
+        };
+        self.is_clustering_failures = true;
+        GitHubClient::spawn_failure_clustering(
+            client,
+            self.action_sender.clone(),
+            self.repositories.clone(),
+        );
+    }
+
+    /// Handle keyboard input on the release readiness board
+    fn handle_release_board_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::Dashboard;
+                true
+            }
+            KeyCode::Up => {
+                self.release_scroll = self.release_scroll.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                self.release_scroll = self.release_scroll.saturating_add(1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Releases shared by the loaded repositories' open pull requests
+    ///
+    /// An invalid label pattern leaves only milestones to group by.
+    pub fn release_readiness(&self) -> Vec<ReleaseReadiness> {
+        let pattern = self.config.releases.label_regex();
+        ReleaseReadiness::from_repositories(&self.repositories, pattern.as_ref())
+    }
+
+    /// Handle keyboard input in the issue burn-down view
+    fn handle_issue_burndown_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::RepoDetails;
+                true
+            }
+            KeyCode::Char('w') | KeyCode::Char('W') => {
+                let next = BURNDOWN_WINDOWS
+                    .iter()
+                    .position(|&weeks| weeks == self.burndown_weeks)
+                    .map_or(0, |index| (index + 1) % BURNDOWN_WINDOWS.len());
+                self.burndown_weeks = BURNDOWN_WINDOWS[next];
+                self.open_issue_burndown();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle keyboard input in the issues view
+    fn handle_issues_key(&mut self, key_code: KeyCode) -> bool {
+        let issue_count = self.issues_for_selected_repository().len();
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::RepoDetails;
+                true
+            }
+            KeyCode::Up => {
+                self.selected_issue = self.selected_issue.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                if self.selected_issue + 1 < issue_count {
+                    self.selected_issue += 1;
+                }
+                true
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                self.open_issue_dialog(IssueTriage::Assign);
+                true
+            }
+            KeyCode::Char('l') | KeyCode::Char('L') => {
+                self.open_issue_dialog(IssueTriage::Label);
+                true
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                self.open_issue_dialog(IssueTriage::Comment);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Open the issues view for the selected repository and fetch its open issues
+    fn open_issues(&mut self) {
+        self.current_view = AppView::Issues;
+        self.selected_issue = 0;
+
+        let (Some(client), Some(repo)) =
+            (self.github_client.clone(), self.get_selected_repository())
+        else {
+            return;
+        };
+        let (owner, name) = (repo.owner.clone(), repo.name.clone());
+
+        // Keep the previous list for the same repository while refetching
+        if self.open_issues.as_ref().map(|(repo, _)| repo) != Some(&format!("{}/{}", owner, name)) {
+            self.open_issues = None;
+        }
+        self.is_fetching_open_issues = true;
+        GitHubClient::spawn_open_issues_fetch(client, self.action_sender.clone(), owner, name);
+    }
+
+    /// Open issues of the selected repository, if they were fetched for it
+    pub fn issues_for_selected_repository(&self) -> &[Issue] {
+        let Some(repo) = self.get_selected_repository() else {
+            return &[];
+        };
+        match &self.open_issues {
+            Some((repository, issues)) if *repository == repo.full_name() => issues,
+            _ => &[],
+        }
+    }
+
+    /// The issue selected in the issues view
+    pub fn get_selected_issue(&self) -> Option<&Issue> {
+        self.issues_for_selected_repository()
+            .get(self.selected_issue)
+    }
+
+    /// Owner, repository name and issue the triage dialogs act on
+    ///
+    /// In the issue search view that's the selected search result, otherwise
+    /// the selected issue of the selected repository.
+    fn triage_target(&self) -> Option<(String, String, &Issue)> {
+        if self.current_view == AppView::IssueSearch {
+            let searched = self.get_selected_searched_issue()?;
+            let (owner, name) = searched.repository.split_once('/')?;
+            return Some((owner.to_string(), name.to_string(), &searched.issue));
+        }
+        let repo = self.get_selected_repository()?;
+        Some((
+            repo.owner.clone(),
+            repo.name.clone(),
+            self.get_selected_issue()?,
+        ))
+    }
+
+    /// Handle keyboard input in the issue search view
+    fn handle_issue_search_key(&mut self, key_code: KeyCode) -> bool {
+        let issue_count = self
+            .searched_issues
+            .as_ref()
+            .map_or(0, |(issues, _)| issues.len());
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::Dashboard;
+                true
+            }
+            KeyCode::Up => {
+                self.selected_searched_issue = self.selected_searched_issue.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                if self.selected_searched_issue + 1 < issue_count {
+                    self.selected_searched_issue += 1;
+                }
+                true
+            }
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                self.open_issue_search_dialog();
+                true
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.search_issues();
+                true
+            }
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                if let Some(searched) = self.get_selected_searched_issue() {
+                    let url = searched.issue.html_url.clone();
+                    self.open_link(&url);
+                }
+                true
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                self.open_issue_dialog(IssueTriage::Assign);
+                true
+            }
+            KeyCode::Char('l') | KeyCode::Char('L') => {
+                self.open_issue_dialog(IssueTriage::Label);
+                true
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                self.open_issue_dialog(IssueTriage::Comment);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Open the issue search view and search with the current filters
+    fn open_issue_search(&mut self) {
+        self.current_view = AppView::IssueSearch;
+        self.search_issues();
+    }
+
+    /// Search open issues across the loaded repositories with the current filters
+    fn search_issues(&mut self) {
+        let Some(client) = self.github_client.clone() else {
+            return;
+        };
+        let repositories: Vec<String> = self
+            .repositories
+            .iter()
+            .map(Repository::full_name)
+            .collect();
+        let today = chrono::Local::now().date_naive();
+        let queries = self.issue_search_filter.queries(&repositories, today);
+        if queries.is_empty() {
+            self.searched_issues = Some((Vec::new(), 0));
+            return;
+        }
+        self.is_searching_issues = true;
+        GitHubClient::spawn_issue_search(
+            client,
+            self.action_sender.clone(),
+            self.issue_search_filter.clone(),
+            queries,
+        );
+    }
+
+    /// The issue selected in the issue search view
+    pub fn get_selected_searched_issue(&self) -> Option<&SearchedIssue> {
+        self.searched_issues
+            .as_ref()
+            .and_then(|(issues, _)| issues.get(self.selected_searched_issue))
+    }
+
+    /// Open the dialog changing the issue search filters
+    fn open_issue_search_dialog(&mut self) {
+        let filter = &self.issue_search_filter;
+        let age = filter.min_age_days.map(|days| days.to_string());
+        self.dialog = Some(Dialog::new(
+            t("dialog.filter_issues"),
+            vec![
+                DialogField::text(t("dialog.filter_label"), false)
+                    .with_value(filter.label.as_deref().unwrap_or_default()),
+                DialogField::text(t("dialog.filter_assignee"), false)
+                    .with_value(filter.assignee.as_deref().unwrap_or_default()),
+                DialogField::text(t("dialog.filter_age"), false)
+                    .with_value(age.as_deref().unwrap_or_default()),
+            ],
+            DialogAction::FilterIssueSearch,
+        ));
+    }
+
+    /// Apply the filter dialog's values and search again
+    fn submit_issue_search_filter(&mut self, values: Vec<String>) {
+        let Ok([label, assignee, age]) = <[String; 3]>::try_from(values) else {
+            return;
+        };
+        let optional = |value: String| {
+            let value = value.trim().to_string();
+            (!value.is_empty()).then_some(value)
+        };
+        let min_age_days = match optional(age).map(|age| age.parse::<u64>()) {
+            None => None,
+            Some(Ok(days)) => Some(days),
+            Some(Err(_)) => {
+                self.status_message = Some(t("messages.invalid_age").to_string());
+                return;
+            }
+        };
+        self.issue_search_filter = IssueSearchFilter {
+            label: optional(label),
+            assignee: optional(assignee).map(|login| login.trim_start_matches('@').to_string()),
+            min_age_days,
+        };
+        self.selected_searched_issue = 0;
+        self.search_issues();
+    }
+
+    /// Open a dialog assigning, labeling or commenting on the selected issue
+    fn open_issue_dialog(&mut self, kind: IssueTriage) {
+        if self.blocked_in_guest_mode() {
+            return;
+        }
+        let Some((owner, repo, issue)) = self.triage_target() else {
+            return;
+        };
+
+        let (title, fields) = match kind {
+            IssueTriage::Assign => (
+                t_with(
+                    "dialog.assign_issue",
+                    &[("number", &issue.number), ("title", &issue.title)],
+                ),
+                vec![DialogField::text(t("dialog.assignee"), true)],
+            ),
+            IssueTriage::Label => (
+                t_with(
+                    "dialog.label_issue",
+                    &[
+                        ("number", &issue.number),
+                        ("labels", &issue.labels.join(", ")),
+                    ],
+                ),
+                vec![
+                    DialogField::text(t("dialog.add_labels"), false),
+                    DialogField::text(t("dialog.remove_labels"), false),
+                ],
+            ),
+            IssueTriage::Comment => (
+                t_with(
+                    "dialog.comment_issue",
+                    &[("number", &issue.number), ("title", &issue.title)],
+                ),
+                vec![DialogField::text(t("dialog.comment"), true)],
+            ),
+        };
+        self.dialog = Some(Dialog::new(
+            &title,
+            fields,
+            DialogAction::TriageIssue {
+                owner,
+                repo,
+                number: issue.number,
+                kind,
+            },
+        ));
+    }
+
+    /// Open the workflow timeline for the selected repository
+    fn open_workflow_timeline(&mut self) {
+        let Some(repo) = self.get_selected_repository() else {
+            return;
+        };
+        // Start zoomed out just far enough to show every recent run
+        let now = SystemTime::now();
+        let oldest = repo
+            .recent_workflows
+            .iter()
+            .map(|run| run.span(now).0)
+            .min()
+            .and_then(|start| now.duration_since(start).ok())
+            .unwrap_or_default();
+        self.timeline_hours = TIMELINE_WINDOWS
+            .iter()
+            .copied()
+            .find(|&hours| std::time::Duration::from_secs(hours * 3600) >= oldest)
+            .unwrap_or(TIMELINE_WINDOWS[TIMELINE_WINDOWS.len() - 1]);
+        self.timeline_scroll = 0;
+        self.current_view = AppView::WorkflowTimeline;
+    }
+
+    /// Handle keyboard input in the workflow timeline
+    fn handle_workflow_timeline_key(&mut self, key_code: KeyCode) -> bool {
+        let zoom = TIMELINE_WINDOWS
+            .iter()
+            .position(|&hours| hours == self.timeline_hours)
+            .unwrap_or(2);
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::RepoDetails;
+                true
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.timeline_hours = TIMELINE_WINDOWS[zoom.saturating_sub(1)];
+                true
+            }
+            KeyCode::Char('-') => {
+                self.timeline_hours = TIMELINE_WINDOWS[(zoom + 1).min(TIMELINE_WINDOWS.len() - 1)];
+                true
+            }
+            KeyCode::Up => {
+                self.timeline_scroll = self.timeline_scroll.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                self.timeline_scroll = self.timeline_scroll.saturating_add(1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Open the burn-down chart for the selected repository and fetch its issues
+    fn open_issue_burndown(&mut self) {
+        self.current_view = AppView::IssueBurndown;
+
+        let (Some(client), Some(repo)) =
+            (self.github_client.clone(), self.get_selected_repository())
+        else {
+            return;
+        };
+        let (owner, name) = (repo.owner.clone(), repo.name.clone());
+
+        // Keep the previous chart for the same repository while refetching
+        if self.issue_activity.as_ref().map(|(repo, _)| repo)
+            != Some(&format!("{}/{}", owner, name))
+        {
+            self.issue_activity = None;
+        }
+        self.is_fetching_issue_activity = true;
+        GitHubClient::spawn_issue_activity_fetch(
+            client,
+            self.action_sender.clone(),
+            owner,
+            name,
+            self.burndown_weeks,
+        );
+    }
+
+    /// Pass a key to the open dialog and run its action when submitted
+    fn handle_dialog_key(&mut self, key_code: KeyCode) {
+        let Some(dialog) = self.dialog.as_mut() else {
+            return;
+        };
+
+        match dialog.handle_key(key_code) {
+            DialogOutcome::Pending => {}
+            DialogOutcome::Cancelled => self.dialog = None,
+            DialogOutcome::Submitted(values) => {
+                let action = dialog.action.clone();
+                self.dialog = None;
+                self.run_dialog_action(action, values);
+            }
+        }
+    }
+
+    /// Whether a dialog, menu or quick jump is capturing all keys
+    pub fn is_modal_open(&self) -> bool {
+        self.dialog.is_some() || self.menu.is_some() || self.quick_jump.is_some()
+    }
+
+    /// Pass a key to the open menu, opening the chosen link in the browser
+    fn handle_menu_key(&mut self, key_code: KeyCode) {
+        let Some(menu) = self.menu.as_mut() else {
+            return;
+        };
+
+        match menu.handle_key(key_code) {
+            MenuOutcome::Pending => {}
+            MenuOutcome::Cancelled => self.menu = None,
+            MenuOutcome::Selected(index) => {
+                let item = menu.items[index].clone();
+                self.menu = None;
+                match item.action {
+                    MenuAction::OpenUrl(url) => self.open_link(&url),
+                    MenuAction::SelectSmartView(index) => self.select_smart_view(index),
+                    MenuAction::SaveSmartView => self.open_save_smart_view_dialog(),
+                    MenuAction::FilterTeam(team) => self.select_team(team),
+                    MenuAction::FilterTopic(topic) => self.select_topic(topic),
+                    MenuAction::ScanOwnership => self.scan_ownership(),
+                    MenuAction::ArchiveRepository(repository) => {
+                        self.archive_queue.pop();
+                        if let (Some(client), Some((owner, name))) =
+                            (self.github_client.clone(), repository.split_once('/'))
+                        {
+                            GitHubClient::spawn_archive_repository(
+                                client,
+                                self.action_sender.clone(),
+                                owner.to_string(),
+                                name.to_string(),
+                            );
+                        }
+                        self.prompt_next_archive();
+                    }
+                    MenuAction::SkipArchive => {
+                        self.archive_queue.pop();
+                        self.prompt_next_archive();
+                    }
+                    MenuAction::StopArchive => {
+                        self.archive_queue.clear();
+                        self.status_message = Some(t("messages.bulk_archive_stopped").to_string());
+                    }
+                    MenuAction::ToggleCollector(collector) => {
+                        self.toggle_collector(collector);
+                        self.open_features_menu(index);
+                    }
+                    MenuAction::Undo(count) => {
+                        for _ in 0..count {
+                            self.undo_last();
+                        }
+                    }
+                    MenuAction::Refresh(profile) => {
+                        self.refresh_profile = profile;
+                        self.refresh();
+                    }
+                    MenuAction::SortBy(key) => {
+                        self.sort_by(key);
+                        self.open_sort_menu(index);
+                    }
+                    MenuAction::ThenSortBy(key) => {
+                        self.then_sort_by(key);
+                        self.open_sort_menu(index);
+                    }
+                    MenuAction::AcceptDataset => self.accept_held_dataset(),
+                    MenuAction::KeepPreviousDataset => self.keep_previous_dataset(),
+                }
+            }
+        }
+    }
+
+    /// Open the links menu for the selected repository
+    fn open_repository_menu(&mut self) {
+        let Some(repo) = self.get_selected_repository() else {
+            return;
+        };
+        let base = if repo.html_url.is_empty() {
+            format!("https://github.com/{}", repo.full_name())
+        } else {
+            repo.html_url.clone()
+        };
+
+        let items = [
+            ("menu.repository", ""),
+            ("menu.actions", "/actions"),
+            ("menu.pull_requests", "/pulls"),
+            ("menu.issues", "/issues"),
+            ("menu.security", "/security"),
+            ("menu.insights", "/pulse"),
+            ("menu.settings", "/settings"),
+        ]
+        .iter()
+        .map(|(label, path)| MenuItem {
+            label: t(label).to_string(),
+            action: MenuAction::OpenUrl(format!("{}{}", base, path)),
+        })
+        .collect();
+        self.menu = Some(Menu::new(&repo.full_name(), items));
+    }
+
+    /// Open the smart views picker
+    fn open_views_menu(&mut self) {
+        let mut items = vec![MenuItem {
+            label: t("menu.all_repositories").to_string(),
+            action: MenuAction::SelectSmartView(None),
+        }];
+        items.extend(
+            self.config
+                .views
+                .iter()
+                .enumerate()
+                .map(|(index, view)| MenuItem {
+                    label: view.name.clone(),
+                    action: MenuAction::SelectSmartView(Some(index)),
+                }),
+        );
+        items.push(MenuItem {
+            label: t("menu.save_view").to_string(),
+            action: MenuAction::SaveSmartView,
+        });
+
+        let mut menu = Menu::new(t("menu.views"), items);
+        menu.selected = self.smart_view.map_or(0, |index| index + 1);
+        self.menu = Some(menu);
+    }
+
+    /// Show only the repositories of a smart view, or all with `None`
+    ///
+    /// A view also brings its sort order.
+    pub fn select_smart_view(&mut self, index: Option<usize>) {
+        self.smart_view = index.filter(|&index| index < self.config.views.len());
+        if let Some(sort) = self.active_smart_view().map(|view| view.sort) {
+            self.sort = sort;
+            self.sort_descending = sort.descending_by_default();
+        }
+        self.refresh_visible_repositories();
+        self.scroll_offset = 0;
+        self.ensure_selected_visible(10);
+    }
+
+    /// Open the data collectors panel with the item at `selected` highlighted
+    ///
+    /// Each collector is labelled with what it costs to refresh the loaded
+    /// repositories.
+    fn open_features_menu(&mut self, selected: usize) {
+        let items = Collector::ALL
+            .into_iter()
+            .map(|collector| {
+                let requests: u64 = self
+                    .repositories
+                    .iter()
+                    .map(|repo| crate::github::collector_requests(collector, repo))
+                    .sum();
+                let checkbox = if self.config.collector_enabled(collector) {
+                    "[x]"
+                } else {
+                    "[ ]"
+                };
+                MenuItem {
+                    label: t_with(
+                        "menu.collector",
+                        &[
+                            ("checkbox", &checkbox),
+                            ("collector", &collector.label()),
+                            ("requests", &requests),
+                        ],
+                    ),
+                    action: MenuAction::ToggleCollector(collector),
+                }
+            })
+            .collect();
+
+        let mut menu = Menu::new(t("menu.collectors"), items);
+        menu.selected = selected;
+        self.menu = Some(menu);
+    }
+
+    /// Switch a collector on or off for the next refreshes and persist it
+    fn toggle_collector(&mut self, collector: Collector) {
+        if self.blocked_in_guest_mode() {
+            return;
+        }
+        let enabled = !self.config.collector_enabled(collector);
+        self.status_message = Some(
+            match self.config.set_collector_enabled(collector, enabled) {
+                Ok(()) => {
+                    self.undo_stack
+                        .push(UndoAction::Collector { collector, enabled });
+                    t_with(
+                        if enabled {
+                            "messages.collector_on"
+                        } else {
+                            "messages.collector_off"
+                        },
+                        &[("collector", &collector.label())],
+                    )
+                }
+                Err(e) => e,
+            },
+        );
+    }
+
+    /// Revert the most recent reversible action
+    fn undo_last(&mut self) {
+        if self.blocked_in_guest_mode() {
+            return;
+        }
+        let Some(action) = self.undo_stack.pop() else {
+            self.status_message = Some(t("messages.nothing_to_undo").to_string());
+            return;
+        };
+
+        let description = action.describe();
+        let result = match &action {
+            UndoAction::Snooze {
+                repository,
+                previous,
+                ..
+            } => {
+                // A snooze ending today wakes the repository up
+                let until = previous.unwrap_or_else(|| chrono::Local::now().date_naive());
+                let result = self.config.snooze_repository(repository, until);
+                self.refresh_visible_repositories();
+                result
+            }
+            UndoAction::Collector { collector, enabled } => {
+                self.config.set_collector_enabled(*collector, !enabled)
+            }
+            UndoAction::Labels {
+                owner,
+                repo,
+                number,
+                ..
+            } => {
+                let (Some(client), Some(revert)) =
+                    (self.github_client.clone(), action.label_revert())
+                else {
+                    self.undo_stack.push(action);
+                    self.status_message = Some(t("messages.no_client").to_string());
+                    return;
+                };
+                self.undoing_issue = Some((format!("{}/{}", owner, repo), *number));
+                GitHubClient::spawn_issue_triage(
+                    client,
+                    self.action_sender.clone(),
+                    owner.clone(),
+                    repo.clone(),
+                    *number,
+                    revert,
+                );
+                Ok(())
+            }
+        };
+        self.status_message = Some(match result {
+            Ok(()) => t_with("messages.undone", &[("action", &description)]),
+            Err(e) => e,
+        });
+    }
+
+    /// Labels an issue had as last shown in the issues view or search results
+    fn shown_issue_labels(&self, repository: &str, number: u64) -> Option<Vec<String>> {
+        let open = self
+            .open_issues
+            .as_ref()
+            .filter(|(fetched, _)| fetched == repository)
+            .and_then(|(_, issues)| issues.iter().find(|issue| issue.number == number));
+        let searched = || {
+            self.searched_issues
+                .as_ref()?
+                .0
+                .iter()
+                .find(|searched| {
+                    searched.repository == repository && searched.issue.number == number
+                })
+                .map(|searched| &searched.issue)
+        };
+        open.or_else(searched).map(|issue| issue.labels.clone())
+    }
+
+    /// Open the undo history, newest first; choosing an action undoes it
+    /// and everything after it
+    fn open_undo_menu(&mut self) {
+        if self.undo_stack.is_empty() {
+            self.status_message = Some(t("messages.nothing_to_undo").to_string());
+            return;
+        }
+        let items = self
+            .undo_stack
+            .history()
+            .enumerate()
+            .map(|(index, action)| MenuItem {
+                label: action.describe(),
+                action: MenuAction::Undo(index + 1),
+            })
+            .collect();
+        self.menu = Some(Menu::new(t("menu.undo_history"), items));
+    }
+
+    /// Open the owning team picker, reading CODEOWNERS files first if needed
+    fn open_team_menu(&mut self) {
+        let Some(ownership) = &self.ownership else {
+            self.scan_ownership();
+            return;
+        };
+
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for codeowners in ownership.values() {
+            if let Some(team) = codeowners.primary_team() {
+                *counts.entry(team).or_default() += 1;
+            }
+        }
+        let mut teams: Vec<(String, usize)> = counts.into_iter().collect();
+        teams.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let mut items = vec![MenuItem {
+            label: t("menu.all_teams").to_string(),
+            action: MenuAction::FilterTeam(None),
+        }];
+        items.extend(teams.into_iter().map(|(team, count)| MenuItem {
+            label: format!("{} ({})", team, count),
+            action: MenuAction::FilterTeam(Some(team)),
+        }));
+        items.push(MenuItem {
+            label: t("menu.rescan_codeowners").to_string(),
+            action: MenuAction::ScanOwnership,
+        });
+
+        let mut menu = Menu::new(t("menu.owning_team"), items);
+        menu.selected = self
+            .team_filter
+            .as_ref()
+            .and_then(|team| {
+                menu.items
+                    .iter()
+                    .position(|item| item.action == MenuAction::FilterTeam(Some(team.clone())))
+            })
+            .unwrap_or(0);
+        self.menu = Some(menu);
+    }
+
+    /// Open the topic picker, most used topics first
+    fn open_topic_menu(&mut self) {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for repo in &self.repositories {
+            for topic in &repo.topics {
+                *counts.entry(topic.as_str()).or_default() += 1;
+            }
+        }
+        if counts.is_empty() {
+            self.status_message = Some(t("messages.no_topics").to_string());
+            return;
+        }
+        let mut topics: Vec<(&str, usize)> = counts.into_iter().collect();
+        topics.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+        let mut items = vec![MenuItem {
+            label: t("menu.all_topics").to_string(),
+            action: MenuAction::FilterTopic(None),
+        }];
+        items.extend(topics.into_iter().map(|(topic, count)| MenuItem {
+            label: format!("{} ({})", topic, count),
+            action: MenuAction::FilterTopic(Some(topic.to_string())),
+        }));
+
+        let mut menu = Menu::new(t("menu.topic"), items);
+        menu.selected = self
+            .topic_filter
+            .as_ref()
+            .and_then(|topic| {
+                menu.items
+                    .iter()
+                    .position(|item| item.action == MenuAction::FilterTopic(Some(topic.clone())))
+            })
+            .unwrap_or(0);
+        self.menu = Some(menu);
+    }
+
+    /// Show only the repositories carrying a topic, or all with `None`
+    pub fn select_topic(&mut self, topic: Option<String>) {
+        self.topic_filter = topic;
+        self.refresh_visible_repositories();
+        self.scroll_offset = 0;
+        self.ensure_selected_visible(10);
+    }
+
+    /// Read the CODEOWNERS of the loaded repositories in the background
+    fn scan_ownership(&mut self) {
+        if self.is_scanning_ownership {
+            return;
+        }
+        let Some(client) = self.github_client.clone() else {
+            return;
+        };
+        let repositories = self
+            .repositories
+            .iter()
+            .map(|repo| (repo.owner.clone(), repo.name.clone()))
+            .collect();
+        self.is_scanning_ownership = true;
+        self.status_message = Some(t("messages.reading_codeowners").to_string());
+        GitHubClient::spawn_ownership_scan(client, self.action_sender.clone(), repositories);
+    }
+
+    /// Show only the repositories owned by a team, or all with `None`
+    pub fn select_team(&mut self, team: Option<String>) {
+        self.team_filter = team;
+        self.refresh_visible_repositories();
+        self.scroll_offset = 0;
+        self.ensure_selected_visible(10);
+    }
+
+    /// Primary owning team of a repository, once CODEOWNERS have been read
+    pub fn primary_team(&self, repo: &Repository) -> Option<String> {
+        self.ownership
+            .as_ref()?
+            .get(&repo.full_name())?
+            .primary_team()
+    }
+
+    /// The smart view filtering the dashboard, if any
+    pub fn active_smart_view(&self) -> Option<&SmartViewConfig> {
+        self.smart_view
+            .and_then(|index| self.config.views.get(index))
+    }
+
+    /// Whether a repository has gone quiet compared with its usual commit rate
+    pub fn commit_anomaly(&self, repo: &Repository) -> Option<CommitAnomaly> {
+        crate::activity::commit_anomaly(repo, &self.commit_baselines, SystemTime::now())
+    }
+
+    /// Full name shown for a repository, redacted in guest mode if it's private
+    ///
+    /// A repository that isn't loaded might be private, so in guest mode its
+    /// name is redacted too.
+    pub fn display_repository(&self, full_name: &str) -> String {
+        if !self.guest_mode {
+            return full_name.to_string();
+        }
+        match self.row_cache.position(full_name) {
+            Some(index) => self.repositories[index].display_full_name(true),
+            None => match full_name.split_once('/') {
+                Some((owner, _)) => format!("{}/{}", owner, redacted_name(full_name)),
+                None => redacted_name(full_name),
+            },
+        }
+    }
+
+    /// Whether a change is refused because guest or demo mode is on, telling
+    /// the user so
+    fn blocked_in_guest_mode(&mut self) -> bool {
+        if self.demo {
+            self.status_message = Some(t("messages.read_only_demo").to_string());
+            return true;
+        }
+        if self.guest_mode {
+            self.status_message = Some(t("messages.read_only_guest").to_string());
+        }
+        self.guest_mode
+    }
+
+    /// Open the dialog saving the current focus mode and sort as a smart view
+    fn open_save_smart_view_dialog(&mut self) {
+        if self.blocked_in_guest_mode() {
+            return;
+        }
+        let mut groups: Vec<&str> = vec![t("dialog.any")];
+        let mut names: Vec<&String> = self.config.groups.keys().collect();
+        names.sort();
+        groups.extend(names.into_iter().map(String::as_str));
+
+        self.dialog = Some(Dialog::new(
+            t("dialog.save_view"),
+            vec![
+                DialogField::text(t("dialog.name"), true),
+                DialogField::text(t("dialog.language"), false),
+                DialogField::text(t("dialog.topic"), false),
+                DialogField::choice(t("dialog.group"), &groups),
+                DialogField::choice(t("dialog.ci"), &[t("dialog.any"), t("dialog.failing")]),
+            ],
+            DialogAction::SaveSmartView,
+        ));
+    }
+
+    /// Save a smart view from the save dialog's values and switch to it
+    fn save_smart_view(&mut self, values: Vec<String>) {
+        let Ok([name, language, topic, group, ci]) = <[String; 5]>::try_from(values) else {
+            return;
+        };
+        let optional = |value: String| {
+            let value = value.trim().to_string();
+            (!value.is_empty() && value != t("dialog.any")).then_some(value)
+        };
+        let view = SmartViewConfig {
+            name: name.trim().to_string(),
+            focus: self.focus_mode,
+            failing_ci: ci == t("dialog.failing"),
+            language: optional(language),
+            topic: optional(topic),
+            group: optional(group),
+            sort: self.sort,
+        };
+
+        let name = view.name.clone();
+        match self.config.add_smart_view(view) {
+            Ok(()) => {
+                self.focus_mode = false;
+                self.select_smart_view(Some(self.config.views.len() - 1));
+                self.status_message = Some(t_with("messages.saved_view", &[("name", &name)]));
+            }
+            Err(e) => self.status_message = Some(e),
+        }
+    }
+
+    /// Snooze the selected repository, or wake it up if it is snoozed
+    ///
+    /// Snoozing asks for how long in a dialog.
+    fn toggle_snooze(&mut self) {
+        if self.blocked_in_guest_mode() {
+            return;
+        }
+        let Some(repo) = self.get_selected_repository() else {
+            return;
+        };
+        let repository = repo.full_name();
+        let today = chrono::Local::now().date_naive();
+        if self.config.attention.is_snoozed(repo, today) {
+            self.snooze_repository(&repository, today);
+            return;
+        }
+
+        self.dialog = Some(Dialog::new(
+            &t_with("dialog.snooze", &[("repository", &repository)]),
+            vec![
+                DialogField::choice(
+                    t("dialog.snooze_for"),
+                    &SNOOZE_DURATIONS.map(|(key, _)| t(key)),
+                ),
+                DialogField::text(t("dialog.snooze_until"), false),
+            ],
+            DialogAction::SnoozeRepository { repository },
+        ));
+    }
+
+    /// Persist a snooze ending on `until`, reporting the outcome
+    fn snooze_repository(&mut self, repository: &str, until: chrono::NaiveDate) {
+        let today = chrono::Local::now().date_naive();
+        let previous = self
+            .config
+            .attention
+            .snooze_end(repository)
+            .filter(|&end| end > today);
+        let result = self.config.snooze_repository(repository, until);
+        if result.is_ok() {
+            self.undo_stack.push(UndoAction::Snooze {
+                repository: repository.to_string(),
+                previous,
+                woke: until <= today,
+            });
+        }
+        match result {
+            Ok(()) if until > today => {
+                self.status_message = Some(t_with(
+                    "messages.snoozed",
+                    &[("repository", &repository), ("until", &until)],
+                ));
+            }
+            Ok(()) => {
+                self.status_message =
+                    Some(t_with("messages.woke_up", &[("repository", &repository)]));
+            }
+            Err(e) => self.status_message = Some(e),
+        }
+        self.refresh_visible_repositories();
+    }
+
+    /// Snooze a repository with the snooze dialog's values
+    fn submit_snooze(&mut self, repository: &str, values: Vec<String>) {
+        let Ok([duration, date]) = <[String; 2]>::try_from(values) else {
+            return;
+        };
+        let until = if date.trim().is_empty() {
+            let days = SNOOZE_DURATIONS
+                .iter()
+                .find(|(key, _)| t(key) == duration)
+                .map_or(7, |(_, days)| *days);
+            chrono::Local::now().date_naive() + chrono::Duration::days(days)
+        } else {
+            match chrono::NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d") {
+                Ok(until) => until,
+                Err(_) => {
+                    self.status_message =
+                        Some(t_with("messages.invalid_date", &[("date", &date.trim())]));
+                    return;
+                }
+            }
+        };
+        self.snooze_repository(repository, until);
+    }
+
+    /// Run the action of a submitted dialog
+    fn run_dialog_action(&mut self, action: DialogAction, values: Vec<String>) {
+        // Saving a view or snooze only touches the config file, and the issue
+        // search filter starts its own search
+        match &action {
+            DialogAction::SaveSmartView => {
+                self.save_smart_view(values);
+                return;
+            }
+            DialogAction::SnoozeRepository { repository } => {
+                self.submit_snooze(repository, values);
+                return;
+            }
+            DialogAction::FilterIssueSearch => {
+                self.submit_issue_search_filter(values);
+                return;
+            }
+            _ => {}
+        }
+
+        let Some(client) = self.github_client.clone() else {
+            self.status_message = Some(t("messages.no_client").to_string());
+            return;
+        };
+
+        match action {
+            DialogAction::CreateRepository { template, owner } => {
+                let Ok([name, description, visibility]) = <[String; 3]>::try_from(values) else {
+                    return;
+                };
+                self.status_message = Some(t_with(
+                    "messages.creating_repository",
+                    &[("repository", &format!("{}/{}", owner, name))],
+                ));
+                GitHubClient::spawn_repository_creation(
+                    client,
+                    self.action_sender.clone(),
+                    template,
+                    owner,
+                    name,
+                    description,
+                    visibility == t("dialog.private"),
+                );
+            }
+            DialogAction::ReviewPullRequest {
+                owner,
+                repo,
+                number,
+            } => {
+                let Ok([event, body]) = <[String; 2]>::try_from(values) else {
+                    return;
+                };
+                let Some(event) = ReviewEvent::from_label(&event) else {
+                    return;
+                };
+                if event.requires_body() && body.is_empty() {
+                    self.status_message = Some(t("messages.review_needs_body").to_string());
+                    return;
+                }
+                self.status_message =
+                    Some(t_with("messages.submitting_review", &[("number", &number)]));
+                GitHubClient::spawn_pull_request_review(
+                    client,
+                    self.action_sender.clone(),
+                    owner,
+                    repo,
+                    number,
+                    event,
+                    body,
+                );
+            }
+            DialogAction::TriageIssue {
+                owner,
+                repo,
+                number,
+                kind,
+            } => {
+                let action = match (kind, values.as_slice()) {
+                    (IssueTriage::Assign, [login]) => {
+                        IssueAction::Assign(login.trim_start_matches('@').to_string())
+                    }
+                    (IssueTriage::Label, [add, remove]) => IssueAction::Label {
+                        add: IssueAction::parse_labels(add),
+                        remove: IssueAction::parse_labels(remove),
+                    },
+                    (IssueTriage::Comment, [body]) => IssueAction::Comment(body.clone()),
+                    _ => return,
+                };
+                if let IssueAction::Label { add, remove } = &action {
+                    if add.is_empty() && remove.is_empty() {
+                        self.status_message = Some(t("messages.no_label_changes").to_string());
+                        return;
+                    }
+                }
+                self.status_message =
+                    Some(t_with("messages.updating_issue", &[("number", &number)]));
+                GitHubClient::spawn_issue_triage(
+                    client,
+                    self.action_sender.clone(),
+                    owner,
+                    repo,
+                    number,
+                    action,
+                );
+            }
+            DialogAction::SaveSmartView
+            | DialogAction::SnoozeRepository { .. }
+            | DialogAction::FilterIssueSearch => {}
+        }
+    }
+
+    /// Open the dialog reviewing the selected pull request
+    fn open_review_dialog(&mut self) {
+        if self.blocked_in_guest_mode() {
+            return;
+        }
+        let Some((owner, repo)) = self
+            .get_selected_repository()
+            .map(|repo| (repo.owner.clone(), repo.name.clone()))
+        else {
+            return;
+        };
+        let Some(pr) = self.get_selected_pull_request() else {
+            return;
+        };
+
+        self.dialog = Some(Dialog::new(
+            &t_with(
+                "dialog.review",
+                &[("number", &pr.number), ("title", &pr.title)],
+            ),
+            vec![
+                DialogField::choice(
+                    t("dialog.review_event"),
+                    &[ReviewEvent::Approve.label(), ReviewEvent::Comment.label()],
+                ),
+                DialogField::text(t("dialog.comment"), false),
+            ],
+            DialogAction::ReviewPullRequest {
+                owner,
+                repo,
+                number: pr.number,
+            },
+        ));
+    }
+
+    /// Open the dialog creating a repository from the configured template
+    ///
+    /// Only available while viewing an organization.
+    fn open_create_repository_dialog(&mut self) {
+        if self.blocked_in_guest_mode() {
+            return;
+        }
+        let RepositoryViewMode::Organization(org) = &self.repo_view_mode else {
+            self.status_message = Some(t("messages.create_needs_organization").to_string());
+            return;
+        };
+        let Some(template) = self.config.compliance.template.clone() else {
+            self.status_message = Some(t("messages.create_needs_template").to_string());
+            return;
+        };
+
+        self.dialog = Some(Dialog::new(
+            &t_with(
+                "dialog.new_repository",
+                &[("org", org), ("template", &template)],
+            ),
+            vec![
+                DialogField::text(t("dialog.name"), true),
+                DialogField::text(t("dialog.description"), false),
+                DialogField::choice(
+                    t("dialog.visibility"),
+                    &[t("dialog.private"), t("dialog.public")],
+                ),
+            ],
+            DialogAction::CreateRepository {
+                template,
+                owner: org.clone(),
+            },
+        ));
+    }
+
+    /// Handle keyboard input in the template compliance view
+    fn handle_template_compliance_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::Dashboard;
+                true
+            }
+            KeyCode::Up => {
+                self.compliance_scroll = self.compliance_scroll.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                self.compliance_scroll = self.compliance_scroll.saturating_add(1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Open the template compliance view and scan the loaded repositories
+    ///
+    /// Requires `[compliance] template` in the config file.
+    fn open_template_compliance(&mut self) {
+        let Some(template) = self.config.compliance.template.clone() else {
+            self.status_message = Some(t("messages.compliance_needs_template").to_string());
+            return;
+        };
+
+        self.current_view = AppView::TemplateCompliance;
+        self.compliance_scroll = 0;
+
+        let Some(client) = self.github_client.clone() else {
+            return;
+        };
+        let repositories = self
+            .repositories
+            .iter()
+            .map(|repo| (repo.owner.clone(), repo.name.clone()))
+            .collect();
+        self.is_scanning_compliance = true;
+        GitHubClient::spawn_compliance_scan(
+            client,
+            self.action_sender.clone(),
+            template,
+            self.config.compliance.files.clone(),
+            repositories,
+        );
+    }
+
+    /// Handle keyboard input in the policy view
+    fn handle_policies_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::Dashboard;
+                true
+            }
+            KeyCode::Up => {
+                self.policy_scroll = self.policy_scroll.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                self.policy_scroll = self.policy_scroll.saturating_add(1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Open the policy view and check the loaded repositories against the
+    /// policies attached to their topics
+    ///
+    /// Requires `[[policies]]` in the config file.
+    fn open_policies(&mut self) {
+        if self.config.policies.is_empty() {
+            self.status_message = Some(t("messages.policies_needed").to_string());
+            return;
+        }
+
+        self.current_view = AppView::Policies;
+        self.policy_scroll = 0;
+
+        // Only policies requiring branch protection or CODEOWNERS need API calls
+        let repositories: Vec<(String, String)> = self
+            .repositories
+            .iter()
+            .filter(|repo| {
+                policy::applicable_policies(repo, &self.config.policies)
+                    .iter()
+                    .any(|policy| policy.require_branch_protection || policy.require_codeowners)
+            })
+            .map(|repo| (repo.owner.clone(), repo.name.clone()))
+            .collect();
+        let client = match self.github_client.clone() {
+            Some(client) if !repositories.is_empty() => client,
+            _ => {
+                self.handle_background_message(BackgroundMessage::PoliciesScanned {
+                    facts: HashMap::new(),
+                });
+                return;
+            }
+        };
+        self.is_scanning_policies = true;
+        GitHubClient::spawn_policy_scan(client, self.action_sender.clone(), repositories);
+    }
+
+    /// Handle keyboard input in the dependency view
+    fn handle_dependencies_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::Dashboard;
+                true
+            }
+            KeyCode::Up => {
+                self.dependencies_scroll = self.dependencies_scroll.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                self.dependencies_scroll = self.dependencies_scroll.saturating_add(1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Open the dependency view and scan the loaded repositories' manifests
+    fn open_dependencies(&mut self) {
+        self.current_view = AppView::Dependencies;
+        self.dependencies_scroll = 0;
+
+        let Some(client) = self.github_client.clone() else {
+            return;
+        };
+        let repositories = self
+            .repositories
+            .iter()
+            .map(|repo| (repo.owner.clone(), repo.name.clone()))
+            .collect();
+        self.is_scanning_dependencies = true;
+        GitHubClient::spawn_dependency_scan(client, self.action_sender.clone(), repositories);
+    }
+
+    /// Handle keyboard input in the cleanup view
+    fn handle_cleanup_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::Dashboard;
+                true
+            }
+            KeyCode::Up => {
+                self.cleanup_scroll = self.cleanup_scroll.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                self.cleanup_scroll = self.cleanup_scroll.saturating_add(1);
+                true
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                self.start_bulk_archive();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Walk through the archive candidates the user administers, asking
+    /// before archiving each one
+    fn start_bulk_archive(&mut self) {
+        if self.blocked_in_guest_mode() {
+            return;
+        }
+        let Some(candidates) = &self.cleanup_candidates else {
+            return;
+        };
+        let queue: Vec<String> = candidates
+            .iter()
+            .map(|candidate| candidate.repository.clone())
+            .filter(|repository| {
+                self.row_cache
+                    .position(repository)
+                    .is_some_and(|index| self.repositories[index].admin)
+            })
+            .collect();
+        if queue.is_empty() {
+            self.status_message = Some(t("messages.no_archive_candidates").to_string());
+            return;
+        }
+
+        self.archive_total = queue.len();
+        // Confirmations pop from the back, so keep the view's order
+        self.archive_queue = queue.into_iter().rev().collect();
+        self.prompt_next_archive();
+    }
+
+    /// Ask whether to archive the next queued candidate
+    fn prompt_next_archive(&mut self) {
+        let Some(repository) = self.archive_queue.last().cloned() else {
+            self.status_message = Some(t("messages.bulk_archive_finished").to_string());
+            return;
+        };
+        let reasons = self
+            .cleanup_candidates
+            .iter()
+            .flatten()
+            .find(|candidate| candidate.repository == repository)
+            .map(|candidate| {
+                candidate
+                    .reasons
+                    .iter()
+                    .map(CleanupReason::description)
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            })
+            .unwrap_or_default();
+
+        let position = self.archive_total - self.archive_queue.len() + 1;
+        self.menu = Some(Menu::new(
+            &t_with(
+                "menu.archive_prompt",
+                &[
+                    ("repository", &repository),
+                    ("position", &position),
+                    ("total", &self.archive_total),
+                    ("reasons", &reasons),
+                ],
+            ),
+            vec![
+                MenuItem {
+                    label: t("menu.skip").to_string(),
+                    action: MenuAction::SkipArchive,
+                },
+                MenuItem {
+                    label: t_with("menu.archive", &[("repository", &repository)]),
+                    action: MenuAction::ArchiveRepository(repository),
+                },
+                MenuItem {
+                    label: t("menu.stop").to_string(),
+                    action: MenuAction::StopArchive,
+                },
+            ],
+        ));
+    }
+
+    /// Open the cleanup view and check the loaded repositories for archive candidates
+    fn open_cleanup(&mut self) {
+        self.current_view = AppView::Cleanup;
+        self.cleanup_scroll = 0;
+
+        let Some(client) = self.github_client.clone() else {
+            return;
+        };
+        self.is_scanning_cleanup = true;
+        GitHubClient::spawn_cleanup_scan(
+            client,
+            self.action_sender.clone(),
+            self.repositories.clone(),
+        );
+    }
+
+    /// Handle keyboard input in the archived reports view
+    fn handle_reports_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::Dashboard;
+                true
+            }
+            KeyCode::Up => {
+                self.selected_archived_report = self.selected_archived_report.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                if self.selected_archived_report + 1 < self.archived_reports.len() {
+                    self.selected_archived_report += 1;
+                }
+                true
+            }
+            KeyCode::Enter | KeyCode::Char('o') | KeyCode::Char('O') => {
+                self.open_archived_report();
+                true
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.load_archived_reports();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Open the browser of archived reports and digests
+    fn open_reports(&mut self) {
+        self.current_view = AppView::Reports;
+        self.selected_archived_report = 0;
+        self.load_archived_reports();
+    }
+
+    /// Read the reports directory again
+    fn load_archived_reports(&mut self) {
+        let listed = match self.config.reports.directory() {
+            Some(directory) => report::archived_reports(&directory),
+            None => Err(t("messages.no_data_directory").to_string()),
+        };
+        match listed {
+            Ok(archived) => self.archived_reports = archived,
+            Err(e) => {
+                self.archived_reports.clear();
+                self.status_message = Some(e);
+            }
+        }
+        self.selected_archived_report = self
+            .selected_archived_report
+            .min(self.archived_reports.len().saturating_sub(1));
+    }
+
+    /// Get the archived report under the selection
+    pub fn get_selected_archived_report(&self) -> Option<&ArchivedReport> {
+        self.archived_reports.get(self.selected_archived_report)
+    }
+
+    /// Open the selected archived report with the system's default application
+    fn open_archived_report(&mut self) {
+        let Some(archived) = self.get_selected_archived_report() else {
+            return;
+        };
+        let path = archived.path.display().to_string();
+        self.open_link(&path);
+    }
+
+    /// Handle keyboard input on the token diagnostics screen
+    fn handle_token_diagnostics_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Enter | KeyCode::Esc => {
+                self.current_view = AppView::Dashboard;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle keyboard input in the config problems view
+    fn handle_config_problems_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Enter | KeyCode::Esc => {
+                let token_warnings = match &self.token_status {
+                    TokenStatus::Checked(Some(check)) => !check.warnings().is_empty(),
+                    TokenStatus::Rejected(_) => true,
+                    _ => false,
+                };
+                self.current_view = if token_warnings {
+                    AppView::TokenDiagnostics
+                } else {
+                    AppView::Dashboard
+                };
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle keyboard input in the links pane
+    fn handle_links_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::Dashboard;
+                true
+            }
+            KeyCode::Up => {
+                self.selected_link = self.selected_link.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                if self.selected_link + 1 < self.opened_links.len() {
+                    self.selected_link += 1;
+                }
+                true
+            }
+            KeyCode::Enter | KeyCode::Char('o') | KeyCode::Char('O') => {
+                if let Some(url) = self.opened_links.get(self.selected_link).cloned() {
+                    self.open_link(&url);
+                    self.selected_link = 0;
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Open a link in the browser, or list it in the links pane where no
+    /// browser can be launched (`[links] mode`)
+    ///
+    /// Every link is also kept in the pane and appended to `[links] file`.
+    pub fn open_link(&mut self, url: &str) {
+        self.opened_links.retain(|link| link != url);
+        self.opened_links.insert(0, url.to_string());
+        self.opened_links.truncate(MAX_OPENED_LINKS);
+
+        let links = &self.config.links;
+        let mut message = if links.opens_in_pane(crate::browser::is_remote_session()) {
+            t_with("messages.link_added", &[("url", &url)])
+        } else {
+            match crate::browser::open(url) {
+                Ok(()) => t_with("messages.link_opened", &[("url", &url)]),
+                Err(e) => t_with("messages.link_failed", &[("error", &e)]),
+            }
+        };
+        if let Some(path) = &links.file {
+            if let Err(e) = crate::browser::append_link(path, url, chrono::Utc::now()) {
+                message = e;
+            }
+        }
+        self.status_message = Some(message);
+    }
+
+    /// Delete reports and digests past the retention policy, as on startup
+    pub fn prune_archived_reports(&mut self) {
+        let Some(directory) = self.config.reports.directory() else {
+            return;
+        };
+        match report::apply_retention(&directory, &self.config.reports, chrono::Utc::now()) {
+            Ok(0) => {}
+            Ok(deleted) => {
+                self.status_message =
+                    Some(t_with("messages.reports_pruned", &[("count", &deleted)]));
+            }
+            Err(e) => self.status_message = Some(e),
+        }
+    }
+
+    /// Handle keyboard input in the contributions view
+    fn handle_contributions_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.should_quit = true;
+                true
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                self.current_view = AppView::Dashboard;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle keyboard input while typing a quick jump prefix
+    ///
+    /// Enter or Esc finish the jump; any other non-character key finishes it
+    /// and is handled as usual, so arrow keys keep navigating from the match.
+    fn handle_quick_jump_key(&mut self, key_code: KeyCode) -> bool {
+        let Some(prefix) = self.quick_jump.as_mut() else {
+            return false;
+        };
+        match key_code {
+            KeyCode::Char(c) => prefix.push(c),
+            KeyCode::Backspace => {
+                prefix.pop();
+            }
+            KeyCode::Enter | KeyCode::Esc => {
+                self.quick_jump = None;
+                return true;
+            }
+            _ => {
+                self.quick_jump = None;
+                return self.handle_key_event(key_code);
+            }
+        }
+
+        if let Some(position) = self.quick_jump_match() {
+            self.selected_repository = position;
+            self.ensure_selected_visible(10);
+        }
+        true
+    }
+
+    /// Position of the first shown repository whose name starts with the
+    /// quick jump prefix, ignoring case
+    ///
+    /// A prefix containing `/` is matched against `owner/name`.
+    pub fn quick_jump_match(&self) -> Option<usize> {
+        let prefix = self.quick_jump.as_deref()?.to_lowercase();
+        if prefix.is_empty() {
+            return None;
+        }
+        self.visible_repositories.iter().position(|&index| {
+            let repo = &self.repositories[index];
+            let name = if prefix.contains('/') {
+                repo.full_name()
+            } else {
+                repo.name.clone()
+            };
+            name.to_lowercase().starts_with(&prefix)
+        })
+    }
+
+    /// Stop paging through an organization once the current page is in
+    ///
+    /// The repositories loaded so far are enhanced as usual.
+    fn stop_pagination(&mut self) {
+        if !self.is_loading {
+            return;
+        }
+        if let Some(stop) = &self.pagination_stop {
+            stop.store(true, Ordering::SeqCst);
+            self.status_message = Some(t("messages.stopping_pagination").to_string());
+        }
+    }
+
+    /// Open the contributions view, fetching the calendar on first use
+    ///
+    /// The calendar only changes by the day, so it is kept until a refresh.
+    fn open_contributions(&mut self) {
+        self.current_view = AppView::Contributions;
+        if self.contributions.is_some() || self.is_fetching_contributions {
+            return;
+        }
+
+        let Some(client) = self.github_client.clone() else {
+            return;
+        };
+        self.is_fetching_contributions = true;
+        GitHubClient::spawn_contributions_fetch(client, self.action_sender.clone());
+    }
+
+    /// Open the membership panel and fetch the current organization's report
+    ///
+    /// Only available while viewing an organization.
+    fn open_org_membership(&mut self) {
+        let RepositoryViewMode::Organization(org) = &self.repo_view_mode else {
+            self.status_message = Some(t("messages.membership_needs_organization").to_string());
+            return;
+        };
+        let org = org.clone();
+
+        self.current_view = AppView::OrgMembership;
+        self.membership_scroll = 0;
+
+        // Keep showing a previous report for the same org while refetching
+        if self.org_membership.as_ref().map(|h| &h.org) != Some(&org) {
+            self.org_membership = None;
+        }
+
+        let Some(client) = self.github_client.clone() else {
+            return;
+        };
+        let repositories = self.repositories.iter().map(|r| r.name.clone()).collect();
+        self.is_fetching_org_membership = true;
+        GitHubClient::spawn_org_membership_fetch(
+            client,
+            self.action_sender.clone(),
+            org,
+            repositories,
+        );
+    }
+
+    /// Open the admin view of the organization's audit log, fetching it on
+    /// first use
+    ///
+    /// Only available while viewing an organization.
+    fn open_audit_log(&mut self) {
+        let RepositoryViewMode::Organization(org) = &self.repo_view_mode else {
+            self.status_message = Some(t("messages.audit_needs_organization").to_string());
+            return;
+        };
+        let org = org.clone();
+
+        self.current_view = AppView::AuditLog;
+        self.selected_audit_event = 0;
+        if self.org_audit_log.as_ref().map(|log| &log.org) == Some(&org)
+            || self.is_fetching_audit_log
+        {
+            return;
+        }
+        self.org_audit_log = None;
+
+        let Some(client) = self.github_client.clone() else {
+            return;
+        };
+        self.is_fetching_audit_log = true;
+        GitHubClient::spawn_org_audit_log_fetch(client, self.action_sender.clone(), org);
+    }
+
+    /// Organizations and configured groups that can be compared
+    pub fn comparison_sources(&self) -> Vec<ComparisonSource> {
+        let mut groups: Vec<&String> = self.config.groups.keys().collect();
+        groups.sort();
+
+        self.user_organizations
+            .iter()
+            .cloned()
+            .map(ComparisonSource::Organization)
+            .chain(groups.into_iter().cloned().map(ComparisonSource::Group))
+            .collect()
+    }
+
+    /// Open the comparison view, initially showing the first two sources
+    fn open_comparison(&mut self) {
+        let sources = self.comparison_sources();
+        if sources.len() < 2 {
+            self.status_message = Some(t("messages.comparison_needs_sources").to_string());
+            return;
+        }
+
+        // Keep the previous comparison when coming back to the view
+        if self.comparison_panes.is_empty() {
+            for source in sources.into_iter().take(2) {
+                let pane = self.comparison_pane(source);
+                self.comparison_panes.push(pane);
+            }
+        }
+        self.comparison_focus = 0;
+        self.current_view = AppView::Comparison;
+    }
+
+    /// Build a comparison pane, fetching organization data when needed
+    fn comparison_pane(&mut self, source: ComparisonSource) -> ComparisonPane {
+        let mut pane = ComparisonPane {
+            source: source.clone(),
+            repositories: Vec::new(),
+            selected: 0,
+            is_loading: false,
+        };
+
+        match source {
+            ComparisonSource::Group(group) => {
+                pane.repositories = self
+                    .repositories
+                    .iter()
+                    .filter(|repo| {
+                        self.config
+                            .groups_for(&repo.owner, &repo.name)
+                            .contains(&group.as_str())
+                    })
+                    .cloned()
+                    .collect();
+            }
+            ComparisonSource::Organization(org) => {
+                let showing_org = self.repo_view_mode
+                    == RepositoryViewMode::Organization(org.clone())
+                    && !self.is_loading
+                    && !self.is_enhancing;
+                if let Some(repositories) = self.comparison_cache.get(&org) {
+                    pane.repositories = repositories.clone();
+                } else if showing_org {
+                    pane.repositories = self.repositories.clone();
+                } else if let Some(client) = self.github_client.clone() {
+                    // The other pane may already be waiting for the same org
+                    let already_fetching = self
+                        .comparison_panes
+                        .iter()
+                        .any(|other| other.is_loading && other.source == pane.source);
+                    if !already_fetching {
+                        GitHubClient::spawn_comparison_fetch(
+                            client,
+                            self.action_sender.clone(),
+                            org,
+                        );
+                    }
+                    pane.is_loading = true;
+                }
+            }
+        }
+        pane
+    }
+
+    /// Point the focused pane at the next or previous organization or group
+    fn cycle_comparison_source(&mut self, forward: bool) {
+        let sources = self.comparison_sources();
+        let Some(pane) = self.comparison_panes.get(self.comparison_focus) else {
+            return;
+        };
+        if sources.is_empty() {
+            return;
+        }
+
+        let current = sources
+            .iter()
+            .position(|source| *source == pane.source)
+            .unwrap_or(0);
+        let next = if forward {
+            (current + 1) % sources.len()
+        } else {
+            (current + sources.len() - 1) % sources.len()
+        };
+        let pane = self.comparison_pane(sources[next].clone());
+        self.comparison_panes[self.comparison_focus] = pane;
+    }
+
+    /// Get the currently selected repository, if any
+    pub fn get_selected_repository(&self) -> Option<&Repository> {
+        self.visible_repositories
+            .get(self.selected_repository)
+            .and_then(|&index| self.repositories.get(index))
+    }
+
+    /// Get the currently selected pull request in the details view, if any
+    pub fn get_selected_pull_request(&self) -> Option<&crate::models::PullRequest> {
+        self.get_selected_repository()
+            .and_then(|repo| repo.open_pull_requests.get(self.selected_pull_request))
+    }
+
+    /// Open the details view for the selected repository and fetch its critical issues
+    fn open_repo_details(&mut self) {
+        self.selected_pull_request = 0;
+        self.current_view = AppView::RepoDetails;
+
+        let (Some(client), Some(repo)) =
+            (self.github_client.clone(), self.get_selected_repository())
+        else {
+            return;
+        };
+        let (owner, name) = (repo.owner.clone(), repo.name.clone());
+
+        GitHubClient::spawn_critical_issues_fetch(
+            client,
+            self.action_sender.clone(),
+            owner,
+            name,
+            self.config.critical_label.clone(),
+        );
+    }
+
+    /// Open the details view for the selected pull request and fetch its checks
+    fn open_pull_request_details(&mut self) {
+        self.check_runs.clear();
+        self.selected_check_run = 0;
+        self.merge_requirements = None;
+        self.current_view = AppView::PullRequestDetails;
+
+        let (Some(client), Some(repo), Some(pr)) = (
+            self.github_client.clone(),
+            self.get_selected_repository(),
+            self.get_selected_pull_request(),
+        ) else {
+            return;
+        };
+        let (owner, name, number, head_sha) = (
+            repo.owner.clone(),
+            repo.name.clone(),
+            pr.number,
+            pr.head_sha.clone(),
+        );
+
+        self.is_fetching_check_runs = true;
+        self.is_fetching_merge_requirements = true;
+        GitHubClient::spawn_merge_requirements_fetch(
+            client.clone(),
+            self.action_sender.clone(),
+            owner.clone(),
+            name.clone(),
+            number,
+        );
+        GitHubClient::spawn_check_runs_fetch(
+            client,
+            self.action_sender.clone(),
+            owner,
+            name,
+            number,
+            head_sha,
+        );
+    }
+
+    /// Open the files view for the selected pull request and fetch its diffstat
+    fn open_pull_request_files(&mut self) {
+        self.pull_request_files.clear();
+        self.pull_request_files_scroll = 0;
+        self.current_view = AppView::PullRequestFiles;
+
+        let (Some(client), Some(repo), Some(pr)) = (
+            self.github_client.clone(),
+            self.get_selected_repository(),
+            self.get_selected_pull_request(),
+        ) else {
+            return;
+        };
+        let (owner, name, number) = (repo.owner.clone(), repo.name.clone(), pr.number);
+
+        self.is_fetching_pull_request_files = true;
+        GitHubClient::spawn_pull_request_files_fetch(
+            client,
+            self.action_sender.clone(),
+            owner,
+            name,
+            number,
+        );
+    }
+
+    /// Files in the files view, most churned first when sorting by churn
+    pub fn sorted_pull_request_files(&self) -> Vec<&ChangedFile> {
+        let mut files: Vec<&ChangedFile> = self.pull_request_files.iter().collect();
+        if self.sort_files_by_churn {
+            files.sort_by_key(|file| std::cmp::Reverse(file.churn()));
+        }
+        files
+    }
+
+    /// Re-request the selected check run if it failed
+    ///
+    /// The check is marked as in progress immediately; it is restored if
+    /// GitHub rejects the request.
+    fn rerun_selected_check(&mut self) {
+        if self.blocked_in_guest_mode() {
+            return;
+        }
+        let Some(client) = self.github_client.clone() else {
+            return;
+        };
+        let Some((owner, name)) = self
+            .get_selected_repository()
+            .map(|repo| (repo.owner.clone(), repo.name.clone()))
+        else {
+            return;
+        };
+        let Some(check) = self.check_runs.get_mut(self.selected_check_run) else {
+            return;
+        };
+        if !check.can_rerun() {
+            self.status_message = Some(t_with(
+                "messages.check_not_failed",
+                &[("name", &check.name)],
+            ));
+            return;
+        }
+
+        let previous = std::mem::replace(&mut check.status, WorkflowStatus::InProgress);
+        self.pending_check_reruns.insert(check.id, previous);
+        self.status_message = Some(t_with("messages.check_rerunning", &[("name", &check.name)]));
+
+        GitHubClient::spawn_check_run_rerequest(
+            client,
+            self.action_sender.clone(),
+            owner,
+            name,
+            check.id,
+        );
+    }
+
+    /// Refresh application data
+    ///
+    /// Updates the last refresh timestamp and initializes GitHub client if needed.
+    /// Marks the app as loading to trigger data fetching.
+    pub fn refresh(&mut self) {
+        // A paused pass resumes on its own; restarting would redo finished work
+        if let Some(pause) = &self.rate_limit_pause {
+            self.status_message = Some(t_with(
+                "messages.rate_limited",
+                &[
+                    ("current", &pause.current),
+                    ("total", &pause.total),
+                    ("countdown", &pause.countdown(SystemTime::now())),
+                ],
+            ));
+            return;
+        }
+
+        self.last_refresh = Some(std::time::Instant::now());
+        self.stall_retry_at = None;
+        if self.demo {
+            self.set_repositories(crate::demo::repositories(SystemTime::now()));
+            self.completed_refreshes += 1;
+            return;
+        }
+        self.platform_status_checked_at = None;
+        self.comparison_cache.clear();
+        self.contributions = None;
+        self.failure_clusters = None;
+
+        // If no GitHub client is initialized, try to initialize it
+        if self.github_client.is_none() {
+            self.initialize_github_client();
+        }
+
+        // Check the token once before fetching anything, so a rejected token
+        // or a missing scope is explained up front instead of failed fetches
+        if let Some(client) = &self.github_client {
+            match self.token_status {
+                TokenStatus::Checking => return,
+                TokenStatus::Unchecked | TokenStatus::Rejected(_) => {
+                    self.token_status = TokenStatus::Checking;
+                    self.status_message = Some(t("messages.checking_token").to_string());
+                    GitHubClient::spawn_token_check(client.clone(), self.action_sender.clone());
+                    return;
+                }
+                TokenStatus::Checked(_) => {}
+            }
+        }
+
+        // Clear cache for current mode to force refresh, keeping what it
+        // held to check the refreshed list against
+        let previous = match &self.repo_view_mode {
+            RepositoryViewMode::Personal => self.personal_repositories.take(),
+            RepositoryViewMode::Organization(org_name) => {
+                self.organization_repositories.remove(org_name)
+            }
+            RepositoryViewMode::All => self.all_repositories.take(),
+        };
+        self.held_dataset = None;
+        self.canary_baseline = previous.filter(|repositories| {
+            self.config.canary.threshold_percent > 0
+                && repositories.len() >= self.config.canary.min_repositories
+        });
+
+        // Fetch repositories for current mode
+        self.fetch_repositories_for_current_mode();
+    }
+
+    /// Cache a repository list for the current view mode
+    fn cache_repositories(&mut self, repositories: Vec<Repository>) {
+        match &self.repo_view_mode {
+            RepositoryViewMode::Personal => self.personal_repositories = Some(repositories),
+            RepositoryViewMode::Organization(org_name) => {
+                self.organization_repositories
+                    .insert(org_name.clone(), repositories);
+            }
+            RepositoryViewMode::All => self.all_repositories = Some(repositories),
+        }
+    }
+
+    /// Hold back a refreshed repository list that changed too much, keep
+    /// showing the previous one and ask which to use
+    fn hold_dataset(
+        &mut self,
+        previous: Vec<Repository>,
+        repositories: Vec<Repository>,
+        change: DatasetChange,
+    ) {
+        self.cache_repositories(previous);
+        self.status_message = Some(t_with(
+            "messages.refresh_held_back",
+            &[("change", &change.summary())],
+        ));
+        self.held_dataset = Some(HeldDataset {
+            repositories,
+            change,
+        });
+        self.open_held_dataset_menu();
+    }
+
+    /// Ask whether to accept the held repository list
+    fn open_held_dataset_menu(&mut self) {
+        let Some(held) = &self.held_dataset else {
+            return;
+        };
+        let preview = |names: &[String]| {
+            let mut preview = names.iter().take(3).cloned().collect::<Vec<_>>().join(", ");
+            if names.len() > 3 {
+                preview.push_str(&t_with("menu.and_more", &[("count", &(names.len() - 3))]));
+            }
+            preview
+        };
+        let mut title = t_with(
+            "menu.refresh_changed",
+            &[("change", &held.change.summary())],
+        );
+        if !held.change.removed.is_empty() {
+            title.push_str(&t_with(
+                "menu.refresh_gone",
+                &[("repositories", &preview(&held.change.removed))],
+            ));
+        }
+        self.menu = Some(Menu::new(
+            &title,
+            vec![
+                MenuItem {
+                    label: t("menu.keep_previous_data").to_string(),
+                    action: MenuAction::KeepPreviousDataset,
+                },
+                MenuItem {
+                    label: t_with(
+                        "menu.accept_new_data",
+                        &[("count", &held.repositories.len())],
+                    ),
+                    action: MenuAction::AcceptDataset,
+                },
+            ],
+        ));
+    }
+
+    /// Show the held repository list
+    ///
+    /// A refresh still enhancing it carries on as usual from here.
+    fn accept_held_dataset(&mut self) {
+        let Some(held) = self.held_dataset.take() else {
+            return;
+        };
+        self.status_message = Some(t_with(
+            "messages.accepted_new_data",
+            &[("count", &held.repositories.len())],
+        ));
+        self.cache_repositories(held.repositories.clone());
+        self.set_repositories(held.repositories);
+        self.ensure_selected_visible(10);
+    }
+
+    /// Drop the held repository list, including any details still coming in
+    fn keep_previous_dataset(&mut self) {
+        if self.held_dataset.take().is_none() {
+            return;
+        }
+        if self.is_enhancing {
+            // Replacing the channel discards the rest of the refresh
+            self.setup_background_processing();
+            self.is_enhancing = false;
+            self.enhancement_progress = None;
+            self.rate_limit_pause = None;
+        }
+        self.status_message = Some(t("messages.kept_previous_data").to_string());
+    }
+
+    /// Refresh after checking the refresh fits in the remaining rate limit
+    ///
+    /// The first load and refreshes without loaded repositories have nothing
+    /// to estimate from, so they start right away.
+    pub fn request_refresh(&mut self) {
+        match &self.github_client {
+            Some(client) if !self.repositories.is_empty() && self.rate_limit_pause.is_none() => {
+                self.status_message = Some(t("messages.checking_budget").to_string());
+                GitHubClient::spawn_budget_check(client.clone(), self.action_sender.clone());
+            }
+            _ => self.refresh(),
+        }
+    }
+
+    /// Refresh if the estimated cost fits the rate limit, otherwise offer a
+    /// lite refresh
+    fn plan_refresh(&mut self, status: Option<RateLimitStatus>) {
+        self.status_message = None;
+        let collectors = self.config.enabled_collectors();
+        let backend = self.config.api.backend;
+        let full =
+            EnhancementProfile::Full.estimate_requests(backend, &self.repositories, &collectors);
+        let Some(status) = status.filter(|status| status.remaining < full) else {
+            self.refresh();
+            return;
+        };
+
+        let lite =
+            EnhancementProfile::Lite.estimate_requests(backend, &self.repositories, &collectors);
+        let reset = chrono::DateTime::<chrono::Local>::from(status.reset).format("%H:%M");
+        self.menu = Some(Menu::new(
+            &t_with(
+                "menu.refresh_budget",
+                &[
+                    ("requests", &full),
+                    ("remaining", &status.remaining),
+                    ("reset", &reset),
+                ],
+            ),
+            vec![
+                MenuItem {
+                    label: t_with("menu.lite_refresh", &[("requests", &lite)]),
+                    action: MenuAction::Refresh(EnhancementProfile::Lite),
+                },
+                MenuItem {
+                    label: t("menu.full_refresh").to_string(),
+                    action: MenuAction::Refresh(EnhancementProfile::Full),
+                },
+            ],
+        ));
+    }
+
+    /// Core API requests left and when they reset, once known
+    pub fn rate_limit(&self) -> Option<RateLimitStatus> {
+        self.github_client
+            .as_ref()
+            .and_then(GitHubClient::rate_limit)
+    }
+
+    /// Whether background work is paused because the terminal is unfocused
+    pub fn is_background_paused(&self) -> bool {
+        !self.terminal_focused && self.config.focus.pause_when_unfocused
+    }
+
+    /// React to the terminal gaining or losing focus
+    ///
+    /// Losing focus holds enhancement before its next repository. Regaining
+    /// it lets a held pass continue, or refreshes right away if nothing was
+    /// in progress and the data is older than `refresh_after_seconds`.
+    pub fn set_focused(&mut self, focused: bool) {
+        if self.terminal_focused == focused {
+            return;
+        }
+        self.terminal_focused = focused;
+        if !self.config.focus.pause_when_unfocused {
+            return;
+        }
+        if let Some(client) = &self.github_client {
+            client.hold(!focused);
+        }
+        // A held fetch makes no progress; the watchdog starts over
+        if focused && self.last_background_progress.is_some() {
+            self.last_background_progress = Some(std::time::Instant::now());
+        }
+
+        let stale = self.last_refresh.is_none_or(|refreshed| {
+            refreshed.elapsed()
+                >= std::time::Duration::from_secs(self.config.focus.refresh_after_seconds)
+        });
+        let busy = self.is_loading() || self.is_enhancing;
+        if focused && stale && !busy && !self.is_modal_open() {
+            self.auto_refresh();
+        }
+    }
+
+    /// Refresh without being asked to, e.g. on regaining focus or on the
+    /// daemon's interval
+    ///
+    /// With `low_bandwidth` on, these refreshes skip the heavy collectors
+    /// (review feedback, LFS, deploy status and releases); `r` still fetches
+    /// everything.
+    pub fn auto_refresh(&mut self) {
+        if self.config.network.low_bandwidth {
+            self.refresh_profile = EnhancementProfile::Lite;
+        }
+        self.refresh();
+    }
+
+    /// Run periodic background work from the main loop
+    ///
+    /// Polls the GitHub status page every `PLATFORM_STATUS_INTERVAL` and
+    /// watches the repository fetch for stalls, except while background work
+    /// is paused.
+    pub fn tick(&mut self) {
+        self.row_cache
+            .refresh_times(&self.repositories, SystemTime::now());
+        if self.is_background_paused() {
+            return;
+        }
+        let due = self
+            .platform_status_checked_at
+            .is_none_or(|checked| checked.elapsed() >= PLATFORM_STATUS_INTERVAL);
+        if due {
+            self.platform_status_checked_at = Some(std::time::Instant::now());
+            PlatformStatus::spawn_fetch(self.action_sender.clone());
+        }
+        self.check_stalled_refresh();
+    }
+
+    /// Cancel a refresh that stopped making progress, or retry one cancelled
+    /// earlier once its backoff has passed
+    ///
+    /// Waiting out a rate limit isn't a stall.
+    fn check_stalled_refresh(&mut self) {
+        if let Some(due) = self.stall_retry_at {
+            if std::time::Instant::now() >= due {
+                self.stall_retry_at = None;
+                self.refresh();
+            }
+            return;
+        }
+        let Some(limit) = self.config.api.stall_timeout() else {
+            return;
+        };
+        let busy = self.is_loading || self.is_enhancing;
+        let stalled = self
+            .last_background_progress
+            .is_some_and(|progress| progress.elapsed() >= limit);
+        if busy && stalled && self.rate_limit_pause.is_none() {
+            self.cancel_stalled_refresh(limit);
+        }
+    }
+
+    /// Stop a stalled refresh, mark the repositories it didn't get to as
+    /// timed out and schedule a retry with backoff
+    fn cancel_stalled_refresh(&mut self, limit: std::time::Duration) {
+        if let Some(task) = self.background_task.take() {
+            task.abort();
+        }
+        self.background_receiver = None;
+        self.last_background_progress = None;
+        if let Some(previous) = self.canary_baseline.take() {
+            self.cache_repositories(previous);
+        }
+        let pending = std::mem::take(&mut self.pending_enhancement);
+        for repo in &mut self.repositories {
+            if pending.contains(&repo.full_name()) {
+                repo.fetch_timed_out = true;
+            }
+        }
+        self.is_loading = false;
+        self.is_enhancing = false;
+        self.loading_progress = None;
+        self.enhancement_progress = None;
+        self.pagination_stop = None;
+
+        let stalled = t_with("messages.stalled", &[("seconds", &limit.as_secs())]);
+        self.session_errors.push(stalled.clone());
+        if self.stall_retries < self.config.api.stall_retries {
+            let delay = self.config.api.stall_retry_delay(self.stall_retries);
+            self.stall_retries += 1;
+            self.stall_retry_at = Some(std::time::Instant::now() + delay);
+            self.status_message = Some(t_with(
+                "messages.stall_retrying",
+                &[
+                    ("stalled", &stalled),
+                    ("seconds", &delay.as_secs()),
+                    ("attempt", &self.stall_retries),
+                    ("attempts", &self.config.api.stall_retries),
+                ],
+            ));
+        } else {
+            self.stall_retries = 0;
+            self.status_message = Some(t_with("messages.stall_gave_up", &[("stalled", &stalled)]));
+        }
+    }
+
+    /// Banner shown while GitHub itself reports an incident
+    pub fn platform_banner(&self) -> Option<String> {
+        self.platform_status
+            .as_ref()
+            .and_then(PlatformStatus::banner)
+    }
+
+    /// Get the display title for the current view
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+
+    /// Check if the application should quit
+    pub fn should_quit(&self) -> bool {
+        self.should_quit
+    }
+
+    /// Get the current repositories
+    pub fn get_repositories(&self) -> &[Repository] {
+        &self.repositories
+    }
+
+    /// Check if the app is currently loading data
+    pub fn is_loading(&self) -> bool {
+        self.is_loading
+    }
+
+    /// Get the current error message, if any
+    pub fn get_error_message(&self) -> Option<&str> {
+        self.error_message.as_deref()
+    }
+
+    /// Replace the repository list, rebuilding the table row cache
+    pub fn set_repositories(&mut self, mut repositories: Vec<Repository>) {
+        for repo in &mut repositories {
+            repo.weigh_workflow_health(&self.config.ci);
+        }
+        self.row_cache.rebuild(&repositories);
+        self.repositories = repositories;
+        self.refresh_visible_repositories();
+    }
+
+    /// Append a single repository, updating the row cache and visible list
+    fn push_repository(&mut self, repository: Repository) {
+        self.row_cache.push(&repository);
+        let visible = self.is_visible(&repository);
+        self.repositories.push(repository);
+        if self.is_sorted() {
+            // Sorted tables can't simply append
+            self.refresh_visible_repositories();
+        } else if visible {
+            self.visible_repositories.push(self.repositories.len() - 1);
+        }
+    }
+
+    /// Switch to the next table sort order
+    pub fn cycle_sort(&mut self) {
+        self.sort = self.sort.next();
+        self.sort_descending = self.sort.descending_by_default();
+        self.refresh_visible_repositories();
+        self.scroll_offset = 0;
+        self.ensure_selected_visible(10);
+    }
+
+    /// Whether the table differs from GitHub's order
+    fn is_sorted(&self) -> bool {
+        self.sort != RepositorySort::Default
+            || !self.sort_descending
+            || self.secondary_sort.is_some()
+    }
+
+    /// Sort by a key, or flip the direction when it already is the sort key
+    fn sort_by(&mut self, key: RepositorySort) {
+        if self.sort == key {
+            self.sort_descending = !self.sort_descending;
+        } else {
+            self.sort = key;
+            self.sort_descending = key.descending_by_default();
+        }
+        if self
+            .secondary_sort
+            .is_some_and(|(secondary, _)| secondary == key)
+        {
+            self.secondary_sort = None;
+        }
+        self.refresh_visible_repositories();
+        self.scroll_offset = 0;
+        self.ensure_selected_visible(10);
+    }
+
+    /// Order ties by a second key (flipping it when already chosen), or stop
+    fn then_sort_by(&mut self, key: Option<RepositorySort>) {
+        self.secondary_sort = match (key, self.secondary_sort) {
+            (Some(key), Some((secondary, descending))) if secondary == key => {
+                Some((key, !descending))
+            }
+            (Some(key), _) if key != self.sort => Some((key, key.descending_by_default())),
+            _ => None,
+        };
+        self.refresh_visible_repositories();
+        self.ensure_selected_visible(10);
+    }
+
+    /// The sort order for the footer, e.g. "Status ▼, Activity ▼"
+    pub fn sort_label(&self) -> String {
+        let mut label = format!("{} {}", self.sort.label(), sort_arrow(self.sort_descending));
+        if let Some((secondary, descending)) = self.secondary_sort {
+            label.push_str(&format!(
+                ", {} {}",
+                secondary.label(),
+                sort_arrow(descending)
+            ));
+        }
+        label
+    }
+
+    /// Open the sort menu with the item at `selected` highlighted
+    ///
+    /// Choosing the current key flips its direction; the menu stays open so
+    /// both keys can be set in one go.
+    fn open_sort_menu(&mut self, selected: usize) {
+        let marker = |key: RepositorySort, current: Option<(RepositorySort, bool)>| match current {
+            Some((current, descending)) if current == key => format!(" {}", sort_arrow(descending)),
+            _ => String::new(),
+        };
+        let primary = Some((self.sort, self.sort_descending));
+        let mut items: Vec<MenuItem> = RepositorySort::ALL
+            .into_iter()
+            .map(|key| MenuItem {
+                label: t_with(
+                    "menu.sort_by",
+                    &[("key", &key.label()), ("marker", &marker(key, primary))],
+                ),
+                action: MenuAction::SortBy(key),
+            })
+            .collect();
+        items.extend(
+            RepositorySort::ALL
+                .into_iter()
+                .filter(|&key| key != self.sort)
+                .map(|key| MenuItem {
+                    label: t_with(
+                        "menu.then_by",
+                        &[
+                            ("key", &key.label()),
+                            ("marker", &marker(key, self.secondary_sort)),
+                        ],
+                    ),
+                    action: MenuAction::ThenSortBy(Some(key)),
+                }),
+        );
+        items.push(MenuItem {
+            label: t("menu.no_second_key").to_string(),
+            action: MenuAction::ThenSortBy(None),
+        });
+
+        let mut menu = Menu::new(
+            &t_with("menu.sort", &[("order", &self.sort_label())]),
+            items,
+        );
+        menu.selected = selected.min(menu.items.len() - 1);
+        self.menu = Some(menu);
+    }
+
+    /// Toggle focus mode, hiding repositories that don't need attention
+    pub fn toggle_focus_mode(&mut self) {
+        self.focus_mode = !self.focus_mode;
+        self.refresh_visible_repositories();
+        self.scroll_offset = 0;
+        self.ensure_selected_visible(10);
+    }
+
+    /// Switch every view between relative and absolute timestamps
+    pub fn toggle_time_style(&mut self) {
+        self.config.time.style = self.config.time.style.toggled();
+        self.row_cache.set_time(self.config.time.clone());
+        self.row_cache.rebuild(&self.repositories);
+        self.status_message = Some(match self.config.time.style {
+            TimeStyle::Relative => t("messages.relative_time").to_string(),
+            TimeStyle::Absolute => match &self.config.time.timezone {
+                Some(timezone) => t_with("messages.time_in_zone", &[("timezone", timezone)]),
+                None => t("messages.local_time").to_string(),
+            },
+        });
+    }
+
+    /// Include or leave out collaborator and organization member repositories
+    /// in the personal view, listing it again
+    pub fn toggle_affiliated(&mut self) {
+        self.config.startup.affiliated = !self.config.startup.affiliated;
+        // The longer or shorter list is expected, not a canary failure
+        self.personal_repositories = None;
+        if self.repo_view_mode == RepositoryViewMode::Personal {
+            self.refresh();
+        }
+        self.status_message = Some(if self.config.startup.affiliated {
+            t("messages.affiliated_on").to_string()
+        } else {
+            t("messages.affiliated_off").to_string()
+        });
+    }
+
+    /// Recompute which repositories the dashboard table shows
+    ///
+    /// Keeps the same repository selected when it is still visible.
+    fn refresh_visible_repositories(&mut self) {
+        let selected = self
+            .visible_repositories
+            .get(self.selected_repository)
+            .copied();
+        self.visible_repositories = self
+            .repositories
+            .iter()
+            .enumerate()
+            .filter(|(_, repo)| self.is_visible(repo))
+            .map(|(index, _)| index)
+            .collect();
+        if self.is_sorted() {
+            // A stable sort keeps GitHub's order among repositories that tie
+            let repositories = &self.repositories;
+            let keys: Vec<(RepositorySort, bool)> =
+                std::iter::once((self.sort, self.sort_descending))
+                    .chain(self.secondary_sort)
+                    .collect();
+            self.visible_repositories.sort_by(|&a, &b| {
+                keys.iter()
+                    .map(|&(key, descending)| {
+                        let ordering = key.compare((a, &repositories[a]), (b, &repositories[b]));
+                        if descending {
+                            ordering.reverse()
+                        } else {
+                            ordering
+                        }
+                    })
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        }
+
+        let count = self.visible_repositories.len();
+        self.selected_repository = selected
+            .and_then(|index| self.visible_repositories.iter().position(|&i| i == index))
+            .unwrap_or_else(|| self.selected_repository.min(count.saturating_sub(1)));
+    }
+
+    /// Whether focus mode and the active smart view let a repository through
+    fn is_visible(&self, repo: &Repository) -> bool {
+        self.config
+            .watched(repo)
+            .is_none_or(|watched| watched.enabled)
+            && (!self.focus_mode || self.config.attention.matches(repo))
+            && self
+                .active_smart_view()
+                .is_none_or(|view| view.matches(repo, &self.config))
+            && self
+                .team_filter
+                .as_ref()
+                .is_none_or(|team| self.primary_team(repo).as_ref() == Some(team))
+            && self
+                .topic_filter
+                .as_ref()
+                .is_none_or(|topic| repo.topics.contains(topic))
+    }
+
+    /// Number of repositories shown in the dashboard table
+    pub fn visible_repository_count(&self) -> usize {
+        self.visible_repositories.len()
+    }
+
+    /// Number of repositories hidden by focus mode
+    pub fn hidden_repository_count(&self) -> usize {
+        self.repositories.len() - self.visible_repositories.len()
+    }
+
+    /// Get the number of repositories currently loaded
+    pub fn repository_count(&self) -> usize {
+        self.repositories.len()
+    }
+
+    /// Get visible items count based on the area height
+    pub fn get_visible_item_count(&self, area_height: usize) -> usize {
+        // Account for header row
+        area_height.saturating_sub(1)
+    }
+
+    /// Ensure the selected repository is visible
+    pub fn ensure_selected_visible(&mut self, visible_items: usize) {
+        // If selected is above viewport, scroll up
+        if self.selected_repository < self.scroll_offset {
+            self.scroll_offset = self.selected_repository;
+        }
+
+        // If selected is below viewport, scroll down
+        if self.selected_repository >= self.scroll_offset + visible_items {
+            self.scroll_offset = self.selected_repository.saturating_sub(visible_items) + 1;
+        }
+    }
+
+    /// Set up background task processing
+    pub fn setup_background_processing(&mut self) -> mpsc::UnboundedSender<BackgroundMessage> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        self.background_receiver = Some(receiver);
+        sender
+    }
+
+    /// Run the configured custom health checks for the loaded repositories
+    /// that have any
+    fn run_custom_checks(&self) {
+        let runs: Vec<_> = self
+            .repositories
+            .iter()
+            .map(|repo| {
+                let checks = self.config.checks_for(&repo.owner, &repo.name);
+                (
+                    repo.owner.clone(),
+                    repo.name.clone(),
+                    checks.into_iter().cloned().collect::<Vec<_>>(),
+                )
+            })
+            .filter(|(_, _, checks)| !checks.is_empty())
+            .collect();
+        if !runs.is_empty() {
+            crate::checks::spawn(runs, self.action_sender.clone());
+        }
+    }
+
+    /// Process any pending background messages
+    pub fn process_background_messages(&mut self) {
+        let mut messages = Vec::new();
+        if let Some(receiver) = &mut self.background_receiver {
+            while let Ok(message) = receiver.try_recv() {
+                messages.push(message);
+            }
+            if !messages.is_empty() {
+                self.last_background_progress = Some(std::time::Instant::now());
+            }
+        }
+        while let Ok(message) = self.action_receiver.try_recv() {
+            messages.push(message);
+        }
+
+        for message in messages {
+            self.handle_background_message(message);
+        }
+    }
+
+    /// Apply a single background message to the application state
+    fn handle_background_message(&mut self, message: BackgroundMessage) {
+        match message {
+            BackgroundMessage::FetchStarted { total } => {
+                self.is_loading = true;
+                self.loading_progress = Some((0, total));
+                self.error_message = None;
+            }
+            BackgroundMessage::RepositoryFetched {
+                repository,
+                current,
+                total,
+            } => {
+                // While a refresh is checked, the previous list stays on screen
+                if self.canary_baseline.is_none() {
+                    self.push_repository(repository);
+                    self.startup.mark(StartupMilestone::FirstRepository);
+                }
+                self.loading_progress = Some((current, total));
+            }
+            BackgroundMessage::FetchCompleted { repositories } => {
+                self.pending_enhancement = repositories.iter().map(Repository::full_name).collect();
+                let stopped = self
+                    .pagination_stop
+                    .as_ref()
+                    .is_some_and(|stop| stop.load(Ordering::SeqCst));
+                // A list cut short on purpose is expected to shrink
+                if let Some(previous) = self.canary_baseline.take().filter(|_| !stopped) {
+                    let change = DatasetChange::between(&previous, &repositories);
+                    if change.exceeds(&self.config.canary) {
+                        self.hold_dataset(previous, repositories, change);
+                        self.is_loading = false;
+                        self.loading_progress = None;
+                        self.pagination_stop = None;
+                        return;
+                    }
+                }
+                // Cache the repositories based on current mode
+                self.cache_repositories(repositories.clone());
+                if let (true, Some((_, total))) = (stopped, self.loading_progress) {
+                    self.status_message = Some(t_with(
+                        "messages.stopped_loading",
+                        &[("loaded", &repositories.len()), ("total", &total)],
+                    ));
+                }
+                self.set_repositories(repositories);
+                self.startup.mark(StartupMilestone::RepositoryList);
+                // We've loaded basic data, but will start enhancing
+                self.is_loading = false;
+                self.loading_progress = None;
+                self.pagination_stop = None;
+                self.last_refresh = Some(std::time::Instant::now());
+            }
+            BackgroundMessage::FetchError { error } => {
+                if let Some(previous) = self.canary_baseline.take() {
+                    self.cache_repositories(previous);
+                }
+                self.session_errors.push(error.clone());
+                self.error_message = Some(error);
+                self.is_loading = false;
+                self.pagination_stop = None;
+                self.is_enhancing = false;
+                self.loading_progress = None;
+                self.enhancement_progress = None;
+                self.rate_limit_pause = None;
+            }
+            BackgroundMessage::EnhancementStarted { total } => {
+                // We already have basic data and are now enhancing
+                self.is_enhancing = true;
+                self.enhancement_progress = Some((0, total));
+            }
+            BackgroundMessage::RepositoryEnhanced {
+                mut repository,
+                current,
+                total,
+            } => {
+                self.enhancement_progress = Some((current, total));
+                self.pending_enhancement.remove(&repository.full_name());
+                if let Some(held) = &mut self.held_dataset {
+                    let name = repository.full_name();
+                    if let Some(existing) =
+                        held.repositories.iter_mut().find(|r| r.full_name() == name)
+                    {
+                        *existing = repository;
+                    }
+                    return;
+                }
+                repository.weigh_workflow_health(&self.config.ci);
+                // Find and replace the repository with the enhanced version
+                if let Some(index) = self.row_cache.position(&repository.full_name()) {
+                    self.row_cache
+                        .update(index, &self.repositories[index], &repository);
+                    self.repositories[index] = repository;
+                    self.event_bus.publish(AppEvent::RepositoryUpdated {
+                        repository: &self.repositories[index],
+                    });
+                    if self.focus_mode || self.smart_view.is_some() || self.is_sorted() {
+                        self.refresh_visible_repositories();
+                    }
+                }
+                self.enhancement_progress = Some((current, total));
+            }
+            BackgroundMessage::EnhancementCompleted { repositories } => {
+                match &mut self.held_dataset {
+                    Some(held) => held.repositories = repositories,
+                    None => self.set_repositories(repositories),
+                }
+                self.is_enhancing = false;
+                self.enhancement_progress = None;
+                self.rate_limit_pause = None;
+                self.stall_retries = 0;
+                self.last_refresh = Some(std::time::Instant::now());
+                self.completed_refreshes += 1;
+                self.startup.mark(StartupMilestone::DetailsLoaded);
+                if let (Some(baseline), Some(client)) =
+                    (self.refresh_request_baseline.take(), &self.github_client)
+                {
+                    let (requests, _) = client.metrics().totals();
+                    self.last_refresh_requests = Some(requests.saturating_sub(baseline));
+                }
+                if self.held_dataset.is_none() {
+                    self.event_bus.publish(AppEvent::RefreshCompleted {
+                        repositories: &self.repositories,
+                    });
+                    self.run_custom_checks();
+                }
+                if let (RepositoryViewMode::Organization(org), Some(requests)) =
+                    (self.repo_view_mode.clone(), self.last_refresh_requests)
+                {
+                    let attention = self.attention_count(&self.repositories);
+                    self.record_org_load(&org, attention, requests as u64);
+                }
+                self.prefetch_next_organization();
+            }
+            BackgroundMessage::EnhancementPaused {
+                current,
+                total,
+                resume_at,
+            } => {
+                self.rate_limit_pause = Some(RateLimitPause {
+                    current,
+                    total,
+                    resume_at,
+                });
+            }
+            BackgroundMessage::EnhancementResumed => {
+                self.rate_limit_pause = None;
+            }
+            BackgroundMessage::OrganizationsFetched { organizations } => {
+                self.user_organizations = organizations;
+                self.is_fetching_organizations = false;
+                self.error_message = None;
+                // Keep cycling from the mode shown, e.g. a startup organization
+                self.current_org_index = self.view_mode_index();
+                if self.config.prefetch.organizations > 0 && self.prefetch_queue.is_none() {
+                    self.plan_prefetch();
+                    self.prefetch_next_organization();
+                }
+
+                // If the user was trying to cycle but we had no organizations,
+                // now we can start cycling
+                if !self.user_organizations.is_empty() {
+                    // The user will need to press Tab again to start cycling
+                    // This is more predictable than auto-cycling
+                }
+            }
+            BackgroundMessage::CheckRunsFetched {
+                pr_number,
+                check_runs,
+            } => {
+                // Ignore results for a PR the user has already navigated away from
+                if self.get_selected_pull_request().map(|pr| pr.number) == Some(pr_number) {
+                    self.check_runs = check_runs;
+                    self.selected_check_run = 0;
+                }
+                self.is_fetching_check_runs = false;
+            }
+            BackgroundMessage::MergeRequirementsFetched {
+                pr_number,
+                requirements,
+            } => {
+                // Ignore results for a PR the user has already navigated away from
+                if self.get_selected_pull_request().map(|pr| pr.number) == Some(pr_number) {
+                    self.merge_requirements = Some(requirements);
+                }
+                self.is_fetching_merge_requirements = false;
+            }
+            BackgroundMessage::PullRequestFilesFetched { pr_number, files } => {
+                // Ignore results for a PR the user has already navigated away from
+                if self.get_selected_pull_request().map(|pr| pr.number) == Some(pr_number) {
+                    self.pull_request_files = files;
+                }
+                self.is_fetching_pull_request_files = false;
+            }
+            BackgroundMessage::PullRequestReviewed { pr_number, event } => {
+                self.status_message = Some(format!("{} #{}", event.past_tense(), pr_number));
+            }
+            BackgroundMessage::CheckRunRerequested { check_run_id } => {
+                self.pending_check_reruns.remove(&check_run_id);
+                if let Some(check) = self.check_runs.iter().find(|c| c.id == check_run_id) {
+                    self.status_message = Some(t_with(
+                        "messages.check_rerequested",
+                        &[("name", &check.name)],
+                    ));
+                }
+            }
+            BackgroundMessage::CheckRunRerequestFailed {
+                check_run_id,
+                error,
+            } => {
+                if let Some(previous) = self.pending_check_reruns.remove(&check_run_id) {
+                    if let Some(check) = self.check_runs.iter_mut().find(|c| c.id == check_run_id) {
+                        check.status = previous;
+                    }
+                }
+                self.status_message = Some(error);
+            }
+            BackgroundMessage::OrgMembershipFetched { health } => {
+                // Ignore a report for an organization the user has already left
+                if self.repo_view_mode == RepositoryViewMode::Organization(health.org.clone()) {
+                    self.org_membership = Some(health);
+                }
+                self.is_fetching_org_membership = false;
+            }
+            BackgroundMessage::OrgAuditLogFetched { audit_log } => {
+                self.org_audit_log = Some(audit_log);
+                self.is_fetching_audit_log = false;
+            }
+            BackgroundMessage::CriticalIssuesFetched { repository, issues } => {
+                if let Some(index) = self.row_cache.position(&repository) {
+                    self.repositories[index].critical_issues = Some(issues);
+                }
+            }
+            BackgroundMessage::OpenIssuesFetched { repository, issues } => {
+                self.selected_issue = self.selected_issue.min(issues.len().saturating_sub(1));
+                self.open_issues = Some((repository, issues));
+                self.is_fetching_open_issues = false;
+            }
+            BackgroundMessage::IssuesSearched {
+                filter,
+                issues,
+                total,
+            } => {
+                // Ignore results for filters the user has already changed
+                if filter == self.issue_search_filter {
+                    self.selected_searched_issue = self
+                        .selected_searched_issue
+                        .min(issues.len().saturating_sub(1));
+                    self.searched_issues = Some((issues, total));
+                    self.is_searching_issues = false;
+                }
+            }
+            BackgroundMessage::IssueTriaged {
+                repository,
+                issue,
+                action,
+            } => {
+                self.status_message = Some(action.describe(issue.number));
+                let undoing =
+                    self.undoing_issue.as_ref() == Some(&(repository.clone(), issue.number));
+                if undoing {
+                    self.undoing_issue = None;
+                } else if let (
+                    IssueAction::Label { add, remove },
+                    Some((owner, repo)),
+                    Some(before),
+                ) = (
+                    &action,
+                    repository.split_once('/'),
+                    self.shown_issue_labels(&repository, issue.number),
+                ) {
+                    // Only labels the edit changed are reverted: adding one the
+                    // issue had or removing one it lacked changed nothing
+                    let added: Vec<String> = add
+                        .iter()
+                        .filter(|label| !before.contains(label) && issue.labels.contains(label))
+                        .cloned()
+                        .collect();
+                    let removed: Vec<String> = remove
+                        .iter()
+                        .filter(|label| before.contains(label) && !issue.labels.contains(label))
+                        .cloned()
+                        .collect();
+                    if !added.is_empty() || !removed.is_empty() {
+                        self.undo_stack.push(UndoAction::Labels {
+                            owner: owner.to_string(),
+                            repo: repo.to_string(),
+                            number: issue.number,
+                            add: added,
+                            remove: removed,
+                        });
+                    }
+                }
+                if let Some((issues, _)) = &mut self.searched_issues {
+                    if let Some(existing) = issues.iter_mut().find(|searched| {
+                        searched.repository == repository && searched.issue.number == issue.number
+                    }) {
+                        existing.issue = issue.clone();
+                    }
+                }
+                if let Some((_, issues)) = self
+                    .open_issues
+                    .as_mut()
+                    .filter(|(fetched, _)| *fetched == repository)
+                {
+                    if let Some(existing) = issues.iter_mut().find(|i| i.number == issue.number) {
+                        *existing = issue;
+                    }
+                }
+            }
+            BackgroundMessage::IssueActivityFetched {
+                repository,
+                weeks,
+                issues,
+            } => {
+                // Ignore results for a window the user has already switched away from
+                if weeks == self.burndown_weeks {
+                    self.issue_activity = Some((repository, issues));
+                    self.is_fetching_issue_activity = false;
+                }
+            }
+            BackgroundMessage::RepositoryCreated { repository } => {
+                let full_name = repository.full_name();
+                self.status_message = match self
+                    .config
+                    .add_watched_repository(&repository.owner, &repository.name)
+                {
+                    Ok(()) => Some(t_with(
+                        "messages.repository_created",
+                        &[("repository", &full_name)],
+                    )),
+                    Err(e) => Some(t_with(
+                        "messages.repository_created_unsaved",
+                        &[("repository", &full_name), ("error", &e)],
+                    )),
+                };
+
+                if let RepositoryViewMode::Organization(org) = &self.repo_view_mode {
+                    if org == &repository.owner {
+                        if let Some(cached) = self.organization_repositories.get_mut(org) {
+                            cached.push(repository.clone());
+                        }
+                        self.push_repository(repository);
+                    }
+                }
+            }
+            BackgroundMessage::PoliciesScanned { facts } => {
+                self.policy_report = Some(PolicyReport::evaluate(
+                    &self.repositories,
+                    &self.config.policies,
+                    &facts,
+                    SystemTime::now(),
+                ));
+                self.is_scanning_policies = false;
+            }
+            BackgroundMessage::DependenciesScanned { graph } => {
+                self.dependency_graph = Some(graph);
+                self.is_scanning_dependencies = false;
+            }
+            BackgroundMessage::OwnershipScanned { ownership } => {
+                self.ownership = Some(ownership);
+                self.is_scanning_ownership = false;
+                self.status_message = None;
+                self.open_team_menu();
+            }
+            BackgroundMessage::FailuresClustered { clusters } => {
+                self.failure_clusters = Some(clusters);
+                self.is_clustering_failures = false;
+            }
+            BackgroundMessage::CleanupScanned { candidates } => {
+                self.cleanup_candidates = Some(candidates);
+                self.is_scanning_cleanup = false;
+            }
+            BackgroundMessage::CustomChecksCompleted {
+                repository,
+                results,
+            } => {
+                self.custom_checks.insert(repository, results);
+            }
+            BackgroundMessage::RepositoryArchived { repository } => {
+                if let Some(index) = self.row_cache.position(&repository) {
+                    self.repositories[index].archived = true;
+                }
+                if let Some(candidates) = &mut self.cleanup_candidates {
+                    candidates.retain(|candidate| candidate.repository != repository);
+                }
+                self.status_message =
+                    Some(t_with("messages.archived", &[("repository", &repository)]));
+            }
+            BackgroundMessage::ContributionsFetched { calendar } => {
+                self.contributions = Some(calendar);
+                self.is_fetching_contributions = false;
+            }
+            BackgroundMessage::ComplianceScanned { report } => {
+                self.compliance_report = Some(report);
+                self.is_scanning_compliance = false;
+            }
+            BackgroundMessage::RateLimitChecked { status } => self.plan_refresh(status),
+            BackgroundMessage::TokenChecked { check } => {
+                self.status_message = None;
+                // Config problems are shown first, the token's after them
+                if check
+                    .as_ref()
+                    .is_some_and(|check| !check.warnings().is_empty())
+                    && self.current_view != AppView::ConfigProblems
+                {
+                    self.current_view = AppView::TokenDiagnostics;
+                }
+                self.token_status = TokenStatus::Checked(check);
+                self.refresh();
+            }
+            BackgroundMessage::TokenRejected { error } => {
+                self.status_message = None;
+                self.error_message = Some(t_with("messages.token_rejected", &[("error", &error)]));
+                self.token_status = TokenStatus::Rejected(error);
+                // Read the token again on the next refresh, e.g. after --login
+                self.github_client = None;
+                self.current_view = AppView::TokenDiagnostics;
+            }
+            BackgroundMessage::PlatformStatusFetched { status } => {
+                self.platform_status = Some(status);
+            }
+            BackgroundMessage::OrganizationPrefetched {
+                org,
+                result,
+                requests,
+            } => {
+                self.prefetching = None;
+                match result {
+                    Ok(mut repositories) => {
+                        for repo in &mut repositories {
+                            repo.weigh_workflow_health(&self.config.ci);
+                        }
+                        let attention = self.attention_count(&repositories);
+                        self.record_org_load(&org, attention, requests);
+                        self.organization_repositories
+                            .entry(org)
+                            .or_insert(repositories);
+                    }
+                    // Quietly: the organization loads as usual when opened
+                    Err(error) => self.session_errors.push(error),
+                }
+                self.prefetch_next_organization();
+            }
+            BackgroundMessage::ComparisonFetched {
+                org,
+                mut repositories,
+            } => {
+                for repo in &mut repositories {
+                    repo.weigh_workflow_health(&self.config.ci);
+                }
+                let source = ComparisonSource::Organization(org.clone());
+                for pane in &mut self.comparison_panes {
+                    if pane.is_loading && pane.source == source {
+                        pane.repositories = repositories.clone();
+                        pane.is_loading = false;
+                    }
+                }
+                self.comparison_cache.insert(org, repositories);
+            }
+            BackgroundMessage::ActionError { error } => {
+                self.is_fetching_check_runs = false;
+                self.is_fetching_open_issues = false;
+                self.is_searching_issues = false;
+                self.is_fetching_merge_requirements = false;
+                self.is_fetching_pull_request_files = false;
+                self.is_fetching_issue_activity = false;
+                self.is_fetching_org_membership = false;
+                self.is_fetching_audit_log = false;
+                self.is_scanning_compliance = false;
+                self.is_scanning_policies = false;
+                self.is_scanning_dependencies = false;
+                self.is_scanning_ownership = false;
+                self.is_scanning_cleanup = false;
+                self.is_fetching_contributions = false;
+                self.is_clustering_failures = false;
+                self.is_fetching_organizations = false;
+                for pane in &mut self.comparison_panes {
+                    pane.is_loading = false;
+                }
+                self.session_errors.push(error.clone());
+                self.status_message = Some(error);
+            }
+        }
+    }
+
+    /// What this session did, for the summary printed on exit
+    pub fn session_summary(&self) -> SessionSummary {
+        SessionSummary {
+            duration: self.startup.uptime(),
+            refreshes: self.completed_refreshes,
+            repositories: self.repositories.len(),
+            endpoints: self
+                .github_client
+                .as_ref()
+                .map(|client| client.metrics().snapshot())
+                .unwrap_or_default(),
+            errors: self.session_errors.clone(),
+        }
+    }
+
+    /// Cycle between repository view modes
+    pub fn cycle_view_mode(&mut self) {
+        // Organizations are only looked up the first time Tab is pressed,
+        // so startup never waits on them
+        if self.user_organizations.is_empty() && self.github_client.is_some() {
+            if !self.is_fetching_organizations {
+                self.fetch_user_organizations();
+            }
+            // If we still have no organizations, there's nothing to cycle to
+            // so just stay in Personal mode for now
+            return;
+        }
 
-    /// Async method to fetch repository data from GitHub
-    /// This should be called from the main event loop when is_loading is true
-    pub async fn fetch_repositories(&mut self) -> Result<(), String> {
-        if let Some(ref client) = self.github_client {
-            self.is_loading = true;
-            self.error_message = None;
+        // If no organizations available (either no GitHub client or no orgs found), 
+        // just stay in Personal mode
+        if self.user_organizations.is_empty() {
+            return;
+        }
+
+        // Cycle through: Personal -> Org1 -> Org2 -> ... -> All -> Personal
+        let total_modes = 2 + self.user_organizations.len(); // Personal + N orgs + All
+        self.current_org_index = (self.current_org_index + 1) % total_modes;
+
+        // Update the view mode based on current index
+        self.repo_view_mode = if self.current_org_index == 0 {
+            RepositoryViewMode::Personal
+        } else if self.current_org_index > self.user_organizations.len() {
+            RepositoryViewMode::All
+        } else {
+            let org_name = self.user_organizations[self.current_org_index - 1].clone();
+            RepositoryViewMode::Organization(org_name)
+        };
+
+        // Switch to the new view
+        self.switch_to_current_view();
+
+        // Reset selection and scroll when switching modes
+        self.selected_repository = 0;
+        self.scroll_offset = 0;
+    }
+
+    /// Position of the current view mode in the Tab cycle
+    fn view_mode_index(&self) -> usize {
+        match &self.repo_view_mode {
+            RepositoryViewMode::Personal => 0,
+            RepositoryViewMode::Organization(org_name) => self
+                .user_organizations
+                .iter()
+                .position(|org| org == org_name)
+                .map_or(0, |index| index + 1),
+            RepositoryViewMode::All => self.user_organizations.len() + 1,
+        }
+    }
+
+    /// Switch to the current view mode
+    fn switch_to_current_view(&mut self) {
+        // A held or checked refresh belongs to the mode being left
+        self.canary_baseline = None;
+        self.held_dataset = None;
 
-            match client.list_user_repositories().await {
-                Ok(repos) => {
-                    self.repositories = repos;
-                    self.is_loading = false;
-                    self.last_refresh = Some(std::time::Instant::now());
-                    Ok(())
+        match self.repo_view_mode.clone() {
+            RepositoryViewMode::Personal => {
+                if let Some(cached_repos) = self.personal_repositories.clone() {
+                    // Use cached data
+                    self.set_repositories(cached_repos);
+                } else {
+                    // Need to fetch personal repositories
+                    self.fetch_repositories_for_current_mode();
+                }
+            }
+            RepositoryViewMode::Organization(org_name) => {
+                self.record_org_view(&org_name);
+                // The view's own fetch replaces a prefetch still running
+                if let Some((_, task)) = self
+                    .prefetching
+                    .take_if(|(prefetched, _)| *prefetched == org_name)
+                {
+                    task.abort();
                 }
-                Err(e) => {
-                    self.error_message = Some(format!("Failed to fetch repositories: {}", e));
-                    self.is_loading = false;
-                    Err(e)
+                if let Some(cached_repos) = self.organization_repositories.get(&org_name).cloned() {
+                    // Use cached data
+                    self.set_repositories(cached_repos);
+                } else {
+                    // Need to fetch organization repositories
+                    self.fetch_repositories_for_current_mode();
                 }
             }
-        } else {
-            // GitHub client not available - this should have been set during app initialization
-            // If we get here, it means the token wasn't set or GitHub client creation failed
-            if self.error_message.is_none() {
-                self.error_message = Some("GitHub client not available. Please check your GH_REPO_HEALTHCHECKS_TOKEN environment variable.".to_string());
+            RepositoryViewMode::All => {
+                if let Some(cached_repos) = self.all_repositories.clone() {
+                    self.set_repositories(cached_repos);
+                } else {
+                    self.fetch_repositories_for_current_mode();
+                }
             }
-            self.is_loading = false;
-            Err("GitHub client not initialized".into())
         }
     }
 
-    /// Get the display title for the current view
-    pub fn get_title(&self) -> &str {
-        &self.title
+    /// Repositories among `repositories` needing attention
+    fn attention_count(&self, repositories: &[Repository]) -> usize {
+        repositories
+            .iter()
+            .filter(|repo| self.config.attention.matches(repo))
+            .count()
     }
 
-    /// Check if the application should quit
-    pub fn should_quit(&self) -> bool {
-        self.should_quit
+    /// Remember switching to an organization, for the prefetch order
+    fn record_org_view(&mut self, org: &str) {
+        self.org_history.viewed(org, chrono::Utc::now());
+        self.save_org_history();
     }
 
-    /// Get the current repositories
-    pub fn get_repositories(&self) -> &[Repository] {
-        &self.repositories
+    /// Remember how an organization looked once loaded and what it cost
+    fn record_org_load(&mut self, org: &str, attention: usize, requests: u64) {
+        self.org_history.loaded(org, attention, requests);
+        self.save_org_history();
     }
 
-    /// Check if the app is currently loading data
-    pub fn is_loading(&self) -> bool {
-        self.is_loading
+    /// Write the organization history, which is only kept while prefetching
+    /// is on
+    fn save_org_history(&mut self) {
+        if self.demo || self.config.prefetch.organizations == 0 {
+            return;
+        }
+        let Some(path) = self.config.prefetch.history_path() else {
+            return;
+        };
+        if let Err(e) = self.org_history.save(&path) {
+            self.session_errors.push(e);
+        }
+    }
+
+    /// Rank the organizations worth prefetching, leaving out the one shown
+    /// and those already cached
+    fn plan_prefetch(&mut self) {
+        let candidates: Vec<String> = self
+            .user_organizations
+            .iter()
+            .filter(|org| {
+                self.repo_view_mode != RepositoryViewMode::Organization(org.to_string())
+                    && !self.organization_repositories.contains_key(*org)
+            })
+            .cloned()
+            .collect();
+        self.prefetch_queue = Some(self.org_history.prefetch_order(
+            &candidates,
+            u64::MAX,
+            self.config.prefetch.organizations,
+        ));
+    }
+
+    /// Start loading the most useful organization left to prefetch whose
+    /// last load fits the API requests left above the reserve
+    ///
+    /// Runs once the view shown has finished loading, one organization at a
+    /// time, so prefetching never competes with what's on screen.
+    fn prefetch_next_organization(&mut self) {
+        if self.config.prefetch.organizations == 0
+            || self.demo
+            || self.prefetching.is_some()
+            || self.is_loading
+            || self.is_enhancing
+            || self.is_background_paused()
+        {
+            return;
+        }
+        let Some(client) = self.github_client.clone() else {
+            return;
+        };
+        let Some(queue) = &self.prefetch_queue else {
+            // Organizations are listed once, then ranked
+            if !self.is_fetching_organizations && self.user_organizations.is_empty() {
+                self.fetch_user_organizations();
+            } else if !self.user_organizations.is_empty() {
+                self.plan_prefetch();
+                self.prefetch_next_organization();
+            }
+            return;
+        };
+        let Some(status) = self.rate_limit() else {
+            return;
+        };
+        let budget = status
+            .remaining
+            .saturating_sub(self.config.api.rate_limit_reserve);
+        // Nothing that fits now may fit after the reset, at a later refresh
+        let Some(org) = self.org_history.prefetch_order(queue, budget, 1).pop() else {
+            return;
+        };
+        if let Some(queue) = &mut self.prefetch_queue {
+            queue.retain(|queued| *queued != org);
+        }
+        if self.organization_repositories.contains_key(&org)
+            || self.repo_view_mode == RepositoryViewMode::Organization(org.clone())
+        {
+            self.prefetch_next_organization();
+            return;
+        }
+        let client = self.configured_client(client, EnhancementProfile::Full);
+        let task = GitHubClient::spawn_organization_prefetch(
+            client,
+            self.action_sender.clone(),
+            org.clone(),
+        );
+        self.prefetching = Some((org, task));
+    }
+
+    /// A client fetching with the configured collectors and API settings
+    fn configured_client(&self, client: GitHubClient, profile: EnhancementProfile) -> GitHubClient {
+        client
+            .with_profile(profile)
+            .with_collectors(self.config.enabled_collectors())
+            .with_backend(self.config.api.backend)
+            .with_rate_limit_reserve(self.config.api.rate_limit_reserve)
+            .with_affiliated(self.config.startup.affiliated)
+            .with_job_timeout(self.config.api.job_timeout())
+    }
+
+    /// Fetch the list of organizations the user belongs to
+    ///
+    /// Runs alongside any repository fetch in progress: the result comes back
+    /// on the action channel, so the repository list keeps loading.
+    fn fetch_user_organizations(&mut self) {
+        if let Some(client) = self.github_client.clone() {
+            let sender = self.action_sender.clone();
+            self.is_fetching_organizations = true;
+
+            tokio::spawn(async move {
+                match client.get_user_organizations().await {
+                    Ok(orgs) => {
+                        // Send a message to update the organizations list
+                        let _ = sender.send(BackgroundMessage::OrganizationsFetched { organizations: orgs });
+                    }
+                    Err(e) => {
+                        let _ = sender.send(BackgroundMessage::ActionError {
+                            error: t_with("messages.organizations_failed", &[("error", &e)]),
+                        });
+                    }
+                }
+            });
+        }
+    }
+
+    /// Fetch repositories for the current view mode
+    fn fetch_repositories_for_current_mode(&mut self) {
+        if let Some(client) = self.github_client.clone() {
+            // Clear current repositories and show loading, unless the
+            // previous list stays up while the refresh is checked
+            if self.canary_baseline.is_none() {
+                self.set_repositories(Vec::new());
+            }
+            self.is_loading = true;
+            self.error_message = None;
+            self.loading_progress = None;
+            self.refresh_request_baseline = Some(client.metrics().totals().0);
+            let profile = std::mem::take(&mut self.refresh_profile);
+            if profile == EnhancementProfile::Lite {
+                self.status_message = Some(t("messages.lite_refresh").to_string());
+            }
+            let client = self.configured_client(client, profile);
+
+            // Setup background processing channel
+            let sender = self.setup_background_processing();
+
+            // Spawn background task based on current mode, replacing any
+            // fetch still running
+            if let Some(task) = self.background_task.take() {
+                task.abort();
+            }
+            self.pending_enhancement.clear();
+            self.last_background_progress = Some(std::time::Instant::now());
+            self.pagination_stop = None;
+            let task = match &self.repo_view_mode {
+                RepositoryViewMode::Personal => {
+                    let stop = Arc::new(AtomicBool::new(false));
+                    self.pagination_stop = Some(stop.clone());
+                    crate::github::GitHubClient::spawn_background_fetch(client, sender, stop)
+                }
+                RepositoryViewMode::Organization(org_name) => {
+                    let stop = Arc::new(AtomicBool::new(false));
+                    self.pagination_stop = Some(stop.clone());
+                    crate::github::GitHubClient::spawn_background_fetch_organization(
+                        client,
+                        sender,
+                        org_name.clone(),
+                        stop,
+                    )
+                }
+                RepositoryViewMode::All => {
+                    crate::github::GitHubClient::spawn_background_fetch_all(client, sender)
+                }
+            };
+            self.background_task = Some(task);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RepositoryStatus, RunTrigger, WorkflowRun};
+
+    #[test]
+    fn test_app_creation() {
+        let app = App::new();
+        assert!(!app.should_quit());
+        assert_eq!(app.current_view, AppView::Dashboard);
+    }
+
+    #[test]
+    fn test_quit_key_handling() {
+        let mut app = App::new();
+
+        // Test 'q' key
+        assert!(app.handle_key_event(KeyCode::Char('q')));
+        assert!(app.should_quit());
+    }
+
+    #[test]
+    fn test_refresh_key_handling() {
+        let mut app = App::new();
+
+        // Test 'r' key
+        assert!(app.handle_key_event(KeyCode::Char('r')));
+        assert!(app.last_refresh.is_some());
+    }
+
+    #[test]
+    fn test_unfocused_terminal_pauses_background_work() {
+        let mut app = App::with_config(AppConfig::default());
+        app.set_focused(false);
+        assert!(app.is_background_paused());
+        app.tick();
+        assert!(app.platform_status_checked_at.is_none());
+
+        // Recent data is kept when focus returns
+        let refreshed = std::time::Instant::now();
+        app.last_refresh = Some(refreshed);
+        app.set_focused(true);
+        assert!(!app.is_background_paused());
+        assert_eq!(app.last_refresh, Some(refreshed));
+
+        // Older data is refreshed right away
+        app.set_focused(false);
+        app.last_refresh = Some(refreshed - std::time::Duration::from_secs(120));
+        app.set_focused(true);
+        assert!(app.last_refresh.unwrap() >= refreshed);
+
+        let mut config = AppConfig::default();
+        config.focus.pause_when_unfocused = false;
+        let mut app = App::with_config(config);
+        app.set_focused(false);
+        assert!(!app.is_background_paused());
+    }
+
+    #[test]
+    fn test_detail_view_navigation() {
+        let mut app = App::new();
+        app.set_repositories(vec![Repository::new(
+            "repo".to_string(),
+            "owner".to_string(),
+        )]);
+
+        assert!(app.handle_key_event(KeyCode::Enter));
+        assert_eq!(app.current_view, AppView::RepoDetails);
+
+        // Esc goes back instead of quitting while in a detail view
+        assert!(app.handle_key_event(KeyCode::Esc));
+        assert_eq!(app.current_view, AppView::Dashboard);
+        assert!(!app.should_quit());
+    }
+
+    #[test]
+    fn test_focus_mode_hides_healthy_repositories() {
+        let mut app = App::with_config(AppConfig::default());
+        let mut healthy = Repository::new("healthy".to_string(), "org".to_string());
+        healthy.status = RepositoryStatus::Active;
+        let mut stale = Repository::new("stale".to_string(), "org".to_string());
+        stale.status = RepositoryStatus::Stale;
+        app.set_repositories(vec![healthy, stale]);
+        app.selected_repository = 1;
+
+        assert!(app.handle_key_event(KeyCode::Char('f')));
+        assert!(app.focus_mode);
+        assert_eq!(app.visible_repository_count(), 1);
+        assert_eq!(app.hidden_repository_count(), 1);
+        assert_eq!(app.get_selected_repository().unwrap().name, "stale");
+
+        app.handle_key_event(KeyCode::Char('f'));
+        assert_eq!(app.visible_repository_count(), 2);
+        assert_eq!(app.get_selected_repository().unwrap().name, "stale");
+    }
+
+    #[test]
+    fn test_smart_view_picker_filters_dashboard() {
+        let mut config = AppConfig::default();
+        config.views.push(SmartViewConfig {
+            name: "Rust".to_string(),
+            language: Some("Rust".to_string()),
+            sort: RepositorySort::Size,
+            ..SmartViewConfig::default()
+        });
+        let mut app = App::with_config(config);
+        let mut api = Repository::new("api".to_string(), "acme".to_string());
+        api.language = Some("Rust".to_string());
+        let web = Repository::new("web".to_string(), "acme".to_string());
+        app.set_repositories(vec![web, api]);
+
+        assert!(app.handle_key_event(KeyCode::Char('w')));
+        let labels: Vec<&str> = app
+            .menu
+            .as_ref()
+            .unwrap()
+            .items
+            .iter()
+            .map(|item| item.label.as_str())
+            .collect();
+        assert_eq!(
+            labels,
+            vec!["All repositories", "Rust", "Save current view..."]
+        );
+
+        app.handle_key_event(KeyCode::Char('2'));
+        assert!(app.menu.is_none());
+        assert_eq!(app.active_smart_view().unwrap().name, "Rust");
+        assert_eq!(app.sort, RepositorySort::Size);
+        assert_eq!(app.visible_repository_count(), 1);
+        assert_eq!(app.get_selected_repository().unwrap().name, "api");
+
+        // Reopening the picker selects the active view; 1 shows everything again
+        app.handle_key_event(KeyCode::Char('w'));
+        assert_eq!(app.menu.as_ref().unwrap().selected, 1);
+        app.handle_key_event(KeyCode::Char('1'));
+        assert!(app.active_smart_view().is_none());
+        assert_eq!(app.visible_repository_count(), 2);
+    }
+
+    #[test]
+    fn test_bulk_archive_confirms_each_admin_candidate() {
+        let mut app = App::with_config(AppConfig::default());
+        let repositories: Vec<Repository> = ["api-old", "web-backup", "shared-copy"]
+            .iter()
+            .map(|name| {
+                let mut repo = Repository::new(name.to_string(), "acme".to_string());
+                repo.admin = *name != "shared-copy";
+                repo
+            })
+            .collect();
+        app.cleanup_candidates = Some(
+            repositories
+                .iter()
+                .map(|repo| CleanupCandidate {
+                    repository: repo.full_name(),
+                    reasons: vec![CleanupReason::Empty],
+                })
+                .collect(),
+        );
+        app.set_repositories(repositories);
+        app.current_view = AppView::Cleanup;
+
+        // Only candidates the user administers are offered, one at a time
+        app.handle_key_event(KeyCode::Char('a'));
+        let menu = app.menu.as_ref().unwrap();
+        assert_eq!(menu.title, "Archive acme/api-old? (1/2) no commits");
+        assert_eq!(menu.items[menu.selected].action, MenuAction::SkipArchive);
+
+        app.handle_key_event(KeyCode::Char('2'));
+        assert!(app
+            .menu
+            .as_ref()
+            .unwrap()
+            .title
+            .starts_with("Archive acme/web-backup? (2/2)"));
+        app.handle_key_event(KeyCode::Enter);
+        assert!(app.menu.is_none());
+        assert_eq!(app.status_message.as_deref(), Some("Bulk archive finished"));
+
+        app.handle_background_message(BackgroundMessage::RepositoryArchived {
+            repository: "acme/api-old".to_string(),
+        });
+        assert!(app.repositories[0].archived);
+        assert_eq!(app.cleanup_candidates.as_ref().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_features_panel_shows_collector_costs() {
+        let mut app = App::with_config(AppConfig::default());
+        let mut repo = Repository::new("api".to_string(), "acme".to_string());
+        repo.has_pages = true;
+        app.set_repositories(vec![
+            repo,
+            Repository::new("web".to_string(), "acme".to_string()),
+        ]);
+
+        assert!(app.handle_key_event(KeyCode::Char('e')));
+        let labels: Vec<&str> = app
+            .menu
+            .as_ref()
+            .unwrap()
+            .items
+            .iter()
+            .map(|item| item.label.as_str())
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                "[x] Workflow runs (~2 calls per refresh)",
+                "[x] Review feedback (~0 calls per refresh)",
+                "[x] Git LFS detection (~2 calls per refresh)",
+                "[x] Deployments (~3 calls per refresh)",
+                "[x] Latest release (~2 calls per refresh)",
+                "[x] Issue metrics (~0 calls per refresh)",
+                "[x] Code scanning alerts (~2 calls per refresh)",
+                "[x] Secret scanning alerts (~2 calls per refresh)",
+                "[x] Branch protection (~2 calls per refresh)",
+                "[x] Community profile (~2 calls per refresh)",
+                "[x] Contributor stats (~2 calls per refresh)",
+                "[x] Commit activity (~2 calls per refresh)",
+            ]
+        );
+
+        // Toggling persists to the config file, so it's read-only in guest mode
+        app.guest_mode = true;
+        app.handle_key_event(KeyCode::Char('4'));
+        assert!(app.config.collector_enabled(Collector::Deployments));
+        assert_eq!(app.menu.as_ref().unwrap().selected, 3);
+    }
+
+    #[test]
+    fn test_team_filter_uses_primary_codeowners_team() {
+        let mut app = App::with_config(AppConfig::default());
+        app.set_repositories(
+            ["api", "web", "docs"]
+                .iter()
+                .map(|name| Repository::new(name.to_string(), "acme".to_string()))
+                .collect(),
+        );
+
+        app.handle_background_message(BackgroundMessage::OwnershipScanned {
+            ownership: HashMap::from([
+                ("acme/api".to_string(), Codeowners::parse("* @acme/backend")),
+                (
+                    "acme/web".to_string(),
+                    Codeowners::parse("* @acme/frontend"),
+                ),
+                (
+                    "acme/docs".to_string(),
+                    Codeowners::parse("/guides/ @acme/backend"),
+                ),
+            ]),
+        });
+        let labels: Vec<&str> = app
+            .menu
+            .as_ref()
+            .unwrap()
+            .items
+            .iter()
+            .map(|item| item.label.as_str())
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                "All teams",
+                "@acme/backend (2)",
+                "@acme/frontend (1)",
+                "Rescan CODEOWNERS"
+            ]
+        );
+
+        app.handle_key_event(KeyCode::Char('2'));
+        assert_eq!(app.team_filter.as_deref(), Some("@acme/backend"));
+        assert_eq!(app.visible_repository_count(), 2);
+        assert_eq!(app.hidden_repository_count(), 1);
+
+        // Reopening the picker selects the active team
+        app.handle_key_event(KeyCode::Char('o'));
+        assert_eq!(app.menu.as_ref().unwrap().selected, 1);
+        app.handle_key_event(KeyCode::Char('1'));
+        assert_eq!(app.visible_repository_count(), 3);
+    }
+
+    #[test]
+    fn test_topic_filter_shows_tagged_repositories() {
+        let mut app = App::with_config(AppConfig::default());
+        app.set_repositories(
+            [
+                ("api", vec!["infra", "payments"]),
+                ("terraform", vec!["infra"]),
+                ("web", vec![]),
+            ]
+            .into_iter()
+            .map(|(name, topics)| {
+                let mut repo = Repository::new(name.to_string(), "acme".to_string());
+                repo.topics = topics.into_iter().map(String::from).collect();
+                repo
+            })
+            .collect(),
+        );
+
+        app.handle_key_event(KeyCode::Char('#'));
+        let labels: Vec<&str> = app
+            .menu
+            .as_ref()
+            .unwrap()
+            .items
+            .iter()
+            .map(|item| item.label.as_str())
+            .collect();
+        assert_eq!(labels, vec!["All topics", "infra (2)", "payments (1)"]);
+
+        app.handle_key_event(KeyCode::Char('2'));
+        assert_eq!(app.topic_filter.as_deref(), Some("infra"));
+        assert_eq!(app.visible_repository_count(), 2);
+        assert_eq!(app.hidden_repository_count(), 1);
+
+        app.select_topic(None);
+        assert_eq!(app.visible_repository_count(), 3);
+    }
+
+    #[test]
+    fn test_quick_jump_selects_without_filtering() {
+        let mut app = App::with_config(AppConfig::default());
+        app.set_repositories(
+            ["web", "api-gateway", "Api", "worker"]
+                .iter()
+                .map(|name| Repository::new(name.to_string(), "acme".to_string()))
+                .collect(),
+        );
+
+        app.handle_key_event(KeyCode::Char('\''));
+        app.handle_key_event(KeyCode::Char('a'));
+        assert_eq!(app.get_selected_repository().unwrap().name, "api-gateway");
+        app.handle_key_event(KeyCode::Char('p'));
+        app.handle_key_event(KeyCode::Char('i'));
+        app.handle_key_event(KeyCode::Char('-'));
+        assert_eq!(app.visible_repository_count(), 4);
+
+        // No match keeps the selection; Backspace widens the prefix again
+        app.handle_key_event(KeyCode::Char('x'));
+        assert_eq!(app.quick_jump_match(), None);
+        assert_eq!(app.get_selected_repository().unwrap().name, "api-gateway");
+        app.handle_key_event(KeyCode::Backspace);
+        assert_eq!(app.quick_jump_match(), Some(1));
+
+        // Arrow keys end the jump and navigate from the match
+        app.handle_key_event(KeyCode::Down);
+        assert!(app.quick_jump.is_none());
+        assert_eq!(app.get_selected_repository().unwrap().name, "Api");
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn test_views_reload_their_own_data_on_r() {
+        let mut config = AppConfig::default();
+        config.reports.directory = Some(std::env::temp_dir().join(format!(
+            "gh-repo-healthchecks-no-reports-{}",
+            std::process::id()
+        )));
+        let mut app = App::with_config(config);
+        app.github_client = None;
+        let press_r = |app: &mut App| {
+            app.handle_terminal_event(crate::events::AppEvent::Key(
+                ratatui::crossterm::event::KeyEvent::new(
+                    KeyCode::Char('r'),
+                    ratatui::crossterm::event::KeyModifiers::NONE,
+                ),
+            ));
+        };
+
+        app.current_view = AppView::IssueSearch;
+        press_r(&mut app);
+        assert_eq!(app.current_view, AppView::IssueSearch);
+
+        app.repo_view_mode = RepositoryViewMode::Organization("acme".to_string());
+        app.org_audit_log = Some(OrgAuditLog {
+            org: "acme".to_string(),
+            events: Some(Vec::new()),
+        });
+        app.current_view = AppView::AuditLog;
+        press_r(&mut app);
+        assert!(app.org_audit_log.is_none());
+        assert_eq!(app.current_view, AppView::AuditLog);
+
+        app.archived_reports = vec![ArchivedReport {
+            path: "gone.json".into(),
+            kind: crate::report::ArchiveKind::Report,
+            format: crate::config::ReportFormat::Json,
+            generated_at: chrono::Utc::now(),
+        }];
+        app.current_view = AppView::Reports;
+        press_r(&mut app);
+        assert!(app.archived_reports.is_empty());
+
+        // None of them refreshed the repositories
+        assert!(app.last_refresh.is_none());
+    }
+
+    #[test]
+    fn test_quick_jump_takes_refresh_keys() {
+        let mut app = App::with_config(AppConfig::default());
+        app.github_client = None;
+        app.set_repositories(
+            ["api", "reports", "web"]
+                .iter()
+                .map(|name| Repository::new(name.to_string(), "acme".to_string()))
+                .collect(),
+        );
+
+        // Keys reach the app the way the main loop reads them
+        let key = |code| {
+            crate::events::AppEvent::Key(ratatui::crossterm::event::KeyEvent::new(
+                code,
+                ratatui::crossterm::event::KeyModifiers::NONE,
+            ))
+        };
+        app.handle_terminal_event(key(KeyCode::Char('\'')));
+        assert!(app.is_modal_open());
+        app.handle_terminal_event(key(KeyCode::Char('r')));
+        app.handle_terminal_event(key(KeyCode::Char('e')));
+        assert_eq!(app.quick_jump.as_deref(), Some("re"));
+        assert_eq!(app.get_selected_repository().unwrap().name, "reports");
+        assert!(app.last_refresh.is_none());
     }
 
-    /// Check if repositories are being enhanced with additional details
-    pub fn is_enhancing(&self) -> bool {
-        self.is_enhancing
+    #[test]
+    fn test_stalled_refresh_is_cancelled_and_retried() {
+        let mut app = App::with_config(AppConfig::default());
+        app.set_repositories(vec![
+            Repository::new("api".to_string(), "acme".to_string()),
+            Repository::new("web".to_string(), "acme".to_string()),
+        ]);
+        app.pending_enhancement = HashSet::from(["acme/web".to_string()]);
+        app.is_enhancing = true;
+        let ago = |seconds| std::time::Instant::now() - std::time::Duration::from_secs(seconds);
+
+        // Slow, but not stalled yet
+        app.last_background_progress = Some(ago(60));
+        app.check_stalled_refresh();
+        assert!(app.is_enhancing);
+
+        // Waiting out a rate limit isn't a stall
+        app.last_background_progress = Some(ago(120));
+        app.rate_limit_pause = Some(RateLimitPause {
+            current: 1,
+            total: 2,
+            resume_at: SystemTime::now(),
+        });
+        app.check_stalled_refresh();
+        assert!(app.is_enhancing);
+
+        app.rate_limit_pause = None;
+        app.check_stalled_refresh();
+        assert!(!app.is_enhancing);
+        let timed_out: Vec<&str> = app
+            .repositories
+            .iter()
+            .filter(|repo| repo.fetch_timed_out)
+            .map(|repo| repo.name.as_str())
+            .collect();
+        assert_eq!(timed_out, vec!["web"]);
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("No progress from GitHub for 120s; retrying in 30s (1/3)")
+        );
+        assert!(app.stall_retry_at.is_some());
+
+        // Out of retries, it's left to the user
+        app.stall_retry_at = None;
+        app.stall_retries = 3;
+        app.is_loading = true;
+        app.last_background_progress = Some(ago(120));
+        app.check_stalled_refresh();
+        assert!(!app.is_loading);
+        assert!(app.stall_retry_at.is_none());
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("No progress from GitHub for 120s; press r to try again")
+        );
+    }
+
+    #[test]
+    fn test_refresh_over_budget_offers_lite_refresh() {
+        let mut app = App::with_config(AppConfig::default());
+        app.set_repositories(vec![Repository::new("api".to_string(), "acme".to_string())]);
+        let reset = SystemTime::now() + std::time::Duration::from_secs(600);
+
+        app.handle_background_message(BackgroundMessage::RateLimitChecked {
+            status: Some(RateLimitStatus {
+                remaining: 3,
+                reset,
+            }),
+        });
+        let menu = app.menu.clone().unwrap();
+        assert!(menu
+            .title
+            .starts_with("Refresh needs ~13 API calls; 3 left"));
+        assert_eq!(
+            menu.items[0].action,
+            MenuAction::Refresh(EnhancementProfile::Lite)
+        );
+        assert!(app.last_refresh.is_none());
+
+        // Within budget, or when the limit couldn't be checked, it just refreshes
+        app.menu = None;
+        app.handle_background_message(BackgroundMessage::RateLimitChecked {
+            status: Some(RateLimitStatus {
+                remaining: 5000,
+                reset,
+            }),
+        });
+        assert!(app.menu.is_none());
+        assert!(app.last_refresh.is_some());
+    }
+
+    #[test]
+    fn test_token_check_explains_missing_scopes() {
+        let mut app = App::with_config(AppConfig::default());
+        app.handle_background_message(BackgroundMessage::TokenChecked {
+            check: Some(TokenCheck {
+                login: "octocat".to_string(),
+                scopes: Some(vec!["public_repo".to_string(), "admin:org".to_string()]),
+            }),
+        });
+        assert_eq!(app.current_view, AppView::TokenDiagnostics);
+        let TokenStatus::Checked(Some(check)) = &app.token_status else {
+            panic!("token not checked");
+        };
+        assert_eq!(
+            check.warnings(),
+            vec!["token lacks `repo` scope — private repositories are hidden".to_string()]
+        );
+        // The fetch still goes ahead behind the diagnostics
+        assert!(app.last_refresh.is_some());
+        app.handle_key_event(KeyCode::Enter);
+        assert_eq!(app.current_view, AppView::Dashboard);
+
+        app.handle_background_message(BackgroundMessage::TokenRejected {
+            error: "GitHub authentication failed".to_string(),
+        });
+        assert_eq!(app.current_view, AppView::TokenDiagnostics);
+        assert!(app.github_client.is_none());
+        assert!(app
+            .get_error_message()
+            .is_some_and(|error| error.contains("invalid, expired or revoked")));
     }
 
-    /// Check if organizations are currently being fetched
-    pub fn is_fetching_organizations(&self) -> bool {
-        self.is_fetching_organizations
+    #[test]
+    fn test_config_problems_shown_before_token_warnings() {
+        let mut app = App::with_config(AppConfig::default());
+        app.config_problems = vec![ConfigProblem {
+            line: Some(4),
+            message: "unknown field `api.retrys`".to_string(),
+        }];
+        app.current_view = AppView::ConfigProblems;
+        app.handle_background_message(BackgroundMessage::TokenChecked {
+            check: Some(TokenCheck {
+                login: "octocat".to_string(),
+                scopes: Some(vec!["read:org".to_string()]),
+            }),
+        });
+        assert_eq!(app.current_view, AppView::ConfigProblems);
+        app.handle_key_event(KeyCode::Enter);
+        assert_eq!(app.current_view, AppView::TokenDiagnostics);
     }
 
-    /// Get the number of available organizations
-    pub fn organization_count(&self) -> usize {
-        self.user_organizations.len()
-    }
+    #[test]
+    fn test_drastic_refresh_is_held_until_accepted() {
+        let mut app = App::with_config(AppConfig::default());
+        app.github_client = None;
+        let previous: Vec<Repository> = ["api", "web", "worker", "docs", "infra"]
+            .iter()
+            .map(|name| Repository::new(name.to_string(), "acme".to_string()))
+            .collect();
+        app.set_repositories(previous.clone());
+        app.canary_baseline = Some(previous);
+
+        // Most of the list vanished: the previous data stays on screen
+        app.handle_background_message(BackgroundMessage::FetchCompleted {
+            repositories: vec![Repository::new("api".to_string(), "acme".to_string())],
+        });
+        assert_eq!(app.get_repositories().len(), 5);
+        assert_eq!(app.personal_repositories.as_ref().unwrap().len(), 5);
+        let held = app.held_dataset.as_ref().unwrap();
+        assert_eq!(held.change.removed.len(), 4);
+        let menu = app.menu.clone().unwrap();
+        assert_eq!(menu.items[0].action, MenuAction::KeepPreviousDataset);
 
-    /// Get the enhancement progress, if available
-    pub fn enhancement_progress(&self) -> Option<(usize, usize)> {
-        self.enhancement_progress
+        app.menu = None;
+        app.accept_held_dataset();
+        assert!(app.held_dataset.is_none());
+        assert_eq!(app.get_repositories().len(), 1);
+        assert_eq!(app.personal_repositories.as_ref().unwrap().len(), 1);
     }
 
-    /// Get the current error message, if any
-    pub fn get_error_message(&self) -> Option<&str> {
-        self.error_message.as_deref()
+    #[test]
+    fn test_guest_mode_is_read_only() {
+        let mut app = App::with_config(AppConfig::default());
+        let mut private = Repository::new("payments-api".to_string(), "acme".to_string());
+        private.private = true;
+        let docs = Repository::new("docs".to_string(), "acme".to_string());
+        app.set_repositories(vec![private, docs]);
+        assert_eq!(
+            app.display_repository("acme/payments-api"),
+            "acme/payments-api"
+        );
+
+        app.handle_key_event(KeyCode::Char('p'));
+        assert!(app.guest_mode);
+        assert_ne!(
+            app.display_repository("acme/payments-api"),
+            "acme/payments-api"
+        );
+        // Visibility of a repository that isn't loaded is unknown
+        assert!(app
+            .display_repository("acme/unknown")
+            .starts_with("acme/private-"));
+        assert_eq!(app.display_repository("acme/docs"), "acme/docs");
+
+        // Snoozing would write the config file, so it's refused
+        app.handle_key_event(KeyCode::Char('z'));
+        assert!(app
+            .status_message
+            .as_deref()
+            .unwrap()
+            .contains("guest mode"));
+        assert!(app.config.attention.snoozed.is_empty());
+
+        app.handle_key_event(KeyCode::Char('p'));
+        assert!(!app.guest_mode);
     }
 
-    /// Clear the current error message
-    pub fn clear_error(&mut self) {
-        self.error_message = None;
+    #[test]
+    fn test_demo_refresh_loads_sample_repositories() {
+        let mut app = App::with_config(AppConfig::default());
+        app.github_client = None;
+        app.demo = true;
+        app.refresh();
+
+        assert_eq!(app.completed_refreshes, 1);
+        assert!(!app.is_loading);
+        assert!(app
+            .get_repositories()
+            .iter()
+            .all(|repo| repo.owner == crate::demo::DEMO_OWNER));
+        let last = app.get_repositories().last().unwrap();
+        assert_eq!(
+            app.row_cache.position(&last.full_name()),
+            Some(app.get_repositories().len() - 1)
+        );
+
+        // Nothing is written back while exploring
+        app.handle_key_event(KeyCode::Char('z'));
+        assert!(app.config.attention.snoozed.is_empty());
     }
 
-    /// Get the number of repositories currently loaded
-    pub fn repository_count(&self) -> usize {
-        self.repositories.len()
+    #[test]
+    fn test_startup_mode_and_view() {
+        let mut config = AppConfig::default();
+        config.views.push(SmartViewConfig {
+            name: "Rust".to_string(),
+            sort: RepositorySort::Size,
+            ..SmartViewConfig::default()
+        });
+        config.startup.mode = "beta".to_string();
+        config.startup.view = Some("Rust".to_string());
+        let mut app = App::with_config(config);
+        app.github_client = None;
+        assert_eq!(
+            app.repo_view_mode,
+            RepositoryViewMode::Organization("beta".to_string())
+        );
+        assert_eq!(app.active_smart_view().unwrap().name, "Rust");
+        assert_eq!(app.sort, RepositorySort::Size);
+
+        // Once organizations arrive, Tab continues from the startup org
+        app.handle_background_message(BackgroundMessage::OrganizationsFetched {
+            organizations: vec!["alpha".to_string(), "beta".to_string()],
+        });
+        app.cycle_view_mode();
+        assert_eq!(app.repo_view_mode, RepositoryViewMode::All);
+        app.cycle_view_mode();
+        assert_eq!(app.repo_view_mode, RepositoryViewMode::Personal);
     }
 
-    /// Get the currently selected repository index
-    pub fn selected_repository(&self) -> usize {
-        self.selected_repository
+    #[test]
+    fn test_organizations_prefetched_by_usefulness() {
+        let history = std::env::temp_dir().join(format!(
+            "gh-repo-healthchecks-prefetch-{}.json",
+            std::process::id()
+        ));
+        let mut config = AppConfig::default();
+        config.prefetch.organizations = 2;
+        config.prefetch.history = Some(history.clone());
+        let mut app = App::with_config(config);
+        app.github_client = None;
+        app.repo_view_mode = RepositoryViewMode::Organization("alpha".to_string());
+        app.organization_repositories
+            .insert("delta".to_string(), Vec::new());
+        app.org_history.loaded("gamma", 4, 30);
+        app.org_history.viewed("beta", chrono::Utc::now());
+
+        // The organization shown and cached ones aren't prefetched
+        let organizations = ["alpha", "beta", "gamma", "delta", "omega"];
+        app.handle_background_message(BackgroundMessage::OrganizationsFetched {
+            organizations: organizations.iter().map(|org| org.to_string()).collect(),
+        });
+        assert_eq!(
+            app.prefetch_queue,
+            Some(vec!["beta".to_string(), "gamma".to_string()])
+        );
+
+        // A prefetched organization opens from the cache
+        app.handle_background_message(BackgroundMessage::OrganizationPrefetched {
+            org: "beta".to_string(),
+            result: Ok(vec![Repository::new("api".to_string(), "beta".to_string())]),
+            requests: 12,
+        });
+        assert_eq!(app.org_history.expected_requests("beta"), 12);
+        app.cycle_view_mode();
+        assert_eq!(
+            app.repo_view_mode,
+            RepositoryViewMode::Organization("beta".to_string())
+        );
+        assert_eq!(app.repositories.len(), 1);
+        assert!(!app.is_loading);
+        let _ = std::fs::remove_file(history);
     }
 
-    /// Move selection to the next repository
-    pub fn select_next_repository(&mut self) {
-        if !self.repositories.is_empty() {
-            self.selected_repository = (self.selected_repository + 1) % self.repositories.len();
-        }
+    #[test]
+    fn test_membership_report_for_another_organization_is_dropped() {
+        let mut app = App::with_config(AppConfig::default());
+        app.github_client = None;
+        app.repo_view_mode = RepositoryViewMode::Organization("beta".to_string());
+        app.is_fetching_org_membership = true;
+
+        // The report requested before switching from alpha to beta
+        app.handle_background_message(BackgroundMessage::OrgMembershipFetched {
+            health: OrgMembershipHealth::new("alpha".to_string()),
+        });
+        assert!(app.org_membership.is_none());
+        assert!(!app.is_fetching_org_membership);
+
+        app.handle_background_message(BackgroundMessage::OrgMembershipFetched {
+            health: OrgMembershipHealth::new("beta".to_string()),
+        });
+        assert_eq!(
+            app.org_membership.map(|health| health.org),
+            Some("beta".to_string())
+        );
     }
 
-    /// Move selection to the previous repository  
-    pub fn select_previous_repository(&mut self) {
-        if !self.repositories.is_empty() {
-            if self.selected_repository == 0 {
-                self.selected_repository = self.repositories.len() - 1;
-            } else {
-                self.selected_repository -= 1;
-            }
-        }
+    #[test]
+    fn test_startup_timings_follow_first_load() {
+        let mut app = App::with_config(AppConfig::default());
+        app.github_client = None;
+        let repository = Repository::new("api".to_string(), "octocat".to_string());
+
+        app.handle_background_message(BackgroundMessage::RepositoryFetched {
+            repository: repository.clone(),
+            current: 1,
+            total: 1,
+        });
+        app.handle_background_message(BackgroundMessage::FetchCompleted {
+            repositories: vec![repository],
+        });
+        assert!(app
+            .startup
+            .elapsed(StartupMilestone::FirstRepository)
+            .is_some());
+        assert!(app
+            .startup
+            .elapsed(StartupMilestone::RepositoryList)
+            .is_some());
+        assert!(app
+            .startup
+            .elapsed(StartupMilestone::DetailsLoaded)
+            .is_none());
+
+        // A failed organization lookup doesn't leave Tab waiting forever
+        app.is_fetching_organizations = true;
+        app.handle_background_message(BackgroundMessage::ActionError {
+            error: "Failed to fetch organizations: boom".to_string(),
+        });
+        assert!(!app.is_fetching_organizations);
     }
 
-    /// Get a title with repository stats
-    pub fn get_title_with_stats(&self) -> String {
-        if self.repositories.is_empty() && !self.is_loading {
-            format!("{} — No repositories found", self.title)
-        } else if self.is_loading {
-            format!("{} — Loading...", self.title)
-        } else {
-            let active_count = self
-                .repositories
+    #[test]
+    fn test_comparison_panes_are_independent() {
+        let mut config = AppConfig::default();
+        config
+            .groups
+            .insert("payments".to_string(), vec!["billing".to_string()]);
+        config.groups.insert(
+            "platform".to_string(),
+            vec!["infra".to_string(), "acme/ci".to_string()],
+        );
+        let mut app = App::with_config(config);
+        app.set_repositories(
+            ["billing", "infra", "ci"]
                 .iter()
-                .filter(|repo| !repo.open_pull_requests.is_empty())
-                .count();
+                .map(|name| Repository::new(name.to_string(), "acme".to_string()))
+                .collect(),
+        );
 
-            format!(
-                "{} — {} repos ({} active)",
-                self.title,
-                self.repositories.len(),
-                active_count
-            )
-        }
+        assert!(app.handle_key_event(KeyCode::Char('v')));
+        assert_eq!(app.current_view, AppView::Comparison);
+        assert_eq!(
+            app.comparison_panes[0].source,
+            ComparisonSource::Group("payments".to_string())
+        );
+        assert_eq!(app.comparison_panes[1].repositories.len(), 2);
+
+        // Navigation only moves the focused pane's selection
+        app.handle_key_event(KeyCode::Tab);
+        app.handle_key_event(KeyCode::Down);
+        assert_eq!(app.comparison_panes[0].selected, 0);
+        assert_eq!(app.comparison_panes[1].selected, 1);
+
+        app.handle_key_event(KeyCode::Right);
+        assert_eq!(
+            app.comparison_panes[1].source,
+            ComparisonSource::Group("payments".to_string())
+        );
+        assert_eq!(app.comparison_panes[1].selected, 0);
+
+        app.handle_key_event(KeyCode::Esc);
+        assert_eq!(app.current_view, AppView::Dashboard);
     }
 
-    /// Update loading progress
-    pub fn set_loading_progress(&mut self, current: usize, total: usize) {
-        self.loading_progress = Some((current, total));
+    #[test]
+    fn test_release_board_groups_demo_pull_requests() {
+        let mut app = App::with_config(AppConfig::default());
+        app.set_repositories(crate::demo::repositories(SystemTime::now()));
+
+        assert!(app.handle_key_event(KeyCode::Char('b')));
+        assert_eq!(app.current_view, AppView::ReleaseBoard);
+        let releases = app.release_readiness();
+        assert_eq!(releases.len(), 1);
+        let release = &releases[0];
+        assert_eq!(release.release, "v3.5");
+        assert_eq!((release.ready(), release.total()), (1, 3));
+        assert_eq!(
+            release.failing_repositories(),
+            vec!["demo-org/legacy-billing", "demo-org/payments-api"]
+        );
+
+        app.handle_key_event(KeyCode::Esc);
+        assert_eq!(app.current_view, AppView::Dashboard);
     }
 
-    /// Clear loading progress
-    pub fn clear_loading_progress(&mut self) {
-        self.loading_progress = None;
+    #[test]
+    fn test_burndown_window_cycles() {
+        let mut app = App::with_config(AppConfig::default());
+        app.set_repositories(vec![Repository::new(
+            "repo".to_string(),
+            "owner".to_string(),
+        )]);
+        app.current_view = AppView::RepoDetails;
+
+        assert!(app.handle_key_event(KeyCode::Char('b')));
+        assert_eq!(app.current_view, AppView::IssueBurndown);
+        assert_eq!(app.burndown_weeks, 12);
+
+        app.handle_key_event(KeyCode::Char('w'));
+        assert_eq!(app.burndown_weeks, 26);
+        app.handle_key_event(KeyCode::Char('w'));
+        app.handle_key_event(KeyCode::Char('w'));
+        assert_eq!(app.burndown_weeks, 4);
+
+        // Results for a stale window are ignored
+        app.handle_background_message(BackgroundMessage::IssueActivityFetched {
+            repository: "owner/repo".to_string(),
+            weeks: 12,
+            issues: Vec::new(),
+        });
+        assert!(app.issue_activity.is_none());
+
+        assert!(app.handle_key_event(KeyCode::Esc));
+        assert_eq!(app.current_view, AppView::RepoDetails);
     }
 
-    /// Get visible items count based on the area height
-    pub fn get_visible_item_count(&self, area_height: usize) -> usize {
-        // Account for header row
-        if area_height > 1 {
-            area_height - 1
-        } else {
-            0
+    #[test]
+    fn test_workflow_timeline_zoom() {
+        let started_at = SystemTime::now() - std::time::Duration::from_secs(30 * 3600);
+        let mut repo = Repository::new("repo".to_string(), "owner".to_string());
+        repo.recent_workflows = vec![WorkflowRun {
+            id: 1,
+            name: "CI".to_string(),
+            status: WorkflowStatus::Success,
+            created_at: started_at,
+            updated_at: started_at,
+            started_at: Some(started_at),
+            duration: Some(std::time::Duration::from_secs(300)),
+            trigger: RunTrigger::default(),
+        }];
+        let mut app = App::with_config(AppConfig::default());
+        app.set_repositories(vec![repo]);
+        app.current_view = AppView::RepoDetails;
+
+        // Opens zoomed out far enough to show the run from yesterday
+        assert!(app.handle_key_event(KeyCode::Char('w')));
+        assert_eq!(app.current_view, AppView::WorkflowTimeline);
+        assert_eq!(app.timeline_hours, 72);
+
+        app.handle_key_event(KeyCode::Char('+'));
+        assert_eq!(app.timeline_hours, 24);
+        for _ in 0..5 {
+            app.handle_key_event(KeyCode::Char('+'));
         }
+        assert_eq!(app.timeline_hours, 1);
+        app.handle_key_event(KeyCode::Char('-'));
+        assert_eq!(app.timeline_hours, 6);
+
+        assert!(app.handle_key_event(KeyCode::Esc));
+        assert_eq!(app.current_view, AppView::RepoDetails);
     }
 
-    /// Scroll up in the repository list
-    pub fn scroll_up(&mut self) {
-        if self.scroll_offset > 0 {
-            self.scroll_offset -= 1;
-        }
+    #[test]
+    fn test_dialog_captures_keys() {
+        let mut config = AppConfig::default();
+        config.compliance.template = Some("acme/template".to_string());
+        let mut app = App::with_config(config);
+        app.repo_view_mode = RepositoryViewMode::Organization("acme".to_string());
+
+        assert!(app.handle_key_event(KeyCode::Char('n')));
+        assert!(app.dialog.is_some());
+
+        // Shortcuts are typed into the dialog instead of triggering
+        app.handle_key_event(KeyCode::Char('q'));
+        assert!(!app.should_quit());
+        assert_eq!(app.dialog.as_ref().unwrap().fields[0].value(), "q");
+
+        app.handle_key_event(KeyCode::Esc);
+        assert!(app.dialog.is_none());
+        assert_eq!(app.current_view, AppView::Dashboard);
     }
 
-    /// Scroll down in the repository list
-    pub fn scroll_down(&mut self, visible_items: usize) {
-        let max_scroll = self.repositories.len().saturating_sub(visible_items);
-        if self.scroll_offset < max_scroll {
-            self.scroll_offset += 1;
-        }
+    #[test]
+    fn test_sort_by_size() {
+        let mut app = App::with_config(AppConfig::default());
+        let repositories = [("small", 10), ("huge", 5_000_000), ("medium", 40_000)]
+            .iter()
+            .map(|(name, size_kb)| {
+                let mut repo = Repository::new(name.to_string(), "acme".to_string());
+                repo.size_kb = *size_kb;
+                repo
+            })
+            .collect();
+        app.set_repositories(repositories);
+        assert_eq!(app.get_selected_repository().unwrap().name, "small");
+
+        app.handle_key_event(KeyCode::Char('s'));
+        assert_eq!(app.sort, RepositorySort::Size);
+        let names: Vec<&str> = app
+            .visible_repositories
+            .iter()
+            .map(|&index| app.repositories[index].name.as_str())
+            .collect();
+        assert_eq!(names, vec!["huge", "medium", "small"]);
+        // The selection follows the repository, not the row
+        assert_eq!(app.get_selected_repository().unwrap().name, "small");
+
+        app.handle_key_event(KeyCode::Char('s'));
+        assert_eq!(app.visible_repositories, vec![0, 1, 2]);
     }
 
-    /// Ensure the selected repository is visible
-    pub fn ensure_selected_visible(&mut self, visible_items: usize) {
-        // If selected is above viewport, scroll up
-        if self.selected_repository < self.scroll_offset {
-            self.scroll_offset = self.selected_repository;
-        }
+    #[test]
+    fn test_sort_menu_orders_by_two_keys() {
+        let now = SystemTime::now();
+        let mut app = App::with_config(AppConfig::default());
+        let repositories = [
+            ("web", RepositoryStatus::Stale, 30),
+            ("legacy", RepositoryStatus::Dormant, 400),
+            ("api", RepositoryStatus::Stale, 90),
+            ("docs", RepositoryStatus::Dormant, 200),
+        ]
+        .iter()
+        .map(|(name, status, days)| {
+            let mut repo = Repository::new(name.to_string(), "acme".to_string());
+            repo.status = status.clone();
+            repo.latest_commit_at = Some(now - std::time::Duration::from_secs(days * 86400));
+            repo
+        })
+        .collect();
+        app.set_repositories(repositories);
+        let names = |app: &App| -> Vec<String> {
+            app.visible_repositories
+                .iter()
+                .map(|&index| app.repositories[index].name.clone())
+                .collect()
+        };
+        let choose = |app: &mut App, action: MenuAction| {
+            let menu = app.menu.as_mut().unwrap();
+            menu.selected = menu
+                .items
+                .iter()
+                .position(|item| item.action == action)
+                .unwrap();
+            app.handle_key_event(KeyCode::Enter);
+        };
 
-        // If selected is below viewport, scroll down
-        if self.selected_repository >= self.scroll_offset + visible_items {
-            self.scroll_offset = self.selected_repository.saturating_sub(visible_items) + 1;
-        }
+        assert!(app.handle_key_event(KeyCode::Char('S')));
+        choose(&mut app, MenuAction::SortBy(RepositorySort::Status));
+        // Ties keep GitHub's order
+        assert_eq!(names(&app), vec!["legacy", "docs", "web", "api"]);
+
+        // Oldest activity first among repositories with the same status
+        choose(
+            &mut app,
+            MenuAction::ThenSortBy(Some(RepositorySort::Activity)),
+        );
+        choose(
+            &mut app,
+            MenuAction::ThenSortBy(Some(RepositorySort::Activity)),
+        );
+        assert_eq!(app.secondary_sort, Some((RepositorySort::Activity, false)));
+        assert_eq!(names(&app), vec!["legacy", "docs", "api", "web"]);
+        assert_eq!(app.sort_label(), "Status ▼, Activity ▲");
+        assert_eq!(
+            app.menu.as_ref().unwrap().title,
+            "Sort: Status ▼, Activity ▲"
+        );
+
+        // Choosing the primary key again flips it
+        choose(&mut app, MenuAction::SortBy(RepositorySort::Status));
+        assert_eq!(names(&app), vec!["api", "web", "legacy", "docs"]);
+        app.handle_key_event(KeyCode::Esc);
+        assert!(app.menu.is_none());
     }
 
-    /// Set up background task processing
-    pub fn setup_background_processing(&mut self) -> mpsc::UnboundedSender<BackgroundMessage> {
-        let (sender, receiver) = mpsc::unbounded_channel();
-        self.background_receiver = Some(receiver);
-        sender
+    #[test]
+    fn test_pull_request_files_sort_by_churn() {
+        let mut app = App::with_config(AppConfig::default());
+        app.current_view = AppView::PullRequestFiles;
+        app.pull_request_files = [
+            ("Cargo.lock", 3, 1),
+            ("src/app.rs", 120, 40),
+            ("README.md", 10, 0),
+        ]
+        .iter()
+        .map(|(path, additions, deletions)| ChangedFile {
+            path: path.to_string(),
+            status: "modified".to_string(),
+            additions: *additions,
+            deletions: *deletions,
+        })
+        .collect();
+
+        let paths = |app: &App| -> Vec<String> {
+            app.sorted_pull_request_files()
+                .iter()
+                .map(|file| file.path.clone())
+                .collect()
+        };
+        assert_eq!(paths(&app), vec!["Cargo.lock", "src/app.rs", "README.md"]);
+
+        assert!(app.handle_key_event(KeyCode::Char('s')));
+        assert_eq!(paths(&app), vec!["src/app.rs", "README.md", "Cargo.lock"]);
+
+        app.handle_key_event(KeyCode::Esc);
+        assert_eq!(app.current_view, AppView::PullRequestDetails);
     }
 
-    /// Process any pending background messages
-    pub fn process_background_messages(&mut self) {
-        if let Some(receiver) = &mut self.background_receiver {
-            while let Ok(message) = receiver.try_recv() {
-                match message {
-                    BackgroundMessage::FetchStarted { total } => {
-                        self.is_loading = true;
-                        self.loading_progress = Some((0, total));
-                        self.error_message = None;
-                    }
-                    BackgroundMessage::RepositoryFetched {
-                        repository,
-                        current,
-                        total,
-                    } => {
-                        self.repositories.push(repository);
-                        self.loading_progress = Some((current, total));
-                    }
-                    BackgroundMessage::FetchCompleted { repositories } => {
-                        self.repositories = repositories.clone();
-                        // Cache the repositories based on current mode
-                        match &self.repo_view_mode {
-                            RepositoryViewMode::Personal => {
-                                self.personal_repositories = Some(repositories);
-                            }
-                            RepositoryViewMode::Organization(org_name) => {
-                                self.organization_repositories
-                                    .insert(org_name.clone(), repositories);
-                            }
-                        }
-                        // We've loaded basic data, but will start enhancing
-                        self.is_loading = false;
-                        self.loading_progress = None;
-                        self.last_refresh = Some(std::time::Instant::now());
-                    }
-                    BackgroundMessage::FetchError { error } => {
-                        self.error_message = Some(error);
-                        self.is_loading = false;
-                        self.is_enhancing = false;
-                        self.loading_progress = None;
-                        self.enhancement_progress = None;
-                    }
-                    BackgroundMessage::EnhancementStarted { total } => {
-                        // We already have basic data and are now enhancing
-                        self.is_enhancing = true;
-                        self.enhancement_progress = Some((0, total));
-                    }
-                    BackgroundMessage::RepositoryEnhanced {
-                        repository,
-                        current,
-                        total,
-                    } => {
-                        // Find and replace the repository with the enhanced version
-                        if let Some(index) = self
-                            .repositories
-                            .iter()
-                            .position(|r| r.name == repository.name)
-                        {
-                            self.repositories[index] = repository;
-                        }
-                        self.enhancement_progress = Some((current, total));
-                    }
-                    BackgroundMessage::EnhancementCompleted { repositories } => {
-                        self.repositories = repositories;
-                        self.is_enhancing = false;
-                        self.enhancement_progress = None;
-                        self.last_refresh = Some(std::time::Instant::now());
-                    }
-                    BackgroundMessage::OrganizationsFetchStarted => {
-                        self.is_fetching_organizations = true;
-                    }
-                    BackgroundMessage::OrganizationsFetched { organizations } => {
-                        self.user_organizations = organizations;
-                        self.is_fetching_organizations = false;
-                        self.error_message = None;
-                        
-                        // If the user was trying to cycle but we had no organizations,
-                        // now we can start cycling
-                        if !self.user_organizations.is_empty() {
-                            // The user will need to press Tab again to start cycling
-                            // This is more predictable than auto-cycling
-                        }
-                    }
-                }
-            }
-        }
+    #[test]
+    fn test_repository_menu() {
+        let mut app = App::with_config(AppConfig::default());
+        app.set_repositories(vec![Repository::new("api".to_string(), "acme".to_string())]);
+
+        assert!(app.handle_key_event(KeyCode::Char('.')));
+        let menu = app.menu.as_ref().unwrap();
+        assert_eq!(menu.title, "acme/api");
+        assert_eq!(
+            menu.items[1].action,
+            MenuAction::OpenUrl("https://github.com/acme/api/actions".to_string())
+        );
+        assert!(app.is_modal_open());
+
+        // The menu captures keys until dismissed
+        app.handle_key_event(KeyCode::Down);
+        assert_eq!(app.menu.as_ref().unwrap().selected, 1);
+        assert_eq!(app.current_view, AppView::Dashboard);
+        app.handle_key_event(KeyCode::Esc);
+        assert!(app.menu.is_none());
+        assert!(!app.should_quit());
     }
 
-    /// Cycle between repository view modes
-    pub fn cycle_view_mode(&mut self) {
-        // If we don't have organizations yet and have a GitHub client, try to fetch them first
-        if self.user_organizations.is_empty() && self.github_client.is_some() {
-            self.fetch_user_organizations();
-            // If we still have no organizations, there's nothing to cycle to
-            // so just stay in Personal mode for now
-            return;
-        }
+    #[test]
+    fn test_inspect_popover_toggle() {
+        let mut app = App::with_config(AppConfig::default());
+        assert!(app.handle_key_event(KeyCode::Char('i')));
+        assert!(!app.show_inspect, "nothing to inspect without a selection");
 
-        // If no organizations available (either no GitHub client or no orgs found), 
-        // just stay in Personal mode
-        if self.user_organizations.is_empty() {
-            return;
-        }
+        app.set_repositories(vec![Repository::new(
+            "repo".to_string(),
+            "owner".to_string(),
+        )]);
+        app.handle_key_event(KeyCode::Char('i'));
+        assert!(app.show_inspect);
 
-        // Cycle through: Personal -> Org1 -> Org2 -> ... -> Personal
-        let total_modes = 1 + self.user_organizations.len(); // 1 for Personal + N orgs
-        self.current_org_index = (self.current_org_index + 1) % total_modes;
+        // Esc closes the popover instead of quitting
+        app.handle_key_event(KeyCode::Esc);
+        assert!(!app.show_inspect);
+        assert!(!app.should_quit());
+    }
 
-        // Update the view mode based on current index
-        self.repo_view_mode = if self.current_org_index == 0 {
-            RepositoryViewMode::Personal
-        } else {
-            let org_name = self.user_organizations[self.current_org_index - 1].clone();
-            RepositoryViewMode::Organization(org_name)
-        };
+    #[test]
+    fn test_metrics_overlay_toggle() {
+        let mut app = App::new();
 
-        // Switch to the new view
-        self.switch_to_current_view();
+        assert!(app.handle_key_event(KeyCode::Char('d')));
+        assert!(app.show_metrics);
 
-        // Reset selection and scroll when switching modes
-        self.selected_repository = 0;
-        self.scroll_offset = 0;
+        // Esc closes the overlay instead of quitting
+        app.handle_key_event(KeyCode::Esc);
+        assert!(!app.show_metrics);
+        assert!(!app.should_quit());
     }
 
-    /// Switch to the current view mode
-    fn switch_to_current_view(&mut self) {
-        match &self.repo_view_mode {
-            RepositoryViewMode::Personal => {
-                if let Some(cached_repos) = &self.personal_repositories {
-                    // Use cached data
-                    self.repositories = cached_repos.clone();
-                } else {
-                    // Need to fetch personal repositories
-                    self.fetch_repositories_for_current_mode();
-                }
-            }
-            RepositoryViewMode::Organization(org_name) => {
-                if let Some(cached_repos) = self.organization_repositories.get(org_name) {
-                    // Use cached data
-                    self.repositories = cached_repos.clone();
-                } else {
-                    // Need to fetch organization repositories
-                    self.fetch_repositories_for_current_mode();
-                }
-            }
-        }
+    #[test]
+    fn test_failed_check_rerequest_restores_status() {
+        let mut app = App::new();
+        app.check_runs.push(CheckRun {
+            id: 7,
+            name: "lint".to_string(),
+            status: WorkflowStatus::InProgress,
+        });
+        app.pending_check_reruns.insert(7, WorkflowStatus::Failed);
+
+        app.handle_background_message(BackgroundMessage::CheckRunRerequestFailed {
+            check_run_id: 7,
+            error: "forbidden".to_string(),
+        });
+
+        assert_eq!(app.check_runs[0].status, WorkflowStatus::Failed);
+        assert_eq!(app.status_message.as_deref(), Some("forbidden"));
     }
 
-    /// Fetch the list of organizations the user belongs to
-    fn fetch_user_organizations(&mut self) {
-        if let Some(client) = self.github_client.clone() {
-            let sender = self.setup_background_processing();
-            
-            // Send start message immediately
-            let _ = sender.send(BackgroundMessage::OrganizationsFetchStarted);
-            
-            tokio::spawn(async move {
-                match client.get_user_organizations().await {
-                    Ok(orgs) => {
-                        // Send a message to update the organizations list
-                        let _ = sender.send(BackgroundMessage::OrganizationsFetched { organizations: orgs });
-                    }
-                    Err(e) => {
-                        let _ = sender.send(BackgroundMessage::FetchError { 
-                            error: format!("Failed to fetch organizations: {}", e) 
-                        });
-                    }
-                }
-            });
-        }
+    #[test]
+    fn test_review_dialog_for_selected_pull_request() {
+        let mut app = App::with_config(AppConfig::default());
+        let mut repo = Repository::new("api".to_string(), "acme".to_string());
+        repo.open_pull_requests.push(crate::models::PullRequest {
+            number: 42,
+            title: "Add retries".to_string(),
+            state: crate::models::PullRequestState::Open,
+            created_at: SystemTime::now(),
+            updated_at: SystemTime::now(),
+            author: "octocat".to_string(),
+            html_url: String::new(),
+            draft: false,
+            approvals: 0,
+            changes_requested: 0,
+            head_sha: String::new(),
+            base_branch: "main".to_string(),
+            linked_issues: Vec::new(),
+            tasks: None,
+            feedback_waiting_since: None,
+            review_decision: None,
+            checks: None,
+            labels: Vec::new(),
+            milestone: None,
+        });
+        app.set_repositories(vec![repo]);
+        app.current_view = AppView::PullRequestDetails;
+
+        assert!(app.handle_key_event(KeyCode::Char('a')));
+        let dialog = app.dialog.as_ref().unwrap();
+        assert_eq!(dialog.title, "Review #42 Add retries");
+        assert_eq!(
+            dialog.action,
+            DialogAction::ReviewPullRequest {
+                owner: "acme".to_string(),
+                repo: "api".to_string(),
+                number: 42,
+            }
+        );
+        assert_eq!(dialog.fields[0].value(), "approve");
+
+        app.handle_key_event(KeyCode::Esc);
+        assert!(app.dialog.is_none());
+        assert_eq!(app.current_view, AppView::PullRequestDetails);
     }
 
-    /// Fetch repositories for the current view mode
-    fn fetch_repositories_for_current_mode(&mut self) {
-        if let Some(client) = self.github_client.clone() {
-            // Clear current repositories and show loading
-            self.repositories.clear();
-            self.is_loading = true;
-            self.error_message = None;
-            self.loading_progress = None;
+    #[test]
+    fn test_issue_search_filters_and_triages_across_repositories() {
+        let mut app = App::with_config(AppConfig::default());
+        app.set_repositories(vec![Repository::new("api".to_string(), "acme".to_string())]);
+        assert!(app.handle_key_event(KeyCode::Char('l')));
+        assert_eq!(app.current_view, AppView::IssueSearch);
 
-            // Setup background processing channel
-            let sender = self.setup_background_processing();
+        let searched = |repository: &str, number: u64| SearchedIssue {
+            repository: repository.to_string(),
+            issue: Issue {
+                number,
+                title: "Flaky deploys".to_string(),
+                html_url: String::new(),
+                assignees: Vec::new(),
+                labels: vec!["bug".to_string()],
+                comments: 0,
+            },
+            created_at: SystemTime::now(),
+        };
+        app.handle_background_message(BackgroundMessage::IssuesSearched {
+            filter: IssueSearchFilter::default(),
+            issues: vec![searched("acme/api", 3), searched("acme/web", 9)],
+            total: 2,
+        });
+        app.handle_key_event(KeyCode::Down);
 
-            // Spawn background task based on current mode
-            match &self.repo_view_mode {
-                RepositoryViewMode::Personal => {
-                    crate::github::GitHubClient::spawn_background_fetch(client, sender);
-                }
-                RepositoryViewMode::Organization(org_name) => {
-                    crate::github::GitHubClient::spawn_background_fetch_organization(
-                        client,
-                        sender,
-                        org_name.clone(),
-                    );
-                }
+        // Triage acts on the selected result's repository, not the dashboard's
+        app.handle_key_event(KeyCode::Char('a'));
+        assert_eq!(
+            app.dialog.as_ref().unwrap().action,
+            DialogAction::TriageIssue {
+                owner: "acme".to_string(),
+                repo: "web".to_string(),
+                number: 9,
+                kind: IssueTriage::Assign,
             }
+        );
+        app.handle_key_event(KeyCode::Esc);
+
+        app.handle_key_event(KeyCode::Char('f'));
+        for key in "bug\t@hubot\t30".chars() {
+            app.handle_key_event(match key {
+                '\t' => KeyCode::Tab,
+                key => KeyCode::Char(key),
+            });
         }
+        app.handle_key_event(KeyCode::Enter);
+        let filter = IssueSearchFilter {
+            label: Some("bug".to_string()),
+            assignee: Some("hubot".to_string()),
+            min_age_days: Some(30),
+        };
+        assert_eq!(app.issue_search_filter, filter);
+        assert_eq!(app.selected_searched_issue, 0);
+
+        // Results of a search with the old filters are dropped
+        app.handle_background_message(BackgroundMessage::IssuesSearched {
+            filter: IssueSearchFilter::default(),
+            issues: Vec::new(),
+            total: 0,
+        });
+        assert_eq!(app.searched_issues.as_ref().unwrap().0.len(), 2);
+
+        // The dialog starts from the current filters
+        app.handle_key_event(KeyCode::Char('f'));
+        let values: Vec<&str> = app
+            .dialog
+            .as_ref()
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| f.value())
+            .collect();
+        assert_eq!(values, vec!["bug", "hubot", "30"]);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_label_edits_can_be_undone() {
+        let mut app = App::with_config(AppConfig::default());
+        app.github_client = None;
+        app.handle_key_event(KeyCode::Char('u'));
+        assert_eq!(app.status_message.as_deref(), Some("Nothing to undo"));
+
+        let labelled = |labels: &[&str]| Issue {
+            number: 3,
+            title: "Flaky deploys".to_string(),
+            html_url: String::new(),
+            assignees: Vec::new(),
+            labels: labels.iter().map(|label| label.to_string()).collect(),
+            comments: 0,
+        };
+        let labels = |labels: &[&str]| labels.iter().map(|label| label.to_string()).collect();
+        app.open_issues = Some(("acme/api".to_string(), vec![labelled(&["bug", "triage"])]));
+
+        // bug was already there and wontfix never was, so only docs and
+        // triage changed
+        app.handle_background_message(BackgroundMessage::IssueTriaged {
+            repository: "acme/api".to_string(),
+            issue: labelled(&["bug", "docs"]),
+            action: IssueAction::Label {
+                add: labels(&["bug", "docs"]),
+                remove: labels(&["triage", "wontfix"]),
+            },
+        });
+        // Comments can't be taken back
+        app.handle_background_message(BackgroundMessage::IssueTriaged {
+            repository: "acme/api".to_string(),
+            issue: labelled(&["bug", "docs"]),
+            action: IssueAction::Comment("On it".to_string()),
+        });
+        // An edit that changed nothing has nothing to undo
+        app.handle_background_message(BackgroundMessage::IssueTriaged {
+            repository: "acme/api".to_string(),
+            issue: labelled(&["bug", "docs"]),
+            action: IssueAction::Label {
+                add: labels(&["bug"]),
+                remove: labels(&["triage"]),
+            },
+        });
+        assert_eq!(
+            app.undo_stack.history().next().unwrap().label_revert(),
+            Some(IssueAction::Label {
+                add: labels(&["triage"]),
+                remove: labels(&["docs"]),
+            })
+        );
+
+        app.handle_key_event(KeyCode::Char('U'));
+        let labels: Vec<&str> = app
+            .menu
+            .as_ref()
+            .unwrap()
+            .items
+            .iter()
+            .map(|item| item.label.as_str())
+            .collect();
+        assert_eq!(labels, vec!["Labelled acme/api#3 +docs -triage"]);
+        app.handle_key_event(KeyCode::Esc);
+
+        // Reverting needs GitHub, so the action stays for another try
+        app.handle_key_event(KeyCode::Char('u'));
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("GitHub client not available")
+        );
+        assert!(!app.undo_stack.is_empty());
+
+        // Nothing is written back in guest mode
+        app.guest_mode = true;
+        app.handle_key_event(KeyCode::Char('u'));
+        assert!(!app.undo_stack.is_empty());
+    }
 
     #[test]
-    fn test_app_creation() {
-        let app = App::new();
-        assert!(!app.should_quit());
-        assert_eq!(app.current_view, AppView::Dashboard);
+    fn test_snoozes_and_collector_toggles_can_be_undone() {
+        let path = std::env::temp_dir().join(format!(
+            "gh-repo-healthchecks-undo-{}.toml",
+            std::process::id()
+        ));
+        let mut app = App::with_config(AppConfig {
+            file: Some(path.clone()),
+            ..AppConfig::default()
+        });
+        let today = chrono::Local::now().date_naive();
+        let week = today + chrono::Days::new(7);
+        let month = today + chrono::Days::new(30);
+
+        app.snooze_repository("acme/api", week);
+        app.snooze_repository("acme/api", month);
+        app.undo_last();
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Undone: Snoozed acme/api")
+        );
+        assert_eq!(app.config.attention.snooze_end("acme/api"), Some(week));
+        // Undoing the first snooze wakes the repository up
+        app.undo_last();
+        assert_eq!(app.config.attention.snooze_end("acme/api"), Some(today));
+
+        app.toggle_collector(Collector::Lfs);
+        assert!(!app.config.collector_enabled(Collector::Lfs));
+        app.undo_last();
+        assert!(app.config.collector_enabled(Collector::Lfs));
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Undone: Switched Git LFS detection off")
+        );
+        assert!(app.undo_stack.is_empty());
+
+        // The file was updated along the way
+        let saved = AppConfig::parse(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert!(saved.collector_enabled(Collector::Lfs));
+        assert_eq!(saved.attention.snooze_end("acme/api"), Some(today));
+        std::fs::remove_file(&path).unwrap();
     }
 
     #[test]
-    fn test_quit_key_handling() {
-        let mut app = App::new();
+    fn test_rate_limit_pause_blocks_restart() {
+        let mut app = App::with_config(AppConfig::default());
+        let resume_at = SystemTime::now() + std::time::Duration::from_secs(185);
+        app.handle_background_message(BackgroundMessage::EnhancementPaused {
+            current: 12,
+            total: 40,
+            resume_at,
+        });
 
-        // Test 'q' key
-        assert!(app.handle_key_event(KeyCode::Char('q')));
-        assert!(app.should_quit());
+        let pause = app.rate_limit_pause.clone().unwrap();
+        assert_eq!(
+            pause.countdown(resume_at - std::time::Duration::from_secs(185)),
+            "3m 05s"
+        );
+        assert_eq!(
+            pause.countdown(resume_at + std::time::Duration::from_secs(5)),
+            "0s"
+        );
+
+        // Refreshing while paused keeps the pass instead of starting over
+        app.refresh();
+        assert!(app
+            .status_message
+            .as_deref()
+            .unwrap()
+            .starts_with("Rate limited: resuming at repository 12/40"));
+        assert!(!app.is_loading);
+
+        app.handle_background_message(BackgroundMessage::EnhancementResumed);
+        assert!(app.rate_limit_pause.is_none());
     }
 
     #[test]
-    fn test_refresh_key_handling() {
-        let mut app = App::new();
+    fn test_links_pane_collects_opened_links() {
+        let dir = std::env::temp_dir().join(format!("gh-links-{}", std::process::id()));
+        let mut config = AppConfig::default();
+        config.links.mode = crate::config::LinkMode::Pane;
+        config.links.file = Some(dir.join("links.txt"));
+        let mut app = App::with_config(config);
 
-        // Test 'r' key
-        assert!(app.handle_key_event(KeyCode::Char('r')));
-        assert!(app.last_refresh.is_some());
+        app.open_link("https://github.com/acme/api/pull/7");
+        app.open_link("https://github.com/acme/web");
+        app.open_link("https://github.com/acme/api/pull/7");
+        assert_eq!(
+            app.opened_links,
+            vec![
+                "https://github.com/acme/api/pull/7",
+                "https://github.com/acme/web"
+            ]
+        );
+        assert!(app
+            .status_message
+            .as_deref()
+            .unwrap()
+            .starts_with("Link added to the links pane"));
+
+        let written = std::fs::read_to_string(dir.join("links.txt")).unwrap();
+        let urls: Vec<&str> = written
+            .lines()
+            .filter_map(|line| line.split('\t').nth(1))
+            .collect();
+        assert_eq!(urls.len(), 3);
+        assert_eq!(urls[1], "https://github.com/acme/web");
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        app.handle_key_event(KeyCode::Char('k'));
+        assert_eq!(app.current_view, AppView::Links);
+        app.handle_key_event(KeyCode::Down);
+        assert_eq!(app.selected_link, 1);
     }
 }
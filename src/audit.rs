@@ -0,0 +1,204 @@
+use crate::i18n::t;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// How far back the audit log feed searches
+pub const AUDIT_LOOKBACK_DAYS: i64 = 30;
+
+/// Audit log actions that can weaken a repository's health posture, each
+/// searched separately so a busy log doesn't bury them
+pub const AUDIT_ACTIONS: [&str; 6] = [
+    "protected_branch.destroy",
+    "repo.access",
+    "org.add_member",
+    "org.update_member",
+    "repo.add_member",
+    "repo.update_member",
+];
+
+/// What a health-relevant audit log event changed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AuditEventKind {
+    /// Branch protection was deleted
+    ProtectionRemoved,
+    /// A repository was made public, private or internal
+    VisibilityChanged,
+    /// Someone was made an organization owner or repository admin
+    AdminGranted,
+}
+
+impl AuditEventKind {
+    /// Classify an audit log action, `permission` being the role it set
+    ///
+    /// Membership changes only count when they grant admin.
+    pub fn classify(action: &str, permission: Option<&str>) -> Option<Self> {
+        match action {
+            "protected_branch.destroy" => Some(AuditEventKind::ProtectionRemoved),
+            "repo.access" => Some(AuditEventKind::VisibilityChanged),
+            "org.add_member" | "org.update_member" | "repo.add_member" | "repo.update_member"
+                if permission == Some("admin") =>
+            {
+                Some(AuditEventKind::AdminGranted)
+            }
+            _ => None,
+        }
+    }
+
+    /// Short label for the admin view
+    pub fn label(self) -> &'static str {
+        match self {
+            AuditEventKind::ProtectionRemoved => t("audit.protection_removed"),
+            AuditEventKind::VisibilityChanged => t("audit.visibility_changed"),
+            AuditEventKind::AdminGranted => t("audit.admin_granted"),
+        }
+    }
+}
+
+/// An audit log entry as GitHub returns it (only the fields we need)
+///
+/// Which fields are present depends on the action.
+#[derive(Debug, Deserialize)]
+pub struct AuditEntry {
+    /// Milliseconds since the epoch
+    #[serde(rename = "@timestamp")]
+    pub timestamp: i64,
+    pub action: String,
+    pub actor: Option<String>,
+    /// `owner/name` of the repository acted on
+    pub repo: Option<String>,
+    /// Member acted on
+    pub user: Option<String>,
+    /// Branch of a protection change
+    pub branch: Option<String>,
+    /// Role granted by a membership change
+    pub permission: Option<String>,
+    /// New visibility of a repository
+    pub visibility: Option<String>,
+}
+
+/// A health-relevant event from an organization's audit log
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEvent {
+    pub kind: AuditEventKind,
+    pub action: String,
+    pub actor: String,
+    /// What changed, e.g. "acme/api (main)" or "mallory on acme/api"
+    pub target: String,
+    /// Repository acted on, if any
+    pub repository: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AuditEvent {
+    /// Read an entry, or `None` if it doesn't affect health
+    pub fn from_entry(entry: AuditEntry) -> Option<Self> {
+        let kind = AuditEventKind::classify(&entry.action, entry.permission.as_deref())?;
+        let repository = entry.repo.clone();
+        let scope = entry
+            .repo
+            .unwrap_or_else(|| t("audit.organization").to_string());
+        let target = match kind {
+            AuditEventKind::ProtectionRemoved => match entry.branch {
+                Some(branch) => format!("{} ({})", scope, branch),
+                None => scope,
+            },
+            AuditEventKind::VisibilityChanged => match entry.visibility {
+                Some(visibility) => format!("{} → {}", scope, visibility),
+                None => scope,
+            },
+            AuditEventKind::AdminGranted => format!(
+                "{} on {}",
+                entry.user.unwrap_or_else(|| "unknown".to_string()),
+                scope
+            ),
+        };
+        Some(Self {
+            kind,
+            action: entry.action,
+            actor: entry.actor.unwrap_or_else(|| "unknown".to_string()),
+            target,
+            repository,
+            created_at: DateTime::from_timestamp_millis(entry.timestamp)?,
+        })
+    }
+}
+
+/// Recent health-relevant audit log events of an organization
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrgAuditLog {
+    /// Organization login
+    pub org: String,
+    /// Newest first; `None` when the token can't read the audit log
+    pub events: Option<Vec<AuditEvent>>,
+}
+
+impl OrgAuditLog {
+    /// Number of events of a kind
+    pub fn count(&self, kind: AuditEventKind) -> usize {
+        self.events
+            .iter()
+            .flatten()
+            .filter(|event| event.kind == kind)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_become_health_events() {
+        let entries: Vec<AuditEntry> = serde_json::from_value(serde_json::json!([
+            {
+                "@timestamp": 1760000000000_i64,
+                "action": "protected_branch.destroy",
+                "actor": "mallory",
+                "repo": "acme/api",
+                "branch": "main"
+            },
+            {
+                "@timestamp": 1760000100000_i64,
+                "action": "repo.access",
+                "actor": "alice",
+                "repo": "acme/site",
+                "visibility": "public"
+            },
+            {
+                "@timestamp": 1760000200000_i64,
+                "action": "org.update_member",
+                "actor": "alice",
+                "user": "bob",
+                "permission": "admin"
+            },
+            {
+                "@timestamp": 1760000300000_i64,
+                "action": "repo.add_member",
+                "actor": "alice",
+                "repo": "acme/api",
+                "user": "carol",
+                "permission": "write"
+            }
+        ]))
+        .unwrap();
+        let events: Vec<AuditEvent> = entries
+            .into_iter()
+            .filter_map(AuditEvent::from_entry)
+            .collect();
+
+        let summary: Vec<(AuditEventKind, &str)> = events
+            .iter()
+            .map(|event| (event.kind, event.target.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (AuditEventKind::ProtectionRemoved, "acme/api (main)"),
+                (AuditEventKind::VisibilityChanged, "acme/site → public"),
+                (AuditEventKind::AdminGranted, "bob on the organization"),
+            ]
+        );
+        assert_eq!(events[0].actor, "mallory");
+        assert_eq!(events[0].created_at.timestamp(), 1760000000);
+    }
+}
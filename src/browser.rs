@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Open a URL in the user's default browser
+///
+/// The launcher's output is discarded so it can't draw over the TUI.
+pub fn open(url: &str) -> Result<(), String> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+
+    command
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to open browser: {}", e))
+}
+
+/// Whether this runs in an SSH session, where a browser would open on the
+/// remote machine if at all
+pub fn is_remote_session() -> bool {
+    ["SSH_CONNECTION", "SSH_CLIENT", "SSH_TTY"]
+        .iter()
+        .any(|variable| std::env::var_os(variable).is_some())
+}
+
+/// Append a link to a file as a timestamped line
+pub fn append_link(path: &Path, url: &str, at: DateTime<Utc>) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| writeln!(file, "{}\t{}", at.to_rfc3339(), url))
+        .map_err(|e| format!("Failed to write link to {}: {}", path.display(), e))
+}
@@ -0,0 +1,93 @@
+use crate::models::Repository;
+
+/// Something that happened inside the app that other modules may react to
+///
+/// Events borrow the app's state rather than copying it, so publishing is
+/// cheap even when nobody is subscribed.
+#[derive(Debug, Clone, Copy)]
+pub enum AppEvent<'a> {
+    /// A repository finished enhancing and its row was updated
+    RepositoryUpdated { repository: &'a Repository },
+    /// A refresh finished enhancing every repository
+    RefreshCompleted { repositories: &'a [Repository] },
+}
+
+/// Reacts to events published on the bus
+///
+/// Subscribers own whatever state they need (e.g. which repositories were
+/// already alerted) and hand slow work such as HTTP delivery to a background
+/// task, so handling an event never blocks the UI.
+pub trait Subscriber: Send {
+    fn on_event(&mut self, event: &AppEvent);
+}
+
+/// Delivers app events to every registered subscriber in registration order
+#[derive(Default)]
+pub struct EventBus {
+    subscribers: Vec<Box<dyn Subscriber>>,
+}
+
+impl EventBus {
+    /// Register a subscriber for all future events
+    pub fn subscribe(&mut self, subscriber: Box<dyn Subscriber>) {
+        self.subscribers.push(subscriber);
+    }
+
+    /// Deliver an event to every subscriber
+    pub fn publish(&mut self, event: AppEvent) {
+        for subscriber in &mut self.subscribers {
+            subscriber.on_event(&event);
+        }
+    }
+}
+
+impl std::fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventBus")
+            .field("subscribers", &self.subscribers.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl Subscriber for Recorder {
+        fn on_event(&mut self, event: &AppEvent) {
+            let entry = match event {
+                AppEvent::RepositoryUpdated { repository } => {
+                    format!("updated {}", repository.full_name())
+                }
+                AppEvent::RefreshCompleted { repositories } => {
+                    format!("completed {}", repositories.len())
+                }
+            };
+            self.0.lock().unwrap().push(entry);
+        }
+    }
+
+    #[test]
+    fn test_publish_reaches_every_subscriber() {
+        let first = Arc::new(Mutex::new(Vec::new()));
+        let second = Arc::new(Mutex::new(Vec::new()));
+        let mut bus = EventBus::default();
+        bus.subscribe(Box::new(Recorder(first.clone())));
+        bus.subscribe(Box::new(Recorder(second.clone())));
+
+        let repositories = vec![Repository::new("api".to_string(), "acme".to_string())];
+        bus.publish(AppEvent::RepositoryUpdated {
+            repository: &repositories[0],
+        });
+        bus.publish(AppEvent::RefreshCompleted {
+            repositories: &repositories,
+        });
+
+        let expected = vec!["updated acme/api", "completed 1"];
+        assert_eq!(*first.lock().unwrap(), expected);
+        assert_eq!(*second.lock().unwrap(), expected);
+    }
+}
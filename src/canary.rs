@@ -0,0 +1,114 @@
+use crate::config::CanaryConfig;
+use crate::i18n::t_with;
+use crate::models::Repository;
+use std::collections::HashSet;
+
+/// How a refreshed repository list differs from the one shown before it
+#[derive(Debug, Clone, PartialEq)]
+pub struct DatasetChange {
+    /// Repositories in the previous list
+    pub previous: usize,
+    /// Full names missing from the refreshed list
+    pub removed: Vec<String>,
+    /// Full names only in the refreshed list
+    pub added: Vec<String>,
+}
+
+impl DatasetChange {
+    /// Compare the repository list shown with a freshly fetched one
+    pub fn between(previous: &[Repository], current: &[Repository]) -> Self {
+        let before: HashSet<String> = previous.iter().map(Repository::full_name).collect();
+        let after: HashSet<String> = current.iter().map(Repository::full_name).collect();
+        let mut removed: Vec<String> = before.difference(&after).cloned().collect();
+        let mut added: Vec<String> = after.difference(&before).cloned().collect();
+        removed.sort();
+        added.sort();
+        Self {
+            previous: previous.len(),
+            removed,
+            added,
+        }
+    }
+
+    /// Removed plus added repositories as a share of the previous list
+    pub fn percentage(&self) -> usize {
+        ((self.removed.len() + self.added.len()) * 100)
+            .checked_div(self.previous)
+            .unwrap_or(0)
+    }
+
+    /// Whether the change is large enough to hold the new list back
+    pub fn exceeds(&self, config: &CanaryConfig) -> bool {
+        config.threshold_percent > 0
+            && self.previous >= config.min_repositories
+            && self.percentage() > config.threshold_percent as usize
+    }
+
+    /// Short description, e.g. "12 removed, 1 added of 40 repositories (32%)"
+    pub fn summary(&self) -> String {
+        t_with(
+            "canary.summary",
+            &[
+                ("removed", &self.removed.len()),
+                ("added", &self.added.len()),
+                ("previous", &self.previous),
+                ("percentage", &self.percentage()),
+            ],
+        )
+    }
+}
+
+/// A refreshed repository list held back until the user accepts it
+#[derive(Debug, Clone)]
+pub struct HeldDataset {
+    pub repositories: Vec<Repository>,
+    pub change: DatasetChange,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repositories(names: &[&str]) -> Vec<Repository> {
+        names
+            .iter()
+            .map(|name| Repository::new(name.to_string(), "acme".to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_change_beyond_threshold_is_held() {
+        let config = CanaryConfig {
+            threshold_percent: 30,
+            min_repositories: 4,
+        };
+        let previous = repositories(&["api", "web", "worker", "docs", "infra"]);
+
+        let change = DatasetChange::between(&previous, &repositories(&["api", "web", "cli"]));
+        assert_eq!(
+            change.removed,
+            vec!["acme/docs", "acme/infra", "acme/worker"]
+        );
+        assert_eq!(change.added, vec!["acme/cli"]);
+        assert_eq!(change.percentage(), 80);
+        assert!(change.exceeds(&config));
+        assert_eq!(
+            change.summary(),
+            "3 removed, 1 added of 5 repositories (80%)"
+        );
+
+        // One repository archived or renamed is a normal refresh
+        let change =
+            DatasetChange::between(&previous, &repositories(&["api", "web", "worker", "docs"]));
+        assert!(!change.exceeds(&config));
+
+        // Too few repositories to judge, or the check switched off
+        let small = repositories(&["api", "web"]);
+        assert!(!DatasetChange::between(&small, &[]).exceeds(&config));
+        let off = CanaryConfig {
+            threshold_percent: 0,
+            ..config
+        };
+        assert!(!DatasetChange::between(&previous, &[]).exceeds(&off));
+    }
+}
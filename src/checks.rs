@@ -0,0 +1,147 @@
+use crate::app::BackgroundMessage;
+use crate::config::CustomCheckConfig;
+use crate::i18n::t_with;
+use crate::models::CustomCheckResult;
+use std::process::Stdio;
+use tokio::sync::mpsc;
+
+/// Environment variable carrying the checked repository's full name
+pub const REPOSITORY_ENV: &str = "GH_REPO_HEALTHCHECKS_REPOSITORY";
+
+/// Longest detail kept from a command's output
+const MAX_DETAIL: usize = 120;
+
+/// Run one custom check against a repository
+///
+/// The command is killed once its timeout passes, which fails the check.
+pub async fn run(check: &CustomCheckConfig, owner: &str, name: &str) -> CustomCheckResult {
+    let result = |passed: bool, detail: String| CustomCheckResult {
+        name: check.name.clone(),
+        passed,
+        detail,
+    };
+    let child = tokio::process::Command::new(&check.command)
+        .args(check.args_for(owner, name))
+        .env(REPOSITORY_ENV, format!("{}/{}", owner, name))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn();
+    let child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            let detail = t_with(
+                "checks.failed_to_start",
+                &[("command", &check.command), ("error", &e)],
+            );
+            return result(false, detail);
+        }
+    };
+
+    let output = match tokio::time::timeout(check.timeout(), child.wait_with_output()).await {
+        Ok(Ok(output)) => output,
+        Ok(Err(e)) => {
+            let detail = t_with(
+                "checks.failed",
+                &[("command", &check.command), ("error", &e)],
+            );
+            return result(false, detail);
+        }
+        Err(_) => {
+            let detail = t_with("checks.timed_out", &[("seconds", &check.timeout_seconds)]);
+            return result(false, detail);
+        }
+    };
+    let stdout = first_line(&output.stdout);
+    if output.status.success() {
+        return result(true, stdout.unwrap_or_default());
+    }
+    let detail = first_line(&output.stderr)
+        .or(stdout)
+        .unwrap_or_else(|| t_with("checks.exited", &[("status", &output.status)]));
+    result(false, detail)
+}
+
+/// First non-blank line of command output, shortened for display
+fn first_line(output: &[u8]) -> Option<String> {
+    let text = String::from_utf8_lossy(output);
+    let line = text.lines().map(str::trim).find(|line| !line.is_empty())?;
+    Some(line.chars().take(MAX_DETAIL).collect())
+}
+
+/// Spawn background tasks running each repository's checks, one task per
+/// repository, reporting its results once they have all finished
+pub fn spawn(
+    runs: Vec<(String, String, Vec<CustomCheckConfig>)>,
+    sender: mpsc::UnboundedSender<BackgroundMessage>,
+) {
+    for (owner, name, checks) in runs {
+        let sender = sender.clone();
+        tokio::spawn(async move {
+            let mut results = Vec::new();
+            for check in &checks {
+                results.push(run(check, &owner, &name).await);
+            }
+            let _ = sender.send(BackgroundMessage::CustomChecksCompleted {
+                repository: format!("{}/{}", owner, name),
+                results,
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AppConfig;
+
+    #[tokio::test]
+    async fn test_checks_pass_fail_and_time_out() {
+        let config = AppConfig::parse(
+            r#"
+            [groups]
+            services = ["acme/api"]
+
+            [[checks]]
+            name = "health"
+            command = "sh"
+            args = ["-c", "echo {full_name} is up"]
+            groups = ["services"]
+
+            [[checks]]
+            name = "smoke"
+            command = "sh"
+            args = ["-c", "echo broken >&2; exit 3"]
+            repositories = ["api"]
+
+            [[checks]]
+            name = "slow"
+            command = "sleep"
+            args = ["5"]
+            repositories = ["acme/api"]
+            timeout_seconds = 1
+            "#,
+        )
+        .unwrap();
+        assert!(config.validate().is_empty());
+        assert!(config.checks_for("acme", "web").is_empty());
+
+        let mut results = Vec::new();
+        for check in config.checks_for("acme", "api") {
+            results.push(run(check, "acme", "api").await);
+        }
+        let outcomes: Vec<(bool, &str)> = results
+            .iter()
+            .map(|result| (result.passed, result.detail.as_str()))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                (true, "acme/api is up"),
+                (false, "broken"),
+                (false, "timed out after 1s")
+            ]
+        );
+    }
+}
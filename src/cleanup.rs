@@ -0,0 +1,155 @@
+use crate::i18n::{t, t_with};
+use crate::models::{Repository, RepositoryStatus};
+use std::time::SystemTime;
+
+/// Name suffixes marking a repository as a leftover copy (e.g. `api-old`)
+pub const LEFTOVER_SUFFIXES: [&str; 8] = [
+    "old",
+    "backup",
+    "bak",
+    "copy",
+    "deprecated",
+    "archive",
+    "archived",
+    "unused",
+];
+
+/// Why a repository looks abandoned or duplicated
+#[derive(Debug, Clone, PartialEq)]
+pub enum CleanupReason {
+    /// Named like a leftover copy, possibly of another loaded repository
+    LeftoverName {
+        suffix: &'static str,
+        original: Option<String>,
+    },
+    /// A fork without any commits its parent doesn't have
+    UnchangedFork { parent: String },
+    /// A repository without any commits
+    Empty,
+    /// Dormant with nothing open and no visitors in the last 14 days
+    Dormant { days: u64 },
+}
+
+impl CleanupReason {
+    /// Get a human-readable description
+    pub fn description(&self) -> String {
+        match self {
+            CleanupReason::LeftoverName {
+                suffix,
+                original: Some(original),
+            } => t_with(
+                "cleanup.reason_copy",
+                &[("suffix", suffix), ("original", original)],
+            ),
+            CleanupReason::LeftoverName {
+                suffix,
+                original: None,
+            } => t_with("cleanup.reason_leftover", &[("suffix", suffix)]),
+            CleanupReason::UnchangedFork { parent } => {
+                t_with("cleanup.reason_fork", &[("parent", parent)])
+            }
+            CleanupReason::Empty => t("cleanup.reason_empty").to_string(),
+            CleanupReason::Dormant { days } => t_with("cleanup.reason_dormant", &[("days", days)]),
+        }
+    }
+}
+
+/// A repository that looks safe to archive, with the reasons why
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleanupCandidate {
+    pub repository: String,
+    pub reasons: Vec<CleanupReason>,
+}
+
+/// Leftover suffix a repository name ends with, ignoring case
+///
+/// The suffix has to follow a `-`, `_` or `.` separator, so `gold` and
+/// `backup` on their own are not leftovers.
+pub fn leftover_suffix(name: &str) -> Option<&'static str> {
+    let (_, last) = name.rsplit_once(['-', '_', '.'])?;
+    LEFTOVER_SUFFIXES
+        .iter()
+        .find(|suffix| suffix.eq_ignore_ascii_case(last))
+        .copied()
+}
+
+/// Reason a repository's name marks it as a leftover, if any
+///
+/// Names the repository it was probably copied from when one with the name
+/// minus the suffix is among `repositories`.
+pub fn leftover_name(repo: &Repository, repositories: &[Repository]) -> Option<CleanupReason> {
+    let suffix = leftover_suffix(&repo.name)?;
+    let stem = &repo.name[..repo.name.len() - suffix.len() - 1];
+    let original = repositories
+        .iter()
+        .find(|other| other.owner == repo.owner && other.name.eq_ignore_ascii_case(stem))
+        .map(Repository::full_name);
+    Some(CleanupReason::LeftoverName { suffix, original })
+}
+
+/// Days a dormant repository with no open pull requests or issues has gone
+/// without commits
+///
+/// Such a repository becomes an archive candidate once its traffic shows
+/// nobody visits it either.
+pub fn dormant_days(repo: &Repository, now: SystemTime) -> Option<u64> {
+    if repo.status != RepositoryStatus::Dormant
+        || !repo.open_pull_requests.is_empty()
+        || repo.open_issues > 0
+    {
+        return None;
+    }
+    let idle = now.duration_since(repo.latest_commit_at?).ok()?;
+    Some(idle.as_secs() / 86400)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leftover_suffix() {
+        assert_eq!(leftover_suffix("api-old"), Some("old"));
+        assert_eq!(leftover_suffix("web_Backup"), Some("backup"));
+        assert_eq!(leftover_suffix("site.bak"), Some("bak"));
+        assert_eq!(leftover_suffix("docs-copy"), Some("copy"));
+        assert_eq!(leftover_suffix("gold"), None);
+        assert_eq!(leftover_suffix("backup"), None);
+        assert_eq!(leftover_suffix("old-api"), None);
+    }
+
+    #[test]
+    fn test_dormant_days_requires_nothing_open() {
+        let now = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1000 * 86400);
+        let mut repo = Repository::new("legacy".to_string(), "acme".to_string());
+        repo.status = RepositoryStatus::Dormant;
+        repo.latest_commit_at = Some(now - std::time::Duration::from_secs(400 * 86400));
+        assert_eq!(dormant_days(&repo, now), Some(400));
+
+        let mut with_issues = repo.clone();
+        with_issues.open_issues = 2;
+        assert_eq!(dormant_days(&with_issues, now), None);
+
+        repo.status = RepositoryStatus::Stale;
+        assert_eq!(dormant_days(&repo, now), None);
+    }
+
+    #[test]
+    fn test_leftover_name_finds_original() {
+        let repositories: Vec<Repository> = ["api", "api-old", "web-backup"]
+            .iter()
+            .map(|name| Repository::new(name.to_string(), "acme".to_string()))
+            .collect();
+
+        assert_eq!(leftover_name(&repositories[0], &repositories), None);
+        assert_eq!(
+            leftover_name(&repositories[1], &repositories),
+            Some(CleanupReason::LeftoverName {
+                suffix: "old",
+                original: Some("acme/api".to_string()),
+            })
+        );
+        let web = leftover_name(&repositories[2], &repositories).unwrap();
+        assert_eq!(web.description(), "named \"-backup\"");
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+/// Locations GitHub reads a CODEOWNERS file from, in the order it checks them
+pub const CODEOWNERS_PATHS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// One CODEOWNERS line: a path pattern and who owns the matching paths
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnershipRule {
+    pub pattern: String,
+    /// Users (`@octocat`), teams (`@org/team`) or emails, as written
+    pub owners: Vec<String>,
+}
+
+/// Path ownership parsed from a repository's CODEOWNERS file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Codeowners {
+    pub rules: Vec<OwnershipRule>,
+}
+
+impl Codeowners {
+    /// Parse a CODEOWNERS file, skipping comments, blank lines and
+    /// patterns without owners
+    pub fn parse(content: &str) -> Self {
+        let rules = content
+            .lines()
+            .filter_map(|line| {
+                let line = line.split('#').next().unwrap_or_default();
+                let mut parts = line.split_whitespace();
+                let pattern = parts.next()?.to_string();
+                let owners: Vec<String> = parts.map(str::to_string).collect();
+                (!owners.is_empty()).then_some(OwnershipRule { pattern, owners })
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// The team that owns the repository as a whole
+    ///
+    /// That's the first team of the catch-all `*` rule when there is one
+    /// (the last such rule wins, as on GitHub); otherwise the team owning the
+    /// most paths. Individual users only count when no team is named at all.
+    pub fn primary_team(&self) -> Option<String> {
+        if let Some(rule) = self.rules.iter().rev().find(|rule| rule.pattern == "*") {
+            if let Some(owner) = rule
+                .owners
+                .iter()
+                .find(|owner| is_team(owner))
+                .or(rule.owners.first())
+            {
+                return Some(owner.clone());
+            }
+        }
+
+        let owners: Vec<&String> = self.rules.iter().flat_map(|rule| &rule.owners).collect();
+        let candidates: Vec<&String> = if owners.iter().any(|owner| is_team(owner)) {
+            owners.into_iter().filter(|owner| is_team(owner)).collect()
+        } else {
+            owners
+        };
+
+        let mut counts: HashMap<&String, usize> = HashMap::new();
+        for owner in &candidates {
+            *counts.entry(owner).or_default() += 1;
+        }
+        // Ties go to the owner named first in the file
+        let most = counts.values().copied().max()?;
+        candidates
+            .into_iter()
+            .find(|owner| counts[owner] == most)
+            .cloned()
+    }
+}
+
+/// Whether an owner is a team (`@org/team`) rather than a user or email
+fn is_team(owner: &str) -> bool {
+    owner.starts_with('@') && owner.contains('/')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_skips_comments_and_ownerless_patterns() {
+        let codeowners = Codeowners::parse(
+            "# Default owners\n\
+             *       @acme/platform @octocat\n\
+             \n\
+             /docs/  @acme/docs # handbook\n\
+             *.lock\n",
+        );
+        assert_eq!(
+            codeowners.rules,
+            vec![
+                OwnershipRule {
+                    pattern: "*".to_string(),
+                    owners: vec!["@acme/platform".to_string(), "@octocat".to_string()],
+                },
+                OwnershipRule {
+                    pattern: "/docs/".to_string(),
+                    owners: vec!["@acme/docs".to_string()],
+                },
+            ]
+        );
+        assert_eq!(codeowners.primary_team().as_deref(), Some("@acme/platform"));
+    }
+
+    #[test]
+    fn test_primary_team_without_catch_all_prefers_most_paths() {
+        let codeowners = Codeowners::parse(
+            "/api/ @octocat @acme/backend\n\
+             /web/ @acme/frontend\n\
+             /db/  @acme/backend\n",
+        );
+        assert_eq!(codeowners.primary_team().as_deref(), Some("@acme/backend"));
+
+        let users_only = Codeowners::parse("*.rs @octocat\n*.md @hubot @octocat\n");
+        assert_eq!(users_only.primary_team().as_deref(), Some("@octocat"));
+        assert_eq!(Codeowners::parse("# empty\n").primary_team(), None);
+    }
+}
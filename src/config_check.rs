@@ -0,0 +1,513 @@
+use crate::config::{AppConfig, Collector};
+use crate::credentials;
+use crate::github::{GitHubClient, GitHubError};
+use serde::de::{
+    self, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+use std::time::Duration;
+use toml_edit::{ImDocument, Item, TableLike, Value};
+
+/// Longest a host may take to accept a connection before it counts as
+/// unreachable
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A problem found in the config file, with the line it's on when known
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigProblem {
+    /// 1-based line of the setting the problem is about
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Parse config text and check it without contacting anything
+///
+/// Reports syntax errors, fields the dashboard doesn't know (usually typos,
+/// which would otherwise be silently ignored) and the values
+/// [`AppConfig::issues`] rejects. The config is `None` when the text doesn't
+/// parse. Key bindings are built in rather than configurable, so there are
+/// no keymap conflicts to look for; a `[keys]` table is an unknown field.
+pub fn check(contents: &str) -> (Option<AppConfig>, Vec<ConfigProblem>) {
+    let unknown = RefCell::new(Vec::new());
+    let parsed = serde::Deserialize::deserialize(Tracked {
+        inner: toml::Deserializer::new(contents),
+        path: String::new(),
+        unknown: &unknown,
+    });
+    let config: AppConfig = match parsed {
+        Ok(config) => config,
+        Err(e) => {
+            let e: toml::de::Error = e;
+            let problem = ConfigProblem {
+                line: e.span().map(|span| line_at(contents, span.start)),
+                message: e.message().trim().to_string(),
+            };
+            return (None, vec![problem]);
+        }
+    };
+
+    let mut problems: Vec<ConfigProblem> = unknown
+        .into_inner()
+        .into_iter()
+        .map(|key| ConfigProblem {
+            line: line_of(contents, &key),
+            message: format!("unknown field `{}`", key),
+        })
+        .collect();
+    problems.extend(config.issues().into_iter().map(|issue| ConfigProblem {
+        line: line_of(contents, &issue.key),
+        message: issue.message,
+    }));
+    (Some(config), problems)
+}
+
+/// Line a setting is on, given its dotted key path (e.g. `webhooks.1.url`)
+///
+/// Settings left at their default are placed at the closest enclosing
+/// table that is written out, if any.
+fn line_of(contents: &str, key: &str) -> Option<usize> {
+    let document = ImDocument::parse(contents).ok()?;
+    let segments: Vec<&str> = key.split('.').collect();
+    let span = span_of(document.as_table(), &segments)?;
+    Some(line_at(contents, span.start))
+}
+
+/// 1-based line of a byte offset
+fn line_at(contents: &str, offset: usize) -> usize {
+    contents[..offset.min(contents.len())].matches('\n').count() + 1
+}
+
+/// Span of the deepest part of a dotted key path present in the document,
+/// e.g. the `[[webhooks]]` header when its `url` isn't set
+fn span_of(table: &dyn TableLike, path: &[&str]) -> Option<Range<usize>> {
+    let (first, rest) = path.split_first()?;
+    let (key, item) = table.get_key_value(first)?;
+    let found = key.span();
+    let deeper = match (item, rest.split_first()) {
+        (_, None) => None,
+        (Item::ArrayOfTables(array), Some((index, rest))) => {
+            let entry = array.get(index.parse().ok()?)?;
+            span_of(entry, rest).or(entry.span())
+        }
+        (Item::Value(Value::Array(array)), Some((index, rest))) => {
+            let entry = array.get(index.parse().ok()?)?;
+            match entry {
+                Value::InlineTable(table) => span_of(table, rest),
+                _ => None,
+            }
+            .or(entry.span())
+        }
+        (item, Some(_)) => item.as_table_like().and_then(|table| span_of(table, rest)),
+    };
+    deeper.or(found)
+}
+
+/// Check the config file, the hosts it names and the token's scopes, and
+/// print what's wrong (`--validate-config`)
+///
+/// Fails when any problem is found, so it can gate a deployment.
+pub async fn run() -> Result<(), Box<dyn Error>> {
+    let path = AppConfig::path().ok_or("No config directory available")?;
+    if !path.exists() {
+        println!("No config file at {}; defaults are used", path.display());
+        return Ok(());
+    }
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    let (config, mut problems) = check(&contents);
+    if let Some(config) = &config {
+        problems.extend(unreachable_hosts(config, &contents).await);
+        problems.extend(token_problems(config, credentials::token).await);
+    }
+
+    if problems.is_empty() {
+        println!("{} is valid", path.display());
+        return Ok(());
+    }
+    for problem in &problems {
+        match problem.line {
+            Some(line) => eprintln!("{}:{}: {}", path.display(), line, problem.message),
+            None => eprintln!("{}: {}", path.display(), problem.message),
+        }
+    }
+    Err(format!("{} problem(s) in the config file", problems.len()).into())
+}
+
+/// Hosts the config makes the dashboard connect to, with the setting each
+/// comes from
+///
+/// With a proxy only the proxy is reached directly, so only it is checked
+/// among the HTTP hosts.
+fn hosts(config: &AppConfig) -> Vec<(String, String, u16)> {
+    let mut urls = Vec::new();
+    match &config.network.proxy {
+        Some(proxy) => urls.push(("network.proxy".to_string(), proxy.clone())),
+        None => {
+            urls.push((String::new(), "https://api.github.com".to_string()));
+            for (index, channel) in config.notifications.channels.iter().enumerate() {
+                let key = format!("notifications.channels.{}.webhook_url", index);
+                urls.push((key, channel.webhook_url.clone()));
+            }
+            for (index, webhook) in config.webhooks.iter().enumerate() {
+                urls.push((format!("webhooks.{}.url", index), webhook.url.clone()));
+            }
+        }
+    }
+
+    let mut hosts: Vec<(String, String, u16)> = urls
+        .into_iter()
+        .filter_map(|(key, url)| {
+            let uri: http::Uri = url.parse().ok()?;
+            let port = uri
+                .port_u16()
+                .unwrap_or(if uri.scheme_str() == Some("http") {
+                    80
+                } else {
+                    443
+                });
+            Some((key, uri.host()?.to_string(), port))
+        })
+        .collect();
+    if let Some(smtp) = config
+        .digest
+        .smtp
+        .as_ref()
+        .filter(|smtp| !smtp.host.is_empty())
+    {
+        hosts.push(("digest.smtp.host".to_string(), smtp.host.clone(), smtp.port));
+    }
+    hosts
+}
+
+/// Hosts from the config that don't accept a connection
+async fn unreachable_hosts(config: &AppConfig, contents: &str) -> Vec<ConfigProblem> {
+    let mut problems = Vec::new();
+    let mut checked = std::collections::HashSet::new();
+    for (key, host, port) in hosts(config) {
+        if !checked.insert((host.clone(), port)) {
+            continue;
+        }
+        let connect = tokio::net::TcpStream::connect((host.as_str(), port));
+        let error = match tokio::time::timeout(CONNECT_TIMEOUT, connect).await {
+            Ok(Ok(_)) => continue,
+            Ok(Err(e)) => e.to_string(),
+            Err(_) => "timed out".to_string(),
+        };
+        problems.push(ConfigProblem {
+            line: line_of(contents, &key),
+            message: format!("can't reach {}:{} ({})", host, port, error),
+        });
+    }
+    problems
+}
+
+/// What the token from `token` can't do that the config asks for
+async fn token_problems(
+    config: &AppConfig,
+    token: impl FnOnce() -> Option<String>,
+) -> Vec<ConfigProblem> {
+    let problem = |message: String| ConfigProblem {
+        line: None,
+        message,
+    };
+    let client = match GitHubClient::new(&config.network, token) {
+        Ok(client) => client,
+        Err(e) => return vec![problem(e.to_string())],
+    };
+    let check = match client.check_token().await {
+        Ok(check) => check,
+        Err(GitHubError::AuthenticationFailed) => {
+            return vec![problem(
+                "GitHub rejected the token: it may be mistyped, expired or revoked".to_string(),
+            )]
+        }
+        // Unreachable hosts are reported separately
+        Err(_) => return Vec::new(),
+    };
+
+    let mut problems: Vec<ConfigProblem> = check.warnings().into_iter().map(problem).collect();
+    let scanning = [Collector::CodeScanning, Collector::SecretScanning]
+        .into_iter()
+        .filter(|&collector| config.collector_enabled(collector))
+        .map(|collector| collector.name())
+        .collect::<Vec<_>>();
+    if !scanning.is_empty() && !check.has_scope("security_events") {
+        problems.push(problem(format!(
+            "token lacks `security_events` scope — the {} collector(s) can't read alerts",
+            scanning.join(" and ")
+        )));
+    }
+    problems
+}
+
+/// A deserializer recording the dotted path of every field the target type
+/// ignores, i.e. fields it doesn't know
+struct Tracked<'a, T> {
+    inner: T,
+    path: String,
+    unknown: &'a RefCell<Vec<String>>,
+}
+
+impl<'a, T> Tracked<'a, T> {
+    fn wrap<U>(&self, inner: U) -> Tracked<'a, U> {
+        Tracked {
+            inner,
+            path: self.path.clone(),
+            unknown: self.unknown,
+        }
+    }
+
+    fn child(&self, key: &str) -> String {
+        match self.path.as_str() {
+            "" => key.to_string(),
+            path => format!("{}.{}", path, key),
+        }
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(
+                self,
+                $($arg: $ty,)*
+                visitor: V,
+            ) -> Result<V::Value, Self::Error> {
+                let visitor = self.wrap(visitor);
+                self.inner.$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Tracked<'_, D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any(); deserialize_bool(); deserialize_i8(); deserialize_i16();
+        deserialize_i32(); deserialize_i64(); deserialize_i128(); deserialize_u8();
+        deserialize_u16(); deserialize_u32(); deserialize_u64(); deserialize_u128();
+        deserialize_f32(); deserialize_f64(); deserialize_char(); deserialize_str();
+        deserialize_string(); deserialize_bytes(); deserialize_byte_buf();
+        deserialize_option(); deserialize_unit(); deserialize_seq(); deserialize_map();
+        deserialize_identifier();
+        deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, D::Error> {
+        self.unknown.borrow_mut().push(self.path);
+        self.inner.deserialize_ignored_any(visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method<E: de::Error>(self, value: $ty) -> Result<Self::Value, E> {
+                self.inner.$method(value)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for Tracked<'_, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    forward_visit! {
+        visit_bool(bool); visit_i8(i8); visit_i16(i16); visit_i32(i32); visit_i64(i64);
+        visit_i128(i128); visit_u8(u8); visit_u16(u16); visit_u32(u32); visit_u64(u64);
+        visit_u128(u128); visit_f32(f32); visit_f64(f64); visit_char(char);
+        visit_str(&str); visit_borrowed_str(&'de str); visit_string(String);
+        visit_bytes(&[u8]); visit_borrowed_bytes(&'de [u8]); visit_byte_buf(Vec<u8>);
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let deserializer = self.wrap(deserializer);
+        self.inner.visit_some(deserializer)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        let deserializer = self.wrap(deserializer);
+        self.inner.visit_newtype_struct(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        let seq = TrackedSeq {
+            inner: self.wrap(seq),
+            index: 0,
+        };
+        self.inner.visit_seq(seq)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let map = TrackedMap {
+            inner: self.wrap(map),
+            key: String::new(),
+        };
+        self.inner.visit_map(map)
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_enum(data)
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for Tracked<'_, S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<S::Value, D::Error> {
+        let deserializer = self.wrap(deserializer);
+        self.inner.deserialize(deserializer)
+    }
+}
+
+/// Entries of an array, tracked by index
+struct TrackedSeq<'a, A> {
+    inner: Tracked<'a, A>,
+    index: usize,
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for TrackedSeq<'_, A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, A::Error> {
+        let mut seed = self.inner.wrap(seed);
+        seed.path = self.inner.child(&self.index.to_string());
+        self.index += 1;
+        self.inner.inner.next_element_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.inner.size_hint()
+    }
+}
+
+/// Entries of a table, tracked by key
+///
+/// Keys are read as strings, which every key in the config is.
+struct TrackedMap<'a, A> {
+    inner: Tracked<'a, A>,
+    key: String,
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for TrackedMap<'_, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        let Some(key) = self.inner.inner.next_key::<String>()? else {
+            return Ok(None);
+        };
+        self.key = key.clone();
+        seed.deserialize(key.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<T::Value, A::Error> {
+        let mut seed = self.inner.wrap(seed);
+        seed.path = self.inner.child(&self.key);
+        self.inner.inner.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_reports_lines() {
+        let (config, problems) = check(
+            r#"refresh_interval = 300
+
+            [api]
+            retrys = 3
+
+            [size]
+            large_mb = 900
+            huge_mb = 100
+
+            [[webhooks]]
+            url = "ftp://example.com"
+            min_health_score = 50
+            "#,
+        );
+        assert!(config.is_some());
+        assert_eq!(
+            problems,
+            vec![
+                ConfigProblem {
+                    line: Some(4),
+                    message: "unknown field `api.retrys`".to_string(),
+                },
+                ConfigProblem {
+                    line: Some(11),
+                    message: "webhook 1 has an invalid url".to_string(),
+                },
+                ConfigProblem {
+                    line: Some(7),
+                    message: "size large_mb must not exceed huge_mb".to_string(),
+                },
+            ]
+        );
+
+        let (config, problems) = check("[api]\nretries = \"three\"\n");
+        assert!(config.is_none());
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].line, Some(2));
+        assert!(problems[0].message.contains("invalid type"));
+
+        let (_, problems) = check("[keys]\nrefresh = \"F5\"\n");
+        assert_eq!(
+            problems,
+            vec![ConfigProblem {
+                line: Some(1),
+                message: "unknown field `keys`".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::config::AppConfig;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use keyring::Entry;
+use std::error::Error;
+use std::io::{IsTerminal, Write};
+
+/// Keyring service the token is stored under
+const SERVICE: &str = "gh-repo-healthchecks";
+
+/// Keyring account name of the token
+const ACCOUNT: &str = "github-token";
+
+/// Environment variable read when no token is saved in the keyring
+pub const TOKEN_ENV: &str = "GH_REPO_HEALTHCHECKS_TOKEN";
+
+/// The token entry in the OS keyring (Keychain, Credential Manager or the
+/// kernel keyring)
+#[cfg(not(test))]
+fn entry() -> keyring::Result<Entry> {
+    Entry::new(SERVICE, ACCOUNT)
+}
+
+/// An empty in-memory entry, so tests never read the machine's keyring
+#[cfg(test)]
+fn entry() -> keyring::Result<Entry> {
+    let credential = keyring::mock::default_credential_builder().build(None, SERVICE, ACCOUNT)?;
+    Ok(Entry::new_with_credential(credential))
+}
+
+/// Token to use: the one saved with `--login`, then the
+/// `GH_REPO_HEALTHCHECKS_TOKEN` environment variable
+///
+/// A keyring that can't be reached counts as holding no token.
+pub fn token() -> Option<String> {
+    entry()
+        .ok()
+        .and_then(|entry| read_token(&entry))
+        .or_else(|| std::env::var(TOKEN_ENV).ok())
+}
+
+/// Token held by an entry, ignoring blank ones
+pub fn read_token(entry: &Entry) -> Option<String> {
+    let token = entry.get_password().ok()?;
+    let token = token.trim();
+    (!token.is_empty()).then(|| token.to_string())
+}
+
+/// Delete the token from an entry, returning whether there was one
+fn delete_token(entry: &Entry) -> Result<bool, keyring::Error> {
+    match entry.delete_credential() {
+        Ok(()) => Ok(true),
+        Err(keyring::Error::NoEntry) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Read a token from the terminal without echoing it, or a line from piped
+/// input
+fn read_token_input() -> Result<String, Box<dyn Error>> {
+    if !std::io::stdin().is_terminal() {
+        let mut line = String::new();
+        std::io::stdin().read_line(&mut line)?;
+        return Ok(line.trim().to_string());
+    }
+
+    eprint!("GitHub token: ");
+    std::io::stderr().flush()?;
+    let raw_mode = RawMode::enable()?;
+    let mut token = String::new();
+    let result = loop {
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key.code {
+            KeyCode::Enter => break Ok(()),
+            KeyCode::Esc => break Err("Login cancelled"),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                break Err("Login cancelled")
+            }
+            KeyCode::Char(c) => token.push(c),
+            KeyCode::Backspace => {
+                token.pop();
+            }
+            _ => {}
+        }
+    };
+    drop(raw_mode);
+    eprintln!();
+    result?;
+    Ok(token.trim().to_string())
+}
+
+/// Raw mode for typing the token, left again when dropped so the terminal
+/// is restored however reading ends
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> std::io::Result<Self> {
+        crossterm::terminal::enable_raw_mode()?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = crossterm::terminal::disable_raw_mode();
+    }
+}
+
+/// Check a token with GitHub and save it in the keyring (`--login`)
+///
+/// The token is read from the terminal, or from stdin when piped (e.g.
+/// `gh auth token | gh-repo-healthchecks --login`).
+pub async fn login() -> Result<(), Box<dyn Error>> {
+    let token = read_token_input()?;
+    if token.is_empty() {
+        return Err("No token entered".into());
+    }
+
+    // The network settings may be needed to reach GitHub at all
+    let config = AppConfig::load()?;
+    let octocrab = crate::network::build_octocrab(token.clone(), None, &config.network)?;
+    let user = octocrab
+        .current()
+        .user()
+        .await
+        .map_err(|e| format!("GitHub rejected the token: {}", e))?;
+
+    entry()?
+        .set_password(&token)
+        .map_err(|e| format!("Failed to save the token in the keyring: {}", e))?;
+    println!(
+        "Logged in as {}; the token is saved in the keyring",
+        user.login
+    );
+    Ok(())
+}
+
+/// Remove the saved token from the keyring (`--logout`)
+pub fn logout() -> Result<(), Box<dyn Error>> {
+    if delete_token(&entry()?)? {
+        println!("Removed the token from the keyring");
+    } else {
+        println!("No token saved in the keyring");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use keyring::mock::MockCredential;
+
+    #[test]
+    fn test_stored_token_round_trip() {
+        let entry = Entry::new_with_credential(Box::new(MockCredential::default()));
+        assert_eq!(read_token(&entry), None);
+        assert!(!delete_token(&entry).unwrap());
+
+        entry.set_password("ghp_example\n").unwrap();
+        assert_eq!(read_token(&entry).as_deref(), Some("ghp_example"));
+        assert!(delete_token(&entry).unwrap());
+        assert_eq!(read_token(&entry), None);
+
+        // A blank entry doesn't shadow the environment variable
+        entry.set_password("  ").unwrap();
+        assert_eq!(read_token(&entry), None);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::app::App;
+use crate::report::{apply_retention, HealthReport};
+use chrono::Utc;
+use std::error::Error;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Shortest refresh interval honoured in daemon mode
+pub const MIN_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How often background messages are processed while waiting
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Run headlessly: refresh on the configured interval and write a report
+/// after every completed refresh
+///
+/// Alerts and threshold webhooks fire exactly as they do in the TUI, since
+/// the same `App` state machine processes the results. Runs until Ctrl-C.
+pub async fn run() -> Result<(), Box<dyn Error>> {
+    let mut app = App::new();
+    if let Some(error) = app.get_error_message() {
+        return Err(error.into());
+    }
+    if let Some(message) = &app.status_message {
+        eprintln!("{}", message);
+    }
+
+    let directory = app
+        .config
+        .reports
+        .directory()
+        .ok_or("No data directory available; set [reports] directory")?;
+    let interval = Duration::from_secs(app.config.refresh_interval).max(MIN_INTERVAL);
+    println!(
+        "Writing reports to {} every {}s (Ctrl-C to stop)",
+        directory.display(),
+        interval.as_secs()
+    );
+    prune(&directory, &app);
+
+    loop {
+        let started = Instant::now();
+        let target = app.completed_refreshes + 1;
+        app.auto_refresh();
+
+        // Wait for this refresh to finish, fail, or run into the next one
+        while started.elapsed() < interval {
+            app.process_background_messages();
+            app.tick();
+
+            if app.completed_refreshes >= target {
+                if let Some(held) = &app.held_dataset {
+                    eprintln!(
+                        "Refresh held back ({}); reporting the previous data",
+                        held.change.summary()
+                    );
+                }
+                let report = HealthReport::new(
+                    app.repo_view_mode.display_name(),
+                    app.get_repositories(),
+                    &app.config.attention,
+                    Utc::now(),
+                )
+                .with_time(app.config.time.clone());
+                match report.write(
+                    &directory,
+                    &app.config.reports.formats,
+                    app.config.reports.retention,
+                ) {
+                    Ok(paths) => {
+                        println!(
+                            "Wrote {} report(s) covering {} repositories",
+                            paths.len(),
+                            report.totals.repositories
+                        );
+                        prune(&directory, &app);
+                    }
+                    Err(e) => eprintln!("{}", e),
+                }
+                break;
+            }
+            if let Some(error) = app.get_error_message() {
+                eprintln!("Refresh failed: {}", error);
+                break;
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(POLL_INTERVAL) => {}
+                _ = tokio::signal::ctrl_c() => return Ok(()),
+            }
+        }
+
+        let remaining = interval.saturating_sub(started.elapsed());
+        tokio::select! {
+            _ = tokio::time::sleep(remaining) => {}
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        }
+    }
+}
+
+/// Delete reports and digests past the retention policy, logging failures
+fn prune(directory: &Path, app: &App) {
+    match apply_retention(directory, &app.config.reports, Utc::now()) {
+        Ok(0) => {}
+        Ok(deleted) => println!("Deleted {} report(s) past retention", deleted),
+        Err(e) => eprintln!("{}", e),
+    }
+}
@@ -0,0 +1,355 @@
+use crate::models::{
+    BranchProtection, BranchProtectionStatus, BusFactor, CodeScanningAlerts, CommitActivity,
+    CommunityProfile, DeployStatus, DeploymentState, EnvironmentDeployment, Issue, IssueMetrics,
+    PullRequest, PullRequestState, Release, Repository, RepositoryStatus, ReviewDecision,
+    RunTrigger, TaskProgress, WorkflowHealth, WorkflowRun, WorkflowStatus,
+};
+use std::time::{Duration, SystemTime};
+
+/// Owner of every demo repository
+pub const DEMO_OWNER: &str = "demo-org";
+
+const DAY: u64 = 86400;
+
+/// A moment `days` before `now`
+fn ago(now: SystemTime, days: u64) -> SystemTime {
+    now - Duration::from_secs(days * DAY)
+}
+
+/// A repository created about 2.5 years ago, with its activity status
+/// following from its last commit
+fn repository(name: &str, commit_days_ago: Option<u64>, now: SystemTime) -> Repository {
+    let mut repo = Repository::new(name.to_string(), DEMO_OWNER.to_string());
+    repo.html_url = format!("https://github.com/{}/{}", DEMO_OWNER, name);
+    repo.default_branch = "main".to_string();
+    repo.created_at = Some(ago(now, 900));
+    repo.latest_commit_at = commit_days_ago.map(|days| ago(now, days));
+    repo.status = RepositoryStatus::from_last_commit(repo.latest_commit_at);
+    repo
+}
+
+/// Recent runs of the CI workflow, newest first, one per day
+fn runs(repo: &mut Repository, statuses: &[WorkflowStatus], now: SystemTime) {
+    repo.recent_workflows = statuses
+        .iter()
+        .enumerate()
+        .map(|(index, status)| {
+            let created_at = ago(now, index as u64) - Duration::from_secs(3600);
+            WorkflowRun {
+                id: 1000 + index as u64,
+                name: "CI".to_string(),
+                status: status.clone(),
+                created_at,
+                updated_at: created_at + Duration::from_secs(420),
+                started_at: Some(created_at + Duration::from_secs(30)),
+                duration: (*status != WorkflowStatus::InProgress)
+                    .then_some(Duration::from_secs(390)),
+                trigger: RunTrigger {
+                    branch: Some("main".to_string()),
+                    event: "push".to_string(),
+                    actor: Some("demo-bot".to_string()),
+                    from_fork: false,
+                },
+            }
+        })
+        .collect();
+    repo.latest_workflow = repo.recent_workflows.first().cloned();
+    repo.workflow_health = WorkflowHealth::from_workflow_runs(&repo.recent_workflows);
+}
+
+/// An open pull request opened `days` ago
+fn pull_request(
+    repo: &Repository,
+    number: u32,
+    title: &str,
+    days: u64,
+    now: SystemTime,
+) -> PullRequest {
+    PullRequest {
+        number,
+        title: title.to_string(),
+        state: PullRequestState::Open,
+        created_at: ago(now, days),
+        updated_at: ago(now, days / 2),
+        author: "demo-dev".to_string(),
+        html_url: format!("{}/pull/{}", repo.html_url, number),
+        draft: false,
+        approvals: 0,
+        changes_requested: 0,
+        head_sha: format!("{:040x}", number),
+        base_branch: "main".to_string(),
+        linked_issues: Vec::new(),
+        tasks: None,
+        feedback_waiting_since: None,
+        review_decision: None,
+        checks: None,
+        labels: Vec::new(),
+        milestone: None,
+    }
+}
+
+/// Realistic fake repositories for `--demo`, covering every activity status
+/// and workflow health and the edge cases the dashboard flags
+pub fn repositories(now: SystemTime) -> Vec<Repository> {
+    use WorkflowStatus::{Cancelled, Failed, InProgress, Success};
+
+    // Busy service: approved, blocked and draft pull requests, deploying
+    let mut payments = repository("payments-api", Some(0), now);
+    payments.description = Some("Card payments and refunds".to_string());
+    payments.language = Some("Rust".to_string());
+    payments.stars = 128;
+    payments.size_kb = 18_400;
+    payments.topics = vec!["production".to_string(), "payments".to_string()];
+    payments.recent_commit_subjects = vec![
+        "feat: retry webhook deliveries".to_string(),
+        "fix: round refunds to the minor unit".to_string(),
+        "docs: describe the idempotency keys".to_string(),
+    ];
+    runs(&mut payments, &vec![Success; 10], now);
+    let mut approved = pull_request(&payments, 412, "Retry webhook deliveries", 2, now);
+    approved.approvals = 2;
+    approved.review_decision = Some(ReviewDecision::Approved);
+    approved.checks = Some(Success);
+    approved.linked_issues = vec![398];
+    approved.milestone = Some("v3.5".to_string());
+    let mut blocked = pull_request(&payments, 409, "Switch to the new ledger API", 9, now);
+    blocked.approvals = 1;
+    blocked.changes_requested = 1;
+    blocked.review_decision = Some(ReviewDecision::ChangesRequested);
+    blocked.checks = Some(Failed);
+    blocked.feedback_waiting_since = Some(ago(now, 4));
+    blocked.labels = vec!["v3.5".to_string(), "breaking".to_string()];
+    let mut draft = pull_request(&payments, 415, "Partial captures", 1, now);
+    draft.draft = true;
+    draft.tasks = Some(TaskProgress { done: 2, total: 5 });
+    draft.checks = Some(InProgress);
+    payments.open_pull_requests = vec![approved, blocked, draft];
+    payments.open_issues = 7;
+    payments.issue_metrics = Some(IssueMetrics {
+        open: 4,
+        unanswered: 0,
+        oldest_opened_at: Some(ago(now, 40)),
+    });
+    payments.deploy_status = DeployStatus {
+        pages: None,
+        environments: vec![
+            EnvironmentDeployment {
+                name: "production".to_string(),
+                state: DeploymentState::Success,
+            },
+            EnvironmentDeployment {
+                name: "staging".to_string(),
+                state: DeploymentState::InProgress,
+            },
+        ],
+    };
+    payments.latest_release = Some(Release {
+        name: "v3.4.0".to_string(),
+        tag: "v3.4.0".to_string(),
+        published_at: ago(now, 6),
+    });
+    payments.code_scanning = Some(CodeScanningAlerts::from_severities(["medium", "low"]));
+    payments.branch_protection = Some(BranchProtectionStatus::Protected(Some(BranchProtection {
+        required_approvals: 2,
+        required_checks: vec!["CI".to_string()],
+        ..BranchProtection::default()
+    })));
+    payments.community = Some(CommunityProfile {
+        health_percentage: 100,
+        readme: true,
+        license: true,
+        contributing: true,
+        code_of_conduct: true,
+        issue_templates: true,
+    });
+    payments.bus_factor = Some(BusFactor {
+        top_contributor: "demo-dev".to_string(),
+        top_commits: 48,
+        commits: 112,
+        contributors: 6,
+    });
+    payments.commit_activity = Some(CommitActivity {
+        weeks: (0..52).map(|week| 2 + week % 5).collect(),
+    });
+    payments.critical_issues = Some(vec![Issue {
+        number: 398,
+        title: "Webhook deliveries dropped during deploys".to_string(),
+        html_url: format!("{}/issues/398", payments.html_url),
+        assignees: vec!["demo-dev".to_string()],
+        labels: vec!["critical".to_string()],
+        comments: 6,
+    }]);
+
+    // Large backlog past the first page, a run still going
+    let mut web = repository("web-app", Some(3), now);
+    web.description = Some("Customer dashboard".to_string());
+    web.language = Some("TypeScript".to_string());
+    web.stars = 64;
+    web.size_kb = 92_000;
+    web.has_pages = true;
+    let mut statuses = vec![Success; 9];
+    statuses.insert(0, InProgress);
+    runs(&mut web, &statuses, now);
+    web.open_pull_requests = (0..30)
+        .map(|index| {
+            let mut pr = pull_request(
+                &web,
+                900 - index,
+                "Bump a dependency",
+                u64::from(index),
+                now,
+            );
+            pr.author = "dependabot[bot]".to_string();
+            pr
+        })
+        .collect();
+    web.open_pull_request_total = Some(142);
+    web.secret_scanning_alerts = Some(1);
+    web.open_issues = 160;
+    web.issue_metrics = Some(IssueMetrics {
+        open: 18,
+        unanswered: 5,
+        oldest_opened_at: Some(ago(now, 210)),
+    });
+    web.deploy_status = DeployStatus {
+        pages: Some(DeploymentState::Success),
+        environments: Vec::new(),
+    };
+    web.latest_release = Some(Release {
+        name: "2026.10".to_string(),
+        tag: "2026.10".to_string(),
+        published_at: ago(now, 14),
+    });
+
+    // Huge repository storing binaries with Git LFS; flaky CI
+    let mut mobile = repository("mobile-client", Some(20), now);
+    mobile.description = Some("Android and iOS apps".to_string());
+    mobile.language = Some("Kotlin".to_string());
+    mobile.stars = 31;
+    mobile.size_kb = 2_600_000;
+    mobile.uses_lfs = Some(true);
+    // One person keeps the apps going
+    mobile.bus_factor = Some(BusFactor {
+        top_contributor: "mobile-lead".to_string(),
+        top_commits: 57,
+        commits: 60,
+        contributors: 2,
+    });
+    // Busy until a few weeks ago
+    mobile.commit_activity = Some(CommitActivity {
+        weeks: (0..52)
+            .map(|week| if week < 49 { 1 + week / 8 } else { 0 })
+            .collect(),
+    });
+    runs(
+        &mut mobile,
+        &[
+            Success, Failed, Success, Success, Failed, Success, Failed, Success, Success, Failed,
+        ],
+        now,
+    );
+
+    // Mostly failing CI and a failed deploy
+    let mut pipeline = repository("data-pipeline", Some(60), now);
+    pipeline.description = Some("Nightly warehouse loads".to_string());
+    pipeline.language = Some("Python".to_string());
+    pipeline.size_kb = 7_300;
+    pipeline.created_at = Some(ago(now, 200));
+    runs(
+        &mut pipeline,
+        &[
+            Failed, Failed, Cancelled, Success, Failed, Success, Failed, Failed, Success, Failed,
+        ],
+        now,
+    );
+    pipeline.deploy_status = DeployStatus {
+        pages: None,
+        environments: vec![EnvironmentDeployment {
+            name: "production".to_string(),
+            state: DeploymentState::Failure,
+        }],
+    };
+    pipeline.open_issues = 3;
+    pipeline.branch_protection = Some(BranchProtectionStatus::Unprotected);
+    pipeline.community = Some(CommunityProfile {
+        health_percentage: 42,
+        readme: true,
+        ..CommunityProfile::default()
+    });
+
+    // Every run failing and a release over a year old
+    let mut billing = repository("legacy-billing", Some(120), now);
+    billing.description = Some("Invoicing before the payments rewrite".to_string());
+    billing.language = Some("Java".to_string());
+    billing.size_kb = 45_000;
+    runs(&mut billing, &vec![Failed; 5], now);
+    billing.latest_release = Some(Release {
+        name: "1.9.2".to_string(),
+        tag: "1.9.2".to_string(),
+        published_at: ago(now, 420),
+    });
+    let mut upgrade = pull_request(&billing, 77, "Upgrade to Java 21", 95, now);
+    upgrade.labels = vec!["v3.5".to_string()];
+    billing.open_pull_requests = vec![upgrade];
+    billing.code_scanning = Some(CodeScanningAlerts::from_severities([
+        "critical", "high", "high", "medium",
+    ]));
+
+    // Archived fork nobody has touched in over a year
+    let mut prototype = repository("old-prototype", Some(400), now);
+    prototype.description = Some("First spike of the dashboard".to_string());
+    prototype.language = Some("Go".to_string());
+    prototype.fork = true;
+    prototype.archived = true;
+    prototype.workflow_health = WorkflowHealth::Unknown;
+
+    // Empty private repository: no commits, no runs
+    let mut infra = repository("infra-config", None, now);
+    infra.description = Some("Terraform for the platform team".to_string());
+    infra.created_at = Some(ago(now, 12));
+    infra.empty = true;
+    infra.status = RepositoryStatus::Empty;
+    infra.private = true;
+    infra.admin = true;
+
+    vec![payments, web, mobile, pipeline, billing, prototype, infra]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo_covers_every_status_and_health() {
+        let repositories = repositories(SystemTime::now());
+        let statuses: Vec<RepositoryStatus> = repositories
+            .iter()
+            .map(|repo| repo.status.clone())
+            .collect();
+        for status in [
+            RepositoryStatus::Hot,
+            RepositoryStatus::Active,
+            RepositoryStatus::Moderate,
+            RepositoryStatus::Quiet,
+            RepositoryStatus::Stale,
+            RepositoryStatus::Dormant,
+            RepositoryStatus::Empty,
+        ] {
+            assert!(statuses.contains(&status), "no {:?} repository", status);
+        }
+        let healths: Vec<WorkflowHealth> = repositories
+            .iter()
+            .map(|repo| repo.workflow_health.clone())
+            .collect();
+        for health in [
+            WorkflowHealth::Excellent,
+            WorkflowHealth::Good,
+            WorkflowHealth::Fair,
+            WorkflowHealth::Poor,
+            WorkflowHealth::Critical,
+            WorkflowHealth::Unknown,
+        ] {
+            assert!(healths.contains(&health), "no {:?} repository", health);
+        }
+    }
+}
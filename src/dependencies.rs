@@ -0,0 +1,312 @@
+use crate::config::AttentionConfig;
+use crate::models::Repository;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+/// Manifest files read from the root of each repository
+pub const MANIFEST_FILES: [&str; 3] = ["Cargo.toml", "package.json", "go.mod"];
+
+/// Packages a repository publishes and the packages it depends on
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Manifest {
+    pub provides: Vec<String>,
+    pub depends_on: Vec<String>,
+}
+
+impl Manifest {
+    /// Parse a manifest file by name, returning `None` if it isn't valid
+    pub fn parse(file_name: &str, content: &str) -> Option<Self> {
+        match file_name {
+            "Cargo.toml" => Self::parse_cargo(content),
+            "package.json" => Self::parse_package_json(content),
+            "go.mod" => Some(Self::parse_go_mod(content)),
+            _ => None,
+        }
+    }
+
+    fn parse_cargo(content: &str) -> Option<Self> {
+        let manifest: toml::Table = content.parse().ok()?;
+        let provides = manifest
+            .get("package")
+            .and_then(|package| package.get("name"))
+            .and_then(|name| name.as_str())
+            .map(|name| vec![name.to_string()])
+            .unwrap_or_default();
+
+        let mut depends_on = Vec::new();
+        for section in ["dependencies", "dev-dependencies", "build-dependencies"] {
+            let Some(dependencies) = manifest.get(section).and_then(|s| s.as_table()) else {
+                continue;
+            };
+            for (name, spec) in dependencies {
+                // `foo = { package = "bar" }` depends on the crate named bar
+                let package = spec
+                    .get("package")
+                    .and_then(|package| package.as_str())
+                    .unwrap_or(name);
+                depends_on.push(package.to_string());
+            }
+        }
+        Some(Self {
+            provides,
+            depends_on,
+        })
+    }
+
+    fn parse_package_json(content: &str) -> Option<Self> {
+        let manifest: serde_json::Value = serde_json::from_str(content).ok()?;
+        let provides = manifest["name"]
+            .as_str()
+            .map(|name| vec![name.to_string()])
+            .unwrap_or_default();
+
+        let depends_on = ["dependencies", "devDependencies", "peerDependencies"]
+            .iter()
+            .filter_map(|section| manifest[section].as_object())
+            .flat_map(|dependencies| dependencies.keys().cloned())
+            .collect();
+        Some(Self {
+            provides,
+            depends_on,
+        })
+    }
+
+    fn parse_go_mod(content: &str) -> Self {
+        let mut manifest = Self::default();
+        let mut in_require_block = false;
+        for line in content.lines() {
+            let line = line.split("//").next().unwrap_or_default().trim();
+            if in_require_block {
+                if line == ")" {
+                    in_require_block = false;
+                } else if let Some(module) = line.split_whitespace().next() {
+                    manifest.depends_on.push(module.to_string());
+                }
+            } else if let Some(module) = line.strip_prefix("module ") {
+                manifest.provides.push(module.trim().to_string());
+            } else if line == "require (" {
+                in_require_block = true;
+            } else if let Some(requirement) = line.strip_prefix("require ") {
+                if let Some(module) = requirement.split_whitespace().next() {
+                    manifest.depends_on.push(module.to_string());
+                }
+            }
+        }
+        manifest
+    }
+}
+
+/// Internal dependencies between repositories, derived from their manifests
+#[derive(Debug, Clone, Default)]
+pub struct DependencyGraph {
+    /// Each scanned repository (full name) with the repositories it depends on
+    dependencies: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl DependencyGraph {
+    /// Link repositories whose manifests depend on packages another one provides
+    pub fn build(manifests: &[(String, Manifest)]) -> Self {
+        let providers: HashMap<&str, &str> = manifests
+            .iter()
+            .flat_map(|(repository, manifest)| {
+                manifest
+                    .provides
+                    .iter()
+                    .map(move |package| (package.as_str(), repository.as_str()))
+            })
+            .collect();
+
+        let mut dependencies: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for (repository, manifest) in manifests {
+            let upstream = dependencies.entry(repository.clone()).or_default();
+            for package in &manifest.depends_on {
+                if let Some(&provider) = providers.get(package.as_str()) {
+                    if provider != repository {
+                        upstream.insert(provider.to_string());
+                    }
+                }
+            }
+        }
+        Self { dependencies }
+    }
+
+    /// Repositories a repository depends on directly
+    pub fn dependencies_of(&self, repository: &str) -> Vec<&str> {
+        self.dependencies
+            .get(repository)
+            .map(|upstream| upstream.iter().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// Repositories depending on a repository directly
+    pub fn dependents_of(&self, repository: &str) -> Vec<&str> {
+        self.dependencies
+            .iter()
+            .filter(|(_, upstream)| upstream.contains(repository))
+            .map(|(dependent, _)| dependent.as_str())
+            .collect()
+    }
+
+    /// Number of repositories depending on a repository directly or transitively
+    pub fn blast_radius(&self, repository: &str) -> usize {
+        let mut affected = BTreeSet::new();
+        let mut queue = VecDeque::from([repository]);
+        while let Some(current) = queue.pop_front() {
+            for dependent in self.dependents_of(current) {
+                if dependent != repository && affected.insert(dependent) {
+                    queue.push_back(dependent);
+                }
+            }
+        }
+        affected.len()
+    }
+
+    /// Whether any internal dependency was found
+    pub fn is_empty(&self) -> bool {
+        self.dependencies.values().all(BTreeSet::is_empty)
+    }
+
+    /// Repositories taking part in the graph, ordered by fix priority
+    ///
+    /// Repositories needing attention come first, those upstream of the most
+    /// others leading, since fixing them helps the most repositories.
+    pub fn prioritized(
+        &self,
+        repositories: &[Repository],
+        attention: &AttentionConfig,
+    ) -> Vec<DependencyRow> {
+        let mut rows: Vec<DependencyRow> = repositories
+            .iter()
+            .filter_map(|repo| {
+                let name = repo.full_name();
+                let depends_on: Vec<String> = self
+                    .dependencies_of(&name)
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect();
+                let dependents = self.dependents_of(&name).len();
+                if depends_on.is_empty() && dependents == 0 {
+                    return None;
+                }
+                Some(DependencyRow {
+                    blast_radius: self.blast_radius(&name),
+                    repository: name,
+                    health_score: repo.health_score(),
+                    needs_attention: attention.matches(repo),
+                    dependents,
+                    depends_on,
+                })
+            })
+            .collect();
+        rows.sort_by(|a, b| {
+            b.needs_attention
+                .cmp(&a.needs_attention)
+                .then(b.blast_radius.cmp(&a.blast_radius))
+                .then(a.repository.cmp(&b.repository))
+        });
+        rows
+    }
+}
+
+/// One repository's place in the dependency graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct DependencyRow {
+    pub repository: String,
+    pub health_score: u8,
+    pub needs_attention: bool,
+    /// Repositories depending on this one directly
+    pub dependents: usize,
+    /// Repositories depending on this one directly or transitively
+    pub blast_radius: usize,
+    pub depends_on: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RepositoryStatus;
+
+    #[test]
+    fn test_parse_manifests() {
+        let cargo = Manifest::parse(
+            "Cargo.toml",
+            r#"
+            [package]
+            name = "acme-api"
+
+            [dependencies]
+            serde = "1"
+            core = { package = "acme-core", git = "https://github.com/acme/core" }
+
+            [dev-dependencies]
+            acme-testing = "0.1"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(cargo.provides, vec!["acme-api"]);
+        assert_eq!(cargo.depends_on, vec!["acme-core", "serde", "acme-testing"]);
+
+        let npm = Manifest::parse(
+            "package.json",
+            r#"{"name": "@acme/web", "dependencies": {"@acme/ui": "^2.0.0"}}"#,
+        )
+        .unwrap();
+        assert_eq!(npm.provides, vec!["@acme/web"]);
+        assert_eq!(npm.depends_on, vec!["@acme/ui"]);
+
+        let go = Manifest::parse(
+            "go.mod",
+            "module github.com/acme/worker\n\ngo 1.22\n\nrequire github.com/acme/core v1.2.0\n\nrequire (\n\tgithub.com/pkg/errors v0.9.1 // indirect\n)\n",
+        )
+        .unwrap();
+        assert_eq!(go.provides, vec!["github.com/acme/worker"]);
+        assert_eq!(
+            go.depends_on,
+            vec!["github.com/acme/core", "github.com/pkg/errors"]
+        );
+
+        assert!(Manifest::parse("package.json", "not json").is_none());
+    }
+
+    #[test]
+    fn test_blast_radius_prioritizes_unhealthy_upstreams() {
+        let manifest = |provides: &str, depends_on: &[&str]| Manifest {
+            provides: vec![provides.to_string()],
+            depends_on: depends_on.iter().map(|d| d.to_string()).collect(),
+        };
+        // core <- api <- web, core <- worker
+        let graph = DependencyGraph::build(&[
+            ("acme/core".to_string(), manifest("core", &["serde"])),
+            ("acme/api".to_string(), manifest("api", &["core"])),
+            ("acme/web".to_string(), manifest("web", &["api"])),
+            ("acme/worker".to_string(), manifest("worker", &["core"])),
+            ("acme/docs".to_string(), manifest("docs", &[])),
+        ]);
+        assert_eq!(
+            graph.dependents_of("acme/core"),
+            vec!["acme/api", "acme/worker"]
+        );
+        assert_eq!(graph.blast_radius("acme/core"), 3);
+        assert_eq!(graph.blast_radius("acme/api"), 1);
+        assert_eq!(graph.blast_radius("acme/web"), 0);
+
+        let repositories: Vec<Repository> = ["core", "api", "web", "worker", "docs"]
+            .iter()
+            .map(|name| {
+                let mut repo = Repository::new(name.to_string(), "acme".to_string());
+                repo.status = if *name == "api" {
+                    RepositoryStatus::Stale
+                } else {
+                    RepositoryStatus::Active
+                };
+                repo
+            })
+            .collect();
+        let rows = graph.prioritized(&repositories, &AttentionConfig::default());
+        let order: Vec<&str> = rows.iter().map(|row| row.repository.as_str()).collect();
+        assert_eq!(
+            order,
+            vec!["acme/api", "acme/core", "acme/web", "acme/worker"]
+        );
+        assert_eq!(rows[0].depends_on, vec!["acme/core"]);
+    }
+}
@@ -0,0 +1,373 @@
+use crate::app::RepositorySort;
+use crate::config::Collector;
+use crate::github::EnhancementProfile;
+use crate::i18n::t_with;
+use ratatui::crossterm::event::KeyCode;
+
+/// A single input in a dialog
+#[derive(Debug, Clone, PartialEq)]
+pub enum DialogField {
+    /// Free-form text input
+    Text {
+        label: String,
+        value: String,
+        required: bool,
+    },
+    /// One of a fixed set of options, changed with ←/→
+    Choice {
+        label: String,
+        options: Vec<String>,
+        selected: usize,
+    },
+}
+
+impl DialogField {
+    /// Create a text field
+    pub fn text(label: &str, required: bool) -> Self {
+        DialogField::Text {
+            label: label.to_string(),
+            value: String::new(),
+            required,
+        }
+    }
+
+    /// Start a text field with `value` already filled in
+    pub fn with_value(mut self, initial: &str) -> Self {
+        if let DialogField::Text { value, .. } = &mut self {
+            *value = initial.to_string();
+        }
+        self
+    }
+
+    /// Create a choice field with the first option selected
+    pub fn choice(label: &str, options: &[&str]) -> Self {
+        DialogField::Choice {
+            label: label.to_string(),
+            options: options.iter().map(|o| o.to_string()).collect(),
+            selected: 0,
+        }
+    }
+
+    /// Label shown next to the field
+    pub fn label(&self) -> &str {
+        match self {
+            DialogField::Text { label, .. } | DialogField::Choice { label, .. } => label,
+        }
+    }
+
+    /// Current value (the selected option for choices)
+    pub fn value(&self) -> &str {
+        match self {
+            DialogField::Text { value, .. } => value,
+            DialogField::Choice {
+                options, selected, ..
+            } => options.get(*selected).map_or("", String::as_str),
+        }
+    }
+}
+
+/// What a dialog does once submitted
+#[derive(Debug, Clone, PartialEq)]
+pub enum DialogAction {
+    /// Create a repository in `owner` from the `template` repository
+    CreateRepository { template: String, owner: String },
+    /// Approve or comment on pull request `number` of `owner/repo`
+    ReviewPullRequest {
+        owner: String,
+        repo: String,
+        number: u32,
+    },
+    /// Save the dashboard's focus mode and sort with these filters as a smart view
+    SaveSmartView,
+    /// Snooze the attention state of `repository` (`owner/name`)
+    SnoozeRepository { repository: String },
+    /// Change the label, assignee and age filters of the issue search
+    FilterIssueSearch,
+    /// Assign, label or comment on issue `number` of `owner/repo`
+    TriageIssue {
+        owner: String,
+        repo: String,
+        number: u64,
+        kind: IssueTriage,
+    },
+}
+
+/// Which triage dialog is open for an issue
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IssueTriage {
+    Assign,
+    Label,
+    Comment,
+}
+
+/// Result of passing a key to a dialog
+#[derive(Debug, Clone, PartialEq)]
+pub enum DialogOutcome {
+    /// The dialog is still open
+    Pending,
+    /// The dialog was submitted with these field values, in order
+    Submitted(Vec<String>),
+    /// The dialog was dismissed
+    Cancelled,
+}
+
+/// A modal form collecting input for an action
+///
+/// While a dialog is open it receives every key press, including keys that
+/// are shortcuts elsewhere (`q`, `r`, ...).
+#[derive(Debug, Clone)]
+pub struct Dialog {
+    pub title: String,
+    pub fields: Vec<DialogField>,
+    pub focused: usize,
+    pub action: DialogAction,
+    /// Validation problem shown under the fields
+    pub error: Option<String>,
+}
+
+impl Dialog {
+    /// Create a dialog with the first field focused
+    pub fn new(title: &str, fields: Vec<DialogField>, action: DialogAction) -> Self {
+        Self {
+            title: title.to_string(),
+            fields,
+            focused: 0,
+            action,
+            error: None,
+        }
+    }
+
+    /// Handle a key press
+    ///
+    /// Tab/↓ and Shift-Tab/↑ move between fields, ←/→ change choices, Enter
+    /// submits and Esc cancels.
+    pub fn handle_key(&mut self, key_code: KeyCode) -> DialogOutcome {
+        match key_code {
+            KeyCode::Esc => return DialogOutcome::Cancelled,
+            KeyCode::Enter => return self.submit(),
+            KeyCode::Tab | KeyCode::Down => {
+                self.focused = (self.focused + 1) % self.fields.len();
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                self.focused = (self.focused + self.fields.len() - 1) % self.fields.len();
+            }
+            _ => match &mut self.fields[self.focused] {
+                DialogField::Text { value, .. } => match key_code {
+                    KeyCode::Char(c) => value.push(c),
+                    KeyCode::Backspace => {
+                        value.pop();
+                    }
+                    _ => {}
+                },
+                DialogField::Choice {
+                    options, selected, ..
+                } => match key_code {
+                    KeyCode::Left => *selected = (*selected + options.len() - 1) % options.len(),
+                    KeyCode::Right | KeyCode::Char(' ') => {
+                        *selected = (*selected + 1) % options.len()
+                    }
+                    _ => {}
+                },
+            },
+        }
+        DialogOutcome::Pending
+    }
+
+    /// Validate required fields and return the values
+    fn submit(&mut self) -> DialogOutcome {
+        let missing = self.fields.iter().position(|field| {
+            matches!(field, DialogField::Text { value, required: true, .. } if value.trim().is_empty())
+        });
+        if let Some(index) = missing {
+            self.error = Some(t_with(
+                "dialog.required",
+                &[("field", &self.fields[index].label())],
+            ));
+            self.focused = index;
+            return DialogOutcome::Pending;
+        }
+
+        DialogOutcome::Submitted(
+            self.fields
+                .iter()
+                .map(|field| field.value().trim().to_string())
+                .collect(),
+        )
+    }
+}
+
+/// An entry in a menu
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuItem {
+    pub label: String,
+    pub action: MenuAction,
+}
+
+/// What choosing a menu item does
+#[derive(Debug, Clone, PartialEq)]
+pub enum MenuAction {
+    /// Open a URL in the default browser
+    OpenUrl(String),
+    /// Show the smart view at this index of the config, or all repositories
+    SelectSmartView(Option<usize>),
+    /// Ask for a name and filters to save the current view under
+    SaveSmartView,
+    /// Show only repositories owned by this team, or all teams
+    FilterTeam(Option<String>),
+    /// Show only repositories carrying this topic, or all topics
+    FilterTopic(Option<String>),
+    /// Read the CODEOWNERS files again
+    ScanOwnership,
+    /// Switch a data collector on or off
+    ToggleCollector(Collector),
+    /// Undo this many of the most recent actions
+    Undo(usize),
+    /// Archive this repository, then ask about the next candidate
+    ArchiveRepository(String),
+    /// Leave the current candidate alone and ask about the next one
+    SkipArchive,
+    /// End the bulk-archive workflow
+    StopArchive,
+    /// Refresh, fetching this much detail
+    Refresh(EnhancementProfile),
+    /// Show the refreshed repository list held back by the canary check
+    AcceptDataset,
+    /// Drop the held repository list and keep showing the previous one
+    KeepPreviousDataset,
+    /// Sort the table by this key, or flip its direction
+    SortBy(RepositorySort),
+    /// Order ties by this key (flipping it when already chosen), or stop
+    ThenSortBy(Option<RepositorySort>),
+}
+
+/// Result of passing a key to a menu
+#[derive(Debug, Clone, PartialEq)]
+pub enum MenuOutcome {
+    /// The menu is still open
+    Pending,
+    /// The item at this index was chosen
+    Selected(usize),
+    /// The menu was dismissed
+    Cancelled,
+}
+
+/// A modal list of items to pick from
+///
+/// Like a dialog, an open menu receives every key press.
+#[derive(Debug, Clone)]
+pub struct Menu {
+    pub title: String,
+    pub items: Vec<MenuItem>,
+    pub selected: usize,
+}
+
+impl Menu {
+    /// Create a menu with the first item selected
+    pub fn new(title: &str, items: Vec<MenuItem>) -> Self {
+        Self {
+            title: title.to_string(),
+            items,
+            selected: 0,
+        }
+    }
+
+    /// Handle a key press
+    ///
+    /// ↑/↓ move the selection, Enter chooses it, 1-9 choose an item directly
+    /// and Esc cancels.
+    pub fn handle_key(&mut self, key_code: KeyCode) -> MenuOutcome {
+        match key_code {
+            KeyCode::Esc | KeyCode::Char('q') => return MenuOutcome::Cancelled,
+            KeyCode::Enter if !self.items.is_empty() => {
+                return MenuOutcome::Selected(self.selected)
+            }
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down if self.selected + 1 < self.items.len() => self.selected += 1,
+            KeyCode::Char(c) => {
+                if let Some(index) = c.to_digit(10).and_then(|d| (d as usize).checked_sub(1)) {
+                    if index < self.items.len() {
+                        return MenuOutcome::Selected(index);
+                    }
+                }
+            }
+            _ => {}
+        }
+        MenuOutcome::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::t;
+
+    fn dialog() -> Dialog {
+        Dialog::new(
+            t("dialog.new_repository"),
+            vec![
+                DialogField::text(t("dialog.name"), true),
+                DialogField::choice(
+                    t("dialog.visibility"),
+                    &[t("dialog.private"), t("dialog.public")],
+                ),
+            ],
+            DialogAction::CreateRepository {
+                template: "acme/template".to_string(),
+                owner: "acme".to_string(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_dialog_collects_values() {
+        let mut dialog = dialog();
+        for c in "qr-api".chars() {
+            assert_eq!(dialog.handle_key(KeyCode::Char(c)), DialogOutcome::Pending);
+        }
+        dialog.handle_key(KeyCode::Backspace);
+        dialog.handle_key(KeyCode::Char('x'));
+        dialog.handle_key(KeyCode::Tab);
+        dialog.handle_key(KeyCode::Right);
+
+        assert_eq!(
+            dialog.handle_key(KeyCode::Enter),
+            DialogOutcome::Submitted(vec!["qr-apx".to_string(), "public".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_required_fields_block_submit() {
+        let mut dialog = dialog();
+        dialog.handle_key(KeyCode::Tab);
+
+        assert_eq!(dialog.handle_key(KeyCode::Enter), DialogOutcome::Pending);
+        assert_eq!(dialog.focused, 0);
+        assert!(dialog.error.as_deref().unwrap().contains(t("dialog.name")));
+        assert_eq!(dialog.handle_key(KeyCode::Esc), DialogOutcome::Cancelled);
+    }
+
+    #[test]
+    fn test_menu_selection() {
+        let item = |label: &str| MenuItem {
+            label: label.to_string(),
+            action: MenuAction::OpenUrl(format!("https://github.com/acme/api/{}", label)),
+        };
+        let mut menu = Menu::new(
+            "acme/api",
+            vec![item("actions"), item("pulls"), item("issues")],
+        );
+
+        assert_eq!(menu.handle_key(KeyCode::Up), MenuOutcome::Pending);
+        menu.handle_key(KeyCode::Down);
+        menu.handle_key(KeyCode::Down);
+        menu.handle_key(KeyCode::Down);
+        assert_eq!(menu.handle_key(KeyCode::Enter), MenuOutcome::Selected(2));
+
+        assert_eq!(
+            menu.handle_key(KeyCode::Char('2')),
+            MenuOutcome::Selected(1)
+        );
+        assert_eq!(menu.handle_key(KeyCode::Char('9')), MenuOutcome::Pending);
+        assert_eq!(menu.handle_key(KeyCode::Esc), MenuOutcome::Cancelled);
+    }
+}
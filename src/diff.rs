@@ -0,0 +1,377 @@
+use crate::config::{AppConfig, ReportFormat};
+use crate::report::{report_files, report_stamp};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+/// A JSON health report read back from the reports directory
+#[derive(Debug, Clone, Deserialize)]
+pub struct Snapshot {
+    pub generated_at: String,
+    pub scope: String,
+    pub totals: SnapshotTotals,
+    pub repositories: Vec<SnapshotRow>,
+}
+
+/// Totals of a stored snapshot
+#[derive(Debug, Clone, Deserialize)]
+pub struct SnapshotTotals {
+    pub repositories: usize,
+    pub average_health: Option<u8>,
+    pub needs_attention: usize,
+    pub open_pull_requests: usize,
+    pub ci_broken: usize,
+}
+
+impl SnapshotTotals {
+    /// Percentage of repositories whose latest workflow run isn't failing
+    pub fn ci_pass_rate(&self) -> Option<f64> {
+        (self.repositories > 0)
+            .then(|| 100.0 * (self.repositories - self.ci_broken) as f64 / self.repositories as f64)
+    }
+}
+
+/// One repository's line in a stored snapshot
+#[derive(Debug, Clone, Deserialize)]
+pub struct SnapshotRow {
+    pub repository: String,
+    pub health_score: u8,
+    pub open_pull_requests: usize,
+    pub needs_attention: bool,
+    /// Missing from reports written before it was recorded
+    #[serde(default)]
+    pub latest_commit_at: Option<DateTime<Utc>>,
+}
+
+impl Snapshot {
+    /// Parse a JSON health report
+    pub fn parse(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Invalid health report: {}", e))
+    }
+
+    /// Read a JSON health report from disk
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        Self::parse(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
+
+/// The latest JSON report written on or before a date
+pub fn find_snapshot(directory: &Path, date: NaiveDate) -> Result<PathBuf, String> {
+    let day = date.format("%Y%m%d").to_string();
+    report_files(directory, ReportFormat::Json.extension())?
+        .into_iter()
+        .rfind(|path| {
+            report_stamp(path)
+                .and_then(|stamp| stamp.get(..8))
+                .is_some_and(|stamp_day| stamp_day <= day.as_str())
+        })
+        .ok_or_else(|| {
+            format!(
+                "No JSON report on or before {} in {}",
+                date,
+                directory.display()
+            )
+        })
+}
+
+/// A repository whose health score changed between snapshots
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthChange {
+    pub repository: String,
+    pub before: u8,
+    pub after: u8,
+}
+
+impl HealthChange {
+    pub fn delta(&self) -> i64 {
+        self.after as i64 - self.before as i64
+    }
+}
+
+/// What changed between two health snapshots
+#[derive(Debug, Clone)]
+pub struct HealthDiff {
+    pub from: String,
+    pub to: String,
+    /// Repositories whose score dropped, largest drop first
+    pub degraded: Vec<HealthChange>,
+    /// Repositories whose score rose, largest rise first
+    pub improved: Vec<HealthChange>,
+    /// Repositories that started needing attention
+    pub newly_needing_attention: Vec<String>,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub average_health: (Option<u8>, Option<u8>),
+    pub open_pull_requests: (usize, usize),
+    pub needs_attention: (usize, usize),
+    pub ci_pass_rate: (Option<f64>, Option<f64>),
+}
+
+impl HealthDiff {
+    /// Compare an older snapshot with a newer one
+    pub fn between(before: &Snapshot, after: &Snapshot) -> Self {
+        let previous: HashMap<&str, &SnapshotRow> = before
+            .repositories
+            .iter()
+            .map(|row| (row.repository.as_str(), row))
+            .collect();
+
+        let mut degraded = Vec::new();
+        let mut improved = Vec::new();
+        let mut newly_needing_attention = Vec::new();
+        let mut added = Vec::new();
+        for row in &after.repositories {
+            let Some(old) = previous.get(row.repository.as_str()) else {
+                added.push(row.repository.clone());
+                continue;
+            };
+            let change = HealthChange {
+                repository: row.repository.clone(),
+                before: old.health_score,
+                after: row.health_score,
+            };
+            if change.delta() < 0 {
+                degraded.push(change);
+            } else if change.delta() > 0 {
+                improved.push(change);
+            }
+            if row.needs_attention && !old.needs_attention {
+                newly_needing_attention.push(row.repository.clone());
+            }
+        }
+        degraded.sort_by_key(|change| change.delta());
+        improved.sort_by_key(|change| -change.delta());
+
+        let current: Vec<&str> = after
+            .repositories
+            .iter()
+            .map(|row| row.repository.as_str())
+            .collect();
+        let removed = before
+            .repositories
+            .iter()
+            .filter(|row| !current.contains(&row.repository.as_str()))
+            .map(|row| row.repository.clone())
+            .collect();
+
+        Self {
+            from: before.generated_at.clone(),
+            to: after.generated_at.clone(),
+            degraded,
+            improved,
+            newly_needing_attention,
+            added,
+            removed,
+            average_health: (before.totals.average_health, after.totals.average_health),
+            open_pull_requests: (
+                before.totals.open_pull_requests,
+                after.totals.open_pull_requests,
+            ),
+            needs_attention: (before.totals.needs_attention, after.totals.needs_attention),
+            ci_pass_rate: (before.totals.ci_pass_rate(), after.totals.ci_pass_rate()),
+        }
+    }
+
+    /// Render the diff as Markdown, ready to paste into meeting notes
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# Health Diff\n\n{} → {}\n\n", self.from, self.to);
+        out.push_str("| Metric | Before | After | Change |\n|---|---|---|---|\n");
+
+        let (before, after) = self.average_health;
+        out.push_str(&format!(
+            "| Average health | {} | {} | {} |\n",
+            before.map_or("n/a".to_string(), |score| score.to_string()),
+            after.map_or("n/a".to_string(), |score| score.to_string()),
+            match (before, after) {
+                (Some(before), Some(after)) => signed(after as i64 - before as i64),
+                _ => "n/a".to_string(),
+            }
+        ));
+        let (before, after) = self.open_pull_requests;
+        out.push_str(&format!(
+            "| Open PRs | {} | {} | {} |\n",
+            before,
+            after,
+            signed(after as i64 - before as i64)
+        ));
+        let (before, after) = self.needs_attention;
+        out.push_str(&format!(
+            "| Need attention | {} | {} | {} |\n",
+            before,
+            after,
+            signed(after as i64 - before as i64)
+        ));
+        let (before, after) = self.ci_pass_rate;
+        out.push_str(&format!(
+            "| CI pass rate | {} | {} | {} |\n",
+            before.map_or("n/a".to_string(), |rate| format!("{:.0}%", rate)),
+            after.map_or("n/a".to_string(), |rate| format!("{:.0}%", rate)),
+            match (before, after) {
+                (Some(before), Some(after)) => format!("{:+.0} pts", after - before),
+                _ => "n/a".to_string(),
+            }
+        ));
+
+        let changes = |title: &str, changes: &[HealthChange]| {
+            let mut section = format!("\n## {} ({})\n\n", title, changes.len());
+            for change in changes {
+                section.push_str(&format!(
+                    "- {}: {} → {} ({})\n",
+                    change.repository,
+                    change.before,
+                    change.after,
+                    signed(change.delta())
+                ));
+            }
+            section
+        };
+        out.push_str(&changes("Degraded", &self.degraded));
+        out.push_str(&changes("Improved", &self.improved));
+
+        for (title, names) in [
+            ("Newly needing attention", &self.newly_needing_attention),
+            ("New repositories", &self.added),
+            ("Removed repositories", &self.removed),
+        ] {
+            if !names.is_empty() {
+                out.push_str(&format!("\n## {} ({})\n\n", title, names.len()));
+                for name in names {
+                    out.push_str(&format!("- {}\n", name));
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Format a change with an explicit sign (e.g. "+3", "-12", "0")
+pub fn signed(delta: i64) -> String {
+    if delta == 0 {
+        "0".to_string()
+    } else {
+        format!("{:+}", delta)
+    }
+}
+
+/// Resolve a `--diff` argument: a path to a JSON report, or a date (YYYY-MM-DD)
+/// selecting the latest report written on or before it
+fn resolve(argument: &str, directory: &Path) -> Result<PathBuf, String> {
+    let path = PathBuf::from(argument);
+    if path.is_file() {
+        return Ok(path);
+    }
+    let date = NaiveDate::parse_from_str(argument, "%Y-%m-%d").map_err(|_| {
+        format!(
+            "'{}' is neither a report file nor a date (YYYY-MM-DD)",
+            argument
+        )
+    })?;
+    find_snapshot(directory, date)
+}
+
+/// Print what changed between two stored snapshots (`--diff FROM TO`)
+pub fn run(from: &str, to: &str) -> Result<(), Box<dyn Error>> {
+    let config = AppConfig::load()?;
+    let directory = config
+        .reports
+        .directory()
+        .ok_or("No data directory available; set [reports] directory")?;
+
+    let before = Snapshot::load(&resolve(from, &directory)?)?;
+    let after = Snapshot::load(&resolve(to, &directory)?)?;
+    print!("{}", HealthDiff::between(&before, &after).to_markdown());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AttentionConfig;
+    use crate::models::{Repository, RepositoryStatus};
+    use crate::report::HealthReport;
+    use chrono::{TimeZone, Utc};
+
+    fn snapshot(day: u32, statuses: &[(&str, RepositoryStatus)]) -> HealthReport {
+        let repositories: Vec<Repository> = statuses
+            .iter()
+            .map(|(name, status)| {
+                let mut repo = Repository::new(name.to_string(), "acme".to_string());
+                repo.status = status.clone();
+                repo
+            })
+            .collect();
+        HealthReport::new(
+            "Org: acme".to_string(),
+            &repositories,
+            &AttentionConfig::default(),
+            Utc.with_ymd_and_hms(2026, 1, day, 12, 0, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_diff_between_snapshots() {
+        let before = Snapshot::parse(
+            &snapshot(
+                1,
+                &[
+                    ("api", RepositoryStatus::Active),
+                    ("web", RepositoryStatus::Stale),
+                    ("legacy", RepositoryStatus::Dormant),
+                ],
+            )
+            .render(ReportFormat::Json),
+        )
+        .unwrap();
+        let after = Snapshot::parse(
+            &snapshot(
+                8,
+                &[
+                    ("api", RepositoryStatus::Dormant),
+                    ("web", RepositoryStatus::Active),
+                    ("docs", RepositoryStatus::Active),
+                ],
+            )
+            .render(ReportFormat::Json),
+        )
+        .unwrap();
+
+        let diff = HealthDiff::between(&before, &after);
+        assert_eq!(diff.degraded.len(), 1);
+        assert_eq!(diff.degraded[0].repository, "acme/api");
+        assert!(diff.degraded[0].delta() < 0);
+        assert_eq!(diff.improved[0].repository, "acme/web");
+        assert_eq!(diff.newly_needing_attention, vec!["acme/api"]);
+        assert_eq!(diff.added, vec!["acme/docs"]);
+        assert_eq!(diff.removed, vec!["acme/legacy"]);
+        assert_eq!(diff.ci_pass_rate, (Some(100.0), Some(100.0)));
+
+        let markdown = diff.to_markdown();
+        assert!(markdown.contains("| Open PRs | 0 | 0 | 0 |"));
+        assert!(markdown.contains("## Degraded (1)\n\n- acme/api: "));
+        assert!(markdown.contains("## Removed repositories (1)\n\n- acme/legacy\n"));
+    }
+
+    #[test]
+    fn test_find_snapshot_on_or_before_date() {
+        let directory =
+            std::env::temp_dir().join(format!("gh-repo-healthchecks-diff-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        for day in [1, 3, 8] {
+            snapshot(day, &[("api", RepositoryStatus::Active)])
+                .write(&directory, &[ReportFormat::Json], 10)
+                .unwrap();
+        }
+
+        let date = |day| NaiveDate::from_ymd_opt(2026, 1, day).unwrap();
+        let found = find_snapshot(&directory, date(5)).unwrap();
+        assert_eq!(report_stamp(&found), Some("20260103T120000Z"));
+        let found = find_snapshot(&directory, date(8)).unwrap();
+        assert_eq!(report_stamp(&found), Some("20260108T120000Z"));
+        assert!(find_snapshot(&directory, NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()).is_err());
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,334 @@
+use crate::config::{AppConfig, ReportFormat};
+use crate::diff::{find_snapshot, signed, HealthChange, HealthDiff, Snapshot, SnapshotRow};
+use crate::report::{
+    apply_retention, escape_html, report_files, report_stamp, DIGEST_PREFIX, STAMP_FORMAT,
+};
+use crate::smtp;
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+/// Regressions and improvements listed in a digest
+const TOP_CHANGES: usize = 5;
+
+/// Repositories needing attention listed before the rest are summarized
+const TOP_ATTENTION: usize = 10;
+
+/// Weekly summary of repository health, composed from stored snapshots
+#[derive(Debug, Clone)]
+pub struct WeeklyDigest {
+    /// The newest snapshot the digest describes
+    pub latest: Snapshot,
+    /// Week-over-week changes; None until a report a week older exists
+    pub comparison: Option<HealthDiff>,
+}
+
+impl WeeklyDigest {
+    /// Compose a digest from the latest snapshot and one from a week before
+    pub fn new(latest: Snapshot, week_before: Option<&Snapshot>) -> Self {
+        let comparison = week_before.map(|before| HealthDiff::between(before, &latest));
+        Self { latest, comparison }
+    }
+
+    /// Email subject line
+    pub fn subject(&self) -> String {
+        format!(
+            "Repository health digest: {} ({})",
+            self.latest.scope,
+            self.latest.generated_at.get(..10).unwrap_or_default()
+        )
+    }
+
+    /// Repositories needing attention, least healthy first
+    pub fn needing_attention(&self) -> Vec<&SnapshotRow> {
+        let mut rows: Vec<&SnapshotRow> = self
+            .latest
+            .repositories
+            .iter()
+            .filter(|row| row.needs_attention)
+            .collect();
+        rows.sort_by_key(|row| row.health_score);
+        rows
+    }
+
+    /// Render the digest as a self-contained HTML email
+    pub fn to_html(&self) -> String {
+        let totals = &self.latest.totals;
+        let mut out = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+             <style>body{{font-family:sans-serif}}table{{border-collapse:collapse}}td,th{{padding:4px 8px;border:1px solid #ccc}}\
+             .down{{color:#b42318}}.up{{color:#067647}}</style>\n</head>\n<body>\n\
+             <h1>Weekly Repository Health Digest</h1>\n<p>{}</p>\n",
+            escape_html(&self.subject()),
+            escape_html(&self.latest.scope)
+        );
+
+        match &self.comparison {
+            Some(diff) => {
+                out.push_str(&format!(
+                    "<p>{} → {}</p>\n<table>\n<tr><th>Metric</th><th>Last week</th><th>This week</th><th>Change</th></tr>\n",
+                    escape_html(&diff.from),
+                    escape_html(&diff.to)
+                ));
+                let score = |score: Option<u8>| score.map_or("n/a".to_string(), |s| s.to_string());
+                let (before, after) = diff.average_health;
+                let change = match (before, after) {
+                    (Some(before), Some(after)) => signed(after as i64 - before as i64),
+                    _ => "n/a".to_string(),
+                };
+                out.push_str(&metric_row("Average health", score(before), score(after), change));
+                for (name, (before, after)) in [
+                    ("Need attention", diff.needs_attention),
+                    ("Open PRs", diff.open_pull_requests),
+                ] {
+                    out.push_str(&metric_row(
+                        name,
+                        before.to_string(),
+                        after.to_string(),
+                        signed(after as i64 - before as i64),
+                    ));
+                }
+                out.push_str("</table>\n");
+
+                out.push_str(&changes_section("Top regressions", "down", &diff.degraded));
+                if !diff.newly_needing_attention.is_empty() {
+                    out.push_str(&format!(
+                        "<p>Newly needing attention: {}</p>\n",
+                        escape_html(&diff.newly_needing_attention.join(", "))
+                    ));
+                }
+            }
+            None => out.push_str(&format!(
+                "<p>{} repositories · average health {} · {} need attention · {} open PRs</p>\n\
+                 <p>No report from a week earlier yet, so there is no week-over-week comparison.</p>\n",
+                totals.repositories,
+                totals
+                    .average_health
+                    .map_or("n/a".to_string(), |score| score.to_string()),
+                totals.needs_attention,
+                totals.open_pull_requests
+            )),
+        }
+
+        let attention = self.needing_attention();
+        out.push_str(&format!(
+            "<h2>Needing attention ({})</h2>\n",
+            attention.len()
+        ));
+        if attention.is_empty() {
+            out.push_str("<p>Nothing needs attention.</p>\n");
+        } else {
+            out.push_str("<ul>\n");
+            for row in attention.iter().take(TOP_ATTENTION) {
+                out.push_str(&format!(
+                    "<li>{} (health {}, {} open PRs)</li>\n",
+                    escape_html(&row.repository),
+                    row.health_score,
+                    row.open_pull_requests
+                ));
+            }
+            out.push_str("</ul>\n");
+            if attention.len() > TOP_ATTENTION {
+                out.push_str(&format!(
+                    "<p>…and {} more.</p>\n",
+                    attention.len() - TOP_ATTENTION
+                ));
+            }
+        }
+
+        if let Some(diff) = &self.comparison {
+            out.push_str(&changes_section(
+                "Notable improvements",
+                "up",
+                &diff.improved,
+            ));
+        }
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+
+    /// Store the rendered digest in the reports directory, stamped with `at`
+    pub fn archive(&self, directory: &Path, at: DateTime<Utc>) -> Result<PathBuf, String> {
+        let path = directory.join(format!("{}{}.html", DIGEST_PREFIX, at.format(STAMP_FORMAT)));
+        std::fs::write(&path, self.to_html())
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        Ok(path)
+    }
+}
+
+/// A row of the week-over-week totals table
+fn metric_row(name: &str, before: String, after: String, change: String) -> String {
+    format!(
+        "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+        name, before, after, change
+    )
+}
+
+/// The largest score changes as an HTML section
+fn changes_section(title: &str, class: &str, changes: &[HealthChange]) -> String {
+    let mut section = format!("<h2>{}</h2>\n", title);
+    if changes.is_empty() {
+        section.push_str("<p>None this week.</p>\n");
+        return section;
+    }
+    section.push_str("<ul>\n");
+    for change in changes.iter().take(TOP_CHANGES) {
+        section.push_str(&format!(
+            "<li>{}: {} → {} <span class=\"{}\">({})</span></li>\n",
+            escape_html(&change.repository),
+            change.before,
+            change.after,
+            class,
+            signed(change.delta())
+        ));
+    }
+    section.push_str("</ul>\n");
+    section
+}
+
+/// The latest JSON report, and the latest one written at least a week before it
+pub fn find_week(directory: &Path) -> Result<(PathBuf, Option<PathBuf>), String> {
+    let latest = report_files(directory, ReportFormat::Json.extension())?
+        .pop()
+        .ok_or_else(|| format!("No JSON reports in {}", directory.display()))?;
+    let day = report_stamp(&latest)
+        .and_then(|stamp| stamp.get(..8))
+        .and_then(|day| NaiveDate::parse_from_str(day, "%Y%m%d").ok())
+        .ok_or_else(|| format!("Unexpected report name {}", latest.display()))?;
+    let week_before = day
+        .checked_sub_days(Days::new(7))
+        .and_then(|date| find_snapshot(directory, date).ok());
+    Ok((latest, week_before))
+}
+
+/// Compose the weekly digest from stored reports (`--digest`)
+///
+/// The digest is written to `[digest] output` and/or sent through
+/// `[digest.smtp]`; with neither configured it is printed.
+pub async fn run() -> Result<(), Box<dyn Error>> {
+    let config = AppConfig::load()?;
+    let directory = config
+        .reports
+        .directory()
+        .ok_or("No data directory available; set [reports] directory")?;
+
+    let (latest, week_before) = find_week(&directory)?;
+    let week_before = week_before.map(|path| Snapshot::load(&path)).transpose()?;
+    let digest = WeeklyDigest::new(Snapshot::load(&latest)?, week_before.as_ref());
+    let html = digest.to_html();
+    // Digests are kept with the reports, under the same retention policy
+    digest.archive(&directory, Utc::now())?;
+    apply_retention(&directory, &config.reports, Utc::now())?;
+
+    let settings = &config.digest;
+    if let Some(path) = &settings.output {
+        std::fs::write(path, &html)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        println!("Wrote digest to {}", path.display());
+    }
+    if let Some(smtp) = &settings.smtp {
+        smtp::send(
+            smtp,
+            config.network.ca_bundle.as_deref(),
+            &digest.subject(),
+            &html,
+        )
+        .await?;
+        println!("Sent digest to {}", smtp.to.join(", "));
+    }
+    if settings.output.is_none() && settings.smtp.is_none() {
+        print!("{}", html);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AttentionConfig;
+    use crate::models::{Repository, RepositoryStatus};
+    use crate::report::{archived_reports, ArchiveKind, HealthReport};
+    use chrono::TimeZone;
+
+    fn report(day: u32, statuses: &[(&str, RepositoryStatus)]) -> HealthReport {
+        let repositories: Vec<Repository> = statuses
+            .iter()
+            .map(|(name, status)| {
+                let mut repo = Repository::new(name.to_string(), "acme".to_string());
+                repo.status = status.clone();
+                repo
+            })
+            .collect();
+        HealthReport::new(
+            "Org: acme".to_string(),
+            &repositories,
+            &AttentionConfig::default(),
+            Utc.with_ymd_and_hms(2026, 3, day, 12, 0, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_digest_compares_with_the_report_a_week_earlier() {
+        let directory = std::env::temp_dir().join(format!("gh-digest-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        let write = |report: HealthReport| {
+            report.write(&directory, &[ReportFormat::Json], 10).unwrap();
+        };
+        write(report(
+            1,
+            &[
+                ("api", RepositoryStatus::Active),
+                ("web", RepositoryStatus::Dormant),
+            ],
+        ));
+        write(report(
+            5,
+            &[
+                ("api", RepositoryStatus::Stale),
+                ("web", RepositoryStatus::Stale),
+            ],
+        ));
+        write(report(
+            9,
+            &[
+                ("api", RepositoryStatus::Dormant),
+                ("web", RepositoryStatus::Active),
+            ],
+        ));
+
+        // Day 9's week-before report is the latest one on or before day 2
+        let (latest, week_before) = find_week(&directory).unwrap();
+        let week_before = Snapshot::load(&week_before.unwrap()).unwrap();
+        assert!(week_before.generated_at.starts_with("2026-03-01"));
+
+        let digest = WeeklyDigest::new(Snapshot::load(&latest).unwrap(), Some(&week_before));
+        assert_eq!(
+            digest.subject(),
+            "Repository health digest: Org: acme (2026-03-09)"
+        );
+        let diff = digest.comparison.as_ref().unwrap();
+        assert_eq!(diff.degraded[0].repository, "acme/api");
+        assert_eq!(diff.improved[0].repository, "acme/web");
+        let html = digest.to_html();
+        assert!(html.contains("<h2>Top regressions</h2>\n<ul>\n<li>acme/api"));
+        assert!(html.contains("<h2>Notable improvements</h2>\n<ul>\n<li>acme/web"));
+
+        let first_week = WeeklyDigest::new(week_before, None);
+        assert!(first_week
+            .to_html()
+            .contains("No report from a week earlier yet"));
+
+        // The archived digest doesn't count as a report
+        digest
+            .archive(
+                &directory,
+                Utc.with_ymd_and_hms(2026, 3, 9, 13, 0, 0).unwrap(),
+            )
+            .unwrap();
+        assert_eq!(find_week(&directory).unwrap().0, latest);
+        let archived = archived_reports(&directory).unwrap();
+        assert_eq!(archived[0].kind, ArchiveKind::Digest);
+        assert_eq!(archived[0].format, ReportFormat::Html);
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -1,4 +1,4 @@
-use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use ratatui::crossterm::event::{self, Event, KeyEvent, KeyEventKind};
 use std::{io, time::Duration};
 
 /// Event handling utilities
@@ -37,44 +37,14 @@ impl EventHandler {
         // Poll for events with the configured timeout
         // This prevents the app from consuming too much CPU while idle
         if event::poll(self.poll_timeout)? {
-            match event::read()? {
-                Event::Key(key_event) => {
-                    // Only process key press events, ignore key release events
-                    // This prevents double-triggering on systems that send both
-                    if key_event.kind == KeyEventKind::Press {
-                        Ok(Some(AppEvent::Key(key_event)))
-                    } else {
-                        Ok(None)
-                    }
-                }
-                Event::Mouse(mouse_event) => {
-                    // Future: Handle mouse events for enhanced interaction
-                    Ok(Some(AppEvent::Mouse(mouse_event)))
-                }
-                Event::Resize(width, height) => {
-                    // Handle terminal resize events
-                    Ok(Some(AppEvent::Resize(width, height)))
-                }
-                _ => {
-                    // Ignore other event types for now
-                    Ok(None)
-                }
-            }
+            // Only key presses are passed on, so systems that also report key
+            // releases don't trigger twice
+            Ok(EventProcessor::process_event(event::read()?))
         } else {
             // No events available within timeout
             Ok(None)
         }
     }
-    
-    /// Get the current poll timeout
-    pub fn poll_timeout(&self) -> Duration {
-        self.poll_timeout
-    }
-    
-    /// Set a new poll timeout
-    pub fn set_poll_timeout(&mut self, timeout: Duration) {
-        self.poll_timeout = timeout;
-    }
 }
 
 impl Default for EventHandler {
@@ -98,6 +68,9 @@ pub enum AppEvent {
     
     /// Terminal resize event
     Resize(u16, u16),
+
+    /// The terminal gained (true) or lost (false) focus
+    Focus(bool),
     
     // Future: Application-specific events
     // These could include things like:
@@ -109,39 +82,6 @@ pub enum AppEvent {
     // NetworkStatus(bool),
 }
 
-impl AppEvent {
-    /// Extract key code from a key event
-    /// 
-    /// Returns None if this is not a key event
-    pub fn key_code(&self) -> Option<KeyCode> {
-        match self {
-            AppEvent::Key(key_event) => Some(key_event.code),
-            _ => None,
-        }
-    }
-    
-    /// Check if this is a specific key press
-    pub fn is_key(&self, target_key: KeyCode) -> bool {
-        self.key_code() == Some(target_key)
-    }
-    
-    /// Check if this is a quit event (q, Q, or Escape)
-    pub fn is_quit(&self) -> bool {
-        match self.key_code() {
-            Some(KeyCode::Char('q')) | Some(KeyCode::Char('Q')) | Some(KeyCode::Esc) => true,
-            _ => false,
-        }
-    }
-    
-    /// Check if this is a refresh event (r, R, or F5)
-    pub fn is_refresh(&self) -> bool {
-        match self.key_code() {
-            Some(KeyCode::Char('r')) | Some(KeyCode::Char('R')) | Some(KeyCode::F(5)) => true,
-            _ => false,
-        }
-    }
-}
-
 /// Event processing utilities
 pub struct EventProcessor;
 
@@ -161,47 +101,40 @@ impl EventProcessor {
             Event::Resize(width, height) => {
                 Some(AppEvent::Resize(width, height))
             }
+            Event::FocusGained => Some(AppEvent::Focus(true)),
+            Event::FocusLost => Some(AppEvent::Focus(false)),
             _ => None,
         }
     }
-    
-    /// Check if an event should be handled immediately (like quit events)
-    pub fn is_priority_event(event: &AppEvent) -> bool {
-        event.is_quit()
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ratatui::crossterm::event::{KeyEvent, KeyModifiers};
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
     #[test]
     fn test_event_handler_creation() {
         let handler = EventHandler::new();
-        assert_eq!(handler.poll_timeout(), Duration::from_millis(100));
+        assert_eq!(handler.poll_timeout, Duration::from_millis(100));
         
         let custom_handler = EventHandler::with_timeout(50);
-        assert_eq!(custom_handler.poll_timeout(), Duration::from_millis(50));
+        assert_eq!(custom_handler.poll_timeout, Duration::from_millis(50));
     }
 
     #[test]
-    fn test_app_event_key_detection() {
-        let quit_event = AppEvent::Key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE));
-        assert!(quit_event.is_quit());
-        assert!(!quit_event.is_refresh());
-        
-        let refresh_event = AppEvent::Key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE));
-        assert!(refresh_event.is_refresh());
-        assert!(!refresh_event.is_quit());
-    }
+    fn test_process_event_skips_key_releases() {
+        let mut key = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        assert!(matches!(
+            EventProcessor::process_event(Event::Key(key)),
+            Some(AppEvent::Key(event)) if event.code == KeyCode::Char('q')
+        ));
 
-    #[test]
-    fn test_event_key_code_extraction() {
-        let key_event = AppEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
-        assert_eq!(key_event.key_code(), Some(KeyCode::Enter));
-        
-        let resize_event = AppEvent::Resize(80, 24);
-        assert_eq!(resize_event.key_code(), None);
+        key.kind = KeyEventKind::Release;
+        assert!(EventProcessor::process_event(Event::Key(key)).is_none());
+        assert!(matches!(
+            EventProcessor::process_event(Event::FocusLost),
+            Some(AppEvent::Focus(false))
+        ));
     }
 }
@@ -0,0 +1,270 @@
+use crate::i18n::{t, t_with};
+
+/// Lines read from the end of a failing job's log
+pub const LOG_TAIL_LINES: usize = 100;
+
+/// Most recent failed runs per repository whose logs are downloaded
+pub const RUNS_PER_REPOSITORY: usize = 3;
+
+/// Longest signature kept, so one noisy line doesn't swamp the view
+const MAX_SIGNATURE_LENGTH: usize = 100;
+
+/// Log lines that show up in every failure and say nothing about the cause
+const GENERIC_LINES: [&str; 2] = [
+    "process completed with exit code",
+    "the operation was canceled",
+];
+
+/// Words marking a log line as describing a failure
+const ERROR_MARKERS: [&str; 9] = [
+    "error",
+    "failed",
+    "failure",
+    "panicked",
+    "exception",
+    "fatal",
+    "not found",
+    "denied",
+    "secret",
+];
+
+/// A failed job of a workflow run
+#[derive(Debug, Clone, PartialEq)]
+pub struct FailedJob {
+    pub repository: String,
+    pub workflow: String,
+    pub job: String,
+    pub html_url: String,
+}
+
+/// Failed jobs whose logs share an error signature
+#[derive(Debug, Clone, PartialEq)]
+pub struct FailureCluster {
+    pub signature: String,
+    pub failures: Vec<FailedJob>,
+}
+
+impl FailureCluster {
+    /// Number of distinct repositories with a failure in the cluster
+    pub fn repository_count(&self) -> usize {
+        let mut repositories: Vec<&str> = self
+            .failures
+            .iter()
+            .map(|failure| failure.repository.as_str())
+            .collect();
+        repositories.sort_unstable();
+        repositories.dedup();
+        repositories.len()
+    }
+
+    /// One-line summary, e.g. "8 failures across 3 repos share ..."
+    pub fn summary(&self) -> String {
+        let repositories = self.repository_count();
+        let failures = match self.failures.len() {
+            1 => t("failures.one_failure").to_string(),
+            count => t_with("failures.failures", &[("count", &count)]),
+        };
+        let repositories = match repositories {
+            1 => t("failures.one_repository").to_string(),
+            count => t_with("failures.repositories", &[("count", &count)]),
+        };
+        t_with(
+            if self.failures.len() == 1 {
+                "failures.summary_one"
+            } else {
+                "failures.summary"
+            },
+            &[
+                ("failures", &failures),
+                ("repositories", &repositories),
+                ("signature", &self.signature),
+            ],
+        )
+    }
+}
+
+/// The last `lines` lines of a log
+pub fn log_tail(log: &str, lines: usize) -> String {
+    let all: Vec<&str> = log.lines().collect();
+    all[all.len().saturating_sub(lines)..].join("\n")
+}
+
+/// Normalized error signature of a failing job's log tail
+///
+/// Takes the first line annotated as an error or mentioning one that isn't
+/// a line GitHub adds to every failure, and blanks out what differs between otherwise identical
+/// failures: timestamps, numbers, hashes and temporary paths. Returns `None`
+/// when no line looks like an error.
+pub fn error_signature(log_tail: &str) -> Option<String> {
+    log_tail.lines().find_map(|line| {
+        let annotated = line.contains("##[error]");
+        let line = strip_log_prefix(line);
+        let lower = line.to_lowercase();
+        let is_error = annotated || ERROR_MARKERS.iter().any(|marker| lower.contains(marker));
+        let generic = GENERIC_LINES.iter().any(|generic| lower.contains(generic));
+        (is_error && !generic && !line.is_empty()).then(|| normalize(line))
+    })
+}
+
+/// Group failed jobs by error signature, largest cluster first
+pub fn cluster(failures: Vec<(String, FailedJob)>) -> Vec<FailureCluster> {
+    let mut clusters: Vec<FailureCluster> = Vec::new();
+    for (signature, failure) in failures {
+        match clusters
+            .iter_mut()
+            .find(|cluster| cluster.signature == signature)
+        {
+            Some(cluster) => cluster.failures.push(failure),
+            None => clusters.push(FailureCluster {
+                signature,
+                failures: vec![failure],
+            }),
+        }
+    }
+    clusters.sort_by(|a, b| {
+        b.failures
+            .len()
+            .cmp(&a.failures.len())
+            .then(b.repository_count().cmp(&a.repository_count()))
+    });
+    clusters
+}
+
+/// Strip the timestamp and error annotation GitHub prefixes log lines with
+fn strip_log_prefix(line: &str) -> &str {
+    let line = line.trim();
+    let line = match line.split_once(' ') {
+        Some((stamp, rest)) if stamp.len() >= 20 && stamp.ends_with('Z') && stamp.contains('T') => {
+            rest
+        }
+        _ => line,
+    };
+    line.trim_start_matches("##[error]").trim()
+}
+
+/// Replace the parts of a line that vary between runs with placeholders
+fn normalize(line: &str) -> String {
+    let mut signature = String::new();
+    for word in line.split_whitespace() {
+        if !signature.is_empty() {
+            signature.push(' ');
+        }
+        signature.push_str(&normalize_word(word));
+    }
+    if signature.chars().count() > MAX_SIGNATURE_LENGTH {
+        signature = signature.chars().take(MAX_SIGNATURE_LENGTH - 1).collect();
+        signature.push('…');
+    }
+    signature
+}
+
+fn normalize_word(word: &str) -> String {
+    let word = strip_ansi(word);
+    if word.starts_with("/tmp/") || word.starts_with("/home/runner/work/_temp/") {
+        return "<path>".to_string();
+    }
+    let trimmed = word.trim_matches(|c: char| !c.is_ascii_alphanumeric());
+    if trimmed.len() >= 7
+        && trimmed.chars().all(|c| c.is_ascii_hexdigit())
+        && trimmed.chars().any(|c| c.is_ascii_digit())
+    {
+        return word.replace(trimmed, "<hash>");
+    }
+
+    // Collapse each run of digits into a single `N`
+    let mut normalized = String::new();
+    let mut in_number = false;
+    for c in word.chars() {
+        if c.is_ascii_digit() {
+            if !in_number {
+                normalized.push('N');
+            }
+            in_number = true;
+        } else {
+            normalized.push(c);
+            in_number = false;
+        }
+    }
+    normalized
+}
+
+/// Remove ANSI escape sequences (e.g. `\x1b[31m`)
+fn strip_ansi(text: &str) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failure(repository: &str) -> FailedJob {
+        FailedJob {
+            repository: repository.to_string(),
+            workflow: "CI".to_string(),
+            job: "test".to_string(),
+            html_url: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_error_signature_normalizes() {
+        let log = "\
+2026-03-01T10:00:00.1234567Z ##[group]Run cargo test
+2026-03-01T10:00:05.1234567Z running 12 tests
+2026-03-01T10:00:06.1234567Z thread 'api::tests::refund' panicked at src/api.rs:42:9:
+2026-03-01T10:00:06.1234567Z ##[error]Process completed with exit code 101.";
+        assert_eq!(
+            error_signature(log).as_deref(),
+            Some("thread 'api::tests::refund' panicked at src/api.rs:N:N:")
+        );
+
+        let secret =
+            "2026-03-01T10:00:00.0000000Z ##[error]Input required and not supplied: token\n\
+                      Error: commit 3f9a2c1d not found in /tmp/abc123";
+        assert_eq!(
+            error_signature(secret).as_deref(),
+            Some("Input required and not supplied: token")
+        );
+        assert_eq!(
+            error_signature("Error: commit 3f9a2c1d not found in /tmp/abc123").as_deref(),
+            Some("Error: commit <hash> not found in <path>")
+        );
+        assert_eq!(
+            error_signature("all good\n##[error]Process completed with exit code 1."),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cluster_orders_by_size() {
+        let clusters = cluster(vec![
+            ("timeout".to_string(), failure("acme/web")),
+            ("missing secret".to_string(), failure("acme/api")),
+            ("missing secret".to_string(), failure("acme/web")),
+            ("missing secret".to_string(), failure("acme/api")),
+        ]);
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(
+            clusters[0].summary(),
+            "3 failures across 2 repos share \"missing secret\""
+        );
+        assert_eq!(
+            clusters[1].summary(),
+            "1 failure across 1 repo shares \"timeout\""
+        );
+        assert_eq!(log_tail("a\nb\nc", 2), "b\nc");
+    }
+}
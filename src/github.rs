@@ -1,112 +1,825 @@
 use crate::app::BackgroundMessage;
+use crate::audit::{AuditEntry, AuditEvent, OrgAuditLog, AUDIT_ACTIONS, AUDIT_LOOKBACK_DAYS};
+use crate::cleanup::{self, CleanupCandidate, CleanupReason};
+use crate::codeowners::{Codeowners, CODEOWNERS_PATHS};
+use crate::config::{ApiBackend, Collector, NetworkConfig, PageSizes, RetryPolicy};
+use crate::dependencies::{DependencyGraph, Manifest, MANIFEST_FILES};
+use crate::failures::{self, FailedJob, FailureCluster, LOG_TAIL_LINES, RUNS_PER_REPOSITORY};
+use crate::metrics::RequestMetrics;
 use crate::models::{
-    PullRequest as AppPullRequest, PullRequestState, Repository as AppRepository, RepositoryStatus,
+    Affiliation, BranchProtection, BranchProtectionStatus, BusFactor, ChangedFile,
+    CheckRun as AppCheckRun, CodeScanningAlerts, CommitActivity, CommunityProfile,
+    ComplianceReport, ContributionCalendar, ContributionDay, DeployStatus, DeploymentState,
+    EnvironmentDeployment, Issue, IssueAction, IssueActivity, IssueHealth, IssueMetrics,
+    IssueSearchFilter, MergeRequirements, OrgInvitation, OrgMembershipHealth, OutsideCollaborator,
+    PullRequest as AppPullRequest, PullRequestState, Release, Repository as AppRepository,
+    RepositoryStatus, ReviewComment, ReviewDecision, ReviewEvent, RunTrigger, SearchedIssue,
+    SubmittedReview, TaskProgress, WeeklyCommits, WorkflowStatus, ISSUE_HEALTH_WEEKS,
 };
+use crate::policy::PolicyFacts;
+use bytes::Bytes;
+use http_body_util::combinators::BoxBody;
+use http_body_util::{BodyExt, Full};
 use octocrab::models::Repository;
-use octocrab::Octocrab;
-use std::time::SystemTime;
+use octocrab::{Octocrab, Page};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
 use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+/// Repositories fetched per GraphQL details query
+const GRAPHQL_BATCH_SIZE: usize = 20;
+
+/// Pull requests whose review states one GraphQL query reads
+const REVIEW_STATES_BATCH_SIZE: usize = 100;
+
+/// Pull request fields read for review states: the review decision, each
+/// reviewer's latest vote and the head commit's check rollup
+const REVIEW_STATE_FIELDS: &str = "reviewDecision \
+    latestOpinionatedReviews(first: 20) { nodes { state author { login } } } \
+    commits(last: 1) { nodes { commit { statusCheckRollup { state } } } }";
+
+/// Check suites read per commit when taking workflow runs from GraphQL
+const GRAPHQL_CHECK_SUITES_PER_COMMIT: u8 = 5;
+
+/// How often a held enhancement pass checks whether it may continue
+const HOLD_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Wait after a secondary rate limit, which reports no reset time
+const SECONDARY_RATE_LIMIT_WAIT: Duration = Duration::from_secs(60);
+
+/// Repositories per page when paging through a repository list (GitHub's maximum)
+const REPOSITORY_PAGE_SIZE: u8 = 100;
+
+/// `affiliation` filter listing every repository the user owns, collaborates
+/// on or reaches through an organization membership
+const ALL_AFFILIATIONS: &str = "owner,collaborator,organization_member";
+
+/// GraphQL query for the authenticated user's contribution calendar
+const CONTRIBUTIONS_QUERY: &str = "query { viewer { login contributionsCollection { \
+    contributionCalendar { totalContributions weeks { contributionDays { date contributionCount } } } } } }";
+
+/// How much detail enhancing a repository fetches
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EnhancementProfile {
+    /// Everything the dashboard shows
+    #[default]
+    Full,
+    /// Only pull requests, commits and workflow runs, which the health score
+    /// and attention rules are built from; skips review feedback, LFS,
+    /// deploy status and releases to save API calls
+    Lite,
+}
+
+impl EnhancementProfile {
+    /// Whether the profile fetches a collector's data
+    pub fn includes(&self, collector: Collector) -> bool {
+        *self == EnhancementProfile::Full || collector == Collector::Workflows
+    }
+
+    /// API calls enhancing a repository costs with these collectors enabled,
+    /// estimated from what the repository looked like last time
+    pub fn requests_for(&self, repo: &AppRepository, collectors: &[Collector]) -> u64 {
+        // Pull requests and the latest commits are always fetched, plus the
+        // search for backlogs past a page and issues for issues-only repositories
+        2 + u64::from(repo.open_pull_request_total.is_some())
+            + u64::from(repo.issue_health.is_some())
+            + collectors
+                .iter()
+                .filter(|&&collector| self.includes(collector))
+                .map(|&collector| collector_requests(collector, repo))
+                .sum::<u64>()
+    }
+
+    /// API calls enhancing a repository costs on top of its GraphQL batch,
+    /// which covers pull requests and their reviews, commits, workflow runs
+    /// and the latest release
+    pub fn graphql_requests_for(&self, repo: &AppRepository, collectors: &[Collector]) -> u64 {
+        u64::from(repo.issue_health.is_some())
+            + collectors
+                .iter()
+                .filter(|&&collector| self.includes(collector))
+                .map(|&collector| match collector {
+                    Collector::Workflows | Collector::Releases => 0,
+                    // Only the review comments are left
+                    Collector::Reviews => u64::from(!repo.open_pull_requests.is_empty()),
+                    _ => collector_requests(collector, repo),
+                })
+                .sum::<u64>()
+    }
+
+    /// API calls a refresh of these repositories costs, listing included
+    pub fn estimate_requests(
+        &self,
+        backend: ApiBackend,
+        repositories: &[AppRepository],
+        collectors: &[Collector],
+    ) -> u64 {
+        let listing = (repositories.len() as u64).div_ceil(100).max(1);
+        let details = match backend {
+            ApiBackend::Rest => repositories
+                .iter()
+                .map(|repo| self.requests_for(repo, collectors))
+                .sum::<u64>(),
+            ApiBackend::Graphql => {
+                (repositories.len() as u64).div_ceil(GRAPHQL_BATCH_SIZE as u64)
+                    + repositories
+                        .iter()
+                        .map(|repo| self.graphql_requests_for(repo, collectors))
+                        .sum::<u64>()
+            }
+        };
+        listing + details
+    }
+}
+
+/// API calls a collector costs for a repository, estimated from what the
+/// repository looked like last time
+pub fn collector_requests(collector: Collector, repo: &AppRepository) -> u64 {
+    match collector {
+        Collector::Workflows
+        | Collector::Lfs
+        | Collector::Releases
+        | Collector::CodeScanning
+        | Collector::SecretScanning => 1,
+        // The default branch, then its rules when the token may read them
+        Collector::BranchProtection => 1 + u64::from(repo.admin),
+        // Forks have no community profile
+        Collector::Community => u64::from(!repo.fork),
+        // Empty repositories have no contributors or commits
+        Collector::Contributors | Collector::CommitActivity => u64::from(!repo.empty),
+        // Skipped when every open issue is a pull request
+        Collector::Issues => u64::from(repo.open_issue_count() > 0),
+        // Review comments, then the reviews of a single open pull request
+        // or a GraphQL query per hundred
+        Collector::Reviews => match repo.open_pull_requests.len() {
+            0 => 0,
+            1 => 2,
+            open => 1 + (open as u64).div_ceil(REVIEW_STATES_BATCH_SIZE as u64),
+        },
+        // Pages, environments, then the latest deployment and its status per environment
+        Collector::Deployments => {
+            u64::from(repo.has_pages) + 1 + 2 * repo.deploy_status.environments.len() as u64
+        }
+    }
+}
+
+/// Remaining core API requests and when the allowance resets
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitStatus {
+    pub remaining: u64,
+    pub reset: SystemTime,
+}
+
+/// Who a token authenticates as and the scopes it was granted
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenCheck {
+    pub login: String,
+    /// Scopes of a classic token, from `X-OAuth-Scopes`; `None` for
+    /// fine-grained and app tokens, whose permissions GitHub doesn't list
+    pub scopes: Option<Vec<String>>,
+}
+
+impl TokenCheck {
+    /// Whether the token was granted a scope, directly or through its parent
+    /// (e.g. `admin:org` includes `read:org`)
+    pub fn has_scope(&self, scope: &str) -> bool {
+        let Some(scopes) = &self.scopes else {
+            return true;
+        };
+        let parents: &[&str] = match scope {
+            "read:org" => &["write:org", "admin:org"],
+            _ => &[],
+        };
+        scopes
+            .iter()
+            .any(|granted| granted == scope || parents.contains(&granted.as_str()))
+    }
+
+    /// What the dashboard won't show for lack of scopes
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if !self.has_scope("repo") {
+            warnings.push("token lacks `repo` scope — private repositories are hidden".to_string());
+        }
+        if !self.has_scope("read:org") {
+            warnings.push(
+                "token lacks `read:org` scope — private organization memberships and \
+                 membership health are hidden"
+                    .to_string(),
+            );
+        }
+        warnings
+    }
+}
+
+/// A response kept so a conditional request for the same URL can reuse it
+#[derive(Debug, Clone)]
+struct CachedResponse {
+    /// Headers of the original response, including its ETag/Last-Modified
+    headers: http::HeaderMap,
+    body: Bytes,
+}
+
+impl CachedResponse {
+    /// Whether GitHub sent a validator to make conditional requests with
+    fn is_conditional(&self) -> bool {
+        self.headers.contains_key(http::header::ETAG)
+            || self.headers.contains_key(http::header::LAST_MODIFIED)
+    }
+
+    /// Headers asking GitHub to answer 304 if nothing changed since
+    fn validators(&self) -> http::HeaderMap {
+        let mut headers = http::HeaderMap::new();
+        if let Some(etag) = self.headers.get(http::header::ETAG) {
+            headers.insert(http::header::IF_NONE_MATCH, etag.clone());
+        }
+        if let Some(modified) = self.headers.get(http::header::LAST_MODIFIED) {
+            headers.insert(http::header::IF_MODIFIED_SINCE, modified.clone());
+        }
+        headers
+    }
+
+    /// Rebuild the response for octocrab to deserialize
+    fn into_response(self) -> http::Response<BoxBody<Bytes, octocrab::Error>> {
+        let body = Full::new(self.body)
+            .map_err(|never: std::convert::Infallible| -> octocrab::Error { match never {} })
+            .boxed();
+        let mut response = http::Response::new(body);
+        *response.headers_mut() = self.headers;
+        response
+    }
+}
 
 /// GitHub API client for fetching repository health data
 #[derive(Debug, Clone)]
 pub struct GitHubClient {
     octocrab: Octocrab,
+    metrics: RequestMetrics,
+    /// Set when a request is rejected by a rate limit (shared by clones)
+    rate_limited: Arc<AtomicBool>,
+    /// Set while enhancement should wait, e.g. while the terminal is
+    /// unfocused (shared by clones)
+    held: Arc<AtomicBool>,
+    /// Page sizes of the requests made while enhancing repositories
+    page_sizes: PageSizes,
+    /// How much detail enhancing a repository fetches
+    profile: EnhancementProfile,
+    /// Optional data fetched when enhancing repositories
+    collectors: Vec<Collector>,
+    /// REST or batched GraphQL requests for repository details
+    backend: ApiBackend,
+    /// Core rate limit as last read, counted down by each request since
+    /// (shared by clones)
+    rate_limit: Arc<Mutex<Option<RateLimitStatus>>>,
+    /// Enhancement pauses until the reset once this few requests are left
+    /// (0 never pauses early)
+    rate_limit_reserve: u64,
+    /// How requests failing transiently are retried
+    retry_policy: RetryPolicy,
+    /// Responses by URL, reused when GitHub answers a conditional request
+    /// with 304 Not Modified (shared by clones)
+    response_cache: Arc<Mutex<HashMap<String, CachedResponse>>>,
+    /// Whether the user's repository list includes collaborator and
+    /// organization member repositories
+    affiliated: bool,
+    /// Longest one repository's details may take to load (None waits forever)
+    job_timeout: Option<Duration>,
+    /// Requests counted against the rate limit since the last
+    /// `counting_requests` (shared by clones)
+    requests: Arc<AtomicU64>,
 }
 
 impl GitHubClient {
     /// Create a new GitHub client using a personal access token
     ///
-    /// The token should be set in the `GH_REPO_HEALTHCHECKS_TOKEN` environment variable
-    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        let token = std::env::var("GH_REPO_HEALTHCHECKS_TOKEN")
-            .map_err(|_| "GH_REPO_HEALTHCHECKS_TOKEN environment variable not set")?;
+    /// The token comes from `token`, usually [`credentials::token`]
+    /// (the keyring, then the environment).
+    /// Requests go through the configured or environment proxy.
+    ///
+    /// [`credentials::token`]: crate::credentials::token
+    pub fn new(
+        network: &NetworkConfig,
+        token: impl FnOnce() -> Option<String>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let token = token().ok_or(
+            "No token: run with --login or set the GH_REPO_HEALTHCHECKS_TOKEN environment variable",
+        )?;
+
+        let octocrab = crate::network::build_octocrab(token, None, network)?;
+        Ok(Self::from_octocrab(octocrab))
+    }
+
+    /// Create a client for a GitHub-compatible API served at `base_uri`
+    ///
+    /// Used to point the client at a fake API in tests.
+    #[cfg(test)]
+    pub fn with_base_uri(
+        token: String,
+        base_uri: &str,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let octocrab = Octocrab::builder()
+            .personal_token(token)
+            .base_uri(base_uri)?
+            .build()?;
+        Ok(Self::from_octocrab(octocrab))
+    }
+
+    /// Create a client for `base_uri` that connects with the given proxy and
+    /// TLS settings
+    #[cfg(test)]
+    pub fn with_network(
+        token: String,
+        base_uri: &str,
+        network: &NetworkConfig,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let octocrab = crate::network::build_octocrab(token, Some(base_uri), network)?;
+        Ok(Self::from_octocrab(octocrab))
+    }
+
+    fn from_octocrab(octocrab: Octocrab) -> Self {
+        Self {
+            octocrab,
+            metrics: RequestMetrics::default(),
+            rate_limited: Arc::new(AtomicBool::new(false)),
+            held: Arc::new(AtomicBool::new(false)),
+            page_sizes: PageSizes::default(),
+            profile: EnhancementProfile::default(),
+            collectors: Collector::ALL.to_vec(),
+            backend: ApiBackend::default(),
+            rate_limit: Arc::new(Mutex::new(None)),
+            rate_limit_reserve: 0,
+            retry_policy: RetryPolicy::NONE,
+            response_cache: Arc::new(Mutex::new(HashMap::new())),
+            affiliated: false,
+            job_timeout: None,
+            requests: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Use different page sizes when enhancing repositories
+    pub fn with_page_sizes(mut self, page_sizes: PageSizes) -> Self {
+        self.page_sizes = page_sizes;
+        self
+    }
+
+    /// Fetch more or less detail when enhancing repositories
+    pub fn with_profile(mut self, profile: EnhancementProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Fetch only these collectors' data when enhancing repositories
+    pub fn with_collectors(mut self, collectors: Vec<Collector>) -> Self {
+        self.collectors = collectors;
+        self
+    }
+
+    /// Fetch repository details with REST or batched GraphQL requests
+    pub fn with_backend(mut self, backend: ApiBackend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Retry requests failing transiently per `policy`
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Pause enhancement until the reset once this few requests are left
+    pub fn with_rate_limit_reserve(mut self, reserve: u64) -> Self {
+        self.rate_limit_reserve = reserve;
+        self
+    }
+
+    /// List collaborator and organization member repositories alongside the
+    /// user's own
+    pub fn with_affiliated(mut self, affiliated: bool) -> Self {
+        self.affiliated = affiliated;
+        self
+    }
+
+    /// Give up on a repository's details after this long, marking it as
+    /// timed out and moving on to the next
+    pub fn with_job_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.job_timeout = timeout;
+        self
+    }
+
+    /// Whether enhancing repositories fetches a collector's data
+    fn collects(&self, collector: Collector) -> bool {
+        self.profile.includes(collector) && self.collectors.contains(&collector)
+    }
+
+    /// Request metrics recorded by this client and all its clones
+    pub fn metrics(&self) -> RequestMetrics {
+        self.metrics.clone()
+    }
+
+    /// Count this client's requests apart from those of the client it was
+    /// cloned from, to tell what one task cost
+    pub fn counting_requests(mut self) -> Self {
+        self.requests = Arc::new(AtomicU64::new(0));
+        self
+    }
+
+    /// Requests made since `counting_requests`, by this client and its clones
+    pub fn requests_counted(&self) -> u64 {
+        self.requests.load(Ordering::SeqCst)
+    }
+
+    /// Make an API request, recording its latency and outcome under `endpoint`
+    ///
+    /// Transient failures are retried with jittered exponential backoff per
+    /// the retry policy, so `request` builds a fresh request for each attempt.
+    async fn timed<T, F, Fut>(
+        &self,
+        endpoint: &'static str,
+        mut request: F,
+    ) -> Result<T, octocrab::Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T, octocrab::Error>>,
+    {
+        let mut retry = 0;
+        loop {
+            let result = self.measured(endpoint, request()).await;
+            match result {
+                Err(e)
+                    if retry < self.retry_policy.retries
+                        && GitHubError::classify(&e).is_transient() =>
+                {
+                    tokio::time::sleep(self.retry_policy.delay(retry, jitter())).await;
+                    retry += 1;
+                }
+                result => {
+                    self.flag_rate_limit(&result);
+                    return result;
+                }
+            }
+        }
+    }
+
+    /// Await an API request once, recording its latency and outcome under
+    /// `endpoint`
+    ///
+    /// For requests that mustn't be repeated, such as creating a comment:
+    /// after a network failure it may have gone through anyway.
+    async fn timed_once<T, E: std::error::Error>(
+        &self,
+        endpoint: &'static str,
+        request: impl std::future::Future<Output = Result<T, E>>,
+    ) -> Result<T, E> {
+        let result = self.measured(endpoint, request).await;
+        self.flag_rate_limit(&result);
+        result
+    }
 
-        let octocrab = Octocrab::builder().personal_token(token).build()?;
+    /// Await one attempt of a request, recording it in the metrics and
+    /// counting it against the rate limit
+    async fn measured<T, E>(
+        &self,
+        endpoint: &'static str,
+        request: impl std::future::Future<Output = Result<T, E>>,
+    ) -> Result<T, E> {
+        let started = Instant::now();
+        let result = request.await;
+        self.metrics
+            .record(endpoint, started.elapsed(), result.is_ok());
+        // Reading the rate limit doesn't count against it
+        if endpoint != "rate_limit" {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            if let Some(status) = self.rate_limit.lock().unwrap().as_mut() {
+                status.remaining = status.remaining.saturating_sub(1);
+            }
+        }
+        result
+    }
 
-        Ok(Self { octocrab })
+    /// Note a request that finally failed on a rate limit
+    fn flag_rate_limit<T, E: std::error::Error>(&self, result: &Result<T, E>) {
+        // octocrab's API errors only say "GitHub"; the message is in their source
+        let rate_limited = result.as_ref().err().is_some_and(|e| {
+            std::iter::successors(Some(e as &dyn std::error::Error), |e| e.source())
+                .any(|e| is_rate_limit_error(&e.to_string()))
+        });
+        if rate_limited {
+            self.rate_limited.store(true, Ordering::SeqCst);
+        }
     }
 
-    /// List all repositories for the authenticated user
+    /// GET a route like `octocrab.get`, as a conditional request
     ///
-    /// This fetches repositories owned by the authenticated user with additional
-    /// data about pull requests, latest commits, and releases.
-    pub async fn list_user_repositories(&self) -> Result<Vec<AppRepository>, String> {
-        let mut repositories = Vec::new();
+    /// The ETag and Last-Modified of the previous response for the same URL
+    /// are sent back. When GitHub answers 304 Not Modified, which doesn't
+    /// count against the rate limit, that response is reused.
+    async fn get_cached<R, P>(
+        &self,
+        endpoint: &'static str,
+        route: impl AsRef<str>,
+        parameters: Option<&P>,
+    ) -> Result<R, octocrab::Error>
+    where
+        R: octocrab::FromResponse,
+        P: serde::Serialize + ?Sized,
+    {
+        let Ok(query) = parameters.map(serde_urlencoded::to_string).transpose() else {
+            // octocrab reports why the parameters don't encode
+            return self
+                .timed(endpoint, || self.octocrab.get(route.as_ref(), parameters))
+                .await;
+        };
+        let route = route.as_ref();
+        let uri = match query {
+            Some(query) if route.contains('?') => format!("{}&{}", route, query),
+            Some(query) => format!("{}?{}", route, query),
+            None => route.to_string(),
+        };
 
-        // Get repositories for the authenticated user
-        let repos_page = self
-            .octocrab
-            .current()
-            .list_repos_for_authenticated_user()
-            .type_("owner") // Only repositories owned by the user
-            .sort("updated") // Sort by last updated
-            .per_page(100) // Maximum per page
-            .send()
-            .await
-            .map_err(|e| format!("GitHub API error: {}", e))?;
+        let cached = self.response_cache.lock().unwrap().get(&uri).cloned();
+        let headers = cached.as_ref().map(CachedResponse::validators);
+        let (response, not_modified) = self
+            .timed(endpoint, || async {
+                let response = self
+                    .octocrab
+                    ._get_with_headers(uri.as_str(), headers.clone())
+                    .await?;
+                if let Some(cached) = cached
+                    .clone()
+                    .filter(|_| response.status() == http::StatusCode::NOT_MODIFIED)
+                {
+                    return Ok((cached, true));
+                }
+                let (parts, body) = octocrab::map_github_error(response).await?.into_parts();
+                let body = body.collect().await?.to_bytes();
+                Ok::<_, octocrab::Error>((
+                    CachedResponse {
+                        headers: parts.headers,
+                        body,
+                    },
+                    false,
+                ))
+            })
+            .await?;
 
-        for repo in repos_page.items {
-            match self.convert_repository_with_data(repo).await {
-                Ok(app_repo) => repositories.push(app_repo),
-                Err(e) => eprintln!("Error processing repository: {}", e),
+        if not_modified {
+            self.metrics.record_not_modified(endpoint);
+            if let Some(status) = self.rate_limit.lock().unwrap().as_mut() {
+                status.remaining += 1;
             }
+        } else if response.is_conditional() {
+            self.response_cache
+                .lock()
+                .unwrap()
+                .insert(uri, response.clone());
         }
+        R::from_response(response.into_response()).await
+    }
 
-        Ok(repositories)
+    /// Core requests left and when they reset, as last read from GitHub and
+    /// counted down by the requests made since
+    pub fn rate_limit(&self) -> Option<RateLimitStatus> {
+        *self.rate_limit.lock().unwrap()
+    }
+
+    /// Hold enhancement passes before their next repository, or let them go on
+    pub fn hold(&self, held: bool) {
+        self.held.store(held, Ordering::SeqCst);
+    }
+
+    /// Wait until enhancement is no longer held
+    async fn wait_while_held(&self) {
+        while self.held.load(Ordering::SeqCst) {
+            tokio::time::sleep(HOLD_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Whether a request was rate limited since the last call, clearing the flag
+    fn take_rate_limited(&self) -> bool {
+        self.rate_limited.swap(false, Ordering::SeqCst)
     }
 
-    /// List basic repository information quickly without additional API calls
+    /// When requests may be made again after hitting a rate limit
     ///
-    /// This is a faster version that only fetches the basic repository list
-    /// without making additional API calls for PRs, commits, etc.
-    pub async fn list_basic_repositories(&self) -> Result<Vec<AppRepository>, String> {
-        let mut repositories = Vec::new();
+    /// Uses the core limit's reset time once it is exhausted; otherwise the
+    /// limit hit was a secondary one, which GitHub asks to wait out for a minute.
+    async fn rate_limit_reset(&self) -> SystemTime {
+        let fallback = SystemTime::now() + SECONDARY_RATE_LIMIT_WAIT;
+        match self.rate_limit_status().await {
+            Ok(status) if status.remaining == 0 => {
+                // Resume a second after the reset to avoid racing it
+                status.reset + Duration::from_secs(1)
+            }
+            _ => fallback,
+        }
+    }
 
-        // Get repositories for the authenticated user
-        let repos_page = self
-            .octocrab
-            .current()
-            .list_repos_for_authenticated_user()
-            .type_("owner") // Only repositories owned by the user
-            .sort("updated") // Sort by last updated
-            .per_page(100) // Maximum per page
-            .send()
+    /// Remaining core API requests for the token, remembered for `rate_limit`
+    pub async fn rate_limit_status(&self) -> Result<RateLimitStatus, String> {
+        let limits = self
+            .timed("rate_limit", || async {
+                self.octocrab.ratelimit().get().await
+            })
             .await
-            .map_err(|e| format!("GitHub API error: {}", e))?;
+            .map_err(|e| format!("Failed to check the rate limit: {}", e))?;
+        let status = RateLimitStatus {
+            remaining: limits.resources.core.remaining as u64,
+            reset: SystemTime::UNIX_EPOCH + Duration::from_secs(limits.resources.core.reset),
+        };
+        *self.rate_limit.lock().unwrap() = Some(status);
+        Ok(status)
+    }
 
-        for repo in repos_page.items {
-            let owner = repo
-                .owner
-                .as_ref()
-                .ok_or("Repository missing owner".to_string())?
-                .login
-                .clone();
+    /// Verify the token with `/user` and read the scopes it was granted
+    pub async fn check_token(&self) -> Result<TokenCheck, GitHubError> {
+        let (scopes, body) = self
+            .timed("user", || async {
+                let response = self.octocrab._get("/user").await?;
+                let (parts, body) = octocrab::map_github_error(response).await?.into_parts();
+                let scopes = parts
+                    .headers
+                    .get("x-oauth-scopes")
+                    .and_then(|value| value.to_str().ok())
+                    .map(|value| {
+                        value
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|scope| !scope.is_empty())
+                            .map(String::from)
+                            .collect::<Vec<_>>()
+                    });
+                Ok::<_, octocrab::Error>((scopes, body.collect().await?.to_bytes()))
+            })
+            .await
+            .map_err(|e| GitHubError::classify(&e))?;
 
-            let mut app_repo = AppRepository::new(repo.name.clone(), owner.clone());
+        #[derive(Deserialize)]
+        struct User {
+            login: String,
+        }
+        let user: User = serde_json::from_slice(&body)
+            .map_err(|e| GitHubError::ApiError(format!("Unexpected /user response: {}", e)))?;
+        Ok(TokenCheck {
+            login: user.login,
+            scopes,
+        })
+    }
 
-            // Set basic repository information
-            app_repo.html_url = repo.html_url.map(|url| url.to_string()).unwrap_or_default();
-            app_repo.description = repo.description;
-            app_repo.language = repo
-                .language
-                .and_then(|lang| lang.as_str().map(|s| s.to_string()));
-            app_repo.stars = repo.stargazers_count.unwrap_or(0) as u32;
-            app_repo.last_updated = SystemTime::now();
+    /// Spawn a background task checking the token before the first fetch
+    ///
+    /// Only a rejected token stops the fetch; when GitHub can't be asked,
+    /// `TokenChecked` carries no check and the fetch reports the problem.
+    pub fn spawn_token_check(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+    ) {
+        tokio::spawn(async move {
+            let message = match client.check_token().await {
+                Ok(check) => BackgroundMessage::TokenChecked { check: Some(check) },
+                Err(GitHubError::AuthenticationFailed) => BackgroundMessage::TokenRejected {
+                    error: GitHubError::AuthenticationFailed.to_string(),
+                },
+                Err(_) => BackgroundMessage::TokenChecked { check: None },
+            };
+            let _ = sender.send(message);
+        });
+    }
 
-            // Set default status
-            app_repo.status = RepositoryStatus::Unknown;
+    /// Wait for the rate limit to reset once the requests left are down to
+    /// the reserve, so a pass pauses instead of failing mid-way
+    ///
+    /// The counted-down estimate is confirmed with GitHub before pausing.
+    /// Returns false if the receiver was dropped.
+    async fn wait_for_quota(
+        &self,
+        sender: &mpsc::UnboundedSender<BackgroundMessage>,
+        current: usize,
+        total: usize,
+    ) -> bool {
+        let low = |status: &RateLimitStatus| status.remaining <= self.rate_limit_reserve;
+        if self.rate_limit_reserve == 0 || !self.rate_limit().is_some_and(|status| low(&status)) {
+            return true;
+        }
+        match self.rate_limit_status().await {
+            Ok(status) if low(&status) => {
+                let resume_at = status.reset + Duration::from_secs(1);
+                self.pause_until(sender, current, total, resume_at).await
+            }
+            _ => true,
+        }
+    }
 
-            repositories.push(app_repo);
+    /// Tell the app the pass is paused, sleep until `resume_at`, then tell it
+    /// the pass goes on
+    ///
+    /// Returns false if the receiver was dropped.
+    async fn pause_until(
+        &self,
+        sender: &mpsc::UnboundedSender<BackgroundMessage>,
+        current: usize,
+        total: usize,
+        resume_at: SystemTime,
+    ) -> bool {
+        if sender
+            .send(BackgroundMessage::EnhancementPaused {
+                current,
+                total,
+                resume_at,
+            })
+            .is_err()
+        {
+            return false;
+        }
+        if let Ok(wait) = resume_at.duration_since(SystemTime::now()) {
+            tokio::time::sleep(wait).await;
         }
+        sender.send(BackgroundMessage::EnhancementResumed).is_ok()
+    }
 
-        Ok(repositories)
+    /// Spawn a background task checking the rate limit before a refresh
+    ///
+    /// A failed check sends `None`, so the refresh goes ahead unplanned
+    /// rather than not at all.
+    pub fn spawn_budget_check(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+    ) {
+        tokio::spawn(async move {
+            let status = client.rate_limit_status().await.ok();
+            let _ = sender.send(BackgroundMessage::RateLimitChecked { status });
+        });
     }
 
-    /// Convert a GitHub repository to our app repository with additional data
-    async fn convert_repository_with_data(
+    /// Enhance a repository, pausing until the rate limit resets if it is hit
+    ///
+    /// A rate-limited pass leaves the repository partially enhanced, so it is
+    /// enhanced again after the pause and the pass resumes where it stopped.
+    /// One taking longer than the job timeout is left as far as it got and
+    /// marked as timed out. Returns false if the receiver was dropped.
+    async fn enhance_repository_resuming(
         &self,
-        repo: Repository,
-    ) -> Result<AppRepository, String> {
+        repo: &mut AppRepository,
+        prefetched: bool,
+        sender: &mpsc::UnboundedSender<BackgroundMessage>,
+        current: usize,
+        total: usize,
+    ) -> bool {
+        repo.fetch_timed_out = false;
+        loop {
+            let details = self.enhance_repository_details(repo, prefetched);
+            let result = match self.job_timeout {
+                Some(limit) => tokio::time::timeout(limit, details).await,
+                None => Ok(details.await),
+            };
+            match result {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => eprintln!("Error enhancing repository {}: {}", repo.name, e),
+                Err(_) => {
+                    // Whatever loaded in time is kept
+                    eprintln!("Timed out enhancing repository {}", repo.name);
+                    repo.fetch_timed_out = true;
+                    return true;
+                }
+            }
+            if !self.take_rate_limited() {
+                return true;
+            }
+
+            let resume_at = self.rate_limit_reset().await;
+            if !self.pause_until(sender, current, total, resume_at).await {
+                return false;
+            }
+        }
+    }
+
+    /// Login affiliations are worked out against, when the user's repository
+    /// list includes affiliated repositories
+    async fn listing_viewer(&self) -> Result<Option<String>, String> {
+        if !self.affiliated {
+            return Ok(None);
+        }
+        self.get_current_user()
+            .await
+            .map(Some)
+            .map_err(|e| format!("Failed to get current user: {}", e))
+    }
+
+    /// How `viewer` reaches a listed repository (None without a viewer)
+    fn affiliation(repo: &Repository, viewer: Option<&str>) -> Option<Affiliation> {
+        let owner = repo.owner.as_ref()?;
+        Some(Affiliation::of(
+            &owner.login,
+            owner.r#type == "Organization",
+            viewer?,
+        ))
+    }
+
+    /// Convert a GitHub repository to our app repository with basic information only
+    fn basic_repository(repo: Repository) -> Result<AppRepository, String> {
         let owner = repo
             .owner
             .as_ref()
@@ -114,7 +827,7 @@ impl GitHubClient {
             .login
             .clone();
 
-        let mut app_repo = AppRepository::new(repo.name.clone(), owner.clone());
+        let mut app_repo = AppRepository::new(repo.name.clone(), owner);
 
         // Set basic repository information
         app_repo.html_url = repo.html_url.map(|url| url.to_string()).unwrap_or_default();
@@ -122,27 +835,24 @@ impl GitHubClient {
         app_repo.language = repo
             .language
             .and_then(|lang| lang.as_str().map(|s| s.to_string()));
-        app_repo.stars = repo.stargazers_count.unwrap_or(0) as u32;
+        app_repo.stars = repo.stargazers_count.unwrap_or(0);
+        app_repo.topics = repo.topics.unwrap_or_default();
+        app_repo.has_pages = repo.has_pages.unwrap_or(false);
+        app_repo.fork = repo.fork.unwrap_or(false);
+        app_repo.private = repo.private.unwrap_or(false);
+        app_repo.admin = repo
+            .permissions
+            .as_ref()
+            .is_some_and(|permissions| permissions.admin);
+        app_repo.archived = repo.archived.unwrap_or(false);
+        app_repo.size_kb = repo.size.unwrap_or(0) as u64;
+        app_repo.created_at = repo.created_at.map(SystemTime::from);
+        app_repo.default_branch = repo.default_branch.unwrap_or_default();
+        app_repo.open_issues = repo.open_issues_count.unwrap_or(0);
         app_repo.last_updated = SystemTime::now();
 
-        // Fetch additional data
-        match self.fetch_open_pull_requests(&owner, &repo.name).await {
-            Ok(open_prs) => app_repo.open_pull_requests = open_prs,
-            Err(e) => eprintln!("Failed to fetch PRs for {}/{}: {}", owner, repo.name, e),
-        }
-
-        // Fetch latest commit data
-        match self.fetch_latest_commit(&owner, &repo.name).await {
-            Ok(Some(commit_time)) => app_repo.latest_commit_at = Some(commit_time),
-            Ok(None) => {} // No commits found
-            Err(e) => eprintln!(
-                "Failed to fetch latest commit for {}/{}: {}",
-                owner, repo.name, e
-            ),
-        }
-
-        // Determine overall repository status based on available data
-        app_repo.status = self.determine_repository_status(&app_repo);
+        // Set default status
+        app_repo.status = RepositoryStatus::Unknown;
 
         Ok(app_repo)
     }
@@ -153,18 +863,19 @@ impl GitHubClient {
         owner: &str,
         repo: &str,
     ) -> Result<Vec<AppPullRequest>, Box<dyn std::error::Error>> {
-        let pulls_page = self
-            .octocrab
-            .pulls(owner, repo)
-            .list()
-            .state(octocrab::params::State::Open)
-            .per_page(50) // Limit to first 50 open PRs
-            .send()
+        let per_page = self.page_sizes.pull_requests.to_string();
+        let pulls_page: Page<octocrab::models::pulls::PullRequest> = self
+            .get_cached(
+                "pulls",
+                format!("/repos/{}/{}/pulls", owner, repo),
+                Some(&[("state", "open"), ("per_page", per_page.as_str())]),
+            )
             .await?;
 
         let mut app_pulls = Vec::new();
 
         for pr in pulls_page.items {
+            let body = pr.body.as_deref().unwrap_or_default();
             let app_pr = AppPullRequest {
                 number: pr.number as u32,
                 title: pr.title.unwrap_or_default(),
@@ -199,8 +910,23 @@ impl GitHubClient {
                     .unwrap_or_else(|| "unknown".to_string()),
                 html_url: pr.html_url.map(|url| url.to_string()).unwrap_or_default(),
                 draft: pr.draft.unwrap_or(false),
-                approvals: 0,         // TODO: Fetch review data
-                changes_requested: 0, // TODO: Fetch review data
+                // Counted from the PR's reviews while enhancing
+                approvals: 0,
+                changes_requested: 0,
+                head_sha: pr.head.sha,
+                base_branch: pr.base.ref_field,
+                linked_issues: AppPullRequest::parse_linked_issues(body),
+                tasks: TaskProgress::parse(body),
+                feedback_waiting_since: None,
+                review_decision: None,
+                checks: None,
+                labels: pr
+                    .labels
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|label| label.name)
+                    .collect(),
+                milestone: pr.milestone.map(|milestone| milestone.title),
             };
             app_pulls.push(app_pr);
         }
@@ -208,340 +934,2727 @@ impl GitHubClient {
         Ok(app_pulls)
     }
 
-    /// Fetch the latest commit for a repository
-    async fn fetch_latest_commit(
+    /// Count a repository's open pull requests with the search API
+    ///
+    /// Only the total is read, so one request covers any backlog size.
+    async fn count_open_pull_requests(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<usize, octocrab::Error> {
+        let query = format!("repo:{}/{} is:pr is:open", owner, repo);
+        let result: SearchCountResponse = self
+            .get_cached(
+                "search/issues",
+                "/search/issues",
+                Some(&[("q", query.as_str()), ("per_page", "1")]),
+            )
+            .await?;
+        Ok(result.total_count)
+    }
+
+    /// Fetch the most recent review comments across a repository's pull requests
+    async fn fetch_review_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<ReviewComment>, octocrab::Error> {
+        let comments: Vec<ReviewCommentResponse> = self
+            .get_cached(
+                "pulls/comments",
+                format!("/repos/{}/{}/pulls/comments", owner, repo),
+                Some(&[
+                    ("sort", "created"),
+                    ("direction", "desc"),
+                    ("per_page", "100"),
+                ]),
+            )
+            .await?;
+
+        Ok(comments
+            .into_iter()
+            .filter_map(|comment| {
+                // The PR number is the last segment of its API URL
+                let pull_request = comment.pull_request_url.rsplit('/').next()?.parse().ok()?;
+                Some(ReviewComment {
+                    pull_request,
+                    author: comment.user?.login,
+                    created_at: SystemTime::from(comment.created_at),
+                })
+            })
+            .collect())
+    }
+
+    /// Fetch the reviews submitted on a pull request
+    async fn fetch_pull_request_reviews(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u32,
+    ) -> Result<Vec<SubmittedReview>, octocrab::Error> {
+        let reviews: Vec<ReviewResponse> = self
+            .get_cached(
+                "pulls/reviews",
+                format!("/repos/{}/{}/pulls/{}/reviews", owner, repo, number),
+                Some(&[("per_page", "100")]),
+            )
+            .await?;
+
+        Ok(reviews
+            .into_iter()
+            .filter_map(|review| {
+                Some(SubmittedReview {
+                    author: review.user?.login,
+                    state: review.state,
+                })
+            })
+            .collect())
+    }
+
+    /// Read the review states of a repository's pull requests with one
+    /// GraphQL query per `REVIEW_STATES_BATCH_SIZE` of them
+    ///
+    /// Replaces a REST request per pull request. States are keyed by pull
+    /// request number; ones that can't be read are left out.
+    async fn fetch_review_states(
+        &self,
+        owner: &str,
+        repo: &str,
+        numbers: &[u32],
+    ) -> Result<HashMap<u32, GraphReviewState>, String> {
+        // Owner and repository names are quoted like JSON strings
+        let quote = |value: &str| serde_json::to_string(value).unwrap_or_default();
+        let mut states = HashMap::new();
+        for batch in numbers.chunks(REVIEW_STATES_BATCH_SIZE) {
+            let aliases: Vec<String> = batch
+                .iter()
+                .map(|number| format!("p{0}: pullRequest(number: {0}) {{ ...state }}", number))
+                .collect();
+            let query = format!(
+                "query {{ repository(owner: {}, name: {}) {{ {} }} }} \
+                 fragment state on PullRequest {{ {} }}",
+                quote(owner),
+                quote(repo),
+                aliases.join(" "),
+                REVIEW_STATE_FIELDS
+            );
+            let query = serde_json::json!({ "query": query });
+            let response: GraphQlResponse<ReviewStatesData> = self
+                .timed("graphql", || self.octocrab.graphql(&query))
+                .await
+                .map_err(|e| e.to_string())?;
+
+            let Some(pulls) = response.data.and_then(|data| data.repository) else {
+                let errors: Vec<&str> = response
+                    .errors
+                    .iter()
+                    .map(|error| error.message.as_str())
+                    .collect();
+                return Err(errors.join("; "));
+            };
+            states.extend(pulls.into_iter().filter_map(|(alias, state)| {
+                Some((alias.strip_prefix('p')?.parse().ok()?, state?))
+            }));
+        }
+        Ok(states)
+    }
+
+    /// Fetch the latest commit time and recent commit subject lines
+    ///
+    /// One request serves both the activity timestamp and the commit
+    /// convention sample. Returns `None` for an empty repository.
+    async fn fetch_recent_commits(
         &self,
         owner: &str,
         repo: &str,
-    ) -> Result<Option<SystemTime>, Box<dyn std::error::Error>> {
+    ) -> Result<Option<(Option<SystemTime>, Vec<String>)>, Box<dyn std::error::Error>> {
+        let per_page = self.page_sizes.commits.to_string();
         match self
-            .octocrab
-            .repos(owner, repo)
-            .list_commits()
-            .per_page(1)
-            .send()
+            .get_cached::<Page<octocrab::models::repos::RepoCommit>, _>(
+                "commits",
+                format!("/repos/{}/{}/commits", owner, repo),
+                Some(&[("per_page", per_page.as_str())]),
+            )
             .await
         {
             Ok(commits_page) => {
-                if let Some(commit) = commits_page.items.first() {
-                    let commit_info = &commit.commit;
-                    if let Some(author) = &commit_info.author {
-                        if let Some(date) = author.date {
-                            let timestamp = date.timestamp() as u64;
-                            return Ok(Some(
-                                SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(timestamp),
-                            ));
-                        }
-                    }
-                }
+                let latest_commit_at = commits_page
+                    .items
+                    .first()
+                    .and_then(|commit| commit.commit.author.as_ref())
+                    .and_then(|author| author.date)
+                    .map(|date| {
+                        SystemTime::UNIX_EPOCH
+                            + std::time::Duration::from_secs(date.timestamp() as u64)
+                    });
+                let subjects = commits_page
+                    .items
+                    .iter()
+                    .map(|commit| {
+                        commit
+                            .commit
+                            .message
+                            .lines()
+                            .next()
+                            .unwrap_or("")
+                            .to_string()
+                    })
+                    .collect();
+                Ok(Some((latest_commit_at, subjects)))
+            }
+            // GitHub answers 409 Conflict for repositories without commits
+            Err(octocrab::Error::GitHub { source, .. })
+                if source.status_code == http::StatusCode::CONFLICT =>
+            {
                 Ok(None)
             }
-            Err(_) => Ok(None), // If we can't fetch commits, just return None
+            Err(_) => Ok(Some((None, Vec::new()))), // If we can't fetch commits, just return None
         }
     }
 
-    /// Fetch the latest release for a repository  
-    /// This is a placeholder for future implementation
-    async fn fetch_latest_release(
-        &self,
-        _owner: &str,
-        _repo: &str,
-    ) -> Result<Option<String>, Box<dyn std::error::Error>> {
-        // TODO: Implement fetching latest release data
-        // let releases = self.octocrab.repos(owner, repo).releases().list().per_page(1).send().await?;
-        Ok(None)
+    /// Whether a repository tracks files with Git LFS
+    ///
+    /// Detected from `filter=lfs` entries in the root `.gitattributes`, since
+    /// GitHub doesn't expose LFS storage per repository.
+    async fn fetch_uses_lfs(&self, owner: &str, repo: &str) -> Result<bool, octocrab::Error> {
+        match self
+            .get_cached::<octocrab::models::repos::ContentItems, _>(
+                "contents",
+                format!("/repos/{}/{}/contents/.gitattributes", owner, repo),
+                None::<&()>,
+            )
+            .await
+        {
+            Ok(mut contents) => Ok(contents
+                .take_items()
+                .first()
+                .and_then(|file| file.decoded_content())
+                .is_some_and(|attributes| attributes.contains("filter=lfs"))),
+            Err(octocrab::Error::GitHub { source, .. })
+                if source.status_code == http::StatusCode::NOT_FOUND =>
+            {
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
     }
 
-    /// Fetch recent workflow runs for a repository
-    async fn fetch_workflow_runs(
+    /// Fetch the files changed by a pull request with their line counts
+    pub async fn fetch_pull_request_files(
         &self,
         owner: &str,
         repo: &str,
-    ) -> Result<Vec<crate::models::WorkflowRun>, Box<dyn std::error::Error>> {
-        // For now, return empty vector as octocrab doesn't have direct workflow support
-        // This would need to be implemented with raw HTTP requests to GitHub Actions API
-        // or wait for octocrab to add workflow support
-
-        // Placeholder implementation - in a real scenario, you'd call:
-        // GET /repos/{owner}/{repo}/actions/runs
+        pr_number: u32,
+    ) -> Result<Vec<ChangedFile>, octocrab::Error> {
+        let route = format!("/repos/{}/{}/pulls/{}/files", owner, repo, pr_number);
+        let files: Vec<PullRequestFileResponse> = self
+            .timed("pulls/files", || {
+                self.octocrab.get(&route, Some(&[("per_page", "100")]))
+            })
+            .await?;
 
-        Ok(Vec::new())
+        Ok(files
+            .into_iter()
+            .map(|file| ChangedFile {
+                path: file.filename,
+                status: file.status,
+                additions: file.additions,
+                deletions: file.deletions,
+            })
+            .collect())
     }
 
-    /// Determine the overall status of a repository based on last commit time
-    fn determine_repository_status(&self, repo: &AppRepository) -> RepositoryStatus {
-        RepositoryStatus::from_last_commit(repo.latest_commit_at)
-    }
+    /// Fetch a pull request's mergeability and its base branch's protection
+    ///
+    /// Protection is only readable with admin access; without it the rules
+    /// are left out rather than failing the fetch.
+    pub async fn fetch_merge_requirements(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u32,
+    ) -> Result<MergeRequirements, octocrab::Error> {
+        let route = format!("/repos/{}/{}/pulls/{}", owner, repo, pr_number);
+        let pull: PullMergeResponse = self
+            .timed("pulls/get", || self.octocrab.get(&route, None::<&()>))
+            .await?;
 
-    /// Get the authenticated user information for testing
-    pub async fn get_current_user(&self) -> Result<String, Box<dyn std::error::Error>> {
-        let user = self.octocrab.current().user().await?;
-        Ok(user.login)
+        let route = format!(
+            "/repos/{}/{}/branches/{}/protection",
+            owner, repo, pull.base.branch
+        );
+        let protection: Option<ProtectionResponse> = self
+            .get_if_visible("branches/protection", route, None::<&()>)
+            .await?;
+
+        Ok(MergeRequirements {
+            mergeable: pull.mergeable,
+            mergeable_state: pull.mergeable_state,
+            base_branch: pull.base.branch,
+            protection: protection.map(ProtectionResponse::into_protection),
+        })
     }
 
-    /// Spawn a background task to fetch repositories progressively
-    pub fn spawn_background_fetch(
-        client: GitHubClient,
+    /// Audit the protection of a repository's default branch
+    ///
+    /// Whether the branch is protected is readable with any access; its rules
+    /// need admin access and are only fetched with it. `None` when the branch
+    /// can't be read.
+    async fn fetch_branch_protection(
+        &self,
+        repo: &AppRepository,
+    ) -> Result<Option<BranchProtectionStatus>, octocrab::Error> {
+        let route = format!(
+            "/repos/{}/{}/branches/{}",
+            repo.owner, repo.name, repo.default_branch
+        );
+        let branch: Option<BranchResponse> =
+            self.get_if_visible("branches", route, None::<&()>).await?;
+        let Some(branch) = branch else {
+            return Ok(None);
+        };
+        if !branch.protected {
+            return Ok(Some(BranchProtectionStatus::Unprotected));
+        }
+        if !repo.admin {
+            return Ok(Some(BranchProtectionStatus::Protected(None)));
+        }
+
+        let route = format!(
+            "/repos/{}/{}/branches/{}/protection",
+            repo.owner, repo.name, repo.default_branch
+        );
+        let protection: Option<ProtectionResponse> = self
+            .get_if_visible("branches/protection", route, None::<&()>)
+            .await?;
+        Ok(Some(BranchProtectionStatus::Protected(
+            protection.map(ProtectionResponse::into_protection),
+        )))
+    }
+
+    /// Spawn a background task to fetch what a pull request needs to merge
+    pub fn spawn_merge_requirements_fetch(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        owner: String,
+        repo: String,
+        pr_number: u32,
+    ) {
+        tokio::spawn(async move {
+            let message = match client
+                .fetch_merge_requirements(&owner, &repo, pr_number)
+                .await
+            {
+                Ok(requirements) => BackgroundMessage::MergeRequirementsFetched {
+                    pr_number,
+                    requirements,
+                },
+                Err(e) => BackgroundMessage::ActionError {
+                    error: format!(
+                        "Failed to fetch merge requirements for #{}: {}",
+                        pr_number, e
+                    ),
+                },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Spawn a background task to fetch a pull request's changed files
+    pub fn spawn_pull_request_files_fetch(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        owner: String,
+        repo: String,
+        pr_number: u32,
+    ) {
+        tokio::spawn(async move {
+            let message = match client
+                .fetch_pull_request_files(&owner, &repo, pr_number)
+                .await
+            {
+                Ok(files) => BackgroundMessage::PullRequestFilesFetched { pr_number, files },
+                Err(e) => BackgroundMessage::ActionError {
+                    error: format!("Failed to fetch files for #{}: {}", pr_number, e),
+                },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Fetch the check runs reported against a commit (typically a PR head)
+    pub async fn fetch_check_runs(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+    ) -> Result<Vec<AppCheckRun>, Box<dyn std::error::Error>> {
+        let check_runs = self
+            .timed("check-runs", || async {
+                self.octocrab
+                    .checks(owner, repo)
+                    .list_check_runs_for_git_ref(octocrab::params::repos::Commitish(
+                        sha.to_string(),
+                    ))
+                    .per_page(100)
+                    .send()
+                    .await
+            })
+            .await?;
+
+        let app_checks = check_runs
+            .check_runs
+            .into_iter()
+            .map(|check| AppCheckRun {
+                id: check.id.0,
+                status: AppCheckRun::status_from_conclusion(check.conclusion.as_deref()),
+                name: check.name,
+            })
+            .collect();
+
+        Ok(app_checks)
+    }
+
+    /// Re-request a single check run so GitHub runs it again
+    pub async fn rerequest_check_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: u64,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.timed("check-runs/rerequest", || async {
+            self.octocrab
+                .checks(owner, repo)
+                .rerequest_check_run(octocrab::models::CheckRunId(check_run_id))
+                .send()
+                .await
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Submit a review (approval or general comment) on a pull request
+    pub async fn submit_pull_request_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u32,
+        event: ReviewEvent,
+        body: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let route = format!("/repos/{}/{}/pulls/{}/reviews", owner, repo, number);
+        let mut review = serde_json::json!({ "event": event.api_name() });
+        if !body.is_empty() {
+            review["body"] = serde_json::Value::String(body.to_string());
+        }
+        let _: serde_json::Value = self
+            .timed_once("pulls/reviews", self.octocrab.post(route, Some(&review)))
+            .await?;
+        Ok(())
+    }
+
+    /// Fetch GitHub Pages build status and the latest deployment per environment
+    async fn fetch_deploy_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        has_pages: bool,
+    ) -> Result<DeployStatus, Box<dyn std::error::Error>> {
+        let mut deploy_status = DeployStatus::default();
+
+        // Only ask for Pages when the repo has it enabled; the endpoint 404s otherwise
+        if has_pages {
+            let pages: PagesResponse = self
+                .timed("pages", || {
+                    self.octocrab
+                        .get(format!("/repos/{}/{}/pages", owner, repo), None::<&()>)
+                })
+                .await?;
+            deploy_status.pages = Some(
+                pages
+                    .status
+                    .as_deref()
+                    .map(DeploymentState::from_pages_status)
+                    .unwrap_or(DeploymentState::Unknown),
+            );
+        }
+
+        let environments: EnvironmentsResponse = self
+            .timed("environments", || {
+                self.octocrab.get(
+                    format!("/repos/{}/{}/environments", owner, repo),
+                    None::<&()>,
+                )
+            })
+            .await?;
+
+        for environment in environments.environments {
+            // GitHub Pages deployments are already covered by the Pages build status
+            if has_pages && environment.name == "github-pages" {
+                continue;
+            }
+
+            let deployments: Vec<DeploymentResponse> = self
+                .timed("deployments", || async {
+                    self.octocrab
+                        .get(
+                            format!("/repos/{}/{}/deployments", owner, repo),
+                            Some(&[
+                                ("environment", environment.name.as_str()),
+                                ("per_page", "1"),
+                            ]),
+                        )
+                        .await
+                })
+                .await?;
+            let Some(deployment) = deployments.first() else {
+                continue;
+            };
+
+            let statuses: Vec<DeploymentStatusResponse> = self
+                .timed("deployments/statuses", || {
+                    self.octocrab.get(
+                        format!(
+                            "/repos/{}/{}/deployments/{}/statuses",
+                            owner, repo, deployment.id
+                        ),
+                        Some(&[("per_page", "1")]),
+                    )
+                })
+                .await?;
+            let state = statuses
+                .first()
+                .map(|status| DeploymentState::from_deployment_status(&status.state))
+                .unwrap_or(DeploymentState::InProgress);
+
+            deploy_status.environments.push(EnvironmentDeployment {
+                name: environment.name,
+                state,
+            });
+        }
+
+        Ok(deploy_status)
+    }
+
+    /// GET a route, returning `None` when the token isn't allowed to see it
+    async fn get_if_visible<T, P>(
+        &self,
+        endpoint: &'static str,
+        route: String,
+        parameters: Option<&P>,
+    ) -> Result<Option<T>, octocrab::Error>
+    where
+        T: serde::de::DeserializeOwned,
+        P: serde::Serialize + ?Sized,
+    {
+        match self.get_cached(endpoint, route, parameters).await {
+            Ok(value) => Ok(Some(value)),
+            Err(octocrab::Error::GitHub { source, .. })
+                if source.status_code == http::StatusCode::FORBIDDEN
+                    || source.status_code == http::StatusCode::NOT_FOUND =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Paths of all files in a repository's default branch
+    ///
+    /// Returns `None` for empty or inaccessible repositories.
+    async fn fetch_file_paths(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<HashSet<String>>, octocrab::Error> {
+        let route = format!("/repos/{}/{}/git/trees/HEAD", owner, repo);
+        match self
+            .get_if_visible::<TreeResponse, _>("git/trees", route, Some(&[("recursive", "1")]))
+            .await
+        {
+            Ok(tree) => Ok(tree.map(|tree| {
+                tree.tree
+                    .into_iter()
+                    .filter(|entry| entry.kind == "blob")
+                    .map(|entry| entry.path)
+                    .collect()
+            })),
+            // Empty repositories have no tree yet
+            Err(octocrab::Error::GitHub { source, .. })
+                if source.status_code == http::StatusCode::CONFLICT =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Compare repositories against the files of a standard template repository
+    ///
+    /// `manifest` lists the expected paths; when empty, the template's root
+    /// files and everything under `.github/` are expected.
+    pub async fn scan_template_compliance(
+        &self,
+        template: &str,
+        manifest: &[String],
+        repositories: &[(String, String)],
+    ) -> Result<ComplianceReport, String> {
+        let (template_owner, template_name) = template
+            .split_once('/')
+            .ok_or_else(|| format!("Invalid template repository '{}'", template))?;
+
+        let expected = if manifest.is_empty() {
+            let paths = self
+                .fetch_file_paths(template_owner, template_name)
+                .await
+                .map_err(|e| format!("Failed to read template {}: {}", template, e))?
+                .ok_or_else(|| format!("Template {} is empty or not accessible", template))?;
+            let mut expected: Vec<String> = paths
+                .into_iter()
+                .filter(|path| !path.contains('/') || path.starts_with(".github/"))
+                .collect();
+            expected.sort();
+            expected
+        } else {
+            manifest.to_vec()
+        };
+
+        let mut files = Vec::new();
+        for (owner, name) in repositories {
+            if owner.eq_ignore_ascii_case(template_owner)
+                && name.eq_ignore_ascii_case(template_name)
+            {
+                continue;
+            }
+            let paths = self
+                .fetch_file_paths(owner, name)
+                .await
+                .map_err(|e| format!("Failed to read {}/{}: {}", owner, name, e))?;
+            files.push((name.clone(), paths));
+        }
+
+        Ok(ComplianceReport::new(template.to_string(), expected, files))
+    }
+
+    /// Spawn a background task scanning repositories for template compliance
+    pub fn spawn_compliance_scan(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        template: String,
+        manifest: Vec<String>,
+        repositories: Vec<(String, String)>,
+    ) {
+        tokio::spawn(async move {
+            let message = match client
+                .scan_template_compliance(&template, &manifest, &repositories)
+                .await
+            {
+                Ok(report) => BackgroundMessage::ComplianceScanned { report },
+                Err(error) => BackgroundMessage::ActionError { error },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Manifests found at the root of a repository's default branch
+    async fn fetch_manifests(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<Manifest>, octocrab::Error> {
+        let mut manifests = Vec::new();
+        for file_name in MANIFEST_FILES {
+            let route = format!("/repos/{}/{}/contents/{}", owner, repo, file_name);
+            let content: Option<octocrab::models::repos::Content> =
+                self.get_if_visible("contents", route, None::<&()>).await?;
+            if let Some(manifest) = content
+                .and_then(|content| content.decoded_content())
+                .and_then(|text| Manifest::parse(file_name, &text))
+            {
+                manifests.push(manifest);
+            }
+        }
+        Ok(manifests)
+    }
+
+    /// Build the internal dependency graph between repositories from their manifests
+    pub async fn scan_dependencies(
+        &self,
+        repositories: &[(String, String)],
+    ) -> Result<DependencyGraph, String> {
+        let mut manifests = Vec::new();
+        for (owner, name) in repositories {
+            let found = self
+                .fetch_manifests(owner, name)
+                .await
+                .map_err(|e| format!("Failed to read manifests of {}/{}: {}", owner, name, e))?;
+            for manifest in found {
+                manifests.push((format!("{}/{}", owner, name), manifest));
+            }
+        }
+        Ok(DependencyGraph::build(&manifests))
+    }
+
+    /// Spawn a background task building the dependency graph of repositories
+    pub fn spawn_dependency_scan(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        repositories: Vec<(String, String)>,
+    ) {
+        tokio::spawn(async move {
+            let message = match client.scan_dependencies(&repositories).await {
+                Ok(graph) => BackgroundMessage::DependenciesScanned { graph },
+                Err(error) => BackgroundMessage::ActionError { error },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// The first CODEOWNERS file found in the places GitHub looks for one
+    async fn fetch_codeowners(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<Codeowners>, octocrab::Error> {
+        for path in CODEOWNERS_PATHS {
+            let route = format!("/repos/{}/{}/contents/{}", owner, repo, path);
+            let content: Option<octocrab::models::repos::Content> =
+                self.get_if_visible("contents", route, None::<&()>).await?;
+            if let Some(text) = content.and_then(|content| content.decoded_content()) {
+                return Ok(Some(Codeowners::parse(&text)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Read the CODEOWNERS of repositories, keyed by full name
+    ///
+    /// Repositories without a CODEOWNERS file are left out.
+    pub async fn scan_ownership(
+        &self,
+        repositories: &[(String, String)],
+    ) -> Result<HashMap<String, Codeowners>, String> {
+        let mut ownership = HashMap::new();
+        for (owner, name) in repositories {
+            let codeowners = self
+                .fetch_codeowners(owner, name)
+                .await
+                .map_err(|e| format!("Failed to read CODEOWNERS of {}/{}: {}", owner, name, e))?;
+            if let Some(codeowners) = codeowners {
+                ownership.insert(format!("{}/{}", owner, name), codeowners);
+            }
+        }
+        Ok(ownership)
+    }
+
+    /// Spawn a background task reading the CODEOWNERS of repositories
+    pub fn spawn_ownership_scan(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        repositories: Vec<(String, String)>,
+    ) {
+        tokio::spawn(async move {
+            let message = match client.scan_ownership(&repositories).await {
+                Ok(ownership) => BackgroundMessage::OwnershipScanned { ownership },
+                Err(error) => BackgroundMessage::ActionError { error },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Read what topic policies check beyond the dashboard data
+    async fn fetch_policy_facts(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<PolicyFacts, octocrab::Error> {
+        let route = format!("/repos/{}/{}", owner, repo);
+        let details: Option<DefaultBranchResponse> =
+            self.get_if_visible("repos", route, None::<&()>).await?;
+
+        // The branch endpoint tells whether it's protected without admin access
+        let mut default_branch_protected = None;
+        if let Some(branch) = details.and_then(|details| details.default_branch) {
+            let route = format!("/repos/{}/{}/branches/{}", owner, repo, branch);
+            let branch: Option<BranchResponse> =
+                self.get_if_visible("branches", route, None::<&()>).await?;
+            default_branch_protected = branch.map(|branch| branch.protected);
+        }
+
+        Ok(PolicyFacts {
+            default_branch_protected,
+            has_codeowners: self.fetch_codeowners(owner, repo).await?.is_some(),
+        })
+    }
+
+    /// Read the policy facts of repositories, keyed by full name
+    pub async fn scan_policy_facts(
+        &self,
+        repositories: &[(String, String)],
+    ) -> Result<HashMap<String, PolicyFacts>, String> {
+        let mut facts = HashMap::new();
+        for (owner, name) in repositories {
+            let repo_facts = self
+                .fetch_policy_facts(owner, name)
+                .await
+                .map_err(|e| format!("Failed to check policies of {}/{}: {}", owner, name, e))?;
+            facts.insert(format!("{}/{}", owner, name), repo_facts);
+        }
+        Ok(facts)
+    }
+
+    /// Spawn a background task reading the policy facts of repositories
+    pub fn spawn_policy_scan(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        repositories: Vec<(String, String)>,
+    ) {
+        tokio::spawn(async move {
+            let message = match client.scan_policy_facts(&repositories).await {
+                Ok(facts) => BackgroundMessage::PoliciesScanned { facts },
+                Err(error) => BackgroundMessage::ActionError { error },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Parent of a fork without any commits of its own, by full name
+    async fn unchanged_fork_parent(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<String>, octocrab::Error> {
+        // Only the single repository endpoint includes the parent
+        let route = format!("/repos/{}/{}", owner, repo);
+        let Some(fork) = self
+            .get_if_visible::<ForkResponse, _>("repos", route, None::<&()>)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let Some(parent) = fork.parent else {
+            return Ok(None);
+        };
+
+        let route = format!(
+            "/repos/{}/compare/{}...{}:{}",
+            parent.full_name, parent.default_branch, owner, fork.default_branch
+        );
+        let comparison: Option<CompareResponse> =
+            self.get_if_visible("compare", route, None::<&()>).await?;
+        Ok(comparison
+            .filter(|comparison| comparison.ahead_by == 0)
+            .map(|_| parent.full_name))
+    }
+
+    /// Whether a repository has no commits at all
+    async fn has_no_commits(&self, owner: &str, repo: &str) -> Result<bool, octocrab::Error> {
+        let route = format!("/repos/{}/{}/commits", owner, repo);
+        match self
+            .timed("commits", || {
+                self.octocrab
+                    .get::<serde_json::Value, _, _>(&route, Some(&[("per_page", "1")]))
+            })
+            .await
+        {
+            Ok(_) => Ok(false),
+            // GitHub answers 409 Conflict for repositories without commits
+            Err(octocrab::Error::GitHub { source, .. })
+                if source.status_code == http::StatusCode::CONFLICT =>
+            {
+                Ok(true)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Page views of a repository over the last 14 days
+    ///
+    /// Returns `None` when the token can't see traffic, which needs push access.
+    async fn fetch_recent_views(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<u64>, octocrab::Error> {
+        let route = format!("/repos/{}/{}/traffic/views", owner, repo);
+        let views: Option<TrafficViewsResponse> = self
+            .get_if_visible("traffic/views", route, None::<&()>)
+            .await?;
+        Ok(views.map(|views| views.count))
+    }
+
+    /// Find repositories that look abandoned or duplicated
+    ///
+    /// Names are checked locally; only forks, repositories reporting no
+    /// content and dormant repositories with nothing open cost API requests.
+    /// Archived repositories are skipped.
+    pub async fn scan_cleanup_candidates(
+        &self,
+        repositories: &[AppRepository],
+    ) -> Result<Vec<CleanupCandidate>, String> {
+        let now = SystemTime::now();
+        let mut candidates = Vec::new();
+        for repo in repositories.iter().filter(|repo| !repo.archived) {
+            let mut reasons: Vec<CleanupReason> = cleanup::leftover_name(repo, repositories)
+                .into_iter()
+                .collect();
+            if repo.fork {
+                let parent = self
+                    .unchanged_fork_parent(&repo.owner, &repo.name)
+                    .await
+                    .map_err(|e| format!("Failed to compare {}: {}", repo.full_name(), e))?;
+                if let Some(parent) = parent {
+                    reasons.push(CleanupReason::UnchangedFork { parent });
+                }
+            }
+            if repo.empty {
+                reasons.push(CleanupReason::Empty);
+            } else if repo.size_kb == 0 {
+                let empty = self
+                    .has_no_commits(&repo.owner, &repo.name)
+                    .await
+                    .map_err(|e| format!("Failed to read {}: {}", repo.full_name(), e))?;
+                if empty {
+                    reasons.push(CleanupReason::Empty);
+                }
+            }
+            if let Some(days) = cleanup::dormant_days(repo, now) {
+                let views = self
+                    .fetch_recent_views(&repo.owner, &repo.name)
+                    .await
+                    .map_err(|e| {
+                        format!("Failed to read traffic of {}: {}", repo.full_name(), e)
+                    })?;
+                if views == Some(0) {
+                    reasons.push(CleanupReason::Dormant { days });
+                }
+            }
+            if !reasons.is_empty() {
+                candidates.push(CleanupCandidate {
+                    repository: repo.full_name(),
+                    reasons,
+                });
+            }
+        }
+        Ok(candidates)
+    }
+
+    /// Archive a repository, making it read-only
+    pub async fn archive_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let route = format!("/repos/{}/{}", owner, repo);
+        let body = serde_json::json!({ "archived": true });
+        let _: serde_json::Value = self
+            .timed("repos", || self.octocrab.patch(&route, Some(&body)))
+            .await?;
+        Ok(())
+    }
+
+    /// Spawn a background task archiving a repository
+    pub fn spawn_archive_repository(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        owner: String,
+        repo: String,
+    ) {
+        tokio::spawn(async move {
+            let repository = format!("{}/{}", owner, repo);
+            let message = match client.archive_repository(&owner, &repo).await {
+                Ok(()) => BackgroundMessage::RepositoryArchived { repository },
+                Err(e) => BackgroundMessage::ActionError {
+                    error: format!("Failed to archive {}: {}", repository, e),
+                },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Spawn a background task looking for repositories to archive
+    pub fn spawn_cleanup_scan(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        repositories: Vec<AppRepository>,
+    ) {
+        tokio::spawn(async move {
+            let message = match client.scan_cleanup_candidates(&repositories).await {
+                Ok(candidates) => BackgroundMessage::CleanupScanned { candidates },
+                Err(error) => BackgroundMessage::ActionError { error },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Download the last lines of a job's log
+    async fn fetch_job_log_tail(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: u64,
+    ) -> Result<String, octocrab::Error> {
+        let route = format!("/repos/{}/{}/actions/jobs/{}/logs", owner, repo, job_id);
+        let log = self
+            .timed("actions/jobs/logs", || async {
+                // GitHub answers with a redirect to a short-lived download URL
+                let response = self.octocrab._get(route.as_str()).await?;
+                let response = self.octocrab.follow_location_to_data(response).await?;
+                let response = octocrab::map_github_error(response).await?;
+                self.octocrab.body_to_string(response).await
+            })
+            .await?;
+        Ok(failures::log_tail(&log, LOG_TAIL_LINES))
+    }
+
+    /// Cluster the recent failed workflow runs of repositories by error signature
+    ///
+    /// Reads the log tail of every failed job in each repository's latest
+    /// failed runs. Jobs whose logs have expired are skipped.
+    pub async fn cluster_workflow_failures(
+        &self,
+        repositories: &[AppRepository],
+    ) -> Result<Vec<FailureCluster>, String> {
+        let mut signatures = Vec::new();
+        for repo in repositories {
+            let failed_runs = repo
+                .recent_workflows
+                .iter()
+                .filter(|run| run.status == WorkflowStatus::Failed)
+                .take(RUNS_PER_REPOSITORY);
+            for run in failed_runs {
+                let route = format!(
+                    "/repos/{}/{}/actions/runs/{}/jobs",
+                    repo.owner, repo.name, run.id
+                );
+                let jobs: JobsResponse = self
+                    .timed("actions/runs/jobs", || {
+                        self.octocrab.get(&route, Some(&[("filter", "latest")]))
+                    })
+                    .await
+                    .map_err(|e| format!("Failed to list jobs for {}: {}", repo.full_name(), e))?;
+
+                for job in jobs.jobs {
+                    if job.conclusion.as_deref() != Some("failure") {
+                        continue;
+                    }
+                    let tail = match self
+                        .fetch_job_log_tail(&repo.owner, &repo.name, job.id)
+                        .await
+                    {
+                        Ok(tail) => tail,
+                        Err(e) => {
+                            eprintln!(
+                                "Failed to download log of {} job {}: {}",
+                                repo.full_name(),
+                                job.id,
+                                e
+                            );
+                            continue;
+                        }
+                    };
+                    let signature = failures::error_signature(&tail)
+                        .unwrap_or_else(|| format!("{} failed without an error message", job.name));
+                    signatures.push((
+                        signature,
+                        FailedJob {
+                            repository: repo.full_name(),
+                            workflow: run.name.clone(),
+                            job: job.name,
+                            html_url: job.html_url.unwrap_or_default(),
+                        },
+                    ));
+                }
+            }
+        }
+        Ok(failures::cluster(signatures))
+    }
+
+    /// Spawn a background task clustering workflow failures by error signature
+    pub fn spawn_failure_clustering(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        repositories: Vec<AppRepository>,
+    ) {
+        tokio::spawn(async move {
+            let message = match client.cluster_workflow_failures(&repositories).await {
+                Ok(clusters) => BackgroundMessage::FailuresClustered { clusters },
+                Err(error) => BackgroundMessage::ActionError { error },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Fetch the authenticated user's contribution calendar for the last year
+    ///
+    /// Only the GraphQL API exposes the calendar shown on GitHub profiles.
+    pub async fn fetch_contribution_calendar(&self) -> Result<ContributionCalendar, String> {
+        let query = serde_json::json!({ "query": CONTRIBUTIONS_QUERY });
+        let response: GraphQlResponse<ContributionsData> = self
+            .timed("graphql", || self.octocrab.graphql(&query))
+            .await
+            .map_err(|e| format!("Failed to fetch contributions: {}", e))?;
+
+        let Some(data) = response.data else {
+            let errors = response
+                .errors
+                .iter()
+                .map(|error| error.message.as_str())
+                .collect::<Vec<_>>()
+                .join("; ");
+            return Err(format!("Failed to fetch contributions: {}", errors));
+        };
+        let viewer = data.viewer;
+        let calendar = viewer.contributions_collection.contribution_calendar;
+        Ok(ContributionCalendar {
+            login: viewer.login,
+            total: calendar.total_contributions,
+            weeks: calendar
+                .weeks
+                .into_iter()
+                .map(|week| {
+                    week.contribution_days
+                        .into_iter()
+                        .map(|day| ContributionDay {
+                            date: day.date,
+                            count: day.contribution_count,
+                        })
+                        .collect()
+                })
+                .collect(),
+        })
+    }
+
+    /// Spawn a background task to fetch the user's contribution calendar
+    pub fn spawn_contributions_fetch(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+    ) {
+        tokio::spawn(async move {
+            let message = match client.fetch_contribution_calendar().await {
+                Ok(calendar) => BackgroundMessage::ContributionsFetched { calendar },
+                Err(error) => BackgroundMessage::ActionError { error },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Create a repository from a template repository and fetch its data
+    pub async fn create_repository_from_template(
+        &self,
+        template: &str,
+        owner: &str,
+        name: &str,
+        description: &str,
+        private: bool,
+    ) -> Result<AppRepository, String> {
+        let route = format!("/repos/{}/generate", template);
+        let body = serde_json::json!({
+            "owner": owner,
+            "name": name,
+            "description": description,
+            "private": private,
+        });
+        let created: Repository = self
+            .timed_once("generate", self.octocrab.post(route, Some(&body)))
+            .await
+            .map_err(|e| format!("Failed to create {}/{}: {}", owner, name, e))?;
+
+        let mut repository = Self::basic_repository(created)?;
+        self.enhance_repository(&mut repository).await?;
+        Ok(repository)
+    }
+
+    /// Spawn a background task creating a repository from a template
+    pub fn spawn_repository_creation(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        template: String,
+        owner: String,
+        name: String,
+        description: String,
+        private: bool,
+    ) {
+        tokio::spawn(async move {
+            let message = match client
+                .create_repository_from_template(&template, &owner, &name, &description, private)
+                .await
+            {
+                Ok(repository) => BackgroundMessage::RepositoryCreated { repository },
+                Err(error) => BackgroundMessage::ActionError { error },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Fetch membership hygiene signals for an organization
+    ///
+    /// `repositories` are the org repositories checked for outside
+    /// collaborators with write access.
+    pub async fn fetch_org_membership_health(
+        &self,
+        org: &str,
+        repositories: &[String],
+    ) -> Result<OrgMembershipHealth, Box<dyn std::error::Error>> {
+        let mut health = OrgMembershipHealth::new(org.to_string());
+
+        let invitations: Option<Vec<InvitationResponse>> = self
+            .get_if_visible(
+                "orgs/invitations",
+                format!("/orgs/{}/invitations", org),
+                Some(&[("per_page", "100")]),
+            )
+            .await?;
+        health.pending_invitations = invitations.map(|invitations| {
+            invitations
+                .into_iter()
+                .map(|invitation| OrgInvitation {
+                    invitee: invitation
+                        .login
+                        .or(invitation.email)
+                        .unwrap_or_else(|| "unknown".to_string()),
+                    role: invitation.role,
+                    inviter: invitation
+                        .inviter
+                        .map(|user| user.login)
+                        .unwrap_or_else(|| "unknown".to_string()),
+                    created_at: invitation
+                        .created_at
+                        .map(|dt| {
+                            SystemTime::UNIX_EPOCH
+                                + std::time::Duration::from_secs(dt.timestamp() as u64)
+                        })
+                        .unwrap_or_else(SystemTime::now),
+                })
+                .collect()
+        });
+
+        // The 2fa_disabled filter is only honoured for organization owners
+        let members: Option<Vec<UserResponse>> = self
+            .get_if_visible(
+                "orgs/members",
+                format!("/orgs/{}/members", org),
+                Some(&[("filter", "2fa_disabled"), ("per_page", "100")]),
+            )
+            .await?;
+        health.members_without_2fa =
+            members.map(|members| members.into_iter().map(|user| user.login).collect());
+
+        let mut collaborators: Vec<OutsideCollaborator> = Vec::new();
+        let mut any_visible = false;
+        for repo in repositories {
+            let repo_collaborators: Option<Vec<CollaboratorResponse>> = self
+                .get_if_visible(
+                    "collaborators",
+                    format!("/repos/{}/{}/collaborators", org, repo),
+                    Some(&[("affiliation", "outside"), ("per_page", "100")]),
+                )
+                .await?;
+            let Some(repo_collaborators) = repo_collaborators else {
+                continue;
+            };
+            any_visible = true;
+
+            for collaborator in repo_collaborators {
+                if !collaborator.permissions.push && !collaborator.permissions.admin {
+                    continue;
+                }
+                match collaborators
+                    .iter_mut()
+                    .find(|c| c.login == collaborator.login)
+                {
+                    Some(existing) => existing.repositories.push(repo.clone()),
+                    None => collaborators.push(OutsideCollaborator {
+                        login: collaborator.login,
+                        repositories: vec![repo.clone()],
+                    }),
+                }
+            }
+        }
+        if any_visible || repositories.is_empty() {
+            health.outside_collaborators = Some(collaborators);
+        }
+
+        Ok(health)
+    }
+
+    /// Fetch the last weeks' health-relevant events from an organization's
+    /// audit log
+    ///
+    /// Reading the audit log takes an organization owner's token on GitHub
+    /// Enterprise Cloud; without access the events are `None`.
+    pub async fn fetch_org_audit_log(
+        &self,
+        org: &str,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<OrgAuditLog, Box<dyn std::error::Error>> {
+        let mut events = Vec::new();
+        for action in AUDIT_ACTIONS {
+            let phrase = format!("action:{} created:>={}", action, since.format("%Y-%m-%d"));
+            let entries: Option<Vec<AuditEntry>> = self
+                .get_if_visible(
+                    "orgs/audit-log",
+                    format!("/orgs/{}/audit-log", org),
+                    Some(&[("phrase", phrase.as_str()), ("per_page", "100")]),
+                )
+                .await?;
+            // Without access every search is refused alike
+            let Some(entries) = entries else {
+                return Ok(OrgAuditLog {
+                    org: org.to_string(),
+                    events: None,
+                });
+            };
+            events.extend(entries.into_iter().filter_map(AuditEvent::from_entry));
+        }
+        events.sort_by_key(|event| std::cmp::Reverse(event.created_at));
+        Ok(OrgAuditLog {
+            org: org.to_string(),
+            events: Some(events),
+        })
+    }
+
+    /// Fetch open issues carrying the given label (pull requests excluded)
+    pub async fn fetch_labeled_issues(
+        &self,
+        owner: &str,
+        repo: &str,
+        label: &str,
+    ) -> Result<Vec<Issue>, Box<dyn std::error::Error>> {
+        let labels = [label.to_string()];
+        let page = self
+            .timed("issues", || async {
+                self.octocrab
+                    .issues(owner, repo)
+                    .list()
+                    .state(octocrab::params::State::Open)
+                    .labels(&labels)
+                    .per_page(100)
+                    .send()
+                    .await
+            })
+            .await?;
+
+        Ok(page
+            .items
+            .into_iter()
+            .filter(|issue| issue.pull_request.is_none())
+            .map(Self::app_issue)
+            .collect())
+    }
+
+    /// Convert a GitHub issue to our app issue
+    fn app_issue(issue: octocrab::models::issues::Issue) -> Issue {
+        Issue {
+            number: issue.number,
+            title: issue.title,
+            html_url: issue.html_url.to_string(),
+            assignees: issue
+                .assignees
+                .into_iter()
+                .map(|assignee| assignee.login)
+                .collect(),
+            labels: issue.labels.into_iter().map(|label| label.name).collect(),
+            comments: issue.comments,
+        }
+    }
+
+    /// Fetch the most recently updated open issues (pull requests excluded)
+    pub async fn fetch_open_issues(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<Issue>, Box<dyn std::error::Error>> {
+        let page = self
+            .timed("issues", || async {
+                self.octocrab
+                    .issues(owner, repo)
+                    .list()
+                    .state(octocrab::params::State::Open)
+                    .sort(octocrab::params::issues::Sort::Updated)
+                    .per_page(100)
+                    .send()
+                    .await
+            })
+            .await?;
+
+        Ok(page
+            .items
+            .into_iter()
+            .filter(|issue| issue.pull_request.is_none())
+            .map(Self::app_issue)
+            .collect())
+    }
+
+    /// Search open issues with each query, oldest first
+    ///
+    /// Each query returns up to 100 issues; the total is what GitHub found
+    /// across all queries, so the caller can tell when the list is cut off.
+    pub async fn search_issues(
+        &self,
+        queries: &[String],
+    ) -> Result<(Vec<SearchedIssue>, usize), Box<dyn std::error::Error>> {
+        let mut issues = Vec::new();
+        let mut total = 0;
+        for query in queries {
+            let page = self
+                .timed("search/issues", || {
+                    self.octocrab
+                        .search()
+                        .issues_and_pull_requests(query)
+                        .sort("created")
+                        .order("asc")
+                        .per_page(100)
+                        .send()
+                })
+                .await?;
+            total += page.total_count.unwrap_or_default() as usize;
+            issues.extend(page.items.into_iter().map(|issue| {
+                // The repository URL ends with owner/name
+                let mut segments = issue.repository_url.path().rsplit('/');
+                let name = segments.next().unwrap_or_default();
+                let owner = segments.next().unwrap_or_default();
+                SearchedIssue {
+                    repository: format!("{}/{}", owner, name),
+                    created_at: system_time(issue.created_at),
+                    issue: Self::app_issue(issue),
+                }
+            }));
+        }
+        issues.sort_by_key(|issue| issue.created_at);
+        Ok((issues, total))
+    }
+
+    /// Apply a triage change to an issue, returning the updated issue
+    pub async fn triage_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        action: &IssueAction,
+    ) -> Result<Issue, Box<dyn std::error::Error>> {
+        let issues = self.octocrab.issues(owner, repo);
+        match action {
+            IssueAction::Assign(login) => {
+                self.timed("issues/assignees", || async {
+                    issues.add_assignees(number, &[login.as_str()]).await
+                })
+                .await?;
+            }
+            IssueAction::Label { add, remove } => {
+                if !add.is_empty() {
+                    self.timed("issues/labels", || async {
+                        issues.add_labels(number, add).await
+                    })
+                    .await?;
+                }
+                for label in remove {
+                    self.timed("issues/labels", || async {
+                        issues.remove_label(number, label).await
+                    })
+                    .await?;
+                }
+            }
+            IssueAction::Comment(body) => {
+                self.timed_once("issues/comments", issues.create_comment(number, body))
+                    .await?;
+            }
+        }
+
+        let issue = self.timed("issues/get", || issues.get(number)).await?;
+        Ok(Self::app_issue(issue))
+    }
+
+    /// Fetch open/close times of issues updated within the last `weeks` weeks
+    ///
+    /// Pull requests are excluded. At most 1000 issues are fetched.
+    pub async fn fetch_issue_activity(
+        &self,
+        owner: &str,
+        repo: &str,
+        weeks: usize,
+    ) -> Result<Vec<IssueActivity>, Box<dyn std::error::Error>> {
+        let since = chrono::Utc::now() - chrono::Duration::weeks(weeks as i64);
+        let since = since.to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+        let route = format!("/repos/{}/{}/issues", owner, repo);
+
+        let mut activity = Vec::new();
+        for page in 1..=10u32 {
+            let page = page.to_string();
+            let issues: Vec<IssueActivityResponse> = self
+                .timed("issues", || async {
+                    self.octocrab
+                        .get(
+                            &route,
+                            Some(&[
+                                ("state", "all"),
+                                ("since", since.as_str()),
+                                ("per_page", "100"),
+                                ("page", page.as_str()),
+                            ]),
+                        )
+                        .await
+                })
+                .await?;
+            let last_page = issues.len() < 100;
+
+            activity.extend(
+                issues
+                    .into_iter()
+                    .filter(|issue| issue.pull_request.is_none())
+                    .map(|issue| IssueActivity {
+                        created_at: system_time(issue.created_at),
+                        closed_at: issue.closed_at.map(system_time),
+                        comments: issue.comments,
+                    }),
+            );
+            if last_page {
+                break;
+            }
+        }
+
+        Ok(activity)
+    }
+
+    /// Spawn a background task to fetch issue activity for a burn-down chart
+    pub fn spawn_issue_activity_fetch(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        owner: String,
+        repo: String,
+        weeks: usize,
+    ) {
+        tokio::spawn(async move {
+            let message = match client.fetch_issue_activity(&owner, &repo, weeks).await {
+                Ok(issues) => BackgroundMessage::IssueActivityFetched {
+                    repository: format!("{}/{}", owner, repo),
+                    weeks,
+                    issues,
+                },
+                Err(e) => BackgroundMessage::ActionError {
+                    error: format!("Failed to fetch issues for {}/{}: {}", owner, repo, e),
+                },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Spawn a background task to fetch a repository's open issues for triage
+    pub fn spawn_open_issues_fetch(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        owner: String,
+        repo: String,
+    ) {
+        tokio::spawn(async move {
+            let message = match client.fetch_open_issues(&owner, &repo).await {
+                Ok(issues) => BackgroundMessage::OpenIssuesFetched {
+                    repository: format!("{}/{}", owner, repo),
+                    issues,
+                },
+                Err(e) => BackgroundMessage::ActionError {
+                    error: format!("Failed to fetch issues for {}/{}: {}", owner, repo, e),
+                },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Spawn a background task searching open issues across repositories
+    pub fn spawn_issue_search(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        filter: IssueSearchFilter,
+        queries: Vec<String>,
+    ) {
+        tokio::spawn(async move {
+            let message = match client.search_issues(&queries).await {
+                Ok((issues, total)) => BackgroundMessage::IssuesSearched {
+                    filter,
+                    issues,
+                    total,
+                },
+                Err(e) => BackgroundMessage::ActionError {
+                    error: format!("Failed to search issues: {}", e),
+                },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Spawn a background task to assign, label or comment on an issue
+    pub fn spawn_issue_triage(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        owner: String,
+        repo: String,
+        number: u64,
+        action: IssueAction,
+    ) {
+        tokio::spawn(async move {
+            let message = match client.triage_issue(&owner, &repo, number, &action).await {
+                Ok(issue) => BackgroundMessage::IssueTriaged {
+                    repository: format!("{}/{}", owner, repo),
+                    issue,
+                    action,
+                },
+                Err(e) => BackgroundMessage::ActionError {
+                    error: format!("Failed to update #{}: {}", number, e),
+                },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Spawn a background task to fetch a repository's critical issues
+    pub fn spawn_critical_issues_fetch(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        owner: String,
+        repo: String,
+        label: String,
+    ) {
+        tokio::spawn(async move {
+            let message = match client.fetch_labeled_issues(&owner, &repo, &label).await {
+                Ok(issues) => BackgroundMessage::CriticalIssuesFetched {
+                    repository: format!("{}/{}", owner, repo),
+                    issues,
+                },
+                Err(e) => BackgroundMessage::ActionError {
+                    error: format!("Failed to fetch issues for {}/{}: {}", owner, repo, e),
+                },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Spawn a background task to fetch organization membership health
+    pub fn spawn_org_membership_fetch(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        org: String,
+        repositories: Vec<String>,
+    ) {
+        tokio::spawn(async move {
+            let message = match client
+                .fetch_org_membership_health(&org, &repositories)
+                .await
+            {
+                Ok(health) => BackgroundMessage::OrgMembershipFetched { health },
+                Err(e) => BackgroundMessage::ActionError {
+                    error: format!("Failed to fetch membership for {}: {}", org, e),
+                },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Spawn a background task fetching an organization's audit log feed
+    pub fn spawn_org_audit_log_fetch(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        org: String,
+    ) {
+        tokio::spawn(async move {
+            let since = chrono::Utc::now() - chrono::Duration::days(AUDIT_LOOKBACK_DAYS);
+            let message = match client.fetch_org_audit_log(&org, since).await {
+                Ok(audit_log) => BackgroundMessage::OrgAuditLogFetched { audit_log },
+                Err(e) => BackgroundMessage::ActionError {
+                    error: format!("Failed to fetch the audit log of {}: {}", org, e),
+                },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Spawn a background task loading an organization for the comparison view
+    ///
+    /// Repositories are enhanced before a single message is sent, since the
+    /// pane's aggregates are only meaningful once all of them are complete.
+    pub fn spawn_comparison_fetch(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        org: String,
+    ) {
+        tokio::spawn(async move {
+            let message = match client.list_repositories_for_organization(&org).await {
+                Ok(mut repositories) => {
+                    let prefetched = client.prefetch_details(&mut repositories).await;
+                    for repo in repositories.iter_mut() {
+                        let repo_prefetched = prefetched.contains(&repo.full_name());
+                        if let Err(e) = client
+                            .enhance_repository_details(repo, repo_prefetched)
+                            .await
+                        {
+                            eprintln!("Failed to enhance repository {}: {}", repo.name, e);
+                        }
+                    }
+                    BackgroundMessage::ComparisonFetched { org, repositories }
+                }
+                Err(e) => BackgroundMessage::ActionError {
+                    error: format!("Failed to fetch repositories for {}: {}", org, e),
+                },
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Spawn a background task loading an organization before it's viewed
+    ///
+    /// Like the comparison fetch, repositories are enhanced before a single
+    /// message is sent, with the requests the load took. Those are counted
+    /// for this task alone, not alongside requests the dashboard makes
+    /// meanwhile.
+    pub fn spawn_organization_prefetch(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        org: String,
+    ) -> JoinHandle<()> {
+        let client = client.counting_requests();
+        tokio::spawn(async move {
+            let result = match client.list_repositories_for_organization(&org).await {
+                Ok(mut repositories) => {
+                    let prefetched = client.prefetch_details(&mut repositories).await;
+                    for repo in repositories.iter_mut() {
+                        let repo_prefetched = prefetched.contains(&repo.full_name());
+                        if let Err(e) = client
+                            .enhance_repository_details(repo, repo_prefetched)
+                            .await
+                        {
+                            eprintln!("Failed to enhance repository {}: {}", repo.name, e);
+                        }
+                    }
+                    Ok(repositories)
+                }
+                Err(e) => Err(format!("Failed to prefetch {}: {}", org, e)),
+            };
+            let _ = sender.send(BackgroundMessage::OrganizationPrefetched {
+                org,
+                result,
+                requests: client.requests_counted(),
+            });
+        })
+    }
+
+    /// Fetch the oldest open issues of a repository, up to 100
+    async fn fetch_oldest_open_issues(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<IssueActivity>, octocrab::Error> {
+        let issues: Vec<IssueActivityResponse> = self
+            .get_cached(
+                "issues",
+                format!("/repos/{}/{}/issues", owner, repo),
+                Some(&[
+                    ("state", "open"),
+                    ("sort", "created"),
+                    ("direction", "asc"),
+                    ("per_page", "100"),
+                ]),
+            )
+            .await?;
+
+        // The issues endpoint lists pull requests too
+        Ok(issues
+            .into_iter()
+            .filter(|issue| issue.pull_request.is_none())
+            .map(|issue| IssueActivity {
+                created_at: system_time(issue.created_at),
+                closed_at: issue.closed_at.map(system_time),
+                comments: issue.comments,
+            })
+            .collect())
+    }
+
+    /// Fetch the latest published release for a repository
+    ///
+    /// GitHub's "latest" skips drafts and prereleases, and 404s when there
+    /// is no release at all.
+    async fn fetch_latest_release(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<Release>, Box<dyn std::error::Error>> {
+        let route = format!("/repos/{}/{}/releases/latest", owner, repo);
+        let release: Option<ReleaseResponse> = self
+            .get_if_visible("releases/latest", route, None::<&()>)
+            .await?;
+        Ok(release.map(|release| Release {
+            name: release
+                .name
+                .filter(|name| !name.trim().is_empty())
+                .unwrap_or_else(|| release.tag_name.clone()),
+            tag: release.tag_name,
+            published_at: system_time(release.published_at.unwrap_or(release.created_at)),
+        }))
+    }
+
+    /// Count the open code scanning alerts of a repository by severity
+    ///
+    /// Only the first 100 alerts are counted. GitHub answers 403 when code
+    /// scanning isn't available or the token lacks `security_events`, and
+    /// 404 when nothing was ever analyzed; both give `None`.
+    async fn fetch_code_scanning_alerts(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<CodeScanningAlerts>, octocrab::Error> {
+        let alerts: Option<Vec<CodeScanningAlertResponse>> = self
+            .get_if_visible(
+                "code-scanning/alerts",
+                format!("/repos/{}/{}/code-scanning/alerts", owner, repo),
+                Some(&[("state", "open"), ("per_page", "100")]),
+            )
+            .await?;
+        Ok(alerts.map(|alerts| {
+            CodeScanningAlerts::from_severities(alerts.iter().map(|alert| {
+                alert
+                    .rule
+                    .security_severity_level
+                    .as_deref()
+                    .or(alert.rule.severity.as_deref())
+                    .unwrap_or("note")
+            }))
+        }))
+    }
+
+    /// Count the open secret scanning alerts of a repository
+    ///
+    /// Only the first 100 alerts are counted. GitHub answers 404 when secret
+    /// scanning is disabled and 403 when the token lacks access to alerts;
+    /// both give `None`.
+    async fn fetch_secret_scanning_alerts(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<u32>, octocrab::Error> {
+        let alerts: Option<Vec<serde::de::IgnoredAny>> = self
+            .get_if_visible(
+                "secret-scanning/alerts",
+                format!("/repos/{}/{}/secret-scanning/alerts", owner, repo),
+                Some(&[("state", "open"), ("per_page", "100")]),
+            )
+            .await?;
+        Ok(alerts.map(|alerts| alerts.len() as u32))
+    }
+
+    /// Read which community health files a repository has from its
+    /// community profile
+    ///
+    /// `None` when GitHub has no profile for it.
+    async fn fetch_community_profile(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<CommunityProfile>, octocrab::Error> {
+        let profile: Option<CommunityProfileResponse> = self
+            .get_if_visible(
+                "community/profile",
+                format!("/repos/{}/{}/community/profile", owner, repo),
+                None::<&()>,
+            )
+            .await?;
+        Ok(profile.map(|profile| {
+            let files = profile.files;
+            CommunityProfile {
+                health_percentage: profile.health_percentage.min(100) as u8,
+                readme: files.readme.is_some(),
+                license: files.license.is_some(),
+                contributing: files.contributing.is_some(),
+                code_of_conduct: files.code_of_conduct.is_some()
+                    || files.code_of_conduct_file.is_some(),
+                issue_templates: files.issue_template.is_some(),
+            }
+        }))
+    }
+
+    /// How concentrated a repository's recent commits are on one
+    /// contributor, from its contributor statistics
+    ///
+    /// `None` without recent commits, and while GitHub is still computing
+    /// the statistics (it answers 202 and they're read on a later refresh).
+    async fn fetch_bus_factor(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<BusFactor>, octocrab::Error> {
+        let stats: Option<ContributorStatsResponse> = self
+            .get_if_visible(
+                "stats/contributors",
+                format!("/repos/{}/{}/stats/contributors", owner, repo),
+                None::<&()>,
+            )
+            .await?;
+        let Some(ContributorStatsResponse::Ready(contributors)) = stats else {
+            return Ok(None);
+        };
+        // Commits of deleted accounts have no author to attribute them to
+        let weeks: Vec<WeeklyCommits> = contributors
+            .into_iter()
+            .filter_map(|contributor| Some((contributor.author?.login, contributor.weeks)))
+            .flat_map(|(author, weeks)| {
+                weeks.into_iter().map(move |week| WeeklyCommits {
+                    author: author.clone(),
+                    week: SystemTime::UNIX_EPOCH + Duration::from_secs(week.w),
+                    commits: week.c,
+                })
+            })
+            .collect();
+        Ok(BusFactor::from_weekly_commits(&weeks, SystemTime::now()))
+    }
+
+    /// Weekly commit counts of a repository's last year
+    ///
+    /// `None` while GitHub is still computing the statistics (202).
+    async fn fetch_commit_activity(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<CommitActivity>, octocrab::Error> {
+        let stats: Option<CommitActivityResponse> = self
+            .get_if_visible(
+                "stats/commit_activity",
+                format!("/repos/{}/{}/stats/commit_activity", owner, repo),
+                None::<&()>,
+            )
+            .await?;
+        let Some(CommitActivityResponse::Ready(weeks)) = stats else {
+            return Ok(None);
+        };
+        Ok(Some(CommitActivity {
+            weeks: weeks.into_iter().map(|week| week.total).collect(),
+        }))
+    }
+
+    /// Fetch recent workflow runs for a repository
+    async fn fetch_workflow_runs(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::models::WorkflowRun>, Box<dyn std::error::Error>> {
+        let route = format!("/repos/{}/{}/actions/runs", owner, repo);
+        let per_page = self.page_sizes.workflow_runs.to_string();
+        let response: WorkflowRunsResponse = self
+            .get_cached(
+                "actions/runs",
+                route,
+                Some(&[("per_page", per_page.as_str())]),
+            )
+            .await?;
+
+        let runs = response
+            .workflow_runs
+            .into_iter()
+            .map(|run| {
+                let status = if run.status.as_deref() == Some("completed") {
+                    AppCheckRun::status_from_conclusion(run.conclusion.as_deref())
+                } else {
+                    WorkflowStatus::InProgress
+                };
+                let created_at = system_time(run.created_at);
+                let updated_at = system_time(run.updated_at);
+                let started_at = run.run_started_at.map(system_time);
+                let duration = (status != WorkflowStatus::InProgress)
+                    .then(|| {
+                        updated_at
+                            .duration_since(started_at.unwrap_or(created_at))
+                            .ok()
+                    })
+                    .flatten();
+
+                crate::models::WorkflowRun {
+                    id: run.id,
+                    name: run.name.unwrap_or_default(),
+                    status,
+                    created_at,
+                    updated_at,
+                    started_at,
+                    duration,
+                    trigger: RunTrigger {
+                        // A pull request from a fork runs the fork's code
+                        from_fork: match (&run.head_repository, &run.repository) {
+                            (Some(head), Some(base)) => head.full_name != base.full_name,
+                            _ => false,
+                        },
+                        branch: run.head_branch,
+                        event: run.event,
+                        actor: run.triggering_actor.or(run.actor).map(|user| user.login),
+                    },
+                }
+            })
+            .collect();
+
+        Ok(runs)
+    }
+
+    /// Determine the overall status of a repository based on last commit time
+    /// and, once fetched, its weekly commit counts
+    ///
+    /// Issues-only repositories follow their issue activity instead, so they
+    /// aren't marked Dormant for lacking commits.
+    fn determine_repository_status(&self, repo: &AppRepository) -> RepositoryStatus {
+        match &repo.issue_health {
+            Some(health) => RepositoryStatus::from_last_commit(health.latest_activity),
+            None if repo.empty => RepositoryStatus::Empty,
+            None => RepositoryStatus::from_activity(
+                repo.latest_commit_at,
+                repo.commit_activity.as_ref(),
+            ),
+        }
+    }
+
+    /// Get the authenticated user information for testing
+    pub async fn get_current_user(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let user = self
+            .timed("user", || async { self.octocrab.current().user().await })
+            .await?;
+        Ok(user.login)
+    }
+
+    /// Spawn a background task to fetch the user's repositories progressively
+    ///
+    /// Repositories are shown page by page; setting `stop` ends paging early
+    /// and enhances only the repositories loaded so far. The returned handle
+    /// cancels a fetch that stopped making progress.
+    pub fn spawn_background_fetch(
+        client: GitHubClient,
         sender: mpsc::UnboundedSender<BackgroundMessage>,
-    ) {
+        stop: Arc<AtomicBool>,
+    ) -> JoinHandle<()> {
         tokio::spawn(async move {
-            // Phase 1: Fetch basic repository information quickly
-            let basic_result = client.list_basic_repositories().await;
+            let viewer = match client.listing_viewer().await {
+                Ok(viewer) => viewer,
+                Err(error) => {
+                    let error = format!("Failed to fetch repositories: {}", error);
+                    let _ = sender.send(BackgroundMessage::FetchError { error });
+                    return;
+                }
+            };
 
-            match basic_result {
-                Ok(basic_repositories) => {
-                    let total = basic_repositories.len();
+            // Phase 1: Page through basic repository information
+            let listing = RepositoryListing {
+                endpoint: "user/repos",
+                route: "/user/repos".to_string(),
+                filter: match viewer {
+                    Some(_) => ("affiliation", ALL_AFFILIATIONS),
+                    None => ("type", "owner"),
+                },
+                viewer,
+            };
+            Self::stream_repository_pages(
+                &client,
+                &sender,
+                &listing,
+                &stop,
+                "Failed to fetch repositories",
+            )
+            .await;
+        })
+    }
 
-                    // Send start message
-                    if sender
-                        .send(BackgroundMessage::FetchStarted { total })
-                        .is_err()
-                    {
-                        return; // Receiver dropped
-                    }
+    /// Send a basic repository list to the app, then enhance each repository
+    ///
+    /// Shared by the progressive fetches, which only differ in how they list
+    /// repositories and how a listing error reads.
+    async fn stream_repositories(
+        client: &GitHubClient,
+        sender: &mpsc::UnboundedSender<BackgroundMessage>,
+        basic_result: Result<Vec<AppRepository>, String>,
+        error_context: &str,
+    ) {
+        match basic_result {
+            Ok(basic_repositories) => {
+                let total = basic_repositories.len();
 
-                    // Send each basic repository immediately
-                    for (i, repository) in basic_repositories.iter().enumerate() {
-                        if sender
-                            .send(BackgroundMessage::RepositoryFetched {
-                                repository: repository.clone(),
-                                current: i + 1,
-                                total,
-                            })
-                            .is_err()
-                        {
-                            return; // Receiver dropped
-                        }
-                    }
+                // Send start message
+                if sender
+                    .send(BackgroundMessage::FetchStarted { total })
+                    .is_err()
+                {
+                    return; // Receiver dropped
+                }
 
-                    // Send initial completion to show the basic list
+                // Send each basic repository immediately
+                for (i, repository) in basic_repositories.iter().enumerate() {
                     if sender
-                        .send(BackgroundMessage::FetchCompleted {
-                            repositories: basic_repositories.clone(),
+                        .send(BackgroundMessage::RepositoryFetched {
+                            repository: repository.clone(),
+                            current: i + 1,
+                            total,
                         })
                         .is_err()
                     {
                         return; // Receiver dropped
                     }
+                }
 
-                    // Phase 2: Enhance repositories with additional data
-                    let mut enhanced_repositories = basic_repositories.clone();
+                Self::enhance_repositories(client, sender, basic_repositories).await;
+            }
+            Err(e) => {
+                let error_msg = format!("{}: {}", error_context, e);
+                let _ = sender.send(BackgroundMessage::FetchError { error: error_msg });
+            }
+        }
+    }
 
-                    // Update the UI to show we're enhancing repositories
-                    if sender
-                        .send(BackgroundMessage::EnhancementStarted { total })
-                        .is_err()
-                    {
-                        return; // Receiver dropped
-                    }
+    /// Show the complete basic list, then enhance each repository with additional details
+    async fn enhance_repositories(
+        client: &GitHubClient,
+        sender: &mpsc::UnboundedSender<BackgroundMessage>,
+        basic_repositories: Vec<AppRepository>,
+    ) {
+        let total = basic_repositories.len();
 
-                    // Enhance each repository with additional details
-                    for (i, repo) in enhanced_repositories.iter_mut().enumerate() {
-                        // Enhance this repository with additional data
-                        if let Err(e) = client.enhance_repository(repo).await {
-                            eprintln!("Error enhancing repository {}: {}", repo.name, e);
-                        }
+        // Send initial completion to show the basic list
+        if sender
+            .send(BackgroundMessage::FetchCompleted {
+                repositories: basic_repositories.clone(),
+            })
+            .is_err()
+        {
+            return; // Receiver dropped
+        }
 
-                        // Send update for this enhanced repository
-                        if sender
-                            .send(BackgroundMessage::RepositoryEnhanced {
-                                repository: repo.clone(),
-                                current: i + 1,
-                                total,
-                            })
-                            .is_err()
-                        {
-                            return; // Receiver dropped
-                        }
+        // Phase 2: Enhance repositories with additional data
+        let mut enhanced_repositories = basic_repositories.clone();
 
-                        // Small delay to allow UI updates and prevent API rate limiting
-                        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-                    }
+        // Update the UI to show we're enhancing repositories
+        if sender
+            .send(BackgroundMessage::EnhancementStarted { total })
+            .is_err()
+        {
+            return; // Receiver dropped
+        }
 
-                    // Send final completion message with all enhanced data
-                    let _ = sender.send(BackgroundMessage::EnhancementCompleted {
-                        repositories: enhanced_repositories,
-                    });
-                }
-                Err(e) => {
-                    let error_msg = format!("Failed to fetch repositories: {}", e);
-                    let _ = sender.send(BackgroundMessage::FetchError { error: error_msg });
-                }
+        // Start counting down from the quota GitHub reports (reading it is free)
+        if client.rate_limit_reserve > 0 {
+            let _ = client.rate_limit_status().await;
+        }
+
+        // With the GraphQL backend, most details arrive in a few batched queries
+        client.wait_while_held().await;
+        let prefetched = client.prefetch_details(&mut enhanced_repositories).await;
+
+        // Enhance each repository with additional details
+        for (i, repo) in enhanced_repositories.iter_mut().enumerate() {
+            client.wait_while_held().await;
+            if !client.wait_for_quota(sender, i + 1, total).await {
+                return; // Receiver dropped
+            }
+
+            // Enhance this repository, waiting out any rate limit
+            let repo_prefetched = prefetched.contains(&repo.full_name());
+            if !client
+                .enhance_repository_resuming(repo, repo_prefetched, sender, i + 1, total)
+                .await
+            {
+                return; // Receiver dropped
             }
+
+            // Send update for this enhanced repository
+            if sender
+                .send(BackgroundMessage::RepositoryEnhanced {
+                    repository: repo.clone(),
+                    current: i + 1,
+                    total,
+                })
+                .is_err()
+            {
+                return; // Receiver dropped
+            }
+
+            // Small delay to allow UI updates and prevent API rate limiting
+            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        }
+
+        // Send final completion message with all enhanced data
+        let _ = sender.send(BackgroundMessage::EnhancementCompleted {
+            repositories: enhanced_repositories,
         });
     }
 
-    /// Spawn a background task to fetch organization repositories progressively
-    pub fn spawn_background_fetch_organizations(
+    /// Spawn a background task to fetch the check runs for a pull request head
+    pub fn spawn_check_runs_fetch(
         client: GitHubClient,
         sender: mpsc::UnboundedSender<BackgroundMessage>,
+        owner: String,
+        repo: String,
+        pr_number: u32,
+        head_sha: String,
     ) {
         tokio::spawn(async move {
-            // Phase 1: Fetch basic organization repository information quickly
-            match client.list_organization_repositories().await {
-                Ok(basic_repositories) => {
-                    let total = basic_repositories.len();
+            let message = match client.fetch_check_runs(&owner, &repo, &head_sha).await {
+                Ok(check_runs) => BackgroundMessage::CheckRunsFetched {
+                    pr_number,
+                    check_runs,
+                },
+                Err(e) => BackgroundMessage::ActionError {
+                    error: format!("Failed to fetch checks for #{}: {}", pr_number, e),
+                },
+            };
+            let _ = sender.send(message);
+        });
+    }
 
-                    // Send start message
-                    if sender
-                        .send(BackgroundMessage::FetchStarted { total })
-                        .is_err()
-                    {
-                        return; // Receiver dropped
-                    }
+    /// Spawn a background task to re-request a single failed check run
+    pub fn spawn_check_run_rerequest(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        owner: String,
+        repo: String,
+        check_run_id: u64,
+    ) {
+        tokio::spawn(async move {
+            let message = match client
+                .rerequest_check_run(&owner, &repo, check_run_id)
+                .await
+            {
+                Ok(()) => BackgroundMessage::CheckRunRerequested { check_run_id },
+                Err(e) => BackgroundMessage::CheckRunRerequestFailed {
+                    check_run_id,
+                    error: format!("Failed to re-run check: {}", e),
+                },
+            };
+            let _ = sender.send(message);
+        });
+    }
 
-                    // Send each basic repository immediately
-                    for (i, repository) in basic_repositories.iter().enumerate() {
-                        if sender
-                            .send(BackgroundMessage::RepositoryFetched {
-                                repository: repository.clone(),
-                                current: i + 1,
-                                total,
-                            })
-                            .is_err()
-                        {
-                            return; // Receiver dropped
-                        }
-                    }
+    /// Spawn a background task submitting a pull request review
+    pub fn spawn_pull_request_review(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        owner: String,
+        repo: String,
+        number: u32,
+        event: ReviewEvent,
+        body: String,
+    ) {
+        tokio::spawn(async move {
+            let message = match client
+                .submit_pull_request_review(&owner, &repo, number, event, &body)
+                .await
+            {
+                Ok(()) => BackgroundMessage::PullRequestReviewed {
+                    pr_number: number,
+                    event,
+                },
+                Err(e) => BackgroundMessage::ActionError {
+                    error: format!("Failed to review #{}: {}", number, e),
+                },
+            };
+            let _ = sender.send(message);
+        });
+    }
 
-                    // Send initial completion to show the basic list
-                    if sender
-                        .send(BackgroundMessage::FetchCompleted {
-                            repositories: basic_repositories.clone(),
-                        })
-                        .is_err()
-                    {
-                        return; // Receiver dropped
-                    }
+    /// GraphQL query for the details of `repositories`, aliased `r0`, `r1`, ...
+    fn details_query(&self, repositories: &[AppRepository]) -> String {
+        let reviews = if self.collects(Collector::Reviews) {
+            format!(" {}", REVIEW_STATE_FIELDS)
+        } else {
+            String::new()
+        };
+        let check_suites = if self.collects(Collector::Workflows) {
+            format!(
+                " checkSuites(first: {}) {{ nodes {{ status conclusion branch {{ name }} \
+                 workflowRun {{ databaseId event createdAt updatedAt workflow {{ name }} }} }} }}",
+                GRAPHQL_CHECK_SUITES_PER_COMMIT
+            )
+        } else {
+            String::new()
+        };
+        let mut fields = format!(
+            "pullRequests(states: OPEN, first: {}, orderBy: {{field: CREATED_AT, direction: DESC}}) \
+             {{ totalCount nodes {{ number title createdAt updatedAt isDraft url body headRefOid \
+             baseRefName labels(first: 20) {{ nodes {{ name }} }} milestone {{ title }} \
+             author {{ login }}{} }} }} \
+             defaultBranchRef {{ target {{ ... on Commit {{ history(first: {}) \
+             {{ nodes {{ authoredDate message{} }} }} }} }} }}",
+            self.page_sizes.pull_requests, reviews, self.page_sizes.commits, check_suites
+        );
+        if self.collects(Collector::Releases) {
+            fields.push_str(" latestRelease { name tagName publishedAt createdAt }");
+        }
 
-                    // Phase 2: Enhance repositories with additional data
-                    let mut enhanced_repositories = basic_repositories.clone();
+        // Owner and repository names are quoted like JSON strings
+        let quote = |value: &str| serde_json::to_string(value).unwrap_or_default();
+        let aliases: Vec<String> = repositories
+            .iter()
+            .enumerate()
+            .map(|(index, repo)| {
+                format!(
+                    "r{}: repository(owner: {}, name: {}) {{ ...details }}",
+                    index,
+                    quote(&repo.owner),
+                    quote(&repo.name)
+                )
+            })
+            .collect();
+        format!(
+            "query {{ {} }} fragment details on Repository {{ {} }}",
+            aliases.join(" "),
+            fields
+        )
+    }
 
-                    // Update the UI to show we're enhancing repositories
-                    if sender
-                        .send(BackgroundMessage::EnhancementStarted { total })
-                        .is_err()
-                    {
-                        return; // Receiver dropped
-                    }
+    /// Fill in pull requests, commits, workflow runs and latest releases
+    /// with one GraphQL query per `GRAPHQL_BATCH_SIZE` repositories
+    ///
+    /// Only runs with the GraphQL backend. Returns the full names of the
+    /// repositories filled in; the others, e.g. of a failed batch, are
+    /// enhanced through REST as usual.
+    async fn prefetch_details(&self, repositories: &mut [AppRepository]) -> HashSet<String> {
+        let mut prefetched = HashSet::new();
+        if self.backend != ApiBackend::Graphql {
+            return prefetched;
+        }
+        for batch in repositories.chunks_mut(GRAPHQL_BATCH_SIZE) {
+            let query = serde_json::json!({ "query": self.details_query(batch) });
+            let response: GraphQlResponse<HashMap<String, Option<GraphRepository>>> = match self
+                .timed("graphql", || self.octocrab.graphql(&query))
+                .await
+            {
+                Ok(response) => response,
+                Err(e) => {
+                    eprintln!("GraphQL details query failed, using REST: {}", e);
+                    continue;
+                }
+            };
 
-                    // Enhance each repository with additional details
-                    for (i, repo) in enhanced_repositories.iter_mut().enumerate() {
-                        // Enhance this repository with additional data
-                        if let Err(e) = client.enhance_repository(repo).await {
-                            eprintln!("Error enhancing repository {}: {}", repo.name, e);
-                        }
+            // A repository that can't be read comes back as null, with an error
+            let mut data = response.data.unwrap_or_default();
+            for (index, repo) in batch.iter_mut().enumerate() {
+                if let Some(Some(details)) = data.remove(&format!("r{}", index)) {
+                    self.apply_details(repo, details);
+                    prefetched.insert(repo.full_name());
+                }
+            }
+        }
+        prefetched
+    }
 
-                        // Send update for this enhanced repository
-                        if sender
-                            .send(BackgroundMessage::RepositoryEnhanced {
-                                repository: repo.clone(),
-                                current: i + 1,
-                                total,
-                            })
-                            .is_err()
-                        {
-                            return; // Receiver dropped
-                        }
+    /// Copy a repository's GraphQL details into it
+    fn apply_details(&self, repo: &mut AppRepository, details: GraphRepository) {
+        let pulls = details.pull_requests;
+        repo.open_pull_request_total = (pulls.nodes.len()
+            >= usize::from(self.page_sizes.pull_requests))
+        .then_some(pulls.total_count);
+        repo.open_pull_requests = pulls
+            .nodes
+            .into_iter()
+            .map(|pr| {
+                let mut app_pr = AppPullRequest {
+                    number: pr.number,
+                    title: pr.title,
+                    state: PullRequestState::Open,
+                    created_at: system_time(pr.created_at),
+                    updated_at: system_time(pr.updated_at),
+                    author: pr
+                        .author
+                        .map_or_else(|| "unknown".to_string(), |author| author.login),
+                    html_url: pr.url,
+                    draft: pr.is_draft,
+                    approvals: 0,
+                    changes_requested: 0,
+                    head_sha: pr.head_ref_oid,
+                    base_branch: pr.base_ref_name,
+                    linked_issues: AppPullRequest::parse_linked_issues(&pr.body),
+                    tasks: TaskProgress::parse(&pr.body),
+                    feedback_waiting_since: None,
+                    review_decision: None,
+                    checks: None,
+                    labels: pr
+                        .labels
+                        .nodes
+                        .into_iter()
+                        .map(|label| label.name)
+                        .collect(),
+                    milestone: pr.milestone.map(|milestone| milestone.title),
+                };
+                pr.review_state.apply(&mut app_pr);
+                app_pr
+            })
+            .collect();
 
-                        // Small delay to allow UI updates and prevent API rate limiting
-                        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-                    }
+        // Only repositories without commits have no default branch
+        repo.empty = details.default_branch_ref.is_none();
+        let commits = details
+            .default_branch_ref
+            .and_then(|branch| branch.target.history)
+            .map(|history| history.nodes)
+            .unwrap_or_default();
+        if let Some(latest) = commits.first() {
+            repo.latest_commit_at = Some(system_time(latest.authored_date));
+        }
+        repo.recent_commit_subjects = commits
+            .iter()
+            .map(|commit| commit.message.lines().next().unwrap_or("").to_string())
+            .collect();
 
-                    // Send final completion message with all enhanced data
-                    let _ = sender.send(BackgroundMessage::EnhancementCompleted {
-                        repositories: enhanced_repositories,
-                    });
-                }
-                Err(e) => {
-                    let error_msg = format!("Failed to fetch organization repositories: {}", e);
-                    let _ = sender.send(BackgroundMessage::FetchError { error: error_msg });
-                }
-            }
-        });
+        // GraphQL has no list of Actions runs; they come from the check
+        // suites of the default branch's recent commits
+        if self.collects(Collector::Workflows) {
+            let mut runs: Vec<crate::models::WorkflowRun> = commits
+                .into_iter()
+                .flat_map(|commit| commit.check_suites.map(|suites| suites.nodes))
+                .flatten()
+                .filter_map(Self::graph_workflow_run)
+                .collect();
+            runs.sort_by_key(|run| std::cmp::Reverse(run.created_at));
+            runs.truncate(usize::from(self.page_sizes.workflow_runs));
+            repo.workflow_health = crate::models::WorkflowHealth::from_workflow_runs(&runs);
+            repo.latest_workflow = runs.first().cloned();
+            repo.recent_workflows = runs;
+        }
+
+        if self.collects(Collector::Releases) {
+            repo.latest_release = details.latest_release.map(|release| Release {
+                name: release
+                    .name
+                    .filter(|name| !name.trim().is_empty())
+                    .unwrap_or_else(|| release.tag_name.clone()),
+                tag: release.tag_name,
+                published_at: system_time(release.published_at.unwrap_or(release.created_at)),
+            });
+        }
+    }
+
+    /// The workflow run behind a check suite, if GitHub Actions created it
+    fn graph_workflow_run(suite: GraphCheckSuite) -> Option<crate::models::WorkflowRun> {
+        let run = suite.workflow_run?;
+        let conclusion = suite.conclusion.map(|conclusion| conclusion.to_lowercase());
+        let status = if suite.status == "COMPLETED" {
+            AppCheckRun::status_from_conclusion(conclusion.as_deref())
+        } else {
+            WorkflowStatus::InProgress
+        };
+        let created_at = system_time(run.created_at);
+        let updated_at = system_time(run.updated_at);
+        let duration = (status != WorkflowStatus::InProgress)
+            .then(|| updated_at.duration_since(created_at).ok())
+            .flatten();
+
+        Some(crate::models::WorkflowRun {
+            id: run.database_id,
+            name: run.workflow.name,
+            status,
+            created_at,
+            updated_at,
+            started_at: None,
+            duration,
+            trigger: RunTrigger {
+                branch: suite.branch.map(|branch| branch.name),
+                event: run.event,
+                actor: None,
+                from_fork: false,
+            },
+        })
     }
 
     /// Enhance a repository with additional data like PRs and commits
     pub async fn enhance_repository(&self, repo: &mut AppRepository) -> Result<(), String> {
+        self.enhance_repository_details(repo, false).await
+    }
+
+    /// Enhance a repository, skipping what a GraphQL batch already filled in
+    ///
+    /// `prefetched` repositories have their pull requests (with reviews),
+    /// commits, workflow runs and latest release, so only the remaining
+    /// collectors make REST requests.
+    async fn enhance_repository_details(
+        &self,
+        repo: &mut AppRepository,
+        prefetched: bool,
+    ) -> Result<(), String> {
+        // Fetch latest commit data first: an empty repository has no pull
+        // requests, workflow runs, deployments or releases to look for
+        if !prefetched {
+            match self.fetch_recent_commits(&repo.owner, &repo.name).await {
+                Ok(Some((latest_commit_at, subjects))) => {
+                    repo.empty = false;
+                    if latest_commit_at.is_some() {
+                        repo.latest_commit_at = latest_commit_at;
+                    }
+                    repo.recent_commit_subjects = subjects;
+                }
+                Ok(None) => repo.empty = true,
+                Err(e) => eprintln!(
+                    "Failed to fetch latest commit for {}/{}: {}",
+                    repo.owner, repo.name, e
+                ),
+            }
+        }
+
         // Fetch additional data
-        match self.fetch_open_pull_requests(&repo.owner, &repo.name).await {
-            Ok(open_prs) => repo.open_pull_requests = open_prs,
-            Err(e) => eprintln!(
-                "Failed to fetch PRs for {}/{}: {}",
-                repo.owner, repo.name, e
-            ),
+        if !prefetched && !repo.empty {
+            let open_prs = self
+                .fetch_open_pull_requests(&repo.owner, &repo.name)
+                .await
+                .map_err(|e| e.to_string());
+            match open_prs {
+                Ok(open_prs) => {
+                    // A full page may be cut off; the search API knows the real total
+                    repo.open_pull_request_total = None;
+                    if open_prs.len() >= usize::from(self.page_sizes.pull_requests) {
+                        match self.count_open_pull_requests(&repo.owner, &repo.name).await {
+                            Ok(total) => repo.open_pull_request_total = Some(total),
+                            Err(e) => eprintln!(
+                                "Failed to count PRs for {}/{}: {}",
+                                repo.owner, repo.name, e
+                            ),
+                        }
+                    }
+                    repo.open_pull_requests = open_prs;
+                }
+                Err(e) => eprintln!(
+                    "Failed to fetch PRs for {}/{}: {}",
+                    repo.owner, repo.name, e
+                ),
+            }
         }
 
-        // Fetch latest commit data
-        match self.fetch_latest_commit(&repo.owner, &repo.name).await {
-            Ok(Some(commit_time)) => repo.latest_commit_at = Some(commit_time),
-            Ok(None) => {} // No commits found
-            Err(e) => eprintln!(
-                "Failed to fetch latest commit for {}/{}: {}",
-                repo.owner, repo.name, e
-            ),
+        // Find reviewer feedback still waiting for the PR author
+        if self.collects(Collector::Reviews) && !repo.open_pull_requests.is_empty() {
+            match self.fetch_review_comments(&repo.owner, &repo.name).await {
+                Ok(comments) => {
+                    for pr in &mut repo.open_pull_requests {
+                        pr.feedback_waiting_since = pr.unanswered_feedback_since(&comments);
+                    }
+                }
+                Err(e) => eprintln!(
+                    "Failed to fetch review comments for {}/{}: {}",
+                    repo.owner, repo.name, e
+                ),
+            }
+
+            // Approvals and change requests, to tell approved PRs from blocked
+            // ones (a GraphQL batch already counted them). Several PRs are
+            // read in one GraphQL query, falling back to REST per PR
+            let mut batched = prefetched;
+            if !prefetched && repo.open_pull_requests.len() > 1 {
+                let numbers: Vec<u32> =
+                    repo.open_pull_requests.iter().map(|pr| pr.number).collect();
+                match self
+                    .fetch_review_states(&repo.owner, &repo.name, &numbers)
+                    .await
+                {
+                    Ok(mut states) => {
+                        for pr in &mut repo.open_pull_requests {
+                            if let Some(state) = states.remove(&pr.number) {
+                                state.apply(pr);
+                            }
+                        }
+                        batched = true;
+                    }
+                    Err(e) => eprintln!(
+                        "GraphQL review states query failed for {}/{}, using REST: {}",
+                        repo.owner, repo.name, e
+                    ),
+                }
+            }
+            let pull_requests = repo.open_pull_requests.iter_mut();
+            for pr in pull_requests.filter(|_| !batched) {
+                match self
+                    .fetch_pull_request_reviews(&repo.owner, &repo.name, pr.number)
+                    .await
+                {
+                    Ok(reviews) => pr.tally_reviews(&reviews),
+                    Err(e) => eprintln!(
+                        "Failed to fetch reviews for {}/{}#{}: {}",
+                        repo.owner, repo.name, pr.number, e
+                    ),
+                }
+            }
+        }
+
+        // Check for Git LFS usage
+        if self.collects(Collector::Lfs) && !repo.empty {
+            match self.fetch_uses_lfs(&repo.owner, &repo.name).await {
+                Ok(uses_lfs) => repo.uses_lfs = Some(uses_lfs),
+                Err(e) => eprintln!(
+                    "Failed to check LFS usage for {}/{}: {}",
+                    repo.owner, repo.name, e
+                ),
+            }
         }
 
         // Fetch workflow runs
-        match self.fetch_workflow_runs(&repo.owner, &repo.name).await {
-            Ok(workflows) => {
-                repo.recent_workflows = workflows.clone();
-                repo.workflow_health =
-                    crate::models::WorkflowHealth::from_workflow_runs(&workflows);
-                repo.latest_workflow = workflows.first().cloned();
-            }
-            Err(e) => eprintln!(
-                "Failed to fetch workflows for {}/{}: {}",
-                repo.owner, repo.name, e
-            ),
+        if self.collects(Collector::Workflows) && !prefetched && !repo.empty {
+            match self.fetch_workflow_runs(&repo.owner, &repo.name).await {
+                Ok(workflows) => {
+                    repo.recent_workflows = workflows.clone();
+                    repo.workflow_health =
+                        crate::models::WorkflowHealth::from_workflow_runs(&workflows);
+                    repo.latest_workflow = workflows.first().cloned();
+                }
+                Err(e) => eprintln!(
+                    "Failed to fetch workflows for {}/{}: {}",
+                    repo.owner, repo.name, e
+                ),
+            }
         }
 
-        // Determine overall repository status based on commit activity
-        repo.status = self.determine_repository_status(&repo);
+        // Fetch Pages and deployment environment status
+        if self.collects(Collector::Deployments) && !repo.empty {
+            match self
+                .fetch_deploy_status(&repo.owner, &repo.name, repo.has_pages)
+                .await
+            {
+                Ok(deploy_status) => repo.deploy_status = deploy_status,
+                Err(e) => eprintln!(
+                    "Failed to fetch deployments for {}/{}: {}",
+                    repo.owner, repo.name, e
+                ),
+            }
+        }
 
-        Ok(())
-    }
+        // Fetch the latest release
+        if self.collects(Collector::Releases) && !prefetched && !repo.empty {
+            match self.fetch_latest_release(&repo.owner, &repo.name).await {
+                Ok(release) => repo.latest_release = release,
+                Err(e) => eprintln!(
+                    "Failed to fetch latest release for {}/{}: {}",
+                    repo.owner, repo.name, e
+                ),
+            }
+        }
 
-    /// List repositories from organizations the user belongs to
-    pub async fn list_organization_repositories(&self) -> Result<Vec<AppRepository>, String> {
-        let mut repositories = Vec::new();
+        // Count open code scanning alerts by severity
+        if self.collects(Collector::CodeScanning) && !repo.empty {
+            match self
+                .fetch_code_scanning_alerts(&repo.owner, &repo.name)
+                .await
+            {
+                Ok(alerts) => repo.code_scanning = alerts,
+                Err(e) => eprintln!(
+                    "Failed to fetch code scanning alerts for {}/{}: {}",
+                    repo.owner, repo.name, e
+                ),
+            }
+        }
+
+        // Audit the default branch's protection
+        if self.collects(Collector::BranchProtection)
+            && !repo.empty
+            && !repo.default_branch.is_empty()
+        {
+            match self.fetch_branch_protection(repo).await {
+                Ok(status) => repo.branch_protection = status,
+                Err(e) => eprintln!(
+                    "Failed to fetch branch protection for {}/{}: {}",
+                    repo.owner, repo.name, e
+                ),
+            }
+        }
 
-        // First, get the organizations the user belongs to
-        let user_orgs = self.get_user_organizations().await?;
+        // Record which community health files are present
+        if self.collects(Collector::Community) && !repo.fork {
+            match self.fetch_community_profile(&repo.owner, &repo.name).await {
+                Ok(profile) => repo.community = profile,
+                Err(e) => eprintln!(
+                    "Failed to fetch the community profile for {}/{}: {}",
+                    repo.owner, repo.name, e
+                ),
+            }
+        }
 
-        // For each organization, fetch its repositories
-        for org_name in user_orgs {
-            match self.list_repositories_for_organization(&org_name).await {
-                Ok(mut org_repos) => repositories.append(&mut org_repos),
-                Err(e) => eprintln!("Failed to fetch repositories for org {}: {}", org_name, e),
+        // Measure how concentrated recent commits are on one contributor
+        if self.collects(Collector::Contributors) && !repo.empty {
+            match self.fetch_bus_factor(&repo.owner, &repo.name).await {
+                Ok(bus_factor) => repo.bus_factor = bus_factor,
+                Err(e) => eprintln!(
+                    "Failed to fetch contributor statistics for {}/{}: {}",
+                    repo.owner, repo.name, e
+                ),
             }
         }
 
-        Ok(repositories)
-    }
+        // Weekly commit counts, which refine the activity status
+        if self.collects(Collector::CommitActivity) && !repo.empty {
+            match self.fetch_commit_activity(&repo.owner, &repo.name).await {
+                // Counts still being computed keep last refresh's
+                Ok(Some(activity)) => repo.commit_activity = Some(activity),
+                Ok(None) => {}
+                Err(e) => eprintln!(
+                    "Failed to fetch commit activity for {}/{}: {}",
+                    repo.owner, repo.name, e
+                ),
+            }
+        }
 
-    /// Get list of organizations the user belongs to
-    pub async fn get_user_organizations(&self) -> Result<Vec<String>, String> {
-        // Get all repositories the user has access to and extract organization names
-        // This includes organizations where the user is a member
-        let repos_page = self
-            .octocrab
-            .current()
-            .list_repos_for_authenticated_user()
-            .type_("all") // All repositories (owned, member, collaborator)
-            .sort("updated")
-            .per_page(100)
-            .send()
-            .await
-            .map_err(|e| format!("GitHub API error: {}", e))?;
+        // Look for leaked credentials
+        if self.collects(Collector::SecretScanning) && !repo.empty {
+            match self
+                .fetch_secret_scanning_alerts(&repo.owner, &repo.name)
+                .await
+            {
+                Ok(alerts) => repo.secret_scanning_alerts = alerts,
+                Err(e) => eprintln!(
+                    "Failed to fetch secret scanning alerts for {}/{}: {}",
+                    repo.owner, repo.name, e
+                ),
+            }
+        }
 
-        // Get current user to exclude their personal repositories
-        let user = self
-            .octocrab
-            .current()
-            .user()
-            .await
-            .map_err(|e| format!("Failed to get current user: {}", e))?;
+        // Count open issues, unanswered ones and the oldest one's age
+        if self.collects(Collector::Issues) {
+            let open = repo.open_issue_count();
+            if open == 0 {
+                repo.issue_metrics = Some(IssueMetrics::from_open_issues(0, &[]));
+            } else {
+                match self.fetch_oldest_open_issues(&repo.owner, &repo.name).await {
+                    Ok(oldest) => {
+                        repo.issue_metrics = Some(IssueMetrics::from_open_issues(open, &oldest))
+                    }
+                    Err(e) => eprintln!(
+                        "Failed to fetch open issues for {}/{}: {}",
+                        repo.owner, repo.name, e
+                    ),
+                }
+            }
+        }
+
+        // Judge planning and docs repositories by their issues
+        if repo.is_issues_only(SystemTime::now()) {
+            match self
+                .fetch_issue_activity(&repo.owner, &repo.name, ISSUE_HEALTH_WEEKS)
+                .await
+            {
+                Ok(issues) => {
+                    repo.issue_health = Some(IssueHealth::from_activity(&issues, SystemTime::now()))
+                }
+                Err(e) => eprintln!(
+                    "Failed to fetch issue activity for {}/{}: {}",
+                    repo.owner, repo.name, e
+                ),
+            }
+        } else {
+            repo.issue_health = None;
+        }
+
+        // Determine overall repository status based on commit activity
+        repo.status = self.determine_repository_status(repo);
+
+        Ok(())
+    }
+
+    /// Get list of organizations the user belongs to
+    pub async fn get_user_organizations(&self) -> Result<Vec<String>, String> {
+        // Get all repositories the user has access to and extract organization names
+        // This includes organizations where the user is a member. The current
+        // user (to exclude their personal repositories) is fetched alongside.
+        let current = self.octocrab.current();
+        let (repos_page, user) = tokio::join!(
+            self.timed("user/repos", || current
+                .list_repos_for_authenticated_user()
+                .type_("all") // All repositories (owned, member, collaborator)
+                .sort("updated")
+                .per_page(100)
+                .send(),),
+            self.timed("user", || current.user()),
+        );
+        let repos_page = repos_page.map_err(|e| format!("GitHub API error: {}", e))?;
+        let user = user.map_err(|e| format!("Failed to get current user: {}", e))?;
 
         let user_login = user.login;
         let mut organizations = std::collections::HashSet::new();
@@ -570,13 +3683,15 @@ impl GitHubClient {
 
         // Get repositories for the authenticated user that belong to this specific org
         let repos_page = self
-            .octocrab
-            .current()
-            .list_repos_for_authenticated_user()
-            .type_("all") // All repositories (not just owned)
-            .sort("updated")
-            .per_page(100)
-            .send()
+            .timed("user/repos", || {
+                self.octocrab
+                    .current()
+                    .list_repos_for_authenticated_user()
+                    .type_("all") // All repositories (not just owned)
+                    .sort("updated")
+                    .per_page(100)
+                    .send()
+            })
             .await
             .map_err(|e| format!("GitHub API error: {}", e))?;
 
@@ -594,123 +3709,787 @@ impl GitHubClient {
                 continue;
             }
 
-            let mut app_repo = AppRepository::new(repo.name.clone(), owner.clone());
+            repositories.push(Self::basic_repository(repo)?);
+        }
 
-            // Set basic repository information
-            app_repo.html_url = repo.html_url.map(|url| url.to_string()).unwrap_or_default();
-            app_repo.description = repo.description;
-            app_repo.language = repo
-                .language
-                .and_then(|lang| lang.as_str().map(|s| s.to_string()));
-            app_repo.stars = repo.stargazers_count.unwrap_or(0) as u32;
-            app_repo.last_updated = SystemTime::now();
+        Ok(repositories)
+    }
 
-            // Set default status
-            app_repo.status = RepositoryStatus::Unknown;
+    /// List every repository the user can access: their own and their organizations'
+    pub async fn list_all_repositories(&self) -> Result<Vec<AppRepository>, String> {
+        let repos_page = self
+            .timed("user/repos", || {
+                self.octocrab
+                    .current()
+                    .list_repos_for_authenticated_user()
+                    .type_("all") // Owned, member and collaborator repositories
+                    .sort("updated")
+                    .per_page(100)
+                    .send()
+            })
+            .await
+            .map_err(|e| format!("GitHub API error: {}", e))?;
 
-            repositories.push(app_repo);
-        }
+        repos_page
+            .items
+            .into_iter()
+            .map(Self::basic_repository)
+            .collect()
+    }
 
-        Ok(repositories)
+    /// Spawn a background task to fetch every accessible repository progressively
+    pub fn spawn_background_fetch_all(
+        client: GitHubClient,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+    ) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let basic_result = client.list_all_repositories().await;
+            Self::stream_repositories(
+                &client,
+                &sender,
+                basic_result,
+                "Failed to fetch repositories",
+            )
+            .await;
+        })
+    }
+
+    /// Page through a repository listing, sending each repository as it arrives
+    ///
+    /// The first page shows up before the rest are requested. Until the last
+    /// page arrives, the total is estimated from the `Link` header's last
+    /// page number. Once `stop` is set, no further pages are requested.
+    /// Returns `Ok(None)` if the receiver was dropped.
+    async fn page_repositories(
+        &self,
+        listing: &RepositoryListing,
+        sender: &mpsc::UnboundedSender<BackgroundMessage>,
+        stop: &AtomicBool,
+    ) -> Result<Option<Vec<AppRepository>>, octocrab::Error> {
+        let per_page = REPOSITORY_PAGE_SIZE.to_string();
+        let mut page: Page<Repository> = self
+            .timed(listing.endpoint, || async {
+                self.octocrab
+                    .get(
+                        &listing.route,
+                        Some(&[
+                            listing.filter,
+                            ("sort", "updated"),
+                            ("per_page", per_page.as_str()),
+                        ]),
+                    )
+                    .await
+            })
+            .await?;
+        let pages = page.number_of_pages().unwrap_or(1) as usize;
+        let mut total = pages * REPOSITORY_PAGE_SIZE as usize;
+
+        let mut repositories = Vec::new();
+        loop {
+            let items = page.take_items();
+            if page.next.is_none() {
+                total = repositories.len() + items.len();
+            }
+            if repositories.is_empty()
+                && sender
+                    .send(BackgroundMessage::FetchStarted { total })
+                    .is_err()
+            {
+                return Ok(None); // Receiver dropped
+            }
+
+            for repo in items {
+                let affiliation = Self::affiliation(&repo, listing.viewer.as_deref());
+                let Ok(mut repository) = Self::basic_repository(repo) else {
+                    continue;
+                };
+                repository.affiliation = affiliation;
+                repositories.push(repository.clone());
+                if sender
+                    .send(BackgroundMessage::RepositoryFetched {
+                        repository,
+                        current: repositories.len(),
+                        total,
+                    })
+                    .is_err()
+                {
+                    return Ok(None); // Receiver dropped
+                }
+            }
+
+            // The last page needs no request for what follows it
+            if stop.load(Ordering::SeqCst) || page.next.is_none() {
+                break;
+            }
+            let next = self
+                .timed(listing.endpoint, || self.octocrab.get_page(&page.next))
+                .await?;
+            match next {
+                Some(next) => page = next,
+                None => break,
+            }
+        }
+        Ok(Some(repositories))
     }
 
     /// Spawn a background task to fetch repositories for a specific organization
+    ///
+    /// Repositories are shown page by page; setting `stop` ends paging early
+    /// and enhances only the repositories loaded so far.
     pub fn spawn_background_fetch_organization(
         client: GitHubClient,
         sender: mpsc::UnboundedSender<BackgroundMessage>,
         org_name: String,
-    ) {
+        stop: Arc<AtomicBool>,
+    ) -> JoinHandle<()> {
         tokio::spawn(async move {
-            // Phase 1: Fetch basic repository information for the specific organization
-            let basic_result = client.list_repositories_for_organization(&org_name).await;
+            // Phase 1: Page through the organization's repositories
+            let listing = RepositoryListing {
+                endpoint: "orgs/repos",
+                route: format!("/orgs/{}/repos", org_name),
+                filter: ("type", "all"),
+                viewer: None,
+            };
+            let error_context =
+                format!("Failed to fetch repositories for organization {}", org_name);
+            Self::stream_repository_pages(&client, &sender, &listing, &stop, &error_context).await;
+        })
+    }
 
-            match basic_result {
-                Ok(basic_repositories) => {
-                    let total = basic_repositories.len();
+    /// Page through a repository listing, then enhance the repositories loaded
+    async fn stream_repository_pages(
+        client: &GitHubClient,
+        sender: &mpsc::UnboundedSender<BackgroundMessage>,
+        listing: &RepositoryListing,
+        stop: &AtomicBool,
+        error_context: &str,
+    ) {
+        match client.page_repositories(listing, sender, stop).await {
+            Ok(Some(repositories)) => {
+                Self::enhance_repositories(client, sender, repositories).await;
+            }
+            Ok(None) => {} // Receiver dropped
+            Err(e) => {
+                let error = format!("{}: {}", error_context, e);
+                let _ = sender.send(BackgroundMessage::FetchError { error });
+            }
+        }
+    }
+}
 
-                    // Send start message
-                    if sender
-                        .send(BackgroundMessage::FetchStarted { total })
-                        .is_err()
-                    {
-                        return; // Receiver dropped
-                    }
+/// A paged list of repositories, e.g. `GET /orgs/{org}/repos`
+struct RepositoryListing {
+    /// Endpoint label recorded in the request metrics
+    endpoint: &'static str,
+    route: String,
+    /// The listing's `type` or `affiliation` filter, e.g. `("type", "owner")`
+    filter: (&'static str, &'static str),
+    /// Login affiliations are worked out against, if they are shown
+    viewer: Option<String>,
+}
 
-                    // Send each basic repository immediately
-                    for (i, repository) in basic_repositories.iter().enumerate() {
-                        if sender
-                            .send(BackgroundMessage::RepositoryFetched {
-                                repository: repository.clone(),
-                                current: i + 1,
-                                total,
-                            })
-                            .is_err()
-                        {
-                            return; // Receiver dropped
-                        }
-                    }
+/// Response of `GET /repos/{owner}/{repo}/releases/latest`
+#[derive(Debug, Deserialize)]
+struct ReleaseResponse {
+    tag_name: String,
+    name: Option<String>,
+    created_at: chrono::DateTime<chrono::Utc>,
+    published_at: Option<chrono::DateTime<chrono::Utc>>,
+}
 
-                    // Send initial completion to show the basic list
-                    if sender
-                        .send(BackgroundMessage::FetchCompleted {
-                            repositories: basic_repositories.clone(),
-                        })
-                        .is_err()
-                    {
-                        return; // Receiver dropped
-                    }
+/// An alert from `GET /repos/{owner}/{repo}/code-scanning/alerts`
+#[derive(Debug, Deserialize)]
+struct CodeScanningAlertResponse {
+    rule: CodeScanningRuleResponse,
+}
 
-                    // Phase 2: Enhance repositories with additional data
-                    let mut enhanced_repositories = basic_repositories.clone();
+/// The rule an alert was raised by
+#[derive(Debug, Deserialize)]
+struct CodeScanningRuleResponse {
+    /// `error`, `warning`, `note` or `none`
+    severity: Option<String>,
+    /// `critical`, `high`, `medium` or `low`, for security rules
+    security_severity_level: Option<String>,
+}
 
-                    // Update the UI to show we're enhancing repositories
-                    if sender
-                        .send(BackgroundMessage::EnhancementStarted { total })
-                        .is_err()
-                    {
-                        return; // Receiver dropped
-                    }
+/// Response of `GET /repos/{owner}/{repo}/community/profile`
+#[derive(Debug, Deserialize)]
+struct CommunityProfileResponse {
+    health_percentage: u32,
+    files: CommunityFilesResponse,
+}
 
-                    // Enhance each repository with additional details
-                    for (i, repo) in enhanced_repositories.iter_mut().enumerate() {
-                        // Enhance this repository with additional data
-                        if let Err(e) = client.enhance_repository(repo).await {
-                            eprintln!("Error enhancing repository {}: {}", repo.name, e);
-                        }
+/// Community health files found, each `null` when missing
+#[derive(Debug, Deserialize)]
+struct CommunityFilesResponse {
+    readme: Option<serde::de::IgnoredAny>,
+    license: Option<serde::de::IgnoredAny>,
+    contributing: Option<serde::de::IgnoredAny>,
+    code_of_conduct: Option<serde::de::IgnoredAny>,
+    code_of_conduct_file: Option<serde::de::IgnoredAny>,
+    issue_template: Option<serde::de::IgnoredAny>,
+}
 
-                        // Send update for this enhanced repository
-                        if sender
-                            .send(BackgroundMessage::RepositoryEnhanced {
-                                repository: repo.clone(),
-                                current: i + 1,
-                                total,
-                            })
-                            .is_err()
-                        {
-                            return; // Receiver dropped
-                        }
+/// Response of `GET /repos/{owner}/{repo}/stats/contributors`: each
+/// contributor's weekly commits, or an empty object while GitHub computes them
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ContributorStatsResponse {
+    Ready(Vec<ContributorResponse>),
+    Computing(serde::de::IgnoredAny),
+}
 
-                        // Small delay to allow UI updates and prevent API rate limiting
-                        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-                    }
+/// Response of `GET /repos/{owner}/{repo}/stats/commit_activity`: the last
+/// year's weeks, oldest first, or an empty object while GitHub computes them
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum CommitActivityResponse {
+    Ready(Vec<CommitWeekResponse>),
+    Computing(serde::de::IgnoredAny),
+}
 
-                    // Send final completion message with all enhanced data
-                    let _ = sender.send(BackgroundMessage::EnhancementCompleted {
-                        repositories: enhanced_repositories,
-                    });
-                }
-                Err(e) => {
-                    let error_msg = format!(
-                        "Failed to fetch repositories for organization {}: {}",
-                        org_name, e
-                    );
-                    let _ = sender.send(BackgroundMessage::FetchError { error: error_msg });
-                }
-            }
-        });
+/// Commits in one week of the commit activity
+#[derive(Debug, Deserialize)]
+struct CommitWeekResponse {
+    total: u32,
+}
+
+/// A contributor's commits, week by week
+#[derive(Debug, Deserialize)]
+struct ContributorResponse {
+    author: Option<UserResponse>,
+    weeks: Vec<ContributorWeekResponse>,
+}
+
+/// Commits in one week, starting at `w` (Unix seconds)
+#[derive(Debug, Deserialize)]
+struct ContributorWeekResponse {
+    w: u64,
+    c: u32,
+}
+
+/// Response of `GET /repos/{owner}/{repo}/pages`
+#[derive(Debug, Deserialize)]
+struct PagesResponse {
+    status: Option<String>,
+}
+
+/// Response of `GET /repos/{owner}/{repo}/environments`
+#[derive(Debug, Deserialize)]
+struct EnvironmentsResponse {
+    #[serde(default)]
+    environments: Vec<EnvironmentResponse>,
+}
+
+/// A single deployment environment
+#[derive(Debug, Deserialize)]
+struct EnvironmentResponse {
+    name: String,
+}
+
+/// A single deployment (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct DeploymentResponse {
+    id: u64,
+}
+
+/// A single deployment status (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct DeploymentStatusResponse {
+    state: String,
+}
+
+/// A page of workflow runs
+#[derive(Debug, Deserialize)]
+struct WorkflowRunsResponse {
+    workflow_runs: Vec<WorkflowRunResponse>,
+}
+
+/// A single workflow run (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct WorkflowRunResponse {
+    id: u64,
+    name: Option<String>,
+    status: Option<String>,
+    conclusion: Option<String>,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+    run_started_at: Option<chrono::DateTime<chrono::Utc>>,
+    head_branch: Option<String>,
+    #[serde(default)]
+    event: String,
+    actor: Option<UserResponse>,
+    triggering_actor: Option<UserResponse>,
+    head_repository: Option<RunRepositoryResponse>,
+    repository: Option<RunRepositoryResponse>,
+}
+
+/// The repository a workflow run belongs to or took its code from
+#[derive(Debug, Deserialize)]
+struct RunRepositoryResponse {
+    full_name: String,
+}
+
+/// An issue's open and close times (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct IssueActivityResponse {
+    created_at: chrono::DateTime<chrono::Utc>,
+    closed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default)]
+    comments: u32,
+    pull_request: Option<serde_json::Value>,
+}
+
+/// A file changed by a pull request (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct PullRequestFileResponse {
+    filename: String,
+    status: String,
+    additions: u32,
+    deletions: u32,
+}
+
+/// Response of `GET /repos/{owner}/{repo}/pulls/{number}` (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct PullMergeResponse {
+    mergeable: Option<bool>,
+    #[serde(default)]
+    mergeable_state: String,
+    base: PullBaseResponse,
+}
+
+/// The branch a pull request merges into
+#[derive(Debug, Deserialize)]
+struct PullBaseResponse {
+    #[serde(rename = "ref")]
+    branch: String,
+}
+
+/// Response of `GET /repos/{owner}/{repo}/branches/{branch}/protection`
+#[derive(Debug, Deserialize)]
+struct ProtectionResponse {
+    required_status_checks: Option<RequiredStatusChecksResponse>,
+    required_pull_request_reviews: Option<RequiredReviewsResponse>,
+}
+
+impl ProtectionResponse {
+    fn into_protection(self) -> BranchProtection {
+        let reviews = self.required_pull_request_reviews;
+        let checks = self.required_status_checks;
+        BranchProtection {
+            required_approvals: reviews
+                .as_ref()
+                .map_or(0, |reviews| reviews.required_approving_review_count),
+            code_owner_reviews: reviews
+                .as_ref()
+                .is_some_and(|reviews| reviews.require_code_owner_reviews),
+            strict: checks.as_ref().is_some_and(|checks| checks.strict),
+            required_checks: checks.map(|checks| checks.contexts).unwrap_or_default(),
+        }
+    }
+}
+
+/// Status checks a protected branch requires
+#[derive(Debug, Deserialize)]
+struct RequiredStatusChecksResponse {
+    #[serde(default)]
+    strict: bool,
+    #[serde(default)]
+    contexts: Vec<String>,
+}
+
+/// Reviews a protected branch requires
+#[derive(Debug, Deserialize)]
+struct RequiredReviewsResponse {
+    #[serde(default)]
+    required_approving_review_count: u32,
+    #[serde(default)]
+    require_code_owner_reviews: bool,
+}
+
+/// A single repository's default branch (None for empty repositories)
+#[derive(Debug, Deserialize)]
+struct DefaultBranchResponse {
+    default_branch: Option<String>,
+}
+
+/// A branch (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct BranchResponse {
+    protected: bool,
+}
+
+/// A single repository with its fork parent (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct ForkResponse {
+    default_branch: String,
+    parent: Option<ForkParentResponse>,
+}
+
+/// The repository a fork was created from
+#[derive(Debug, Deserialize)]
+struct ForkParentResponse {
+    full_name: String,
+    default_branch: String,
+}
+
+/// A comparison between two branches (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct CompareResponse {
+    ahead_by: u64,
+}
+
+/// Search results (only the total)
+#[derive(Debug, Deserialize)]
+struct SearchCountResponse {
+    total_count: usize,
+}
+
+/// Page views over the last 14 days (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct TrafficViewsResponse {
+    count: u64,
+}
+
+/// A pull request review comment (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct ReviewCommentResponse {
+    user: Option<UserResponse>,
+    created_at: chrono::DateTime<chrono::Utc>,
+    pull_request_url: String,
+}
+
+/// A pull request review (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct ReviewResponse {
+    user: Option<UserResponse>,
+    state: String,
+}
+
+/// Jobs of a workflow run (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct JobsResponse {
+    jobs: Vec<JobResponse>,
+}
+
+/// A single workflow job
+#[derive(Debug, Deserialize)]
+struct JobResponse {
+    id: u64,
+    name: String,
+    conclusion: Option<String>,
+    html_url: Option<String>,
+}
+
+/// A git tree listing (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct TreeResponse {
+    tree: Vec<TreeEntryResponse>,
+}
+
+/// A single git tree entry
+#[derive(Debug, Deserialize)]
+struct TreeEntryResponse {
+    path: String,
+    #[serde(rename = "type")]
+    kind: String,
+}
+
+/// A pending organization invitation (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct InvitationResponse {
+    login: Option<String>,
+    email: Option<String>,
+    role: String,
+    created_at: Option<chrono::DateTime<chrono::Utc>>,
+    inviter: Option<UserResponse>,
+}
+
+/// A user reference (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct UserResponse {
+    login: String,
+}
+
+/// A repository collaborator with their permissions
+#[derive(Debug, Deserialize)]
+struct CollaboratorResponse {
+    login: String,
+    #[serde(default)]
+    permissions: CollaboratorPermissions,
+}
+
+/// Permissions a collaborator has on a repository
+#[derive(Debug, Default, Deserialize)]
+struct CollaboratorPermissions {
+    #[serde(default)]
+    admin: bool,
+    #[serde(default)]
+    push: bool,
+}
+
+/// GraphQL response envelope
+#[derive(Debug, Deserialize)]
+struct GraphQlResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+/// An error reported in a GraphQL response
+#[derive(Debug, Deserialize)]
+struct GraphQlError {
+    message: String,
+}
+
+/// Data of the contribution calendar query
+#[derive(Debug, Deserialize)]
+struct ContributionsData {
+    viewer: ContributionsViewer,
+}
+
+/// The authenticated user with their contributions
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ContributionsViewer {
+    login: String,
+    contributions_collection: ContributionsCollection,
+}
+
+/// A user's contributions (only the calendar is queried)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ContributionsCollection {
+    contribution_calendar: ContributionCalendarResponse,
+}
+
+/// Contribution calendar of the last year
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ContributionCalendarResponse {
+    total_contributions: u32,
+    weeks: Vec<ContributionWeekResponse>,
+}
+
+/// One week column of the contribution calendar
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ContributionWeekResponse {
+    contribution_days: Vec<ContributionDayResponse>,
+}
+
+/// One day of the contribution calendar
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ContributionDayResponse {
+    date: chrono::NaiveDate,
+    contribution_count: u32,
+}
+
+/// A repository in a batched details query (only the fields we need)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphRepository {
+    pull_requests: GraphPullRequests,
+    default_branch_ref: Option<GraphBranchRef>,
+    #[serde(default)]
+    latest_release: Option<GraphRelease>,
+}
+
+/// Nodes of a GraphQL connection
+#[derive(Debug, Deserialize)]
+struct GraphNodes<T> {
+    nodes: Vec<T>,
+}
+
+/// A repository's open pull requests
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphPullRequests {
+    total_count: usize,
+    nodes: Vec<GraphPullRequest>,
+}
+
+/// An open pull request (only the fields we need)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphPullRequest {
+    number: u32,
+    title: String,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+    is_draft: bool,
+    url: String,
+    body: String,
+    head_ref_oid: String,
+    base_ref_name: String,
+    author: Option<GraphActor>,
+    labels: GraphNodes<GraphLabel>,
+    milestone: Option<GraphMilestone>,
+    /// Only read with the reviews collector
+    #[serde(flatten)]
+    review_state: GraphReviewState,
+}
+
+/// A label on a pull request
+#[derive(Debug, Deserialize)]
+struct GraphLabel {
+    name: String,
+}
+
+/// A pull request's milestone
+#[derive(Debug, Deserialize)]
+struct GraphMilestone {
+    title: String,
+}
+
+/// Review and check state of a pull request (null when not queried)
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphReviewState {
+    #[serde(default)]
+    review_decision: Option<String>,
+    /// Each reviewer's latest approval or change request
+    #[serde(default)]
+    latest_opinionated_reviews: Option<GraphNodes<GraphReview>>,
+    /// The head commit
+    #[serde(default)]
+    commits: Option<GraphNodes<GraphPullRequestCommit>>,
+}
+
+impl GraphReviewState {
+    /// Copy the votes, review decision and check rollup into a pull request
+    fn apply(self, pr: &mut AppPullRequest) {
+        if let Some(reviews) = self.latest_opinionated_reviews {
+            let reviews: Vec<SubmittedReview> = reviews
+                .nodes
+                .into_iter()
+                .filter_map(|review| {
+                    Some(SubmittedReview {
+                        author: review.author?.login,
+                        state: review.state,
+                    })
+                })
+                .collect();
+            pr.tally_reviews(&reviews);
+        }
+        pr.review_decision = self
+            .review_decision
+            .as_deref()
+            .and_then(ReviewDecision::from_graphql);
+        pr.checks = self
+            .commits
+            .and_then(|commits| commits.nodes.into_iter().next())
+            .and_then(|node| node.commit.status_check_rollup)
+            .map(|rollup| WorkflowStatus::from_check_rollup(&rollup.state));
     }
 }
 
+/// A commit of a pull request
+#[derive(Debug, Deserialize)]
+struct GraphPullRequestCommit {
+    commit: GraphRollupCommit,
+}
+
+/// A commit with its checks rolled up (null without any checks)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphRollupCommit {
+    status_check_rollup: Option<GraphCheckRollup>,
+}
+
+/// Combined state of a commit's checks and statuses
+#[derive(Debug, Deserialize)]
+struct GraphCheckRollup {
+    state: String,
+}
+
+/// Data of a review states query: the repository's pull requests aliased
+/// `p<number>` (null for numbers that can't be read)
+#[derive(Debug, Deserialize)]
+struct ReviewStatesData {
+    repository: Option<HashMap<String, Option<GraphReviewState>>>,
+}
+
+/// A user or bot (deleted accounts are null)
+#[derive(Debug, Deserialize)]
+struct GraphActor {
+    login: String,
+}
+
+/// A pull request review
+#[derive(Debug, Deserialize)]
+struct GraphReview {
+    state: String,
+    author: Option<GraphActor>,
+}
+
+/// The default branch, whose target is its head commit
+#[derive(Debug, Deserialize)]
+struct GraphBranchRef {
+    target: GraphBranchTarget,
+}
+
+/// Head of the default branch (history is null for non-commit targets)
+#[derive(Debug, Deserialize)]
+struct GraphBranchTarget {
+    #[serde(default)]
+    history: Option<GraphNodes<GraphCommit>>,
+}
+
+/// A commit on the default branch
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphCommit {
+    authored_date: chrono::DateTime<chrono::Utc>,
+    message: String,
+    #[serde(default)]
+    check_suites: Option<GraphNodes<GraphCheckSuite>>,
+}
+
+/// A check suite of a commit (only the fields we need)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphCheckSuite {
+    status: String,
+    conclusion: Option<String>,
+    branch: Option<GraphBranch>,
+    /// Set when GitHub Actions created the suite
+    workflow_run: Option<GraphWorkflowRun>,
+}
+
+/// A branch name
+#[derive(Debug, Deserialize)]
+struct GraphBranch {
+    name: String,
+}
+
+/// An Actions workflow run (only the fields we need)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphWorkflowRun {
+    database_id: u64,
+    event: String,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+    workflow: GraphBranch,
+}
+
+/// A repository's latest release
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphRelease {
+    name: Option<String>,
+    tag_name: String,
+    published_at: Option<chrono::DateTime<chrono::Utc>>,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Convert an API timestamp to `SystemTime`
+fn system_time(dt: chrono::DateTime<chrono::Utc>) -> SystemTime {
+    SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(dt.timestamp().max(0) as u64)
+}
+
 /// Error type for GitHub API operations
 #[derive(Debug)]
 pub enum GitHubError {
@@ -718,10 +4497,12 @@ pub enum GitHubError {
     AuthenticationFailed,
     /// API rate limit exceeded
     RateLimitExceeded,
+    /// Too many requests in a short time; clears within seconds to minutes
+    SecondaryRateLimit,
+    /// GitHub or a gateway in front of it failed (5xx)
+    ServerError(String),
     /// Network error
     NetworkError(String),
-    /// Repository not found
-    RepositoryNotFound(String),
     /// General API error
     ApiError(String),
 }
@@ -731,8 +4512,9 @@ impl std::fmt::Display for GitHubError {
         match self {
             GitHubError::AuthenticationFailed => write!(f, "GitHub authentication failed"),
             GitHubError::RateLimitExceeded => write!(f, "GitHub API rate limit exceeded"),
+            GitHubError::SecondaryRateLimit => write!(f, "GitHub secondary rate limit exceeded"),
+            GitHubError::ServerError(msg) => write!(f, "GitHub server error: {}", msg),
             GitHubError::NetworkError(msg) => write!(f, "Network error: {}", msg),
-            GitHubError::RepositoryNotFound(repo) => write!(f, "Repository not found: {}", repo),
             GitHubError::ApiError(msg) => write!(f, "GitHub API error: {}", msg),
         }
     }
@@ -740,29 +4522,75 @@ impl std::fmt::Display for GitHubError {
 
 impl std::error::Error for GitHubError {}
 
-impl From<octocrab::Error> for GitHubError {
-    fn from(error: octocrab::Error) -> Self {
+/// Whether an API error message reports a primary or secondary rate limit
+fn is_rate_limit_error(message: &str) -> bool {
+    message.to_lowercase().contains("rate limit")
+}
+
+impl GitHubError {
+    /// Classify an octocrab error
+    pub fn classify(error: &octocrab::Error) -> Self {
         match error {
             octocrab::Error::GitHub { source, .. } => {
-                if source.message.contains("rate limit") {
+                let message = source.message.to_lowercase();
+                if message.contains("secondary rate limit") {
+                    GitHubError::SecondaryRateLimit
+                } else if message.contains("rate limit") {
                     GitHubError::RateLimitExceeded
-                } else if source.message.contains("authentication")
-                    || source.message.contains("401")
+                } else if source.status_code == http::StatusCode::UNAUTHORIZED
+                    || message.contains("authentication")
                 {
                     GitHubError::AuthenticationFailed
+                } else if source.status_code.is_server_error() {
+                    GitHubError::ServerError(format!("{} {}", source.status_code, source.message))
                 } else {
-                    GitHubError::ApiError(source.message)
+                    GitHubError::ApiError(source.message.clone())
                 }
             }
             octocrab::Error::Http { source, .. } => GitHubError::NetworkError(source.to_string()),
+            octocrab::Error::Hyper { source, .. } => GitHubError::NetworkError(source.to_string()),
+            octocrab::Error::Service { source, .. } => {
+                GitHubError::NetworkError(source.to_string())
+            }
             _ => GitHubError::ApiError(error.to_string()),
         }
     }
+
+    /// Whether the same request may succeed if retried shortly
+    ///
+    /// Network failures, server errors and secondary rate limits pass;
+    /// authentication failures, missing resources and the primary rate
+    /// limit (which lasts until its reset) don't.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            GitHubError::NetworkError(_)
+                | GitHubError::ServerError(_)
+                | GitHubError::SecondaryRateLimit
+        )
+    }
+}
+
+impl From<octocrab::Error> for GitHubError {
+    fn from(error: octocrab::Error) -> Self {
+        GitHubError::classify(&error)
+    }
+}
+
+/// A pseudo-random fraction in 0..1, for retry jitter
+fn jitter() -> f64 {
+    use std::hash::{BuildHasher, Hasher};
+    let random = std::collections::hash_map::RandomState::new()
+        .build_hasher()
+        .finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::credentials::read_token;
+    use keyring::{mock::MockCredential, Entry};
 
     #[test]
     fn test_github_error_display() {
@@ -776,20 +4604,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_rate_limit_errors() {
+        assert!(is_rate_limit_error(
+            "API rate limit exceeded for user ID 1234."
+        ));
+        assert!(is_rate_limit_error(
+            "You have exceeded a secondary rate limit. Please wait a few minutes."
+        ));
+        assert!(!is_rate_limit_error("Not Found"));
+    }
+
+    #[test]
+    fn test_refresh_request_estimate() {
+        let quiet = AppRepository::new("docs".to_string(), "acme".to_string());
+        let mut busy = AppRepository::new("web".to_string(), "acme".to_string());
+        busy.has_pages = true;
+        busy.deploy_status.environments.push(EnvironmentDeployment {
+            name: "production".to_string(),
+            state: DeploymentState::Success,
+        });
+
+        let all = Collector::ALL;
+        assert_eq!(EnhancementProfile::Full.requests_for(&quiet, &all), 12);
+        assert_eq!(EnhancementProfile::Full.requests_for(&busy, &all), 15);
+        assert_eq!(EnhancementProfile::Lite.requests_for(&busy, &all), 3);
+        let both = [quiet.clone(), busy.clone()];
+        assert_eq!(
+            EnhancementProfile::Full.estimate_requests(ApiBackend::Rest, &both, &all),
+            28
+        );
+        assert_eq!(
+            EnhancementProfile::Lite.estimate_requests(
+                ApiBackend::Rest,
+                &vec![quiet.clone(); 150],
+                &all
+            ),
+            452
+        );
+
+        // GraphQL batches cover PRs, commits, runs and releases; LFS,
+        // deployments, code and secret scanning, branch protection, the
+        // community profile, contributor statistics and commit activity still
+        // cost REST calls
+        assert_eq!(
+            EnhancementProfile::Full.estimate_requests(ApiBackend::Graphql, &both, &all),
+            21
+        );
+        assert_eq!(
+            EnhancementProfile::Lite.estimate_requests(
+                ApiBackend::Graphql,
+                &vec![quiet; 150],
+                &all
+            ),
+            10
+        );
+
+        // Switched-off collectors aren't counted
+        assert_eq!(
+            EnhancementProfile::Full.requests_for(&busy, &[Collector::Workflows]),
+            3
+        );
+        assert_eq!(EnhancementProfile::Lite.requests_for(&busy, &[]), 2);
+    }
+
     #[tokio::test]
     async fn test_github_client_creation_without_token() {
-        // This test should fail if the token is not set
-        // Remove the environment variable for this test
-        std::env::remove_var("GH_REPO_HEALTHCHECKS_TOKEN");
-
-        let result = GitHubClient::new();
+        let keyring = Entry::new_with_credential(Box::new(MockCredential::default()));
+        let result = GitHubClient::new(&NetworkConfig::default(), || read_token(&keyring));
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
             .to_string()
             .contains("GH_REPO_HEALTHCHECKS_TOKEN"));
+
+        keyring.set_password("ghp_example").unwrap();
+        assert!(GitHubClient::new(&NetworkConfig::default(), || read_token(&keyring)).is_ok());
     }
 
-    // Note: Integration tests with real GitHub API would require a valid token
-    // and should be run separately from unit tests
+    // End-to-end tests against a fake GitHub API live in `mock_github`
 }
@@ -0,0 +1,254 @@
+use crate::app::BackgroundMessage;
+use crate::bus::{AppEvent, Subscriber};
+use crate::config::{AppConfig, AttentionConfig, HookConfig, HookEvent};
+use crate::models::{AggregateMetrics, Repository};
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use std::process::Stdio;
+use std::time::SystemTime;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+
+/// Environment variable carrying the event name, for scripts that skip the payload
+pub const EVENT_ENV: &str = "GH_REPO_HEALTHCHECKS_EVENT";
+
+/// Runs configured external commands when app events happen
+///
+/// Each command gets the event's JSON payload on stdin. Attention and CI
+/// events are edge-triggered like threshold webhooks: they fire once when a
+/// repository enters the state and again only after it has left it.
+pub struct HookRunner {
+    hooks: Vec<HookConfig>,
+    attention: AttentionConfig,
+    sender: mpsc::UnboundedSender<BackgroundMessage>,
+    /// Repositories last seen needing attention
+    needing_attention: HashSet<String>,
+    /// Repositories last seen with failing CI
+    ci_failing: HashSet<String>,
+}
+
+impl HookRunner {
+    /// Create a runner from the application config (None when no hooks are configured)
+    pub fn from_config(
+        config: &AppConfig,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+    ) -> Option<Self> {
+        if config.hooks.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            hooks: config.hooks.clone(),
+            attention: config.attention.clone(),
+            sender,
+            needing_attention: HashSet::new(),
+            ci_failing: HashSet::new(),
+        })
+    }
+
+    /// Events raised by a completed refresh, with their payloads
+    fn events(
+        &mut self,
+        repositories: &[Repository],
+        now: SystemTime,
+    ) -> Vec<(HookEvent, serde_json::Value)> {
+        let timestamp = DateTime::<Utc>::from(now).to_rfc3339();
+        let totals = AggregateMetrics::from_repositories(repositories, &self.attention);
+        let mut events = vec![(
+            HookEvent::RefreshCompleted,
+            serde_json::json!({
+                "event": HookEvent::RefreshCompleted.name(),
+                "repositories": totals.repositories,
+                "average_health": totals.average_health,
+                "needs_attention": totals.needs_attention,
+                "ci_broken": totals.ci_broken,
+                "timestamp": timestamp,
+            }),
+        )];
+
+        for repo in repositories {
+            let full_name = repo.full_name();
+            let states = [
+                (
+                    HookEvent::AttentionEntered,
+                    self.attention.matches(repo),
+                    &mut self.needing_attention,
+                ),
+                (
+                    HookEvent::CiFailed,
+                    repo.is_ci_broken(),
+                    &mut self.ci_failing,
+                ),
+            ];
+            for (event, active, seen) in states {
+                if !active {
+                    seen.remove(&full_name);
+                } else if seen.insert(full_name.clone()) {
+                    events.push((
+                        event,
+                        serde_json::json!({
+                            "event": event.name(),
+                            "repository": full_name,
+                            "health_score": repo.health_score(),
+                            "status": repo.status_summary(),
+                            "html_url": repo.html_url,
+                            "timestamp": timestamp,
+                        }),
+                    ));
+                }
+            }
+        }
+
+        events
+    }
+
+    /// Run one hook, writing the payload to its stdin and waiting for it to exit
+    pub async fn run(hook: &HookConfig, payload: &serde_json::Value) -> Result<(), String> {
+        let mut child = tokio::process::Command::new(&hook.command)
+            .args(&hook.args)
+            .env(EVENT_ENV, hook.event.name())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| format!("Hook '{}' failed to start: {}", hook.command, e))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            // A hook that ignores its payload may exit before reading it
+            let _ = stdin.write_all(payload.to_string().as_bytes()).await;
+        }
+
+        let status = child
+            .wait()
+            .await
+            .map_err(|e| format!("Hook '{}' failed: {}", hook.command, e))?;
+        if !status.success() {
+            return Err(format!("Hook '{}' exited with {}", hook.command, status));
+        }
+        Ok(())
+    }
+
+    /// Spawn a background task running every hook registered for the events
+    fn spawn_run(&self, events: Vec<(HookEvent, serde_json::Value)>) {
+        let runs: Vec<(HookConfig, serde_json::Value)> = events
+            .into_iter()
+            .flat_map(|(event, payload)| {
+                self.hooks
+                    .iter()
+                    .filter(move |hook| hook.event == event)
+                    .map(move |hook| (hook.clone(), payload.clone()))
+            })
+            .collect();
+        if runs.is_empty() {
+            return;
+        }
+
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            for (hook, payload) in &runs {
+                if let Err(error) = Self::run(hook, payload).await {
+                    let _ = sender.send(BackgroundMessage::ActionError { error });
+                }
+            }
+        });
+    }
+}
+
+impl Subscriber for HookRunner {
+    fn on_event(&mut self, event: &AppEvent) {
+        if let AppEvent::RefreshCompleted { repositories } = event {
+            let events = self.events(repositories, SystemTime::now());
+            self.spawn_run(events);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RepositoryStatus;
+
+    fn runner() -> HookRunner {
+        let config = AppConfig::parse(
+            r#"
+            [[hooks]]
+            event = "attention_entered"
+            command = "notify-send"
+            args = ["Repository needs attention"]
+            "#,
+        )
+        .unwrap();
+        let (sender, _receiver) = mpsc::unbounded_channel();
+        HookRunner::from_config(&config, sender).unwrap()
+    }
+
+    fn event_names(events: &[(HookEvent, serde_json::Value)]) -> Vec<&'static str> {
+        events.iter().map(|(event, _)| event.name()).collect()
+    }
+
+    #[test]
+    fn test_attention_hook_fires_once_per_entry() {
+        let mut runner = runner();
+        let mut repo = Repository::new("api".to_string(), "acme".to_string());
+        repo.status = RepositoryStatus::Dormant;
+        let now = SystemTime::now();
+
+        let events = runner.events(std::slice::from_ref(&repo), now);
+        assert_eq!(
+            event_names(&events),
+            vec!["refresh_completed", "attention_entered"]
+        );
+        assert_eq!(events[1].1["repository"], "acme/api");
+        assert_eq!(events[0].1["needs_attention"], 1);
+
+        let events = runner.events(std::slice::from_ref(&repo), now);
+        assert_eq!(event_names(&events), vec!["refresh_completed"]);
+
+        repo.status = RepositoryStatus::Active;
+        runner.events(std::slice::from_ref(&repo), now);
+        repo.status = RepositoryStatus::Dormant;
+        let events = runner.events(std::slice::from_ref(&repo), now);
+        assert_eq!(
+            event_names(&events),
+            vec!["refresh_completed", "attention_entered"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_pipes_payload_to_command() {
+        let path = std::env::temp_dir().join(format!(
+            "gh-repo-healthchecks-hook-{}.json",
+            std::process::id()
+        ));
+        let hook = HookConfig {
+            event: HookEvent::CiFailed,
+            command: "sh".to_string(),
+            args: vec![
+                "-c".to_string(),
+                format!(
+                    "cat > {} && test \"${}\" = ci_failed",
+                    path.display(),
+                    EVENT_ENV
+                ),
+            ],
+        };
+
+        HookRunner::run(&hook, &serde_json::json!({ "event": "ci_failed" }))
+            .await
+            .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            r#"{"event":"ci_failed"}"#
+        );
+        std::fs::remove_file(&path).unwrap();
+
+        let failing = HookConfig {
+            args: vec!["-c".to_string(), "exit 3".to_string()],
+            ..hook
+        };
+        assert!(HookRunner::run(&failing, &serde_json::json!({}))
+            .await
+            .unwrap_err()
+            .contains("exited with"));
+    }
+}
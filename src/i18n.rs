@@ -0,0 +1,222 @@
+use crate::config::I18nConfig;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Built-in message catalogs by locale code
+pub const BUILTIN_CATALOGS: [(&str, &str); 2] = [
+    ("en", include_str!("../locales/en.toml")),
+    ("de", include_str!("../locales/de.toml")),
+];
+
+/// Catalog chosen by the config, set once at startup
+static ACTIVE: OnceLock<Catalog> = OnceLock::new();
+
+/// English catalog, used for keys missing from the active one
+static ENGLISH: OnceLock<Catalog> = OnceLock::new();
+
+#[cfg(test)]
+thread_local! {
+    /// Catalog a test renders with instead of the active one
+    static TEST_CATALOG: std::cell::Cell<Option<&'static Catalog>> =
+        const { std::cell::Cell::new(None) };
+}
+
+/// UI messages by dotted key (e.g. `status.active`)
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    /// Parse a TOML catalog, joining table names into dotted keys
+    pub fn parse(toml: &str) -> Result<Self, String> {
+        let table: toml::Table = toml
+            .parse()
+            .map_err(|e| format!("Invalid message catalog: {}", e))?;
+        let mut catalog = Self::default();
+        catalog.flatten("", &table);
+        Ok(catalog)
+    }
+
+    fn flatten(&mut self, prefix: &str, table: &toml::Table) {
+        for (key, value) in table {
+            let key = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            match value {
+                toml::Value::Table(table) => self.flatten(&key, table),
+                toml::Value::String(message) => {
+                    self.messages.insert(key, message.clone());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Built-in catalog for a locale code
+    pub fn builtin(locale: &str) -> Option<Self> {
+        BUILTIN_CATALOGS
+            .iter()
+            .find(|(code, _)| code.eq_ignore_ascii_case(locale))
+            .and_then(|(_, toml)| Self::parse(toml).ok())
+    }
+
+    /// Load a catalog file written by a team localizing the dashboard
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        Self::parse(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Message for a key, if this catalog has one
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+}
+
+/// Select the catalog used by `t` for the rest of the session
+///
+/// A custom catalog file overrides messages of the configured locale, so it
+/// only needs to contain the strings a team wants to change.
+pub fn init(config: &I18nConfig) -> Result<(), String> {
+    let mut catalog = Catalog::builtin(&config.locale).unwrap_or_default();
+    if let Some(path) = &config.catalog {
+        catalog.messages.extend(Catalog::load(path)?.messages);
+    }
+    let _ = ACTIVE.set(catalog);
+    Ok(())
+}
+
+fn english() -> &'static Catalog {
+    ENGLISH.get_or_init(|| Catalog::builtin("en").unwrap_or_default())
+}
+
+/// Look up a UI message, falling back to English and then to the key itself
+pub fn t(key: &'static str) -> &'static str {
+    #[cfg(test)]
+    if let Some(message) = TEST_CATALOG
+        .with(std::cell::Cell::get)
+        .and_then(|catalog| catalog.get(key))
+    {
+        return message;
+    }
+    ACTIVE
+        .get()
+        .and_then(|catalog| catalog.get(key))
+        .or_else(|| english().get(key))
+        .unwrap_or(key)
+}
+
+/// Look up a UI message and fill in its `{name}` placeholders
+pub fn t_with(key: &'static str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+    fill(t(key), args)
+}
+
+/// Run `f` with a built-in locale in place of the active one, on this
+/// thread only so other tests keep using English
+#[cfg(test)]
+pub fn with_locale<R>(locale: &str, f: impl FnOnce() -> R) -> R {
+    let catalog: &'static Catalog = Box::leak(Box::new(Catalog::builtin(locale).unwrap()));
+    TEST_CATALOG.with(|active| active.set(Some(catalog)));
+    let result = f();
+    TEST_CATALOG.with(|active| active.set(None));
+    result
+}
+
+/// English message text found in `rendered` although `locale` translates it
+///
+/// Only literal runs of eight or more characters between placeholders count,
+/// and none that also occur in the translation catalog.
+#[cfg(test)]
+pub fn untranslated(locale: &str, rendered: &str) -> Vec<String> {
+    let english = Catalog::builtin("en").unwrap();
+    let translated = Catalog::builtin(locale).unwrap();
+    let mut found: Vec<String> = english
+        .messages
+        .iter()
+        .filter(|(key, message)| translated.get(key) != Some(message.as_str()))
+        .flat_map(|(_, message)| {
+            message
+                .split(['{', '}'])
+                .step_by(2)
+                .map(str::trim)
+                .filter(|literal| literal.chars().count() >= 8)
+        })
+        .filter(|literal| {
+            !translated
+                .messages
+                .values()
+                .any(|message| message.contains(literal))
+        })
+        .filter(|literal| rendered.contains(literal))
+        .map(str::to_string)
+        .collect();
+    found.sort();
+    found.dedup();
+    found
+}
+
+/// Fill in a message's `{name}` placeholders
+pub fn fill(message: &str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+    args.iter()
+        .fold(message.to_string(), |message, (name, value)| {
+            message.replace(&format!("{{{}}}", name), &value.to_string())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_catalogs_match_english() {
+        let english = Catalog::builtin("en").unwrap();
+        assert_eq!(english.get("status.active"), Some("Active (this week)"));
+
+        for (locale, _) in BUILTIN_CATALOGS {
+            let catalog = Catalog::builtin(locale).unwrap();
+            for key in catalog.messages.keys() {
+                assert!(
+                    english.get(key).is_some(),
+                    "{} has key {} missing from English",
+                    locale,
+                    key
+                );
+            }
+            for key in english.messages.keys() {
+                assert!(
+                    catalog.get(key).is_some(),
+                    "{} is missing key {}",
+                    locale,
+                    key
+                );
+            }
+        }
+        assert_eq!(
+            Catalog::builtin("DE").unwrap().get("footer.quit"),
+            Some("Beenden")
+        );
+        assert!(Catalog::builtin("xx").is_none());
+    }
+
+    #[test]
+    fn test_lookup_and_placeholders() {
+        // Tests never call `init`, so lookups use English
+        assert_eq!(t("table.repository"), "Repository");
+        assert_eq!(t("no.such.key"), "no.such.key");
+        assert_eq!(
+            t_with("footer.switch_mode", &[("count", &3)]),
+            "Switch Mode (3 orgs)"
+        );
+
+        let catalog = Catalog::parse("[footer]\nsort = \"Orden: {order}\"").unwrap();
+        assert_eq!(
+            fill(catalog.get("footer.sort").unwrap(), &[("order", &"tamaño")]),
+            "Orden: tamaño"
+        );
+        assert!(Catalog::parse("[footer").is_err());
+    }
+}
@@ -1,9 +1,44 @@
+mod activity;
 mod app;
+mod audit;
+mod browser;
+mod bus;
+mod canary;
+mod checks;
+mod cleanup;
+mod codeowners;
+mod config;
+mod config_check;
+mod credentials;
+mod daemon;
+mod demo;
+mod dependencies;
+mod dialog;
+mod diff;
+mod digest;
 mod events;
+mod failures;
 mod github;
+mod hooks;
+mod i18n;
+mod metrics;
+#[cfg(test)]
+mod mock_github;
 mod models;
+mod network;
+mod notifications;
+mod platform_status;
+mod policy;
+mod prefetch;
+mod releases;
+mod report;
+mod smtp;
+mod statusline;
+mod stream;
+mod table;
 mod terminal;
 mod ui;
+mod undo;
 
 use app::App;
 use events::EventHandler;
@@ -18,16 +53,77 @@ use std::error::Error;
 /// runs the main application loop, and handles cleanup when exiting.
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    // Initialize the terminal and run the app
-    let mut terminal = TerminalManager::setup()?;
-    let result = run_app(&mut terminal).await;
+    // Fetch less and wait longer on flaky or metered connections, in every mode
+    if std::env::args().skip(1).any(|arg| arg == "--slow-network") {
+        config::force_low_bandwidth();
+    }
+
+    // Check the config file, the hosts it names and the token, then exit
+    if std::env::args()
+        .skip(1)
+        .any(|arg| arg == "--validate-config")
+    {
+        return config_check::run().await;
+    }
+
+    // Save a token in the OS keyring, or remove it
+    if std::env::args().skip(1).any(|arg| arg == "--login") {
+        return credentials::login().await;
+    }
+    if std::env::args().skip(1).any(|arg| arg == "--logout") {
+        return credentials::logout();
+    }
+
+    // Headless mode: refresh periodically and write reports instead of a TUI
+    if std::env::args().skip(1).any(|arg| arg == "--daemon") {
+        return daemon::run().await;
+    }
+
+    // Compare two stored reports and print what changed
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(index) = args.iter().position(|arg| arg == "--diff") {
+        return match (args.get(index + 1), args.get(index + 2)) {
+            (Some(from), Some(to)) => diff::run(from, to),
+            _ => Err("Usage: gh-repo-healthchecks --diff <FROM> <TO>".into()),
+        };
+    }
+
+    // Compose the weekly digest from stored reports and write or mail it
+    if args.iter().any(|arg| arg == "--digest") {
+        return digest::run().await;
+    }
+
+    // Print a one-line health summary for tmux or a shell prompt
+    if args.iter().any(|arg| arg == "--statusline") {
+        return statusline::run().await;
+    }
+
+    // Stream health events as newline-delimited JSON for other tools
+    if args.iter().any(|arg| arg == "--stream-json") {
+        return stream::run().await;
+    }
+
+    // Explore the dashboard with built-in sample repositories, no token needed
+    let demo = args.iter().any(|arg| arg == "--demo");
+
+    // Initialize the terminal and run the app; the guard restores the
+    // terminal even if the app panics
+    let mut guard = TerminalManager::setup_with_guard()?;
+    let result = run_app(guard.terminal(), demo).await;
 
     // Clean up terminal state before exiting
-    TerminalManager::cleanup(&mut terminal)?;
+    guard.cleanup()?;
 
-    // Report any errors that occurred during execution
-    if let Err(err) = result {
-        eprintln!("Application error: {:?}", err);
+    // Report any errors that occurred during execution, or what the
+    // session did once the alternate screen is gone
+    match result {
+        Ok(Some(summary)) => {
+            for line in summary.lines() {
+                println!("{}", line);
+            }
+        }
+        Ok(None) => {}
+        Err(err) => eprintln!("Application error: {:?}", err),
     }
 
     Ok(())
@@ -40,47 +136,45 @@ async fn main() -> Result<(), Box<dyn Error>> {
 /// - Processing events (keyboard input, terminal resize, etc.)
 /// - Rendering the UI on each frame
 /// - Graceful exit when requested
+///
+/// Returns the session summary to print on exit, unless it's turned off.
 async fn run_app(
     terminal: &mut ratatui::Terminal<ratatui::prelude::CrosstermBackend<std::io::Stdout>>,
-) -> Result<(), Box<dyn Error>> {
-    // Initialize application state
-    let mut app = App::new();
-    let event_handler = EventHandler::new();
+    demo: bool,
+) -> Result<Option<metrics::SessionSummary>, Box<dyn Error>> {
+    // Initialize application state, timing startup from here
+    let launched = std::time::Instant::now();
+    let mut app = if demo { App::demo() } else { App::new() };
+    app.startup = metrics::StartupTimings::new(launched);
+    app.startup.mark(metrics::StartupMilestone::Ready);
+    app.prune_archived_reports();
+    let event_handler = EventHandler::with_timeout(
+        app.config
+            .poll_interval_ms
+            .max(config::MIN_POLL_INTERVAL_MS),
+    );
 
-    // Trigger initial refresh to start background loading
-    app.refresh();
+    // Trigger initial refresh to start background loading before the first
+    // draw; nothing below waits on the network
+    app.auto_refresh();
 
     // Main event loop
     loop {
         // Process any pending background messages
         app.process_background_messages();
 
+        // Run periodic background checks
+        app.tick();
+
         // Draw the current frame
         terminal.draw(|frame| {
             UI::render(frame, &app);
         })?;
+        app.startup.mark(metrics::StartupMilestone::FirstFrame);
 
         // Check for and handle events with a timeout to allow async operations
         if let Some(event) = event_handler.next_event()? {
-            // Handle the event based on its type
-            match event {
-                events::AppEvent::Key(key_event) => {
-                    if event.is_refresh() {
-                        // Refresh repositories in the background
-                        app.refresh();
-                    } else {
-                        // Let the app handle other key events
-                        app.handle_key_event(key_event.code);
-                    }
-                }
-                events::AppEvent::Resize(_width, _height) => {
-                    // Terminal was resized - the next draw will handle the new size
-                    // No explicit action needed as ratatui handles this automatically
-                }
-                events::AppEvent::Mouse(_mouse_event) => {
-                    // Future: Handle mouse events for enhanced interaction
-                }
-            }
+            app.handle_terminal_event(event);
         }
 
         // Check if the application should quit
@@ -92,5 +186,5 @@ async fn run_app(
         tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
     }
 
-    Ok(())
+    Ok(app.config.session_summary.then(|| app.session_summary()))
 }
@@ -0,0 +1,377 @@
+use crate::i18n::t;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Latency samples kept per endpoint for percentile calculations
+const MAX_SAMPLES: usize = 500;
+
+/// Request statistics for a single GitHub API endpoint
+#[derive(Debug, Clone, Default)]
+pub struct EndpointMetrics {
+    pub requests: usize,
+    pub errors: usize,
+    /// Requests GitHub answered with 304 Not Modified, reusing a cached response
+    pub not_modified: usize,
+    /// Most recent request latencies, oldest first
+    latencies: Vec<Duration>,
+}
+
+impl EndpointMetrics {
+    /// Record a completed request
+    pub fn record(&mut self, latency: Duration, succeeded: bool) {
+        self.requests += 1;
+        if !succeeded {
+            self.errors += 1;
+        }
+        if self.latencies.len() == MAX_SAMPLES {
+            self.latencies.remove(0);
+        }
+        self.latencies.push(latency);
+    }
+
+    /// Latency below which `percentile` percent of recent requests completed
+    pub fn percentile(&self, percentile: usize) -> Option<Duration> {
+        if self.latencies.is_empty() {
+            return None;
+        }
+        let mut sorted = self.latencies.clone();
+        sorted.sort();
+        let rank = (percentile * sorted.len()).div_ceil(100).max(1);
+        Some(sorted[rank.min(sorted.len()) - 1])
+    }
+
+    /// Median latency
+    pub fn p50(&self) -> Option<Duration> {
+        self.percentile(50)
+    }
+
+    /// 95th percentile latency
+    pub fn p95(&self) -> Option<Duration> {
+        self.percentile(95)
+    }
+
+    /// Share of requests that failed, as a percentage
+    pub fn error_rate(&self) -> f64 {
+        if self.requests == 0 {
+            0.0
+        } else {
+            self.errors as f64 / self.requests as f64 * 100.0
+        }
+    }
+}
+
+/// Per-endpoint GitHub API request metrics for this session
+///
+/// Cloning shares the underlying counters, so every clone of the GitHub
+/// client reports into the same metrics.
+#[derive(Debug, Clone, Default)]
+pub struct RequestMetrics {
+    endpoints: Arc<Mutex<HashMap<&'static str, EndpointMetrics>>>,
+}
+
+impl RequestMetrics {
+    /// Record a completed request against an endpoint
+    pub fn record(&self, endpoint: &'static str, latency: Duration, succeeded: bool) {
+        if let Ok(mut endpoints) = self.endpoints.lock() {
+            endpoints
+                .entry(endpoint)
+                .or_default()
+                .record(latency, succeeded);
+        }
+    }
+
+    /// Record that a request reused a cached response (already recorded as a
+    /// request)
+    pub fn record_not_modified(&self, endpoint: &'static str) {
+        if let Ok(mut endpoints) = self.endpoints.lock() {
+            endpoints.entry(endpoint).or_default().not_modified += 1;
+        }
+    }
+
+    /// Snapshot of every endpoint's metrics, busiest first
+    pub fn snapshot(&self) -> Vec<(&'static str, EndpointMetrics)> {
+        let mut endpoints: Vec<(&'static str, EndpointMetrics)> = match self.endpoints.lock() {
+            Ok(endpoints) => endpoints
+                .iter()
+                .map(|(name, metrics)| (*name, metrics.clone()))
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        endpoints.sort_by(|a, b| b.1.requests.cmp(&a.1.requests).then(a.0.cmp(b.0)));
+        endpoints
+    }
+
+    /// Total requests and errors across all endpoints
+    pub fn totals(&self) -> (usize, usize) {
+        self.snapshot()
+            .iter()
+            .fold((0, 0), |(requests, errors), (_, metrics)| {
+                (requests + metrics.requests, errors + metrics.errors)
+            })
+    }
+}
+
+/// Points of the first load after launch, in the order they're reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartupMilestone {
+    /// Config loaded and app state built
+    Ready,
+    /// First frame drawn
+    FirstFrame,
+    /// First repository of the basic list received
+    FirstRepository,
+    /// Basic repository list complete
+    RepositoryList,
+    /// Every repository's details loaded
+    DetailsLoaded,
+}
+
+impl StartupMilestone {
+    pub const ALL: [StartupMilestone; 5] = [
+        StartupMilestone::Ready,
+        StartupMilestone::FirstFrame,
+        StartupMilestone::FirstRepository,
+        StartupMilestone::RepositoryList,
+        StartupMilestone::DetailsLoaded,
+    ];
+
+    /// Short label for the diagnostics view
+    pub fn label(self) -> &'static str {
+        match self {
+            StartupMilestone::Ready => t("startup.ready"),
+            StartupMilestone::FirstFrame => t("startup.first_frame"),
+            StartupMilestone::FirstRepository => t("startup.first_repository"),
+            StartupMilestone::RepositoryList => t("startup.repository_list"),
+            StartupMilestone::DetailsLoaded => t("startup.details_loaded"),
+        }
+    }
+}
+
+/// Time from launch to each startup milestone
+///
+/// Only the first time a milestone is reached counts, so later refreshes
+/// don't overwrite how long the first load took.
+#[derive(Debug, Clone)]
+pub struct StartupTimings {
+    launched: Instant,
+    reached: Vec<(StartupMilestone, Duration)>,
+}
+
+impl Default for StartupTimings {
+    fn default() -> Self {
+        Self::new(Instant::now())
+    }
+}
+
+impl StartupTimings {
+    /// Start timing from `launched`
+    pub fn new(launched: Instant) -> Self {
+        Self {
+            launched,
+            reached: Vec::new(),
+        }
+    }
+
+    /// Record that a milestone was reached now, unless it already was
+    pub fn mark(&mut self, milestone: StartupMilestone) {
+        if self.elapsed(milestone).is_none() {
+            self.reached.push((milestone, self.launched.elapsed()));
+        }
+    }
+
+    /// How long after launch a milestone was reached
+    pub fn elapsed(&self, milestone: StartupMilestone) -> Option<Duration> {
+        self.reached
+            .iter()
+            .find(|(reached, _)| *reached == milestone)
+            .map(|(_, elapsed)| *elapsed)
+    }
+
+    /// Time since launch
+    pub fn uptime(&self) -> Duration {
+        self.launched.elapsed()
+    }
+
+    /// One line summary such as `ready 12ms · first frame 15ms · list 0.8s`
+    ///
+    /// Milestones not reached yet show as `…`.
+    pub fn summary(&self) -> String {
+        StartupMilestone::ALL
+            .iter()
+            .map(|milestone| {
+                let elapsed = self.elapsed(*milestone).map_or("…".to_string(), |elapsed| {
+                    if elapsed < Duration::from_secs(1) {
+                        format!("{}ms", elapsed.as_millis())
+                    } else {
+                        format!("{:.1}s", elapsed.as_secs_f64())
+                    }
+                });
+                format!("{} {}", milestone.label(), elapsed)
+            })
+            .collect::<Vec<_>>()
+            .join(" · ")
+    }
+}
+
+/// Errors listed in a session summary; older ones are only counted
+const SUMMARY_ERRORS: usize = 5;
+
+/// What a session did, printed on exit
+#[derive(Debug, Clone, Default)]
+pub struct SessionSummary {
+    /// Time since launch
+    pub duration: Duration,
+    /// Refreshes that completed
+    pub refreshes: u64,
+    /// Repositories loaded at exit
+    pub repositories: usize,
+    /// GitHub API requests by endpoint, busiest first
+    pub endpoints: Vec<(&'static str, EndpointMetrics)>,
+    /// Errors shown during the session, oldest first
+    pub errors: Vec<String>,
+}
+
+impl SessionSummary {
+    /// Lines printed after leaving the alternate screen
+    pub fn lines(&self) -> Vec<String> {
+        let minutes = self.duration.as_secs() / 60;
+        let mut lines = vec![format!(
+            "Session: {}m {}s, {} refreshes, {} repositories",
+            minutes,
+            self.duration.as_secs() % 60,
+            self.refreshes,
+            self.repositories
+        )];
+
+        let total = |count: fn(&EndpointMetrics) -> usize| -> usize {
+            self.endpoints
+                .iter()
+                .map(|(_, metrics)| count(metrics))
+                .sum()
+        };
+        let requests = total(|metrics| metrics.requests);
+        let cached = total(|metrics| metrics.not_modified);
+        let errors = total(|metrics| metrics.errors);
+        if requests == 0 {
+            lines.push("API: no requests".to_string());
+        } else {
+            lines.push(format!(
+                "API: {} requests, {} cached ({:.0}% hit rate), {} errors",
+                requests,
+                cached,
+                cached as f64 / requests as f64 * 100.0,
+                errors
+            ));
+            let width = self.endpoints.iter().map(|(name, _)| name.len()).max();
+            for (name, metrics) in &self.endpoints {
+                lines.push(format!(
+                    "  {:width$}  {:>5} requests  {:>4} cached  {:>3} errors",
+                    name,
+                    metrics.requests,
+                    metrics.not_modified,
+                    metrics.errors,
+                    width = width.unwrap_or_default()
+                ));
+            }
+        }
+
+        if !self.errors.is_empty() {
+            lines.push(format!("Errors: {}", self.errors.len()));
+            let skipped = self.errors.len().saturating_sub(SUMMARY_ERRORS);
+            if skipped > 0 {
+                lines.push(format!("  ... {} earlier", skipped));
+            }
+            lines.extend(
+                self.errors[skipped..]
+                    .iter()
+                    .map(|error| format!("  {}", error)),
+            );
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentiles() {
+        let mut metrics = EndpointMetrics::default();
+        assert!(metrics.p50().is_none());
+
+        for ms in 1..=100 {
+            metrics.record(Duration::from_millis(ms), ms % 10 != 0);
+        }
+        assert_eq!(metrics.p50(), Some(Duration::from_millis(50)));
+        assert_eq!(metrics.p95(), Some(Duration::from_millis(95)));
+        assert_eq!(metrics.errors, 10);
+        assert_eq!(metrics.error_rate(), 10.0);
+    }
+
+    #[test]
+    fn test_clones_share_metrics() {
+        let metrics = RequestMetrics::default();
+        let clone = metrics.clone();
+        clone.record("pulls", Duration::from_millis(120), true);
+        clone.record("commits", Duration::from_millis(80), false);
+        metrics.record("pulls", Duration::from_millis(90), true);
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot[0].0, "pulls");
+        assert_eq!(snapshot[0].1.requests, 2);
+        assert_eq!(metrics.totals(), (3, 1));
+    }
+
+    #[test]
+    fn test_session_summary_lines() {
+        let metrics = RequestMetrics::default();
+        metrics.record("repos/pulls", Duration::from_millis(100), true);
+        metrics.record("repos/pulls", Duration::from_millis(100), true);
+        metrics.record_not_modified("repos/pulls");
+        metrics.record("user/repos", Duration::from_millis(100), false);
+        let summary = SessionSummary {
+            duration: Duration::from_secs(754),
+            refreshes: 2,
+            repositories: 12,
+            endpoints: metrics.snapshot(),
+            errors: vec!["Failed to fetch repositories: timeout".to_string()],
+        };
+        assert_eq!(
+            summary.lines(),
+            vec![
+                "Session: 12m 34s, 2 refreshes, 12 repositories",
+                "API: 3 requests, 1 cached (33% hit rate), 1 errors",
+                "  repos/pulls      2 requests     1 cached    0 errors",
+                "  user/repos       1 requests     0 cached    1 errors",
+                "Errors: 1",
+                "  Failed to fetch repositories: timeout",
+            ]
+        );
+
+        let idle = SessionSummary::default();
+        assert_eq!(idle.lines()[1], "API: no requests");
+    }
+
+    #[test]
+    fn test_startup_milestones_keep_first_time() {
+        let launched = Instant::now() - Duration::from_millis(1500);
+        let mut timings = StartupTimings::new(launched);
+        timings.mark(StartupMilestone::RepositoryList);
+        let first = timings.elapsed(StartupMilestone::RepositoryList).unwrap();
+        assert!(first >= Duration::from_millis(1500));
+
+        // A later refresh reaching the same milestone doesn't count
+        timings.reached[0].1 = Duration::from_millis(800);
+        timings.mark(StartupMilestone::RepositoryList);
+        assert_eq!(
+            timings.elapsed(StartupMilestone::RepositoryList),
+            Some(Duration::from_millis(800))
+        );
+        assert_eq!(
+            timings.summary(),
+            "ready … · first frame … · first repo … · list 800ms · details …"
+        );
+    }
+}
@@ -0,0 +1,1682 @@
+//! End-to-end tests driving `GitHubClient` against a fake GitHub API
+//!
+//! Each test starts a wiremock server answering with the JSON fixtures in
+//! `tests/fixtures/github` for the account `octocat`, which owns a busy `api`
+//! repository and an empty `site` one. The tests run the same background
+//! tasks as the app and check the messages they send.
+
+use crate::app::{App, AppView, BackgroundMessage};
+use crate::cleanup::CleanupReason;
+use crate::config::{ApiBackend, AppConfig, RetryPolicy};
+use crate::github::{GitHubClient, GitHubError};
+use crate::models::{
+    Affiliation, BranchProtectionStatus, IssueAction, Repository, RepositoryStatus, ReviewDecision,
+    WorkflowStatus,
+};
+use crate::policy::PolicyFacts;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::sync::mpsc;
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Longest a background task may take before a test fails
+const TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Read a fixture from `tests/fixtures/github`
+fn fixture(name: &str) -> serde_json::Value {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/github")
+        .join(name);
+    let contents = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path.display(), e));
+    serde_json::from_str(&contents).unwrap()
+}
+
+/// GitHub's error body for a status
+fn error_body(message: &str) -> serde_json::Value {
+    serde_json::json!({
+        "message": message,
+        "documentation_url": "https://docs.github.com/rest",
+    })
+}
+
+/// A fake GitHub API serving the fixtures
+struct MockGitHub {
+    server: MockServer,
+}
+
+impl MockGitHub {
+    /// Start a server answering every request the repository fetch makes
+    async fn start() -> Self {
+        let mock = Self {
+            server: MockServer::start().await,
+        };
+        mock.respond("/user/repos", 200, fixture("user_repos.json"))
+            .await;
+        mock.respond("/repos/octocat/api/pulls", 200, fixture("api_pulls.json"))
+            .await;
+        mock.respond(
+            "/repos/octocat/api/pulls/comments",
+            200,
+            fixture("api_pull_comments.json"),
+        )
+        .await;
+        mock.respond(
+            "/repos/octocat/api/pulls/7/reviews",
+            200,
+            fixture("api_pull_7_reviews.json"),
+        )
+        .await;
+        mock.respond(
+            "/repos/octocat/api/commits",
+            200,
+            fixture("api_commits.json"),
+        )
+        .await;
+        mock.respond(
+            "/repos/octocat/api/actions/runs",
+            200,
+            fixture("api_runs.json"),
+        )
+        .await;
+        mock.respond(
+            "/repos/octocat/api/releases/latest",
+            200,
+            fixture("api_release_latest.json"),
+        )
+        .await;
+        mock.respond(
+            "/repos/octocat/api/code-scanning/alerts",
+            200,
+            serde_json::json!([
+                {"number": 4, "rule": {"severity": "error", "security_severity_level": "high"}},
+                {"number": 2, "rule": {"severity": "warning", "security_severity_level": null}}
+            ]),
+        )
+        .await;
+        mock.respond(
+            "/repos/octocat/api/secret-scanning/alerts",
+            200,
+            serde_json::json!([{
+                "number": 1,
+                "state": "open",
+                "secret_type": "github_personal_access_token"
+            }]),
+        )
+        .await;
+        mock.respond("/repos/octocat/site/pulls", 200, serde_json::json!([]))
+            .await;
+        mock.respond(
+            "/repos/octocat/site/issues",
+            200,
+            serde_json::json!([{
+                "number": 1,
+                "title": "Write the getting started guide",
+                "state": "open",
+                "comments": 0,
+                "created_at": "2026-09-01T10:00:00Z",
+                "closed_at": null
+            }]),
+        )
+        .await;
+        // GitHub answers 409 Conflict for commits of an empty repository
+        mock.respond(
+            "/repos/octocat/site/commits",
+            409,
+            error_body("Git Repository is empty."),
+        )
+        .await;
+        mock.respond(
+            "/repos/octocat/site/actions/runs",
+            200,
+            serde_json::json!({"total_count": 0, "workflow_runs": []}),
+        )
+        .await;
+        for repo in ["api", "site"] {
+            mock.respond(
+                &format!("/repos/octocat/{}/environments", repo),
+                200,
+                serde_json::json!({"total_count": 0, "environments": []}),
+            )
+            .await;
+        }
+
+        // Anything without a fixture (e.g. `.gitattributes`) doesn't exist
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(error_body("Not Found")))
+            .with_priority(u8::MAX)
+            .mount(&mock.server)
+            .await;
+        mock
+    }
+
+    /// Answer GET requests for a route
+    async fn respond(&self, route: &str, status: u16, body: serde_json::Value) {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .respond_with(ResponseTemplate::new(status).set_body_json(body))
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Answer the first GET request for a route, before any mock already mounted
+    async fn respond_once(&self, route: &str, status: u16, body: serde_json::Value) {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .respond_with(ResponseTemplate::new(status).set_body_json(body))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&self.server)
+            .await;
+    }
+
+    fn client(&self) -> GitHubClient {
+        GitHubClient::with_base_uri("test-token".to_string(), &self.server.uri()).unwrap()
+    }
+}
+
+/// Run a user repository fetch to the end, returning every message it sent
+async fn run_background_fetch(client: GitHubClient) -> Vec<BackgroundMessage> {
+    let (sender, receiver) = mpsc::unbounded_channel();
+    GitHubClient::spawn_background_fetch(client, sender, Arc::new(AtomicBool::new(false)));
+    collect_messages(receiver).await
+}
+
+/// Receive messages until the background task finishes
+async fn collect_messages(
+    mut receiver: mpsc::UnboundedReceiver<BackgroundMessage>,
+) -> Vec<BackgroundMessage> {
+    // The task drops its sender once it finishes
+    let mut messages = Vec::new();
+    while let Some(message) = tokio::time::timeout(TIMEOUT, receiver.recv())
+        .await
+        .expect("background fetch timed out")
+    {
+        messages.push(message);
+    }
+    messages
+}
+
+/// Short name of a fetch message, to compare message sequences
+fn kind(message: &BackgroundMessage) -> String {
+    match message {
+        BackgroundMessage::FetchStarted { total } => format!("FetchStarted({})", total),
+        BackgroundMessage::RepositoryFetched {
+            repository,
+            current,
+            ..
+        } => format!("RepositoryFetched({}, {})", repository.name, current),
+        BackgroundMessage::FetchCompleted { repositories } => {
+            format!("FetchCompleted({})", repositories.len())
+        }
+        BackgroundMessage::FetchError { .. } => "FetchError".to_string(),
+        BackgroundMessage::EnhancementStarted { total } => {
+            format!("EnhancementStarted({})", total)
+        }
+        BackgroundMessage::RepositoryEnhanced {
+            repository,
+            current,
+            ..
+        } => format!("RepositoryEnhanced({}, {})", repository.name, current),
+        BackgroundMessage::EnhancementCompleted { repositories } => {
+            format!("EnhancementCompleted({})", repositories.len())
+        }
+        BackgroundMessage::EnhancementPaused { current, .. } => {
+            format!("EnhancementPaused({})", current)
+        }
+        BackgroundMessage::EnhancementResumed => "EnhancementResumed".to_string(),
+        _ => "Other".to_string(),
+    }
+}
+
+fn kinds(messages: &[BackgroundMessage]) -> Vec<String> {
+    messages.iter().map(kind).collect()
+}
+
+#[tokio::test]
+async fn test_background_fetch_end_to_end() {
+    let github = MockGitHub::start().await;
+    let messages = run_background_fetch(github.client()).await;
+
+    assert_eq!(
+        kinds(&messages),
+        vec![
+            "FetchStarted(2)",
+            "RepositoryFetched(api, 1)",
+            "RepositoryFetched(site, 2)",
+            "FetchCompleted(2)",
+            "EnhancementStarted(2)",
+            "RepositoryEnhanced(api, 1)",
+            "RepositoryEnhanced(site, 2)",
+            "EnhancementCompleted(2)",
+        ]
+    );
+
+    // The basic list only has what `/user/repos` returns
+    let Some(BackgroundMessage::FetchCompleted { repositories }) = messages.get(3) else {
+        panic!("expected FetchCompleted");
+    };
+    assert_eq!(repositories[0].full_name(), "octocat/api");
+    assert_eq!(repositories[0].language.as_deref(), Some("Rust"));
+    assert_eq!(repositories[0].topics, vec!["service", "payments"]);
+    assert_eq!(
+        repositories[0].created_at,
+        Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_709_283_600))
+    );
+    assert!(repositories[0].open_pull_requests.is_empty());
+
+    let Some(BackgroundMessage::EnhancementCompleted { repositories }) = messages.last() else {
+        panic!("expected EnhancementCompleted");
+    };
+    let api = &repositories[0];
+    assert_eq!(api.open_pull_requests.len(), 1);
+    assert_eq!(api.open_pull_requests[0].number, 7);
+    assert_eq!(api.open_pull_requests[0].author, "hubot");
+    assert_eq!(api.open_pull_requests[0].base_branch, "main");
+    assert_eq!(api.default_branch, "main");
+    assert_eq!(api.open_pull_requests[0].linked_issues, vec![3]);
+    // octocat approved after requesting changes
+    assert_eq!(api.open_pull_requests[0].approvals, 1);
+    assert_eq!(api.open_pull_requests[0].changes_requested, 0);
+    // hubot answered the first review comment but not the second
+    assert_eq!(
+        api.open_pull_requests[0].feedback_waiting_since,
+        Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_791_908_400))
+    );
+    assert_eq!(
+        api.recent_commit_subjects,
+        vec![
+            "fix: handle empty webhook payloads",
+            "feat: add refund endpoint"
+        ]
+    );
+    assert!(api.latest_commit_at.is_some());
+    assert_ne!(api.status, RepositoryStatus::Unknown);
+    assert_eq!(api.recent_workflows.len(), 2);
+    assert_eq!(
+        api.recent_workflows[0].trigger.label(),
+        "push · main · @octocat"
+    );
+    assert!(api.recent_workflows[1].trigger.from_fork);
+    assert_eq!(
+        api.recent_workflows[1].trigger.label(),
+        "pull_request from fork · patch-1 · @alice"
+    );
+    assert_eq!(
+        api.latest_workflow.as_ref().map(|run| run.status.clone()),
+        Some(WorkflowStatus::Failed)
+    );
+    assert_eq!(
+        api.latest_workflow.as_ref().and_then(|run| run.duration),
+        Some(Duration::from_secs(300))
+    );
+    assert_eq!(api.uses_lfs, Some(false));
+    let release = api.latest_release.as_ref().unwrap();
+    assert_eq!(
+        (release.name.as_str(), release.tag.as_str()),
+        ("Refunds", "v1.4.0")
+    );
+    assert_eq!(
+        release.published_at,
+        SystemTime::UNIX_EPOCH + Duration::from_secs(1_790_848_800)
+    );
+    let alerts = api.code_scanning.unwrap();
+    assert_eq!((alerts.high, alerts.medium, alerts.total()), (1, 1, 2));
+    assert!(api.has_leaked_secrets());
+
+    let site = &repositories[1];
+    assert!(site.open_pull_requests.is_empty());
+    // Without any release, GitHub answers 404
+    assert!(site.latest_release.is_none());
+    // api's only open "issue" is its pull request, so only site's are fetched
+    assert_eq!(api.issue_metrics.map(|metrics| metrics.open), Some(0));
+    let metrics = site.issue_metrics.unwrap();
+    assert_eq!((metrics.open, metrics.unanswered), (1, 1));
+    assert_eq!(
+        metrics.oldest_opened_at,
+        Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_788_256_800))
+    );
+    assert!(site.latest_commit_at.is_none());
+    assert!(site.recent_workflows.is_empty());
+    assert!(site.empty);
+    assert_eq!(site.status, RepositoryStatus::Empty);
+
+    // Nothing but issues is looked for in an empty repository
+    let requests = github.server.received_requests().await.unwrap();
+    for route in [
+        "/repos/octocat/site/pulls",
+        "/repos/octocat/site/actions/runs",
+        "/repos/octocat/site/releases/latest",
+        "/repos/octocat/site/environments",
+        "/repos/octocat/site/code-scanning/alerts",
+        "/repos/octocat/site/secret-scanning/alerts",
+    ] {
+        assert!(
+            !requests.iter().any(|request| request.url.path() == route),
+            "{} was requested",
+            route
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_background_fetch_reports_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user/repos"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(error_body("Bad credentials")))
+        .mount(&server)
+        .await;
+    let client = GitHubClient::with_base_uri("expired".to_string(), &server.uri()).unwrap();
+
+    let messages = run_background_fetch(client).await;
+    assert_eq!(kinds(&messages), vec!["FetchError"]);
+    let BackgroundMessage::FetchError { error } = &messages[0] else {
+        unreachable!();
+    };
+    assert!(
+        error.starts_with("Failed to fetch repositories"),
+        "{}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn test_background_fetch_resumes_after_rate_limit() {
+    let github = MockGitHub::start().await;
+    github
+        .respond_once(
+            "/repos/octocat/site/commits",
+            403,
+            error_body("API rate limit exceeded for user ID 583231."),
+        )
+        .await;
+    // The core limit is exhausted and resets now, so the pause is short
+    let reset = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let rate = serde_json::json!({"limit": 5000, "used": 5000, "remaining": 0, "reset": reset});
+    github
+        .respond(
+            "/rate_limit",
+            200,
+            serde_json::json!({
+                "resources": {"core": rate, "search": rate},
+                "rate": rate,
+            }),
+        )
+        .await;
+
+    let messages = run_background_fetch(github.client()).await;
+    assert_eq!(
+        kinds(&messages)[4..],
+        [
+            "EnhancementStarted(2)",
+            "RepositoryEnhanced(api, 1)",
+            "EnhancementPaused(2)",
+            "EnhancementResumed",
+            "RepositoryEnhanced(site, 2)",
+            "EnhancementCompleted(2)",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_refresh_key_checks_the_api_budget() {
+    let github = MockGitHub::start().await;
+    let reset = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let rate = serde_json::json!({"limit": 5000, "used": 4997, "remaining": 3, "reset": reset});
+    github
+        .respond(
+            "/rate_limit",
+            200,
+            serde_json::json!({"resources": {"core": rate, "search": rate}, "rate": rate}),
+        )
+        .await;
+    let mut app = App::with_config(AppConfig::default());
+    app.github_client = Some(github.client());
+    app.set_repositories(vec![Repository::new(
+        "api".to_string(),
+        "octocat".to_string(),
+    )]);
+    app.current_view = AppView::RepoDetails;
+
+    // Pressed in a view without a refresh of its own, as the main loop reads it
+    let key = ratatui::crossterm::event::KeyEvent::new(
+        ratatui::crossterm::event::KeyCode::Char('r'),
+        ratatui::crossterm::event::KeyModifiers::NONE,
+    );
+    app.handle_terminal_event(crate::events::AppEvent::Key(key));
+    assert_eq!(
+        app.status_message.as_deref(),
+        Some("Checking API budget...")
+    );
+
+    let started = std::time::Instant::now();
+    while app.menu.is_none() {
+        assert!(started.elapsed() < TIMEOUT, "no lite refresh offered");
+        app.process_background_messages();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert!(app
+        .menu
+        .as_ref()
+        .unwrap()
+        .title
+        .starts_with("Refresh needs ~"));
+    assert!(!app.is_loading);
+}
+
+#[tokio::test]
+async fn test_background_fetch_pauses_before_quota_runs_out() {
+    let github = MockGitHub::start().await;
+    let reset = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let rate_limit = |remaining: u64| {
+        let rate = serde_json::json!({
+            "limit": 5000,
+            "used": 5000 - remaining,
+            "remaining": remaining,
+            "reset": reset,
+        });
+        serde_json::json!({"resources": {"core": rate, "search": rate}, "rate": rate})
+    };
+    // Enough quota for the first repository; confirmed low before the second
+    github.respond("/rate_limit", 200, rate_limit(40)).await;
+    github
+        .respond_once("/rate_limit", 200, rate_limit(52))
+        .await;
+
+    let client = github.client().with_rate_limit_reserve(50);
+    let messages = run_background_fetch(client.clone()).await;
+    assert_eq!(
+        kinds(&messages)[4..],
+        [
+            "EnhancementStarted(2)",
+            "RepositoryEnhanced(api, 1)",
+            "EnhancementPaused(2)",
+            "EnhancementResumed",
+            "RepositoryEnhanced(site, 2)",
+            "EnhancementCompleted(2)",
+        ]
+    );
+    // The quota is counted down by every request made since it was read
+    let remaining = client.rate_limit().unwrap().remaining;
+    assert!((1..40).contains(&remaining), "{}", remaining);
+}
+
+#[tokio::test]
+async fn test_refresh_reuses_unchanged_responses() {
+    let github = MockGitHub::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/octocat/api/pulls"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"pulls-v1\"")
+                .set_body_json(fixture("api_pulls.json")),
+        )
+        .with_priority(2)
+        .mount(&github.server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/octocat/api/pulls"))
+        .and(header("if-none-match", "\"pulls-v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .with_priority(1)
+        .mount(&github.server)
+        .await;
+
+    let client = github.client();
+    let enhanced_api = |messages: Vec<BackgroundMessage>| {
+        messages.into_iter().find_map(|message| match message {
+            BackgroundMessage::RepositoryEnhanced { repository, .. }
+                if repository.name == "api" =>
+            {
+                Some(repository)
+            }
+            _ => None,
+        })
+    };
+    let first = enhanced_api(run_background_fetch(client.clone()).await).unwrap();
+    let second = enhanced_api(run_background_fetch(client.clone()).await).unwrap();
+
+    let numbers = |repo: &Repository| -> Vec<u32> {
+        repo.open_pull_requests.iter().map(|pr| pr.number).collect()
+    };
+    assert!(!numbers(&first).is_empty());
+    assert_eq!(numbers(&second), numbers(&first));
+    let pulls = client
+        .metrics()
+        .snapshot()
+        .into_iter()
+        .find(|(endpoint, _)| *endpoint == "pulls")
+        .unwrap()
+        .1;
+    // `api` twice, revalidated the second time; `site` is empty
+    assert_eq!((pulls.requests, pulls.not_modified), (2, 1));
+}
+
+#[tokio::test]
+async fn test_background_fetch_uses_page_sizes() {
+    let github = MockGitHub::start().await;
+    let client = github
+        .client()
+        .with_page_sizes(crate::config::ApiProfile::Lean.page_sizes());
+    let metrics = client.metrics();
+    run_background_fetch(client).await;
+
+    let requests = github.server.received_requests().await.unwrap();
+    let per_page = |route: &str| {
+        requests
+            .iter()
+            .find(|request| request.url.path() == route)
+            .and_then(|request| {
+                request
+                    .url
+                    .query_pairs()
+                    .find(|(key, _)| key == "per_page")
+                    .map(|(_, value)| value.to_string())
+            })
+    };
+    assert_eq!(per_page("/repos/octocat/api/pulls").as_deref(), Some("30"));
+    assert_eq!(per_page("/repos/octocat/api/commits").as_deref(), Some("1"));
+    assert_eq!(
+        per_page("/repos/octocat/api/actions/runs").as_deref(),
+        Some("10")
+    );
+    // Every request the fetch made is counted
+    assert_eq!(metrics.totals().0, requests.len());
+}
+
+#[tokio::test]
+async fn test_slow_repository_is_marked_timed_out() {
+    let github = MockGitHub::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/octocat/api/commits"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(fixture("api_commits.json"))
+                .set_delay(Duration::from_secs(5)),
+        )
+        .with_priority(1)
+        .mount(&github.server)
+        .await;
+    let client = github
+        .client()
+        .with_job_timeout(Some(Duration::from_millis(300)));
+    let messages = run_background_fetch(client).await;
+
+    // The pass moves on instead of waiting for the hung request
+    let Some(BackgroundMessage::EnhancementCompleted { repositories }) = messages.last() else {
+        panic!("expected EnhancementCompleted");
+    };
+    assert!(repositories[0].fetch_timed_out);
+    assert!(repositories[0].latest_commit_at.is_none());
+    assert!(!repositories[1].fetch_timed_out);
+}
+
+#[tokio::test]
+async fn test_held_client_waits_before_enhancing() {
+    let github = MockGitHub::start().await;
+    let client = github.client();
+    client.hold(true);
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    GitHubClient::spawn_background_fetch(client.clone(), sender, Arc::new(AtomicBool::new(false)));
+
+    // The list still arrives, but no repository is enhanced while held
+    loop {
+        match tokio::time::timeout(TIMEOUT, receiver.recv())
+            .await
+            .unwrap()
+        {
+            Some(BackgroundMessage::EnhancementStarted { .. }) => break,
+            Some(_) => {}
+            None => panic!("fetch ended before enhancing"),
+        }
+    }
+    let held = tokio::time::timeout(Duration::from_millis(600), receiver.recv()).await;
+    assert!(held.is_err(), "enhanced while held");
+
+    client.hold(false);
+    let messages = collect_messages(receiver).await;
+    assert!(matches!(
+        messages.last(),
+        Some(BackgroundMessage::EnhancementCompleted { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_review_states_of_several_pull_requests_are_batched() {
+    let github = MockGitHub::start().await;
+    let mut pulls = fixture("api_pulls.json");
+    let mut second = pulls[0].clone();
+    second["number"] = serde_json::json!(8);
+    pulls.as_array_mut().unwrap().push(second);
+    github
+        .respond_once("/repos/octocat/api/pulls", 200, pulls)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": { "repository": {
+                "p7": {
+                    "reviewDecision": "REVIEW_REQUIRED",
+                    "latestOpinionatedReviews": { "nodes": [
+                        { "state": "APPROVED", "author": { "login": "octocat" } }
+                    ]},
+                    "commits": { "nodes": [
+                        { "commit": { "statusCheckRollup": { "state": "FAILURE" } } }
+                    ]}
+                },
+                "p8": {
+                    "reviewDecision": null,
+                    "latestOpinionatedReviews": { "nodes": [] },
+                    "commits": { "nodes": [{ "commit": { "statusCheckRollup": null } }] }
+                }
+            }}
+        })))
+        .expect(1)
+        .mount(&github.server)
+        .await;
+
+    let messages = run_background_fetch(github.client()).await;
+    let Some(BackgroundMessage::EnhancementCompleted { repositories }) = messages.last() else {
+        panic!("expected EnhancementCompleted");
+    };
+    let pulls = &repositories[0].open_pull_requests;
+    assert_eq!(pulls[0].approvals, 1);
+    assert_eq!(
+        pulls[0].review_decision,
+        Some(ReviewDecision::ReviewRequired)
+    );
+    assert_eq!(pulls[0].checks, Some(WorkflowStatus::Failed));
+    assert_eq!(
+        (pulls[1].review_decision, pulls[1].checks.clone()),
+        (None, None)
+    );
+
+    // One query instead of a REST request per pull request
+    let requests = github.server.received_requests().await.unwrap();
+    assert!(!requests
+        .iter()
+        .any(|request| request.url.path().ends_with("/reviews")));
+}
+
+#[tokio::test]
+async fn test_truncated_pull_request_list_is_counted_with_search() {
+    let github = MockGitHub::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/issues"))
+        .and(query_param("q", "repo:octocat/api is:pr is:open"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "total_count": 173,
+            "incomplete_results": false,
+            "items": []
+        })))
+        .mount(&github.server)
+        .await;
+    let client = github.client().with_page_sizes(crate::config::PageSizes {
+        pull_requests: 1,
+        ..Default::default()
+    });
+    let messages = run_background_fetch(client).await;
+
+    let Some(BackgroundMessage::EnhancementCompleted { repositories }) = messages.last() else {
+        panic!("expected EnhancementCompleted");
+    };
+    let api = &repositories[0];
+    assert_eq!(api.open_pull_requests.len(), 1);
+    assert_eq!(api.open_pull_request_count(), 173);
+    // A list that fits in one page needs no search
+    assert_eq!(repositories[1].open_pull_request_total, None);
+}
+
+#[tokio::test]
+async fn test_issue_search_reads_each_issues_repository() {
+    let github = MockGitHub::start().await;
+    let query = "is:issue is:open label:\"bug\" repo:octocat/api repo:octocat/site";
+    Mock::given(method("GET"))
+        .and(path("/search/issues"))
+        .and(query_param("q", query))
+        .and(query_param("sort", "created"))
+        .and(query_param("order", "asc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "total_count": 140,
+            "incomplete_results": false,
+            "items": [fixture("api_issue.json")]
+        })))
+        .expect(1)
+        .mount(&github.server)
+        .await;
+
+    let (issues, total) = github
+        .client()
+        .search_issues(&[query.to_string()])
+        .await
+        .unwrap();
+    assert_eq!(total, 140);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].repository, "octocat/api");
+    assert_eq!(issues[0].issue.number, 3);
+    assert_eq!(issues[0].issue.labels, vec!["bug"]);
+}
+
+#[tokio::test]
+async fn test_merge_requirements_with_and_without_protection() {
+    let github = MockGitHub::start().await;
+    github
+        .respond(
+            "/repos/octocat/api/pulls/7",
+            200,
+            serde_json::json!({
+                "number": 7,
+                "mergeable": false,
+                "mergeable_state": "dirty",
+                "base": {"ref": "main"}
+            }),
+        )
+        .await;
+    github
+        .respond(
+            "/repos/octocat/api/branches/main/protection",
+            200,
+            serde_json::json!({
+                "required_status_checks": {"strict": true, "contexts": ["build"]},
+                "required_pull_request_reviews": {
+                    "required_approving_review_count": 2,
+                    "require_code_owner_reviews": true
+                }
+            }),
+        )
+        .await;
+    github
+        .respond(
+            "/repos/octocat/site/pulls/1",
+            200,
+            serde_json::json!({"number": 1, "mergeable": null, "base": {"ref": "gh-pages"}}),
+        )
+        .await;
+    let client = github.client();
+
+    let requirements = client
+        .fetch_merge_requirements("octocat", "api", 7)
+        .await
+        .unwrap();
+    assert_eq!(requirements.mergeable, Some(false));
+    assert_eq!(requirements.mergeable_state, "dirty");
+    let protection = requirements.protection.unwrap();
+    assert_eq!(protection.required_approvals, 2);
+    assert!(protection.code_owner_reviews && protection.strict);
+    assert_eq!(protection.required_checks, vec!["build"]);
+
+    // Protection the token can't read (404) is left out
+    let requirements = client
+        .fetch_merge_requirements("octocat", "site", 1)
+        .await
+        .unwrap();
+    assert_eq!(requirements.base_branch, "gh-pages");
+    assert_eq!(requirements.mergeable, None);
+    assert!(requirements.protection.is_none());
+}
+
+#[tokio::test]
+async fn test_issue_triage_updates_and_rereads_the_issue() {
+    let github = MockGitHub::start().await;
+    github
+        .respond(
+            "/repos/octocat/api/issues/3",
+            200,
+            fixture("api_issue.json"),
+        )
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/repos/octocat/api/issues/3/assignees"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(fixture("api_issue.json")))
+        .expect(1)
+        .mount(&github.server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/repos/octocat/api/issues/3/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&github.server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/repos/octocat/api/issues/3/labels/triage"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&github.server)
+        .await;
+    let client = github.client();
+
+    let issue = client
+        .triage_issue(
+            "octocat",
+            "api",
+            3,
+            &IssueAction::Assign("hubot".to_string()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(issue.assignees, vec!["hubot"]);
+    assert_eq!(issue.labels, vec!["bug"]);
+    assert_eq!(issue.comments, 2);
+
+    let (sender, receiver) = mpsc::unbounded_channel();
+    let action = IssueAction::Label {
+        add: vec!["bug".to_string()],
+        remove: vec!["triage".to_string()],
+    };
+    GitHubClient::spawn_issue_triage(
+        client,
+        sender,
+        "octocat".to_string(),
+        "api".to_string(),
+        3,
+        action.clone(),
+    );
+    let messages = collect_messages(receiver).await;
+    assert!(matches!(
+        &messages[..],
+        [BackgroundMessage::IssueTriaged { repository, issue, action: sent }]
+            if repository == "octocat/api" && issue.number == 3 && *sent == action
+    ));
+}
+
+#[tokio::test]
+async fn test_audit_log_searches_each_health_action() {
+    let github = MockGitHub::start().await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/acme/audit-log"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .with_priority(2)
+        .mount(&github.server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/acme/audit-log"))
+        .and(query_param(
+            "phrase",
+            "action:protected_branch.destroy created:>=2026-09-16",
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "@timestamp": 1760000000000_i64,
+                "action": "protected_branch.destroy",
+                "actor": "mallory",
+                "repo": "acme/api",
+                "branch": "main"
+            }])),
+        )
+        .with_priority(1)
+        .mount(&github.server)
+        .await;
+
+    let client = github.client();
+    let since = chrono::DateTime::parse_from_rfc3339("2026-09-16T00:00:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+    let audit_log = client.fetch_org_audit_log("acme", since).await.unwrap();
+    let events = audit_log.events.unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].target, "acme/api (main)");
+    assert_eq!(
+        github.server.received_requests().await.unwrap().len(),
+        crate::audit::AUDIT_ACTIONS.len()
+    );
+
+    // Organizations without audit log access have no feed
+    let hidden = client.fetch_org_audit_log("octocat", since).await.unwrap();
+    assert_eq!(hidden.events, None);
+}
+
+#[tokio::test]
+async fn test_transient_failures_are_retried() {
+    let github = MockGitHub::start().await;
+    github
+        .respond("/orgs/acme/audit-log", 200, serde_json::json!([]))
+        .await;
+    github
+        .respond_once(
+            "/orgs/acme/audit-log",
+            502,
+            serde_json::json!({ "message": "Bad Gateway" }),
+        )
+        .await;
+    let since = chrono::Utc::now();
+
+    let client = github.client().with_retry_policy(RetryPolicy {
+        retries: 2,
+        base_delay: Duration::from_millis(10),
+    });
+    let audit_log = client.fetch_org_audit_log("acme", since).await.unwrap();
+    assert_eq!(audit_log.events, Some(Vec::new()));
+    assert_eq!(
+        github.server.received_requests().await.unwrap().len(),
+        crate::audit::AUDIT_ACTIONS.len() + 1
+    );
+
+    // A missing resource fails the same way every time, so isn't retried
+    github.server.reset().await;
+    github
+        .respond(
+            "/orgs/octocat/audit-log",
+            404,
+            serde_json::json!({ "message": "Not Found" }),
+        )
+        .await;
+    let hidden = client.fetch_org_audit_log("octocat", since).await.unwrap();
+    assert_eq!(hidden.events, None);
+    assert_eq!(github.server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_community_profile_is_recorded() {
+    let github = MockGitHub::start().await;
+    github
+        .respond(
+            "/repos/octocat/api/community/profile",
+            200,
+            serde_json::json!({
+                "health_percentage": 71,
+                "files": {
+                    "readme": {"url": "https://api.github.com/repos/octocat/api/readme"},
+                    "license": {"spdx_id": "MIT"},
+                    "contributing": null,
+                    "code_of_conduct": null,
+                    "code_of_conduct_file": null,
+                    "issue_template": {"name": "Bug report"},
+                    "pull_request_template": null
+                }
+            }),
+        )
+        .await;
+    let messages = run_background_fetch(github.client()).await;
+
+    let Some(BackgroundMessage::EnhancementCompleted { repositories }) = messages.last() else {
+        panic!("expected EnhancementCompleted");
+    };
+    let profile = repositories[0].community.unwrap();
+    assert_eq!(profile.label(), "71%");
+    assert_eq!(profile.missing(), vec!["CONTRIBUTING", "Code of conduct"]);
+    // No profile to read
+    assert_eq!(repositories[1].community, None);
+}
+
+#[tokio::test]
+async fn test_bus_factor_from_contributor_statistics() {
+    let github = MockGitHub::start().await;
+    let week = |days_ago: i64| (chrono::Utc::now() - chrono::Duration::days(days_ago)).timestamp();
+    github
+        .respond(
+            "/repos/octocat/api/stats/contributors",
+            200,
+            serde_json::json!([
+                {"author": {"login": "octocat"}, "total": 30, "weeks": [
+                    {"w": week(200), "a": 0, "d": 0, "c": 0},
+                    {"w": week(7), "a": 120, "d": 40, "c": 30}
+                ]},
+                {"author": {"login": "hubot"}, "total": 52, "weeks": [
+                    {"w": week(200), "a": 900, "d": 10, "c": 50},
+                    {"w": week(7), "a": 4, "d": 1, "c": 2}
+                ]},
+                // A deleted account
+                {"author": null, "total": 9, "weeks": [{"w": week(7), "a": 1, "d": 1, "c": 9}]}
+            ]),
+        )
+        .await;
+    let messages = run_background_fetch(github.client()).await;
+
+    let Some(BackgroundMessage::EnhancementCompleted { repositories }) = messages.last() else {
+        panic!("expected EnhancementCompleted");
+    };
+    // hubot's older commits fall outside the 90 days
+    let bus_factor = repositories[0].bus_factor.as_ref().unwrap();
+    assert_eq!((bus_factor.top_commits, bus_factor.commits), (30, 32));
+    assert_eq!(bus_factor.label(), "93% by octocat");
+    assert!(bus_factor.is_risk());
+    // An empty repository isn't asked for statistics
+    assert_eq!(repositories[1].bus_factor, None);
+}
+
+#[tokio::test]
+async fn test_commit_activity_refines_status() {
+    let github = MockGitHub::start().await;
+    let mut weeks: Vec<serde_json::Value> = (0..52)
+        .map(|_| serde_json::json!({"total": 0, "week": 0, "days": [0, 0, 0, 0, 0, 0, 0]}))
+        .collect();
+    weeks[10]["total"] = serde_json::json!(40);
+    weeks[51]["total"] = serde_json::json!(1);
+    github
+        .respond(
+            "/repos/octocat/api/stats/commit_activity",
+            200,
+            serde_json::Value::Array(weeks),
+        )
+        .await;
+    let messages = run_background_fetch(github.client()).await;
+
+    let Some(BackgroundMessage::EnhancementCompleted { repositories }) = messages.last() else {
+        panic!("expected EnhancementCompleted");
+    };
+    let activity = repositories[0].commit_activity.as_ref().unwrap();
+    assert_eq!((activity.weeks.len(), activity.recent(52)), (52, 41));
+    assert_eq!(activity.sparkline(3), "▁▁█");
+    // A lone recent commit isn't sustained activity
+    assert!(!matches!(
+        repositories[0].status,
+        RepositoryStatus::Hot | RepositoryStatus::Active
+    ));
+    // An empty repository isn't asked for statistics
+    assert_eq!(repositories[1].commit_activity, None);
+}
+
+#[tokio::test]
+async fn test_default_branch_protection_is_audited() {
+    let github = MockGitHub::start().await;
+    github
+        .respond(
+            "/repos/octocat/api/branches/main",
+            200,
+            serde_json::json!({"name": "main", "protected": true}),
+        )
+        .await;
+    let messages = run_background_fetch(github.client()).await;
+
+    let Some(BackgroundMessage::EnhancementCompleted { repositories }) = messages.last() else {
+        panic!("expected EnhancementCompleted");
+    };
+    // Without admin access the rules aren't asked for
+    assert_eq!(
+        repositories[0].branch_protection,
+        Some(BranchProtectionStatus::Protected(None))
+    );
+    let requests = github.server.received_requests().await.unwrap();
+    assert!(!requests
+        .iter()
+        .any(|request| request.url.path().ends_with("/protection")));
+    // An empty repository has no branch to protect
+    assert_eq!(repositories[1].branch_protection, None);
+}
+
+#[tokio::test]
+async fn test_policy_scan_reads_branch_protection_and_codeowners() {
+    let github = MockGitHub::start().await;
+    github
+        .respond(
+            "/repos/octocat/api",
+            200,
+            serde_json::json!({"default_branch": "main"}),
+        )
+        .await;
+    github
+        .respond(
+            "/repos/octocat/api/branches/main",
+            200,
+            serde_json::json!({"name": "main", "protected": true}),
+        )
+        .await;
+    github
+        .respond(
+            "/repos/octocat/api/contents/CODEOWNERS",
+            200,
+            serde_json::json!({
+                "type": "file",
+                "encoding": "base64",
+                "size": 20,
+                "name": "CODEOWNERS",
+                "path": "CODEOWNERS",
+                "content": "KiBAYWNtZS9iYWNrZW5kCg==",
+                "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+                "url": "https://api.github.com/repos/octocat/api/contents/CODEOWNERS",
+                "git_url": null,
+                "html_url": null,
+                "download_url": null,
+                "_links": {"self": "https://api.github.com/repos/octocat/api/contents/CODEOWNERS"}
+            }),
+        )
+        .await;
+    // An empty repository has no default branch to protect
+    github
+        .respond(
+            "/repos/octocat/site",
+            200,
+            serde_json::json!({"default_branch": null}),
+        )
+        .await;
+
+    let facts = github
+        .client()
+        .scan_policy_facts(&[
+            ("octocat".to_string(), "api".to_string()),
+            ("octocat".to_string(), "site".to_string()),
+        ])
+        .await
+        .unwrap();
+    assert_eq!(
+        facts["octocat/api"],
+        PolicyFacts {
+            default_branch_protected: Some(true),
+            has_codeowners: true,
+        }
+    );
+    assert_eq!(facts["octocat/site"], PolicyFacts::default());
+}
+
+/// Start an HTTP CONNECT proxy, returning its URL and the targets it tunneled to
+async fn start_connect_proxy() -> (String, Arc<std::sync::Mutex<Vec<String>>>) {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let targets = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let seen = targets.clone();
+    tokio::spawn(async move {
+        while let Ok((client, _)) = listener.accept().await {
+            let seen = seen.clone();
+            tokio::spawn(async move {
+                let mut client = BufReader::new(client);
+                let mut request_line = String::new();
+                client.read_line(&mut request_line).await.unwrap();
+                // Skip the rest of the CONNECT request head
+                let mut header = String::new();
+                while client.read_line(&mut header).await.unwrap() > 2 {
+                    header.clear();
+                }
+                let target = request_line.split_whitespace().nth(1).unwrap().to_string();
+                seen.lock().unwrap().push(target.clone());
+
+                let mut upstream = tokio::net::TcpStream::connect(&target).await.unwrap();
+                let mut client = client.into_inner();
+                client
+                    .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+                    .await
+                    .unwrap();
+                let _ = tokio::io::copy_bidirectional(&mut client, &mut upstream).await;
+            });
+        }
+    });
+    (url, targets)
+}
+
+#[tokio::test]
+async fn test_requests_tunnel_through_configured_proxy() {
+    let github = MockGitHub::start().await;
+    let (proxy, targets) = start_connect_proxy().await;
+    let network = crate::config::NetworkConfig {
+        proxy: Some(proxy),
+        ..Default::default()
+    };
+    let client =
+        GitHubClient::with_network("test-token".to_string(), &github.server.uri(), &network)
+            .unwrap();
+
+    let messages = run_background_fetch(client).await;
+    assert!(kinds(&messages).contains(&"FetchCompleted(2)".to_string()));
+    let address = github.server.address().to_string();
+    assert!(targets.lock().unwrap().contains(&address));
+    // The token still reaches GitHub through the tunnel
+    let requests = github.server.received_requests().await.unwrap();
+    assert_eq!(
+        requests[0].headers.get("authorization").unwrap(),
+        "Bearer test-token"
+    );
+}
+
+#[tokio::test]
+async fn test_contribution_calendar_query() {
+    let github = MockGitHub::start().await;
+    let day =
+        |date: &str, count: u32| serde_json::json!({"date": date, "contributionCount": count});
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": {"viewer": {
+                "login": "octocat",
+                "contributionsCollection": {"contributionCalendar": {
+                    "totalContributions": 5,
+                    "weeks": [
+                        {"contributionDays": [day("2026-03-06", 0), day("2026-03-07", 2)]},
+                        {"contributionDays": [day("2026-03-08", 3)]},
+                    ],
+                }},
+            }},
+        })))
+        .mount(&github.server)
+        .await;
+
+    let calendar = github.client().fetch_contribution_calendar().await.unwrap();
+    assert_eq!(calendar.login, "octocat");
+    assert_eq!(calendar.total, 5);
+    assert_eq!(calendar.weeks.len(), 2);
+    assert_eq!(calendar.longest_streak(), 2);
+}
+
+#[tokio::test]
+async fn test_graphql_backend_batches_details() {
+    let github = MockGitHub::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("graphql_details.json")))
+        .expect(1)
+        .mount(&github.server)
+        .await;
+    let client = github.client().with_backend(ApiBackend::Graphql);
+    let messages = run_background_fetch(client).await;
+
+    let Some(BackgroundMessage::EnhancementCompleted { repositories }) = messages.last() else {
+        panic!("expected EnhancementCompleted");
+    };
+    let api = &repositories[0];
+    let pr = &api.open_pull_requests[0];
+    assert_eq!((pr.number, pr.author.as_str()), (12, "hubot"));
+    assert_eq!(pr.base_branch, "main");
+    assert_eq!((pr.approvals, pr.changes_requested), (1, 1));
+    assert_eq!(pr.linked_issues, vec![3]);
+    assert_eq!(pr.labels, vec!["v2.0", "enhancement"]);
+    assert_eq!(pr.milestone.as_deref(), Some("v2.0"));
+    assert_eq!(
+        api.recent_commit_subjects,
+        vec!["feat: retry webhook deliveries", "docs: describe retries"]
+    );
+    // Workflow runs come from check suites GitHub Actions created
+    assert_eq!(api.recent_workflows.len(), 2);
+    let run = api.latest_workflow.as_ref().unwrap();
+    assert_eq!((run.id, run.name.as_str()), (30433700, "CI"));
+    assert_eq!(run.status, WorkflowStatus::Failed);
+    assert_eq!(run.trigger.label(), "push · main");
+    assert_eq!(api.latest_release.as_ref().unwrap().name, "v2.0.0");
+
+    // REST is left for what GraphQL doesn't cover and the repository it
+    // couldn't read
+    let requests = github.server.received_requests().await.unwrap();
+    let requested = |route: &str| requests.iter().any(|request| request.url.path() == route);
+    for route in [
+        "/repos/octocat/api/pulls",
+        "/repos/octocat/api/pulls/12/reviews",
+        "/repos/octocat/api/commits",
+        "/repos/octocat/api/actions/runs",
+        "/repos/octocat/api/releases/latest",
+    ] {
+        assert!(!requested(route), "{} was requested", route);
+    }
+    assert!(requested("/repos/octocat/api/pulls/comments"));
+    assert!(requested("/repos/octocat/site/commits"));
+
+    let graphql = requests
+        .iter()
+        .find(|request| request.url.path() == "/graphql")
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&graphql.body).unwrap();
+    let query = body["query"].as_str().unwrap();
+    assert!(query.contains(r#"r1: repository(owner: "octocat", name: "site")"#));
+}
+
+#[tokio::test]
+async fn test_failure_clustering_reads_job_logs() {
+    let github = MockGitHub::start().await;
+    let messages = run_background_fetch(github.client()).await;
+    let Some(BackgroundMessage::EnhancementCompleted { repositories }) = messages.last() else {
+        panic!("expected EnhancementCompleted");
+    };
+
+    let job = |id: u64, name: &str, conclusion: &str| {
+        serde_json::json!({
+            "id": id,
+            "name": name,
+            "conclusion": conclusion,
+            "html_url": format!("https://github.com/octocat/api/actions/runs/30433642/job/{}", id),
+        })
+    };
+    github
+        .respond(
+            "/repos/octocat/api/actions/runs/30433642/jobs",
+            200,
+            serde_json::json!({
+                "total_count": 4,
+                "jobs": [
+                    job(1, "lint", "success"),
+                    job(2, "test (stable)", "failure"),
+                    job(3, "test (beta)", "failure"),
+                    job(4, "deploy", "failure"),
+                ],
+            }),
+        )
+        .await;
+    // Job logs redirect to a download URL
+    let log = "2026-03-01T10:00:06.1234567Z thread 'refund' panicked at src/api.rs:42:9:\n\
+               2026-03-01T10:00:06.1234567Z ##[error]Process completed with exit code 101.";
+    for id in [2, 3] {
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/octocat/api/actions/jobs/{}/logs", id)))
+            .respond_with(ResponseTemplate::new(302).insert_header(
+                "Location",
+                format!("{}/logs/{}.txt", github.server.uri(), id),
+            ))
+            .mount(&github.server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/logs/{}.txt", id)))
+            .respond_with(ResponseTemplate::new(200).set_body_string(log))
+            .mount(&github.server)
+            .await;
+    }
+    // Logs past their retention period are gone
+    github
+        .respond(
+            "/repos/octocat/api/actions/jobs/4/logs",
+            410,
+            error_body("Gone"),
+        )
+        .await;
+
+    let clusters = github
+        .client()
+        .cluster_workflow_failures(repositories)
+        .await
+        .unwrap();
+    assert_eq!(clusters.len(), 1);
+    assert_eq!(
+        clusters[0].summary(),
+        "2 failures across 1 repo share \"thread 'refund' panicked at src/api.rs:N:N:\""
+    );
+    assert_eq!(clusters[0].failures[1].job, "test (beta)");
+}
+
+/// Serve a repository listing as two pages of the user repository fixture
+async fn mount_repository_pages(github: &MockGitHub, route: &str) {
+    let page_two = format!("{}{}?page=2&per_page=100", github.server.uri(), route);
+    Mock::given(method("GET"))
+        .and(path(route))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(fixture("user_repos.json"))
+                .insert_header(
+                    "Link",
+                    format!("<{0}>; rel=\"next\", <{0}>; rel=\"last\"", page_two),
+                ),
+        )
+        .with_priority(2)
+        .mount(&github.server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(route))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("user_repos.json")))
+        .with_priority(1)
+        .mount(&github.server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_organization_fetch_streams_pages() {
+    let github = MockGitHub::start().await;
+    mount_repository_pages(&github, "/orgs/acme/repos").await;
+
+    let (sender, receiver) = mpsc::unbounded_channel();
+    let stop = Arc::new(AtomicBool::new(false));
+    GitHubClient::spawn_background_fetch_organization(
+        github.client(),
+        sender,
+        "acme".to_string(),
+        stop,
+    );
+    let messages = collect_messages(receiver).await;
+
+    // The first page is sent before the second is requested, with the
+    // total estimated from the last page link until it arrives
+    assert_eq!(
+        kinds(&messages)[..6],
+        [
+            "FetchStarted(200)",
+            "RepositoryFetched(api, 1)",
+            "RepositoryFetched(site, 2)",
+            "RepositoryFetched(api, 3)",
+            "RepositoryFetched(site, 4)",
+            "FetchCompleted(4)",
+        ]
+    );
+    let Some(BackgroundMessage::RepositoryFetched { total, .. }) = messages.get(3) else {
+        panic!("expected RepositoryFetched");
+    };
+    assert_eq!(*total, 4);
+    assert_eq!(kinds(&messages).last().unwrap(), "EnhancementCompleted(4)");
+}
+
+#[tokio::test]
+async fn test_organization_prefetch_counts_only_its_own_requests() {
+    let github = MockGitHub::start().await;
+    mount_repository_pages(&github, "/orgs/acme/repos").await;
+    let prefetch = |client: GitHubClient| async move {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        GitHubClient::spawn_organization_prefetch(client, sender, "acme".to_string());
+        match tokio::time::timeout(TIMEOUT, receiver.recv())
+            .await
+            .unwrap()
+        {
+            Some(BackgroundMessage::OrganizationPrefetched { requests, .. }) => requests,
+            _ => panic!("expected OrganizationPrefetched"),
+        }
+    };
+
+    let client = github.client();
+    let alone = prefetch(client.clone()).await;
+    assert_eq!(alone, client.metrics().totals().0 as u64);
+
+    // Requests the dashboard makes meanwhile aren't part of its cost
+    let client = github.client();
+    let (during_refresh, _) = tokio::join!(
+        prefetch(client.clone()),
+        run_background_fetch(client.clone())
+    );
+    assert_eq!(during_refresh, alone);
+    assert!(client.metrics().totals().0 as u64 > alone);
+}
+
+#[tokio::test]
+async fn test_organization_fetch_stops_early() {
+    let github = MockGitHub::start().await;
+    mount_repository_pages(&github, "/orgs/acme/repos").await;
+
+    let (sender, receiver) = mpsc::unbounded_channel();
+    let stop = Arc::new(AtomicBool::new(true));
+    GitHubClient::spawn_background_fetch_organization(
+        github.client(),
+        sender,
+        "acme".to_string(),
+        stop,
+    );
+    let messages = collect_messages(receiver).await;
+
+    assert_eq!(
+        kinds(&messages)[..4],
+        [
+            "FetchStarted(200)",
+            "RepositoryFetched(api, 1)",
+            "RepositoryFetched(site, 2)",
+            "FetchCompleted(2)",
+        ]
+    );
+    let requests = github.server.received_requests().await.unwrap();
+    assert_eq!(
+        requests
+            .iter()
+            .filter(|request| request.url.path() == "/orgs/acme/repos")
+            .count(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_personal_fetch_follows_every_page() {
+    let github = MockGitHub::start().await;
+    mount_repository_pages(&github, "/user/repos").await;
+
+    let messages = run_background_fetch(github.client()).await;
+    assert_eq!(
+        kinds(&messages)[..6],
+        [
+            "FetchStarted(200)",
+            "RepositoryFetched(api, 1)",
+            "RepositoryFetched(site, 2)",
+            "RepositoryFetched(api, 3)",
+            "RepositoryFetched(site, 4)",
+            "FetchCompleted(4)",
+        ]
+    );
+    assert_eq!(kinds(&messages).last().unwrap(), "EnhancementCompleted(4)");
+}
+
+#[tokio::test]
+async fn test_token_check_reads_granted_scopes() {
+    let github = MockGitHub::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-oauth-scopes", "public_repo, read:org")
+                .set_body_json(serde_json::json!({"login": "octocat"})),
+        )
+        .mount(&github.server)
+        .await;
+    let check = github.client().check_token().await.unwrap();
+    assert_eq!(check.login, "octocat");
+    assert_eq!(
+        check.scopes,
+        Some(vec!["public_repo".to_string(), "read:org".to_string()])
+    );
+    assert!(!check.has_scope("repo"));
+    assert!(check.has_scope("read:org"));
+
+    let rejected = MockGitHub::start().await;
+    rejected
+        .respond("/user", 401, error_body("Bad credentials"))
+        .await;
+    assert!(matches!(
+        rejected.client().check_token().await,
+        Err(GitHubError::AuthenticationFailed)
+    ));
+}
+
+#[tokio::test]
+async fn test_affiliated_listing_labels_each_repository() {
+    let github = MockGitHub::start().await;
+    let mut repos = fixture("user_repos.json");
+    let octocat = repos[0]["owner"].clone();
+    github.respond("/user", 200, octocat.clone()).await;
+
+    // octocat collaborates on hubot's site and is a member of acme
+    let mut hubot = octocat.clone();
+    hubot["login"] = "hubot".into();
+    repos[1]["owner"] = hubot;
+    let mut tools = repos[0].clone();
+    tools["name"] = "tools".into();
+    tools["owner"]["login"] = "acme".into();
+    tools["owner"]["type"] = "Organization".into();
+    repos.as_array_mut().unwrap().push(tools);
+    Mock::given(method("GET"))
+        .and(path("/user/repos"))
+        .and(query_param(
+            "affiliation",
+            "owner,collaborator,organization_member",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(repos))
+        .with_priority(1)
+        .mount(&github.server)
+        .await;
+
+    let messages = run_background_fetch(github.client().with_affiliated(true)).await;
+    let Some(BackgroundMessage::FetchCompleted { repositories }) = messages
+        .iter()
+        .find(|message| matches!(message, BackgroundMessage::FetchCompleted { .. }))
+    else {
+        panic!("expected FetchCompleted");
+    };
+    let affiliations: Vec<(String, Option<Affiliation>)> = repositories
+        .iter()
+        .map(|repo| (repo.full_name(), repo.affiliation))
+        .collect();
+    assert_eq!(
+        affiliations,
+        vec![
+            ("octocat/api".to_string(), Some(Affiliation::Owner)),
+            ("hubot/site".to_string(), Some(Affiliation::Collaborator)),
+            (
+                "acme/tools".to_string(),
+                Some(Affiliation::OrganizationMember)
+            ),
+        ]
+    );
+
+    // The default listing only asks for owned repositories
+    let messages = run_background_fetch(github.client()).await;
+    let Some(BackgroundMessage::FetchCompleted { repositories }) = messages.get(3) else {
+        panic!("expected FetchCompleted");
+    };
+    assert_eq!(repositories.len(), 2);
+    assert!(repositories.iter().all(|repo| repo.affiliation.is_none()));
+}
+
+#[tokio::test]
+async fn test_dormant_repository_without_traffic_is_archived() {
+    let github = MockGitHub::start().await;
+    github
+        .respond(
+            "/repos/octocat/legacy/traffic/views",
+            200,
+            serde_json::json!({"count": 0, "uniques": 0, "views": []}),
+        )
+        .await;
+    github
+        .respond(
+            "/repos/octocat/visited/traffic/views",
+            200,
+            serde_json::json!({"count": 12, "uniques": 3, "views": []}),
+        )
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/octocat/legacy"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&github.server)
+        .await;
+
+    let repositories: Vec<Repository> = ["legacy", "visited"]
+        .iter()
+        .map(|name| {
+            let mut repo = Repository::new(name.to_string(), "octocat".to_string());
+            repo.status = RepositoryStatus::Dormant;
+            repo.latest_commit_at = Some(SystemTime::now() - Duration::from_secs(400 * 86400));
+            repo.size_kb = 10;
+            repo
+        })
+        .collect();
+    let client = github.client();
+    let candidates = client.scan_cleanup_candidates(&repositories).await.unwrap();
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].repository, "octocat/legacy");
+    assert!(matches!(
+        candidates[0].reasons[..],
+        [CleanupReason::Dormant { days: 400 }]
+    ));
+
+    let (sender, receiver) = mpsc::unbounded_channel();
+    GitHubClient::spawn_archive_repository(
+        client,
+        sender,
+        "octocat".to_string(),
+        "legacy".to_string(),
+    );
+    let messages = collect_messages(receiver).await;
+    assert!(matches!(
+        &messages[..],
+        [BackgroundMessage::RepositoryArchived { repository }] if repository == "octocat/legacy"
+    ));
+}
@@ -1,3 +1,7 @@
+use crate::config::{AppConfig, AttentionConfig, CiConfig};
+use crate::i18n::{t, t_with};
+use regex::Regex;
+use std::collections::HashMap;
 use std::time::{Duration, SystemTime};
 
 /// Represents the activity level of a repository based on last commit time
@@ -15,21 +19,36 @@ pub enum RepositoryStatus {
     Stale,
     /// No commits in over 6 months
     Dormant,
+    /// No commits at all yet
+    Empty,
     /// Status is unknown or being fetched
     Unknown,
 }
 
 impl RepositoryStatus {
+    /// Severity for colorblind-safe indicators
+    pub fn severity(&self) -> Severity {
+        match self {
+            RepositoryStatus::Hot | RepositoryStatus::Active => Severity::Healthy,
+            RepositoryStatus::Moderate => Severity::Minor,
+            RepositoryStatus::Quiet => Severity::Warning,
+            RepositoryStatus::Stale => Severity::Serious,
+            RepositoryStatus::Dormant => Severity::Critical,
+            RepositoryStatus::Empty | RepositoryStatus::Unknown => Severity::Neutral,
+        }
+    }
+
     /// Get a human-readable description of the status
     pub fn description(&self) -> &'static str {
         match self {
-            RepositoryStatus::Hot => "Very active (today)",
-            RepositoryStatus::Active => "Active (this week)",
-            RepositoryStatus::Moderate => "Moderate activity (this month)",
-            RepositoryStatus::Quiet => "Quiet (last 3 months)",
-            RepositoryStatus::Stale => "Stale (last 6 months)",
-            RepositoryStatus::Dormant => "Dormant (6+ months)",
-            RepositoryStatus::Unknown => "Status unknown",
+            RepositoryStatus::Hot => t("status.hot"),
+            RepositoryStatus::Active => t("status.active"),
+            RepositoryStatus::Moderate => t("status.moderate"),
+            RepositoryStatus::Quiet => t("status.quiet"),
+            RepositoryStatus::Stale => t("status.stale"),
+            RepositoryStatus::Dormant => t("status.dormant"),
+            RepositoryStatus::Empty => t("status.empty"),
+            RepositoryStatus::Unknown => t("status.unknown"),
         }
     }
 
@@ -42,10 +61,39 @@ impl RepositoryStatus {
             RepositoryStatus::Quiet => "⚠️",
             RepositoryStatus::Stale => "🟡",
             RepositoryStatus::Dormant => "💤",
+            RepositoryStatus::Empty => "📭",
             RepositoryStatus::Unknown => "❓",
         }
     }
 
+    /// Nerd Font glyph, a single cell wide in patched fonts
+    pub fn nerd_font(&self) -> &'static str {
+        match self {
+            RepositoryStatus::Hot => "\u{f06d}",
+            RepositoryStatus::Active => "\u{f0e7}",
+            RepositoryStatus::Moderate => "\u{f058}",
+            RepositoryStatus::Quiet => "\u{f071}",
+            RepositoryStatus::Stale => "\u{f017}",
+            RepositoryStatus::Dormant => "\u{f186}",
+            RepositoryStatus::Empty => "\u{f114}",
+            RepositoryStatus::Unknown => "\u{f059}",
+        }
+    }
+
+    /// Name of the status in `[theme.icons.repository]`
+    pub fn icon_key(&self) -> &'static str {
+        match self {
+            RepositoryStatus::Hot => "hot",
+            RepositoryStatus::Active => "active",
+            RepositoryStatus::Moderate => "moderate",
+            RepositoryStatus::Quiet => "quiet",
+            RepositoryStatus::Stale => "stale",
+            RepositoryStatus::Dormant => "dormant",
+            RepositoryStatus::Empty => "empty",
+            RepositoryStatus::Unknown => "unknown",
+        }
+    }
+
     /// Get color for UI rendering
     pub fn color(&self) -> ratatui::style::Color {
         match self {
@@ -55,10 +103,32 @@ impl RepositoryStatus {
             RepositoryStatus::Quiet => ratatui::style::Color::Yellow,
             RepositoryStatus::Stale => ratatui::style::Color::Magenta,
             RepositoryStatus::Dormant => ratatui::style::Color::DarkGray,
+            RepositoryStatus::Empty => ratatui::style::Color::Blue,
             RepositoryStatus::Unknown => ratatui::style::Color::Gray,
         }
     }
 
+    /// Determine status from the last commit and, when known, the weekly
+    /// commit counts
+    ///
+    /// A lone commit after a quiet spell doesn't make a repository Hot or
+    /// Active; that takes at least [`SUSTAINED_COMMITS`] commits in the last
+    /// four weeks, otherwise it counts as Moderate.
+    pub fn from_activity(
+        last_commit: Option<SystemTime>,
+        activity: Option<&CommitActivity>,
+    ) -> Self {
+        let status = Self::from_last_commit(last_commit);
+        match (&status, activity) {
+            (RepositoryStatus::Hot | RepositoryStatus::Active, Some(activity))
+                if activity.recent(4) < SUSTAINED_COMMITS =>
+            {
+                RepositoryStatus::Moderate
+            }
+            _ => status,
+        }
+    }
+
     /// Determine status from last commit time
     pub fn from_last_commit(last_commit: Option<SystemTime>) -> Self {
         match last_commit {
@@ -100,14 +170,35 @@ pub enum WorkflowStatus {
 }
 
 impl WorkflowStatus {
+    /// Read the state of a GraphQL status check rollup
+    pub fn from_check_rollup(state: &str) -> Self {
+        match state {
+            "SUCCESS" => WorkflowStatus::Success,
+            "FAILURE" | "ERROR" => WorkflowStatus::Failed,
+            "PENDING" | "EXPECTED" => WorkflowStatus::InProgress,
+            _ => WorkflowStatus::Unknown,
+        }
+    }
+
+    /// Severity for colorblind-safe indicators
+    pub fn severity(&self) -> Severity {
+        match self {
+            WorkflowStatus::Success => Severity::Healthy,
+            WorkflowStatus::InProgress => Severity::Neutral,
+            WorkflowStatus::Cancelled => Severity::Warning,
+            WorkflowStatus::Failed => Severity::Critical,
+            WorkflowStatus::Unknown => Severity::Neutral,
+        }
+    }
+
     /// Get a human-readable description
     pub fn description(&self) -> &'static str {
         match self {
-            WorkflowStatus::Success => "Passed",
-            WorkflowStatus::Failed => "Failed",
-            WorkflowStatus::InProgress => "Running",
-            WorkflowStatus::Cancelled => "Cancelled",
-            WorkflowStatus::Unknown => "Unknown",
+            WorkflowStatus::Success => t("workflow.success"),
+            WorkflowStatus::Failed => t("workflow.failed"),
+            WorkflowStatus::InProgress => t("workflow.in_progress"),
+            WorkflowStatus::Cancelled => t("workflow.cancelled"),
+            WorkflowStatus::Unknown => t("workflow.unknown"),
         }
     }
 
@@ -121,6 +212,28 @@ impl WorkflowStatus {
             WorkflowStatus::Unknown => "❓",
         }
     }
+
+    /// Nerd Font glyph, a single cell wide in patched fonts
+    pub fn nerd_font(&self) -> &'static str {
+        match self {
+            WorkflowStatus::Success => "\u{f058}",
+            WorkflowStatus::Failed => "\u{f057}",
+            WorkflowStatus::InProgress => "\u{f110}",
+            WorkflowStatus::Cancelled => "\u{f05e}",
+            WorkflowStatus::Unknown => "\u{f059}",
+        }
+    }
+
+    /// Name of the status in `[theme.icons.workflow]`
+    pub fn icon_key(&self) -> &'static str {
+        match self {
+            WorkflowStatus::Success => "success",
+            WorkflowStatus::Failed => "failed",
+            WorkflowStatus::InProgress => "in_progress",
+            WorkflowStatus::Cancelled => "cancelled",
+            WorkflowStatus::Unknown => "unknown",
+        }
+    }
 }
 
 /// Represents a GitHub Actions workflow run
@@ -136,29 +249,153 @@ pub struct WorkflowRun {
     pub created_at: SystemTime,
     /// When the workflow was last updated
     pub updated_at: SystemTime,
+    /// When the workflow started running on a runner
+    pub started_at: Option<SystemTime>,
     /// Duration of the workflow (if completed)
     pub duration: Option<Duration>,
-    /// Conclusion of the workflow (if completed)
-    pub conclusion: Option<String>,
-    /// URL to view the workflow on GitHub
-    pub html_url: String,
+    /// What started the run
+    pub trigger: RunTrigger,
+}
+
+/// What started a workflow run
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunTrigger {
+    /// Branch the run checked out (None e.g. for scheduled runs of a tag)
+    pub branch: Option<String>,
+    /// Triggering event, e.g. "push", "pull_request" or "schedule"
+    pub event: String,
+    /// Who triggered the run, or re-ran it
+    pub actor: Option<String>,
+    /// Whether the code came from a fork of the repository
+    pub from_fork: bool,
+}
+
+impl RunTrigger {
+    /// Short description, e.g. "push · main · @octocat" or
+    /// "pull_request from fork · patch-1 · @alice"
+    pub fn label(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.event.is_empty() {
+            parts.push(if self.from_fork {
+                t_with("models.from_fork", &[("event", &self.event)])
+            } else {
+                self.event.clone()
+            });
+        }
+        parts.extend(self.branch.clone());
+        parts.extend(self.actor.as_ref().map(|actor| format!("@{}", actor)));
+        parts.join(" · ")
+    }
 }
 
 impl WorkflowRun {
-    /// Get a human-readable time description for when this run occurred
-    pub fn time_description(&self) -> String {
-        // Future: Implement relative time formatting
-        // For now, just return a placeholder
-        "recently".to_string()
+    /// Time spent waiting for a runner (created → started)
+    pub fn queue_time(&self) -> Option<Duration> {
+        self.started_at
+            .and_then(|started| started.duration_since(self.created_at).ok())
     }
 
-    /// Check if this workflow run is recent (within last 24 hours)
-    pub fn is_recent(&self) -> bool {
-        if let Ok(elapsed) = self.created_at.elapsed() {
-            elapsed < Duration::from_secs(24 * 60 * 60) // 24 hours in seconds
-        } else {
-            false
+    /// When the run started and finished, using `now` for runs still going
+    pub fn span(&self, now: SystemTime) -> (SystemTime, SystemTime) {
+        let start = self.started_at.unwrap_or(self.created_at);
+        let end = match self.duration {
+            Some(duration) => start + duration,
+            None if self.status == WorkflowStatus::InProgress => now,
+            None => self.updated_at,
+        };
+        (start, end.max(start))
+    }
+}
+
+/// Runner queue-time statistics over a repository's recent workflow runs
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueStats {
+    /// Average time runs waited for a runner
+    pub average: Duration,
+    /// Longest time a run waited for a runner
+    pub longest: Duration,
+    /// Runs that waited longer than the slow-queue threshold
+    pub slow_runs: usize,
+    /// Runs with a known start time
+    pub measured_runs: usize,
+}
+
+impl QueueStats {
+    /// Compute queue statistics, or None when no run has started yet
+    pub fn from_workflow_runs(runs: &[WorkflowRun], slow_threshold: Duration) -> Option<Self> {
+        let queue_times: Vec<Duration> = runs.iter().filter_map(WorkflowRun::queue_time).collect();
+        if queue_times.is_empty() {
+            return None;
+        }
+
+        let total: Duration = queue_times.iter().sum();
+        Some(Self {
+            average: total / queue_times.len() as u32,
+            longest: queue_times.iter().copied().max().unwrap_or_default(),
+            slow_runs: queue_times.iter().filter(|&&t| t > slow_threshold).count(),
+            measured_runs: queue_times.len(),
+        })
+    }
+
+    /// Whether at least half of the runs waited longer than the threshold
+    pub fn is_regularly_slow(&self) -> bool {
+        self.slow_runs * 2 >= self.measured_runs && self.slow_runs > 0
+    }
+}
+
+/// A workflow run's bar on a timeline that ends now
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineBar {
+    /// Index of the run in the runs laid out
+    pub run: usize,
+    /// Column the bar starts at
+    pub offset: usize,
+    /// Width of the bar in columns (at least one, however short the run)
+    pub length: usize,
+}
+
+impl TimelineBar {
+    /// Lay out the runs overlapping the `window` before `now` across `width` columns
+    ///
+    /// Bars are ordered oldest first, like a Gantt chart; runs that ended
+    /// before the window are left out.
+    pub fn layout(
+        runs: &[WorkflowRun],
+        window: Duration,
+        now: SystemTime,
+        width: usize,
+    ) -> Vec<Self> {
+        if width == 0 || window.is_zero() {
+            return Vec::new();
         }
+        let start = now.checked_sub(window).unwrap_or(SystemTime::UNIX_EPOCH);
+        let column = |time: SystemTime| {
+            let elapsed = time.duration_since(start).unwrap_or_default();
+            (elapsed.as_secs_f64() / window.as_secs_f64() * width as f64) as usize
+        };
+
+        let mut bars: Vec<(SystemTime, Self)> = runs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, run)| {
+                let (run_start, run_end) = run.span(now);
+                if run_end < start {
+                    return None;
+                }
+                let offset = column(run_start).min(width - 1);
+                let end = column(run_end).min(width);
+                Some((
+                    run_start,
+                    Self {
+                        run: index,
+                        offset,
+                        length: end.saturating_sub(offset).max(1),
+                    },
+                ))
+            })
+            .collect();
+        bars.sort_by_key(|(run_start, _)| *run_start);
+        bars.into_iter().map(|(_, bar)| bar).collect()
     }
 }
 
@@ -185,290 +422,3153 @@ pub struct PullRequest {
     pub approvals: u32,
     /// Number of requested changes
     pub changes_requested: u32,
+    /// SHA of the PR head commit (used to look up check runs)
+    pub head_sha: String,
+    /// Branch the PR merges into
+    pub base_branch: String,
+    /// Issues this PR closes when merged (from "Fixes #N" style references)
+    pub linked_issues: Vec<u64>,
+    /// Task-list checkboxes in the PR description, if it has any
+    pub tasks: Option<TaskProgress>,
+    /// Since when reviewer comments have been waiting for a reply from the author
+    pub feedback_waiting_since: Option<SystemTime>,
+    /// GitHub's review verdict under the branch's rules, when read via GraphQL
+    pub review_decision: Option<ReviewDecision>,
+    /// Combined state of the head commit's checks, when read via GraphQL
+    pub checks: Option<WorkflowStatus>,
+    /// Names of the labels on the PR
+    pub labels: Vec<String>,
+    /// Title of the PR's milestone
+    pub milestone: Option<String>,
 }
 
-/// Represents the state of a pull request
-#[derive(Debug, Clone, PartialEq)]
-pub enum PullRequestState {
-    Open,
-    Closed,
-    Merged,
+/// Open pull requests grouped by the branch they merge into
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TargetBranches {
+    /// Pull requests into the default branch
+    pub default: usize,
+    /// Pull requests into release branches (`release/*`, `release-*`)
+    pub release: usize,
+    /// Pull requests into any other branch by branch, most targeted first
+    pub other: Vec<(String, usize)>,
 }
 
-impl PullRequestState {
-    /// Get an emoji representation
-    pub fn emoji(&self) -> &'static str {
-        match self {
-            PullRequestState::Open => "🟢",
-            PullRequestState::Closed => "🔴",
-            PullRequestState::Merged => "🟣",
-        }
+impl TargetBranches {
+    /// Whether a branch is named like a release branch
+    pub fn is_release_branch(branch: &str) -> bool {
+        ["release/", "releases/", "release-"]
+            .iter()
+            .any(|prefix| branch.starts_with(prefix))
     }
-}
-
-/// Represents the overall workflow health of a repository
-#[derive(Debug, Clone, PartialEq)]
-pub enum WorkflowHealth {
-    /// All workflows passing or no workflows
-    Excellent,
-    /// Most workflows passing (>80%)
-    Good,
-    /// Some workflows failing (50-80% passing)
-    Fair,
-    /// Many workflows failing (<50% passing)
-    Poor,
-    /// All workflows failing
-    Critical,
-    /// No workflow data available
-    Unknown,
-}
 
-impl WorkflowHealth {
-    /// Get a human-readable description
-    pub fn description(&self) -> &'static str {
-        match self {
-            WorkflowHealth::Excellent => "All workflows passing",
-            WorkflowHealth::Good => "Most workflows passing",
-            WorkflowHealth::Fair => "Some workflows failing",
-            WorkflowHealth::Poor => "Many workflows failing",
-            WorkflowHealth::Critical => "All workflows failing",
-            WorkflowHealth::Unknown => "No workflow data",
-        }
+    /// Pull requests into neither the default nor a release branch
+    pub fn other_count(&self) -> usize {
+        self.other.iter().map(|(_, count)| count).sum()
     }
 
-    /// Get an emoji representation
-    pub fn emoji(&self) -> &'static str {
-        match self {
-            WorkflowHealth::Excellent => "✅",
-            WorkflowHealth::Good => "🟢",
-            WorkflowHealth::Fair => "🟡",
-            WorkflowHealth::Poor => "🟠",
-            WorkflowHealth::Critical => "🔴",
-            WorkflowHealth::Unknown => "❓",
+    /// One-line breakdown, e.g. "main 4 · release/* 1 · other 3 (develop 2, spike 1)"
+    pub fn summary(&self, default_branch: &str) -> String {
+        let mut parts = vec![format!("{} {}", default_branch, self.default)];
+        if self.release > 0 {
+            parts.push(t_with(
+                "models.release_branches",
+                &[("count", &self.release)],
+            ));
         }
-    }
-
-    /// Get color for UI rendering
-    pub fn color(&self) -> ratatui::style::Color {
-        match self {
-            WorkflowHealth::Excellent => ratatui::style::Color::Green,
-            WorkflowHealth::Good => ratatui::style::Color::Cyan,
-            WorkflowHealth::Fair => ratatui::style::Color::Yellow,
-            WorkflowHealth::Poor => ratatui::style::Color::LightRed,
-            WorkflowHealth::Critical => ratatui::style::Color::Red,
-            WorkflowHealth::Unknown => ratatui::style::Color::Gray,
+        if !self.other.is_empty() {
+            let branches: Vec<String> = self
+                .other
+                .iter()
+                .map(|(branch, count)| format!("{} {}", branch, count))
+                .collect();
+            parts.push(t_with(
+                "models.other_branches",
+                &[
+                    ("count", &self.other_count()),
+                    ("branches", &branches.join(", ")),
+                ],
+            ));
         }
+        parts.join(" · ")
     }
+}
 
-    /// Calculate workflow health from a list of recent workflow runs
-    pub fn from_workflow_runs(workflows: &[WorkflowRun]) -> Self {
-        if workflows.is_empty() {
-            return WorkflowHealth::Excellent; // No workflows = no failures
-        }
+/// Whether a pull request's reviews satisfy its base branch's rules
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReviewDecision {
+    Approved,
+    ChangesRequested,
+    /// More approvals are needed
+    ReviewRequired,
+}
 
-        let total = workflows.len() as f32;
-        let successful = workflows
-            .iter()
-            .filter(|w| matches!(w.status, WorkflowStatus::Success))
-            .count() as f32;
-        
-        let success_rate = successful / total;
-        
-        match success_rate {
-            r if r >= 1.0 => WorkflowHealth::Excellent,
-            r if r >= 0.8 => WorkflowHealth::Good,
-            r if r >= 0.5 => WorkflowHealth::Fair,
-            r if r > 0.0 => WorkflowHealth::Poor,
-            _ => WorkflowHealth::Critical,
+impl ReviewDecision {
+    /// Parse GraphQL's `reviewDecision`
+    pub fn from_graphql(decision: &str) -> Option<Self> {
+        match decision {
+            "APPROVED" => Some(ReviewDecision::Approved),
+            "CHANGES_REQUESTED" => Some(ReviewDecision::ChangesRequested),
+            "REVIEW_REQUIRED" => Some(ReviewDecision::ReviewRequired),
+            _ => None,
         }
     }
 }
 
-/// Represents a complete repository with all its health data
+/// A review comment left on a pull request's diff
 #[derive(Debug, Clone)]
-pub struct Repository {
-    /// Repository name
-    pub name: String,
-    /// Repository owner/organization
-    pub owner: String,
-    /// Overall health status based on commit activity
-    pub status: RepositoryStatus,
-    /// Overall workflow health based on recent runs
-    pub workflow_health: WorkflowHealth,
-    /// Most recent workflow run
-    pub latest_workflow: Option<WorkflowRun>,
-    /// Recent workflow runs for health calculation
-    pub recent_workflows: Vec<WorkflowRun>,
-    /// Open pull requests
-    pub open_pull_requests: Vec<PullRequest>,
-    /// When this data was last fetched
-    pub last_updated: SystemTime,
-    /// URL to the repository on GitHub
-    pub html_url: String,
-    /// Repository description
-    pub description: Option<String>,
-    /// Primary programming language
-    pub language: Option<String>,
-    /// Number of stars
-    pub stars: u32,
-    /// Latest commit timestamp
-    pub latest_commit_at: Option<SystemTime>,
+pub struct ReviewComment {
+    /// Number of the pull request commented on
+    pub pull_request: u32,
+    /// Login of the commenter
+    pub author: String,
+    /// When the comment was posted
+    pub created_at: SystemTime,
 }
 
-impl Repository {
-    /// Create a new repository with default values
-    pub fn new(name: String, owner: String) -> Self {
-        Self {
-            name,
-            owner,
-            status: RepositoryStatus::Unknown,
-            workflow_health: WorkflowHealth::Unknown,
-            latest_workflow: None,
-            recent_workflows: Vec::new(),
-            open_pull_requests: Vec::new(),
-            last_updated: SystemTime::now(),
-            html_url: String::new(),
-            description: None,
-            language: None,
-            stars: 0,
-            latest_commit_at: None,
-        }
-    }
-
-    /// Get the full repository name (owner/name)
-    pub fn full_name(&self) -> String {
-        format!("{}/{}", self.owner, self.name)
-    }
+/// A submitted pull request review, oldest first as GitHub lists them
+#[derive(Debug, Clone)]
+pub struct SubmittedReview {
+    /// Login of the reviewer
+    pub author: String,
+    /// Review state: APPROVED, CHANGES_REQUESTED, COMMENTED or DISMISSED
+    pub state: String,
+}
 
-    /// Get a summary of the repository's current state
-    pub fn status_summary(&self) -> String {
-        let workflow_status = self
-            .latest_workflow
-            .as_ref()
-            .map(|w| w.status.description())
-            .unwrap_or("No workflows");
+/// Checked and total task-list items in a Markdown description
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TaskProgress {
+    pub done: usize,
+    pub total: usize,
+}
 
-        let pr_count = self.open_pull_requests.len();
+impl TaskProgress {
+    /// Count the `- [ ]` / `- [x]` task-list items in a Markdown body
+    ///
+    /// Items may use any list marker (`-`, `*`, `+` or `1.`) and are ignored
+    /// inside code fences. Returns `None` when there are no items.
+    pub fn parse(body: &str) -> Option<Self> {
+        let mut progress = Self { done: 0, total: 0 };
+        let mut in_fence = false;
+        for line in body.lines() {
+            let line = line.trim_start();
+            if line.starts_with("```") || line.starts_with("~~~") {
+                in_fence = !in_fence;
+                continue;
+            }
+            if in_fence {
+                continue;
+            }
 
-        format!(
-            "{} | {} | {} open PRs",
-            self.status.description(),
-            workflow_status,
-            pr_count
-        )
+            let item = match line.split_once(' ') {
+                Some((marker, rest)) if is_list_marker(marker) => rest.trim_start(),
+                _ => continue,
+            };
+            if item.starts_with("[ ]") {
+                progress.total += 1;
+            } else if item.starts_with("[x]") || item.starts_with("[X]") {
+                progress.done += 1;
+                progress.total += 1;
+            }
+        }
+        (progress.total > 0).then_some(progress)
     }
 
-    /// Check if the repository needs attention
-    pub fn needs_attention(&self) -> bool {
-        matches!(
-            self.status,
-            RepositoryStatus::Stale | RepositoryStatus::Dormant
-        ) || !self.open_pull_requests.is_empty()
+    /// Whether every task is checked
+    pub fn is_complete(&self) -> bool {
+        self.done == self.total
     }
 }
 
-/// Configuration for repositories to monitor
-#[derive(Debug, Clone)]
-pub struct RepositoryConfig {
-    /// Repository name
-    pub name: String,
-    /// Repository owner/organization
-    pub owner: String,
-    /// Whether to monitor this repository
-    pub enabled: bool,
-    /// Custom display name (optional)
-    pub display_name: Option<String>,
+/// Whether a word starts a Markdown list item (`-`, `*`, `+`, `1.` or `1)`)
+fn is_list_marker(marker: &str) -> bool {
+    if matches!(marker, "-" | "*" | "+") {
+        return true;
+    }
+    marker
+        .strip_suffix(['.', ')'])
+        .is_some_and(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
 }
 
-impl RepositoryConfig {
-    /// Create a new repository configuration
-    pub fn new(name: String, owner: String) -> Self {
-        Self {
-            name,
-            owner,
-            enabled: true,
-            display_name: None,
+impl PullRequest {
+    /// Issue numbers referenced with a closing keyword in a PR body
+    ///
+    /// Recognises GitHub's keywords (close, fix, resolve and their variants)
+    /// followed by a same-repository reference such as `#12`.
+    pub fn parse_linked_issues(body: &str) -> Vec<u64> {
+        const KEYWORDS: [&str; 9] = [
+            "close", "closes", "closed", "fix", "fixes", "fixed", "resolve", "resolves", "resolved",
+        ];
+
+        let words: Vec<String> = body
+            .split_whitespace()
+            .map(|word| word.to_lowercase())
+            .collect();
+
+        let mut issues = Vec::new();
+        for pair in words.windows(2) {
+            let keyword = pair[0].trim_end_matches(':');
+            if !KEYWORDS.contains(&keyword) {
+                continue;
+            }
+            let Some(reference) = pair[1].strip_prefix('#') else {
+                continue;
+            };
+            let digits: String = reference.chars().take_while(char::is_ascii_digit).collect();
+            if let Ok(number) = digits.parse::<u64>() {
+                if !issues.contains(&number) {
+                    issues.push(number);
+                }
+            }
         }
+        issues
     }
 
-    /// Get the display name (uses custom name if set, otherwise repository name)
-    pub fn display_name(&self) -> &str {
-        self.display_name.as_ref().unwrap_or(&self.name)
+    /// Whether the PR is ready for review with unchecked tasks left
+    pub fn has_incomplete_checklist(&self) -> bool {
+        !self.draft && self.tasks.is_some_and(|tasks| !tasks.is_complete())
     }
-}
 
-/// Application configuration
-#[derive(Debug, Clone)]
-pub struct AppConfig {
-    /// List of repositories to monitor
-    pub repositories: Vec<RepositoryConfig>,
-    /// GitHub personal access token
-    pub github_token: Option<String>,
-    /// Auto-refresh interval in seconds
-    pub refresh_interval: u64,
-    /// Maximum number of repositories to display
-    pub max_repositories: usize,
-}
-
-impl Default for AppConfig {
-    fn default() -> Self {
-        Self {
-            repositories: Vec::new(),
-            github_token: None,
-            refresh_interval: 300, // 5 minutes
-            max_repositories: 50,
+    /// Count approvals and change requests from the PR's reviews
+    ///
+    /// Like GitHub, each reviewer's latest approval, change request or
+    /// dismissal decides their vote; plain comments don't change it.
+    pub fn tally_reviews(&mut self, reviews: &[SubmittedReview]) {
+        let mut votes: HashMap<&str, &str> = HashMap::new();
+        for review in reviews {
+            if review.author == self.author || review.state == "COMMENTED" {
+                continue;
+            }
+            votes.insert(review.author.as_str(), review.state.as_str());
         }
+        self.approvals = votes.values().filter(|&&state| state == "APPROVED").count() as u32;
+        self.changes_requested = votes
+            .values()
+            .filter(|&&state| state == "CHANGES_REQUESTED")
+            .count() as u32;
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Earliest reviewer comment the author hasn't replied to since
+    ///
+    /// Reviewer feedback counts as answered once the author comments after
+    /// it. Bot comments are ignored.
+    pub fn unanswered_feedback_since(&self, comments: &[ReviewComment]) -> Option<SystemTime> {
+        let mut comments: Vec<&ReviewComment> = comments
+            .iter()
+            .filter(|comment| comment.pull_request == self.number)
+            .filter(|comment| !comment.author.ends_with("[bot]"))
+            .collect();
+        comments.sort_by_key(|comment| comment.created_at);
 
-    #[test]
-    fn test_repository_status() {
-        assert_eq!(RepositoryStatus::Hot.emoji(), "🔥");
-        assert_eq!(RepositoryStatus::Active.emoji(), "⚡");
-        assert_eq!(RepositoryStatus::Dormant.emoji(), "💤");
+        let mut waiting_since = None;
+        for comment in comments {
+            if comment.author == self.author {
+                waiting_since = None;
+            } else if waiting_since.is_none() {
+                waiting_since = Some(comment.created_at);
+            }
+        }
+        waiting_since
     }
 
-    #[test]
-    fn test_workflow_status() {
-        assert_eq!(WorkflowStatus::Success.description(), "Passed");
-        assert_eq!(WorkflowStatus::Failed.description(), "Failed");
-        assert_eq!(WorkflowStatus::InProgress.description(), "Running");
+    /// How long reviewer feedback has been waiting for the author
+    pub fn feedback_wait(&self, now: SystemTime) -> Option<Duration> {
+        self.feedback_waiting_since
+            .map(|since| now.duration_since(since).unwrap_or_default())
     }
 
-    #[test]
-    fn test_repository_creation() {
-        let repo = Repository::new("test-repo".to_string(), "test-org".to_string());
-        assert_eq!(repo.full_name(), "test-org/test-repo");
-        assert_eq!(repo.status, RepositoryStatus::Unknown);
-        assert!(repo.open_pull_requests.is_empty());
+    /// Whether reviewer feedback has waited for the author longer than `threshold`
+    pub fn is_awaiting_author(&self, threshold: Duration, now: SystemTime) -> bool {
+        self.feedback_wait(now).is_some_and(|wait| wait > threshold)
     }
 
-    #[test]
-    fn test_repository_config() {
-        let config = RepositoryConfig::new("test".to_string(), "org".to_string());
-        assert!(config.enabled);
-        assert_eq!(config.display_name(), "test");
-
-        let mut config_with_display = config.clone();
-        config_with_display.display_name = Some("Custom Name".to_string());
-        assert_eq!(config_with_display.display_name(), "Custom Name");
+    /// Whether the PR could be merged for a release: out of draft, approved
+    /// without change requests and no checks failing or still running
+    pub fn is_release_ready(&self) -> bool {
+        let approved = match self.review_decision {
+            Some(decision) => decision == ReviewDecision::Approved,
+            None => self.approvals > 0 && self.changes_requested == 0,
+        };
+        let checks_pass = !matches!(
+            self.checks,
+            Some(WorkflowStatus::Failed | WorkflowStatus::InProgress)
+        );
+        !self.draft && approved && checks_pass
     }
+}
 
-    #[test]
-    fn test_workflow_health() {
-        let run1 = WorkflowRun {
-            id: 1,
-            name: "CI".to_string(),
+/// An open issue tracked for backlog health
+#[derive(Debug, Clone)]
+pub struct Issue {
+    /// Issue number
+    pub number: u64,
+    /// Issue title
+    pub title: String,
+    /// URL to view the issue on GitHub
+    pub html_url: String,
+    /// Logins of the assigned users
+    pub assignees: Vec<String>,
+    /// Label names
+    pub labels: Vec<String>,
+    /// Number of comments
+    pub comments: u32,
+}
+
+/// Triage change made to an issue from the dashboard
+#[derive(Debug, Clone, PartialEq)]
+pub enum IssueAction {
+    /// Assign the issue to a user
+    Assign(String),
+    /// Add and remove labels
+    Label {
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
+    /// Post a comment
+    Comment(String),
+}
+
+impl IssueAction {
+    /// Labels from a comma-separated dialog field, ignoring blanks
+    pub fn parse_labels(input: &str) -> Vec<String> {
+        input
+            .split(',')
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Status message once the change went through
+    pub fn describe(&self, number: u64) -> String {
+        match self {
+            IssueAction::Assign(login) => {
+                t_with("models.assigned", &[("number", &number), ("login", login)])
+            }
+            IssueAction::Label { add, remove } => {
+                let mut changes = Vec::new();
+                if !add.is_empty() {
+                    changes.push(t_with(
+                        "models.labels_added",
+                        &[("labels", &add.join(", "))],
+                    ));
+                }
+                if !remove.is_empty() {
+                    changes.push(t_with(
+                        "models.labels_removed",
+                        &[("labels", &remove.join(", "))],
+                    ));
+                }
+                t_with(
+                    "models.labelled",
+                    &[("number", &number), ("changes", &changes.join("; "))],
+                )
+            }
+            IssueAction::Comment(_) => t_with("models.commented", &[("number", &number)]),
+        }
+    }
+}
+
+/// GitHub rejects search queries longer than this
+const SEARCH_QUERY_MAX_LEN: usize = 256;
+
+/// An open issue found by the issue search across loaded repositories
+#[derive(Debug, Clone)]
+pub struct SearchedIssue {
+    /// Full name of the issue's repository (`owner/name`)
+    pub repository: String,
+    pub issue: Issue,
+    pub created_at: SystemTime,
+}
+
+impl SearchedIssue {
+    /// Days since the issue was opened
+    pub fn age_days(&self, now: SystemTime) -> u64 {
+        now.duration_since(self.created_at)
+            .unwrap_or_default()
+            .as_secs()
+            / 86400
+    }
+}
+
+/// Filters of the issue search across loaded repositories
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IssueSearchFilter {
+    /// Label name, or "none" for unlabeled issues
+    pub label: Option<String>,
+    /// Assignee login, or "none" for unassigned issues
+    pub assignee: Option<String>,
+    /// Only issues opened at least this many days ago
+    pub min_age_days: Option<u64>,
+}
+
+impl IssueSearchFilter {
+    /// Search qualifiers for the filters, after `is:issue is:open`
+    fn qualifiers(&self, today: chrono::NaiveDate) -> String {
+        let mut qualifiers = vec!["is:issue".to_string(), "is:open".to_string()];
+        match self.label.as_deref() {
+            Some("none") => qualifiers.push("no:label".to_string()),
+            Some(label) => qualifiers.push(format!("label:\"{}\"", label)),
+            None => {}
+        }
+        match self.assignee.as_deref() {
+            Some("none") => qualifiers.push("no:assignee".to_string()),
+            Some(login) => qualifiers.push(format!("assignee:{}", login)),
+            None => {}
+        }
+        if let Some(days) = self.min_age_days {
+            let cutoff = today - chrono::Days::new(days);
+            qualifiers.push(format!("created:<={}", cutoff.format("%Y-%m-%d")));
+        }
+        qualifiers.join(" ")
+    }
+
+    /// Search queries covering `repositories` (`owner/name`)
+    ///
+    /// Repositories are split over as many queries as needed to keep each
+    /// within GitHub's query length limit.
+    pub fn queries(&self, repositories: &[String], today: chrono::NaiveDate) -> Vec<String> {
+        let qualifiers = self.qualifiers(today);
+        let mut queries = Vec::new();
+        let mut query = qualifiers.clone();
+        for repository in repositories {
+            let scope = format!(" repo:{}", repository);
+            if query.len() + scope.len() > SEARCH_QUERY_MAX_LEN && query != qualifiers {
+                queries.push(std::mem::replace(&mut query, qualifiers.clone()));
+            }
+            query.push_str(&scope);
+        }
+        if query != qualifiers {
+            queries.push(query);
+        }
+        queries
+    }
+
+    /// Short description, e.g. "label bug · unassigned · 30+ days old"
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        match self.label.as_deref() {
+            Some("none") => parts.push(t("models.unlabeled").to_string()),
+            Some(label) => parts.push(t_with("models.label", &[("label", &label)])),
+            None => {}
+        }
+        match self.assignee.as_deref() {
+            Some("none") => parts.push(t("models.unassigned").to_string()),
+            Some(login) => parts.push(t_with("models.assignee", &[("login", &login)])),
+            None => {}
+        }
+        if let Some(days) = self.min_age_days {
+            parts.push(t_with("models.min_age", &[("days", &days)]));
+        }
+        if parts.is_empty() {
+            t("models.no_filters").to_string()
+        } else {
+            parts.join(" · ")
+        }
+    }
+}
+
+/// A file changed by a pull request, with its line counts
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangedFile {
+    pub path: String,
+    /// Change type as reported by GitHub (added, modified, removed, renamed, ...)
+    pub status: String,
+    pub additions: u32,
+    pub deletions: u32,
+}
+
+impl ChangedFile {
+    /// Lines added plus lines deleted
+    pub fn churn(&self) -> u32 {
+        self.additions + self.deletions
+    }
+
+    /// Widths of the additions and deletions parts of a diffstat bar
+    ///
+    /// Bars are scaled so the file with `max_churn` fills `width`; any
+    /// changed file gets at least one cell.
+    pub fn bar_widths(&self, max_churn: u32, width: usize) -> (usize, usize) {
+        let churn = self.churn() as usize;
+        if churn == 0 || max_churn == 0 {
+            return (0, 0);
+        }
+        let total = (churn * width).div_ceil(max_churn as usize).clamp(1, width);
+        let additions = (self.additions as usize * total + churn / 2) / churn;
+        (additions, total - additions)
+    }
+}
+
+/// Represents a single check run reported against a commit
+#[derive(Debug, Clone)]
+pub struct CheckRun {
+    /// Unique identifier for the check run
+    pub id: u64,
+    /// Name of the check (e.g. "build", "lint")
+    pub name: String,
+    /// Current status of the check
+    pub status: WorkflowStatus,
+}
+
+impl CheckRun {
+    /// Map a GitHub check run conclusion to a workflow status
+    ///
+    /// A missing conclusion means the check is still queued or running.
+    pub fn status_from_conclusion(conclusion: Option<&str>) -> WorkflowStatus {
+        match conclusion {
+            Some("success") | Some("neutral") | Some("skipped") => WorkflowStatus::Success,
+            Some("failure")
+            | Some("timed_out")
+            | Some("action_required")
+            | Some("startup_failure") => WorkflowStatus::Failed,
+            Some("cancelled") | Some("stale") => WorkflowStatus::Cancelled,
+            Some(_) => WorkflowStatus::Unknown,
+            None => WorkflowStatus::InProgress,
+        }
+    }
+
+    /// Check if this check run can be re-requested (it failed or was cancelled)
+    pub fn can_rerun(&self) -> bool {
+        matches!(
+            self.status,
+            WorkflowStatus::Failed | WorkflowStatus::Cancelled
+        )
+    }
+}
+
+/// Protection rules of a pull request's base branch that gate merging
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BranchProtection {
+    /// Approving reviews required
+    pub required_approvals: u32,
+    /// Whether a code owner must approve
+    pub code_owner_reviews: bool,
+    /// Status checks that must pass, by name
+    pub required_checks: Vec<String>,
+    /// Whether the branch must be up to date with the base
+    pub strict: bool,
+}
+
+/// Protection of a repository's default branch
+#[derive(Debug, Clone, PartialEq)]
+pub enum BranchProtectionStatus {
+    Unprotected,
+    /// Protected, with its rules when the token has admin access to read them
+    Protected(Option<BranchProtection>),
+}
+
+/// What GitHub reports about merging a pull request, with the base branch's
+/// protection rules
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MergeRequirements {
+    /// Whether the PR merges without conflicts (`None` while GitHub computes it)
+    pub mergeable: Option<bool>,
+    /// GitHub's `mergeable_state`: `clean`, `dirty`, `behind`, `blocked`, ...
+    pub mergeable_state: String,
+    /// Branch the PR merges into
+    pub base_branch: String,
+    /// The base branch's protection, if the token may read it
+    pub protection: Option<BranchProtection>,
+}
+
+/// One reason a pull request can't be merged yet
+#[derive(Debug, Clone, PartialEq)]
+pub enum MergeBlocker {
+    Draft,
+    Conflicts,
+    /// The base branch moved on and protection requires the PR to be up to date
+    BehindBase {
+        branch: String,
+    },
+    ChangesRequested(u32),
+    MissingApprovals {
+        have: u32,
+        required: u32,
+        code_owners: bool,
+    },
+    FailingChecks(Vec<String>),
+    PendingChecks(Vec<String>),
+    /// Required checks that haven't reported on the head commit
+    MissingChecks(Vec<String>),
+    /// GitHub says blocked, but by a rule the token can't see
+    Blocked,
+}
+
+impl MergeBlocker {
+    /// One line explanation for the PR pane
+    pub fn description(&self) -> String {
+        match self {
+            MergeBlocker::Draft => t("merge.draft").to_string(),
+            MergeBlocker::Conflicts => t("merge.conflicts").to_string(),
+            MergeBlocker::BehindBase { branch } => t_with("merge.behind", &[("branch", branch)]),
+            MergeBlocker::ChangesRequested(count) => {
+                t_with("merge.changes_requested", &[("count", count)])
+            }
+            MergeBlocker::MissingApprovals {
+                have,
+                required,
+                code_owners,
+            } => t_with(
+                if *code_owners {
+                    "merge.code_owner_approvals"
+                } else {
+                    "merge.approvals"
+                },
+                &[("have", have), ("required", required)],
+            ),
+            MergeBlocker::FailingChecks(names) => {
+                t_with("merge.failing", &[("checks", &names.join(", "))])
+            }
+            MergeBlocker::PendingChecks(names) => {
+                t_with("merge.pending", &[("checks", &names.join(", "))])
+            }
+            MergeBlocker::MissingChecks(names) => {
+                t_with("merge.missing", &[("checks", &names.join(", "))])
+            }
+            MergeBlocker::Blocked => t("merge.blocked").to_string(),
+        }
+    }
+}
+
+impl MergeRequirements {
+    /// Everything standing between a pull request and merging, given its
+    /// check runs
+    ///
+    /// With visible branch protection only required checks count; otherwise
+    /// every failing check is listed since any of them may be required.
+    pub fn blockers(&self, pr: &PullRequest, checks: &[CheckRun]) -> Vec<MergeBlocker> {
+        let mut blockers = Vec::new();
+        if pr.draft {
+            blockers.push(MergeBlocker::Draft);
+        }
+        if self.mergeable == Some(false) || self.mergeable_state == "dirty" {
+            blockers.push(MergeBlocker::Conflicts);
+        }
+        if pr.changes_requested > 0 {
+            blockers.push(MergeBlocker::ChangesRequested(pr.changes_requested));
+        }
+
+        let protection = self.protection.as_ref();
+        if let Some(protection) = protection {
+            if self.mergeable_state == "behind" && protection.strict {
+                blockers.push(MergeBlocker::BehindBase {
+                    branch: self.base_branch.clone(),
+                });
+            }
+            let required = protection
+                .required_approvals
+                .max(u32::from(protection.code_owner_reviews));
+            if pr.approvals < required {
+                blockers.push(MergeBlocker::MissingApprovals {
+                    have: pr.approvals,
+                    required,
+                    code_owners: protection.code_owner_reviews,
+                });
+            }
+        }
+
+        let required_checks = protection
+            .map(|protection| protection.required_checks.as_slice())
+            .filter(|required| !required.is_empty());
+        let counts = |check: &&CheckRun| {
+            required_checks.is_none_or(|required| required.contains(&check.name))
+        };
+        let names = |status: fn(&WorkflowStatus) -> bool| -> Vec<String> {
+            checks
+                .iter()
+                .filter(counts)
+                .filter(|check| status(&check.status))
+                .map(|check| check.name.clone())
+                .collect()
+        };
+        let failing =
+            names(|status| matches!(status, WorkflowStatus::Failed | WorkflowStatus::Cancelled));
+        if !failing.is_empty() {
+            blockers.push(MergeBlocker::FailingChecks(failing));
+        }
+        let pending = names(|status| *status == WorkflowStatus::InProgress);
+        if !pending.is_empty() {
+            blockers.push(MergeBlocker::PendingChecks(pending));
+        }
+        if let Some(required) = required_checks {
+            let missing: Vec<String> = required
+                .iter()
+                .filter(|name| !checks.iter().any(|check| &&check.name == name))
+                .cloned()
+                .collect();
+            if !missing.is_empty() {
+                blockers.push(MergeBlocker::MissingChecks(missing));
+            }
+        }
+
+        if blockers.is_empty() && self.mergeable_state == "blocked" {
+            blockers.push(MergeBlocker::Blocked);
+        }
+        blockers
+    }
+}
+
+/// Represents the state of a pull request
+#[derive(Debug, Clone, PartialEq)]
+pub enum PullRequestState {
+    Open,
+    Closed,
+    Merged,
+}
+
+impl PullRequestState {
+    /// Get an emoji representation
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            PullRequestState::Open => "🟢",
+            PullRequestState::Closed => "🔴",
+            PullRequestState::Merged => "🟣",
+        }
+    }
+}
+
+/// Kind of pull request review submitted from the dashboard
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReviewEvent {
+    Approve,
+    Comment,
+}
+
+impl ReviewEvent {
+    /// Parse the option chosen in the review dialog
+    pub fn from_label(label: &str) -> Option<Self> {
+        [ReviewEvent::Approve, ReviewEvent::Comment]
+            .into_iter()
+            .find(|event| event.label() == label)
+    }
+
+    /// Option shown for the event in the review dialog
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReviewEvent::Approve => t("dialog.approve"),
+            ReviewEvent::Comment => t("dialog.review_comment"),
+        }
+    }
+
+    /// Value of the reviews API `event` field
+    pub fn api_name(&self) -> &'static str {
+        match self {
+            ReviewEvent::Approve => "APPROVE",
+            ReviewEvent::Comment => "COMMENT",
+        }
+    }
+
+    /// Whether the review needs a body (GitHub rejects empty comments)
+    pub fn requires_body(&self) -> bool {
+        matches!(self, ReviewEvent::Comment)
+    }
+
+    /// Past-tense description for status messages
+    pub fn past_tense(&self) -> &'static str {
+        match self {
+            ReviewEvent::Approve => t("models.review_approved"),
+            ReviewEvent::Comment => t("models.review_commented"),
+        }
+    }
+}
+
+/// Represents the overall workflow health of a repository
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkflowHealth {
+    /// All workflows passing or no workflows
+    Excellent,
+    /// Most workflows passing (>80%)
+    Good,
+    /// Some workflows failing (50-80% passing)
+    Fair,
+    /// Many workflows failing (<50% passing)
+    Poor,
+    /// All workflows failing
+    Critical,
+    /// No workflow data available
+    Unknown,
+}
+
+impl WorkflowHealth {
+    /// Severity for colorblind-safe indicators
+    pub fn severity(&self) -> Severity {
+        match self {
+            WorkflowHealth::Excellent => Severity::Healthy,
+            WorkflowHealth::Good => Severity::Minor,
+            WorkflowHealth::Fair => Severity::Warning,
+            WorkflowHealth::Poor => Severity::Serious,
+            WorkflowHealth::Critical => Severity::Critical,
+            WorkflowHealth::Unknown => Severity::Neutral,
+        }
+    }
+
+    /// Get a human-readable description
+    pub fn description(&self) -> &'static str {
+        match self {
+            WorkflowHealth::Excellent => t("workflow_health.excellent"),
+            WorkflowHealth::Good => t("workflow_health.good"),
+            WorkflowHealth::Fair => t("workflow_health.fair"),
+            WorkflowHealth::Poor => t("workflow_health.poor"),
+            WorkflowHealth::Critical => t("workflow_health.critical"),
+            WorkflowHealth::Unknown => t("workflow_health.unknown"),
+        }
+    }
+
+    /// Get an emoji representation
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            WorkflowHealth::Excellent => "✅",
+            WorkflowHealth::Good => "🟢",
+            WorkflowHealth::Fair => "🟡",
+            WorkflowHealth::Poor => "🟠",
+            WorkflowHealth::Critical => "🔴",
+            WorkflowHealth::Unknown => "❓",
+        }
+    }
+
+    /// Nerd Font glyph, a single cell wide in patched fonts
+    pub fn nerd_font(&self) -> &'static str {
+        match self {
+            WorkflowHealth::Excellent => "\u{f058}",
+            WorkflowHealth::Good => "\u{f111}",
+            WorkflowHealth::Fair => "\u{f042}",
+            WorkflowHealth::Poor => "\u{f06a}",
+            WorkflowHealth::Critical => "\u{f057}",
+            WorkflowHealth::Unknown => "\u{f059}",
+        }
+    }
+
+    /// Name of the health in `[theme.icons.workflow_health]`
+    pub fn icon_key(&self) -> &'static str {
+        match self {
+            WorkflowHealth::Excellent => "excellent",
+            WorkflowHealth::Good => "good",
+            WorkflowHealth::Fair => "fair",
+            WorkflowHealth::Poor => "poor",
+            WorkflowHealth::Critical => "critical",
+            WorkflowHealth::Unknown => "unknown",
+        }
+    }
+
+    /// Get color for UI rendering
+    pub fn color(&self) -> ratatui::style::Color {
+        match self {
+            WorkflowHealth::Excellent => ratatui::style::Color::Green,
+            WorkflowHealth::Good => ratatui::style::Color::Cyan,
+            WorkflowHealth::Fair => ratatui::style::Color::Yellow,
+            WorkflowHealth::Poor => ratatui::style::Color::LightRed,
+            WorkflowHealth::Critical => ratatui::style::Color::Red,
+            WorkflowHealth::Unknown => ratatui::style::Color::Gray,
+        }
+    }
+
+    /// Calculate workflow health from a list of recent workflow runs
+    pub fn from_workflow_runs(workflows: &[WorkflowRun]) -> Self {
+        Self::from_weighted_workflow_runs(workflows, |_| 1)
+    }
+
+    /// Calculate workflow health, counting each run by its workflow's weight
+    ///
+    /// Lets failures of critical workflows (deploy, release) pull health down
+    /// further than failures of lint or docs workflows.
+    pub fn from_weighted_workflow_runs(
+        workflows: &[WorkflowRun],
+        weight: impl Fn(&str) -> u32,
+    ) -> Self {
+        if workflows.is_empty() {
+            return WorkflowHealth::Excellent; // No workflows = no failures
+        }
+
+        let (successful, total) = workflows.iter().fold((0, 0), |(successful, total), w| {
+            let weight = weight(&w.name);
+            if matches!(w.status, WorkflowStatus::Success) {
+                (successful + weight, total + weight)
+            } else {
+                (successful, total + weight)
+            }
+        });
+        if total == 0 {
+            return WorkflowHealth::Excellent;
+        }
+        let successful = successful as f32;
+        let total = total as f32;
+        
+        let success_rate = successful / total;
+        
+        match success_rate {
+            r if r >= 1.0 => WorkflowHealth::Excellent,
+            r if r >= 0.8 => WorkflowHealth::Good,
+            r if r >= 0.5 => WorkflowHealth::Fair,
+            r if r > 0.0 => WorkflowHealth::Poor,
+            _ => WorkflowHealth::Critical,
+        }
+    }
+}
+
+/// Recent runs of one workflow and the weight they carry in workflow health
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkflowBreakdown {
+    pub name: String,
+    pub weight: u32,
+    pub runs: usize,
+    pub failures: usize,
+}
+
+impl WorkflowBreakdown {
+    /// Group recent runs by workflow, heaviest workflows first
+    pub fn from_workflow_runs(
+        workflows: &[WorkflowRun],
+        weight: impl Fn(&str) -> u32,
+    ) -> Vec<Self> {
+        let mut breakdown: Vec<Self> = Vec::new();
+        for run in workflows {
+            let index = match breakdown.iter().position(|entry| entry.name == run.name) {
+                Some(index) => index,
+                None => {
+                    breakdown.push(Self {
+                        name: run.name.clone(),
+                        weight: weight(&run.name),
+                        runs: 0,
+                        failures: 0,
+                    });
+                    breakdown.len() - 1
+                }
+            };
+            breakdown[index].runs += 1;
+            if !matches!(run.status, WorkflowStatus::Success) {
+                breakdown[index].failures += 1;
+            }
+        }
+        breakdown.sort_by(|a, b| b.weight.cmp(&a.weight).then(a.name.cmp(&b.name)));
+        breakdown
+    }
+}
+
+/// State of a deployment or GitHub Pages build
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeploymentState {
+    /// Deployed/built successfully
+    Success,
+    /// Deployment or build failed
+    Failure,
+    /// Deployment or build is queued or running
+    InProgress,
+    /// State could not be determined
+    Unknown,
+}
+
+impl DeploymentState {
+    /// Map a GitHub deployment status state to a deployment state
+    pub fn from_deployment_status(state: &str) -> Self {
+        match state {
+            "success" | "inactive" => DeploymentState::Success,
+            "failure" | "error" => DeploymentState::Failure,
+            "pending" | "queued" | "in_progress" => DeploymentState::InProgress,
+            _ => DeploymentState::Unknown,
+        }
+    }
+
+    /// Map a GitHub Pages build status to a deployment state
+    pub fn from_pages_status(status: &str) -> Self {
+        match status {
+            "built" => DeploymentState::Success,
+            "errored" => DeploymentState::Failure,
+            "building" | "queued" => DeploymentState::InProgress,
+            _ => DeploymentState::Unknown,
+        }
+    }
+
+    /// Get an emoji representation
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            DeploymentState::Success => "✅",
+            DeploymentState::Failure => "❌",
+            DeploymentState::InProgress => "⏳",
+            DeploymentState::Unknown => "❓",
+        }
+    }
+}
+
+/// Latest deployment of a single deployment environment
+#[derive(Debug, Clone)]
+pub struct EnvironmentDeployment {
+    /// Environment name (e.g. "production")
+    pub name: String,
+    /// State of the most recent deployment
+    pub state: DeploymentState,
+}
+
+impl EnvironmentDeployment {
+    /// Check if this environment is a production environment
+    pub fn is_production(&self) -> bool {
+        let name = self.name.to_lowercase();
+        name == "production" || name == "prod" || name.starts_with("production")
+    }
+}
+
+/// Days after which a repository's latest release is flagged as old
+pub const STALE_RELEASE_DAYS: u64 = 90;
+
+/// Latest published release of a repository
+#[derive(Debug, Clone, PartialEq)]
+pub struct Release {
+    /// Release title, or the tag when the release has none
+    pub name: String,
+    /// Tag the release was cut from
+    pub tag: String,
+    /// When the release was published
+    pub published_at: SystemTime,
+}
+
+impl Release {
+    /// Whole days since the release was published
+    pub fn days_ago(&self, now: SystemTime) -> u64 {
+        now.duration_since(self.published_at)
+            .unwrap_or_default()
+            .as_secs()
+            / 86400
+    }
+
+    /// Get a human-readable age, e.g. "released 34 days ago"
+    pub fn released_ago(&self, now: SystemTime) -> String {
+        match self.days_ago(now) {
+            0 => t("models.released_today").to_string(),
+            1 => t("models.released_yesterday").to_string(),
+            days => t_with("models.released_days_ago", &[("days", &days)]),
+        }
+    }
+
+    /// Whether no release was cut for STALE_RELEASE_DAYS
+    pub fn is_stale(&self, now: SystemTime) -> bool {
+        self.days_ago(now) >= STALE_RELEASE_DAYS
+    }
+}
+
+/// Deployment status of a repository: GitHub Pages plus environments
+#[derive(Debug, Clone, Default)]
+pub struct DeployStatus {
+    /// GitHub Pages build state (None when Pages is not enabled)
+    pub pages: Option<DeploymentState>,
+    /// Latest deployment per environment
+    pub environments: Vec<EnvironmentDeployment>,
+}
+
+/// Overall deployment health shown in the "Deploy" column
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeployHealth {
+    /// Pages and all environments deployed successfully
+    Healthy,
+    /// A deployment is currently running
+    Deploying,
+    /// A non-production environment failed
+    Degraded,
+    /// Pages build broken or production deployment failed
+    Broken,
+    /// Nothing is deployed from this repository
+    None,
+}
+
+impl DeployStatus {
+    /// Compute overall deployment health
+    ///
+    /// Broken Pages builds and failed production deployments are flagged
+    /// as Broken; failures elsewhere only degrade the health.
+    pub fn health(&self) -> DeployHealth {
+        if self.pages.is_none() && self.environments.is_empty() {
+            return DeployHealth::None;
+        }
+
+        let production_failed = self
+            .environments
+            .iter()
+            .any(|env| env.is_production() && env.state == DeploymentState::Failure);
+        if self.pages == Some(DeploymentState::Failure) || production_failed {
+            return DeployHealth::Broken;
+        }
+
+        if self
+            .environments
+            .iter()
+            .any(|env| env.state == DeploymentState::Failure)
+        {
+            return DeployHealth::Degraded;
+        }
+
+        let in_progress = self.pages == Some(DeploymentState::InProgress)
+            || self
+                .environments
+                .iter()
+                .any(|env| env.state == DeploymentState::InProgress);
+        if in_progress {
+            DeployHealth::Deploying
+        } else {
+            DeployHealth::Healthy
+        }
+    }
+
+    /// One-line breakdown of Pages and environment states
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(pages) = &self.pages {
+            parts.push(t_with("models.pages", &[("state", &pages.emoji())]));
+        }
+        for env in &self.environments {
+            parts.push(format!("{} {}", env.name, env.state.emoji()));
+        }
+        if parts.is_empty() {
+            t("models.no_deployments").to_string()
+        } else {
+            parts.join(" · ")
+        }
+    }
+}
+
+impl DeployHealth {
+    /// Severity for colorblind-safe indicators
+    pub fn severity(&self) -> Severity {
+        match self {
+            DeployHealth::Healthy => Severity::Healthy,
+            DeployHealth::Deploying => Severity::Minor,
+            DeployHealth::Degraded => Severity::Serious,
+            DeployHealth::Broken => Severity::Critical,
+            DeployHealth::None => Severity::Neutral,
+        }
+    }
+
+    /// Get a short label for the table column
+    pub fn description(&self) -> &'static str {
+        match self {
+            DeployHealth::Healthy => t("deploy.healthy"),
+            DeployHealth::Deploying => t("deploy.deploying"),
+            DeployHealth::Degraded => t("deploy.degraded"),
+            DeployHealth::Broken => t("deploy.broken"),
+            DeployHealth::None => t("deploy.none"),
+        }
+    }
+
+    /// Get an emoji representation
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            DeployHealth::Healthy => "✅",
+            DeployHealth::Deploying => "⏳",
+            DeployHealth::Degraded => "🟡",
+            DeployHealth::Broken => "🔴",
+            DeployHealth::None => "",
+        }
+    }
+
+    /// Get color for UI rendering
+    pub fn color(&self) -> ratatui::style::Color {
+        match self {
+            DeployHealth::Healthy => ratatui::style::Color::Green,
+            DeployHealth::Deploying => ratatui::style::Color::Yellow,
+            DeployHealth::Degraded => ratatui::style::Color::LightRed,
+            DeployHealth::Broken => ratatui::style::Color::Red,
+            DeployHealth::None => ratatui::style::Color::DarkGray,
+        }
+    }
+}
+
+/// A pending invitation to join an organization
+#[derive(Debug, Clone)]
+pub struct OrgInvitation {
+    /// Login (or email for email invitations) of the invitee
+    pub invitee: String,
+    /// Role the invitee will get (e.g. "direct_member", "admin")
+    pub role: String,
+    /// Login of the member who sent the invitation
+    pub inviter: String,
+    /// When the invitation was sent
+    pub created_at: SystemTime,
+}
+
+/// An outside collaborator with write access to organization repositories
+#[derive(Debug, Clone)]
+pub struct OutsideCollaborator {
+    /// Collaborator login
+    pub login: String,
+    /// Repositories the collaborator can push to
+    pub repositories: Vec<String>,
+}
+
+/// Membership hygiene signals for an organization
+///
+/// Each signal is `None` when the token isn't allowed to see it (most of
+/// these endpoints are restricted to organization owners).
+#[derive(Debug, Clone)]
+pub struct OrgMembershipHealth {
+    /// Organization login
+    pub org: String,
+    /// Invitations that haven't been accepted yet
+    pub pending_invitations: Option<Vec<OrgInvitation>>,
+    /// Logins of members without two-factor authentication
+    pub members_without_2fa: Option<Vec<String>>,
+    /// Outside collaborators with write access
+    pub outside_collaborators: Option<Vec<OutsideCollaborator>>,
+}
+
+impl OrgMembershipHealth {
+    /// Create an empty report where nothing is visible yet
+    pub fn new(org: String) -> Self {
+        Self {
+            org,
+            pending_invitations: None,
+            members_without_2fa: None,
+            outside_collaborators: None,
+        }
+    }
+
+    /// Total number of visible hygiene issues
+    pub fn issue_count(&self) -> usize {
+        self.pending_invitations.as_ref().map_or(0, Vec::len)
+            + self.members_without_2fa.as_ref().map_or(0, Vec::len)
+            + self.outside_collaborators.as_ref().map_or(0, Vec::len)
+    }
+}
+
+/// When an issue was opened and (if so) closed, for backlog trends
+#[derive(Debug, Clone)]
+pub struct IssueActivity {
+    /// When the issue was opened
+    pub created_at: SystemTime,
+    /// When the issue was closed, if it has been
+    pub closed_at: Option<SystemTime>,
+    /// Number of comments on the issue
+    pub comments: u32,
+}
+
+/// Issues opened and closed during one week of a burn-down chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct BurndownWeek {
+    /// Start of the week
+    pub start: SystemTime,
+    /// Issues opened during the week
+    pub opened: usize,
+    /// Issues closed during the week
+    pub closed: usize,
+}
+
+impl BurndownWeek {
+    /// Bucket issue activity into the `weeks` weeks ending at `now`, oldest first
+    pub fn from_activity(issues: &[IssueActivity], weeks: usize, now: SystemTime) -> Vec<Self> {
+        const WEEK: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+        let window_start = now
+            .checked_sub(WEEK * weeks as u32)
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+        let mut buckets: Vec<Self> = (0..weeks)
+            .map(|week| Self {
+                start: window_start + WEEK * week as u32,
+                opened: 0,
+                closed: 0,
+            })
+            .collect();
+
+        let bucket = |time: SystemTime| {
+            time.duration_since(window_start)
+                .ok()
+                .map(|elapsed| (elapsed.as_secs() / WEEK.as_secs()) as usize)
+                .filter(|&week| week < weeks)
+        };
+        for issue in issues {
+            if let Some(week) = bucket(issue.created_at) {
+                buckets[week].opened += 1;
+            }
+            if let Some(week) = issue.closed_at.and_then(bucket) {
+                buckets[week].closed += 1;
+            }
+        }
+
+        buckets
+    }
+
+    /// Net change of the open backlog over the given weeks (positive = growing)
+    pub fn net_change(weeks: &[Self]) -> i64 {
+        weeks
+            .iter()
+            .map(|week| week.opened as i64 - week.closed as i64)
+            .sum()
+    }
+}
+
+/// Weeks of issue activity an issues-only repository is judged on
+pub const ISSUE_HEALTH_WEEKS: usize = 4;
+
+/// Open issues a repository needs before it can count as issues-only
+const ISSUES_ONLY_MIN_OPEN_ISSUES: u32 = 10;
+
+/// Days without commits before a repository with many issues counts as issues-only
+const ISSUES_ONLY_QUIET_DAYS: u64 = 90;
+
+/// Issue-centric health of an issues-only (planning or docs) repository
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssueHealth {
+    /// Issues opened during the last `ISSUE_HEALTH_WEEKS` weeks
+    pub opened: usize,
+    /// Issues closed during the same weeks
+    pub closed: usize,
+    /// Issues opened during those weeks that got a comment or were closed
+    pub responded: usize,
+    /// When an issue was last opened or closed
+    pub latest_activity: Option<SystemTime>,
+}
+
+impl IssueHealth {
+    /// Measure issue health over the `ISSUE_HEALTH_WEEKS` weeks ending at `now`
+    pub fn from_activity(issues: &[IssueActivity], now: SystemTime) -> Self {
+        let weeks = BurndownWeek::from_activity(issues, ISSUE_HEALTH_WEEKS, now);
+        let window_start = weeks.first().map_or(now, |week| week.start);
+        let responded = issues
+            .iter()
+            .filter(|issue| issue.created_at >= window_start)
+            .filter(|issue| issue.comments > 0 || issue.closed_at.is_some())
+            .count();
+
+        Self {
+            opened: weeks.iter().map(|week| week.opened).sum(),
+            closed: weeks.iter().map(|week| week.closed).sum(),
+            responded,
+            latest_activity: issues
+                .iter()
+                .flat_map(|issue| std::iter::once(issue.created_at).chain(issue.closed_at))
+                .max(),
+        }
+    }
+
+    /// Share of recently opened issues that got a response, in percent
+    pub fn responsiveness(&self) -> Option<u8> {
+        (self.opened > 0).then(|| (self.responded * 100 / self.opened) as u8)
+    }
+
+    /// Net change of the open backlog (positive = growing)
+    pub fn backlog_change(&self) -> i64 {
+        self.opened as i64 - self.closed as i64
+    }
+
+    /// Health penalty added to the activity penalty, which follows issue
+    /// activity instead of commits for these repositories
+    pub fn penalty(&self) -> u8 {
+        let responsiveness = match self.responsiveness() {
+            Some(0..=49) => 25,
+            Some(50..=79) => 10,
+            _ => 0,
+        };
+        let backlog = if self.backlog_change() > 0 { 15 } else { 0 };
+        responsiveness + backlog
+    }
+
+    /// One-line summary, e.g. "5 opened, 3 closed in 4 weeks · 80% responded"
+    pub fn summary(&self) -> String {
+        let mut summary = t_with(
+            "models.issue_flow",
+            &[
+                ("opened", &self.opened),
+                ("closed", &self.closed),
+                ("weeks", &ISSUE_HEALTH_WEEKS),
+            ],
+        );
+        if let Some(percentage) = self.responsiveness() {
+            summary.push_str(&t_with("models.responded", &[("percentage", &percentage)]));
+        }
+        summary
+    }
+}
+
+/// Open issue counts of a repository
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IssueMetrics {
+    /// Open issues, not counting pull requests
+    pub open: u32,
+    /// Open issues without a single comment, among the oldest 100
+    pub unanswered: u32,
+    /// When the oldest open issue was opened
+    pub oldest_opened_at: Option<SystemTime>,
+}
+
+impl IssueMetrics {
+    /// Measure from the open issue count and the oldest open issues
+    pub fn from_open_issues(open: u32, oldest: &[IssueActivity]) -> Self {
+        Self {
+            open,
+            unanswered: oldest.iter().filter(|issue| issue.comments == 0).count() as u32,
+            oldest_opened_at: oldest.iter().map(|issue| issue.created_at).min(),
+        }
+    }
+
+    /// Age of the oldest open issue in whole days
+    pub fn oldest_age_days(&self, now: SystemTime) -> Option<u64> {
+        self.oldest_opened_at
+            .map(|opened| now.duration_since(opened).unwrap_or_default().as_secs() / 86400)
+    }
+
+    /// One-line summary, e.g. "12 open issues · 3 unanswered · oldest 140 days"
+    pub fn summary(&self, now: SystemTime) -> String {
+        let mut summary = t_with("models.open_issues", &[("count", &self.open)]);
+        if self.unanswered > 0 {
+            summary.push_str(&t_with("models.unanswered", &[("count", &self.unanswered)]));
+        }
+        if let Some(days) = self.oldest_age_days(now) {
+            summary.push_str(&t_with("models.oldest_issue", &[("days", &days)]));
+        }
+        summary
+    }
+}
+
+/// Points open secret scanning alerts take off the health score
+const LEAKED_SECRETS_PENALTY: u8 = 40;
+
+/// Open code scanning alerts of a repository, by severity
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CodeScanningAlerts {
+    pub critical: u32,
+    pub high: u32,
+    pub medium: u32,
+    pub low: u32,
+}
+
+impl CodeScanningAlerts {
+    /// Count alerts by the security severity of their rule (`critical`,
+    /// `high`, `medium`, `low`), or for rules without one by their plain
+    /// severity (`error`, `warning`, `note`)
+    pub fn from_severities<'a>(severities: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut alerts = Self::default();
+        for severity in severities {
+            match severity {
+                "critical" => alerts.critical += 1,
+                "high" | "error" => alerts.high += 1,
+                "medium" | "warning" => alerts.medium += 1,
+                _ => alerts.low += 1,
+            }
+        }
+        alerts
+    }
+
+    /// Open alerts of every severity
+    pub fn total(&self) -> u32 {
+        self.critical + self.high + self.medium + self.low
+    }
+
+    /// Table cell, e.g. "5 (2 critical)", naming the worst severity present
+    pub fn label(&self) -> String {
+        let worst = [
+            (self.critical, t("models.critical")),
+            (self.high, t("models.high")),
+            (self.medium, t("models.medium")),
+        ]
+        .into_iter()
+        .find(|&(count, _)| count > 0);
+        match worst {
+            Some((count, severity)) => format!("{} ({} {})", self.total(), count, severity),
+            None => self.total().to_string(),
+        }
+    }
+
+    /// Severity of the worst open alert
+    pub fn severity(&self) -> Severity {
+        if self.critical > 0 {
+            Severity::Critical
+        } else if self.high > 0 {
+            Severity::Serious
+        } else if self.medium > 0 {
+            Severity::Warning
+        } else if self.low > 0 {
+            Severity::Minor
+        } else {
+            Severity::Healthy
+        }
+    }
+
+    /// Get color for UI rendering
+    pub fn color(&self) -> ratatui::style::Color {
+        match self.severity() {
+            Severity::Critical => ratatui::style::Color::Red,
+            Severity::Serious => ratatui::style::Color::LightRed,
+            Severity::Warning => ratatui::style::Color::Yellow,
+            Severity::Minor => ratatui::style::Color::Cyan,
+            _ => ratatui::style::Color::Green,
+        }
+    }
+
+    /// Points the alerts take off the health score, at most 30
+    pub fn penalty(&self) -> u8 {
+        (self.critical * 15 + self.high * 5 + self.medium * 2).min(30) as u8
+    }
+}
+
+/// Outcome of a custom health check command
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomCheckResult {
+    /// Name of the check in the config
+    pub name: String,
+    pub passed: bool,
+    /// First line of the command's output, or why it failed to run
+    pub detail: String,
+}
+
+/// Community health files of a repository, from its community profile
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CommunityProfile {
+    /// GitHub's community health percentage
+    pub health_percentage: u8,
+    pub readme: bool,
+    pub license: bool,
+    pub contributing: bool,
+    pub code_of_conduct: bool,
+    pub issue_templates: bool,
+}
+
+impl CommunityProfile {
+    /// Each recorded file with whether the repository has it
+    pub fn files(&self) -> [(&'static str, bool); 5] {
+        [
+            ("README", self.readme),
+            ("LICENSE", self.license),
+            ("CONTRIBUTING", self.contributing),
+            (t("models.code_of_conduct"), self.code_of_conduct),
+            (t("models.issue_templates"), self.issue_templates),
+        ]
+    }
+
+    /// Recorded files the repository lacks
+    pub fn missing(&self) -> Vec<&'static str> {
+        self.files()
+            .into_iter()
+            .filter(|&(_, present)| !present)
+            .map(|(file, _)| file)
+            .collect()
+    }
+
+    /// Table cell, e.g. "71%"
+    pub fn label(&self) -> String {
+        format!("{}%", self.health_percentage)
+    }
+
+    /// How far the profile is from complete
+    pub fn severity(&self) -> Severity {
+        match self.health_percentage {
+            80.. => Severity::Healthy,
+            50..80 => Severity::Warning,
+            _ => Severity::Serious,
+        }
+    }
+
+    /// Get color for UI rendering
+    pub fn color(&self) -> ratatui::style::Color {
+        match self.severity() {
+            Severity::Healthy => ratatui::style::Color::Green,
+            Severity::Warning => ratatui::style::Color::Yellow,
+            _ => ratatui::style::Color::LightRed,
+        }
+    }
+}
+
+/// Commits in the last four weeks a Hot or Active repository needs
+pub const SUSTAINED_COMMITS: u32 = 2;
+
+/// Bars of the commit sparkline, from no commits to the busiest week
+const SPARKLINE_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Weekly commit counts of a repository's last year, from the commit
+/// activity statistics
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommitActivity {
+    /// Commits per week, oldest first; the last week is the current one
+    pub weeks: Vec<u32>,
+}
+
+impl CommitActivity {
+    /// Commits in the last `weeks` weeks, the current one included
+    pub fn recent(&self, weeks: usize) -> u32 {
+        self.weeks.iter().rev().take(weeks).sum()
+    }
+
+    /// Sparkline of the last `weeks` weeks, scaled to the busiest of them
+    ///
+    /// Weeks without commits get the lowest bar; any commit raises it.
+    pub fn sparkline(&self, weeks: usize) -> String {
+        let shown = &self.weeks[self.weeks.len().saturating_sub(weeks)..];
+        let busiest = shown.iter().copied().max().unwrap_or(0).max(1) as usize;
+        let levels = SPARKLINE_BARS.len() - 1;
+        shown
+            .iter()
+            .map(|&commits| match commits as usize {
+                0 => SPARKLINE_BARS[0],
+                commits => SPARKLINE_BARS[(commits * levels).div_ceil(busiest)],
+            })
+            .collect()
+    }
+}
+
+/// Days of commits the bus factor looks back over
+pub const BUS_FACTOR_WINDOW_DAYS: u64 = 90;
+
+/// Share of recent commits above which one contributor is a risk
+const BUS_FACTOR_RISK_SHARE: u8 = 90;
+
+/// Commits one contributor made in a week, from the contributor statistics
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeeklyCommits {
+    pub author: String,
+    /// Start of the week
+    pub week: SystemTime,
+    pub commits: u32,
+}
+
+/// How concentrated a repository's recent commits are on one contributor
+#[derive(Debug, Clone, PartialEq)]
+pub struct BusFactor {
+    /// Login of the contributor with the most recent commits
+    pub top_contributor: String,
+    /// Their commits in the window
+    pub top_commits: u32,
+    /// Commits by everyone in the window
+    pub commits: u32,
+    /// Contributors with at least one commit in the window
+    pub contributors: usize,
+}
+
+impl BusFactor {
+    /// Concentration of the commits made in the last
+    /// [`BUS_FACTOR_WINDOW_DAYS`] days, `None` without any
+    pub fn from_weekly_commits(weeks: &[WeeklyCommits], now: SystemTime) -> Option<Self> {
+        let since = now - Duration::from_secs(BUS_FACTOR_WINDOW_DAYS * 86400);
+        let mut by_author: HashMap<&str, u32> = HashMap::new();
+        for week in weeks.iter().filter(|week| week.week >= since) {
+            if week.commits > 0 {
+                *by_author.entry(week.author.as_str()).or_default() += week.commits;
+            }
+        }
+        // Ties go to the alphabetically first login so the result is stable
+        let (top_contributor, top_commits) = by_author
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+            .map(|(author, commits)| (author.to_string(), *commits))?;
+        Some(Self {
+            top_contributor,
+            top_commits,
+            commits: by_author.values().sum(),
+            contributors: by_author.len(),
+        })
+    }
+
+    /// Percentage of the commits made by the top contributor
+    pub fn share(&self) -> u8 {
+        (u64::from(self.top_commits) * 100 / u64::from(self.commits.max(1))) as u8
+    }
+
+    /// Whether one person owns nearly all recent commits
+    pub fn is_risk(&self) -> bool {
+        // Compared exactly; the rounded-down share would miss 90.1%
+        u64::from(self.top_commits) * 100
+            > u64::from(self.commits) * u64::from(BUS_FACTOR_RISK_SHARE)
+    }
+
+    /// Short description, e.g. "94% by octocat"
+    pub fn label(&self) -> String {
+        t_with(
+            "models.bus_factor",
+            &[
+                ("share", &self.share()),
+                ("contributor", &self.top_contributor),
+            ],
+        )
+    }
+}
+
+/// Contributions the user made on one day
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContributionDay {
+    pub date: chrono::NaiveDate,
+    pub count: u32,
+}
+
+impl ContributionDay {
+    /// Row of the day in the calendar, from 0 (Sunday) to 6 (Saturday)
+    pub fn weekday(&self) -> usize {
+        use chrono::Datelike;
+        self.date.weekday().num_days_from_sunday() as usize
+    }
+}
+
+/// The authenticated user's contribution calendar for the last year
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContributionCalendar {
+    /// Login of the user the calendar belongs to
+    pub login: String,
+    /// Contributions over the whole calendar
+    pub total: u32,
+    /// Days oldest first, grouped into weeks starting on Sunday
+    ///
+    /// The first and last weeks may be partial.
+    pub weeks: Vec<Vec<ContributionDay>>,
+}
+
+impl ContributionCalendar {
+    /// Every day of the calendar, oldest first
+    pub fn days(&self) -> impl DoubleEndedIterator<Item = &ContributionDay> {
+        self.weeks.iter().flatten()
+    }
+
+    /// Consecutive days with contributions up to `today`
+    ///
+    /// Like on GitHub, having no contributions yet today doesn't end the streak.
+    pub fn current_streak(&self, today: chrono::NaiveDate) -> usize {
+        let mut days = self.days().rev().filter(|day| day.date <= today).peekable();
+        if days
+            .peek()
+            .is_some_and(|day| day.date == today && day.count == 0)
+        {
+            days.next();
+        }
+        days.take_while(|day| day.count > 0).count()
+    }
+
+    /// Most consecutive days with contributions in the calendar
+    pub fn longest_streak(&self) -> usize {
+        self.days()
+            .fold((0, 0), |(longest, current), day| {
+                let current = if day.count > 0 { current + 1 } else { 0 };
+                (longest.max(current), current)
+            })
+            .0
+    }
+
+    /// Heatmap intensity of a day from 0 (none) to 4, relative to the busiest day
+    pub fn level(&self, count: u32) -> u8 {
+        let busiest = self.days().map(|day| day.count).max().unwrap_or(0);
+        if count == 0 || busiest == 0 {
+            0
+        } else {
+            (count * 4).div_ceil(busiest).min(4) as u8
+        }
+    }
+}
+
+/// How a repository's size compares to the configured thresholds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SizeLevel {
+    Normal,
+    Large,
+    Huge,
+}
+
+/// How good or bad an indicator is, from best to worst
+///
+/// Shared by the status, workflow and deploy indicators so alternate themes
+/// can encode them by shape or letter as well as color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Healthy,
+    Minor,
+    Warning,
+    Serious,
+    Critical,
+    /// Unknown, or nothing to report
+    Neutral,
+}
+
+impl SizeLevel {
+    /// Get color for UI rendering
+    pub fn color(&self) -> ratatui::style::Color {
+        match self {
+            SizeLevel::Normal => ratatui::style::Color::Gray,
+            SizeLevel::Large => ratatui::style::Color::Yellow,
+            SizeLevel::Huge => ratatui::style::Color::Red,
+        }
+    }
+}
+
+/// How many recent commit messages follow the commit convention
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConventionCompliance {
+    pub matching: usize,
+    pub sampled: usize,
+}
+
+impl ConventionCompliance {
+    /// Check commit subject lines against the convention pattern
+    ///
+    /// Returns `None` when there are no commits to sample.
+    pub fn measure(subjects: &[String], pattern: &Regex) -> Option<Self> {
+        if subjects.is_empty() {
+            return None;
+        }
+        Some(Self {
+            matching: subjects
+                .iter()
+                .filter(|subject| pattern.is_match(subject))
+                .count(),
+            sampled: subjects.len(),
+        })
+    }
+
+    /// Share of sampled commits following the convention, as a percentage
+    pub fn percentage(&self) -> u8 {
+        (self.matching * 100 / self.sampled) as u8
+    }
+}
+
+/// How closely one repository follows the standard template
+#[derive(Debug, Clone)]
+pub struct RepoCompliance {
+    /// Repository name
+    pub repository: String,
+    /// Expected paths the repository is missing (empty when compliant)
+    pub missing: Vec<String>,
+    /// Whether the repository's files could be read (false for empty repos)
+    pub scanned: bool,
+}
+
+/// Template compliance of every scanned repository
+#[derive(Debug, Clone)]
+pub struct ComplianceReport {
+    /// Template repository the expectations come from (`owner/name`)
+    pub template: String,
+    /// Paths every repository is expected to contain
+    pub expected: Vec<String>,
+    /// Per-repository results, least compliant first
+    pub repositories: Vec<RepoCompliance>,
+}
+
+impl ComplianceReport {
+    /// Build a report from each repository's file list
+    ///
+    /// `None` file lists (e.g. empty repositories) count as not scanned.
+    pub fn new(
+        template: String,
+        expected: Vec<String>,
+        files: Vec<(String, Option<std::collections::HashSet<String>>)>,
+    ) -> Self {
+        let mut repositories: Vec<RepoCompliance> = files
+            .into_iter()
+            .map(|(repository, paths)| RepoCompliance {
+                missing: match &paths {
+                    Some(paths) => expected
+                        .iter()
+                        .filter(|path| !paths.contains(*path))
+                        .cloned()
+                        .collect(),
+                    None => Vec::new(),
+                },
+                scanned: paths.is_some(),
+                repository,
+            })
+            .collect();
+        repositories.sort_by(|a, b| {
+            b.missing
+                .len()
+                .cmp(&a.missing.len())
+                .then_with(|| a.repository.cmp(&b.repository))
+        });
+
+        Self {
+            template,
+            expected,
+            repositories,
+        }
+    }
+
+    /// Compliance percentage of a single repository
+    pub fn percentage(&self, repo: &RepoCompliance) -> u8 {
+        if self.expected.is_empty() {
+            return 100;
+        }
+        let present = self.expected.len() - repo.missing.len();
+        (present * 100 / self.expected.len()) as u8
+    }
+
+    /// Overall compliance: share of expected files present across scanned repositories
+    pub fn overall_percentage(&self) -> u8 {
+        let scanned: Vec<&RepoCompliance> = self
+            .repositories
+            .iter()
+            .filter(|repo| repo.scanned)
+            .collect();
+        let expected = self.expected.len() * scanned.len();
+        if expected == 0 {
+            return 100;
+        }
+        let missing: usize = scanned.iter().map(|repo| repo.missing.len()).sum();
+        ((expected - missing) * 100 / expected) as u8
+    }
+
+    /// Number of scanned repositories missing nothing
+    pub fn compliant_count(&self) -> usize {
+        self.repositories
+            .iter()
+            .filter(|repo| repo.scanned && repo.missing.is_empty())
+            .count()
+    }
+}
+
+/// Health metrics summed up over a set of repositories
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AggregateMetrics {
+    pub repositories: usize,
+    /// Mean health score (None for an empty set)
+    pub average_health: Option<u8>,
+    pub needs_attention: usize,
+    pub open_pull_requests: usize,
+    pub ci_broken: usize,
+    /// Repositories with no commits in over 3 months
+    pub inactive: usize,
+}
+
+impl AggregateMetrics {
+    /// Summarize repositories, counting attention under the given rules
+    pub fn from_repositories(repositories: &[Repository], attention: &AttentionConfig) -> Self {
+        let total_health: usize = repositories
+            .iter()
+            .map(|repo| repo.health_score() as usize)
+            .sum();
+
+        Self {
+            repositories: repositories.len(),
+            average_health: (!repositories.is_empty())
+                .then(|| (total_health / repositories.len()) as u8),
+            needs_attention: repositories
+                .iter()
+                .filter(|repo| attention.matches(repo))
+                .count(),
+            open_pull_requests: repositories
+                .iter()
+                .map(Repository::open_pull_request_count)
+                .sum(),
+            ci_broken: repositories
+                .iter()
+                .filter(|repo| repo.is_ci_broken())
+                .count(),
+            inactive: repositories
+                .iter()
+                .filter(|repo| {
+                    matches!(
+                        repo.status,
+                        RepositoryStatus::Stale | RepositoryStatus::Dormant
+                    )
+                })
+                .count(),
+        }
+    }
+}
+
+/// Why one of a repository's indicators has its value
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndicatorExplanation {
+    /// Indicator name, as in the table header
+    pub indicator: &'static str,
+    /// Value shown for the indicator
+    pub value: String,
+    /// What the value was computed from
+    pub reason: String,
+}
+
+/// Stable placeholder for a private repository's name, e.g. "private-3fa9c2"
+///
+/// The same repository always gets the same placeholder, so rows can still be
+/// told apart and followed across refreshes during a screen-share.
+pub fn redacted_name(full_name: &str) -> String {
+    // FNV-1a, which unlike `DefaultHasher` is stable across Rust releases
+    let hash = full_name.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("private-{:06x}", hash & 0xffffff)
+}
+
+/// How the authenticated user reaches a repository
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Affiliation {
+    /// The user owns the repository
+    Owner,
+    /// The user was added to someone else's repository
+    Collaborator,
+    /// The repository belongs to an organization the user is a member of
+    OrganizationMember,
+}
+
+impl Affiliation {
+    /// Affiliation to a repository from its owner, as seen by `viewer`
+    ///
+    /// GitHub doesn't say which affiliation matched, so organization-owned
+    /// repositories count as reached through the membership.
+    pub fn of(owner: &str, owner_is_organization: bool, viewer: &str) -> Self {
+        if owner.eq_ignore_ascii_case(viewer) {
+            Affiliation::Owner
+        } else if owner_is_organization {
+            Affiliation::OrganizationMember
+        } else {
+            Affiliation::Collaborator
+        }
+    }
+
+    /// Localized label shown in the affiliation column
+    pub fn label(&self) -> &'static str {
+        match self {
+            Affiliation::Owner => t("affiliation.owner"),
+            Affiliation::Collaborator => t("affiliation.collaborator"),
+            Affiliation::OrganizationMember => t("affiliation.member"),
+        }
+    }
+}
+
+/// Represents a complete repository with all its health data
+#[derive(Debug, Clone)]
+pub struct Repository {
+    /// Repository name
+    pub name: String,
+    /// Repository owner/organization
+    pub owner: String,
+    /// Overall health status based on commit activity
+    pub status: RepositoryStatus,
+    /// Overall workflow health based on recent runs
+    pub workflow_health: WorkflowHealth,
+    /// Most recent workflow run
+    pub latest_workflow: Option<WorkflowRun>,
+    /// Recent workflow runs for health calculation
+    pub recent_workflows: Vec<WorkflowRun>,
+    /// Open pull requests, up to one page of them
+    pub open_pull_requests: Vec<PullRequest>,
+    /// Total open pull requests from the search API, when more than a page are open
+    pub open_pull_request_total: Option<usize>,
+    /// When this data was last fetched
+    pub last_updated: SystemTime,
+    /// URL to the repository on GitHub
+    pub html_url: String,
+    /// Repository description
+    pub description: Option<String>,
+    /// Primary programming language
+    pub language: Option<String>,
+    /// Number of stars
+    pub stars: u32,
+    /// Latest commit timestamp
+    pub latest_commit_at: Option<SystemTime>,
+    /// Subject lines of the most recent default-branch commits, newest first
+    pub recent_commit_subjects: Vec<String>,
+    /// Repository size in kilobytes as reported by GitHub
+    pub size_kb: u64,
+    /// Whether `.gitattributes` routes files through Git LFS (None until fetched)
+    pub uses_lfs: Option<bool>,
+    /// Repository topics (tags)
+    pub topics: Vec<String>,
+    /// Whether GitHub Pages is enabled
+    pub has_pages: bool,
+    /// Whether the repository is a fork of another
+    pub fork: bool,
+    /// Whether the repository is private
+    pub private: bool,
+    /// Whether the authenticated user administers the repository
+    pub admin: bool,
+    /// Whether the repository is archived (read-only)
+    pub archived: bool,
+    /// GitHub Pages and deployment environment status
+    pub deploy_status: DeployStatus,
+    /// Latest published release (None when there is none or it wasn't fetched)
+    pub latest_release: Option<Release>,
+    /// Open issues carrying the critical label (None until fetched)
+    pub critical_issues: Option<Vec<Issue>>,
+    /// Open issues plus open pull requests, as GitHub counts them
+    pub open_issues: u32,
+    /// Issue-centric health, set for issues-only repositories
+    pub issue_health: Option<IssueHealth>,
+    /// Open issue counts (None until fetched)
+    pub issue_metrics: Option<IssueMetrics>,
+    /// Open code scanning alerts (None until fetched, or when code scanning
+    /// isn't set up or visible to the token)
+    pub code_scanning: Option<CodeScanningAlerts>,
+    /// Open secret scanning alerts, i.e. leaked credentials (None until
+    /// fetched, or when secret scanning isn't enabled or visible to the token)
+    pub secret_scanning_alerts: Option<u32>,
+    /// Protection of the default branch (None until fetched)
+    pub branch_protection: Option<BranchProtectionStatus>,
+    /// Community health files (None until fetched, and for forks, which
+    /// have no community profile)
+    pub community: Option<CommunityProfile>,
+    /// Concentration of recent commits (None until fetched, while GitHub is
+    /// still computing the statistics, or without recent commits)
+    pub bus_factor: Option<BusFactor>,
+    /// Weekly commits over the last year (None until fetched, or while
+    /// GitHub is still computing the statistics)
+    pub commit_activity: Option<CommitActivity>,
+    /// Whether the repository has no commits at all
+    pub empty: bool,
+    /// How the user reaches the repository (None unless collaborator and
+    /// member repositories were listed)
+    pub affiliation: Option<Affiliation>,
+    /// When the repository was created
+    pub created_at: Option<SystemTime>,
+    /// Name of the default branch (empty until listed)
+    pub default_branch: String,
+    /// Whether loading its details timed out on the last refresh, leaving
+    /// them incomplete
+    pub fetch_timed_out: bool,
+}
+
+impl Repository {
+    /// Create a new repository with default values
+    pub fn new(name: String, owner: String) -> Self {
+        Self {
+            name,
+            owner,
+            status: RepositoryStatus::Unknown,
+            workflow_health: WorkflowHealth::Unknown,
+            latest_workflow: None,
+            recent_workflows: Vec::new(),
+            open_pull_requests: Vec::new(),
+            open_pull_request_total: None,
+            last_updated: SystemTime::now(),
+            html_url: String::new(),
+            description: None,
+            language: None,
+            stars: 0,
+            latest_commit_at: None,
+            recent_commit_subjects: Vec::new(),
+            size_kb: 0,
+            uses_lfs: None,
+            topics: Vec::new(),
+            has_pages: false,
+            fork: false,
+            private: false,
+            admin: false,
+            archived: false,
+            deploy_status: DeployStatus::default(),
+            latest_release: None,
+            critical_issues: None,
+            open_issues: 0,
+            issue_health: None,
+            issue_metrics: None,
+            code_scanning: None,
+            secret_scanning_alerts: None,
+            branch_protection: None,
+            community: None,
+            bus_factor: None,
+            commit_activity: None,
+            empty: false,
+            affiliation: None,
+            created_at: None,
+            default_branch: String::new(),
+            fetch_timed_out: false,
+        }
+    }
+
+    /// Whether secret scanning found credentials that are still exposed
+    pub fn has_leaked_secrets(&self) -> bool {
+        self.secret_scanning_alerts.is_some_and(|alerts| alerts > 0)
+    }
+
+    /// Number of open pull requests, including those past the fetched page
+    pub fn open_pull_request_count(&self) -> usize {
+        self.open_pull_request_total
+            .unwrap_or_default()
+            .max(self.open_pull_requests.len())
+    }
+
+    /// Number of open issues, which GitHub counts together with open pull requests
+    pub fn open_issue_count(&self) -> u32 {
+        self.open_issues
+            .saturating_sub(self.open_pull_request_count() as u32)
+    }
+
+    /// Whether this looks like a planning or docs repository that lives in
+    /// its issues: no code language, no commits for months, many open issues
+    pub fn is_issues_only(&self, now: SystemTime) -> bool {
+        let issues = self.open_issue_count();
+        let quiet = self.latest_commit_at.is_none_or(|commit| {
+            now.duration_since(commit)
+                .is_ok_and(|idle| idle.as_secs() / 86400 >= ISSUES_ONLY_QUIET_DAYS)
+        });
+        self.language.is_none() && issues >= ISSUES_ONLY_MIN_OPEN_ISSUES && quiet
+    }
+
+    /// Get the full repository name (owner/name)
+    pub fn full_name(&self) -> String {
+        format!("{}/{}", self.owner, self.name)
+    }
+
+    /// Name shown in the UI, replaced by a placeholder for private
+    /// repositories while guest mode is on
+    pub fn display_name(&self, guest: bool) -> String {
+        if guest && self.private {
+            redacted_name(&self.full_name())
+        } else {
+            self.name.clone()
+        }
+    }
+
+    /// Full name (owner/name) shown in the UI, redacted like `display_name`
+    pub fn display_full_name(&self, guest: bool) -> String {
+        format!("{}/{}", self.owner, self.display_name(guest))
+    }
+
+    /// Description shown in the UI, hidden for private repositories in guest mode
+    pub fn display_description(&self, guest: bool) -> String {
+        if guest && self.private {
+            t("models.hidden_description").to_string()
+        } else {
+            self.description.clone().unwrap_or_default()
+        }
+    }
+
+    /// Get a summary of the repository's current state
+    pub fn status_summary(&self) -> String {
+        let workflow_status = self
+            .latest_workflow
+            .as_ref()
+            .map(|w| w.status.description())
+            .unwrap_or(t("models.no_workflows"));
+
+        let pr_count = self.open_pull_request_count();
+
+        let mut summary = t_with(
+            "models.status_summary",
+            &[
+                ("status", &self.status.description()),
+                ("workflows", &workflow_status),
+                ("count", &pr_count),
+            ],
+        );
+        if let Some(metrics) = &self.issue_metrics {
+            summary.push_str(&format!(" | {}", metrics.summary(SystemTime::now())));
+        }
+        if let Some(health) = &self.issue_health {
+            summary.push_str(&t_with(
+                "models.issues_only",
+                &[("summary", &health.summary())],
+            ));
+        }
+        summary
+    }
+
+    /// Recalculate workflow health with the configured workflow weights
+    ///
+    /// Repositories whose runs haven't been fetched yet keep their health.
+    pub fn weigh_workflow_health(&mut self, ci: &CiConfig) {
+        if !self.recent_workflows.is_empty() {
+            self.workflow_health =
+                WorkflowHealth::from_weighted_workflow_runs(&self.recent_workflows, |name| {
+                    ci.workflow_weight(name)
+                });
+        }
+    }
+
+    /// Overall health score from 0 (critical) to 100 (healthy)
+    ///
+    /// Combines commit activity, workflow health and deployment health.
+    /// Unknown signals don't reduce the score.
+    pub fn health_score(&self) -> u8 {
+        let (activity, workflows, deploy, security) = self.health_penalties();
+        100u8.saturating_sub(activity + workflows + deploy + security)
+    }
+
+    /// Points the activity, workflow, deploy and code scanning signals take
+    /// off the health score
+    fn health_penalties(&self) -> (u8, u8, u8, u8) {
+        let activity_penalty = match self.status {
+            RepositoryStatus::Moderate => 5,
+            RepositoryStatus::Quiet => 15,
+            RepositoryStatus::Stale => 30,
+            RepositoryStatus::Dormant => 45,
+            _ => 0,
+        } + self.issue_health.as_ref().map_or(0, IssueHealth::penalty);
+        let workflow_penalty = match self.workflow_health {
+            WorkflowHealth::Good => 10,
+            WorkflowHealth::Fair => 25,
+            WorkflowHealth::Poor => 40,
+            WorkflowHealth::Critical => 50,
+            _ => 0,
+        };
+        let deploy_penalty = match self.deploy_status.health() {
+            DeployHealth::Degraded => 10,
+            DeployHealth::Broken => 30,
+            _ => 0,
+        };
+
+        let secrets_penalty = if self.has_leaked_secrets() {
+            LEAKED_SECRETS_PENALTY
+        } else {
+            0
+        };
+        let security_penalty = self
+            .code_scanning
+            .as_ref()
+            .map_or(0, CodeScanningAlerts::penalty)
+            + secrets_penalty;
+
+        (
+            activity_penalty,
+            workflow_penalty,
+            deploy_penalty,
+            security_penalty,
+        )
+    }
+
+    /// Explain why each dashboard indicator has its value
+    pub fn explain_indicators(
+        &self,
+        config: &AppConfig,
+        now: SystemTime,
+    ) -> Vec<IndicatorExplanation> {
+        let explain =
+            |indicator: &'static str, value: String, reason: String| IndicatorExplanation {
+                indicator,
+                value,
+                reason,
+            };
+        let mut explanations = Vec::new();
+
+        if self.fetch_timed_out {
+            explanations.push(explain(
+                t("explain.details"),
+                t("explain.timed_out").to_string(),
+                t("explain.timed_out_reason").to_string(),
+            ));
+        }
+
+        let activity = match (&self.issue_health, self.latest_commit_at) {
+            (Some(health), _) => {
+                let last = match health.latest_activity {
+                    Some(at) => t_with(
+                        "explain.last_issue_activity",
+                        &[(
+                            "days",
+                            &(now.duration_since(at).unwrap_or_default().as_secs() / 86400),
+                        )],
+                    ),
+                    None => t("explain.no_issue_activity").to_string(),
+                };
+                t_with(
+                    "explain.issues_only",
+                    &[("last", &last), ("summary", &health.summary())],
+                )
+            }
+            (None, Some(commit)) => {
+                let days = now.duration_since(commit).unwrap_or_default().as_secs() / 86400;
+                match days {
+                    0 => t("explain.commit_today").to_string(),
+                    1 => t("explain.commit_yesterday").to_string(),
+                    days => t_with("explain.commit_days_ago", &[("days", &days)]),
+                }
+            }
+            (None, None) if self.empty => t("explain.no_commits_pushed").to_string(),
+            (None, None) => t("explain.no_commits_loaded").to_string(),
+        };
+        explanations.push(explain(
+            t("table.status"),
+            self.status.description().to_string(),
+            activity,
+        ));
+
+        let workflows = if self.recent_workflows.is_empty() {
+            t("explain.no_workflow_runs").to_string()
+        } else {
+            let passed = self
+                .recent_workflows
+                .iter()
+                .filter(|run| run.status == WorkflowStatus::Success)
+                .count();
+            let mut reason = t_with(
+                "explain.runs_passed",
+                &[("passed", &passed), ("total", &self.recent_workflows.len())],
+            );
+            let (weighted_passed, weighted_total) =
+                self.recent_workflows
+                    .iter()
+                    .fold((0, 0), |(passed, total), run| {
+                        let weight = config.ci.workflow_weight(&run.name);
+                        if run.status == WorkflowStatus::Success {
+                            (passed + weight, total + weight)
+                        } else {
+                            (passed, total + weight)
+                        }
+                    });
+            if weighted_total as usize != self.recent_workflows.len() {
+                reason.push_str(&t_with(
+                    "explain.weighted",
+                    &[
+                        ("passed", &weighted_passed),
+                        ("total", &weighted_total),
+                        ("weight", &config.ci.critical_weight),
+                    ],
+                ));
+            }
+            reason
+        };
+        explanations.push(explain(
+            t("table.workflows"),
+            self.workflow_health.description().to_string(),
+            workflows,
+        ));
+
+        let failed: Vec<&str> = self
+            .deploy_status
+            .environments
+            .iter()
+            .filter(|env| env.state == DeploymentState::Failure)
+            .map(|env| env.name.as_str())
+            .collect();
+        let deploy = if self.deploy_status.pages == Some(DeploymentState::Failure) {
+            t("explain.pages_failed").to_string()
+        } else if !failed.is_empty() {
+            t_with(
+                "explain.deployment_failed",
+                &[("environments", &failed.join(", "))],
+            )
+        } else {
+            self.deploy_status.summary()
+        };
+        explanations.push(explain(
+            t("table.deploy"),
+            self.deploy_status.health().description().to_string(),
+            deploy,
+        ));
+
+        let drafts = self.open_pull_requests.iter().filter(|pr| pr.draft).count();
+        let mut pull_requests = t_with(
+            "explain.pull_requests",
+            &[
+                ("open", &self.open_pull_request_count()),
+                ("drafts", &drafts),
+            ],
+        );
+        if config.reviews.branching_drift(self) {
+            pull_requests.push_str(&t_with(
+                "explain.branching_drift",
+                &[("count", &self.target_branches().other_count())],
+            ));
+        }
+        explanations.push(explain(
+            t("table.pull_requests"),
+            self.open_pull_request_count().to_string(),
+            pull_requests,
+        ));
+
+        let size = match config.size.level(self.size_kb) {
+            SizeLevel::Huge => t_with("explain.size_huge", &[("mb", &config.size.huge_mb)]),
+            SizeLevel::Large => t_with("explain.size_large", &[("mb", &config.size.large_mb)]),
+            SizeLevel::Normal => t_with("explain.size_normal", &[("mb", &config.size.large_mb)]),
+        };
+        explanations.push(explain(t("table.size"), self.size_label(), size));
+
+        if let Some(created) = self.created_at {
+            let created = chrono::DateTime::<chrono::Local>::from(created).date_naive();
+            let reason = if config.age.never_released(self, now) {
+                t_with(
+                    "explain.created_unreleased",
+                    &[
+                        ("date", &created),
+                        ("days", &config.age.unreleased_after_days),
+                    ],
+                )
+            } else {
+                t_with("explain.created", &[("date", &created)])
+            };
+            explanations.push(explain(t("table.age"), self.age_label(now), reason));
+        }
+
+        if let Some(status) = &self.branch_protection {
+            let violations = config.protection.violations(status);
+            let (value, reason) = match status {
+                _ if !violations.is_empty() => (t("explain.fail"), violations.join("; ")),
+                BranchProtectionStatus::Protected(Some(rules)) => (
+                    t("explain.pass"),
+                    t_with(
+                        "explain.protection_rules",
+                        &[
+                            ("approvals", &rules.required_approvals),
+                            ("checks", &rules.required_checks.len()),
+                        ],
+                    ),
+                ),
+                _ => (
+                    t("explain.pass"),
+                    t("explain.protection_hidden").to_string(),
+                ),
+            };
+            explanations.push(explain(t("table.protection"), value.to_string(), reason));
+        }
+
+        if let Some(alerts) = self.secret_scanning_alerts {
+            let reason = match alerts {
+                0 => t("explain.no_secrets").to_string(),
+                _ => t_with("explain.secrets_open", &[("count", &alerts)]),
+            };
+            explanations.push(explain(t("explain.secrets"), alerts.to_string(), reason));
+        }
+
+        if let Some(profile) = &self.community {
+            let reason = match profile.missing().as_slice() {
+                [] => t("explain.community_complete").to_string(),
+                missing => t_with("explain.missing", &[("files", &missing.join(", "))]),
+            };
+            explanations.push(explain(t("table.community"), profile.label(), reason));
+        }
+
+        if let Some(bus_factor) = &self.bus_factor {
+            let reason = if bus_factor.is_risk() {
+                t_with(
+                    "explain.bus_factor_risk",
+                    &[
+                        ("contributor", &bus_factor.top_contributor),
+                        ("top", &bus_factor.top_commits),
+                        ("commits", &bus_factor.commits),
+                        ("days", &BUS_FACTOR_WINDOW_DAYS),
+                    ],
+                )
+            } else {
+                t_with(
+                    "explain.bus_factor_shared",
+                    &[
+                        ("contributors", &bus_factor.contributors),
+                        ("commits", &bus_factor.commits),
+                        ("days", &BUS_FACTOR_WINDOW_DAYS),
+                    ],
+                )
+            };
+            explanations.push(explain(t("explain.bus_factor"), bus_factor.label(), reason));
+        }
+
+        if let Some(alerts) = &self.code_scanning {
+            let reason = match alerts.total() {
+                0 => t("explain.no_code_scanning").to_string(),
+                _ => t_with(
+                    "explain.code_scanning",
+                    &[
+                        ("critical", &alerts.critical),
+                        ("high", &alerts.high),
+                        ("medium", &alerts.medium),
+                        ("low", &alerts.low),
+                    ],
+                ),
+            };
+            explanations.push(explain(t("table.code_scanning"), alerts.label(), reason));
+        }
+
+        let (activity, workflows, deploy, security) = self.health_penalties();
+        let mut health = t_with(
+            "explain.penalties",
+            &[
+                ("activity", &activity),
+                ("workflows", &workflows),
+                ("deploy", &deploy),
+            ],
+        );
+        if security > 0 {
+            health.push_str(&t_with(
+                "explain.security_penalty",
+                &[("security", &security)],
+            ));
+        }
+        explanations.push(explain(
+            t("explain.health"),
+            format!("{}/100", self.health_score()),
+            health,
+        ));
+
+        let rules = config.attention.matched_rules(self);
+        let today = chrono::DateTime::<chrono::Local>::from(now).date_naive();
+        let attention = if config.attention.is_snoozed(self, today) {
+            let until = config.attention.snoozed_until(self).unwrap_or(today);
+            explain(
+                t("explain.attention"),
+                t("explain.snoozed").to_string(),
+                t_with("explain.snoozed_until", &[("date", &until)]),
+            )
+        } else if rules.is_empty() {
+            explain(
+                t("explain.attention"),
+                t("explain.no").to_string(),
+                t("explain.no_rule").to_string(),
+            )
+        } else {
+            explain(
+                t("explain.attention"),
+                t("explain.yes").to_string(),
+                rules.join(", "),
+            )
+        };
+        explanations.push(attention);
+
+        explanations
+    }
+
+    /// Fetched open pull requests grouped by the branch they merge into
+    pub fn target_branches(&self) -> TargetBranches {
+        let mut targets = TargetBranches::default();
+        let mut other: HashMap<&str, usize> = HashMap::new();
+        for pr in &self.open_pull_requests {
+            let base = pr.base_branch.as_str();
+            if base == self.default_branch || self.default_branch.is_empty() {
+                targets.default += 1;
+            } else if TargetBranches::is_release_branch(base) {
+                targets.release += 1;
+            } else {
+                *other.entry(base).or_default() += 1;
+            }
+        }
+        targets.other = other
+            .into_iter()
+            .map(|(branch, count)| (branch.to_string(), count))
+            .collect();
+        targets
+            .other
+            .sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        targets
+    }
+
+    /// Open pull requests that close the given issue
+    pub fn pull_requests_closing(&self, issue: u64) -> Vec<&PullRequest> {
+        self.open_pull_requests
+            .iter()
+            .filter(|pr| pr.linked_issues.contains(&issue))
+            .collect()
+    }
+
+    /// Whole days since the repository was created
+    pub fn age_days(&self, now: SystemTime) -> Option<u64> {
+        self.created_at
+            .map(|created| now.duration_since(created).unwrap_or_default().as_secs() / 86400)
+    }
+
+    /// Compact repository age for the Age column, e.g. "12d", "8mo" or "3y"
+    pub fn age_label(&self, now: SystemTime) -> String {
+        match self.age_days(now) {
+            None => "-".to_string(),
+            Some(days) if days < 60 => format!("{}d", days),
+            Some(days) if days < 730 => format!("{}mo", days / 30),
+            Some(days) => format!("{}y", days / 365),
+        }
+    }
+
+    /// Human-readable repository size, marked when Git LFS is in use
+    pub fn size_label(&self) -> String {
+        let size = match self.size_kb {
+            kb if kb >= 1024 * 1024 => format!("{:.1} GB", kb as f64 / (1024.0 * 1024.0)),
+            kb if kb >= 1024 => format!("{} MB", kb / 1024),
+            kb => format!("{} KB", kb),
+        };
+        if self.uses_lfs == Some(true) {
+            format!("{} LFS", size)
+        } else {
+            size
+        }
+    }
+
+    /// Critical issues that no open pull request closes
+    pub fn unlinked_critical_issues(&self) -> Vec<&Issue> {
+        self.critical_issues
+            .iter()
+            .flatten()
+            .filter(|issue| self.pull_requests_closing(issue.number).is_empty())
+            .collect()
+    }
+
+    /// Open pull requests whose reviewer feedback has waited longer than `threshold`
+    pub fn pull_requests_awaiting_author(&self, threshold: Duration, now: SystemTime) -> usize {
+        self.open_pull_requests
+            .iter()
+            .filter(|pr| pr.is_awaiting_author(threshold, now))
+            .count()
+    }
+
+    /// Workflow runs currently queued or running
+    pub fn active_workflow_runs(&self) -> usize {
+        self.recent_workflows
+            .iter()
+            .filter(|run| run.status == WorkflowStatus::InProgress)
+            .count()
+    }
+
+    /// Average duration of completed workflow runs
+    pub fn average_workflow_duration(&self) -> Option<Duration> {
+        let durations: Vec<Duration> = self
+            .recent_workflows
+            .iter()
+            .filter_map(|run| run.duration)
+            .collect();
+        if durations.is_empty() {
+            return None;
+        }
+        Some(durations.iter().sum::<Duration>() / durations.len() as u32)
+    }
+
+    /// Check if the most recent workflow run failed
+    pub fn is_ci_broken(&self) -> bool {
+        self.latest_workflow
+            .as_ref()
+            .is_some_and(|run| run.status == WorkflowStatus::Failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repository_status() {
+        assert_eq!(RepositoryStatus::Hot.emoji(), "🔥");
+        assert_eq!(RepositoryStatus::Active.emoji(), "⚡");
+        assert_eq!(RepositoryStatus::Dormant.emoji(), "💤");
+    }
+
+    #[test]
+    fn test_commit_activity_sparkline_and_status() {
+        let mut weeks = vec![0; 52];
+        weeks[40] = 30;
+        weeks.extend([0, 14, 1, 7]);
+        let activity = CommitActivity { weeks };
+        assert_eq!(activity.sparkline(6), "▁▁▁█▂▅");
+        assert_eq!(activity.sparkline(100).chars().count(), 56);
+        assert_eq!((activity.recent(4), activity.recent(52)), (22, 52));
+        assert_eq!(CommitActivity::default().sparkline(12), "");
+
+        let yesterday = Some(SystemTime::now() - Duration::from_secs(86400 + 60));
+        assert_eq!(
+            RepositoryStatus::from_activity(yesterday, Some(&activity)),
+            RepositoryStatus::Active
+        );
+        // A single commit after a quiet year isn't sustained activity
+        let lone = CommitActivity {
+            weeks: [vec![0; 51], vec![1]].concat(),
+        };
+        assert_eq!(
+            RepositoryStatus::from_activity(yesterday, Some(&lone)),
+            RepositoryStatus::Moderate
+        );
+        assert_eq!(
+            RepositoryStatus::from_activity(yesterday, None),
+            RepositoryStatus::Active
+        );
+    }
+
+    #[test]
+    fn test_workflow_status() {
+        assert_eq!(WorkflowStatus::Success.description(), "Passed");
+        assert_eq!(WorkflowStatus::Failed.description(), "Failed");
+        assert_eq!(WorkflowStatus::InProgress.description(), "Running");
+    }
+
+    #[test]
+    fn test_check_run_status_from_conclusion() {
+        assert_eq!(
+            CheckRun::status_from_conclusion(Some("success")),
+            WorkflowStatus::Success
+        );
+        assert_eq!(
+            CheckRun::status_from_conclusion(Some("timed_out")),
+            WorkflowStatus::Failed
+        );
+        assert_eq!(
+            CheckRun::status_from_conclusion(None),
+            WorkflowStatus::InProgress
+        );
+
+        let check = CheckRun {
+            id: 1,
+            name: "lint".to_string(),
+            status: WorkflowStatus::Failed,
+        };
+        assert!(check.can_rerun());
+    }
+
+    #[test]
+    fn test_deploy_health() {
+        let mut deploy = DeployStatus::default();
+        assert_eq!(deploy.health(), DeployHealth::None);
+
+        deploy.pages = Some(DeploymentState::Success);
+        deploy.environments.push(EnvironmentDeployment {
+            name: "staging".to_string(),
+            state: DeploymentState::Failure,
+        });
+        assert_eq!(deploy.health(), DeployHealth::Degraded);
+
+        deploy.environments.push(EnvironmentDeployment {
+            name: "production".to_string(),
+            state: DeploymentState::Failure,
+        });
+        assert_eq!(deploy.health(), DeployHealth::Broken);
+
+        let pages_broken = DeployStatus {
+            pages: Some(DeploymentState::from_pages_status("errored")),
+            environments: Vec::new(),
+        };
+        assert_eq!(pages_broken.health(), DeployHealth::Broken);
+    }
+
+    #[test]
+    fn test_org_membership_issue_count() {
+        let mut health = OrgMembershipHealth::new("acme".to_string());
+        assert_eq!(health.issue_count(), 0);
+
+        health.members_without_2fa = Some(vec!["alice".to_string(), "bob".to_string()]);
+        health.outside_collaborators = Some(vec![OutsideCollaborator {
+            login: "carol".to_string(),
+            repositories: vec!["api".to_string()],
+        }]);
+        assert_eq!(health.issue_count(), 3);
+    }
+
+    #[test]
+    fn test_health_score() {
+        let mut repo = Repository::new("repo".to_string(), "org".to_string());
+        assert_eq!(repo.health_score(), 100);
+
+        repo.status = RepositoryStatus::Stale;
+        repo.workflow_health = WorkflowHealth::Fair;
+        assert_eq!(repo.health_score(), 45);
+
+        repo.status = RepositoryStatus::Dormant;
+        repo.workflow_health = WorkflowHealth::Critical;
+        repo.deploy_status.pages = Some(DeploymentState::Failure);
+        assert_eq!(repo.health_score(), 0);
+    }
+
+    #[test]
+    fn test_parse_linked_issues() {
+        let body = "Fixes #12 and closes: #7.\nRelated to #99, resolves #12\nfixup #3";
+        assert_eq!(PullRequest::parse_linked_issues(body), vec![12, 7]);
+        assert!(PullRequest::parse_linked_issues("See #4").is_empty());
+    }
+
+    #[test]
+    fn test_issue_action_labels_and_description() {
+        assert_eq!(
+            IssueAction::parse_labels(" bug, ,needs triage ,"),
+            vec!["bug", "needs triage"]
+        );
+        let action = IssueAction::Label {
+            add: vec!["bug".to_string()],
+            remove: vec!["triage".to_string(), "stale".to_string()],
+        };
+        assert_eq!(
+            action.describe(3),
+            "Labels on #3: added bug; removed triage, stale"
+        );
+        assert_eq!(
+            IssueAction::Assign("hubot".to_string()).describe(3),
+            "Assigned #3 to hubot"
+        );
+    }
+
+    #[test]
+    fn test_unlinked_critical_issues() {
+        let mut repo = Repository::new("repo".to_string(), "org".to_string());
+        assert!(repo.unlinked_critical_issues().is_empty());
+
+        let issue = |number| Issue {
+            number,
+            title: format!("Issue {}", number),
+            html_url: String::new(),
+            assignees: Vec::new(),
+            labels: Vec::new(),
+            comments: 0,
+        };
+        repo.critical_issues = Some(vec![issue(1), issue(2)]);
+        repo.open_pull_requests.push(PullRequest {
+            number: 10,
+            title: "Fix crash".to_string(),
+            state: PullRequestState::Open,
+            created_at: SystemTime::now(),
+            updated_at: SystemTime::now(),
+            author: "octocat".to_string(),
+            html_url: String::new(),
+            draft: false,
+            approvals: 0,
+            changes_requested: 0,
+            head_sha: String::new(),
+            base_branch: "main".to_string(),
+            linked_issues: vec![1],
+            tasks: None,
+            feedback_waiting_since: None,
+            review_decision: None,
+            checks: None,
+            labels: Vec::new(),
+            milestone: None,
+        });
+
+        assert_eq!(repo.pull_requests_closing(1).len(), 1);
+        let unlinked: Vec<u64> = repo
+            .unlinked_critical_issues()
+            .iter()
+            .map(|issue| issue.number)
+            .collect();
+        assert_eq!(unlinked, vec![2]);
+    }
+
+    #[test]
+    fn test_target_branches_flag_drift() {
+        let mut repo = Repository::new("repo".to_string(), "org".to_string());
+        repo.default_branch = "main".to_string();
+        let pr = |number: u32, base: &str| PullRequest {
+            number,
+            title: format!("PR {}", number),
+            state: PullRequestState::Open,
+            created_at: SystemTime::now(),
+            updated_at: SystemTime::now(),
+            author: "octocat".to_string(),
+            html_url: String::new(),
+            draft: false,
+            approvals: 0,
+            changes_requested: 0,
+            head_sha: String::new(),
+            base_branch: base.to_string(),
+            linked_issues: Vec::new(),
+            tasks: None,
+            feedback_waiting_since: None,
+            review_decision: None,
+            checks: None,
+            labels: Vec::new(),
+            milestone: None,
+        };
+        repo.open_pull_requests = vec![
+            pr(1, "main"),
+            pr(2, "release/2.1"),
+            pr(3, "develop"),
+            pr(4, "spike"),
+            pr(5, "develop"),
+        ];
+
+        let targets = repo.target_branches();
+        assert_eq!((targets.default, targets.release), (1, 1));
+        assert_eq!(targets.other_count(), 3);
+        assert_eq!(
+            targets.summary("main"),
+            "main 1 · release/* 1 · other 3 (develop 2, spike 1)"
+        );
+        let config = AppConfig::default();
+        assert!(config.reviews.branching_drift(&repo));
+
+        repo.open_pull_requests.pop();
+        assert!(!config.reviews.branching_drift(&repo));
+    }
+
+    #[test]
+    fn test_task_progress() {
+        let body = "## Checklist\n\
+            - [x] Tests\n\
+            * [X] Docs\n\
+            1. [ ] Changelog\n\
+            - [ ]\n\
+            ```\n\
+            - [ ] not a task\n\
+            ```\n\
+            [ ] not a list item";
+        let tasks = TaskProgress::parse(body).unwrap();
+        assert_eq!(tasks, TaskProgress { done: 2, total: 4 });
+        assert!(!tasks.is_complete());
+        assert_eq!(TaskProgress::parse("No tasks here"), None);
+
+        let mut pr = PullRequest {
+            number: 1,
+            title: "Add refunds".to_string(),
+            state: PullRequestState::Open,
+            created_at: SystemTime::now(),
+            updated_at: SystemTime::now(),
+            author: "octocat".to_string(),
+            html_url: String::new(),
+            draft: true,
+            approvals: 0,
+            changes_requested: 0,
+            head_sha: String::new(),
+            base_branch: "main".to_string(),
+            linked_issues: Vec::new(),
+            tasks: Some(tasks),
+            feedback_waiting_since: None,
+            review_decision: None,
+            checks: None,
+            labels: Vec::new(),
+            milestone: None,
+        };
+        assert!(!pr.has_incomplete_checklist());
+        pr.draft = false;
+        assert!(pr.has_incomplete_checklist());
+    }
+
+    #[test]
+    fn test_merge_blockers_combine_protection_and_checks() {
+        let pr = PullRequest {
+            number: 3,
+            title: "Bump deps".to_string(),
+            state: PullRequestState::Open,
+            created_at: SystemTime::now(),
+            updated_at: SystemTime::now(),
+            author: "octocat".to_string(),
+            html_url: String::new(),
+            draft: false,
+            approvals: 1,
+            changes_requested: 0,
+            head_sha: String::new(),
+            base_branch: "main".to_string(),
+            linked_issues: Vec::new(),
+            tasks: None,
+            feedback_waiting_since: None,
+            review_decision: None,
+            checks: None,
+            labels: Vec::new(),
+            milestone: None,
+        };
+        let check = |name: &str, conclusion: Option<&str>| CheckRun {
+            id: 0,
+            name: name.to_string(),
+            status: CheckRun::status_from_conclusion(conclusion),
+        };
+        let checks = vec![
+            check("build", Some("failure")),
+            check("lint", Some("failure")),
+            check("e2e", None),
+        ];
+        let mut requirements = MergeRequirements {
+            mergeable: Some(true),
+            mergeable_state: "blocked".to_string(),
+            base_branch: "main".to_string(),
+            protection: Some(BranchProtection {
+                required_approvals: 2,
+                code_owner_reviews: false,
+                required_checks: vec!["build".to_string(), "e2e".to_string(), "deploy".to_string()],
+                strict: true,
+            }),
+        };
+
+        // Only required checks count once protection is known
+        assert_eq!(
+            requirements.blockers(&pr, &checks),
+            vec![
+                MergeBlocker::MissingApprovals {
+                    have: 1,
+                    required: 2,
+                    code_owners: false,
+                },
+                MergeBlocker::FailingChecks(vec!["build".to_string()]),
+                MergeBlocker::PendingChecks(vec!["e2e".to_string()]),
+                MergeBlocker::MissingChecks(vec!["deploy".to_string()]),
+            ]
+        );
+
+        // Without protection every failing check may matter, and an
+        // unexplained "blocked" is still reported
+        requirements.protection = None;
+        assert_eq!(
+            requirements.blockers(&pr, &checks)[0],
+            MergeBlocker::FailingChecks(vec!["build".to_string(), "lint".to_string()])
+        );
+        assert_eq!(requirements.blockers(&pr, &[]), vec![MergeBlocker::Blocked]);
+        requirements.mergeable_state = "clean".to_string();
+        assert!(requirements.blockers(&pr, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_unanswered_review_feedback() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let hours = |h: u64| start + Duration::from_secs(h * 3600);
+        let comment = |pull_request: u32, author: &str, at: SystemTime| ReviewComment {
+            pull_request,
+            author: author.to_string(),
+            created_at: at,
+        };
+        let mut repo = Repository::new("api".to_string(), "acme".to_string());
+        repo.open_pull_requests.push(PullRequest {
+            number: 7,
+            title: "Retry webhooks".to_string(),
+            state: PullRequestState::Open,
+            created_at: start,
+            updated_at: start,
+            author: "hubot".to_string(),
+            html_url: String::new(),
+            draft: false,
+            approvals: 0,
+            changes_requested: 0,
+            head_sha: String::new(),
+            base_branch: "main".to_string(),
+            linked_issues: Vec::new(),
+            tasks: None,
+            feedback_waiting_since: None,
+            review_decision: None,
+            checks: None,
+            labels: Vec::new(),
+            milestone: None,
+        });
+        let pr = &repo.open_pull_requests[0];
+
+        // The author answered the first round; the second round is still open
+        let comments = vec![
+            comment(7, "octocat", hours(5)),
+            comment(7, "octocat", hours(1)),
+            comment(7, "hubot", hours(2)),
+            comment(7, "dependabot[bot]", hours(3)),
+            comment(7, "monalisa", hours(6)),
+            comment(8, "octocat", hours(0)),
+        ];
+        assert_eq!(pr.unanswered_feedback_since(&comments), Some(hours(5)));
+        assert_eq!(pr.unanswered_feedback_since(&comments[1..3]), None);
+
+        repo.open_pull_requests[0].feedback_waiting_since = Some(hours(5));
+        let threshold = Duration::from_secs(48 * 3600);
+        assert_eq!(repo.pull_requests_awaiting_author(threshold, hours(40)), 0);
+        assert_eq!(repo.pull_requests_awaiting_author(threshold, hours(60)), 1);
+    }
+
+    #[test]
+    fn test_review_tally_uses_each_reviewers_latest_vote() {
+        let mut pr = PullRequest {
+            number: 7,
+            title: "Retry webhooks".to_string(),
+            state: PullRequestState::Open,
+            created_at: SystemTime::now(),
+            updated_at: SystemTime::now(),
+            author: "hubot".to_string(),
+            html_url: String::new(),
+            draft: false,
+            approvals: 0,
+            changes_requested: 0,
+            head_sha: String::new(),
+            base_branch: "main".to_string(),
+            linked_issues: Vec::new(),
+            tasks: None,
+            feedback_waiting_since: None,
+            review_decision: None,
+            checks: None,
+            labels: Vec::new(),
+            milestone: None,
+        };
+        let review = |author: &str, state: &str| SubmittedReview {
+            author: author.to_string(),
+            state: state.to_string(),
+        };
+
+        pr.tally_reviews(&[
+            review("octocat", "CHANGES_REQUESTED"),
+            review("octocat", "COMMENTED"),
+            review("monalisa", "APPROVED"),
+            review("mona", "APPROVED"),
+            review("mona", "DISMISSED"),
+            review("hubot", "APPROVED"),
+        ]);
+        assert_eq!((pr.approvals, pr.changes_requested), (1, 1));
+
+        // Approving after requesting changes unblocks the PR
+        pr.tally_reviews(&[
+            review("octocat", "CHANGES_REQUESTED"),
+            review("octocat", "APPROVED"),
+        ]);
+        assert_eq!((pr.approvals, pr.changes_requested), (1, 0));
+    }
+
+    #[test]
+    fn test_repository_creation() {
+        let repo = Repository::new("test-repo".to_string(), "test-org".to_string());
+        assert_eq!(repo.full_name(), "test-org/test-repo");
+        assert_eq!(repo.status, RepositoryStatus::Unknown);
+        assert!(repo.open_pull_requests.is_empty());
+    }
+
+    #[test]
+    fn test_workflow_health() {
+        let run1 = WorkflowRun {
+            id: 1,
+            name: "CI".to_string(),
             status: WorkflowStatus::Success,
             created_at: SystemTime::now(),
             updated_at: SystemTime::now(),
+            started_at: None,
             duration: Some(Duration::from_secs(60)),
-            conclusion: Some("success".to_string()),
-            html_url: "http://example.com".to_string(),
+            trigger: RunTrigger::default(),
         };
 
         let run2 = WorkflowRun {
@@ -477,12 +3577,577 @@ mod tests {
             status: WorkflowStatus::Failed,
             created_at: SystemTime::now(),
             updated_at: SystemTime::now(),
+            started_at: None,
             duration: Some(Duration::from_secs(120)),
-            conclusion: Some("failure".to_string()),
-            html_url: "http://example.com".to_string(),
+            trigger: RunTrigger::default(),
+        };
+
+        assert_eq!(
+            WorkflowHealth::from_workflow_runs(std::slice::from_ref(&run1)),
+            WorkflowHealth::Excellent
+        );
+        assert_eq!(
+            WorkflowHealth::from_workflow_runs(&[run1, run2]),
+            WorkflowHealth::Fair
+        );
+    }
+
+    #[test]
+    fn test_review_events() {
+        let approve = ReviewEvent::from_label("approve").unwrap();
+        assert_eq!(approve.api_name(), "APPROVE");
+        assert!(!approve.requires_body());
+
+        let comment = ReviewEvent::from_label("comment").unwrap();
+        assert_eq!(comment.api_name(), "COMMENT");
+        assert!(comment.requires_body());
+        assert!(ReviewEvent::from_label("request changes").is_none());
+    }
+
+    #[test]
+    fn test_weighted_workflow_health() {
+        let run = |name: &str, status: WorkflowStatus| WorkflowRun {
+            id: 1,
+            name: name.to_string(),
+            status,
+            created_at: SystemTime::now(),
+            updated_at: SystemTime::now(),
+            started_at: None,
+            duration: None,
+            trigger: RunTrigger::default(),
+        };
+        let runs = [
+            run("Deploy", WorkflowStatus::Failed),
+            run("Lint", WorkflowStatus::Success),
+            run("Lint", WorkflowStatus::Success),
+            run("Lint", WorkflowStatus::Success),
+            run("Docs", WorkflowStatus::Success),
+        ];
+        let weight = |name: &str| if name == "Deploy" { 3 } else { 1 };
+
+        // 4 of 5 runs pass, but the failing deploy outweighs them
+        assert_eq!(
+            WorkflowHealth::from_workflow_runs(&runs),
+            WorkflowHealth::Good
+        );
+        assert_eq!(
+            WorkflowHealth::from_weighted_workflow_runs(&runs, weight),
+            WorkflowHealth::Fair
+        );
+        assert_eq!(
+            WorkflowHealth::from_weighted_workflow_runs(&runs[..2], weight),
+            WorkflowHealth::Poor
+        );
+
+        let breakdown = WorkflowBreakdown::from_workflow_runs(&runs, weight);
+        assert_eq!(breakdown[0].name, "Deploy");
+        assert_eq!((breakdown[0].weight, breakdown[0].failures), (3, 1));
+        assert_eq!(breakdown[2].name, "Lint");
+        assert_eq!((breakdown[2].runs, breakdown[2].failures), (3, 0));
+    }
+
+    #[test]
+    fn test_queue_stats() {
+        let created_at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let run = |queued_secs: Option<u64>| WorkflowRun {
+            id: 1,
+            name: "CI".to_string(),
+            status: WorkflowStatus::Success,
+            created_at,
+            updated_at: created_at,
+            started_at: queued_secs.map(|secs| created_at + Duration::from_secs(secs)),
+            duration: None,
+            trigger: RunTrigger::default(),
+        };
+        let threshold = Duration::from_secs(300);
+
+        assert!(QueueStats::from_workflow_runs(&[run(None)], threshold).is_none());
+
+        let stats =
+            QueueStats::from_workflow_runs(&[run(Some(60)), run(Some(600)), run(None)], threshold)
+                .unwrap();
+        assert_eq!(stats.average, Duration::from_secs(330));
+        assert_eq!(stats.longest, Duration::from_secs(600));
+        assert_eq!(stats.measured_runs, 2);
+        assert!(stats.is_regularly_slow());
+
+        let fast = QueueStats::from_workflow_runs(&[run(Some(10)), run(Some(20))], threshold);
+        assert!(!fast.unwrap().is_regularly_slow());
+    }
+
+    #[test]
+    fn test_timeline_layout() {
+        let hour = Duration::from_secs(3600);
+        let now = SystemTime::UNIX_EPOCH + hour * 1000;
+        let run = |id: u64, hours_ago: u32, minutes: u64, status: WorkflowStatus| {
+            let started_at = now - hour * hours_ago;
+            WorkflowRun {
+                id,
+                name: "CI".to_string(),
+                status: status.clone(),
+                created_at: started_at,
+                updated_at: started_at + Duration::from_secs(minutes * 60),
+                started_at: Some(started_at),
+                duration: (status != WorkflowStatus::InProgress)
+                    .then(|| Duration::from_secs(minutes * 60)),
+                trigger: RunTrigger::default(),
+            }
+        };
+        // Newest first, as the API returns them
+        let runs = vec![
+            run(4, 1, 0, WorkflowStatus::InProgress),
+            run(3, 6, 180, WorkflowStatus::Failed),
+            run(2, 12, 5, WorkflowStatus::Success),
+            run(1, 48, 5, WorkflowStatus::Success),
+        ];
+
+        let bars = TimelineBar::layout(&runs, hour * 24, now, 24);
+        assert_eq!(
+            bars,
+            vec![
+                TimelineBar {
+                    run: 2,
+                    offset: 12,
+                    length: 1
+                },
+                TimelineBar {
+                    run: 1,
+                    offset: 18,
+                    length: 3
+                },
+                // Still running, so it reaches the right edge
+                TimelineBar {
+                    run: 0,
+                    offset: 23,
+                    length: 1
+                },
+            ]
+        );
+
+        // Zooming out brings the older run back
+        assert_eq!(TimelineBar::layout(&runs, hour * 72, now, 24).len(), 4);
+        assert!(TimelineBar::layout(&runs, hour * 24, now, 0).is_empty());
+    }
+
+    #[test]
+    fn test_weekly_burndown() {
+        let week = Duration::from_secs(7 * 24 * 60 * 60);
+        let now = SystemTime::UNIX_EPOCH + week * 100;
+        let issue = |opened_weeks_ago: u32, closed_weeks_ago: Option<u32>| IssueActivity {
+            created_at: now - week * opened_weeks_ago + Duration::from_secs(60),
+            closed_at: closed_weeks_ago.map(|ago| now - week * ago + Duration::from_secs(60)),
+            comments: 0,
+        };
+        let issues = vec![
+            issue(20, Some(2)), // opened before the window, closed inside it
+            issue(3, None),
+            issue(3, Some(1)),
+            issue(1, None),
+        ];
+
+        let weeks = BurndownWeek::from_activity(&issues, 4, now);
+        assert_eq!(weeks.len(), 4);
+        let opened: Vec<usize> = weeks.iter().map(|w| w.opened).collect();
+        let closed: Vec<usize> = weeks.iter().map(|w| w.closed).collect();
+        assert_eq!(opened, vec![0, 2, 0, 1]);
+        assert_eq!(closed, vec![0, 0, 1, 1]);
+        assert_eq!(BurndownWeek::net_change(&weeks), 1);
+    }
+
+    #[test]
+    fn test_issues_only_repository_health() {
+        let day = Duration::from_secs(24 * 60 * 60);
+        let now = SystemTime::UNIX_EPOCH + day * 1000;
+        let mut repo = Repository::new("roadmap".to_string(), "acme".to_string());
+        repo.open_issues = 40;
+        repo.latest_commit_at = Some(now - day * 400);
+        assert!(repo.is_issues_only(now));
+
+        // Code, recent commits or a small backlog make it a normal repository
+        let mut coded = repo.clone();
+        coded.language = Some("Rust".to_string());
+        assert!(!coded.is_issues_only(now));
+        let mut committed = repo.clone();
+        committed.latest_commit_at = Some(now - day * 10);
+        assert!(!committed.is_issues_only(now));
+        let mut small = repo.clone();
+        small.open_issues = 5;
+        assert!(!small.is_issues_only(now));
+
+        let issue = |opened_days_ago: u32, closed_days_ago: Option<u32>, comments| IssueActivity {
+            created_at: now - day * opened_days_ago,
+            closed_at: closed_days_ago.map(|ago| now - day * ago),
+            comments,
+        };
+        let health = IssueHealth::from_activity(
+            &[
+                issue(60, Some(20), 3),
+                issue(12, None, 1),
+                issue(9, Some(2), 0),
+                issue(5, None, 0),
+                issue(1, None, 2),
+            ],
+            now,
+        );
+        assert_eq!((health.opened, health.closed, health.responded), (4, 2, 3));
+        assert_eq!(health.responsiveness(), Some(75));
+        assert_eq!(health.backlog_change(), 2);
+        assert_eq!(health.latest_activity, Some(now - day));
+        assert_eq!(
+            health.summary(),
+            "4 opened, 2 closed in 4 weeks · 75% responded"
+        );
+
+        // Judged on issue activity (a day ago) rather than commits, the
+        // repository is active; the slow responses and growing backlog count
+        repo.status = RepositoryStatus::Active;
+        repo.issue_health = Some(health);
+        assert_eq!(repo.health_penalties().0, 10 + 15);
+    }
+
+    #[test]
+    fn test_diffstat_bar_widths() {
+        let file = |additions, deletions| ChangedFile {
+            path: "src/lib.rs".to_string(),
+            status: "modified".to_string(),
+            additions,
+            deletions,
+        };
+
+        assert_eq!(file(150, 50).bar_widths(200, 20), (15, 5));
+        assert_eq!(file(10, 0).bar_widths(200, 20), (1, 0));
+        assert_eq!(file(0, 1).bar_widths(1000, 20), (0, 1));
+        assert_eq!(file(0, 0).bar_widths(200, 20), (0, 0));
+    }
+
+    #[test]
+    fn test_contribution_streaks() {
+        // Sunday 2026-03-01 through Saturday 2026-03-14
+        let start = chrono::NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let counts = [1, 2, 0, 3, 4, 5, 0, 0, 1, 8, 2, 1, 3, 0];
+        let days: Vec<ContributionDay> = counts
+            .iter()
+            .enumerate()
+            .map(|(offset, &count)| ContributionDay {
+                date: start + chrono::Duration::days(offset as i64),
+                count,
+            })
+            .collect();
+        let calendar = ContributionCalendar {
+            login: "octocat".to_string(),
+            total: counts.iter().sum(),
+            weeks: days.chunks(7).map(<[ContributionDay]>::to_vec).collect(),
+        };
+
+        assert_eq!(calendar.weeks[1][0].weekday(), 0);
+        assert_eq!(calendar.longest_streak(), 5);
+        // No contributions yet today doesn't end yesterday's streak
+        let today = start + chrono::Duration::days(13);
+        assert_eq!(calendar.current_streak(today), 5);
+        assert_eq!(
+            calendar.current_streak(today - chrono::Duration::days(1)),
+            5
+        );
+        assert_eq!(
+            calendar.current_streak(start + chrono::Duration::days(7)),
+            0
+        );
+
+        assert_eq!(calendar.level(0), 0);
+        assert_eq!(calendar.level(1), 1);
+        assert_eq!(calendar.level(4), 2);
+        assert_eq!(calendar.level(8), 4);
+    }
+
+    #[test]
+    fn test_bus_factor_over_recent_weeks() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1000 * 86400);
+        let week = |author: &str, days_ago: u64, commits: u32| WeeklyCommits {
+            author: author.to_string(),
+            week: now - Duration::from_secs(days_ago * 86400),
+            commits,
+        };
+        assert_eq!(BusFactor::from_weekly_commits(&[], now), None);
+        assert_eq!(
+            BusFactor::from_weekly_commits(&[week("alice", 120, 40)], now),
+            None
+        );
+
+        let shared = [
+            week("bob", 10, 6),
+            week("alice", 3, 4),
+            week("alice", 17, 2),
+            week("carol", 120, 90),
+        ];
+        let bus_factor = BusFactor::from_weekly_commits(&shared, now).unwrap();
+        assert_eq!(bus_factor.contributors, 2);
+        assert_eq!(bus_factor.label(), "50% by alice");
+        assert!(!bus_factor.is_risk());
+
+        let solo = [week("alice", 3, 91), week("bob", 3, 9)];
+        let bus_factor = BusFactor::from_weekly_commits(&solo, now).unwrap();
+        assert_eq!(bus_factor.share(), 91);
+        assert!(bus_factor.is_risk());
+
+        let just_over = [week("alice", 3, 901), week("bob", 3, 99)];
+        let bus_factor = BusFactor::from_weekly_commits(&just_over, now).unwrap();
+        assert_eq!(bus_factor.share(), 90);
+        assert!(bus_factor.is_risk());
+        let exactly = [week("alice", 3, 90), week("bob", 3, 10)];
+        let bus_factor = BusFactor::from_weekly_commits(&exactly, now).unwrap();
+        assert!(!bus_factor.is_risk());
+    }
+
+    #[test]
+    fn test_explain_indicators() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1000 * 86400);
+        let run = |status: WorkflowStatus| WorkflowRun {
+            id: 1,
+            name: "CI".to_string(),
+            status,
+            created_at: now,
+            updated_at: now,
+            started_at: None,
+            duration: None,
+            trigger: RunTrigger::default(),
+        };
+        let mut repo = Repository::new("api".to_string(), "acme".to_string());
+        repo.latest_commit_at = Some(now - Duration::from_secs(112 * 86400));
+        repo.status = RepositoryStatus::Stale;
+        repo.recent_workflows = vec![
+            run(WorkflowStatus::Success),
+            run(WorkflowStatus::Failed),
+            run(WorkflowStatus::Success),
+            run(WorkflowStatus::Failed),
+            run(WorkflowStatus::Success),
+        ];
+        repo.workflow_health = WorkflowHealth::from_workflow_runs(&repo.recent_workflows);
+
+        let explanations = repo.explain_indicators(&AppConfig::default(), now);
+        let reason = |indicator: &str| {
+            explanations
+                .iter()
+                .find(|explanation| explanation.indicator == indicator)
+                .map(|explanation| (explanation.value.as_str(), explanation.reason.as_str()))
+                .unwrap()
+        };
+        assert_eq!(reason("Status").1, "last commit 112 days ago");
+        assert_eq!(reason("Workflows").1, "3/5 recent runs passed");
+        assert_eq!(
+            reason("Health"),
+            ("45/100", "100 − 30 activity − 25 workflows − 0 deploy")
+        );
+        assert_eq!(reason("Attention"), ("yes", "inactive"));
+    }
+
+    #[test]
+    fn test_aggregate_metrics() {
+        let attention = AttentionConfig::default();
+        assert_eq!(
+            AggregateMetrics::from_repositories(&[], &attention),
+            AggregateMetrics::default()
+        );
+
+        let healthy = Repository::new("api".to_string(), "acme".to_string());
+        let mut dormant = Repository::new("legacy".to_string(), "acme".to_string());
+        dormant.status = RepositoryStatus::Dormant;
+
+        let metrics =
+            AggregateMetrics::from_repositories(&[healthy.clone(), dormant.clone()], &attention);
+        assert_eq!(metrics.repositories, 2);
+        assert_eq!(
+            metrics.average_health,
+            Some(((healthy.health_score() as usize + dormant.health_score() as usize) / 2) as u8)
+        );
+        assert_eq!(metrics.open_pull_requests, 0);
+        assert_eq!(metrics.inactive, 1);
+        assert_eq!(metrics.needs_attention, 1);
+    }
+
+    #[test]
+    fn test_size_label() {
+        let mut repo = Repository::new("assets".to_string(), "acme".to_string());
+        repo.size_kb = 512;
+        assert_eq!(repo.size_label(), "512 KB");
+
+        repo.size_kb = 300 * 1024;
+        assert_eq!(repo.size_label(), "300 MB");
+
+        repo.size_kb = 3 * 1024 * 1024 / 2;
+        repo.uses_lfs = Some(true);
+        assert_eq!(repo.size_label(), "1.5 GB LFS");
+    }
+
+    #[test]
+    fn test_issue_search_queries_split_long_repository_lists() {
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        let filter = IssueSearchFilter {
+            label: Some("good first issue".to_string()),
+            assignee: Some("none".to_string()),
+            min_age_days: Some(30),
+        };
+        assert_eq!(
+            filter.queries(&["acme/api".to_string()], today),
+            vec![
+                "is:issue is:open label:\"good first issue\" no:assignee created:<=2026-09-16 \
+                 repo:acme/api"
+            ]
+        );
+        assert_eq!(
+            filter.summary(),
+            "label good first issue · unassigned · 30+ days old"
+        );
+
+        let repositories: Vec<String> = (0..20)
+            .map(|index| format!("acme/service-{:02}", index))
+            .collect();
+        let queries = IssueSearchFilter::default().queries(&repositories, today);
+        assert_eq!(queries.len(), 2);
+        assert!(queries
+            .iter()
+            .all(|query| query.len() <= SEARCH_QUERY_MAX_LEN));
+        assert!(queries[1].starts_with("is:issue is:open repo:"));
+        assert_eq!(queries.join(" ").matches("repo:").count(), 20);
+        assert!(IssueSearchFilter::default().queries(&[], today).is_empty());
+    }
+
+    #[test]
+    fn test_issue_metrics_summary() {
+        let now = SystemTime::now();
+        let issue = |days: u64, comments: u32| IssueActivity {
+            created_at: now - Duration::from_secs(days * 86400),
+            closed_at: None,
+            comments,
+        };
+        let metrics =
+            IssueMetrics::from_open_issues(12, &[issue(140, 0), issue(30, 2), issue(2, 0)]);
+        assert_eq!(metrics.unanswered, 2);
+        assert_eq!(metrics.oldest_age_days(now), Some(140));
+        assert_eq!(
+            metrics.summary(now),
+            "12 open issues · 2 unanswered · oldest 140 days"
+        );
+        assert_eq!(
+            IssueMetrics::from_open_issues(0, &[]).summary(now),
+            "0 open issues"
+        );
+    }
+
+    #[test]
+    fn test_code_scanning_alerts() {
+        let alerts =
+            CodeScanningAlerts::from_severities(["high", "critical", "error", "warning", "note"]);
+        assert_eq!(
+            alerts,
+            CodeScanningAlerts {
+                critical: 1,
+                high: 2,
+                medium: 1,
+                low: 1
+            }
+        );
+        assert_eq!(alerts.label(), "5 (1 critical)");
+        assert_eq!(alerts.severity(), Severity::Critical);
+        assert_eq!(alerts.penalty(), 27);
+
+        let mut repo = Repository::new("api".to_string(), "acme".to_string());
+        repo.status = RepositoryStatus::Active;
+        repo.workflow_health = WorkflowHealth::Excellent;
+        repo.code_scanning = Some(alerts);
+        assert_eq!(repo.health_score(), 73);
+
+        let low = CodeScanningAlerts::from_severities(["low"]);
+        assert_eq!(low.label(), "1");
+        assert_eq!(low.penalty(), 0);
+        assert_eq!(CodeScanningAlerts::default().severity(), Severity::Healthy);
+    }
+
+    #[test]
+    fn test_release_age() {
+        let now = SystemTime::now();
+        let mut release = Release {
+            name: "Spring cleanup".to_string(),
+            tag: "v2.3.0".to_string(),
+            published_at: now - Duration::from_secs(34 * 86400 + 3600),
         };
+        assert_eq!(release.days_ago(now), 34);
+        assert_eq!(release.released_ago(now), "released 34 days ago");
+        assert!(!release.is_stale(now));
+
+        release.published_at = now - Duration::from_secs(STALE_RELEASE_DAYS * 86400);
+        assert!(release.is_stale(now));
+        release.published_at = now;
+        assert_eq!(release.released_ago(now), "released today");
+    }
+
+    #[test]
+    fn test_convention_compliance() {
+        let pattern = Regex::new(crate::config::DEFAULT_COMMIT_CONVENTION).unwrap();
+        let subjects: Vec<String> = [
+            "feat(ui): add burn-down chart",
+            "fix!: drop legacy config keys",
+            "Update README",
+            "chore: bump dependencies",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+        let compliance = ConventionCompliance::measure(&subjects, &pattern).unwrap();
+        assert_eq!(compliance.matching, 3);
+        assert_eq!(compliance.percentage(), 75);
+        assert!(ConventionCompliance::measure(&[], &pattern).is_none());
+    }
+
+    #[test]
+    fn test_compliance_report() {
+        let files = |paths: &[&str]| Some(paths.iter().map(|p| p.to_string()).collect());
+        let expected = vec![
+            "SECURITY.md".to_string(),
+            ".github/workflows/ci.yml".to_string(),
+        ];
+        let report = ComplianceReport::new(
+            "acme/template".to_string(),
+            expected,
+            vec![
+                (
+                    "full".to_string(),
+                    files(&["SECURITY.md", ".github/workflows/ci.yml"]),
+                ),
+                (
+                    "partial".to_string(),
+                    files(&["SECURITY.md", "src/main.rs"]),
+                ),
+                ("empty".to_string(), None),
+            ],
+        );
+
+        assert_eq!(report.repositories[0].repository, "partial");
+        assert_eq!(
+            report.repositories[0].missing,
+            vec![".github/workflows/ci.yml"]
+        );
+        assert_eq!(report.percentage(&report.repositories[0]), 50);
+        assert_eq!(report.overall_percentage(), 75);
+        assert_eq!(report.compliant_count(), 1);
+    }
+
+    #[test]
+    fn test_guest_mode_redacts_private_repositories() {
+        let mut repo = Repository::new("payments-api".to_string(), "acme".to_string());
+        repo.description = Some("Card processing".to_string());
+        assert_eq!(repo.display_full_name(true), "acme/payments-api");
 
-        assert_eq!(WorkflowHealth::from_workflow_runs(&[run1.clone()]), WorkflowHealth::Excellent);
-        assert_eq!(WorkflowHealth::from_workflow_runs(&[run1, run2]), WorkflowHealth::Fair);
+        repo.private = true;
+        let placeholder = repo.display_name(true);
+        assert!(placeholder.starts_with("private-"));
+        assert_eq!(placeholder, redacted_name("acme/payments-api"));
+        assert_ne!(placeholder, redacted_name("acme/payments-web"));
+        assert_eq!(
+            repo.display_full_name(true),
+            format!("acme/{}", placeholder)
+        );
+        assert!(!repo.display_description(true).contains("Card"));
+        assert_eq!(repo.display_name(false), "payments-api");
     }
 }
@@ -0,0 +1,566 @@
+use crate::app::BackgroundMessage;
+use crate::bus::{AppEvent, Subscriber};
+use crate::config::{AppConfig, ChannelConfig, ChannelKind, WebhookConfig};
+use crate::models::Repository;
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, SystemTime};
+use tokio::sync::mpsc;
+
+/// An alert about a single repository, ready to be delivered
+#[derive(Debug, Clone)]
+pub struct Alert {
+    /// Full name (owner/name) of the repository the alert is about
+    pub repository: String,
+    /// Message text posted to the channel
+    pub text: String,
+    /// Channels the alert is routed to
+    pub channels: Vec<ChannelConfig>,
+}
+
+/// Routes repository alerts to chat channels and delivers them
+///
+/// Routing is driven by the `[notifications]` section of the config: each
+/// route maps repository groups or topics to a channel, and alerts matching
+/// no route go to the default channel (if any).
+#[derive(Debug, Clone)]
+pub struct Notifier {
+    config: AppConfig,
+    http: reqwest::Client,
+}
+
+impl Notifier {
+    /// Create a notifier from the application config
+    ///
+    /// Returns `Ok(None)` when notifications are disabled and the list of
+    /// validation errors when the routing configuration is invalid.
+    pub fn from_config(config: &AppConfig) -> Result<Option<Self>, Vec<String>> {
+        if !config.notifications.enabled {
+            return Ok(None);
+        }
+
+        let errors = config.validate();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(Some(Self {
+            config: config.clone(),
+            http: reqwest::Client::new(),
+        }))
+    }
+
+    /// Channels an alert about this repository should be sent to
+    pub fn channels_for(&self, repo: &Repository) -> Vec<ChannelConfig> {
+        let notifications = &self.config.notifications;
+        let groups = self.config.groups_for(&repo.owner, &repo.name);
+
+        let mut names: Vec<&str> = Vec::new();
+        for route in &notifications.routes {
+            let group_match = route.groups.iter().any(|g| groups.contains(&g.as_str()));
+            let topic_match = route.topics.iter().any(|t| repo.topics.contains(t));
+            if (group_match || topic_match) && !names.contains(&route.channel.as_str()) {
+                names.push(&route.channel);
+            }
+        }
+
+        if names.is_empty() {
+            if let Some(default) = &notifications.default_channel {
+                names.push(default);
+            }
+        }
+
+        names
+            .into_iter()
+            .filter_map(|name| notifications.channels.iter().find(|c| c.name == name))
+            .cloned()
+            .collect()
+    }
+
+    /// Build a routed alert for a repository that needs attention
+    pub fn alert_for(&self, repo: &Repository) -> Option<Alert> {
+        if !self.config.attention.matches(repo) {
+            return None;
+        }
+
+        let channels = self.channels_for(repo);
+        if channels.is_empty() {
+            return None;
+        }
+
+        Some(Alert {
+            repository: repo.full_name(),
+            text: format!(
+                "⚠️ {} needs attention: {}",
+                repo.full_name(),
+                repo.status_summary()
+            ),
+            channels,
+        })
+    }
+
+    /// Post a message to a single channel's webhook
+    pub async fn send(&self, channel: &ChannelConfig, text: &str) -> Result<(), String> {
+        let payload = match channel.kind {
+            ChannelKind::Slack => serde_json::json!({ "text": text }),
+            ChannelKind::Discord => serde_json::json!({ "content": text }),
+        };
+
+        let response = self
+            .http
+            .post(&channel.webhook_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to notify '{}': {}", channel.name, e))?;
+
+        if !response.status().is_success() {
+            return Err(format!(
+                "Failed to notify '{}': HTTP {}",
+                channel.name,
+                response.status()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Spawn a background task delivering alerts to their channels
+    pub fn spawn_dispatch(
+        notifier: Notifier,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        alerts: Vec<Alert>,
+    ) {
+        tokio::spawn(async move {
+            for alert in alerts {
+                for channel in &alert.channels {
+                    if let Err(error) = notifier.send(channel, &alert.text).await {
+                        let _ = sender.send(BackgroundMessage::ActionError { error });
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// A threshold-crossing event, ready to be POSTed to a webhook
+#[derive(Debug, Clone)]
+pub struct WebhookEvent {
+    /// Webhook the event is delivered to
+    pub webhook: WebhookConfig,
+    /// JSON body of the request
+    pub payload: serde_json::Value,
+}
+
+/// Fires outgoing webhooks when repositories cross configured thresholds
+///
+/// Events are edge-triggered: a webhook fires once when a repository crosses
+/// a threshold and again only after it has recovered and crossed it anew.
+#[derive(Debug, Clone)]
+pub struct ThresholdMonitor {
+    webhooks: Vec<WebhookConfig>,
+    http: reqwest::Client,
+    /// (webhook index, repository) pairs currently below the health threshold
+    below_health: HashSet<(usize, String)>,
+    /// (webhook index, repository) pairs already reported as CI broken
+    ci_reported: HashSet<(usize, String)>,
+    /// When each repository's CI was first seen broken
+    ci_broken_since: HashMap<String, SystemTime>,
+}
+
+impl ThresholdMonitor {
+    /// Create a monitor from the application config
+    ///
+    /// Returns `Ok(None)` when no webhooks are configured and the list of
+    /// validation errors when the configuration is invalid.
+    pub fn from_config(config: &AppConfig) -> Result<Option<Self>, Vec<String>> {
+        if config.webhooks.is_empty() {
+            return Ok(None);
+        }
+
+        let errors = config.validate();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(Some(Self {
+            webhooks: config.webhooks.clone(),
+            http: reqwest::Client::new(),
+            below_health: HashSet::new(),
+            ci_reported: HashSet::new(),
+            ci_broken_since: HashMap::new(),
+        }))
+    }
+
+    /// Compare repositories against the thresholds and return new crossings
+    pub fn evaluate(&mut self, repositories: &[Repository], now: SystemTime) -> Vec<WebhookEvent> {
+        let mut events = Vec::new();
+
+        for repo in repositories {
+            let full_name = repo.full_name();
+            let health_score = repo.health_score();
+
+            let broken_for = match &repo.latest_workflow {
+                Some(run) if repo.is_ci_broken() => {
+                    let since = *self
+                        .ci_broken_since
+                        .entry(full_name.clone())
+                        .or_insert(run.updated_at);
+                    Some(now.duration_since(since).unwrap_or(Duration::ZERO))
+                }
+                _ => {
+                    self.ci_broken_since.remove(&full_name);
+                    None
+                }
+            };
+
+            for (index, webhook) in self.webhooks.iter().enumerate() {
+                let key = (index, full_name.clone());
+
+                if let Some(threshold) = webhook.min_health_score {
+                    if health_score < threshold {
+                        if self.below_health.insert(key.clone()) {
+                            events.push(WebhookEvent {
+                                webhook: webhook.clone(),
+                                payload: serde_json::json!({
+                                    "event": "health_below_threshold",
+                                    "repository": full_name,
+                                    "health_score": health_score,
+                                    "threshold": threshold,
+                                    "status": repo.status_summary(),
+                                    "html_url": repo.html_url,
+                                    "timestamp": timestamp(now),
+                                }),
+                            });
+                        }
+                    } else {
+                        self.below_health.remove(&key);
+                    }
+                }
+
+                if let Some(hours) = webhook.ci_broken_hours {
+                    match broken_for {
+                        Some(duration) if duration >= Duration::from_secs(hours * 3600) => {
+                            if self.ci_reported.insert(key.clone()) {
+                                events.push(WebhookEvent {
+                                    webhook: webhook.clone(),
+                                    payload: serde_json::json!({
+                                        "event": "ci_broken",
+                                        "repository": full_name,
+                                        "broken_hours": duration.as_secs() / 3600,
+                                        "threshold_hours": hours,
+                                        "html_url": repo.html_url,
+                                        "timestamp": timestamp(now),
+                                    }),
+                                });
+                            }
+                        }
+                        Some(_) => {}
+                        None => {
+                            self.ci_reported.remove(&key);
+                        }
+                    }
+                }
+            }
+        }
+
+        events
+    }
+
+    /// POST a single event to its webhook
+    pub async fn send(&self, event: &WebhookEvent) -> Result<(), String> {
+        let mut request = self.http.post(&event.webhook.url).json(&event.payload);
+        for (name, value) in &event.webhook.headers {
+            request = request.header(name, value);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| format!("Webhook {} failed: {}", event.webhook.url, e))?;
+
+        if !response.status().is_success() {
+            return Err(format!(
+                "Webhook {} failed: HTTP {}",
+                event.webhook.url,
+                response.status()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Spawn a background task delivering webhook events
+    pub fn spawn_dispatch(
+        monitor: ThresholdMonitor,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+        events: Vec<WebhookEvent>,
+    ) {
+        tokio::spawn(async move {
+            for event in &events {
+                if let Err(error) = monitor.send(event).await {
+                    let _ = sender.send(BackgroundMessage::ActionError { error });
+                }
+            }
+        });
+    }
+}
+
+/// Sends chat alerts when a refresh finds repositories newly needing attention
+pub struct AlertSubscriber {
+    notifier: Notifier,
+    sender: mpsc::UnboundedSender<BackgroundMessage>,
+    /// Repositories an alert has already been sent for
+    ///
+    /// A repository is alerted once when it starts needing attention and
+    /// becomes eligible again after it recovers.
+    alerted: HashSet<String>,
+}
+
+impl AlertSubscriber {
+    pub fn new(notifier: Notifier, sender: mpsc::UnboundedSender<BackgroundMessage>) -> Self {
+        Self {
+            notifier,
+            sender,
+            alerted: HashSet::new(),
+        }
+    }
+
+    /// Alerts for repositories not alerted since they last recovered
+    fn new_alerts(&mut self, repositories: &[Repository]) -> Vec<Alert> {
+        // Forget repositories that have recovered so they can alert again
+        for repo in repositories {
+            if !self.notifier.config.attention.matches(repo) {
+                self.alerted.remove(&repo.full_name());
+            }
+        }
+
+        let alerts: Vec<Alert> = repositories
+            .iter()
+            .filter(|repo| !self.alerted.contains(&repo.full_name()))
+            .filter_map(|repo| self.notifier.alert_for(repo))
+            .collect();
+        for alert in &alerts {
+            self.alerted.insert(alert.repository.clone());
+        }
+        alerts
+    }
+}
+
+impl Subscriber for AlertSubscriber {
+    fn on_event(&mut self, event: &AppEvent) {
+        if let AppEvent::RefreshCompleted { repositories } = event {
+            let alerts = self.new_alerts(repositories);
+            if !alerts.is_empty() {
+                Notifier::spawn_dispatch(self.notifier.clone(), self.sender.clone(), alerts);
+            }
+        }
+    }
+}
+
+/// Fires threshold webhooks after every completed refresh
+pub struct ThresholdSubscriber {
+    monitor: ThresholdMonitor,
+    sender: mpsc::UnboundedSender<BackgroundMessage>,
+}
+
+impl ThresholdSubscriber {
+    pub fn new(
+        monitor: ThresholdMonitor,
+        sender: mpsc::UnboundedSender<BackgroundMessage>,
+    ) -> Self {
+        Self { monitor, sender }
+    }
+}
+
+impl Subscriber for ThresholdSubscriber {
+    fn on_event(&mut self, event: &AppEvent) {
+        if let AppEvent::RefreshCompleted { repositories } = event {
+            let events = self.monitor.evaluate(repositories, SystemTime::now());
+            if !events.is_empty() {
+                ThresholdMonitor::spawn_dispatch(self.monitor.clone(), self.sender.clone(), events);
+            }
+        }
+    }
+}
+
+/// RFC 3339 timestamp used in webhook payloads
+fn timestamp(time: SystemTime) -> String {
+    DateTime::<Utc>::from(time).to_rfc3339()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RepositoryStatus, RunTrigger, WorkflowRun, WorkflowStatus};
+
+    fn notifier() -> Notifier {
+        let config = AppConfig::parse(
+            r#"
+            [groups]
+            payments = ["acme/payments-api"]
+
+            [notifications]
+            enabled = true
+            default_channel = "general"
+
+            [[notifications.channels]]
+            name = "general"
+            kind = "slack"
+            webhook_url = "https://hooks.slack.com/services/T/B/1"
+
+            [[notifications.channels]]
+            name = "payments-eng"
+            kind = "slack"
+            webhook_url = "https://hooks.slack.com/services/T/B/2"
+
+            [[notifications.channels]]
+            name = "infra"
+            kind = "discord"
+            webhook_url = "https://discord.com/api/webhooks/1/abc"
+
+            [[notifications.routes]]
+            channel = "payments-eng"
+            groups = ["payments"]
+
+            [[notifications.routes]]
+            channel = "infra"
+            topics = ["infra"]
+            "#,
+        )
+        .unwrap();
+        Notifier::from_config(&config).unwrap().unwrap()
+    }
+
+    fn channel_names(channels: Vec<ChannelConfig>) -> Vec<String> {
+        channels.into_iter().map(|c| c.name).collect()
+    }
+
+    #[test]
+    fn test_routes_by_group_and_topic() {
+        let notifier = notifier();
+
+        let mut payments = Repository::new("payments-api".to_string(), "acme".to_string());
+        payments.topics = vec!["infra".to_string()];
+        assert_eq!(
+            channel_names(notifier.channels_for(&payments)),
+            vec!["payments-eng", "infra"]
+        );
+
+        let docs = Repository::new("docs".to_string(), "acme".to_string());
+        assert_eq!(channel_names(notifier.channels_for(&docs)), vec!["general"]);
+    }
+
+    #[test]
+    fn test_alert_only_for_repos_needing_attention() {
+        let notifier = notifier();
+
+        let mut repo = Repository::new("payments-api".to_string(), "acme".to_string());
+        repo.status = RepositoryStatus::Active;
+        assert!(notifier.alert_for(&repo).is_none());
+
+        repo.status = RepositoryStatus::Dormant;
+        let alert = notifier.alert_for(&repo).unwrap();
+        assert_eq!(alert.repository, "acme/payments-api");
+        assert_eq!(alert.channels[0].name, "payments-eng");
+    }
+
+    #[test]
+    fn test_alert_subscriber_alerts_once_until_recovered() {
+        let (sender, _receiver) = mpsc::unbounded_channel();
+        let mut subscriber = AlertSubscriber::new(notifier(), sender);
+
+        let mut repo = Repository::new("payments-api".to_string(), "acme".to_string());
+        repo.status = RepositoryStatus::Dormant;
+        assert_eq!(subscriber.new_alerts(std::slice::from_ref(&repo)).len(), 1);
+        assert!(subscriber
+            .new_alerts(std::slice::from_ref(&repo))
+            .is_empty());
+
+        repo.status = RepositoryStatus::Active;
+        assert!(subscriber
+            .new_alerts(std::slice::from_ref(&repo))
+            .is_empty());
+        repo.status = RepositoryStatus::Dormant;
+        assert_eq!(subscriber.new_alerts(std::slice::from_ref(&repo)).len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_routing_is_rejected() {
+        let config = AppConfig::parse(
+            r#"
+            [notifications]
+            enabled = true
+
+            [[notifications.routes]]
+            channel = "missing"
+            topics = ["infra"]
+            "#,
+        )
+        .unwrap();
+        assert!(Notifier::from_config(&config).is_err());
+    }
+
+    fn monitor() -> ThresholdMonitor {
+        let config = AppConfig::parse(
+            r#"
+            [[webhooks]]
+            url = "https://example.com/hooks/health"
+            min_health_score = 60
+            ci_broken_hours = 24
+            "#,
+        )
+        .unwrap();
+        ThresholdMonitor::from_config(&config).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_health_threshold_fires_once_per_crossing() {
+        let mut monitor = monitor();
+        let now = SystemTime::now();
+        let mut repo = Repository::new("api".to_string(), "acme".to_string());
+
+        repo.status = RepositoryStatus::Active;
+        assert!(monitor.evaluate(&[repo.clone()], now).is_empty());
+
+        repo.status = RepositoryStatus::Dormant;
+        let events = monitor.evaluate(&[repo.clone()], now);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].payload["event"], "health_below_threshold");
+        assert_eq!(events[0].payload["repository"], "acme/api");
+
+        // Still below: no repeat until it recovers
+        assert!(monitor.evaluate(&[repo.clone()], now).is_empty());
+        repo.status = RepositoryStatus::Active;
+        assert!(monitor.evaluate(&[repo.clone()], now).is_empty());
+        repo.status = RepositoryStatus::Dormant;
+        assert_eq!(monitor.evaluate(&[repo], now).len(), 1);
+    }
+
+    #[test]
+    fn test_ci_broken_fires_after_configured_hours() {
+        let mut monitor = monitor();
+        let failed_at = SystemTime::now();
+        let mut repo = Repository::new("api".to_string(), "acme".to_string());
+        repo.latest_workflow = Some(WorkflowRun {
+            id: 1,
+            name: "CI".to_string(),
+            status: WorkflowStatus::Failed,
+            created_at: failed_at,
+            updated_at: failed_at,
+            started_at: Some(failed_at),
+            duration: None,
+            trigger: RunTrigger::default(),
+        });
+
+        let later = failed_at + Duration::from_secs(3600);
+        assert!(monitor.evaluate(&[repo.clone()], later).is_empty());
+
+        let much_later = failed_at + Duration::from_secs(25 * 3600);
+        let events = monitor.evaluate(&[repo.clone()], much_later);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].payload["event"], "ci_broken");
+        assert_eq!(events[0].payload["broken_hours"], 25);
+        assert!(monitor.evaluate(&[repo], much_later).is_empty());
+    }
+}
@@ -0,0 +1,188 @@
+use crate::app::BackgroundMessage;
+use crate::i18n::{t, t_with};
+use serde::Deserialize;
+use tokio::sync::mpsc;
+
+/// GitHub status page API listing component states
+pub const STATUS_URL: &str = "https://www.githubstatus.com/api/v2/components.json";
+
+/// Status page components whose incidents affect the dashboard's data
+pub const WATCHED_COMPONENTS: [&str; 3] = ["API Requests", "Actions", "Webhooks"];
+
+/// State of a single GitHub platform component
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComponentState {
+    Operational,
+    Degraded,
+    PartialOutage,
+    MajorOutage,
+    Maintenance,
+    Unknown,
+}
+
+impl ComponentState {
+    /// Map a status page component status to a state
+    pub fn from_api(status: &str) -> Self {
+        match status {
+            "operational" => ComponentState::Operational,
+            "degraded_performance" => ComponentState::Degraded,
+            "partial_outage" => ComponentState::PartialOutage,
+            "major_outage" => ComponentState::MajorOutage,
+            "under_maintenance" => ComponentState::Maintenance,
+            _ => ComponentState::Unknown,
+        }
+    }
+
+    /// Get a human-readable description
+    pub fn description(&self) -> &'static str {
+        match self {
+            ComponentState::Operational => t("platform.operational"),
+            ComponentState::Degraded => t("platform.degraded"),
+            ComponentState::PartialOutage => t("platform.partial_outage"),
+            ComponentState::MajorOutage => t("platform.major_outage"),
+            ComponentState::Maintenance => t("platform.maintenance"),
+            ComponentState::Unknown => t("platform.unknown"),
+        }
+    }
+
+    /// Whether the component is not fully operational
+    pub fn is_degraded(&self) -> bool {
+        !matches!(self, ComponentState::Operational | ComponentState::Unknown)
+    }
+}
+
+/// State of one watched GitHub platform component
+#[derive(Debug, Clone)]
+pub struct ComponentStatus {
+    pub name: String,
+    pub state: ComponentState,
+}
+
+/// Current state of the GitHub platform components the dashboard relies on
+#[derive(Debug, Clone, Default)]
+pub struct PlatformStatus {
+    pub components: Vec<ComponentStatus>,
+}
+
+impl PlatformStatus {
+    /// Parse the status page components response, keeping watched components
+    pub fn parse(json: &str) -> Result<Self, String> {
+        let response: ComponentsResponse =
+            serde_json::from_str(json).map_err(|e| format!("Invalid status response: {}", e))?;
+
+        let components = response
+            .components
+            .into_iter()
+            .filter(|component| WATCHED_COMPONENTS.contains(&component.name.as_str()))
+            .map(|component| ComponentStatus {
+                state: ComponentState::from_api(&component.status),
+                name: component.name,
+            })
+            .collect();
+
+        Ok(Self { components })
+    }
+
+    /// Watched components that are not fully operational
+    pub fn degraded(&self) -> Vec<&ComponentStatus> {
+        self.components
+            .iter()
+            .filter(|component| component.state.is_degraded())
+            .collect()
+    }
+
+    /// Banner text shown while GitHub itself is degraded
+    pub fn banner(&self) -> Option<String> {
+        let degraded = self.degraded();
+        if degraded.is_empty() {
+            return None;
+        }
+
+        let components = degraded
+            .iter()
+            .map(|component| format!("{} ({})", component.name, component.state.description()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Some(t_with("platform.banner", &[("components", &components)]))
+    }
+
+    /// Fetch the current platform status from githubstatus.com
+    pub async fn fetch(http: &reqwest::Client) -> Result<Self, String> {
+        let body = http
+            .get(STATUS_URL)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to fetch GitHub status: {}", e))?
+            .text()
+            .await
+            .map_err(|e| format!("Failed to read GitHub status: {}", e))?;
+        Self::parse(&body)
+    }
+
+    /// Spawn a background task polling the status page once
+    ///
+    /// Failures are ignored: the status page being unreachable says nothing
+    /// about the user's repositories, and the previous status is kept.
+    pub fn spawn_fetch(sender: mpsc::UnboundedSender<BackgroundMessage>) {
+        tokio::spawn(async move {
+            if let Ok(status) = Self::fetch(&reqwest::Client::new()).await {
+                let _ = sender.send(BackgroundMessage::PlatformStatusFetched { status });
+            }
+        });
+    }
+}
+
+/// Status page components response (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct ComponentsResponse {
+    components: Vec<ComponentResponse>,
+}
+
+/// A single status page component
+#[derive(Debug, Deserialize)]
+struct ComponentResponse {
+    name: String,
+    status: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESPONSE: &str = r#"{
+        "page": {"id": "kctbh9vrtdwd", "name": "GitHub"},
+        "components": [
+            {"id": "1", "name": "Git Operations", "status": "major_outage"},
+            {"id": "2", "name": "API Requests", "status": "operational"},
+            {"id": "3", "name": "Actions", "status": "partial_outage"},
+            {"id": "4", "name": "Webhooks", "status": "degraded_performance"}
+        ]
+    }"#;
+
+    #[test]
+    fn test_parse_keeps_watched_components() {
+        let status = PlatformStatus::parse(RESPONSE).unwrap();
+        assert_eq!(status.components.len(), 3);
+
+        let degraded: Vec<&str> = status
+            .degraded()
+            .iter()
+            .map(|component| component.name.as_str())
+            .collect();
+        assert_eq!(degraded, vec!["Actions", "Webhooks"]);
+    }
+
+    #[test]
+    fn test_banner_only_when_degraded() {
+        let status = PlatformStatus::parse(RESPONSE).unwrap();
+        let banner = status.banner().unwrap();
+        assert!(banner.contains("Actions (partial outage)"));
+        assert!(banner.contains("Webhooks (degraded performance)"));
+
+        let operational = PlatformStatus::parse(
+            r#"{"components": [{"name": "Actions", "status": "operational"}]}"#,
+        )
+        .unwrap();
+        assert!(operational.banner().is_none());
+    }
+}
@@ -0,0 +1,254 @@
+use crate::config::PolicyConfig;
+use crate::i18n::{t, t_with};
+use crate::models::{PullRequestState, Repository};
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+/// What the policy scan read about a repository beyond the dashboard data
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PolicyFacts {
+    /// Whether the default branch is protected (None for empty repositories)
+    pub default_branch_protected: Option<bool>,
+    /// Whether a CODEOWNERS file exists in one of the places GitHub reads it
+    pub has_codeowners: bool,
+}
+
+/// A requirement of a topic policy that a repository doesn't meet
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolicyViolation {
+    /// No workflow runs
+    NoCi,
+    /// The default branch is not protected
+    UnprotectedBranch,
+    /// No CODEOWNERS file
+    NoCodeowners,
+    /// An open pull request has waited longer than the review SLA
+    ReviewOverdue {
+        number: u32,
+        days: u64,
+        sla_days: u64,
+    },
+}
+
+impl PolicyViolation {
+    /// Get a human-readable description
+    pub fn description(&self) -> String {
+        match self {
+            PolicyViolation::NoCi => t("policies.no_ci").to_string(),
+            PolicyViolation::UnprotectedBranch => t("protection.unprotected").to_string(),
+            PolicyViolation::NoCodeowners => t("policies.no_codeowners").to_string(),
+            PolicyViolation::ReviewOverdue {
+                number,
+                days,
+                sla_days,
+            } => t_with(
+                "policies.review_overdue",
+                &[("number", number), ("days", days), ("sla_days", sla_days)],
+            ),
+        }
+    }
+}
+
+/// Policy results of one repository
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyResult {
+    pub repository: String,
+    /// Topics whose policies apply to the repository
+    pub topics: Vec<String>,
+    pub violations: Vec<PolicyViolation>,
+}
+
+/// Policy results of every repository with at least one policy topic
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PolicyReport {
+    /// Most violations first
+    pub repositories: Vec<PolicyResult>,
+}
+
+impl PolicyReport {
+    /// Evaluate repositories against the policies attached to their topics
+    ///
+    /// When a repository carries several policy topics, their requirements
+    /// add up and the shortest review SLA applies. Checks needing facts the
+    /// scan couldn't read are skipped.
+    pub fn evaluate(
+        repositories: &[Repository],
+        policies: &[PolicyConfig],
+        facts: &HashMap<String, PolicyFacts>,
+        now: SystemTime,
+    ) -> Self {
+        let mut results: Vec<PolicyResult> = repositories
+            .iter()
+            .filter_map(|repo| {
+                let applicable = applicable_policies(repo, policies);
+                if applicable.is_empty() {
+                    return None;
+                }
+                let repo_facts = facts.get(&repo.full_name());
+                let violations = violations(repo, &applicable, repo_facts, now);
+                Some(PolicyResult {
+                    repository: repo.full_name(),
+                    topics: applicable
+                        .iter()
+                        .map(|policy| policy.topic.clone())
+                        .collect(),
+                    violations,
+                })
+            })
+            .collect();
+        results.sort_by(|a, b| {
+            b.violations
+                .len()
+                .cmp(&a.violations.len())
+                .then_with(|| a.repository.cmp(&b.repository))
+        });
+        Self {
+            repositories: results,
+        }
+    }
+
+    /// Number of repositories meeting all their policies
+    pub fn compliant_count(&self) -> usize {
+        self.repositories
+            .iter()
+            .filter(|result| result.violations.is_empty())
+            .count()
+    }
+}
+
+/// Policies whose topic the repository carries, ignoring case
+pub fn applicable_policies<'a>(
+    repo: &Repository,
+    policies: &'a [PolicyConfig],
+) -> Vec<&'a PolicyConfig> {
+    policies
+        .iter()
+        .filter(|policy| {
+            repo.topics
+                .iter()
+                .any(|topic| topic.eq_ignore_ascii_case(&policy.topic))
+        })
+        .collect()
+}
+
+/// Requirements of `policies` the repository doesn't meet
+fn violations(
+    repo: &Repository,
+    policies: &[&PolicyConfig],
+    facts: Option<&PolicyFacts>,
+    now: SystemTime,
+) -> Vec<PolicyViolation> {
+    let mut violations = Vec::new();
+
+    let has_ci = repo.latest_workflow.is_some() || !repo.recent_workflows.is_empty();
+    if policies.iter().any(|policy| policy.require_ci) && !has_ci {
+        violations.push(PolicyViolation::NoCi);
+    }
+    if let Some(facts) = facts {
+        if policies
+            .iter()
+            .any(|policy| policy.require_branch_protection)
+            && facts.default_branch_protected == Some(false)
+        {
+            violations.push(PolicyViolation::UnprotectedBranch);
+        }
+        if policies.iter().any(|policy| policy.require_codeowners) && !facts.has_codeowners {
+            violations.push(PolicyViolation::NoCodeowners);
+        }
+    }
+
+    if let Some(sla_days) = policies.iter().filter_map(|p| p.review_sla_days).min() {
+        for pr in &repo.open_pull_requests {
+            let reviewed = pr.approvals > 0 || pr.changes_requested > 0;
+            if pr.draft || reviewed || pr.state != PullRequestState::Open {
+                continue;
+            }
+            let days = now
+                .duration_since(pr.created_at)
+                .unwrap_or_default()
+                .as_secs()
+                / 86400;
+            if days >= sla_days {
+                violations.push(PolicyViolation::ReviewOverdue {
+                    number: pr.number,
+                    days,
+                    sla_days,
+                });
+            }
+        }
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::PullRequest;
+    use std::time::Duration;
+
+    fn production_policy() -> PolicyConfig {
+        PolicyConfig {
+            topic: "production".to_string(),
+            require_ci: true,
+            require_branch_protection: true,
+            require_codeowners: true,
+            review_sla_days: Some(5),
+        }
+    }
+
+    #[test]
+    fn test_policies_apply_by_topic() {
+        let now = SystemTime::now();
+        let mut api = Repository::new("api".to_string(), "acme".to_string());
+        api.topics = vec!["Production".to_string()];
+        api.open_pull_requests = vec![PullRequest {
+            number: 7,
+            title: "Retry webhooks".to_string(),
+            state: PullRequestState::Open,
+            created_at: now - Duration::from_secs(8 * 86400),
+            updated_at: now,
+            author: "octocat".to_string(),
+            html_url: String::new(),
+            draft: false,
+            approvals: 0,
+            changes_requested: 0,
+            head_sha: String::new(),
+            base_branch: "main".to_string(),
+            linked_issues: Vec::new(),
+            tasks: None,
+            feedback_waiting_since: None,
+            review_decision: None,
+            checks: None,
+            labels: Vec::new(),
+            milestone: None,
+        }];
+        let mut site = Repository::new("site".to_string(), "acme".to_string());
+        site.topics = vec!["docs".to_string()];
+
+        let facts = HashMap::from([(
+            "acme/api".to_string(),
+            PolicyFacts {
+                default_branch_protected: Some(false),
+                has_codeowners: true,
+            },
+        )]);
+        let report = PolicyReport::evaluate(&[api, site], &[production_policy()], &facts, now);
+
+        assert_eq!(report.repositories.len(), 1);
+        let result = &report.repositories[0];
+        assert_eq!(result.topics, vec!["production"]);
+        assert_eq!(
+            result.violations,
+            vec![
+                PolicyViolation::NoCi,
+                PolicyViolation::UnprotectedBranch,
+                PolicyViolation::ReviewOverdue {
+                    number: 7,
+                    days: 8,
+                    sla_days: 5
+                },
+            ]
+        );
+        assert_eq!(report.compliant_count(), 0);
+    }
+}
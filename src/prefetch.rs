@@ -0,0 +1,155 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// API requests assumed for an organization that was never loaded
+pub const DEFAULT_ORG_REQUESTS: u64 = 100;
+
+/// What is remembered about an organization across sessions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrgUsage {
+    /// When the dashboard last switched to the organization
+    pub last_viewed: Option<DateTime<Utc>>,
+    /// Repositories needing attention when it last loaded
+    pub attention: usize,
+    /// API requests its last load took
+    pub requests: Option<u64>,
+}
+
+/// Organization usage, keyed by organization name, used to pick which
+/// organizations to prefetch first
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrgHistory {
+    #[serde(default)]
+    organizations: BTreeMap<String, OrgUsage>,
+}
+
+impl OrgHistory {
+    /// Read the history from disk; a missing or unreadable file starts afresh
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write the history to disk, creating its directory if needed
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        }
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to encode organization history: {}", e))?;
+        std::fs::write(path, json).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    pub fn usage(&self, org: &str) -> Option<&OrgUsage> {
+        self.organizations.get(org)
+    }
+
+    /// Record that the dashboard switched to an organization
+    pub fn viewed(&mut self, org: &str, at: DateTime<Utc>) {
+        self.organizations
+            .entry(org.to_string())
+            .or_default()
+            .last_viewed = Some(at);
+    }
+
+    /// Record how an organization looked and what loading it cost
+    pub fn loaded(&mut self, org: &str, attention: usize, requests: u64) {
+        let usage = self.organizations.entry(org.to_string()).or_default();
+        usage.attention = attention;
+        usage.requests = Some(requests);
+    }
+
+    /// API requests loading an organization is expected to take
+    pub fn expected_requests(&self, org: &str) -> u64 {
+        self.usage(org)
+            .and_then(|usage| usage.requests)
+            .unwrap_or(DEFAULT_ORG_REQUESTS)
+    }
+
+    /// Organizations to prefetch, most useful first, that fit the budget
+    ///
+    /// The most recently viewed come first, then those with the most
+    /// repositories needing attention; organizations never seen keep their
+    /// listed order. One too expensive for what's left is skipped so cheaper
+    /// ones after it still get their turn.
+    pub fn prefetch_order(&self, orgs: &[String], budget: u64, limit: usize) -> Vec<String> {
+        let mut ranked: Vec<&String> = orgs.iter().collect();
+        ranked.sort_by_key(|org| {
+            let usage = self.usage(org);
+            (
+                std::cmp::Reverse(usage.and_then(|usage| usage.last_viewed)),
+                std::cmp::Reverse(usage.map_or(0, |usage| usage.attention)),
+            )
+        });
+
+        let mut left = budget;
+        let mut order = Vec::new();
+        for org in ranked {
+            if order.len() == limit {
+                break;
+            }
+            let cost = self.expected_requests(org);
+            if cost <= left {
+                left -= cost;
+                order.push(org.clone());
+            }
+        }
+        order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefetch_order_by_recency_attention_and_budget() {
+        let day = |d: u32| {
+            chrono::NaiveDate::from_ymd_opt(2026, 10, d)
+                .unwrap()
+                .and_hms_opt(9, 0, 0)
+                .unwrap()
+                .and_utc()
+        };
+        let mut history = OrgHistory::default();
+        history.viewed("alpha", day(1));
+        history.loaded("alpha", 0, 40);
+        history.viewed("beta", day(12));
+        history.loaded("beta", 2, 300);
+        history.loaded("gamma", 9, 30);
+        history.loaded("delta", 3, 20);
+        let orgs: Vec<String> = ["alpha", "beta", "gamma", "delta", "omega"]
+            .iter()
+            .map(|org| org.to_string())
+            .collect();
+
+        assert_eq!(
+            history.prefetch_order(&orgs, 10_000, 10),
+            vec!["beta", "alpha", "gamma", "delta", "omega"]
+        );
+        // beta is too expensive; omega, never loaded, would cost 100
+        assert_eq!(
+            history.prefetch_order(&orgs, 120, 10),
+            vec!["alpha", "gamma", "delta"]
+        );
+        assert_eq!(
+            history.prefetch_order(&orgs, 10_000, 2),
+            vec!["beta", "alpha"]
+        );
+
+        // Round-trips through disk
+        let path = std::env::temp_dir()
+            .join(format!("gh-repo-healthchecks-orgs-{}", std::process::id()))
+            .join("orgs.json");
+        history.save(&path).unwrap();
+        assert_eq!(OrgHistory::load(&path), history);
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        assert_eq!(OrgHistory::load(&path), OrgHistory::default());
+    }
+}
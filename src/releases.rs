@@ -0,0 +1,177 @@
+use crate::models::{PullRequest, Repository, WorkflowStatus};
+use regex::Regex;
+use std::collections::BTreeMap;
+
+/// Open pull requests of one repository that belong to a release
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReleaseRepository {
+    pub repository: String,
+    /// Pull requests ready to merge
+    pub ready: usize,
+    pub total: usize,
+    /// Whether a pull request's checks or the default branch's CI are failing
+    pub ci_failing: bool,
+}
+
+/// Progress of a release shared by pull requests across repositories
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReleaseReadiness {
+    /// Milestone title or release label the pull requests carry
+    pub release: String,
+    /// Repositories with pull requests for the release, by name
+    pub repositories: Vec<ReleaseRepository>,
+}
+
+impl ReleaseReadiness {
+    /// Group open pull requests by milestone and by labels matching the
+    /// release label pattern, releases in name order
+    pub fn from_repositories(
+        repositories: &[Repository],
+        label_pattern: Option<&Regex>,
+    ) -> Vec<Self> {
+        let mut releases: BTreeMap<String, BTreeMap<String, ReleaseRepository>> = BTreeMap::new();
+        for repo in repositories {
+            for pr in &repo.open_pull_requests {
+                for release in Self::releases_of(pr, label_pattern) {
+                    let entry = releases
+                        .entry(release)
+                        .or_default()
+                        .entry(repo.full_name())
+                        .or_insert_with(|| ReleaseRepository {
+                            repository: repo.full_name(),
+                            ready: 0,
+                            total: 0,
+                            ci_failing: repo.is_ci_broken(),
+                        });
+                    entry.total += 1;
+                    entry.ready += usize::from(pr.is_release_ready());
+                    entry.ci_failing |= pr.checks == Some(WorkflowStatus::Failed);
+                }
+            }
+        }
+        releases
+            .into_iter()
+            .map(|(release, repositories)| Self {
+                release,
+                repositories: repositories.into_values().collect(),
+            })
+            .collect()
+    }
+
+    /// Releases a pull request counts toward, without duplicates
+    fn releases_of(pr: &PullRequest, label_pattern: Option<&Regex>) -> Vec<String> {
+        let mut releases: Vec<String> = pr.milestone.iter().cloned().collect();
+        let Some(label_pattern) = label_pattern else {
+            return releases;
+        };
+        for label in &pr.labels {
+            if label_pattern.is_match(label) && !releases.contains(label) {
+                releases.push(label.clone());
+            }
+        }
+        releases
+    }
+
+    /// Pull requests ready to merge, across repositories
+    pub fn ready(&self) -> usize {
+        self.repositories.iter().map(|repo| repo.ready).sum()
+    }
+
+    /// Pull requests in the release, across repositories
+    pub fn total(&self) -> usize {
+        self.repositories.iter().map(|repo| repo.total).sum()
+    }
+
+    /// Share of the pull requests ready to merge, as a percentage
+    pub fn percent(&self) -> usize {
+        match self.total() {
+            0 => 0,
+            total => self.ready() * 100 / total,
+        }
+    }
+
+    /// Repositories whose CI is failing for the release
+    pub fn failing_repositories(&self) -> Vec<&str> {
+        self.repositories
+            .iter()
+            .filter(|repo| repo.ci_failing)
+            .map(|repo| repo.repository.as_str())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{PullRequestState, RunTrigger, WorkflowRun};
+    use std::time::SystemTime;
+
+    fn pull_request(number: u32, milestone: Option<&str>, labels: &[&str]) -> PullRequest {
+        PullRequest {
+            number,
+            title: format!("PR {}", number),
+            state: PullRequestState::Open,
+            created_at: SystemTime::now(),
+            updated_at: SystemTime::now(),
+            author: "octocat".to_string(),
+            html_url: String::new(),
+            draft: false,
+            approvals: 0,
+            changes_requested: 0,
+            head_sha: String::new(),
+            base_branch: "main".to_string(),
+            linked_issues: Vec::new(),
+            tasks: None,
+            feedback_waiting_since: None,
+            review_decision: None,
+            checks: None,
+            labels: labels.iter().map(|label| label.to_string()).collect(),
+            milestone: milestone.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_release_readiness_across_repositories() {
+        let pattern = Regex::new(crate::config::DEFAULT_RELEASE_LABEL_PATTERN).unwrap();
+
+        let mut api = Repository::new("api".to_string(), "acme".to_string());
+        let mut ready = pull_request(1, Some("v2.0"), &["v2.0", "enhancement"]);
+        ready.approvals = 1;
+        let mut failing = pull_request(2, None, &["v2.0"]);
+        failing.approvals = 1;
+        failing.checks = Some(WorkflowStatus::Failed);
+        api.open_pull_requests = vec![ready, failing, pull_request(3, None, &["bug"])];
+
+        let mut web = Repository::new("web".to_string(), "acme".to_string());
+        let mut draft = pull_request(9, Some("v2.0"), &[]);
+        draft.draft = true;
+        draft.approvals = 2;
+        web.open_pull_requests = vec![draft, pull_request(10, None, &["v2.1"])];
+        web.latest_workflow = Some(WorkflowRun {
+            id: 1,
+            name: "CI".to_string(),
+            status: WorkflowStatus::Failed,
+            created_at: SystemTime::now(),
+            updated_at: SystemTime::now(),
+            started_at: None,
+            duration: None,
+            trigger: RunTrigger::default(),
+        });
+        let repositories = [api, web];
+
+        let releases = ReleaseReadiness::from_repositories(&repositories, Some(&pattern));
+        let names: Vec<&str> = releases.iter().map(|r| r.release.as_str()).collect();
+        assert_eq!(names, vec!["v2.0", "v2.1"]);
+
+        // The milestone and the label don't count #1 twice
+        let v2 = &releases[0];
+        assert_eq!((v2.ready(), v2.total(), v2.percent()), (1, 3, 33));
+        assert_eq!(v2.failing_repositories(), vec!["acme/api", "acme/web"]);
+        assert_eq!(releases[1].failing_repositories(), vec!["acme/web"]);
+
+        // Without a label pattern only milestones name releases
+        let releases = ReleaseReadiness::from_repositories(&repositories, None);
+        assert_eq!(releases.len(), 1);
+        assert_eq!(releases[0].total(), 2);
+    }
+}
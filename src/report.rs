@@ -0,0 +1,520 @@
+use crate::config::{AttentionConfig, ReportFormat, ReportsConfig, TimeConfig};
+use crate::i18n::t;
+use crate::models::{AggregateMetrics, Repository};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// File name prefix shared by all written reports
+const FILE_PREFIX: &str = "health-report-";
+
+/// File name prefix of weekly digests archived next to the reports
+pub const DIGEST_PREFIX: &str = "weekly-digest-";
+
+/// Timestamp format embedded in archived file names
+pub const STAMP_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// One repository's line in a health report
+#[derive(Debug, Clone)]
+pub struct ReportRow {
+    pub name: String,
+    pub html_url: String,
+    pub health_score: u8,
+    pub status: String,
+    pub workflow: String,
+    pub deploy: String,
+    pub open_pull_requests: usize,
+    pub needs_attention: bool,
+    /// Kept so later runs can learn how often the repository usually commits
+    pub latest_commit_at: Option<DateTime<Utc>>,
+}
+
+/// Snapshot of repository health written by `--daemon` mode
+#[derive(Debug, Clone)]
+pub struct HealthReport {
+    pub generated_at: DateTime<Utc>,
+    /// Which repositories the report covers (e.g. "Org: acme")
+    pub scope: String,
+    pub totals: AggregateMetrics,
+    pub repositories: Vec<ReportRow>,
+    /// How the Markdown and HTML reports show timestamps
+    pub time: TimeConfig,
+}
+
+impl HealthReport {
+    /// Build a report, listing repositories needing attention first
+    pub fn new(
+        scope: String,
+        repositories: &[Repository],
+        attention: &AttentionConfig,
+        generated_at: DateTime<Utc>,
+    ) -> Self {
+        let mut rows: Vec<ReportRow> = repositories
+            .iter()
+            .map(|repo| ReportRow {
+                name: repo.full_name(),
+                html_url: repo.html_url.clone(),
+                health_score: repo.health_score(),
+                status: repo.status.description().to_string(),
+                workflow: repo.workflow_health.description().to_string(),
+                deploy: repo.deploy_status.health().description().to_string(),
+                open_pull_requests: repo.open_pull_request_count(),
+                needs_attention: attention.matches(repo),
+                latest_commit_at: repo.latest_commit_at.map(DateTime::<Utc>::from),
+            })
+            .collect();
+        rows.sort_by(|a, b| {
+            b.needs_attention
+                .cmp(&a.needs_attention)
+                .then(a.health_score.cmp(&b.health_score))
+        });
+
+        Self {
+            generated_at,
+            scope,
+            totals: AggregateMetrics::from_repositories(repositories, attention),
+            repositories: rows,
+            time: TimeConfig::default(),
+        }
+    }
+
+    /// Show timestamps per the time settings rather than the defaults
+    pub fn with_time(mut self, time: TimeConfig) -> Self {
+        self.time = time;
+        self
+    }
+
+    /// When the report was generated, in the configured time zone
+    fn generated(&self) -> String {
+        self.time.absolute(self.generated_at.into())
+    }
+
+    /// A row's last commit in the configured style, as of the report
+    fn last_commit(&self, row: &ReportRow) -> String {
+        row.latest_commit_at.map_or("-".to_string(), |at| {
+            self.time.format(at.into(), self.generated_at.into())
+        })
+    }
+
+    /// Render the report in a format
+    pub fn render(&self, format: ReportFormat) -> String {
+        match format {
+            ReportFormat::Markdown => self.to_markdown(),
+            ReportFormat::Html => self.to_html(),
+            ReportFormat::Json => self.to_json(),
+        }
+    }
+
+    fn average_health(&self) -> String {
+        self.totals
+            .average_health
+            .map_or("n/a".to_string(), |score| format!("{}/100", score))
+    }
+
+    fn to_markdown(&self) -> String {
+        let mut out = format!(
+            "# Repository Health Report\n\n{} · generated {}\n\n",
+            self.scope,
+            self.generated()
+        );
+        out.push_str(&format!(
+            "- Repositories: {}\n- Average health: {}\n- Need attention: {}\n- Open PRs: {}\n- CI broken: {}\n- Inactive: {}\n\n",
+            self.totals.repositories,
+            self.average_health(),
+            self.totals.needs_attention,
+            self.totals.open_pull_requests,
+            self.totals.ci_broken,
+            self.totals.inactive
+        ));
+        out.push_str(
+            "| Repository | Health | Status | Last commit | Workflows | Deploy | PRs | Attention |\n",
+        );
+        out.push_str("|---|---|---|---|---|---|---|---|\n");
+        for row in &self.repositories {
+            out.push_str(&format!(
+                "| [{}]({}) | {} | {} | {} | {} | {} | {} | {} |\n",
+                row.name,
+                row.html_url,
+                row.health_score,
+                row.status,
+                self.last_commit(row),
+                row.workflow,
+                row.deploy,
+                row.open_pull_requests,
+                if row.needs_attention { "⚠️" } else { "" }
+            ));
+        }
+        out
+    }
+
+    fn to_html(&self) -> String {
+        let mut rows = String::new();
+        for row in &self.repositories {
+            rows.push_str(&format!(
+                "<tr{}><td><a href=\"{}\">{}</a></td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                if row.needs_attention { " class=\"attention\"" } else { "" },
+                escape_html(&row.html_url),
+                escape_html(&row.name),
+                row.health_score,
+                escape_html(&row.status),
+                escape_html(&self.last_commit(row)),
+                escape_html(&row.workflow),
+                escape_html(&row.deploy),
+                row.open_pull_requests
+            ));
+        }
+
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Repository Health Report</title>\n\
+             <style>body{{font-family:sans-serif}}table{{border-collapse:collapse}}td,th{{padding:4px 8px;border:1px solid #ccc}}.attention{{background:#fff3cd}}</style>\n\
+             </head>\n<body>\n<h1>Repository Health Report</h1>\n<p>{} · generated {}</p>\n\
+             <p>{} repositories · average health {} · {} need attention · {} open PRs · {} CI broken · {} inactive</p>\n\
+             <table>\n<tr><th>Repository</th><th>Health</th><th>Status</th><th>Last commit</th><th>Workflows</th><th>Deploy</th><th>PRs</th></tr>\n{}</table>\n</body>\n</html>\n",
+            escape_html(&self.scope),
+            self.generated(),
+            self.totals.repositories,
+            self.average_health(),
+            self.totals.needs_attention,
+            self.totals.open_pull_requests,
+            self.totals.ci_broken,
+            self.totals.inactive,
+            rows
+        )
+    }
+
+    fn to_json(&self) -> String {
+        let repositories: Vec<serde_json::Value> = self
+            .repositories
+            .iter()
+            .map(|row| {
+                serde_json::json!({
+                    "repository": row.name,
+                    "html_url": row.html_url,
+                    "health_score": row.health_score,
+                    "status": row.status,
+                    "workflows": row.workflow,
+                    "deploy": row.deploy,
+                    "open_pull_requests": row.open_pull_requests,
+                    "needs_attention": row.needs_attention,
+                    "latest_commit_at": row.latest_commit_at.map(|at| at.to_rfc3339()),
+                })
+            })
+            .collect();
+
+        let report = serde_json::json!({
+            "generated_at": self.generated_at.to_rfc3339(),
+            "scope": self.scope,
+            "totals": {
+                "repositories": self.totals.repositories,
+                "average_health": self.totals.average_health,
+                "needs_attention": self.totals.needs_attention,
+                "open_pull_requests": self.totals.open_pull_requests,
+                "ci_broken": self.totals.ci_broken,
+                "inactive": self.totals.inactive,
+            },
+            "repositories": repositories,
+        });
+        serde_json::to_string_pretty(&report).unwrap_or_default()
+    }
+
+    /// Write the report in each format, then delete reports beyond `retention`
+    ///
+    /// Returns the paths written.
+    pub fn write(
+        &self,
+        directory: &Path,
+        formats: &[ReportFormat],
+        retention: usize,
+    ) -> Result<Vec<PathBuf>, String> {
+        std::fs::create_dir_all(directory)
+            .map_err(|e| format!("Failed to create {}: {}", directory.display(), e))?;
+
+        let stamp = self.generated_at.format(STAMP_FORMAT);
+        let mut written = Vec::new();
+        for format in formats {
+            let path = directory.join(format!("{}{}.{}", FILE_PREFIX, stamp, format.extension()));
+            std::fs::write(&path, self.render(*format))
+                .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+            prune_reports(directory, format.extension(), retention)?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+}
+
+/// Reports with an extension in a directory, oldest first
+///
+/// Report file names embed a sortable timestamp, so name order is age order.
+pub fn report_files(directory: &Path, extension: &str) -> Result<Vec<PathBuf>, String> {
+    let suffix = format!(".{}", extension);
+    let mut reports: Vec<PathBuf> = std::fs::read_dir(directory)
+        .map_err(|e| format!("Failed to read {}: {}", directory.display(), e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(FILE_PREFIX) && name.ends_with(&suffix))
+        })
+        .collect();
+    reports.sort();
+    Ok(reports)
+}
+
+/// Timestamp embedded in a report file name (e.g. "20260101T120000Z")
+pub fn report_stamp(path: &Path) -> Option<&str> {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .and_then(|stem| stem.strip_prefix(FILE_PREFIX))
+}
+
+/// Delete the oldest reports with an extension, keeping `retention` of them
+fn prune_reports(directory: &Path, extension: &str, retention: usize) -> Result<(), String> {
+    let reports = report_files(directory, extension)?;
+    let excess = reports.len().saturating_sub(retention);
+    for path in &reports[..excess] {
+        std::fs::remove_file(path)
+            .map_err(|e| format!("Failed to delete {}: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+/// What an archived file holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArchiveKind {
+    /// A health report written by the daemon
+    Report,
+    /// A weekly digest
+    Digest,
+}
+
+impl ArchiveKind {
+    /// Get a human-readable label
+    pub fn label(&self) -> &'static str {
+        match self {
+            ArchiveKind::Report => t("reports.report"),
+            ArchiveKind::Digest => t("reports.digest"),
+        }
+    }
+
+    /// File name prefix of this kind
+    fn prefix(&self) -> &'static str {
+        match self {
+            ArchiveKind::Report => FILE_PREFIX,
+            ArchiveKind::Digest => DIGEST_PREFIX,
+        }
+    }
+}
+
+/// A report or digest stored in the reports directory
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchivedReport {
+    pub path: PathBuf,
+    pub kind: ArchiveKind,
+    pub format: ReportFormat,
+    pub generated_at: DateTime<Utc>,
+}
+
+impl ArchivedReport {
+    /// Recognize an archived file by its name
+    fn parse(path: PathBuf) -> Option<Self> {
+        let name = path.file_name()?.to_str()?;
+        let (stem, extension) = name.rsplit_once('.')?;
+        let format = ReportFormat::from_extension(extension)?;
+        let (kind, stamp) = [ArchiveKind::Report, ArchiveKind::Digest]
+            .into_iter()
+            .find_map(|kind| stem.strip_prefix(kind.prefix()).map(|stamp| (kind, stamp)))?;
+        let generated_at = NaiveDateTime::parse_from_str(stamp, STAMP_FORMAT)
+            .ok()?
+            .and_utc();
+        Some(Self {
+            path,
+            kind,
+            format,
+            generated_at,
+        })
+    }
+}
+
+/// Reports and digests in a directory, newest first
+///
+/// A directory that doesn't exist yet has none.
+pub fn archived_reports(directory: &Path) -> Result<Vec<ArchivedReport>, String> {
+    if !directory.exists() {
+        return Ok(Vec::new());
+    }
+    let mut archived: Vec<ArchivedReport> = std::fs::read_dir(directory)
+        .map_err(|e| format!("Failed to read {}: {}", directory.display(), e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter_map(ArchivedReport::parse)
+        .collect();
+    archived.sort_by(|a, b| {
+        b.generated_at
+            .cmp(&a.generated_at)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    Ok(archived)
+}
+
+/// Apply the retention policy to the reports directory
+///
+/// Of each kind and format, the newest `retention` files are kept, minus
+/// those older than `retention_days` when it's set. Returns how many files
+/// were deleted.
+pub fn apply_retention(
+    directory: &Path,
+    reports: &ReportsConfig,
+    now: DateTime<Utc>,
+) -> Result<usize, String> {
+    let max_age = chrono::Duration::days(reports.retention_days as i64);
+    let mut kept: HashMap<(ArchiveKind, ReportFormat), usize> = HashMap::new();
+    let mut deleted = 0;
+    for archived in archived_reports(directory)? {
+        let count = kept.entry((archived.kind, archived.format)).or_default();
+        let expired = reports.retention_days > 0 && now - archived.generated_at > max_age;
+        if *count < reports.retention && !expired {
+            *count += 1;
+            continue;
+        }
+        std::fs::remove_file(&archived.path)
+            .map_err(|e| format!("Failed to delete {}: {}", archived.path.display(), e))?;
+        deleted += 1;
+    }
+    Ok(deleted)
+}
+
+/// Escape text for inclusion in HTML
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RepositoryStatus;
+    use chrono::TimeZone;
+
+    fn report(at: DateTime<Utc>) -> HealthReport {
+        let mut active = Repository::new("api".to_string(), "acme".to_string());
+        active.status = RepositoryStatus::Active;
+        let mut stale = Repository::new("legacy".to_string(), "acme".to_string());
+        stale.status = RepositoryStatus::Stale;
+
+        HealthReport::new(
+            "Org: acme".to_string(),
+            &[active, stale],
+            &AttentionConfig::default(),
+            at,
+        )
+    }
+
+    #[test]
+    fn test_report_formats() {
+        let report = report(Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap());
+        assert_eq!(report.repositories[0].name, "acme/legacy");
+
+        let markdown = report.render(ReportFormat::Markdown);
+        assert!(markdown.contains("- Need attention: 1"));
+        assert!(markdown.contains("| [acme/api]() |"));
+        let utc = TimeConfig {
+            timezone: Some("UTC".to_string()),
+            ..TimeConfig::default()
+        };
+        assert!(report
+            .clone()
+            .with_time(utc)
+            .render(ReportFormat::Markdown)
+            .contains("acme · generated 2026-01-01 12:00\n"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&report.render(ReportFormat::Json)).unwrap();
+        assert_eq!(json["totals"]["repositories"], 2);
+        assert_eq!(json["repositories"][0]["needs_attention"], true);
+
+        assert!(report
+            .render(ReportFormat::Html)
+            .contains("<tr class=\"attention\"><td><a href=\"\">acme/legacy</a>"));
+    }
+
+    #[test]
+    fn test_write_prunes_old_reports() {
+        let directory = std::env::temp_dir().join(format!(
+            "gh-repo-healthchecks-reports-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&directory);
+
+        for hour in 0..3 {
+            report(Utc.with_ymd_and_hms(2026, 1, 1, hour, 0, 0).unwrap())
+                .write(&directory, &[ReportFormat::Markdown, ReportFormat::Json], 2)
+                .unwrap();
+        }
+
+        let mut names: Vec<String> = std::fs::read_dir(&directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                "health-report-20260101T010000Z.json",
+                "health-report-20260101T010000Z.md",
+                "health-report-20260101T020000Z.json",
+                "health-report-20260101T020000Z.md",
+            ]
+        );
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_retention_keeps_newest_within_age() {
+        let directory = std::env::temp_dir().join(format!(
+            "gh-repo-healthchecks-archive-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&directory);
+        for day in 1..=4 {
+            report(Utc.with_ymd_and_hms(2026, 1, day, 12, 0, 0).unwrap())
+                .write(&directory, &[ReportFormat::Json], 10)
+                .unwrap();
+        }
+        std::fs::write(directory.join("weekly-digest-20260101T130000Z.html"), "").unwrap();
+        std::fs::write(directory.join("notes.txt"), "").unwrap();
+
+        let archived = archived_reports(&directory).unwrap();
+        assert_eq!(archived.len(), 5);
+        assert_eq!(
+            archived[0].generated_at,
+            Utc.with_ymd_and_hms(2026, 1, 4, 12, 0, 0).unwrap()
+        );
+        assert_eq!(archived[3].kind, ArchiveKind::Digest);
+
+        // Three per kind and format, then nothing older than two days
+        let mut reports = ReportsConfig {
+            retention: 3,
+            ..ReportsConfig::default()
+        };
+        let now = Utc.with_ymd_and_hms(2026, 1, 5, 0, 0, 0).unwrap();
+        assert_eq!(apply_retention(&directory, &reports, now).unwrap(), 1);
+        assert_eq!(archived_reports(&directory).unwrap().len(), 4);
+        reports.retention_days = 2;
+        assert_eq!(apply_retention(&directory, &reports, now).unwrap(), 2);
+        let kept: Vec<DateTime<Utc>> = archived_reports(&directory)
+            .unwrap()
+            .iter()
+            .map(|archived| archived.generated_at)
+            .collect();
+        assert_eq!(
+            kept,
+            vec![
+                Utc.with_ymd_and_hms(2026, 1, 4, 12, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2026, 1, 3, 12, 0, 0).unwrap(),
+            ]
+        );
+        assert!(directory.join("notes.txt").exists());
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,277 @@
+use crate::config::{SmtpConfig, SmtpSecurity};
+use crate::network;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use rustls::pki_types::ServerName;
+use rustls::ClientConfig;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufStream};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+
+/// Name this client introduces itself with in EHLO
+const CLIENT_NAME: &str = "localhost";
+
+/// Format an HTML email with its headers
+///
+/// The body is base64 encoded, so long HTML lines and lines starting with a
+/// dot need no further care during DATA.
+pub fn format_message(smtp: &SmtpConfig, subject: &str, html: &str, date: DateTime<Utc>) -> String {
+    let mut message = format!(
+        "From: {}\r\nTo: {}\r\nSubject: {}\r\nDate: {}\r\nMIME-Version: 1.0\r\n\
+         Content-Type: text/html; charset=utf-8\r\nContent-Transfer-Encoding: base64\r\n\r\n",
+        smtp.from,
+        smtp.to.join(", "),
+        encode_header(subject),
+        date.to_rfc2822()
+    );
+    let body = STANDARD.encode(html);
+    for line in body.as_bytes().chunks(76) {
+        message.push_str(&String::from_utf8_lossy(line));
+        message.push_str("\r\n");
+    }
+    message
+}
+
+/// Header text, as an RFC 2047 encoded word when it isn't plain ASCII
+fn encode_header(text: &str) -> String {
+    if text.is_ascii() {
+        text.to_string()
+    } else {
+        format!("=?UTF-8?B?{}?=", STANDARD.encode(text))
+    }
+}
+
+/// Send an HTML email to the configured recipients
+///
+/// TLS connections trust the system certificates plus `ca_bundle`.
+pub async fn send(
+    smtp: &SmtpConfig,
+    ca_bundle: Option<&Path>,
+    subject: &str,
+    html: &str,
+) -> Result<(), String> {
+    let message = format_message(smtp, subject, html, Utc::now());
+    let tcp = TcpStream::connect((smtp.host.as_str(), smtp.port))
+        .await
+        .map_err(|e| format!("Failed to connect to {}:{}: {}", smtp.host, smtp.port, e))?;
+
+    match smtp.security {
+        SmtpSecurity::None => {
+            let mut stream = BufStream::new(tcp);
+            reply(&mut stream, &[220]).await?;
+            deliver(&mut stream, smtp, &message).await
+        }
+        SmtpSecurity::StartTls => {
+            let mut stream = BufStream::new(tcp);
+            reply(&mut stream, &[220]).await?;
+            command(&mut stream, &format!("EHLO {}", CLIENT_NAME), &[250]).await?;
+            command(&mut stream, "STARTTLS", &[220]).await?;
+            let tls = start_tls(stream.into_inner(), &smtp.host, ca_bundle).await?;
+            deliver(&mut BufStream::new(tls), smtp, &message).await
+        }
+        SmtpSecurity::Tls => {
+            let tls = start_tls(tcp, &smtp.host, ca_bundle).await?;
+            let mut stream = BufStream::new(tls);
+            reply(&mut stream, &[220]).await?;
+            deliver(&mut stream, smtp, &message).await
+        }
+    }
+}
+
+/// Wrap a connection in TLS, verifying the server's certificate for `host`
+async fn start_tls(
+    tcp: TcpStream,
+    host: &str,
+    ca_bundle: Option<&Path>,
+) -> Result<tokio_rustls::client::TlsStream<TcpStream>, String> {
+    let roots = network::root_certificates(ca_bundle)?;
+    let config = ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    let server_name = ServerName::try_from(host.to_string())
+        .map_err(|e| format!("Invalid SMTP host {}: {}", host, e))?;
+    TlsConnector::from(Arc::new(config))
+        .connect(server_name, tcp)
+        .await
+        .map_err(|e| format!("TLS handshake with {} failed: {}", host, e))
+}
+
+/// Greet, authenticate and hand over the message
+async fn deliver<S>(
+    stream: &mut BufStream<S>,
+    smtp: &SmtpConfig,
+    message: &str,
+) -> Result<(), String>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    command(stream, &format!("EHLO {}", CLIENT_NAME), &[250]).await?;
+    if let Some(username) = &smtp.username {
+        let password = smtp
+            .password()
+            .ok_or("Set digest smtp password or GH_REPO_HEALTHCHECKS_SMTP_PASSWORD")?;
+        let credentials = STANDARD.encode(format!("\0{}\0{}", username, password));
+        command(stream, &format!("AUTH PLAIN {}", credentials), &[235]).await?;
+    }
+
+    command(stream, &format!("MAIL FROM:<{}>", smtp.from), &[250]).await?;
+    for recipient in &smtp.to {
+        command(stream, &format!("RCPT TO:<{}>", recipient), &[250, 251]).await?;
+    }
+    command(stream, "DATA", &[354]).await?;
+    stream
+        .write_all(format!("{}.\r\n", message).as_bytes())
+        .await
+        .map_err(|e| format!("Failed to send message: {}", e))?;
+    stream
+        .flush()
+        .await
+        .map_err(|e| format!("Failed to send message: {}", e))?;
+    reply(stream, &[250]).await?;
+
+    // The message is accepted at this point, so a failed goodbye doesn't matter
+    let _ = command(stream, "QUIT", &[221]).await;
+    Ok(())
+}
+
+/// Send one command and check the reply code
+async fn command<S>(stream: &mut BufStream<S>, line: &str, expected: &[u16]) -> Result<(), String>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    stream
+        .write_all(format!("{}\r\n", line).as_bytes())
+        .await
+        .map_err(|e| format!("Failed to talk to the SMTP server: {}", e))?;
+    stream
+        .flush()
+        .await
+        .map_err(|e| format!("Failed to talk to the SMTP server: {}", e))?;
+    reply(stream, expected).await.map_err(|e| {
+        // Keep credentials out of error messages
+        let verb = line.split_whitespace().next().unwrap_or_default();
+        format!("{} rejected: {}", verb, e)
+    })
+}
+
+/// Read a (possibly multi-line) reply and check its code
+async fn reply<S>(stream: &mut BufStream<S>, expected: &[u16]) -> Result<(), String>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut lines = Vec::new();
+    loop {
+        let mut line = String::new();
+        let read = stream
+            .read_line(&mut line)
+            .await
+            .map_err(|e| format!("Failed to read the SMTP reply: {}", e))?;
+        if read == 0 {
+            return Err("SMTP server closed the connection".to_string());
+        }
+        let line = line.trim_end().to_string();
+        // "250-..." continues a reply, "250 ..." ends it
+        let last = line.as_bytes().get(3) != Some(&b'-');
+        lines.push(line);
+        if last {
+            break;
+        }
+    }
+
+    let code = lines[0].get(..3).and_then(|code| code.parse::<u16>().ok());
+    if code.is_some_and(|code| expected.contains(&code)) {
+        Ok(())
+    } else {
+        Err(lines.join(" / "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::BufReader;
+    use tokio::net::TcpListener;
+
+    /// Accept one SMTP session, returning the commands and message it received
+    async fn fake_server(listener: TcpListener) -> (Vec<String>, String) {
+        let (socket, _) = listener.accept().await.unwrap();
+        let (read, mut write) = socket.into_split();
+        let mut lines = BufReader::new(read).lines();
+        write.write_all(b"220 mail.test ESMTP\r\n").await.unwrap();
+
+        let mut commands = Vec::new();
+        let mut data = String::new();
+        while let Some(line) = lines.next_line().await.unwrap() {
+            let reply: &[u8] = match line.split_whitespace().next().unwrap_or_default() {
+                "EHLO" => b"250-mail.test\r\n250 AUTH PLAIN\r\n",
+                "AUTH" => b"235 Authenticated\r\n",
+                "DATA" => b"354 Go ahead\r\n",
+                "QUIT" => b"221 Bye\r\n",
+                _ => b"250 OK\r\n",
+            };
+            commands.push(line.clone());
+            write.write_all(reply).await.unwrap();
+            if line == "DATA" {
+                while let Some(line) = lines.next_line().await.unwrap() {
+                    if line == "." {
+                        break;
+                    }
+                    data.push_str(&line);
+                    data.push('\n');
+                }
+                write.write_all(b"250 Queued\r\n").await.unwrap();
+            }
+            if line == "QUIT" {
+                break;
+            }
+        }
+        (commands, data)
+    }
+
+    #[tokio::test]
+    async fn test_send_authenticates_and_delivers_to_every_recipient() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(fake_server(listener));
+
+        let smtp = SmtpConfig {
+            host: "127.0.0.1".to_string(),
+            port,
+            security: SmtpSecurity::None,
+            username: Some("digest".to_string()),
+            password: Some("secret".to_string()),
+            from: "health@acme.test".to_string(),
+            to: vec![
+                "platform@acme.test".to_string(),
+                "cto@acme.test".to_string(),
+            ],
+        };
+        send(&smtp, None, "Weekly digest · acme", "<p>All good</p>")
+            .await
+            .unwrap();
+
+        let (commands, data) = server.await.unwrap();
+        assert_eq!(
+            commands,
+            vec![
+                "EHLO localhost".to_string(),
+                format!("AUTH PLAIN {}", STANDARD.encode("\0digest\0secret")),
+                "MAIL FROM:<health@acme.test>".to_string(),
+                "RCPT TO:<platform@acme.test>".to_string(),
+                "RCPT TO:<cto@acme.test>".to_string(),
+                "DATA".to_string(),
+                "QUIT".to_string(),
+            ]
+        );
+        assert!(data.contains("To: platform@acme.test, cto@acme.test\n"));
+        assert!(data.contains(&format!(
+            "Subject: =?UTF-8?B?{}?=\n",
+            STANDARD.encode("Weekly digest · acme")
+        )));
+        let body = data.split("\n\n").nth(1).unwrap().replace('\n', "");
+        assert_eq!(STANDARD.decode(body).unwrap(), b"<p>All good</p>");
+    }
+}
@@ -0,0 +1,174 @@
+use crate::app::App;
+use crate::config::{AppConfig, ReportFormat};
+use crate::diff::Snapshot;
+use crate::i18n::fill;
+use crate::report::{report_files, HealthReport};
+use chrono::{DateTime, Utc};
+use std::error::Error;
+use std::time::{Duration, Instant};
+
+/// Longest a fresh refresh may take before the status line gives up
+const REFRESH_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// How often background messages are processed while refreshing
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Fill a status line format with a snapshot's totals
+///
+/// `{healthy}` counts repositories not needing attention, `{failing}` those
+/// whose latest workflow run failed and `{age}` how old the snapshot is.
+pub fn render(format: &str, snapshot: &Snapshot, now: DateTime<Utc>) -> String {
+    let totals = &snapshot.totals;
+    let health = totals
+        .average_health
+        .map_or("n/a".to_string(), |score| score.to_string());
+    let age = snapshot_time(snapshot).map_or("?".to_string(), |at| compact_age(now - at));
+    fill(
+        format,
+        &[
+            (
+                "healthy",
+                &totals.repositories.saturating_sub(totals.needs_attention),
+            ),
+            ("failing", &totals.ci_broken),
+            ("attention", &totals.needs_attention),
+            ("total", &totals.repositories),
+            ("health", &health),
+            ("prs", &totals.open_pull_requests),
+            ("age", &age),
+        ],
+    )
+}
+
+/// When a snapshot was generated
+fn snapshot_time(snapshot: &Snapshot) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(&snapshot.generated_at)
+        .ok()
+        .map(|at| at.with_timezone(&Utc))
+}
+
+/// A duration in its largest whole unit, e.g. "5m", "3h" or "2d"
+fn compact_age(age: chrono::Duration) -> String {
+    let minutes = age.num_minutes().max(0);
+    match minutes {
+        0..60 => format!("{}m", minutes),
+        60..1440 => format!("{}h", minutes / 60),
+        _ => format!("{}d", minutes / 1440),
+    }
+}
+
+/// Print a one-line health summary for tmux or a shell prompt (`--statusline`)
+///
+/// The latest JSON report is used while it's younger than `[statusline]
+/// max_age_minutes`; otherwise the repositories are refreshed first and the
+/// result is stored as a report for the next call.
+pub async fn run() -> Result<(), Box<dyn Error>> {
+    let config = AppConfig::load()?;
+    let directory = config
+        .reports
+        .directory()
+        .ok_or("No data directory available; set [reports] directory")?;
+    let settings = &config.statusline;
+    let now = Utc::now();
+
+    let cached = report_files(&directory, ReportFormat::Json.extension())
+        .ok()
+        .and_then(|mut reports| reports.pop())
+        .map(|path| Snapshot::load(&path))
+        .transpose()?;
+    let fresh_enough = |snapshot: &Snapshot| {
+        settings.max_age_minutes == 0
+            || snapshot_time(snapshot)
+                .is_some_and(|at| (now - at).num_minutes() < settings.max_age_minutes as i64)
+    };
+    let snapshot = match cached {
+        Some(snapshot) if fresh_enough(&snapshot) => snapshot,
+        _ => refresh(&directory).await?,
+    };
+    println!("{}", render(&settings.format, &snapshot, now));
+    Ok(())
+}
+
+/// Refresh the repositories, storing the result as a JSON report
+async fn refresh(directory: &std::path::Path) -> Result<Snapshot, Box<dyn Error>> {
+    let mut app = App::new();
+    if let Some(error) = app.get_error_message() {
+        return Err(error.into());
+    }
+
+    // A slow connection gets twice as long before the refresh is given up
+    let timeout = if app.config.network.low_bandwidth {
+        REFRESH_TIMEOUT * 2
+    } else {
+        REFRESH_TIMEOUT
+    };
+    let started = Instant::now();
+    let target = app.completed_refreshes + 1;
+    app.auto_refresh();
+    while app.completed_refreshes < target {
+        if let Some(error) = app.get_error_message() {
+            return Err(format!("Refresh failed: {}", error).into());
+        }
+        if started.elapsed() > timeout {
+            return Err("Refresh timed out".into());
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+        app.process_background_messages();
+        app.tick();
+    }
+
+    let report = HealthReport::new(
+        app.repo_view_mode.display_name(),
+        app.get_repositories(),
+        &app.config.attention,
+        Utc::now(),
+    );
+    report.write(
+        directory,
+        &[ReportFormat::Json],
+        app.config.reports.retention,
+    )?;
+    Ok(Snapshot::parse(&report.render(ReportFormat::Json))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AttentionConfig;
+    use crate::models::{Repository, RepositoryStatus};
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_render_fills_totals() {
+        let generated_at = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+        let repositories: Vec<Repository> = [
+            ("api", RepositoryStatus::Active),
+            ("web", RepositoryStatus::Active),
+            ("legacy", RepositoryStatus::Stale),
+        ]
+        .into_iter()
+        .map(|(name, status)| {
+            let mut repo = Repository::new(name.to_string(), "acme".to_string());
+            repo.status = status;
+            repo
+        })
+        .collect();
+        let report = HealthReport::new(
+            "Org: acme".to_string(),
+            &repositories,
+            &AttentionConfig::default(),
+            generated_at,
+        );
+        let snapshot = Snapshot::parse(&report.render(ReportFormat::Json)).unwrap();
+
+        let now = generated_at + chrono::Duration::minutes(135);
+        assert_eq!(
+            render("✔ {healthy} ✖ {failing} ⚠ {attention}", &snapshot, now),
+            "✔ 2 ✖ 0 ⚠ 1"
+        );
+        assert_eq!(
+            render("{total} repos, {prs} PRs ({age} ago)", &snapshot, now),
+            "3 repos, 0 PRs (2h ago)"
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use crate::app::App;
+use crate::bus::{AppEvent, Subscriber};
+use crate::config::AttentionConfig;
+use crate::daemon::MIN_INTERVAL;
+use crate::models::{AggregateMetrics, Repository};
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use std::error::Error;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+/// How often background messages are processed while waiting
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Writes app events as newline-delimited JSON, one object per line
+///
+/// Every object has an `event` field: `repository_updated` as each
+/// repository finishes loading, `alert_raised` when one starts needing
+/// attention (once, until it recovers) and `refresh_completed` with the
+/// totals after each refresh.
+pub struct JsonStream<W: Write + Send> {
+    writer: W,
+    attention: AttentionConfig,
+    /// Repositories an alert has been raised for since they last recovered
+    alerted: HashSet<String>,
+    /// Set once writing fails, e.g. when the reading end of a pipe closed
+    closed: Arc<AtomicBool>,
+}
+
+impl<W: Write + Send> JsonStream<W> {
+    pub fn new(writer: W, attention: AttentionConfig) -> Self {
+        Self {
+            writer,
+            attention,
+            alerted: HashSet::new(),
+            closed: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Flag set once the stream can no longer be written
+    pub fn closed(&self) -> Arc<AtomicBool> {
+        self.closed.clone()
+    }
+
+    /// JSON lines for an event, in the order they're written
+    fn lines(&mut self, event: &AppEvent, now: SystemTime) -> Vec<serde_json::Value> {
+        let timestamp = DateTime::<Utc>::from(now).to_rfc3339();
+        match event {
+            AppEvent::RepositoryUpdated { repository } => {
+                let full_name = repository.full_name();
+                let mut lines = vec![serde_json::json!({
+                    "event": "repository_updated",
+                    "repository": full_name,
+                    "health_score": repository.health_score(),
+                    "activity": repository.status.icon_key(),
+                    "workflow_health": repository.workflow_health.icon_key(),
+                    "open_pull_requests": repository.open_pull_request_count(),
+                    "needs_attention": self.attention.matches(repository),
+                    "html_url": repository.html_url,
+                    "timestamp": timestamp,
+                })];
+                if !self.attention.matches(repository) {
+                    self.alerted.remove(&full_name);
+                } else if self.alerted.insert(full_name.clone()) {
+                    lines.push(alert(repository, &timestamp));
+                }
+                lines
+            }
+            AppEvent::RefreshCompleted { repositories } => {
+                let totals = AggregateMetrics::from_repositories(repositories, &self.attention);
+                vec![serde_json::json!({
+                    "event": "refresh_completed",
+                    "repositories": totals.repositories,
+                    "average_health": totals.average_health,
+                    "needs_attention": totals.needs_attention,
+                    "ci_broken": totals.ci_broken,
+                    "timestamp": timestamp,
+                })]
+            }
+        }
+    }
+}
+
+/// Payload of an `alert_raised` line
+fn alert(repo: &Repository, timestamp: &str) -> serde_json::Value {
+    serde_json::json!({
+        "event": "alert_raised",
+        "repository": repo.full_name(),
+        "health_score": repo.health_score(),
+        "status": repo.status_summary(),
+        "html_url": repo.html_url,
+        "timestamp": timestamp,
+    })
+}
+
+impl<W: Write + Send> Subscriber for JsonStream<W> {
+    fn on_event(&mut self, event: &AppEvent) {
+        if self.closed.load(Ordering::SeqCst) {
+            return;
+        }
+        for line in self.lines(event, SystemTime::now()) {
+            // Flushed per line so readers see each event as it happens
+            let written = writeln!(self.writer, "{}", line).and_then(|_| self.writer.flush());
+            if written.is_err() {
+                self.closed.store(true, Ordering::SeqCst);
+                return;
+            }
+        }
+    }
+}
+
+/// Refresh on the configured interval, streaming events to stdout
+/// (`--stream-json`)
+///
+/// Only JSON goes to stdout; problems are reported on stderr. Runs until
+/// Ctrl-C or until the reader closes stdout.
+pub async fn run() -> Result<(), Box<dyn Error>> {
+    let mut app = App::new();
+    if let Some(error) = app.get_error_message() {
+        return Err(error.into());
+    }
+    if let Some(message) = &app.status_message {
+        eprintln!("{}", message);
+    }
+
+    let stream = JsonStream::new(std::io::stdout(), app.config.attention.clone());
+    let closed = stream.closed();
+    app.event_bus.subscribe(Box::new(stream));
+    let interval = Duration::from_secs(app.config.refresh_interval).max(MIN_INTERVAL);
+
+    loop {
+        let started = Instant::now();
+        let target = app.completed_refreshes + 1;
+        app.auto_refresh();
+
+        while started.elapsed() < interval {
+            app.process_background_messages();
+            app.tick();
+            if closed.load(Ordering::SeqCst) {
+                return Ok(());
+            }
+            if app.completed_refreshes >= target {
+                break;
+            }
+            if let Some(error) = app.get_error_message() {
+                eprintln!("Refresh failed: {}", error);
+                break;
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(POLL_INTERVAL) => {}
+                _ = tokio::signal::ctrl_c() => return Ok(()),
+            }
+        }
+
+        let remaining = interval.saturating_sub(started.elapsed());
+        tokio::select! {
+            _ = tokio::time::sleep(remaining) => {}
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RepositoryStatus;
+
+    #[test]
+    fn test_events_become_json_lines() {
+        let mut stream = JsonStream::new(Vec::new(), AttentionConfig::default());
+        let mut repo = Repository::new("api".to_string(), "acme".to_string());
+        repo.status = RepositoryStatus::Dormant;
+
+        stream.on_event(&AppEvent::RepositoryUpdated { repository: &repo });
+        // Still needing attention: no second alert
+        stream.on_event(&AppEvent::RepositoryUpdated { repository: &repo });
+        stream.on_event(&AppEvent::RefreshCompleted {
+            repositories: std::slice::from_ref(&repo),
+        });
+
+        let output = String::from_utf8(stream.writer).unwrap();
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let events: Vec<&str> = lines
+            .iter()
+            .map(|line| line["event"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                "repository_updated",
+                "alert_raised",
+                "repository_updated",
+                "refresh_completed"
+            ]
+        );
+        assert_eq!(lines[0]["repository"], "acme/api");
+        assert_eq!(lines[0]["activity"], "dormant");
+        assert_eq!(lines[0]["needs_attention"], true);
+        assert_eq!(lines[3]["needs_attention"], 1);
+    }
+}
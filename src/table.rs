@@ -0,0 +1,367 @@
+use crate::config::{ThemeConfig, TimeConfig};
+use crate::models::Repository;
+use ratatui::style::Color;
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+/// Weeks drawn in the Commits column
+const COMMIT_SPARKLINE_WEEKS: usize = 12;
+
+/// How often the relative times in cached rows are reformatted
+const TIME_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Pre-formatted cell contents for one dashboard table row
+///
+/// Formatting (relative dates, emoji labels, colors) is done once when a
+/// repository changes rather than on every frame; the relative dates are
+/// brought up to date by [`RowCache::refresh_times`].
+#[derive(Debug, Clone)]
+pub struct RepositoryRow {
+    pub name: String,
+    pub pr_count: String,
+    pub pr_color: Color,
+    pub issues: String,
+    pub issues_color: Color,
+    pub last_activity: String,
+    /// Sparkline of the last weeks' commits
+    pub commits: String,
+    pub info: String,
+    pub workflow: String,
+    pub workflow_color: Color,
+    pub deploy: String,
+    pub deploy_color: Color,
+    pub release: String,
+    pub release_color: Color,
+    pub code_scanning: String,
+    pub code_scanning_color: Color,
+    pub community: String,
+    pub community_color: Color,
+    pub size: String,
+    pub size_kb: u64,
+    pub status: String,
+    pub status_color: Color,
+}
+
+impl RepositoryRow {
+    /// Format the table cells for a repository, drawing indicators per the theme
+    /// and timestamps per the time settings
+    pub fn from_repository(repo: &Repository, theme: &ThemeConfig, time: &TimeConfig) -> Self {
+        let now = SystemTime::now();
+
+        // Format pull request count
+        let pr_count = repo.open_pull_request_count().to_string();
+        let pr_color = if repo.open_pull_requests.is_empty() {
+            Color::Gray
+        } else {
+            Color::Green
+        };
+
+        // Format open issues, with the unanswered ones in parentheses
+        let (issues, issues_color) = match &repo.issue_metrics {
+            Some(metrics) if metrics.unanswered > 0 => (
+                format!("{} ({})", metrics.open, metrics.unanswered),
+                Color::Yellow,
+            ),
+            Some(metrics) => (metrics.open.to_string(), Color::Gray),
+            None => ("-".to_string(), Color::DarkGray),
+        };
+
+        let last_activity = Self::last_activity(repo, time, now);
+
+        // Draw recent weekly commit counts
+        let commits = match &repo.commit_activity {
+            Some(activity) => activity.sparkline(COMMIT_SPARKLINE_WEEKS),
+            None => "-".to_string(),
+        };
+
+        // Format repository language and stars info
+        let info = match (&repo.language, repo.stars) {
+            (Some(lang), stars) if stars > 0 => format!("{} ({} ⭐)", lang, stars),
+            (Some(lang), _) => lang.clone(),
+            (None, stars) if stars > 0 => format!("{} ⭐", stars),
+            _ => "N/A".to_string(),
+        };
+
+        let deploy_health = repo.deploy_status.health();
+        let (release, release_color) = Self::release(repo, now);
+
+        // Format open code scanning alerts, colored by the worst severity
+        let (code_scanning, code_scanning_color) = match &repo.code_scanning {
+            Some(alerts) => (
+                alerts.label(),
+                theme.color(alerts.color(), alerts.severity()),
+            ),
+            None => ("-".to_string(), Color::DarkGray),
+        };
+
+        // Format the community health percentage
+        let (community, community_color) = match &repo.community {
+            Some(profile) => (
+                profile.label(),
+                theme.color(profile.color(), profile.severity()),
+            ),
+            None => ("-".to_string(), Color::DarkGray),
+        };
+
+        Self {
+            name: repo.name.clone(),
+            pr_count,
+            pr_color,
+            issues,
+            issues_color,
+            last_activity,
+            commits,
+            info,
+            workflow: format!(
+                "{} {}",
+                theme.workflow_health_icon(&repo.workflow_health),
+                repo.workflow_health.description()
+            ),
+            workflow_color: theme.color(
+                repo.workflow_health.color(),
+                repo.workflow_health.severity(),
+            ),
+            deploy: format!(
+                "{} {}",
+                theme.indicator(deploy_health.emoji(), deploy_health.severity()),
+                deploy_health.description()
+            ),
+            deploy_color: theme.color(deploy_health.color(), deploy_health.severity()),
+            release,
+            release_color,
+            code_scanning,
+            code_scanning_color,
+            community,
+            community_color,
+            size: repo.size_label(),
+            size_kb: repo.size_kb,
+            status: format!(
+                "{} {}",
+                theme.repository_icon(&repo.status),
+                repo.status.description()
+            ),
+            status_color: theme.color(repo.status.color(), repo.status.severity()),
+        }
+    }
+
+    /// Format last commit date, or last issue activity for issues-only repositories
+    fn last_activity(repo: &Repository, time: &TimeConfig, now: SystemTime) -> String {
+        let latest = match &repo.issue_health {
+            Some(health) => health.latest_activity,
+            None => repo.latest_commit_at,
+        };
+        match latest {
+            Some(at) => time.format(at, now),
+            None => "No commits".to_string(),
+        }
+    }
+
+    /// Format the latest release tag and its age, flagging old releases
+    fn release(repo: &Repository, now: SystemTime) -> (String, Color) {
+        match &repo.latest_release {
+            Some(release) => (
+                format!("{} · {}d", release.tag, release.days_ago(now)),
+                if release.is_stale(now) {
+                    Color::Yellow
+                } else {
+                    Color::Reset
+                },
+            ),
+            None => ("None".to_string(), Color::Gray),
+        }
+    }
+}
+
+/// Cache of formatted rows kept in step with the repository list
+///
+/// Rows are updated incrementally as repositories are fetched or enhanced,
+/// and a full-name index makes replacing a single repository O(1) even with
+/// thousands of rows.
+#[derive(Debug, Default)]
+pub struct RowCache {
+    /// Theme the rows are formatted with
+    theme: ThemeConfig,
+    /// How the last activity is shown
+    time: TimeConfig,
+    rows: Vec<RepositoryRow>,
+    index: HashMap<String, usize>,
+    with_open_prs: usize,
+    /// When the relative times of all rows were last formatted
+    times_formatted_at: Option<SystemTime>,
+}
+
+impl RowCache {
+    /// Create an empty cache formatting rows with a theme and time settings
+    pub fn new(theme: ThemeConfig, time: TimeConfig) -> Self {
+        Self {
+            theme,
+            time,
+            ..Self::default()
+        }
+    }
+
+    /// Change the time settings; rows are reformatted on the next rebuild
+    pub fn set_time(&mut self, time: TimeConfig) {
+        self.time = time;
+    }
+
+    /// Rebuild the cache from scratch
+    pub fn rebuild(&mut self, repositories: &[Repository]) {
+        self.clear();
+        for repo in repositories {
+            self.push(repo);
+        }
+        self.times_formatted_at = Some(SystemTime::now());
+    }
+
+    /// Reformat the relative times ("3 days ago", release age) of every row
+    /// once they're a minute old, so a dashboard left open doesn't show
+    /// stale ones until the next refresh
+    ///
+    /// `repositories` must be the list the cache was built from.
+    pub fn refresh_times(&mut self, repositories: &[Repository], now: SystemTime) {
+        let fresh = self.times_formatted_at.is_some_and(|at| {
+            now.duration_since(at)
+                .is_ok_and(|age| age < TIME_REFRESH_INTERVAL)
+        });
+        if fresh {
+            return;
+        }
+        for (row, repo) in self.rows.iter_mut().zip(repositories) {
+            row.last_activity = RepositoryRow::last_activity(repo, &self.time, now);
+            (row.release, row.release_color) = RepositoryRow::release(repo, now);
+        }
+        self.times_formatted_at = Some(now);
+    }
+
+    /// Remove all rows
+    pub fn clear(&mut self) {
+        self.rows.clear();
+        self.index.clear();
+        self.with_open_prs = 0;
+    }
+
+    /// Append a row for a newly fetched repository
+    pub fn push(&mut self, repo: &Repository) {
+        self.index.insert(repo.full_name(), self.rows.len());
+        if !repo.open_pull_requests.is_empty() {
+            self.with_open_prs += 1;
+        }
+        self.rows.push(RepositoryRow::from_repository(
+            repo,
+            &self.theme,
+            &self.time,
+        ));
+    }
+
+    /// Replace the row at `position` after `previous` was updated to `repo`
+    pub fn update(&mut self, position: usize, previous: &Repository, repo: &Repository) {
+        if !previous.open_pull_requests.is_empty() {
+            self.with_open_prs -= 1;
+        }
+        if !repo.open_pull_requests.is_empty() {
+            self.with_open_prs += 1;
+        }
+        self.rows[position] = RepositoryRow::from_repository(repo, &self.theme, &self.time);
+    }
+
+    /// Position of a repository by full name (owner/name)
+    pub fn position(&self, full_name: &str) -> Option<usize> {
+        self.index.get(full_name).copied()
+    }
+
+    /// Row for the repository at `position`
+    pub fn row(&self, position: usize) -> Option<&RepositoryRow> {
+        self.rows.get(position)
+    }
+
+    /// Number of repositories with at least one open pull request
+    pub fn with_open_prs(&self) -> usize {
+        self.with_open_prs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{PullRequest, PullRequestState};
+    use std::time::SystemTime;
+
+    fn pull_request() -> PullRequest {
+        PullRequest {
+            number: 1,
+            title: "Fix".to_string(),
+            state: PullRequestState::Open,
+            created_at: SystemTime::now(),
+            updated_at: SystemTime::now(),
+            author: "octocat".to_string(),
+            html_url: String::new(),
+            draft: false,
+            approvals: 0,
+            changes_requested: 0,
+            head_sha: String::new(),
+            base_branch: "main".to_string(),
+            linked_issues: Vec::new(),
+            tasks: None,
+            feedback_waiting_since: None,
+            review_decision: None,
+            checks: None,
+            labels: Vec::new(),
+            milestone: None,
+        }
+    }
+
+    #[test]
+    fn test_row_cache_incremental_updates() {
+        let mut cache = RowCache::default();
+        let a = Repository::new("a".to_string(), "org".to_string());
+        let b = Repository::new("b".to_string(), "org".to_string());
+        cache.rebuild(&[a.clone(), b.clone()]);
+
+        assert!(cache.row(1).is_some() && cache.row(2).is_none());
+        assert_eq!(cache.position("org/b"), Some(1));
+        assert_eq!(cache.with_open_prs(), 0);
+
+        let mut enhanced = b.clone();
+        enhanced.open_pull_requests.push(pull_request());
+        cache.update(1, &b, &enhanced);
+
+        assert_eq!(cache.with_open_prs(), 1);
+        assert_eq!(cache.row(1).unwrap().pr_count, "1");
+    }
+
+    #[test]
+    fn test_rows_use_theme_indicators() {
+        let theme = ThemeConfig {
+            indicators: crate::config::IndicatorSet::Letters,
+            palette: crate::config::Palette::Colorblind,
+            ..ThemeConfig::default()
+        };
+        let mut cache = RowCache::new(theme, TimeConfig::default());
+        let mut repo = Repository::new("a".to_string(), "org".to_string());
+        repo.status = crate::models::RepositoryStatus::Dormant;
+        cache.push(&repo);
+
+        let row = cache.row(0).unwrap();
+        assert!(row.status.starts_with("F "));
+        assert_eq!(row.status_color, Color::Rgb(213, 94, 0));
+    }
+
+    #[test]
+    fn test_relative_times_are_refreshed() {
+        let now = SystemTime::now();
+        let mut repo = Repository::new("a".to_string(), "org".to_string());
+        repo.latest_commit_at = Some(now - Duration::from_secs(86400 - 20));
+        let repositories = [repo];
+        let mut cache = RowCache::default();
+        cache.rebuild(&repositories);
+        assert_eq!(cache.row(0).unwrap().last_activity, "Today");
+
+        // Not yet a minute since the rows were formatted
+        cache.refresh_times(&repositories, now + Duration::from_secs(30));
+        assert_eq!(cache.row(0).unwrap().last_activity, "Today");
+
+        cache.refresh_times(&repositories, now + Duration::from_secs(2 * 86400));
+        assert_eq!(cache.row(0).unwrap().last_activity, "2 days ago");
+    }
+}
@@ -2,7 +2,7 @@ use ratatui::{
     crossterm::{
         execute,
         terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-        event::{DisableMouseCapture, EnableMouseCapture},
+        event::{DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture},
     },
     prelude::*,
 };
@@ -34,8 +34,14 @@ impl TerminalManager {
         
         // Enter alternate screen - this creates a new screen buffer so we don't
         // interfere with the user's existing terminal content
-        // Enable mouse capture for potential future features
-        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        // Enable mouse capture for potential future features, and focus
+        // reporting so background work can pause while the terminal is unfocused
+        execute!(
+            stdout,
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableFocusChange
+        )?;
 
         // Create the terminal backend and terminal instance
         let backend = CrosstermBackend::new(stdout);
@@ -59,11 +65,12 @@ impl TerminalManager {
         // Disable raw mode to restore normal terminal behavior
         disable_raw_mode()?;
         
-        // Exit alternate screen and disable mouse capture
+        // Exit alternate screen and disable mouse capture and focus reporting
         execute!(
             terminal.backend_mut(),
             LeaveAlternateScreen,
-            DisableMouseCapture
+            DisableMouseCapture,
+            DisableFocusChange
         )?;
         
         // Make sure the cursor is visible when we exit
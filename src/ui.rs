@@ -1,6 +1,18 @@
-use crate::app::{App, AppView};
+use crate::app::{
+    sort_arrow, App, AppView, ComparisonPane, RepositorySort, RepositoryViewMode, TokenStatus,
+};
+use crate::audit::{AuditEventKind, AUDIT_LOOKBACK_DAYS};
+use crate::cleanup::CleanupReason;
+use crate::config::{AppConfig, Collector, RepositoryConfig, TimeStyle};
+use crate::dialog::{Dialog, DialogField, Menu};
+use crate::i18n::{t, t_with};
+use crate::models::{
+    AggregateMetrics, BurndownWeek, BusFactor, ConventionCompliance, IndicatorExplanation,
+    PullRequest, QueueStats, Release, Repository, ReviewDecision, TimelineBar, WorkflowBreakdown,
+    WorkflowStatus,
+};
 use ratatui::{prelude::*, widgets::*};
-use std::time::Duration;
+use std::borrow::Cow;
 
 /// Main UI renderer
 ///
@@ -16,9 +28,44 @@ impl UI {
     pub fn render(frame: &mut Frame, app: &App) {
         match app.current_view {
             AppView::Dashboard => Self::render_dashboard(frame, app),
+            AppView::RepoDetails => Self::render_repo_details(frame, app),
+            AppView::PullRequestDetails => Self::render_pull_request_details(frame, app),
+            AppView::PullRequestFiles => Self::render_pull_request_files(frame, app),
+            AppView::OrgMembership => Self::render_org_membership(frame, app),
+            AppView::AuditLog => Self::render_audit_log(frame, app),
+            AppView::CiAnalytics => Self::render_ci_analytics(frame, app),
+            AppView::ReleaseBoard => Self::render_release_board(frame, app),
+            AppView::IssueBurndown => Self::render_issue_burndown(frame, app),
+            AppView::Issues => Self::render_issues(frame, app),
+            AppView::IssueSearch => Self::render_issue_search(frame, app),
+            AppView::WorkflowTimeline => Self::render_workflow_timeline(frame, app),
+            AppView::TemplateCompliance => Self::render_template_compliance(frame, app),
+            AppView::Policies => Self::render_policies(frame, app),
+            AppView::Comparison => Self::render_comparison(frame, app),
+            AppView::Dependencies => Self::render_dependencies(frame, app),
+            AppView::Cleanup => Self::render_cleanup(frame, app),
+            AppView::Contributions => Self::render_contributions(frame, app),
+            AppView::Reports => Self::render_reports(frame, app),
+            AppView::Links => Self::render_links(frame, app),
+            AppView::TokenDiagnostics => Self::render_token_diagnostics(frame, app),
+            AppView::ConfigProblems => Self::render_config_problems(frame, app),
             // Future views:
             // AppView::Settings => Self::render_settings(frame, app),
-            // AppView::RepoDetails => Self::render_repo_details(frame, app),
+        }
+
+        if app.show_inspect && app.current_view == AppView::Dashboard {
+            Self::render_inspect_popover(frame, app);
+        }
+        if app.show_metrics {
+            Self::render_metrics_overlay(frame, app);
+        }
+
+        // Dialogs and menus are drawn on top of whichever view opened them
+        if let Some(dialog) = &app.dialog {
+            Self::render_dialog(frame, dialog);
+        }
+        if let Some(menu) = &app.menu {
+            Self::render_menu(frame, menu);
         }
     }
 
@@ -31,31 +78,78 @@ impl UI {
     fn render_dashboard(frame: &mut Frame, app: &App) {
         let area = frame.area();
 
-        // Create the main layout: header, content, footer
+        // Show a banner line while GitHub itself reports an incident, and a
+        // second one while guest mode is on so it's obvious on a screen-share
+        let banner_style = Style::default().add_modifier(Modifier::BOLD);
+        let mut banners = Vec::new();
+        if app.demo {
+            banners.push(Line::styled(
+                t("header.demo_banner"),
+                banner_style.fg(Color::Black).bg(Color::Cyan),
+            ));
+        }
+        if app.guest_mode {
+            banners.push(Line::styled(
+                t("header.guest_banner"),
+                banner_style.fg(Color::Black).bg(Color::Yellow),
+            ));
+        }
+        if let Some(banner) = app.platform_banner() {
+            banners.push(Line::styled(
+                banner,
+                banner_style.fg(Color::White).bg(Color::Red),
+            ));
+        }
+        if let Some(held) = &app.held_dataset {
+            banners.push(Line::styled(
+                t_with(
+                    "header.canary_banner",
+                    &[("summary", &held.change.summary())],
+                ),
+                banner_style.fg(Color::Black).bg(Color::LightYellow),
+            ));
+        }
+
+        // Create the main layout: header, banners, content, footer
+        let banner_height = banners.len() as u16;
         let main_layout = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3), // Header
-                Constraint::Min(0),    // Content (flexible)
-                Constraint::Length(3), // Footer
+                Constraint::Length(3),             // Header
+                Constraint::Length(banner_height), // Guest and platform status banners
+                Constraint::Min(0),                // Content (flexible)
+                Constraint::Length(3),             // Footer
             ])
             .split(area);
 
         // Render each section
         Self::render_header(frame, main_layout[0], app);
-        Self::render_content(frame, main_layout[1], app);
-        Self::render_footer(frame, main_layout[2], app);
+        frame.render_widget(
+            Paragraph::new(banners).alignment(Alignment::Center),
+            main_layout[1],
+        );
+        Self::render_content(frame, main_layout[2], app);
+        Self::render_footer(frame, main_layout[3], app);
     }
 
     /// Render the header section
     ///
     /// Shows the application title and status information
     fn render_header(frame: &mut Frame, area: Rect, app: &App) {
-        let title_with_mode = format!(
+        let mut title_with_mode = format!(
             "{} - {}",
             app.get_title(),
             app.repo_view_mode.display_name()
         );
+        if let Some(view) = app.active_smart_view() {
+            title_with_mode.push_str(&t_with("header.view_title", &[("name", &view.name)]));
+        }
+        if let Some(team) = &app.team_filter {
+            title_with_mode.push_str(&t_with("header.team_title", &[("team", team)]));
+        }
+        if let Some(topic) = &app.topic_filter {
+            title_with_mode.push_str(&t_with("header.topic_title", &[("topic", topic)]));
+        }
 
         let header_block = Block::default()
             .title(title_with_mode)
@@ -75,32 +169,87 @@ impl UI {
 
         // Repository count info
         if app.is_fetching_organizations {
-            status_lines.push(Line::from("Fetching organizations..."));
+            status_lines.push(Line::from(t("header.fetching_organizations")));
         } else if app.repositories.is_empty() && !app.is_loading() {
-            status_lines.push(Line::from("No repositories found"));
+            status_lines.push(Line::from(t("header.no_repositories")));
         } else if app.is_loading() && app.repositories.is_empty() {
-            status_lines.push(Line::from("Loading repositories..."));
+            status_lines.push(Line::from(t("header.loading")));
         } else {
-            let active_count = app
-                .repositories
-                .iter()
-                .filter(|repo| !repo.open_pull_requests.is_empty())
-                .count();
+            let active_count = app.row_cache.with_open_prs();
 
-            status_lines.push(Line::from(format!(
-                "{} repositories ({} with active PRs)",
-                app.repositories.len(),
-                active_count
-            )));
+            if let Some(view) = app.active_smart_view() {
+                status_lines.push(Line::from(t_with(
+                    "header.view",
+                    &[
+                        ("name", &view.name),
+                        ("visible", &app.visible_repository_count()),
+                        ("hidden", &app.hidden_repository_count()),
+                    ],
+                )));
+            } else if let Some(team) = &app.team_filter {
+                status_lines.push(Line::from(t_with(
+                    "header.team",
+                    &[
+                        ("team", team),
+                        ("visible", &app.visible_repository_count()),
+                        ("hidden", &app.hidden_repository_count()),
+                    ],
+                )));
+            } else if let Some(topic) = &app.topic_filter {
+                status_lines.push(Line::from(t_with(
+                    "header.topic",
+                    &[
+                        ("topic", topic),
+                        ("visible", &app.visible_repository_count()),
+                        ("hidden", &app.hidden_repository_count()),
+                    ],
+                )));
+            } else if app.focus_mode {
+                status_lines.push(Line::from(t_with(
+                    "header.focus",
+                    &[
+                        ("visible", &app.visible_repository_count()),
+                        ("hidden", &app.hidden_repository_count()),
+                    ],
+                )));
+            } else {
+                status_lines.push(Line::from(t_with(
+                    "header.repositories",
+                    &[
+                        ("count", &app.repositories.len()),
+                        ("active", &active_count),
+                    ],
+                )));
+            }
         }
 
         // Last refresh info
         if let Some(last_refresh) = app.last_refresh {
             let elapsed = last_refresh.elapsed();
-            let refresh_text = if elapsed.as_secs() < 60 {
-                format!("Last refresh: {}s ago", elapsed.as_secs())
+            let refresh_text = if app.config.time.style == TimeStyle::Absolute {
+                let at = std::time::SystemTime::now() - elapsed;
+                t_with(
+                    "header.refreshed_at",
+                    &[("time", &app.config.time.absolute(at))],
+                )
+            } else if elapsed.as_secs() < 60 {
+                t_with(
+                    "header.refreshed_seconds",
+                    &[("seconds", &elapsed.as_secs())],
+                )
             } else {
-                format!("Last refresh: {}m ago", elapsed.as_secs() / 60)
+                t_with(
+                    "header.refreshed_minutes",
+                    &[("minutes", &(elapsed.as_secs() / 60))],
+                )
+            };
+            let refresh_text = match app.last_refresh_requests {
+                Some(requests) => format!(
+                    "{} · {}",
+                    refresh_text,
+                    t_with("header.refresh_requests", &[("count", &requests)])
+                ),
+                None => refresh_text,
             };
             status_lines.push(Line::from(refresh_text));
         }
@@ -120,7 +269,7 @@ impl UI {
         let content_block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Gray))
-            .title("Repositories")
+            .title(t("content.title"))
             .title_alignment(Alignment::Left);
 
         frame.render_widget(content_block, area);
@@ -132,24 +281,20 @@ impl UI {
             // Show loading indicator with progress ONLY if we don't have any repositories yet
             let mut loading_text = vec![
                 Line::from(""),
-                Line::from("🔄 Loading repositories..."),
+                Line::from(t("content.loading")),
                 Line::from(""),
             ];
 
             // Add progress information if available
             if let Some((current, total)) = app.loading_progress {
-                loading_text.push(Line::from(format!(
-                    "Progress: {} / {} repositories",
-                    current, total
+                loading_text.push(Line::from(t_with(
+                    "content.progress",
+                    &[("current", &current), ("total", &total)],
                 )));
 
                 // Create a simple progress bar
                 let progress_width = 40;
-                let filled = if total > 0 {
-                    (current * progress_width) / total
-                } else {
-                    0
-                };
+                let filled = (current * progress_width).checked_div(total).unwrap_or(0);
                 let empty = progress_width - filled;
 
                 let progress_bar = format!(
@@ -166,9 +311,7 @@ impl UI {
                 loading_text.push(Line::from(""));
                 loading_text.push(Line::from(progress_bar));
             } else {
-                loading_text.push(Line::from(
-                    "This may take a moment while we fetch data from GitHub.",
-                ));
+                loading_text.push(Line::from(t("content.loading_hint")));
             }
 
             let loading = Paragraph::new(loading_text)
@@ -180,11 +323,11 @@ impl UI {
             // Show error message
             let error_text = vec![
                 Line::from(""),
-                Line::from("❌ Error loading repositories"),
+                Line::from(t("content.error")),
                 Line::from(""),
                 Line::from(error),
                 Line::from(""),
-                Line::from("Press 'r' to retry"),
+                Line::from(t("content.retry")),
             ];
 
             let error_paragraph = Paragraph::new(error_text)
@@ -196,11 +339,11 @@ impl UI {
             // Show empty state
             let empty_text = vec![
                 Line::from(""),
-                Line::from("📂 No repositories found"),
+                Line::from(t("content.empty")),
                 Line::from(""),
-                Line::from("Make sure your GitHub token has access to repositories."),
+                Line::from(t("content.empty_hint")),
                 Line::from(""),
-                Line::from("Press 'r' to refresh"),
+                Line::from(t("content.refresh_hint")),
             ];
 
             let empty = Paragraph::new(empty_text)
@@ -212,18 +355,38 @@ impl UI {
             // Show repository table
             Self::render_repository_table(frame, inner_area, app);
 
-            // If we're enhancing repositories, show an enhancement indicator in the corner
-            if app.is_enhancing {
-                let enhancement_indicator = match app.enhancement_progress {
-                    Some((current, total)) => {
-                        format!("Enhancing: {}/{} repos", current, total)
-                    }
-                    None => "Enhancing...".to_string(),
-                };
-
+            // While pages are still arriving or repositories are being enhanced,
+            // show the progress in the corner
+            let indicator = if app.is_loading() {
+                app.loading_progress.map(|(current, total)| {
+                    t_with(
+                        "content.paginating",
+                        &[("current", &current), ("total", &total)],
+                    )
+                })
+            } else if app.is_enhancing {
+                Some(match (&app.rate_limit_pause, app.enhancement_progress) {
+                    (Some(pause), _) => t_with(
+                        "content.rate_limited",
+                        &[
+                            ("current", &pause.current),
+                            ("total", &pause.total),
+                            ("countdown", &pause.countdown(std::time::SystemTime::now())),
+                        ],
+                    ),
+                    (None, Some((current, total))) => t_with(
+                        "content.enhancing",
+                        &[("current", &current), ("total", &total)],
+                    ),
+                    (None, None) => t("content.enhancing_unknown").to_string(),
+                })
+            } else {
+                None
+            };
+            if let Some(indicator_text) = indicator {
                 // Create a small floating widget for the enhancement status
                 let indicator_height = 3;
-                let indicator_width = enhancement_indicator.len() as u16 + 4;
+                let indicator_width = indicator_text.chars().count() as u16 + 4;
                 let indicator_x = area.width.saturating_sub(indicator_width);
                 let indicator_y = 0;
 
@@ -234,7 +397,7 @@ impl UI {
                     indicator_height,
                 );
 
-                let indicator_widget = Paragraph::new(enhancement_indicator)
+                let indicator_widget = Paragraph::new(indicator_text)
                     .style(Style::default().fg(Color::Yellow))
                     .block(
                         Block::default()
@@ -259,7 +422,7 @@ impl UI {
                     .fg(Color::Green)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::raw("Refresh  "),
+            Span::raw(format!("{}  ", t("footer.refresh"))),
             Span::styled(
                 "[Tab] ",
                 Style::default()
@@ -270,35 +433,220 @@ impl UI {
         
         // Dynamic Tab key description based on organization state
         if app.is_fetching_organizations {
-            controls.push(Span::raw("Fetching Orgs...  "));
+            controls.push(Span::raw(format!("{}  ", t("footer.fetching_orgs"))));
         } else if app.user_organizations.is_empty() {
-            controls.push(Span::raw("Fetch Orgs  "));
+            controls.push(Span::raw(format!("{}  ", t("footer.fetch_orgs"))));
         } else {
-            let mode_text = format!("Switch Mode ({} orgs)  ", app.user_organizations.len());
-            controls.push(Span::raw(mode_text));
+            let mode_text = t_with(
+                "footer.switch_mode",
+                &[("count", &app.user_organizations.len())],
+            );
+            controls.push(Span::raw(format!("{}  ", mode_text)));
+        }
+
+        // Membership panel is only available while viewing an organization
+        if matches!(app.repo_view_mode, RepositoryViewMode::Organization(_)) {
+            controls.push(Span::styled(
+                "[m] ",
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            controls.push(Span::raw(format!("{}  ", t("footer.members"))));
+            if app.config.compliance.template.is_some() {
+                controls.push(Span::styled(
+                    "[n] ",
+                    Style::default()
+                        .fg(Color::Magenta)
+                        .add_modifier(Modifier::BOLD),
+                ));
+                controls.push(Span::raw(format!("{}  ", t("footer.new_repo"))));
+            }
+        }
+
+        controls.push(Span::styled(
+            "[c] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!("{}  ", t("footer.ci"))));
+        controls.push(Span::styled(
+            "[g] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!("{}  ", t("footer.dependencies"))));
+        controls.push(Span::styled(
+            "[h] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!("{}  ", t("footer.cleanup"))));
+        controls.push(Span::styled(
+            "[u] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!("{}  ", t("footer.contributions"))));
+        controls.push(Span::styled(
+            "[l] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!("{}  ", t("footer.issues"))));
+        controls.push(Span::styled(
+            "[j] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!("{}  ", t("footer.reports"))));
+        controls.push(Span::styled(
+            "[k] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!("{}  ", t("footer.links"))));
+        controls.push(Span::styled(
+            "[i] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!("{}  ", t("footer.inspect"))));
+        controls.push(Span::styled(
+            "[z] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!("{}  ", t("footer.snooze"))));
+        controls.push(Span::styled(
+            "['] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!("{}  ", t("footer.jump"))));
+        controls.push(Span::styled(
+            "[p] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!("{}  ", t("footer.guest"))));
+        if app.is_loading() && app.pagination_stop.is_some() {
+            controls.push(Span::styled(
+                "[x] ",
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            controls.push(Span::raw(format!("{}  ", t("footer.stop_loading"))));
+        }
+        if app.comparison_sources().len() >= 2 {
+            controls.push(Span::styled(
+                "[v] ",
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            controls.push(Span::raw(format!("{}  ", t("footer.compare"))));
         }
+        if app.config.compliance.template.is_some() {
+            controls.push(Span::styled(
+                "[t] ",
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            controls.push(Span::raw(format!("{}  ", t("footer.template"))));
+        }
+        controls.push(Span::styled(
+            "[f] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!(
+            "{}  ",
+            if app.focus_mode {
+                t("footer.show_all")
+            } else {
+                t("footer.focus")
+            }
+        )));
+        controls.push(Span::styled(
+            "[w] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!("{}  ", t("footer.views"))));
+        controls.push(Span::styled(
+            "[o] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!("{}  ", t("footer.teams"))));
+        controls.push(Span::styled(
+            "[e] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!("{}  ", t("footer.features"))));
+        controls.push(Span::styled(
+            "[s] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!(
+            "{}  ",
+            t_with("footer.sort", &[("order", &app.sort_label())])
+        )));
+        controls.push(Span::styled(
+            "[d] ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+        controls.push(Span::raw(format!("{}  ", t("footer.metrics"))));
         
         controls.extend_from_slice(&[
             Span::styled(
                 "[q] ",
                 Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
             ),
-            Span::raw("Quit  "),
+            Span::raw(format!("{}  ", t("footer.quit"))),
             Span::styled(
                 "[↑↓] ",
                 Style::default()
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::raw("Navigate  "),
+            Span::raw(format!("{}  ", t("footer.navigate"))),
         ]);
 
         // Add pagination info if we have repositories
         if !app.repositories.is_empty() {
             let page_info = format!(
-                "({}/{} repos) ",
-                app.scroll_offset + 1,
-                app.repositories.len()
+                "{} ",
+                t_with(
+                    "footer.page_info",
+                    &[
+                        ("position", &(app.scroll_offset + 1)),
+                        ("count", &app.visible_repository_count()),
+                    ],
+                )
             );
             controls.push(Span::styled(
                 page_info,
@@ -314,7 +662,7 @@ impl UI {
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
             ));
-            controls.push(Span::raw("Page  "));
+            controls.push(Span::raw(format!("{}  ", t("footer.page"))));
 
             // Home/End controls
             controls.push(Span::styled(
@@ -323,21 +671,77 @@ impl UI {
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
             ));
-            controls.push(Span::raw("Top/Bottom"));
+            controls.push(Span::raw(t("footer.top_bottom")));
 
             // Enhancement status
             if app.is_enhancing {
-                if let Some((current, total)) = app.enhancement_progress {
+                if let Some(pause) = &app.rate_limit_pause {
                     controls.push(Span::raw("  "));
                     controls.push(Span::styled(
-                        format!("Enhancing: {}/{}", current, total),
+                        t_with(
+                            "footer.paused",
+                            &[("countdown", &pause.countdown(std::time::SystemTime::now()))],
+                        ),
+                        Style::default().fg(Color::LightRed),
+                    ));
+                } else if app.is_background_paused() {
+                    controls.push(Span::raw("  "));
+                    controls.push(Span::styled(
+                        t("footer.unfocused"),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                } else if let Some((current, total)) = app.enhancement_progress {
+                    controls.push(Span::raw("  "));
+                    controls.push(Span::styled(
+                        t_with(
+                            "footer.enhancing",
+                            &[("current", &current), ("total", &total)],
+                        ),
                         Style::default().fg(Color::Yellow),
                     ));
                 }
             }
         }
 
-        let footer_text = Line::from(controls);
+        // API quota left, red once loading details would pause for it
+        if let Some(status) = app.rate_limit() {
+            let reset = chrono::DateTime::<chrono::Local>::from(status.reset).format("%H:%M");
+            let style = if status.remaining <= app.config.api.rate_limit_reserve {
+                Style::default().fg(Color::LightRed)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            controls.push(Span::raw("  "));
+            controls.push(Span::styled(
+                t_with(
+                    "footer.quota",
+                    &[("remaining", &status.remaining), ("reset", &reset)],
+                ),
+                style,
+            ));
+        }
+
+        // While quick jump is active the footer shows the typed prefix instead
+        let footer_text = match &app.quick_jump {
+            Some(prefix) => {
+                let prefix_style = if prefix.is_empty() || app.quick_jump_match().is_some() {
+                    Style::default().fg(Color::Yellow)
+                } else {
+                    Style::default().fg(Color::Red)
+                };
+                Line::from(vec![
+                    Span::styled(
+                        format!("{} ", t("footer.jump")),
+                        Style::default()
+                            .fg(Color::Magenta)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(format!("{}▏", prefix), prefix_style),
+                    Span::raw(format!("  {}", t("footer.jump_hint"))),
+                ])
+            }
+            None => Line::from(controls),
+        };
 
         let footer = Paragraph::new(footer_text)
             .alignment(Alignment::Center)
@@ -354,9 +758,16 @@ impl UI {
 impl UI {
     /// Render the repository table with actual data
     fn render_repository_table(frame: &mut Frame, area: Rect, app: &App) {
-        let repositories = app.get_repositories();
+        if app.get_repositories().is_empty() {
+            return;
+        }
 
-        if repositories.is_empty() {
+        let count = app.visible_repository_count();
+        if count == 0 {
+            let message = Paragraph::new(t("content.none_need_attention"))
+                .style(Style::default().fg(Color::Green))
+                .alignment(Alignment::Center);
+            frame.render_widget(message, area);
             return;
         }
 
@@ -365,171 +776,3783 @@ impl UI {
         let visible_items = app.get_visible_item_count(visible_height);
 
         // Get scroll window based on current offset
-        let start_index = app.scroll_offset;
-        let end_index = (app.scroll_offset + visible_items).min(repositories.len());
+        let end_index = (app.scroll_offset + visible_items).min(count);
+        let start_index = app.scroll_offset.min(end_index);
 
-        // Create table headers
-        let header = Row::new(vec![
-            Cell::from("Repository").style(
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Cell::from("PRs").style(
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Cell::from("Last Activity").style(
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Cell::from("Info").style(
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Cell::from("Workflows").style(
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Cell::from("Status").style(
+        // Create table headers, marking the sort keys with their direction
+        let arrow = |key: Option<RepositorySort>| match key {
+            Some(key) if key == app.sort => format!(" {}", sort_arrow(app.sort_descending)),
+            Some(key) => match app.secondary_sort {
+                Some((secondary, descending)) if secondary == key => {
+                    format!(" {}²", sort_arrow(descending))
+                }
+                _ => String::new(),
+            },
+            None => String::new(),
+        };
+        // Affiliations are only known when the personal list includes them;
+        // the age column is opt-in
+        let affiliated =
+            app.config.startup.affiliated && app.repo_view_mode == RepositoryViewMode::Personal;
+        let shown = |label: &str| match label {
+            "table.affiliation" => affiliated,
+            "table.age" => app.config.age.column,
+            "table.code_scanning" => app.config.collector_enabled(Collector::CodeScanning),
+            "table.protection" => app.config.collector_enabled(Collector::BranchProtection),
+            "table.community" => app.config.collector_enabled(Collector::Community),
+            "table.commits" => app.config.collector_enabled(Collector::CommitActivity),
+            "table.custom" => !app.config.checks.is_empty(),
+            _ => true,
+        };
+        // Label, sort key and relative width of each column
+        let columns: Vec<(&str, Option<RepositorySort>, u16)> = [
+            ("table.repository", Some(RepositorySort::Name), 18),
+            ("table.affiliation", None, 8),
+            ("table.pull_requests", Some(RepositorySort::PullRequests), 5),
+            ("table.issues", None, 7),
+            ("table.last_activity", Some(RepositorySort::Activity), 10),
+            ("table.age", None, 5),
+            ("table.commits", None, 8),
+            ("table.info", None, 10),
+            ("table.workflows", None, 14),
+            ("table.deploy", None, 8),
+            ("table.custom", None, 6),
+            ("table.release", Some(RepositorySort::Release), 11),
+            ("table.code_scanning", None, 8),
+            ("table.protection", None, 6),
+            ("table.community", None, 6),
+            ("table.size", Some(RepositorySort::Size), 7),
+            ("table.status", Some(RepositorySort::Status), 10),
+        ]
+        .into_iter()
+        .filter(|&(label, _, _)| shown(label))
+        .collect();
+        let header = Row::new(columns.iter().map(|&(label, key, _)| {
+            Cell::from(format!("{}{}", t(label), arrow(key))).style(
                 Style::default()
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD),
-            ),
-        ]);
+            )
+        }));
 
-        // Create table rows from visible repository data
-        let rows: Vec<Row> = repositories
+        // Create table rows from the pre-formatted cache (visible window only)
+        let now = std::time::SystemTime::now();
+        let today = chrono::Local::now().date_naive();
+        let rows: Vec<Row> = app.visible_repositories[start_index..end_index]
             .iter()
+            .filter_map(|&index| Some((app.repositories.get(index)?, app.row_cache.row(index)?)))
             .enumerate()
-            .skip(start_index)
-            .take(end_index - start_index)
-            .map(|(index, repo)| {
-                // Format pull request count
-                let pr_count = if repo.open_pull_requests.is_empty() {
-                    "0".to_string()
+            .map(|(offset, (repo, row))| {
+                // Apply selection highlighting
+                let row_style = if app.selected_repository == start_index + offset {
+                    Style::default().bg(Color::Blue).fg(Color::White)
                 } else {
-                    repo.open_pull_requests.len().to_string()
+                    Style::default()
                 };
 
-                // Format last commit date - use actual commit data now
-                let last_activity = if let Some(commit_time) = repo.latest_commit_at {
-                    let duration = commit_time.elapsed().unwrap_or(Duration::from_secs(0));
-                    let days_ago = duration.as_secs() / 86400; // seconds in a day
-                    if days_ago == 0 {
-                        "Today".to_string()
-                    } else if days_ago == 1 {
-                        "1 day ago".to_string()
-                    } else if days_ago < 7 {
-                        format!("{} days ago", days_ago)
-                    } else {
-                        format!("{} weeks ago", days_ago / 7)
-                    }
+                let name = if app.guest_mode && repo.private {
+                    Cow::Owned(repo.display_name(true))
                 } else {
-                    "No commits".to_string()
+                    Cow::Borrowed(
+                        app.config
+                            .watched(repo)
+                            .map_or(row.name.as_str(), RepositoryConfig::display_name),
+                    )
                 };
-
-                // Format repository language and stars info
-                let info = match (&repo.language, repo.stars) {
-                    (Some(lang), stars) if stars > 0 => format!("{} ({} ⭐)", lang, stars),
-                    (Some(lang), _) => lang.clone(),
-                    (None, stars) if stars > 0 => format!("{} ⭐", stars),
-                    _ => "N/A".to_string(),
+                // A bus factor risk is flagged next to any other icon
+                let name = if repo.bus_factor.as_ref().is_some_and(BusFactor::is_risk) {
+                    Cow::Owned(format!("👤 {}", name))
+                } else {
+                    name
                 };
-
-                // Format workflow status
-                let workflow_status = format!(
-                    "{} {}",
-                    repo.workflow_health.emoji(),
-                    repo.workflow_health.description()
+                let name = if repo.has_leaked_secrets() {
+                    Cell::from(format!("🔑 {}", name)).style(
+                        Style::default()
+                            .fg(Color::LightRed)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else if repo.fetch_timed_out {
+                    Cell::from(format!("⏱ {}", name))
+                } else if app.config.attention.is_snoozed(repo, today) {
+                    Cell::from(format!("🔕 {}", name))
+                } else if app.commit_anomaly(repo).is_some() {
+                    Cell::from(format!("📉 {}", name))
+                } else if repo.issue_health.is_some() {
+                    Cell::from(format!("📋 {}", name))
+                } else if app.config.age.never_released(repo, now) {
+                    Cell::from(format!("🧪 {}", name))
+                } else {
+                    Cell::from(name)
+                };
+                let mut cells = vec![name];
+                if affiliated {
+                    cells.push(Cell::from(
+                        repo.affiliation
+                            .map(|affiliation| affiliation.label())
+                            .unwrap_or("-"),
+                    ));
+                }
+                cells.extend([
+                    Cell::from(row.pr_count.as_str()).style(Style::default().fg(row.pr_color)),
+                    Cell::from(row.issues.as_str()).style(Style::default().fg(row.issues_color)),
+                    Cell::from(row.last_activity.as_str()),
+                ]);
+                if app.config.age.column {
+                    cells.push(Cell::from(repo.age_label(now)));
+                }
+                if app.config.collector_enabled(Collector::CommitActivity) {
+                    cells.push(Cell::from(row.commits.as_str()));
+                }
+                cells.extend([
+                    Cell::from(row.info.as_str()),
+                    Cell::from(row.workflow.as_str())
+                        .style(Style::default().fg(row.workflow_color)),
+                    Cell::from(row.deploy.as_str()).style(Style::default().fg(row.deploy_color)),
+                ]);
+                if !app.config.checks.is_empty() {
+                    cells.push(Self::custom_checks_cell(app, repo));
+                }
+                cells.push(
+                    Cell::from(row.release.as_str()).style(Style::default().fg(row.release_color)),
                 );
+                if app.config.collector_enabled(Collector::CodeScanning) {
+                    cells.push(
+                        Cell::from(row.code_scanning.as_str())
+                            .style(Style::default().fg(row.code_scanning_color)),
+                    );
+                }
+                if app.config.collector_enabled(Collector::BranchProtection) {
+                    cells.push(Self::protection_cell(app, repo));
+                }
+                if app.config.collector_enabled(Collector::Community) {
+                    cells.push(
+                        Cell::from(row.community.as_str())
+                            .style(Style::default().fg(row.community_color)),
+                    );
+                }
+                cells.extend([
+                    Cell::from(row.size.as_str())
+                        .style(Style::default().fg(app.config.size.level(row.size_kb).color())),
+                    Cell::from(row.status.as_str()).style(Style::default().fg(row.status_color)),
+                ]);
+                Row::new(cells).style(row_style)
+            })
+            .collect();
 
-                // Determine status based on commit activity
-                let status_text = format!("{} {}", repo.status.emoji(), repo.status.description());
+        // Create the table widget, sharing the width out between the columns shown
+        let widths = columns.iter().map(|&(_, _, width)| Constraint::Fill(width));
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(Block::default().borders(Borders::NONE))
+            .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol(">> ");
 
-                // Apply selection highlighting
-                let row_style = if app.selected_repository == index {
+        frame.render_widget(table, area);
+    }
+}
+
+impl UI {
+    /// Split the screen into header, content and footer areas
+    fn detail_layout(area: Rect) -> std::rc::Rc<[Rect]> {
+        Self::detail_layout_with_summary(area, 6)
+    }
+
+    /// Split a detail view whose summary holds `summary_lines` lines
+    fn detail_layout_with_summary(area: Rect, summary_lines: u16) -> std::rc::Rc<[Rect]> {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),                 // Header
+                Constraint::Length(summary_lines + 2), // Summary
+                Constraint::Min(0),                    // List (flexible)
+                Constraint::Length(3),                 // Footer
+            ])
+            .split(area)
+    }
+
+    /// Description followed by the repository's topics, which guest mode
+    /// hides for private repositories like the description
+    fn description_line<'a>(app: &App, repo: &Repository) -> Line<'a> {
+        let mut spans = vec![Span::raw(repo.display_description(app.guest_mode))];
+        if !(app.guest_mode && repo.private) {
+            spans.extend(repo.topics.iter().map(|topic| {
+                Span::styled(format!("  #{}", topic), Style::default().fg(Color::Magenta))
+            }));
+        }
+        Line::from(spans)
+    }
+
+    /// Render the repository details view
+    ///
+    /// Shows a summary of the selected repository and its open pull requests
+    fn render_repo_details(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout_with_summary(frame.area(), 10);
+        Self::render_header(frame, layout[0], app);
+
+        let Some(repo) = app.get_selected_repository() else {
+            Self::render_detail_footer(frame, layout[3], app, &[("Esc", t("controls.back"))]);
+            return;
+        };
+
+        let summary = vec![
+            Line::from(vec![
+                Span::styled(
+                    repo.display_full_name(app.guest_mode),
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("  {}", repo.size_label()),
+                    Style::default().fg(app.config.size.level(repo.size_kb).color()),
+                ),
+                Self::leaked_secrets_span(repo),
+                Self::bus_factor_span(repo),
+                Span::raw(match &repo.latest_release {
+                    Some(release) if release.name == release.tag => t_with(
+                        "repo.release_tag",
+                        &[
+                            ("tag", &release.tag),
+                            ("released", &Self::released(app, release)),
+                        ],
+                    ),
+                    Some(release) => t_with(
+                        "repo.release_named",
+                        &[
+                            ("name", &release.name),
+                            ("tag", &release.tag),
+                            ("released", &Self::released(app, release)),
+                        ],
+                    ),
+                    None => String::new(),
+                }),
+            ]),
+            Self::description_line(app, repo),
+            Line::from(repo.status_summary()),
+            Self::workflow_breakdown_line(app, repo),
+            Self::deploy_line(app, repo),
+            Self::target_branches_line(app, repo),
+            Self::critical_issues_line(repo),
+            Self::community_line(app, repo),
+            Self::commit_convention_line(app, repo),
+            Self::ownership_line(app, repo),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("repo.title")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let now = std::time::SystemTime::now();
+        let rows: Vec<Row> = repo
+            .open_pull_requests
+            .iter()
+            .enumerate()
+            .map(|(index, pr)| {
+                let row_style = if app.selected_pull_request == index {
                     Style::default().bg(Color::Blue).fg(Color::White)
                 } else {
                     Style::default()
                 };
-
+                let title = if pr.draft {
+                    t_with("repo.draft", &[("title", &pr.title)])
+                } else {
+                    pr.title.clone()
+                };
+                let closes = pr
+                    .linked_issues
+                    .iter()
+                    .map(|issue| format!("#{}", issue))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let number = match &pr.checks {
+                    Some(checks) => {
+                        format!("#{} {}", pr.number, app.config.theme.workflow_icon(checks))
+                    }
+                    None => format!("#{}", pr.number),
+                };
                 Row::new(vec![
-                    Cell::from(repo.name.as_str()),
-                    Cell::from(pr_count).style(Style::default().fg(
-                        if repo.open_pull_requests.is_empty() {
-                            Color::Gray
-                        } else {
-                            Color::Green
-                        },
-                    )),
-                    Cell::from(last_activity),
-                    Cell::from(info),
-                    Cell::from(workflow_status)
-                        .style(Style::default().fg(repo.workflow_health.color())),
-                    Cell::from(status_text).style(Style::default().fg(repo.status.color())),
+                    Cell::from(number),
+                    Cell::from(title),
+                    Cell::from(pr.author.clone()),
+                    Self::reviews_cell(pr),
+                    Self::task_progress_cell(pr),
+                    Self::feedback_cell(app, pr, now),
+                    Cell::from(closes),
                 ])
                 .style(row_style)
             })
             .collect();
 
-        // Create the table widget
-        let table = Table::new(
-            rows,
-            [
-                Constraint::Percentage(25), // Repository name
-                Constraint::Percentage(8),  // PR count
-                Constraint::Percentage(15), // Last activity
-                Constraint::Percentage(17), // Info
-                Constraint::Percentage(20), // Workflow status
-                Constraint::Percentage(15), // Status
+        let pr_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(Self::pull_requests_title(app, repo, now));
+
+        if rows.is_empty() {
+            let empty = Paragraph::new(t("repo.no_pull_requests"))
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center)
+                .block(pr_block);
+            frame.render_widget(empty, layout[2]);
+        } else {
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Length(10),
+                    Constraint::Min(20),
+                    Constraint::Length(20),
+                    Constraint::Length(14),
+                    Constraint::Length(12),
+                    Constraint::Length(12),
+                    Constraint::Length(16),
+                ],
+            )
+            .header(Self::header_row(&[
+                t("repo.col_pr"),
+                t("repo.col_title"),
+                t("repo.col_author"),
+                t("repo.col_reviews"),
+                t("repo.col_tasks"),
+                t("repo.col_feedback"),
+                t("repo.col_closes"),
+            ]))
+            .block(pr_block);
+            frame.render_widget(table, layout[2]);
+        }
+
+        Self::render_detail_footer(
+            frame,
+            layout[3],
+            app,
+            &[
+                ("↑↓", t("controls.select_pr")),
+                ("Enter", t("controls.checks")),
+                ("b", t("controls.burndown")),
+                ("i", t("controls.issues")),
+                ("w", t("controls.workflow_timeline")),
+                (".", t("controls.links")),
+                ("Esc", t("controls.back")),
             ],
-        )
-        .header(header)
-        .block(Block::default().borders(Borders::NONE))
-        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
-        .highlight_symbol(">> ");
+        );
+    }
 
-        frame.render_widget(table, area);
+    /// Review votes of a PR: blocked by a change request, or approved
+    ///
+    /// Approvals short of what the base branch requires are shown in yellow
+    /// when GitHub's review decision is known.
+    fn reviews_cell(pr: &PullRequest) -> Cell<'static> {
+        if pr.changes_requested > 0 {
+            Cell::from(t_with(
+                "repo.changes_requested",
+                &[("count", &pr.changes_requested)],
+            ))
+            .style(Style::default().fg(Color::Red))
+        } else if pr.approvals > 0 && pr.review_decision == Some(ReviewDecision::ReviewRequired) {
+            Cell::from(t_with("repo.approved_pending", &[("count", &pr.approvals)]))
+                .style(Style::default().fg(Color::Yellow))
+        } else if pr.approvals > 0 {
+            Cell::from(t_with("repo.approved", &[("count", &pr.approvals)]))
+                .style(Style::default().fg(Color::Green))
+        } else {
+            Cell::from("")
+        }
     }
-}
 
-/// Future: Repository table rendering
-///
-/// This will be used to render the repository list with status indicators
-#[allow(dead_code)]
-fn render_repository_table_old(frame: &mut Frame, area: Rect) {
-    // Future implementation:
-    // - Create table with columns: Repository, Last PR, CI Status, Last Run
-    // - Add status indicators with colors
-    // - Handle selection and scrolling
+    /// Checklist completion of a PR, flagged when it is ready for review
+    /// with tasks left unchecked
+    fn task_progress_cell(pr: &PullRequest) -> Cell<'static> {
+        let Some(tasks) = pr.tasks else {
+            return Cell::from("");
+        };
+        let text = t_with(
+            "repo.tasks",
+            &[("done", &tasks.done), ("total", &tasks.total)],
+        );
+        if pr.has_incomplete_checklist() {
+            Cell::from(format!("⚠️ {}", text)).style(Style::default().fg(Color::Yellow))
+        } else if tasks.is_complete() {
+            Cell::from(text).style(Style::default().fg(Color::Green))
+        } else {
+            Cell::from(text)
+        }
+    }
+
+    /// A moment in the configured time style, e.g. "3 days ago"
+    fn timestamp(app: &App, at: std::time::SystemTime) -> String {
+        app.config.time.format(at, std::time::SystemTime::now())
+    }
 
-    let placeholder = Block::default().title("Repositories").borders(Borders::ALL);
+    /// When a release was published, e.g. "released 34 days ago"
+    fn released(app: &App, release: &Release) -> String {
+        match app.config.time.style {
+            TimeStyle::Relative => release.released_ago(std::time::SystemTime::now()),
+            TimeStyle::Absolute => t_with(
+                "repo.released_at",
+                &[("time", &app.config.time.absolute(release.published_at))],
+            ),
+        }
+    }
 
-    frame.render_widget(placeholder, area);
-}
+    /// Open pull request block title, counting PRs waiting on their author
+    fn pull_requests_title(app: &App, repo: &Repository, now: std::time::SystemTime) -> String {
+        let threshold = app.config.reviews.author_response_threshold();
+        let count = if repo.open_pull_request_count() > repo.open_pull_requests.len() {
+            t_with(
+                "repo.showing",
+                &[
+                    ("shown", &repo.open_pull_requests.len()),
+                    ("total", &repo.open_pull_request_count()),
+                ],
+            )
+        } else {
+            repo.open_pull_requests.len().to_string()
+        };
+        match repo.pull_requests_awaiting_author(threshold, now) {
+            0 => t_with("repo.pull_requests", &[("count", &count)]),
+            waiting => t_with(
+                "repo.pull_requests_awaiting",
+                &[
+                    ("count", &count),
+                    ("waiting", &waiting),
+                    ("hours", &app.config.reviews.author_response_hours),
+                ],
+            ),
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::app::App;
+    /// How long reviewer feedback has waited for the author, flagged past the threshold
+    fn feedback_cell(app: &App, pr: &PullRequest, now: std::time::SystemTime) -> Cell<'static> {
+        let Some(wait) = pr.feedback_wait(now) else {
+            return Cell::from("");
+        };
+        let text = format!("💬 {}", Self::format_wait(wait));
+        if pr.is_awaiting_author(app.config.reviews.author_response_threshold(), now) {
+            Cell::from(text).style(Style::default().fg(Color::Yellow))
+        } else {
+            Cell::from(text)
+        }
+    }
 
-    #[test]
-    fn test_ui_render_does_not_panic() {
-        // This is a basic smoke test to ensure UI rendering doesn't panic
-        // More comprehensive UI tests would require a mock terminal
-        let app = App::new();
+    /// Pull request summary line describing reviewer feedback waiting for the author
+    fn feedback_line(app: &App, pr: &PullRequest) -> Line<'static> {
+        let now = std::time::SystemTime::now();
+        let Some(wait) = pr.feedback_wait(now) else {
+            return Line::from(Span::styled(
+                t("repo.feedback_none"),
+                Style::default().fg(Color::DarkGray),
+            ));
+        };
+        if pr.is_awaiting_author(app.config.reviews.author_response_threshold(), now) {
+            Line::from(Span::styled(
+                t_with(
+                    "repo.feedback_unanswered",
+                    &[("author", &pr.author), ("wait", &Self::format_wait(wait))],
+                ),
+                Style::default().fg(Color::Yellow),
+            ))
+        } else {
+            Line::from(t_with(
+                "repo.feedback_waiting",
+                &[("author", &pr.author), ("wait", &Self::format_wait(wait))],
+            ))
+        }
+    }
 
-        // We can't easily test the actual rendering without a terminal,
-        // but we can test that our UI struct can be created
-        let _ui = UI;
+    /// Pull request summary lines explaining what blocks merging, if anything
+    ///
+    /// Waits for both the merge requirements and the check runs, since the
+    /// explanation combines them.
+    fn merge_lines(app: &App, pr: &PullRequest) -> Vec<Line<'static>> {
+        if app.is_fetching_merge_requirements || app.is_fetching_check_runs {
+            return vec![Line::from(Span::styled(
+                t("repo.merge_checking"),
+                Style::default().fg(Color::DarkGray),
+            ))];
+        }
+        let Some(requirements) = &app.merge_requirements else {
+            return vec![Line::from(Span::styled(
+                t("repo.merge_unavailable"),
+                Style::default().fg(Color::DarkGray),
+            ))];
+        };
 
-        // Verify app state is as expected
-        assert!(!app.should_quit());
-        assert_eq!(app.current_view, AppView::Dashboard);
+        let blockers = requirements.blockers(pr, &app.check_runs);
+        if blockers.is_empty() {
+            let key = if requirements.protection.is_none() {
+                "repo.merge_ready_unprotected"
+            } else {
+                "repo.merge_ready"
+            };
+            return vec![Line::from(Span::styled(
+                t_with(key, &[("branch", &requirements.base_branch)]),
+                Style::default().fg(Color::Green),
+            ))];
+        }
+        let mut lines = vec![Line::from(Span::styled(
+            t_with("repo.merge_blocked", &[("count", &blockers.len())]),
+            Style::default().fg(Color::Red),
+        ))];
+        lines.extend(
+            blockers
+                .iter()
+                .map(|blocker| Line::from(format!("  • {}", blocker.description()))),
+        );
+        lines
+    }
+
+    /// Format a waiting time as "3d 4h" or "5h"
+    fn format_wait(wait: std::time::Duration) -> String {
+        let hours = wait.as_secs() / 3600;
+        if hours >= 24 {
+            format!("{}d {}h", hours / 24, hours % 24)
+        } else {
+            format!("{}h", hours)
+        }
+    }
+
+    /// Pull request summary line with the description's checklist progress
+    fn checklist_line(pr: &PullRequest) -> Line<'static> {
+        match pr.tasks {
+            None => Line::from(Span::styled(
+                t("repo.checklist_none"),
+                Style::default().fg(Color::DarkGray),
+            )),
+            Some(tasks) if pr.has_incomplete_checklist() => Line::from(Span::styled(
+                t_with(
+                    "repo.checklist_incomplete",
+                    &[("done", &tasks.done), ("total", &tasks.total)],
+                ),
+                Style::default().fg(Color::Yellow),
+            )),
+            Some(tasks) => Line::from(t_with(
+                "repo.checklist",
+                &[("done", &tasks.done), ("total", &tasks.total)],
+            )),
+        }
+    }
+
+    /// Pass/fail cell of the default branch protection audit
+    fn protection_cell(app: &App, repo: &Repository) -> Cell<'static> {
+        match &repo.branch_protection {
+            Some(status) if app.config.protection.violations(status).is_empty() => {
+                Cell::from(t("repo.protection_pass")).style(Style::default().fg(Color::Green))
+            }
+            Some(_) => {
+                Cell::from(t("repo.protection_fail")).style(Style::default().fg(Color::LightRed))
+            }
+            None => Cell::from("-").style(Style::default().fg(Color::DarkGray)),
+        }
+    }
+
+    /// Custom health checks passed out of those run, failing when any did
+    fn custom_checks_cell(app: &App, repo: &Repository) -> Cell<'static> {
+        match app.custom_checks.get(&repo.full_name()) {
+            Some(results) if !results.is_empty() => {
+                let passed = results.iter().filter(|result| result.passed).count();
+                if passed == results.len() {
+                    Cell::from(format!("✔ {}/{}", passed, results.len()))
+                        .style(Style::default().fg(Color::Green))
+                } else {
+                    Cell::from(format!("✘ {}/{}", passed, results.len()))
+                        .style(Style::default().fg(Color::LightRed))
+                }
+            }
+            _ => Cell::from("-").style(Style::default().fg(Color::DarkGray)),
+        }
+    }
+
+    /// Warning shown next to the name while secret scanning alerts are open
+    fn leaked_secrets_span(repo: &Repository) -> Span<'static> {
+        match repo.secret_scanning_alerts {
+            Some(alerts) if alerts > 0 => Span::styled(
+                t_with(
+                    if alerts == 1 {
+                        "repo.leaked_secret"
+                    } else {
+                        "repo.leaked_secrets"
+                    },
+                    &[("count", &alerts)],
+                ),
+                Style::default()
+                    .fg(Color::White)
+                    .bg(Color::Red)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            _ => Span::raw(""),
+        }
+    }
+
+    /// Which community health files the repository has
+    fn community_line(app: &App, repo: &Repository) -> Line<'static> {
+        let Some(profile) = &repo.community else {
+            let text = if !app.config.collector_enabled(Collector::Community) {
+                t("repo.community_off")
+            } else if repo.fork {
+                t("repo.community_fork")
+            } else {
+                t("repo.community_loading")
+            };
+            return Line::from(Span::styled(text, Style::default().fg(Color::DarkGray)));
+        };
+
+        let mut spans = vec![Span::styled(
+            t_with("repo.community", &[("label", &profile.label())]),
+            Style::default().fg(profile.color()),
+        )];
+        for (file, present) in profile.files() {
+            spans.push(if present {
+                Span::styled(format!("  ✔ {}", file), Style::default().fg(Color::Green))
+            } else {
+                Span::styled(
+                    format!("  ✘ {}", file),
+                    Style::default().fg(Color::LightRed),
+                )
+            });
+        }
+        Line::from(spans)
+    }
+
+    /// Share of recent commits by the top contributor, highlighted when one
+    /// person made nearly all of them
+    fn bus_factor_span(repo: &Repository) -> Span<'static> {
+        match &repo.bus_factor {
+            Some(bus_factor) if bus_factor.is_risk() => Span::styled(
+                t_with(
+                    "repo.bus_factor_risk",
+                    &[
+                        ("share", &bus_factor.share()),
+                        ("contributor", &bus_factor.top_contributor),
+                    ],
+                ),
+                Style::default().fg(Color::Yellow),
+            ),
+            Some(bus_factor) => Span::styled(
+                t_with("repo.contributors", &[("count", &bus_factor.contributors)]),
+                Style::default().fg(Color::DarkGray),
+            ),
+            None => Span::raw(""),
+        }
+    }
+
+    /// Summary line connecting critical issues to the open PRs closing them
+    fn critical_issues_line(repo: &Repository) -> Line<'static> {
+        let Some(issues) = &repo.critical_issues else {
+            return Line::from(Span::styled(
+                t("repo.critical_loading"),
+                Style::default().fg(Color::DarkGray),
+            ));
+        };
+
+        let unlinked = repo.unlinked_critical_issues();
+        if unlinked.is_empty() {
+            return Line::from(t_with("repo.critical_linked", &[("count", &issues.len())]));
+        }
+
+        let numbers = unlinked
+            .iter()
+            .map(|issue| format!("#{}", issue.number))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Line::from(Span::styled(
+            t_with(
+                "repo.critical_unlinked",
+                &[
+                    ("count", &issues.len()),
+                    ("unlinked", &unlinked.len()),
+                    ("numbers", &numbers),
+                ],
+            ),
+            Style::default().fg(Color::Red),
+        ))
+    }
+
+    /// Summary line with the overall deployment health
+    fn deploy_line(app: &App, repo: &Repository) -> Line<'static> {
+        let theme = &app.config.theme;
+        let health = repo.deploy_status.health();
+        Line::from(t_with(
+            "repo.deploy",
+            &[
+                ("icon", &theme.indicator(health.emoji(), health.severity())),
+                ("health", &health.description()),
+                ("summary", &repo.deploy_status.summary()),
+            ],
+        ))
+    }
+
+    /// Summary line breaking workflow health down by workflow and weight
+    fn workflow_breakdown_line(app: &App, repo: &Repository) -> Line<'static> {
+        let breakdown = WorkflowBreakdown::from_workflow_runs(&repo.recent_workflows, |name| {
+            app.config.ci.workflow_weight(name)
+        });
+        let theme = &app.config.theme;
+        let health = &repo.workflow_health;
+        let mut spans = vec![Span::styled(
+            t_with(
+                "repo.workflows",
+                &[
+                    ("icon", &theme.workflow_health_icon(health)),
+                    ("health", &health.description()),
+                ],
+            ),
+            Style::default().fg(theme.color(health.color(), health.severity())),
+        )];
+        let latest = repo
+            .latest_workflow
+            .as_ref()
+            .map(|run| run.trigger.label())
+            .unwrap_or_default();
+        if !latest.is_empty() {
+            spans.push(Span::raw(t_with(
+                "repo.latest_trigger",
+                &[("trigger", &latest)],
+            )));
+        }
+        for workflow in breakdown {
+            let style = if workflow.failures > 0 {
+                Style::default().fg(Color::Red)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            spans.push(Span::styled(
+                t_with(
+                    "repo.workflow_failures",
+                    &[
+                        ("name", &workflow.name),
+                        ("weight", &workflow.weight),
+                        ("failures", &workflow.failures),
+                        ("runs", &workflow.runs),
+                    ],
+                ),
+                style,
+            ));
+        }
+        Line::from(spans)
+    }
+
+    /// Open pull requests by the branch they merge into, flagging drift away
+    /// from the default branch
+    fn target_branches_line(app: &App, repo: &Repository) -> Line<'static> {
+        if repo.open_pull_requests.is_empty() {
+            return Line::from(Span::styled(
+                t("repo.targets_none"),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        let default_branch = match repo.default_branch.as_str() {
+            "" => t("repo.default_branch"),
+            branch => branch,
+        };
+        let mut spans = vec![Span::raw(t_with(
+            "repo.targets",
+            &[("summary", &repo.target_branches().summary(default_branch))],
+        ))];
+        if app.config.reviews.branching_drift(repo) {
+            spans.push(Span::styled(
+                t("repo.branching_drift"),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        Line::from(spans)
+    }
+
+    /// Owners line of the repository summary: the primary team, then who owns
+    /// the other paths in CODEOWNERS
+    fn ownership_line(app: &App, repo: &Repository) -> Line<'static> {
+        let dimmed = |text: &str| {
+            Line::from(Span::styled(
+                text.to_string(),
+                Style::default().fg(Color::DarkGray),
+            ))
+        };
+        let Some(ownership) = &app.ownership else {
+            return dimmed(t("repo.owners_hint"));
+        };
+        let Some(codeowners) = ownership.get(&repo.full_name()) else {
+            return dimmed(t("repo.owners_none"));
+        };
+
+        let mut spans = vec![Span::raw(t("repo.owners"))];
+        if let Some(team) = codeowners.primary_team() {
+            spans.push(Span::styled(
+                team,
+                Style::default().add_modifier(Modifier::BOLD),
+            ));
+        }
+        let paths = codeowners
+            .rules
+            .iter()
+            .filter(|rule| rule.pattern != "*")
+            .map(|rule| format!("{} → {}", rule.pattern, rule.owners.join(" ")))
+            .collect::<Vec<_>>()
+            .join(" · ");
+        if !paths.is_empty() {
+            spans.push(Span::styled(
+                format!("  {}", paths),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        Line::from(spans)
+    }
+
+    /// Summary line showing how many recent commits follow the convention
+    fn commit_convention_line(app: &App, repo: &Repository) -> Line<'static> {
+        let Some(pattern) = &app.commit_convention else {
+            return Line::from(Span::styled(
+                t("repo.convention_invalid"),
+                Style::default().fg(Color::DarkGray),
+            ));
+        };
+        let Some(compliance) = ConventionCompliance::measure(&repo.recent_commit_subjects, pattern)
+        else {
+            return Line::from(Span::styled(
+                t("repo.convention_none"),
+                Style::default().fg(Color::DarkGray),
+            ));
+        };
+
+        let color = match compliance.percentage() {
+            90..=100 => Color::Green,
+            60..=89 => Color::Yellow,
+            _ => Color::Red,
+        };
+        Line::from(Span::styled(
+            t_with(
+                "repo.convention",
+                &[
+                    ("percentage", &compliance.percentage()),
+                    ("matching", &compliance.matching),
+                    ("sampled", &compliance.sampled),
+                ],
+            ),
+            Style::default().fg(color),
+        ))
+    }
+
+    /// Render the pull request details view
+    ///
+    /// Shows the check runs reported for the PR head commit
+    fn render_pull_request_details(frame: &mut Frame, app: &App) {
+        let Some(pr) = app.get_selected_pull_request() else {
+            let layout = Self::detail_layout(frame.area());
+            Self::render_header(frame, layout[0], app);
+            Self::render_detail_footer(frame, layout[3], app, &[("Esc", t("controls.back"))]);
+            return;
+        };
+
+        let mut summary = vec![
+            Line::from(Span::styled(
+                format!("{} #{} {}", pr.state.emoji(), pr.number, pr.title),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(t_with(
+                "pull_request.author",
+                &[
+                    ("author", &pr.author),
+                    ("opened", &Self::timestamp(app, pr.created_at)),
+                    ("updated", &Self::timestamp(app, pr.updated_at)),
+                ],
+            )),
+            Self::checklist_line(pr),
+            Self::feedback_line(app, pr),
+            Line::from(pr.html_url.clone()),
+        ];
+        summary.extend(Self::merge_lines(app, pr));
+        let layout = Self::detail_layout_with_summary(frame.area(), summary.len() as u16);
+        Self::render_header(frame, layout[0], app);
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("pull_request.title")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let checks_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(t_with(
+                "pull_request.checks",
+                &[("count", &app.check_runs.len())],
+            ));
+
+        if app.is_fetching_check_runs {
+            let loading = Paragraph::new(t("pull_request.loading_checks"))
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center)
+                .block(checks_block);
+            frame.render_widget(loading, layout[2]);
+        } else if app.check_runs.is_empty() {
+            let empty = Paragraph::new(t("pull_request.no_checks"))
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center)
+                .block(checks_block);
+            frame.render_widget(empty, layout[2]);
+        } else {
+            let rows: Vec<Row> = app
+                .check_runs
+                .iter()
+                .enumerate()
+                .map(|(index, check)| {
+                    let row_style = if app.selected_check_run == index {
+                        Style::default().bg(Color::Blue).fg(Color::White)
+                    } else {
+                        Style::default()
+                    };
+                    Row::new(vec![
+                        Cell::from(check.name.clone()),
+                        Cell::from(format!(
+                            "{} {}",
+                            app.config.theme.workflow_icon(&check.status),
+                            check.status.description()
+                        )),
+                    ])
+                    .style(row_style)
+                })
+                .collect();
+
+            let table = Table::new(rows, [Constraint::Min(20), Constraint::Length(16)])
+                .header(Self::header_row(&[
+                    t("pull_request.col_check"),
+                    t("pull_request.col_status"),
+                ]))
+                .block(checks_block);
+            frame.render_widget(table, layout[2]);
+        }
+
+        Self::render_detail_footer(
+            frame,
+            layout[3],
+            app,
+            &[
+                ("↑↓", t("controls.select_check")),
+                ("e", t("controls.rerun_check")),
+                ("f", t("controls.files")),
+                ("a", t("controls.review")),
+                ("Esc", t("controls.back")),
+            ],
+        );
+    }
+
+    /// Render the files changed by the selected pull request
+    ///
+    /// Each file gets a diffstat bar scaled to the most churned file, so the
+    /// scope of a change is visible without opening the full diff.
+    fn render_pull_request_files(frame: &mut Frame, app: &App) {
+        const BAR_WIDTH: usize = 30;
+
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let controls = [
+            ("↑↓", t("controls.scroll")),
+            (
+                "s",
+                if app.sort_files_by_churn {
+                    t("controls.sort_path")
+                } else {
+                    t("controls.sort_churn")
+                },
+            ),
+            ("Esc", t("controls.back")),
+        ];
+        let Some(pr) = app.get_selected_pull_request() else {
+            Self::render_detail_footer(frame, layout[3], app, &controls);
+            return;
+        };
+
+        let files = app.sorted_pull_request_files();
+        let additions: u32 = files.iter().map(|file| file.additions).sum();
+        let deletions: u32 = files.iter().map(|file| file.deletions).sum();
+        let summary = vec![
+            Line::from(Span::styled(
+                format!("#{} {}", pr.number, pr.title),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(vec![
+                Span::raw(t_with(
+                    "pull_request.files_changed",
+                    &[("count", &files.len())],
+                )),
+                Span::styled(format!("+{}", additions), Style::default().fg(Color::Green)),
+                Span::raw(" "),
+                Span::styled(format!("-{}", deletions), Style::default().fg(Color::Red)),
+            ]),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("pull_request.title")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let files_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(if app.sort_files_by_churn {
+                t("pull_request.files_by_churn")
+            } else {
+                t("pull_request.files")
+            });
+
+        if app.is_fetching_pull_request_files {
+            let loading = Paragraph::new(t("pull_request.loading_files"))
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center)
+                .block(files_block);
+            frame.render_widget(loading, layout[2]);
+        } else if files.is_empty() {
+            let empty = Paragraph::new(t("pull_request.no_files"))
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center)
+                .block(files_block);
+            frame.render_widget(empty, layout[2]);
+        } else {
+            let max_churn = files.iter().map(|file| file.churn()).max().unwrap_or(0);
+            let rows: Vec<Row> = files
+                .iter()
+                .skip(app.pull_request_files_scroll)
+                .map(|file| {
+                    let (added, deleted) = file.bar_widths(max_churn, BAR_WIDTH);
+                    Row::new(vec![
+                        Cell::from(file.path.clone()),
+                        Cell::from(file.status.clone()),
+                        Cell::from(format!("+{}", file.additions))
+                            .style(Style::default().fg(Color::Green)),
+                        Cell::from(format!("-{}", file.deletions))
+                            .style(Style::default().fg(Color::Red)),
+                        Cell::from(Line::from(vec![
+                            Span::styled("■".repeat(added), Style::default().fg(Color::Green)),
+                            Span::styled("■".repeat(deleted), Style::default().fg(Color::Red)),
+                        ])),
+                    ])
+                })
+                .collect();
+
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Min(24),
+                    Constraint::Length(10),
+                    Constraint::Length(7),
+                    Constraint::Length(7),
+                    Constraint::Length(BAR_WIDTH as u16),
+                ],
+            )
+            .header(Self::header_row(&[
+                t("pull_request.col_file"),
+                t("pull_request.col_status"),
+                t("pull_request.col_added"),
+                t("pull_request.col_deleted"),
+                t("pull_request.col_churn"),
+            ]))
+            .block(files_block);
+            frame.render_widget(table, layout[2]);
+        }
+
+        Self::render_detail_footer(frame, layout[3], app, &controls);
+    }
+
+    /// Render the organization membership health panel
+    ///
+    /// Shows pending invitations, members without 2FA and outside
+    /// collaborators with write access
+    fn render_org_membership(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let controls = [
+            ("↑↓", t("controls.scroll")),
+            ("a", t("controls.audit_log")),
+            ("Esc", t("controls.back")),
+        ];
+        let content_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(t("membership.title"));
+
+        let Some(health) = &app.org_membership else {
+            let text = if app.is_fetching_org_membership {
+                t("membership.loading")
+            } else {
+                t("membership.empty")
+            };
+            let loading = Paragraph::new(text)
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center)
+                .block(content_block);
+            frame.render_widget(loading, layout[1].union(layout[2]));
+            Self::render_detail_footer(frame, layout[3], app, &controls);
+            return;
+        };
+
+        let count = |signal: Option<usize>| match signal {
+            Some(count) => count.to_string(),
+            None => t("membership.not_available").to_string(),
+        };
+        let issues = health.issue_count();
+        let summary = vec![
+            Line::from(vec![
+                Span::styled(
+                    t_with("membership.organization", &[("org", &health.org)]),
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" · "),
+                Span::styled(
+                    t_with("membership.issues", &[("count", &issues)]),
+                    Style::default().fg(if issues == 0 {
+                        Color::Green
+                    } else {
+                        Color::Yellow
+                    }),
+                ),
+            ]),
+            Line::from(t_with(
+                "membership.counts",
+                &[
+                    (
+                        "invitations",
+                        &count(health.pending_invitations.as_ref().map(Vec::len)),
+                    ),
+                    (
+                        "members",
+                        &count(health.members_without_2fa.as_ref().map(Vec::len)),
+                    ),
+                    (
+                        "collaborators",
+                        &count(health.outside_collaborators.as_ref().map(Vec::len)),
+                    ),
+                ],
+            )),
+            Line::from(if app.is_fetching_org_membership {
+                t("common.refreshing")
+            } else {
+                t("membership.owner_access")
+            }),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("common.summary")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let section = |title: &str| {
+            Line::from(Span::styled(
+                title.to_string(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        };
+        let not_visible = || {
+            Line::from(Span::styled(
+                t("membership.not_visible"),
+                Style::default().fg(Color::DarkGray),
+            ))
+        };
+
+        let mut lines = vec![section(t("membership.invitations"))];
+        match &health.pending_invitations {
+            Some(invitations) if invitations.is_empty() => lines.push(Line::from(t("common.none"))),
+            Some(invitations) => {
+                for invitation in invitations {
+                    let days = invitation
+                        .created_at
+                        .elapsed()
+                        .map(|d| d.as_secs() / 86400)
+                        .unwrap_or(0);
+                    lines.push(Line::from(t_with(
+                        "membership.invitation",
+                        &[
+                            ("invitee", &invitation.invitee),
+                            ("role", &invitation.role),
+                            ("inviter", &invitation.inviter),
+                            ("days", &days),
+                        ],
+                    )));
+                }
+            }
+            None => lines.push(not_visible()),
+        }
+
+        lines.push(Line::from(""));
+        lines.push(section(t("membership.without_2fa")));
+        match &health.members_without_2fa {
+            Some(members) if members.is_empty() => lines.push(Line::from(t("common.none"))),
+            Some(members) => {
+                for member in members {
+                    lines.push(Line::from(Span::styled(
+                        format!("  ⚠️ {}", member),
+                        Style::default().fg(Color::Red),
+                    )));
+                }
+            }
+            None => lines.push(not_visible()),
+        }
+
+        lines.push(Line::from(""));
+        lines.push(section(t("membership.outside_collaborators")));
+        match &health.outside_collaborators {
+            Some(collaborators) if collaborators.is_empty() => {
+                lines.push(Line::from(t("common.none")))
+            }
+            Some(collaborators) => {
+                for collaborator in collaborators {
+                    lines.push(Line::from(format!(
+                        "  {}: {}",
+                        collaborator.login,
+                        collaborator.repositories.join(", ")
+                    )));
+                }
+            }
+            None => lines.push(not_visible()),
+        }
+
+        let details = Paragraph::new(lines)
+            .scroll((app.membership_scroll, 0))
+            .block(content_block);
+        frame.render_widget(details, layout[2]);
+
+        Self::render_detail_footer(frame, layout[3], app, &controls);
+    }
+
+    /// Render the CI analytics panel
+    ///
+    /// Lists per-repository workflow metrics, longest runner queue first, and
+    /// flags repositories whose runs regularly wait for a runner. Below, failed
+    /// jobs are grouped by the error their logs end with.
+    fn render_ci_analytics(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let threshold = app.config.ci.slow_queue_threshold();
+        let mut stats: Vec<_> = app
+            .repositories
+            .iter()
+            .filter(|repo| !repo.recent_workflows.is_empty())
+            .map(|repo| {
+                let queue = QueueStats::from_workflow_runs(&repo.recent_workflows, threshold);
+                (repo, queue)
+            })
+            .collect();
+        stats.sort_by(|(_, a), (_, b)| {
+            let average = |q: &Option<QueueStats>| q.as_ref().map(|q| q.average);
+            average(b).cmp(&average(a))
+        });
+
+        let slow_count = stats
+            .iter()
+            .filter(|(_, queue)| queue.as_ref().is_some_and(QueueStats::is_regularly_slow))
+            .count();
+        let active_runs: usize = stats
+            .iter()
+            .map(|(repo, _)| repo.active_workflow_runs())
+            .sum();
+        let summary = vec![
+            Line::from(Span::styled(
+                t("ci.title"),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(t_with(
+                "ci.counts",
+                &[("repositories", &stats.len()), ("active", &active_runs)],
+            )),
+            Line::from(Span::styled(
+                t_with(
+                    "ci.slow",
+                    &[
+                        ("count", &slow_count),
+                        ("minutes", &app.config.ci.slow_queue_minutes),
+                    ],
+                ),
+                Style::default().fg(if slow_count > 0 {
+                    Color::Yellow
+                } else {
+                    Color::Green
+                }),
+            )),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("common.summary")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let rows: Vec<Row> = stats
+            .iter()
+            .skip(app.ci_scroll)
+            .map(|(repo, queue)| {
+                let passed = repo
+                    .recent_workflows
+                    .iter()
+                    .filter(|run| run.status == WorkflowStatus::Success)
+                    .count();
+                let slow = queue.as_ref().is_some_and(QueueStats::is_regularly_slow);
+                Row::new(vec![
+                    Cell::from(repo.display_name(app.guest_mode)),
+                    Cell::from(repo.recent_workflows.len().to_string()),
+                    Cell::from(format!("{}%", passed * 100 / repo.recent_workflows.len())),
+                    Cell::from(Self::format_duration(repo.average_workflow_duration())),
+                    Cell::from(Self::format_duration(queue.as_ref().map(|q| q.average))),
+                    Cell::from(Self::format_duration(queue.as_ref().map(|q| q.longest))),
+                    Cell::from(repo.active_workflow_runs().to_string()),
+                    Cell::from(if slow { t("ci.slow_queue") } else { "" })
+                        .style(Style::default().fg(Color::Yellow)),
+                ])
+            })
+            .collect();
+
+        let pattern_lines = Self::failure_pattern_lines(app);
+        let content = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Length(pattern_lines.len().min(10) as u16 + 2),
+            ])
+            .split(layout[2]);
+        let patterns = Paragraph::new(pattern_lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("ci.failure_patterns")),
+        );
+        frame.render_widget(patterns, content[1]);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(t("ci.workflows"));
+
+        if rows.is_empty() {
+            let empty = Paragraph::new(t("ci.empty"))
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(empty, content[0]);
+        } else {
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Min(20),
+                    Constraint::Length(6),
+                    Constraint::Length(6),
+                    Constraint::Length(10),
+                    Constraint::Length(10),
+                    Constraint::Length(10),
+                    Constraint::Length(8),
+                    Constraint::Length(14),
+                ],
+            )
+            .header(Self::header_row(&[
+                t("table.repository"),
+                t("ci.col_runs"),
+                t("ci.col_pass"),
+                t("ci.col_average_time"),
+                t("ci.col_average_queue"),
+                t("ci.col_max_queue"),
+                t("ci.col_active"),
+                "",
+            ]))
+            .block(block);
+            frame.render_widget(table, content[0]);
+        }
+
+        Self::render_detail_footer(
+            frame,
+            layout[3],
+            app,
+            &[("↑↓", t("controls.scroll")), ("Esc", t("controls.back"))],
+        );
+    }
+
+    /// Lines of the CI analytics failure patterns, largest cluster first
+    ///
+    /// Each cluster shows its summary and the repositories it spans.
+    fn failure_pattern_lines(app: &App) -> Vec<Line<'static>> {
+        let hint = |text: &str| {
+            vec![Line::from(Span::styled(
+                text.to_string(),
+                Style::default().fg(Color::DarkGray),
+            ))]
+        };
+        if app.is_clustering_failures {
+            return hint(t("ci.downloading_logs"));
+        }
+        let Some(clusters) = &app.failure_clusters else {
+            return hint(t("ci.no_failure_data"));
+        };
+        if clusters.is_empty() {
+            return vec![Line::from(Span::styled(
+                t("ci.no_failures"),
+                Style::default().fg(Color::Green),
+            ))];
+        }
+
+        let mut lines = Vec::new();
+        for cluster in clusters {
+            let color = if cluster.repository_count() > 1 {
+                Color::Red
+            } else {
+                Color::Yellow
+            };
+            lines.push(Line::from(Span::styled(
+                cluster.summary(),
+                Style::default().fg(color),
+            )));
+            let mut repositories: Vec<String> = cluster
+                .failures
+                .iter()
+                .map(|failure| app.display_repository(&failure.repository))
+                .collect();
+            repositories.sort_unstable();
+            repositories.dedup();
+            lines.push(Line::from(Span::styled(
+                format!("  {}", repositories.join(", ")),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        lines
+    }
+
+    /// Render the open issues of the selected repository for triage
+    fn render_issues(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let controls = [
+            ("↑↓", t("controls.select_issue")),
+            ("a", t("controls.assign")),
+            ("l", t("controls.labels")),
+            ("c", t("controls.comment")),
+            ("Esc", t("controls.back")),
+        ];
+        let issues = app.issues_for_selected_repository();
+
+        let mut summary = vec![Line::from(Span::styled(
+            app.get_selected_repository()
+                .map(|repo| repo.display_full_name(app.guest_mode))
+                .unwrap_or_default(),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))];
+        match app.get_selected_issue() {
+            Some(issue) => {
+                let or_none = |values: &[String]| {
+                    if values.is_empty() {
+                        t("issues.none").to_string()
+                    } else {
+                        values.join(", ")
+                    }
+                };
+                summary.push(Line::from(format!("#{} {}", issue.number, issue.title)));
+                summary.push(Line::from(t_with(
+                    "issues.details",
+                    &[
+                        ("assignees", &or_none(&issue.assignees)),
+                        ("labels", &or_none(&issue.labels)),
+                        ("comments", &issue.comments),
+                    ],
+                )));
+            }
+            None => summary.push(Line::from("")),
+        }
+        summary.push(Line::from(if app.is_fetching_open_issues {
+            t("common.refreshing")
+        } else {
+            ""
+        }));
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("issues.title")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let issues_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(t_with("issues.open", &[("count", &issues.len())]));
+
+        if issues.is_empty() {
+            let text = if app.is_fetching_open_issues {
+                t("issues.loading")
+            } else {
+                t("issues.empty")
+            };
+            let empty = Paragraph::new(text)
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center)
+                .block(issues_block);
+            frame.render_widget(empty, layout[2]);
+        } else {
+            let rows: Vec<Row> = issues
+                .iter()
+                .enumerate()
+                .map(|(index, issue)| {
+                    let row_style = if app.selected_issue == index {
+                        Style::default().bg(Color::Blue).fg(Color::White)
+                    } else {
+                        Style::default()
+                    };
+                    Row::new(vec![
+                        Cell::from(format!("#{}", issue.number)),
+                        Cell::from(issue.title.clone()),
+                        Cell::from(issue.assignees.join(", ")),
+                        Cell::from(issue.labels.join(", ")),
+                    ])
+                    .style(row_style)
+                })
+                .collect();
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Length(8),
+                    Constraint::Min(20),
+                    Constraint::Length(20),
+                    Constraint::Length(28),
+                ],
+            )
+            .header(Self::header_row(&[
+                t("issues.col_issue"),
+                t("issues.col_title"),
+                t("issues.col_assignees"),
+                t("issues.col_labels"),
+            ]))
+            .block(issues_block);
+            frame.render_widget(table, layout[2]);
+        }
+
+        Self::render_detail_footer(frame, layout[3], app, &controls);
+    }
+
+    /// Render open issues across the loaded repositories, oldest first
+    fn render_issue_search(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let controls = [
+            ("↑↓", t("controls.select_issue")),
+            ("f", t("controls.filter")),
+            ("a", t("controls.assign")),
+            ("l", t("controls.labels")),
+            ("c", t("controls.comment")),
+            ("o", t("controls.open")),
+            ("r", t("controls.search_again")),
+            ("Esc", t("controls.back")),
+        ];
+        let now = std::time::SystemTime::now();
+        let (issues, total) = match &app.searched_issues {
+            Some((issues, total)) => (issues.as_slice(), *total),
+            None => (&[][..], 0),
+        };
+
+        let mut summary = vec![Line::from(vec![
+            Span::styled(
+                t_with(
+                    "issues.search_heading",
+                    &[("count", &app.repositories.len())],
+                ),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(" · {}", app.issue_search_filter.summary()),
+                Style::default().fg(Color::Gray),
+            ),
+        ])];
+        match app.get_selected_searched_issue() {
+            Some(searched) => {
+                let issue = &searched.issue;
+                summary.push(Line::from(format!(
+                    "{}#{} {}",
+                    app.display_repository(&searched.repository),
+                    issue.number,
+                    issue.title
+                )));
+                summary.push(Line::from(t_with(
+                    "issues.opened",
+                    &[
+                        ("days", &searched.age_days(now)),
+                        ("comments", &issue.comments),
+                    ],
+                )));
+            }
+            None => {
+                summary.push(Line::from(""));
+                summary.push(Line::from(""));
+            }
+        }
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("issues.search_title")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let title = if app.is_searching_issues {
+            t("issues.searching_title").to_string()
+        } else if total > issues.len() {
+            t_with(
+                "issues.oldest",
+                &[("shown", &issues.len()), ("total", &total)],
+            )
+        } else {
+            t_with("issues.open", &[("count", &issues.len())])
+        };
+        let issues_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(title);
+
+        if issues.is_empty() {
+            let text = if app.is_searching_issues {
+                t("issues.searching")
+            } else {
+                t("issues.no_matches")
+            };
+            let empty = Paragraph::new(text)
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center)
+                .block(issues_block);
+            frame.render_widget(empty, layout[2]);
+        } else {
+            let rows: Vec<Row> = issues
+                .iter()
+                .enumerate()
+                .map(|(index, searched)| {
+                    let issue = &searched.issue;
+                    let row_style = if app.selected_searched_issue == index {
+                        Style::default().bg(Color::Blue).fg(Color::White)
+                    } else {
+                        Style::default()
+                    };
+                    Row::new(vec![
+                        Cell::from(app.display_repository(&searched.repository)),
+                        Cell::from(format!("#{}", issue.number)),
+                        Cell::from(issue.title.clone()),
+                        Cell::from(format!("{}d", searched.age_days(now))),
+                        Cell::from(issue.assignees.join(", ")),
+                        Cell::from(issue.labels.join(", ")),
+                    ])
+                    .style(row_style)
+                })
+                .collect();
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Length(24),
+                    Constraint::Length(7),
+                    Constraint::Min(20),
+                    Constraint::Length(6),
+                    Constraint::Length(16),
+                    Constraint::Length(24),
+                ],
+            )
+            .header(Self::header_row(&[
+                t("table.repository"),
+                t("issues.col_issue"),
+                t("issues.col_title"),
+                t("issues.col_age"),
+                t("issues.col_assignees"),
+                t("issues.col_labels"),
+            ]))
+            .block(issues_block);
+            frame.render_widget(table, layout[2]);
+        }
+
+        Self::render_detail_footer(frame, layout[3], app, &controls);
+    }
+
+    /// Render the archived reports and digests, newest first
+    fn render_reports(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let controls = [
+            ("↑↓", t("controls.select_report")),
+            ("Enter", t("controls.open")),
+            ("r", t("controls.reload")),
+            ("Esc", t("controls.back")),
+        ];
+        let settings = &app.config.reports;
+        let directory = settings
+            .directory()
+            .map_or(t("reports.no_directory").to_string(), |dir| {
+                dir.display().to_string()
+            });
+        let retention = if settings.retention_days > 0 {
+            t_with(
+                "reports.retention_days",
+                &[
+                    ("count", &settings.retention),
+                    ("days", &settings.retention_days),
+                ],
+            )
+        } else {
+            t_with("reports.retention", &[("count", &settings.retention)])
+        };
+        let summary = vec![
+            Line::from(Span::styled(
+                t_with(
+                    "reports.archived",
+                    &[
+                        ("count", &app.archived_reports.len()),
+                        ("directory", &directory),
+                    ],
+                ),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(retention),
+            Line::from(
+                app.get_selected_archived_report()
+                    .map(|archived| archived.path.display().to_string())
+                    .unwrap_or_default(),
+            ),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("reports.title")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let reports_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(t("reports.archive"));
+        if app.archived_reports.is_empty() {
+            let empty = Paragraph::new(t("reports.empty"))
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center)
+                .block(reports_block);
+            frame.render_widget(empty, layout[2]);
+        } else {
+            let rows: Vec<Row> = app
+                .archived_reports
+                .iter()
+                .map(|archived| {
+                    let name = archived
+                        .path
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                        .unwrap_or_default();
+                    Row::new(vec![
+                        Cell::from(app.config.time.absolute(archived.generated_at.into())),
+                        Cell::from(archived.kind.label()),
+                        Cell::from(archived.format.label()),
+                        Cell::from(name),
+                    ])
+                })
+                .collect();
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Length(20),
+                    Constraint::Length(8),
+                    Constraint::Length(10),
+                    Constraint::Min(20),
+                ],
+            )
+            .header(Self::header_row(&[
+                t("reports.col_generated"),
+                t("reports.col_kind"),
+                t("reports.col_format"),
+                t("reports.col_file"),
+            ]))
+            .row_highlight_style(Style::default().bg(Color::Blue).fg(Color::White))
+            .block(reports_block);
+            let mut state = TableState::default().with_selected(Some(app.selected_archived_report));
+            frame.render_stateful_widget(table, layout[2], &mut state);
+        }
+
+        Self::render_detail_footer(frame, layout[3], app, &controls);
+    }
+
+    /// Render the admin view of the organization's health-relevant audit log
+    /// events, newest first
+    fn render_audit_log(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let controls = [
+            ("↑↓", t("controls.select_event")),
+            ("Enter", t("controls.open_repository")),
+            ("r", t("controls.reload")),
+            ("Esc", t("controls.back")),
+        ];
+        let events_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(t_with("audit.title", &[("days", &AUDIT_LOOKBACK_DAYS)]));
+
+        let Some(audit_log) = &app.org_audit_log else {
+            let text = if app.is_fetching_audit_log {
+                t("audit.loading")
+            } else {
+                t("audit.empty")
+            };
+            let loading = Paragraph::new(text)
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center)
+                .block(events_block);
+            frame.render_widget(loading, layout[1].union(layout[2]));
+            Self::render_detail_footer(frame, layout[3], app, &controls);
+            return;
+        };
+
+        let count_style = |count: usize| {
+            if count > 0 {
+                Style::default().fg(Color::Red)
+            } else {
+                Style::default().fg(Color::Green)
+            }
+        };
+        let mut counts = vec![Span::raw(t("audit.events"))];
+        for kind in [
+            AuditEventKind::ProtectionRemoved,
+            AuditEventKind::VisibilityChanged,
+            AuditEventKind::AdminGranted,
+        ] {
+            let count = audit_log.count(kind);
+            counts.push(Span::styled(
+                t_with(
+                    "audit.count",
+                    &[("count", &count), ("kind", &kind.label().to_lowercase())],
+                ),
+                count_style(count),
+            ));
+            counts.push(Span::raw("  "));
+        }
+        let summary = vec![
+            Line::from(Span::styled(
+                t_with("audit.heading", &[("org", &audit_log.org)]),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(counts),
+            Line::from(
+                app.get_selected_audit_event()
+                    .map(|event| t_with("audit.action", &[("action", &event.action)]))
+                    .unwrap_or_default(),
+            ),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("audit.admin")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        match &audit_log.events {
+            None => {
+                let empty = Paragraph::new(t("audit.needs_owner"))
+                    .style(Style::default().fg(Color::DarkGray))
+                    .alignment(Alignment::Center)
+                    .block(events_block);
+                frame.render_widget(empty, layout[2]);
+            }
+            Some(events) if events.is_empty() => {
+                let empty = Paragraph::new(t("audit.no_changes"))
+                    .style(Style::default().fg(Color::Green))
+                    .alignment(Alignment::Center)
+                    .block(events_block);
+                frame.render_widget(empty, layout[2]);
+            }
+            Some(events) => {
+                let rows: Vec<Row> = events
+                    .iter()
+                    .map(|event| {
+                        let color = match event.kind {
+                            AuditEventKind::VisibilityChanged => Color::Yellow,
+                            _ => Color::Red,
+                        };
+                        Row::new(vec![
+                            Cell::from(app.config.time.absolute(event.created_at.into())),
+                            Cell::from(event.kind.label()).style(Style::default().fg(color)),
+                            Cell::from(event.actor.clone()),
+                            Cell::from(event.target.clone()),
+                        ])
+                    })
+                    .collect();
+                let table = Table::new(
+                    rows,
+                    [
+                        Constraint::Length(20),
+                        Constraint::Length(19),
+                        Constraint::Length(16),
+                        Constraint::Min(20),
+                    ],
+                )
+                .header(Self::header_row(&[
+                    t("audit.col_when"),
+                    t("audit.col_event"),
+                    t("audit.col_actor"),
+                    t("audit.col_target"),
+                ]))
+                .row_highlight_style(Style::default().bg(Color::Blue).fg(Color::White))
+                .block(events_block);
+                let mut state = TableState::default().with_selected(Some(app.selected_audit_event));
+                frame.render_stateful_widget(table, layout[2], &mut state);
+            }
+        }
+
+        Self::render_detail_footer(frame, layout[3], app, &controls);
+    }
+
+    /// Render the links opened this session as OSC 8 hyperlinks
+    ///
+    /// Over SSH the links can't open a browser, so they're listed here to be
+    /// clicked in the local terminal or copied.
+    fn render_links(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let controls = [
+            ("↑↓", t("controls.select_link")),
+            ("Enter", t("controls.open")),
+            ("Esc", t("controls.back")),
+        ];
+        let settings = &app.config.links;
+        let mode = if settings.opens_in_pane(crate::browser::is_remote_session()) {
+            t("links.in_pane")
+        } else {
+            t("links.in_browser")
+        };
+        let file = settings
+            .file
+            .as_ref()
+            .map_or(t("links.file_hint").to_string(), |path| {
+                t_with("links.file", &[("path", &path.display())])
+            });
+        let summary = vec![
+            Line::from(Span::styled(
+                mode,
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(t("links.click_hint")),
+            Line::from(file),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("links.title")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let links_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(t_with(
+                "links.opened",
+                &[("count", &app.opened_links.len())],
+            ));
+        if app.opened_links.is_empty() {
+            let empty = Paragraph::new(t("links.empty"))
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center)
+                .block(links_block);
+            frame.render_widget(empty, layout[2]);
+        } else {
+            let inner = links_block.inner(layout[2]);
+            frame.render_widget(links_block, layout[2]);
+            // Scroll just far enough to keep the selection in view
+            let offset = app
+                .selected_link
+                .saturating_sub(inner.height.saturating_sub(1) as usize);
+            for (row, (index, url)) in app
+                .opened_links
+                .iter()
+                .enumerate()
+                .skip(offset)
+                .take(inner.height as usize)
+                .enumerate()
+            {
+                let style = if index == app.selected_link {
+                    Style::default().bg(Color::Blue).fg(Color::White)
+                } else {
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::UNDERLINED)
+                };
+                let area = Rect::new(inner.x, inner.y + row as u16, inner.width, 1);
+                Self::render_hyperlink(frame.buffer_mut(), area, url, style);
+            }
+        }
+
+        Self::render_detail_footer(frame, layout[3], app, &controls);
+    }
+
+    /// Render the startup token check: why the token was rejected, or which
+    /// scopes it lacks and what is hidden without them
+    fn render_token_diagnostics(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let (headline, color) = match &app.token_status {
+            TokenStatus::Rejected(error) => {
+                (t_with("token.rejected", &[("error", error)]), Color::Red)
+            }
+            TokenStatus::Checked(Some(check)) => (
+                t_with("token.authenticated", &[("login", &check.login)]),
+                Color::Green,
+            ),
+            _ => (t("token.checking").to_string(), Color::Yellow),
+        };
+        let summary = vec![
+            Line::from(Span::styled(
+                headline,
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(match &app.token_status {
+                TokenStatus::Checked(Some(check)) => match &check.scopes {
+                    Some(scopes) if scopes.is_empty() => t("token.scopes_none").to_string(),
+                    Some(scopes) => t_with("token.scopes", &[("scopes", &scopes.join(", "))]),
+                    None => t("token.fine_grained").to_string(),
+                },
+                _ => String::new(),
+            }),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("token.title")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let mut lines: Vec<Line> = match &app.token_status {
+            TokenStatus::Rejected(_) => vec![
+                Line::from(t("token.not_accepted")),
+                Line::from(t("token.nothing_fetched")),
+            ],
+            TokenStatus::Checked(Some(check)) => check
+                .warnings()
+                .into_iter()
+                .map(|warning| {
+                    Line::from(Span::styled(
+                        t_with("token.warning", &[("warning", &warning)]),
+                        Style::default().fg(Color::Yellow),
+                    ))
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        lines.push(Line::from(""));
+        lines.push(Line::from(t("token.create")));
+        let rejected = matches!(app.token_status, TokenStatus::Rejected(_));
+        lines.push(Line::from(if rejected {
+            t("token.save_and_check")
+        } else {
+            t("token.save_and_restart")
+        }));
+        let problems = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("token.problems")),
+        );
+        frame.render_widget(problems, layout[2]);
+
+        let controls: &[(&str, &str)] = if rejected {
+            &[("r", t("controls.check_again")), ("q", t("footer.quit"))]
+        } else {
+            &[
+                ("Enter", t("controls.continue_dashboard")),
+                ("q", t("footer.quit")),
+            ]
+        };
+        Self::render_detail_footer(frame, layout[3], app, controls);
+    }
+
+    /// Render the problems found in the config file on launch
+    fn render_config_problems(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout_with_summary(frame.area(), 2);
+        Self::render_header(frame, layout[0], app);
+
+        let path = AppConfig::path().map_or(t("config.file").to_string(), |path| {
+            path.display().to_string()
+        });
+        let summary = vec![
+            Line::from(Span::styled(
+                t_with(
+                    "config.problems",
+                    &[("count", &app.config_problems.len()), ("path", &path)],
+                ),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(t("config.defaults")),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("config.title")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let mut lines: Vec<Line> = app
+            .config_problems
+            .iter()
+            .map(|problem| {
+                Line::from(Span::styled(
+                    t_with("config.problem", &[("problem", problem)]),
+                    Style::default().fg(Color::Yellow),
+                ))
+            })
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(Line::from(t("config.fix_hint")));
+        let problems = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("token.problems")),
+        );
+        frame.render_widget(problems, layout[2]);
+
+        Self::render_detail_footer(
+            frame,
+            layout[3],
+            app,
+            &[
+                ("Enter", t("controls.continue_dashboard")),
+                ("q", t("footer.quit")),
+            ],
+        );
+    }
+
+    /// Write a URL as an OSC 8 hyperlink, plain text in terminals without
+    /// hyperlink support
+    ///
+    /// ratatui counts the escape sequence as visible width, so the URL is
+    /// split into two-character chunks that each carry the sequence, as in
+    /// ratatui's hyperlink example.
+    fn render_hyperlink(buffer: &mut Buffer, area: Rect, url: &str, style: Style) {
+        buffer.set_style(area, style);
+        buffer.set_stringn(area.x, area.y, url, area.width as usize, style);
+        let characters: Vec<char> = url.chars().collect();
+        for (i, chunk) in characters.chunks(2).enumerate() {
+            let x = area.x + i as u16 * 2;
+            if x + chunk.len() as u16 > area.right() {
+                break;
+            }
+            let text: String = chunk.iter().collect();
+            buffer[(x, area.y)].set_symbol(&format!("\x1B]8;;{}\x07{}\x1B]8;;\x07", url, text));
+        }
+    }
+
+    /// Render the issue burn-down chart for the selected repository
+    ///
+    /// Plots issues opened vs closed per week over the selected window.
+    fn render_issue_burndown(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let controls = [("w", t("controls.window")), ("Esc", t("controls.back"))];
+        let chart_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(t_with("burndown.chart", &[("weeks", &app.burndown_weeks)]));
+
+        let Some((repository, issues)) = &app.issue_activity else {
+            let text = if app.is_fetching_issue_activity {
+                t("issues.loading")
+            } else {
+                t("burndown.empty")
+            };
+            let loading = Paragraph::new(text)
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center)
+                .block(chart_block);
+            frame.render_widget(loading, layout[1].union(layout[2]));
+            Self::render_detail_footer(frame, layout[3], app, &controls);
+            return;
+        };
+
+        let weeks =
+            BurndownWeek::from_activity(issues, app.burndown_weeks, std::time::SystemTime::now());
+        let opened: usize = weeks.iter().map(|week| week.opened).sum();
+        let closed: usize = weeks.iter().map(|week| week.closed).sum();
+        let net = BurndownWeek::net_change(&weeks);
+        let (trend, trend_color) = match net {
+            n if n > 0 => (t_with("burndown.growing", &[("count", &n)]), Color::Red),
+            n if n < 0 => (
+                t_with("burndown.shrinking", &[("count", &-n)]),
+                Color::Green,
+            ),
+            _ => (t("burndown.unchanged").to_string(), Color::Gray),
+        };
+
+        let summary = vec![
+            Line::from(Span::styled(
+                repository.clone(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(t_with(
+                "burndown.totals",
+                &[("opened", &opened), ("closed", &closed)],
+            )),
+            Line::from(Span::styled(trend, Style::default().fg(trend_color))),
+            Line::from(if app.is_fetching_issue_activity {
+                t("common.refreshing")
+            } else {
+                ""
+            }),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("burndown.title")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let opened_points: Vec<(f64, f64)> = weeks
+            .iter()
+            .enumerate()
+            .map(|(index, week)| (index as f64, week.opened as f64))
+            .collect();
+        let closed_points: Vec<(f64, f64)> = weeks
+            .iter()
+            .enumerate()
+            .map(|(index, week)| (index as f64, week.closed as f64))
+            .collect();
+        let max = weeks
+            .iter()
+            .map(|week| week.opened.max(week.closed))
+            .max()
+            .unwrap_or(0)
+            .max(1) as f64;
+
+        let datasets = vec![
+            Dataset::default()
+                .name(t("burndown.opened"))
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Red))
+                .data(&opened_points),
+            Dataset::default()
+                .name(t("burndown.closed"))
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Green))
+                .data(&closed_points),
+        ];
+        let last_week = weeks.len().saturating_sub(1) as f64;
+        let chart = Chart::new(datasets)
+            .block(chart_block)
+            .x_axis(
+                Axis::default()
+                    .title(t("burndown.week"))
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds([0.0, last_week])
+                    .labels([
+                        t_with("burndown.weeks_ago", &[("weeks", &app.burndown_weeks)]),
+                        t("burndown.now").to_string(),
+                    ]),
+            )
+            .y_axis(
+                Axis::default()
+                    .title(t("burndown.issues"))
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds([0.0, max])
+                    .labels(["0".to_string(), format!("{}", max as usize)]),
+            );
+        frame.render_widget(chart, layout[2]);
+
+        Self::render_detail_footer(frame, layout[3], app, &controls);
+    }
+
+    /// Render the workflow timeline of the selected repository
+    ///
+    /// Each recent run is a bar across a time axis ending now, colored by its
+    /// result, so recurring failures and growing durations stand out.
+    fn render_workflow_timeline(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let controls = [
+            ("+/-", t("controls.zoom")),
+            ("↑↓", t("controls.scroll")),
+            ("Esc", t("controls.back")),
+        ];
+        let Some(repo) = app.get_selected_repository() else {
+            Self::render_detail_footer(frame, layout[3], app, &controls);
+            return;
+        };
+
+        let runs = &repo.recent_workflows;
+        let window = std::time::Duration::from_secs(app.timeline_hours * 3600);
+        let window_label = if app.timeline_hours >= 24 {
+            format!("{}d", app.timeline_hours / 24)
+        } else {
+            format!("{}h", app.timeline_hours)
+        };
+
+        // Average duration of the older and newer half of the runs
+        let finished: Vec<std::time::Duration> =
+            runs.iter().rev().filter_map(|run| run.duration).collect();
+        let average = |durations: &[std::time::Duration]| {
+            (!durations.is_empty())
+                .then(|| durations.iter().sum::<std::time::Duration>() / durations.len() as u32)
+        };
+        let (older, newer) = finished.split_at(finished.len() / 2);
+        let failed = runs
+            .iter()
+            .filter(|run| run.status == WorkflowStatus::Failed)
+            .count();
+        let summary = vec![
+            Line::from(Span::styled(
+                repo.display_full_name(app.guest_mode),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(t_with(
+                "timeline.runs",
+                &[("count", &runs.len()), ("failed", &failed)],
+            )),
+            Line::from(t_with(
+                "timeline.average",
+                &[
+                    ("older", &Self::format_duration(average(older))),
+                    ("newer", &Self::format_duration(average(newer))),
+                ],
+            )),
+            Line::from(t_with("timeline.window", &[("window", &window_label)])),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("timeline.title")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        // Name, duration and trigger columns, then the time axis
+        const LABEL_WIDTH: usize = 50;
+        let width = (layout[2].width as usize).saturating_sub(2 + LABEL_WIDTH);
+        let now = std::time::SystemTime::now();
+        let bars = TimelineBar::layout(runs, window, now, width);
+
+        let mut lines = Vec::new();
+        if bars.is_empty() {
+            lines.push(Line::from(Span::styled(
+                if runs.is_empty() {
+                    t("timeline.empty")
+                } else {
+                    t("timeline.empty_window")
+                },
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        for bar in &bars {
+            let run = &runs[bar.run];
+            let color = match run.status {
+                WorkflowStatus::Success => Color::Green,
+                WorkflowStatus::Failed => Color::Red,
+                WorkflowStatus::InProgress => Color::Yellow,
+                WorkflowStatus::Cancelled | WorkflowStatus::Unknown => Color::DarkGray,
+            };
+            let name: String = run.name.chars().take(16).collect();
+            let trigger: String = run.trigger.label().chars().take(23).collect();
+            let (start, end) = run.span(now);
+            lines.push(Line::from(vec![
+                Span::raw(format!(
+                    "{:<17}{:>8} ",
+                    name,
+                    Self::format_duration(end.duration_since(start).ok())
+                )),
+                Span::styled(
+                    format!("{:<24}", trigger),
+                    Style::default().fg(if run.trigger.from_fork {
+                        Color::Magenta
+                    } else {
+                        Color::DarkGray
+                    }),
+                ),
+                Span::raw(" ".repeat(bar.offset)),
+                Span::styled("█".repeat(bar.length), Style::default().fg(color)),
+            ]));
+        }
+        if !bars.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "{:LABEL_WIDTH$}-{:<w$}{}",
+                    "",
+                    window_label,
+                    t("timeline.now"),
+                    w = width.saturating_sub(4)
+                ),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+
+        let timeline = Paragraph::new(lines)
+            .scroll((app.timeline_scroll, 0))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Gray))
+                    .title(t("timeline.lanes")),
+            );
+        frame.render_widget(timeline, layout[2]);
+
+        Self::render_detail_footer(frame, layout[3], app, &controls);
+    }
+
+    /// Format a duration as "1h 5m", "3m 20s" or "45s"
+    fn format_duration(duration: Option<std::time::Duration>) -> String {
+        match duration {
+            Some(d) if d.as_secs() >= 3600 => {
+                format!("{}h {}m", d.as_secs() / 3600, d.as_secs() % 3600 / 60)
+            }
+            Some(d) if d.as_secs() >= 60 => format!("{}m {}s", d.as_secs() / 60, d.as_secs() % 60),
+            Some(d) => format!("{}s", d.as_secs()),
+            None => "—".to_string(),
+        }
+    }
+
+    /// Render the template compliance view
+    ///
+    /// Lists each repository's compliance with the standard template, least
+    /// compliant first, with the expected files it is missing.
+    fn render_template_compliance(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let controls = [("↑↓", t("controls.scroll")), ("Esc", t("controls.back"))];
+        let content_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(t("common.repositories"));
+
+        let Some(report) = &app.compliance_report else {
+            let text = if app.is_scanning_compliance {
+                t("compliance.scanning")
+            } else {
+                t("compliance.empty")
+            };
+            let loading = Paragraph::new(text)
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center)
+                .block(content_block);
+            frame.render_widget(loading, layout[1].union(layout[2]));
+            Self::render_detail_footer(frame, layout[3], app, &controls);
+            return;
+        };
+
+        let percentage_color = |percentage: u8| match percentage {
+            100 => Color::Green,
+            75..=99 => Color::Yellow,
+            _ => Color::Red,
+        };
+        let overall = report.overall_percentage();
+        let scanned = report.repositories.iter().filter(|r| r.scanned).count();
+        let summary = vec![
+            Line::from(Span::styled(
+                t_with("compliance.template", &[("template", &report.template)]),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(t_with(
+                "compliance.expected",
+                &[("count", &report.expected.len())],
+            )),
+            Line::from(Span::styled(
+                t_with(
+                    "compliance.overall",
+                    &[
+                        ("percentage", &overall),
+                        ("compliant", &report.compliant_count()),
+                        ("scanned", &scanned),
+                    ],
+                ),
+                Style::default().fg(percentage_color(overall)),
+            )),
+            Line::from(if app.is_scanning_compliance {
+                t("common.rescanning")
+            } else {
+                ""
+            }),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("compliance.title")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let mut lines = Vec::new();
+        for repo in &report.repositories {
+            if !repo.scanned {
+                lines.push(Line::from(Span::styled(
+                    t_with(
+                        "compliance.not_scanned",
+                        &[("repository", &app.display_repository(&repo.repository))],
+                    ),
+                    Style::default().fg(Color::DarkGray),
+                )));
+                continue;
+            }
+
+            let percentage = report.percentage(repo);
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{:>4}% ", percentage),
+                    Style::default().fg(percentage_color(percentage)),
+                ),
+                Span::raw(app.display_repository(&repo.repository)),
+            ]));
+            for path in &repo.missing {
+                lines.push(Line::from(Span::styled(
+                    t_with("compliance.missing", &[("path", path)]),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+        }
+
+        let details = Paragraph::new(lines)
+            .scroll((app.compliance_scroll, 0))
+            .block(content_block);
+        frame.render_widget(details, layout[2]);
+
+        Self::render_detail_footer(frame, layout[3], app, &controls);
+    }
+
+    /// Render the release readiness board: open pull requests grouped by
+    /// milestone or release label, with progress and failing CI per release
+    fn render_release_board(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let releases = app.release_readiness();
+        let ready = releases
+            .iter()
+            .filter(|release| release.percent() == 100)
+            .count();
+        let blocked = releases
+            .iter()
+            .filter(|release| !release.failing_repositories().is_empty())
+            .count();
+        let summary = vec![
+            Line::from(Span::styled(
+                t_with("releases.count", &[("count", &releases.len())]),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(t_with(
+                "releases.pattern",
+                &[("pattern", &app.config.releases.label_pattern)],
+            )),
+            Line::from(Span::styled(
+                t_with("releases.ready", &[("count", &ready)]),
+                Style::default().fg(Color::Green),
+            )),
+            Line::from(Span::styled(
+                t_with("releases.failing", &[("count", &blocked)]),
+                Style::default().fg(if blocked > 0 {
+                    Color::Red
+                } else {
+                    Color::Green
+                }),
+            )),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("releases.title")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let mut lines = Vec::new();
+        for release in &releases {
+            let width = 20;
+            let filled = release.percent() * width / 100;
+            let color = if release.percent() == 100 {
+                Color::Green
+            } else {
+                Color::Yellow
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{:<12}", release.release),
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled("█".repeat(filled), Style::default().fg(color)),
+                Span::styled(
+                    "░".repeat(width - filled),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::raw(t_with(
+                    "releases.progress",
+                    &[
+                        ("ready", &release.ready()),
+                        ("total", &release.total()),
+                        ("percent", &release.percent()),
+                    ],
+                )),
+            ]));
+            let failing = release.failing_repositories();
+            if !failing.is_empty() {
+                let names: Vec<String> = failing
+                    .iter()
+                    .map(|name| app.display_repository(name))
+                    .collect();
+                lines.push(Line::from(Span::styled(
+                    t_with(
+                        "releases.ci_failing",
+                        &[("repositories", &names.join(", "))],
+                    ),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+            for repo in &release.repositories {
+                let (marker, color) = if repo.ci_failing {
+                    ("❌", Color::Red)
+                } else if repo.ready == repo.total {
+                    ("✅", Color::Green)
+                } else {
+                    ("⏳", Color::Yellow)
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("    {} ", marker), Style::default().fg(color)),
+                    Span::raw(app.display_repository(&repo.repository)),
+                    Span::styled(
+                        t_with(
+                            "releases.repository_ready",
+                            &[("ready", &repo.ready), ("total", &repo.total)],
+                        ),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]));
+            }
+            lines.push(Line::from(""));
+        }
+        if lines.is_empty() {
+            lines.push(Line::from(Span::styled(
+                t("releases.empty"),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+
+        let board = Paragraph::new(lines).scroll((app.release_scroll, 0)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("releases.releases")),
+        );
+        frame.render_widget(board, layout[2]);
+
+        Self::render_detail_footer(
+            frame,
+            layout[3],
+            app,
+            &[("↑↓", t("controls.scroll")), ("Esc", t("controls.back"))],
+        );
+    }
+
+    /// Render topic policy violations, most violations first
+    fn render_policies(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let controls = [("↑↓", t("controls.scroll")), ("Esc", t("controls.back"))];
+        let content_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(t("common.repositories"));
+
+        let Some(report) = app
+            .policy_report
+            .as_ref()
+            .filter(|_| !app.is_scanning_policies)
+        else {
+            let text = if app.is_scanning_policies {
+                t("policies.checking")
+            } else {
+                t("policies.empty")
+            };
+            let loading = Paragraph::new(text)
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center)
+                .block(content_block);
+            frame.render_widget(loading, layout[1].union(layout[2]));
+            Self::render_detail_footer(frame, layout[3], app, &controls);
+            return;
+        };
+
+        let topics: Vec<&str> = app
+            .config
+            .policies
+            .iter()
+            .map(|policy| policy.topic.as_str())
+            .collect();
+        let compliant = report.compliant_count();
+        let total = report.repositories.len();
+        let summary = vec![
+            Line::from(Span::styled(
+                t_with("policies.heading", &[("topics", &topics.join(", "))]),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(t_with("policies.carrying", &[("count", &total)])),
+            Line::from(Span::styled(
+                t_with(
+                    "policies.compliant",
+                    &[("compliant", &compliant), ("total", &total)],
+                ),
+                Style::default().fg(if compliant == total {
+                    Color::Green
+                } else {
+                    Color::Red
+                }),
+            )),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("policies.title")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let mut lines = Vec::new();
+        for result in &report.repositories {
+            let (marker, color) = if result.violations.is_empty() {
+                ("✅", Color::Green)
+            } else {
+                ("❌", Color::Red)
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!(" {} ", marker), Style::default().fg(color)),
+                Span::raw(app.display_repository(&result.repository)),
+                Span::styled(
+                    format!("  [{}]", result.topics.join(", ")),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+            for violation in &result.violations {
+                lines.push(Line::from(Span::styled(
+                    format!("      {}", violation.description()),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+        }
+        if lines.is_empty() {
+            lines.push(Line::from(Span::styled(
+                t("policies.none"),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+
+        let details = Paragraph::new(lines)
+            .scroll((app.policy_scroll, 0))
+            .block(content_block);
+        frame.render_widget(details, layout[2]);
+
+        Self::render_detail_footer(frame, layout[3], app, &controls);
+    }
+
+    /// Render the dependency view, unhealthy upstream repositories first
+    fn render_dependencies(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let controls = [("↑↓", t("controls.scroll")), ("Esc", t("controls.back"))];
+        let content_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(t("dependencies.priority"));
+
+        let Some(graph) = &app.dependency_graph else {
+            let text = if app.is_scanning_dependencies {
+                t("dependencies.reading")
+            } else {
+                t("dependencies.empty")
+            };
+            let loading = Paragraph::new(text)
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center)
+                .block(content_block);
+            frame.render_widget(loading, layout[1].union(layout[2]));
+            Self::render_detail_footer(frame, layout[3], app, &controls);
+            return;
+        };
+
+        let rows = graph.prioritized(app.get_repositories(), &app.config.attention);
+        let unhealthy_upstreams = rows
+            .iter()
+            .filter(|row| row.needs_attention && row.blast_radius > 0)
+            .count();
+        let summary = vec![
+            Line::from(Span::styled(
+                t("dependencies.heading"),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(t_with("dependencies.linked", &[("count", &rows.len())])),
+            Line::from(Span::styled(
+                t_with(
+                    "dependencies.unhealthy_upstreams",
+                    &[("count", &unhealthy_upstreams)],
+                ),
+                Style::default().fg(if unhealthy_upstreams > 0 {
+                    Color::Red
+                } else {
+                    Color::Green
+                }),
+            )),
+            Line::from(if app.is_scanning_dependencies {
+                t("common.rescanning")
+            } else {
+                ""
+            }),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("dependencies.title")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let mut lines = Vec::new();
+        if graph.is_empty() {
+            lines.push(Line::from(Span::styled(
+                t("dependencies.none"),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        for row in &rows {
+            let color = if row.needs_attention {
+                Color::Red
+            } else {
+                Color::Green
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{:>4} ", row.health_score),
+                    Style::default().fg(color),
+                ),
+                Span::raw(app.display_repository(&row.repository)),
+                Span::styled(
+                    t_with(
+                        "dependencies.upstream",
+                        &[("count", &row.blast_radius), ("direct", &row.dependents)],
+                    ),
+                    Style::default().fg(if row.blast_radius > 0 {
+                        Color::Yellow
+                    } else {
+                        Color::DarkGray
+                    }),
+                ),
+            ]));
+            if !row.depends_on.is_empty() {
+                lines.push(Line::from(Span::styled(
+                    t_with(
+                        "dependencies.depends_on",
+                        &[("repositories", &row.depends_on.join(", "))],
+                    ),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+        }
+
+        let details = Paragraph::new(lines)
+            .scroll((app.dependencies_scroll, 0))
+            .block(content_block);
+        frame.render_widget(details, layout[2]);
+
+        Self::render_detail_footer(frame, layout[3], app, &controls);
+    }
+
+    /// Render the cleanup view listing repositories that look safe to archive
+    fn render_cleanup(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let controls = [
+            ("↑↓", t("controls.scroll")),
+            ("a", t("controls.archive")),
+            ("Esc", t("controls.back")),
+        ];
+        let content_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(t("cleanup.candidates"));
+
+        let Some(candidates) = &app.cleanup_candidates else {
+            let text = if app.is_scanning_cleanup {
+                t("cleanup.checking")
+            } else {
+                t("cleanup.empty")
+            };
+            let loading = Paragraph::new(text)
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center)
+                .block(content_block);
+            frame.render_widget(loading, layout[1].union(layout[2]));
+            Self::render_detail_footer(frame, layout[3], app, &controls);
+            return;
+        };
+
+        let count = |matches: fn(&CleanupReason) -> bool| {
+            candidates
+                .iter()
+                .filter(|candidate| candidate.reasons.iter().any(matches))
+                .count()
+        };
+        let summary = vec![
+            Line::from(Span::styled(
+                t_with(
+                    "cleanup.heading",
+                    &[
+                        ("count", &candidates.len()),
+                        ("total", &app.get_repositories().len()),
+                    ],
+                ),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(t_with(
+                "cleanup.leftover_names",
+                &[(
+                    "count",
+                    &count(|reason| matches!(reason, CleanupReason::LeftoverName { .. })),
+                )],
+            )),
+            Line::from(t_with(
+                "cleanup.unchanged_forks",
+                &[(
+                    "count",
+                    &count(|reason| matches!(reason, CleanupReason::UnchangedFork { .. })),
+                )],
+            )),
+            Line::from(t_with(
+                "cleanup.empty_repositories",
+                &[(
+                    "count",
+                    &count(|reason| matches!(reason, CleanupReason::Empty)),
+                )],
+            )),
+            Line::from(t_with(
+                "cleanup.dormant",
+                &[(
+                    "count",
+                    &count(|reason| matches!(reason, CleanupReason::Dormant { .. })),
+                )],
+            )),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(if app.is_scanning_cleanup {
+                    t("cleanup.title_refreshing")
+                } else {
+                    t("cleanup.title")
+                }),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        let mut lines = Vec::new();
+        if candidates.is_empty() {
+            lines.push(Line::from(Span::styled(
+                t("cleanup.none"),
+                Style::default().fg(Color::Green),
+            )));
+        }
+        for candidate in candidates {
+            let admin = app
+                .row_cache
+                .position(&candidate.repository)
+                .is_some_and(|index| app.get_repositories()[index].admin);
+            let mut spans = vec![Span::styled(
+                app.display_repository(&candidate.repository),
+                Style::default().fg(Color::Yellow),
+            )];
+            if !admin {
+                spans.push(Span::styled(
+                    t("cleanup.not_admin"),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            lines.push(Line::from(spans));
+            for reason in &candidate.reasons {
+                lines.push(Line::from(Span::styled(
+                    format!("    {}", reason.description()),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+        }
+
+        let details = Paragraph::new(lines)
+            .scroll((app.cleanup_scroll, 0))
+            .block(content_block);
+        frame.render_widget(details, layout[2]);
+
+        Self::render_detail_footer(frame, layout[3], app, &controls);
+    }
+
+    /// Render the user's contribution calendar as a heatmap with their streaks
+    ///
+    /// Weeks run left to right and weekdays top to bottom, as on GitHub
+    /// profiles; the most recent weeks that fit the terminal are shown.
+    fn render_contributions(frame: &mut Frame, app: &App) {
+        let layout = Self::detail_layout_with_summary(frame.area(), 4);
+        Self::render_header(frame, layout[0], app);
+
+        let controls = [("Esc", t("controls.back"))];
+        let calendar_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray))
+            .title(t("contributions.title"));
+
+        let Some(calendar) = &app.contributions else {
+            let text = if app.is_fetching_contributions {
+                t("contributions.loading")
+            } else {
+                t("contributions.empty")
+            };
+            let loading = Paragraph::new(text)
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center)
+                .block(calendar_block);
+            frame.render_widget(loading, layout[1].union(layout[2]));
+            Self::render_detail_footer(frame, layout[3], app, &controls);
+            return;
+        };
+
+        let today = chrono::Local::now().date_naive();
+        let current = calendar.current_streak(today);
+        let summary = vec![
+            Line::from(Span::styled(
+                t_with(
+                    "contributions.heading",
+                    &[("login", &calendar.login), ("total", &calendar.total)],
+                ),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(Span::styled(
+                t_with(
+                    "contributions.current_streak",
+                    &[
+                        ("icon", &if current > 0 { "🔥" } else { "💤" }),
+                        ("days", &current),
+                    ],
+                ),
+                Style::default().fg(if current > 0 {
+                    Color::Green
+                } else {
+                    Color::DarkGray
+                }),
+            )),
+            Line::from(t_with(
+                "contributions.longest_streak",
+                &[("days", &calendar.longest_streak())],
+            )),
+            Line::from(t_with(
+                "contributions.active_days",
+                &[
+                    (
+                        "active",
+                        &calendar.days().filter(|day| day.count > 0).count(),
+                    ),
+                    ("total", &calendar.days().count()),
+                ],
+            )),
+        ];
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Gray))
+                .title(t("contributions.streak")),
+        );
+        frame.render_widget(summary_paragraph, layout[1]);
+
+        // Each week takes two columns after the weekday labels
+        let shown = (layout[2].width.saturating_sub(6) / 2) as usize;
+        let weeks = &calendar.weeks[calendar.weeks.len().saturating_sub(shown)..];
+        let level_colors = [
+            Color::DarkGray,
+            Color::Rgb(14, 68, 41),
+            Color::Rgb(0, 109, 50),
+            Color::Rgb(38, 166, 65),
+            Color::Rgb(57, 211, 83),
+        ];
+        let weekday_labels = [
+            "",
+            t("contributions.monday"),
+            "",
+            t("contributions.wednesday"),
+            "",
+            t("contributions.friday"),
+            "",
+        ];
+        let lines: Vec<Line> = weekday_labels
+            .iter()
+            .enumerate()
+            .map(|(weekday, label)| {
+                let mut spans = vec![Span::raw(format!("{:<3} ", label))];
+                for week in weeks {
+                    let day = week.iter().find(|day| day.weekday() == weekday);
+                    spans.push(match day {
+                        Some(day) => Span::styled(
+                            "■ ",
+                            Style::default().fg(level_colors[calendar.level(day.count) as usize]),
+                        ),
+                        None => Span::raw("  "),
+                    });
+                }
+                Line::from(spans)
+            })
+            .collect();
+
+        let heatmap = Paragraph::new(lines)
+            .block(calendar_block.title(t_with("contributions.weeks", &[("count", &weeks.len())])));
+        frame.render_widget(heatmap, layout[2]);
+
+        Self::render_detail_footer(frame, layout[3], app, &controls);
+    }
+
+    /// Render a modal dialog centered over the current view
+    fn render_dialog(frame: &mut Frame, dialog: &Dialog) {
+        let area = frame.area();
+        let width = area.width.min(60);
+        let height = (dialog.fields.len() as u16 + 5).min(area.height);
+        let dialog_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+
+        let mut lines = Vec::new();
+        for (index, field) in dialog.fields.iter().enumerate() {
+            let focused = index == dialog.focused;
+            let value = match field {
+                DialogField::Text { value, .. } if focused => format!("{}█", value),
+                DialogField::Text { value, .. } => value.clone(),
+                DialogField::Choice { .. } => format!("◀ {} ▶", field.value()),
+            };
+            let label_style = if focused {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{:>12}: ", field.label()), label_style),
+                Span::raw(value),
+            ]));
+        }
+        lines.push(Line::from(""));
+        lines.push(match &dialog.error {
+            Some(error) => Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red))),
+            None => Line::from(Span::styled(
+                t("controls.dialog"),
+                Style::default().fg(Color::DarkGray),
+            )),
+        });
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow))
+                .title(dialog.title.clone())
+                .style(Style::default().bg(Color::Black)),
+        );
+        frame.render_widget(Clear, dialog_area);
+        frame.render_widget(paragraph, dialog_area);
+    }
+
+    /// Render two organizations or groups side by side
+    fn render_comparison(frame: &mut Frame, app: &App) {
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Header
+                Constraint::Min(0),    // Panes
+                Constraint::Length(3), // Footer
+            ])
+            .split(frame.area());
+        Self::render_header(frame, layout[0], app);
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(layout[1]);
+        for (index, pane) in app.comparison_panes.iter().enumerate() {
+            let focused = index == app.comparison_focus;
+            Self::render_comparison_pane(frame, columns[index], app, pane, focused);
+        }
+
+        Self::render_detail_footer(
+            frame,
+            layout[2],
+            app,
+            &[
+                ("Tab", t("controls.switch_pane")),
+                ("←→", t("controls.change_group")),
+                ("↑↓", t("controls.select")),
+                ("Esc", t("controls.back")),
+            ],
+        );
+    }
+
+    /// Render one comparison pane: aggregate metrics above its repository list
+    fn render_comparison_pane(
+        frame: &mut Frame,
+        area: Rect,
+        app: &App,
+        pane: &ComparisonPane,
+        focused: bool,
+    ) {
+        let sections = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(8), Constraint::Min(0)])
+            .split(area);
+        let border_color = if focused { Color::Yellow } else { Color::Gray };
+
+        let metrics =
+            AggregateMetrics::from_repositories(&pane.repositories, &app.config.attention);
+        let summary = if pane.is_loading {
+            vec![Line::from(Span::styled(
+                t("header.loading"),
+                Style::default().fg(Color::Yellow),
+            ))]
+        } else {
+            let health = metrics
+                .average_health
+                .map_or("—".to_string(), |score| format!("{}/100", score));
+            vec![
+                Line::from(t_with(
+                    "compare.repositories",
+                    &[("count", &metrics.repositories)],
+                )),
+                Line::from(t_with("compare.health", &[("health", &health)])),
+                Line::from(Span::styled(
+                    t_with("compare.attention", &[("count", &metrics.needs_attention)]),
+                    Style::default().fg(if metrics.needs_attention > 0 {
+                        Color::Yellow
+                    } else {
+                        Color::Green
+                    }),
+                )),
+                Line::from(t_with(
+                    "compare.pull_requests",
+                    &[("count", &metrics.open_pull_requests)],
+                )),
+                Line::from(t_with(
+                    "compare.broken",
+                    &[
+                        ("broken", &metrics.ci_broken),
+                        ("inactive", &metrics.inactive),
+                    ],
+                )),
+            ]
+        };
+        let summary_paragraph = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(border_color))
+                .title(pane.source.display_name()),
+        );
+        frame.render_widget(summary_paragraph, sections[0]);
+
+        let theme = &app.config.theme;
+        let rows: Vec<Row> = pane
+            .repositories
+            .iter()
+            .map(|repo| {
+                Row::new(vec![
+                    Cell::from(repo.display_name(app.guest_mode)),
+                    Cell::from(repo.health_score().to_string()),
+                    Cell::from(repo.open_pull_request_count().to_string()),
+                    Cell::from(format!(
+                        "{} {}",
+                        theme.repository_icon(&repo.status),
+                        repo.status.description()
+                    ))
+                    .style(
+                        Style::default()
+                            .fg(theme.color(repo.status.color(), repo.status.severity())),
+                    ),
+                ])
+            })
+            .collect();
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Min(16),
+                Constraint::Length(7),
+                Constraint::Length(5),
+                Constraint::Length(14),
+            ],
+        )
+        .header(Self::header_row(&[
+            t("table.repository"),
+            t("compare.col_health"),
+            t("table.pull_requests"),
+            t("table.status"),
+        ]))
+        .row_highlight_style(if focused {
+            Style::default().bg(Color::Blue).fg(Color::White)
+        } else {
+            Style::default().add_modifier(Modifier::REVERSED)
+        })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(border_color)),
+        );
+
+        // Each pane keeps its own selection; the table state scrolls to it
+        let mut state = TableState::default().with_selected(Some(pane.selected));
+        frame.render_stateful_widget(table, sections[1], &mut state);
+    }
+
+    /// Render the popover explaining the selected repository's indicators
+    ///
+    /// Spells out what each health signal was computed from, so a surprising
+    /// value can be checked rather than taken on trust.
+    fn render_inspect_popover(frame: &mut Frame, app: &App) {
+        let Some(repo) = app.get_selected_repository() else {
+            return;
+        };
+        let mut explanations = repo.explain_indicators(&app.config, std::time::SystemTime::now());
+        if let Some(anomaly) = app.commit_anomaly(repo) {
+            explanations.push(IndicatorExplanation {
+                indicator: t("explain.activity"),
+                value: t("explain.unusually_quiet").to_string(),
+                reason: anomaly.description(),
+            });
+        }
+        for result in app
+            .custom_checks
+            .get(&repo.full_name())
+            .into_iter()
+            .flatten()
+        {
+            explanations.push(IndicatorExplanation {
+                indicator: t("explain.custom"),
+                value: format!("{} {}", if result.passed { "✔" } else { "✘" }, result.name),
+                reason: match result.detail.as_str() {
+                    "" if result.passed => t("explain.exited_cleanly").to_string(),
+                    detail => detail.to_string(),
+                },
+            });
+        }
+
+        let area = frame.area();
+        let width = area.width.min(80);
+        let height = (explanations.len() as u16 + 2).min(area.height);
+        let popover_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+
+        let lines: Vec<Line> = explanations
+            .into_iter()
+            .map(|explanation| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<12}", explanation.indicator),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(explanation.value, Style::default().fg(Color::Cyan)),
+                    Span::styled(
+                        format!(" — {}", explanation.reason),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ])
+            })
+            .collect();
+        let popover = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Magenta))
+                .title(t_with(
+                    "explain.title",
+                    &[("repository", &repo.display_full_name(app.guest_mode))],
+                ))
+                .style(Style::default().bg(Color::Black)),
+        );
+        frame.render_widget(Clear, popover_area);
+        frame.render_widget(popover, popover_area);
+    }
+
+    /// Render the GitHub API request metrics overlay
+    ///
+    /// Shows per-endpoint request counts, errors and latency percentiles for
+    /// this session, to tell a slow dashboard apart from a slow GitHub.
+    fn render_metrics_overlay(frame: &mut Frame, app: &App) {
+        let area = frame.area();
+        let width = area.width.min(84);
+        let height = area.height.min(20);
+        let overlay_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+
+        let metrics = app.github_client.as_ref().map(|client| client.metrics());
+        let endpoints = metrics
+            .as_ref()
+            .map(|metrics| metrics.snapshot())
+            .unwrap_or_default();
+        let (requests, errors) = metrics
+            .as_ref()
+            .map(|metrics| metrics.totals())
+            .unwrap_or_default();
+
+        let format_latency = |latency: Option<std::time::Duration>| {
+            latency.map_or("-".to_string(), |latency| {
+                format!("{}ms", latency.as_millis())
+            })
+        };
+        let rows: Vec<Row> = endpoints
+            .iter()
+            .map(|(endpoint, metrics)| {
+                let error_style = if metrics.errors > 0 {
+                    Style::default().fg(Color::Red)
+                } else {
+                    Style::default()
+                };
+                Row::new(vec![
+                    Cell::from(endpoint.to_string()),
+                    Cell::from(metrics.requests.to_string()),
+                    Cell::from(metrics.not_modified.to_string()),
+                    Cell::from(format!("{} ({:.0}%)", metrics.errors, metrics.error_rate()))
+                        .style(error_style),
+                    Cell::from(format_latency(metrics.p50())),
+                    Cell::from(format_latency(metrics.p95())),
+                ])
+            })
+            .collect();
+
+        let title = if metrics.is_some() {
+            t_with(
+                "metrics.title",
+                &[("requests", &requests), ("errors", &errors)],
+            )
+        } else {
+            t("metrics.no_client").to_string()
+        };
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Min(20),
+                Constraint::Length(9),
+                Constraint::Length(8),
+                Constraint::Length(11),
+                Constraint::Length(9),
+                Constraint::Length(9),
+            ],
+        )
+        .header(Self::header_row(&[
+            t("metrics.col_endpoint"),
+            t("metrics.col_requests"),
+            t("metrics.col_cached"),
+            t("metrics.col_errors"),
+            "p50",
+            "p95",
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Magenta))
+                .title(title)
+                .style(Style::default().bg(Color::Black)),
+        );
+        // Time from launch to each point of the first load
+        let startup = Paragraph::new(app.startup.summary()).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Magenta))
+                .title(t("metrics.startup"))
+                .style(Style::default().bg(Color::Black)),
+        );
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(3)])
+            .split(overlay_area);
+        frame.render_widget(Clear, overlay_area);
+        frame.render_widget(table, chunks[0]);
+        frame.render_widget(startup, chunks[1]);
+    }
+
+    /// Render a modal menu centered over the current view
+    fn render_menu(frame: &mut Frame, menu: &Menu) {
+        let area = frame.area();
+        let width = area.width.min(50);
+        let height = (menu.items.len() as u16 + 4).min(area.height);
+        let menu_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+
+        let mut lines: Vec<Line> = menu
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let style = if index == menu.selected {
+                    Style::default().bg(Color::Blue).fg(Color::White)
+                } else {
+                    Style::default()
+                };
+                Line::from(Span::styled(
+                    format!(" {} {}", index + 1, item.label),
+                    style,
+                ))
+            })
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            t("controls.menu"),
+            Style::default().fg(Color::DarkGray),
+        )));
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow))
+                .title(menu.title.clone())
+                .style(Style::default().bg(Color::Black)),
+        );
+        frame.render_widget(Clear, menu_area);
+        frame.render_widget(paragraph, menu_area);
+    }
+
+    /// Build a bold cyan table header row
+    fn header_row(titles: &[&'static str]) -> Row<'static> {
+        Row::new(
+            titles
+                .iter()
+                .map(|title| {
+                    Cell::from(*title).style(
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Render the footer for detail views
+    ///
+    /// Shows the view's key bindings followed by the latest status message
+    fn render_detail_footer(frame: &mut Frame, area: Rect, app: &App, controls: &[(&str, &str)]) {
+        let mut spans = Vec::new();
+        for (key, description) in controls {
+            spans.push(Span::styled(
+                format!("[{}] ", key),
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            spans.push(Span::raw(format!("{}  ", description)));
+        }
+
+        if let Some(message) = &app.status_message {
+            spans.push(Span::styled(
+                message.clone(),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+
+        let footer = Paragraph::new(Line::from(spans))
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Gray)),
+            );
+
+        frame.render_widget(footer, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::App;
+
+    #[test]
+    fn test_ui_render_does_not_panic() {
+        // This is a basic smoke test to ensure UI rendering doesn't panic
+        // More comprehensive UI tests would require a mock terminal
+        let app = App::new();
+
+        // We can't easily test the actual rendering without a terminal,
+        // but we can test that our UI struct can be created
+        let _ui = UI;
+
+        // Verify app state is as expected
+        assert!(!app.should_quit());
+        assert_eq!(app.current_view, AppView::Dashboard);
+    }
+
+    #[test]
+    fn test_render_large_repository_list() {
+        use crate::models::Repository;
+        use ratatui::backend::TestBackend;
+
+        let mut app = App::new();
+        app.error_message = None;
+        app.set_repositories(
+            (0..2000)
+                .map(|i| Repository::new(format!("repo-{}", i), "org".to_string()))
+                .collect(),
+        );
+        app.selected_repository = 1500;
+        app.scroll_offset = 1495;
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(120, 30)).unwrap();
+        terminal.draw(|frame| UI::render(frame, &app)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let content: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(content.contains("repo-1500"));
+        assert!(!content.contains("repo-1494 "));
+    }
+
+    #[test]
+    fn test_bus_factor_icon_shows_with_other_icons() {
+        use crate::models::{BusFactor, Repository};
+        use ratatui::backend::TestBackend;
+
+        let mut app = App::new();
+        app.error_message = None;
+        let mut repo = Repository::new("solo".to_string(), "acme".to_string());
+        repo.fetch_timed_out = true;
+        repo.bus_factor = Some(BusFactor {
+            top_contributor: "alice".to_string(),
+            top_commits: 95,
+            commits: 100,
+            contributors: 2,
+        });
+        app.set_repositories(vec![repo]);
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(120, 30)).unwrap();
+        terminal.draw(|frame| UI::render(frame, &app)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let width = buffer.area.width as usize;
+        let row = buffer
+            .content()
+            .chunks(width)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .find(|row| row.contains("solo"))
+            .unwrap();
+        assert!(row.contains('⏱') && row.contains('👤'));
+    }
+
+    #[test]
+    fn test_dashboard_and_menu_render_without_english_under_de() {
+        use ratatui::backend::TestBackend;
+        use ratatui::crossterm::event::KeyCode;
+
+        crate::i18n::with_locale("de", || {
+            let mut app = App::new();
+            app.error_message = None;
+            app.set_repositories(crate::demo::repositories(std::time::SystemTime::now()));
+
+            let mut screens = String::new();
+            for key in [None, Some(KeyCode::Char('S')), Some(KeyCode::Char('e'))] {
+                app.menu = None;
+                app.status_message = None;
+                if let Some(key) = key {
+                    app.handle_key_event(key);
+                }
+                let mut terminal = ratatui::Terminal::new(TestBackend::new(160, 40)).unwrap();
+                terminal.draw(|frame| UI::render(frame, &app)).unwrap();
+                let buffer = terminal.backend().buffer();
+                for row in buffer.content().chunks(buffer.area.width as usize) {
+                    screens.extend(row.iter().map(|cell| cell.symbol()));
+                    screens.push('\n');
+                }
+            }
+
+            assert!(screens.contains(crate::i18n::t("menu.no_second_key")));
+            assert!(screens.contains(crate::i18n::t("menu.collectors")));
+            assert_eq!(
+                crate::i18n::untranslated("de", &screens),
+                Vec::<String>::new()
+            );
+        });
+    }
+
+    #[test]
+    fn test_issues_view_redacts_private_name_in_guest_mode() {
+        use crate::models::Repository;
+        use ratatui::backend::TestBackend;
+
+        let mut app = App::new();
+        let mut private = Repository::new("payments-api".to_string(), "acme".to_string());
+        private.private = true;
+        app.set_repositories(vec![private]);
+        app.guest_mode = true;
+        app.current_view = AppView::Issues;
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(120, 30)).unwrap();
+        terminal.draw(|frame| UI::render(frame, &app)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let content: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(content.contains("acme/private-"));
+        assert!(!content.contains("payments-api"));
     }
 }
@@ -0,0 +1,160 @@
+use crate::config::Collector;
+use crate::i18n::t_with;
+use crate::models::IssueAction;
+
+/// Actions kept for undoing; older ones are dropped
+const MAX_ACTIONS: usize = 50;
+
+/// A reversible change made from the TUI, holding what's needed to revert it
+#[derive(Debug, Clone, PartialEq)]
+pub enum UndoAction {
+    /// A repository was snoozed or woken up
+    Snooze {
+        repository: String,
+        /// Day the snooze it had before ended (None when it wasn't snoozed)
+        previous: Option<chrono::NaiveDate>,
+        /// Whether the snooze was ended rather than started or moved
+        woke: bool,
+    },
+    /// A collector was switched on (`enabled`) or off
+    Collector { collector: Collector, enabled: bool },
+    /// Labels were added to and removed from an issue
+    Labels {
+        owner: String,
+        repo: String,
+        number: u64,
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
+}
+
+impl UndoAction {
+    /// The label edit reverting a `Labels` action: what it added is removed
+    /// and what it removed is added back
+    pub fn label_revert(&self) -> Option<IssueAction> {
+        match self {
+            UndoAction::Labels { add, remove, .. } => Some(IssueAction::Label {
+                add: remove.clone(),
+                remove: add.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// What the action did, for the history and status messages
+    pub fn describe(&self) -> String {
+        match self {
+            UndoAction::Snooze {
+                repository,
+                woke: true,
+                ..
+            } => t_with("undo.woke", &[("repository", repository)]),
+            UndoAction::Snooze { repository, .. } => {
+                t_with("undo.snoozed", &[("repository", repository)])
+            }
+            UndoAction::Collector { collector, enabled } => t_with(
+                if *enabled {
+                    "undo.collector_on"
+                } else {
+                    "undo.collector_off"
+                },
+                &[("collector", &collector.label())],
+            ),
+            UndoAction::Labels {
+                owner,
+                repo,
+                number,
+                add,
+                remove,
+            } => {
+                let mut changes = Vec::new();
+                if !add.is_empty() {
+                    changes.push(format!("+{}", add.join(", +")));
+                }
+                if !remove.is_empty() {
+                    changes.push(format!("-{}", remove.join(", -")));
+                }
+                t_with(
+                    "undo.labelled",
+                    &[
+                        ("repository", &format!("{}/{}", owner, repo)),
+                        ("number", number),
+                        ("changes", &changes.join(" ")),
+                    ],
+                )
+            }
+        }
+    }
+}
+
+/// Reversible actions, most recent last
+#[derive(Debug, Default)]
+pub struct UndoStack {
+    actions: Vec<UndoAction>,
+}
+
+impl UndoStack {
+    /// Remember an action, forgetting the oldest past the limit
+    pub fn push(&mut self, action: UndoAction) {
+        if self.actions.len() == MAX_ACTIONS {
+            self.actions.remove(0);
+        }
+        self.actions.push(action);
+    }
+
+    /// Take the most recent action
+    pub fn pop(&mut self) -> Option<UndoAction> {
+        self.actions.pop()
+    }
+
+    /// Actions newest first
+    pub fn history(&self) -> impl Iterator<Item = &UndoAction> {
+        self.actions.iter().rev()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undo_stack_keeps_latest_actions() {
+        let mut stack = UndoStack::default();
+        for index in 0..=MAX_ACTIONS {
+            stack.push(UndoAction::Snooze {
+                repository: format!("acme/repo-{}", index),
+                previous: None,
+                woke: false,
+            });
+        }
+        stack.push(UndoAction::Labels {
+            owner: "acme".to_string(),
+            repo: "api".to_string(),
+            number: 3,
+            add: vec!["bug".to_string(), "p1".to_string()],
+            remove: vec!["triage".to_string()],
+        });
+
+        assert_eq!(stack.history().count(), MAX_ACTIONS);
+        let described: Vec<String> = stack.history().take(2).map(UndoAction::describe).collect();
+        assert_eq!(
+            described,
+            vec![
+                "Labelled acme/api#3 +bug, +p1 -triage",
+                "Snoozed acme/repo-50"
+            ]
+        );
+        // The first two snoozes were dropped
+        assert_eq!(
+            stack.history().last().unwrap().describe(),
+            "Snoozed acme/repo-2"
+        );
+
+        assert!(matches!(stack.pop(), Some(UndoAction::Labels { .. })));
+        assert!(!stack.is_empty());
+    }
+}